@@ -0,0 +1,332 @@
+//! Backpressure controller — moves the degradation ladder on queue pressure.
+//!
+//! # Overview
+//!
+//! [`BackpressureController`] turns a sequence of `queue_pressure`
+//! observations into ladder transitions, with hysteresis so that pressure
+//! hovering near a threshold does not flap between adjacent levels:
+//!
+//! - Escalate one level after [`ESCALATE_AFTER_OBSERVATIONS`] consecutive
+//!   observations above [`QUEUE_PRESSURE_RAISE`], up to `L4`.
+//! - De-escalate one level after [`DEESCALATE_AFTER_OBSERVATIONS`]
+//!   consecutive observations below [`QUEUE_PRESSURE_CLEAR`].
+//! - An observation between the two thresholds holds the level and resets
+//!   both counts.
+//!
+//! The thresholds are the Queue pressure raise and clear ratios of
+//! `docs/CAPACITY_ENVELOPE.md`. The de-escalation count is its De-escalation
+//! dwell expressed in Backpressure evaluation intervals (2000 ms / 100 ms).
+//! `L5` is reserved for fatal storage failures and is never reached on
+//! pressure alone.
+//!
+//! # Determinism
+//!
+//! The controller reads no clock. Callers observe at points fixed by their
+//! input (Tour observes once per append batch), so the same input always
+//! yields the same transitions. Each [`LevelChange`] becomes a Tier A
+//! `PolicyDecision` event through [`LevelChange::import_event`], which makes
+//! every transition part of the committed truth.
+//!
+//! # Constitution
+//!
+//! See `docs/BACKPRESSURE_POLICY.md` "Ladder transition semantics".
+
+use crate::event::{EventPayload, ImportEvent, Tier};
+use crate::projection::LadderLevel;
+
+/// Pressure above which an observation counts toward escalation.
+pub const QUEUE_PRESSURE_RAISE: f64 = 0.80;
+
+/// Pressure below which an observation counts toward de-escalation.
+pub const QUEUE_PRESSURE_CLEAR: f64 = 0.50;
+
+/// Consecutive observations above the raise ratio before escalating.
+pub const ESCALATE_AFTER_OBSERVATIONS: u32 = 3;
+
+/// Consecutive observations below the clear ratio before de-escalating:
+/// the 2000 ms dwell at one observation per 100 ms evaluation interval.
+pub const DEESCALATE_AFTER_OBSERVATIONS: u32 = 20;
+
+/// `trigger` of the `PolicyDecision` events the controller emits.
+pub const QUEUE_PRESSURE_TRIGGER: &str = "queue_pressure";
+
+/// `source_id` of the `PolicyDecision` events the controller emits.
+pub const BACKPRESSURE_SOURCE_ID: &str = "vifei-backpressure";
+
+/// Normalized queue pressure: `depth / capacity`, clamped to `[0.0, 1.0]`.
+/// A zero capacity reads as full whenever anything is queued.
+pub fn queue_pressure(depth: usize, capacity: usize) -> f64 {
+    if capacity == 0 {
+        return if depth == 0 { 0.0 } else { 1.0 };
+    }
+    depth.min(capacity) as f64 / capacity as f64
+}
+
+/// Hysteresis thresholds of a [`BackpressureController`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackpressureConfig {
+    /// Pressure above which observations count toward escalation.
+    pub raise: f64,
+    /// Pressure below which observations count toward de-escalation.
+    pub clear: f64,
+    /// Consecutive raised observations per escalation step.
+    pub escalate_after: u32,
+    /// Consecutive cleared observations per de-escalation step.
+    pub deescalate_after: u32,
+}
+
+impl BackpressureConfig {
+    /// The documented defaults (see the module docs).
+    pub fn new() -> Self {
+        BackpressureConfig {
+            raise: QUEUE_PRESSURE_RAISE,
+            clear: QUEUE_PRESSURE_CLEAR,
+            escalate_after: ESCALATE_AFTER_OBSERVATIONS,
+            deescalate_after: DEESCALATE_AFTER_OBSERVATIONS,
+        }
+    }
+
+    /// Set the raise and clear ratios.
+    pub fn with_thresholds(mut self, raise: f64, clear: f64) -> Self {
+        self.raise = raise;
+        self.clear = clear;
+        self
+    }
+
+    /// Set the consecutive observation counts per escalation and
+    /// de-escalation step. A count of 0 acts as 1.
+    pub fn with_observations(mut self, escalate_after: u32, deescalate_after: u32) -> Self {
+        self.escalate_after = escalate_after;
+        self.deescalate_after = deescalate_after;
+        self
+    }
+}
+
+impl Default for BackpressureConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One ladder transition decided by a [`BackpressureController`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelChange {
+    /// Level before the transition.
+    pub from: LadderLevel,
+    /// Level after the transition.
+    pub to: LadderLevel,
+    /// Pressure of the observation that completed the streak.
+    pub queue_pressure: f64,
+}
+
+impl LevelChange {
+    /// The Tier A `PolicyDecision` recording this transition, attributed to
+    /// `run_id` and stamped `timestamp_ns`. `seq` numbers the controller's
+    /// decisions and makes the `event_id` unique.
+    pub fn import_event(&self, run_id: &str, seq: u64, timestamp_ns: u64) -> ImportEvent {
+        ImportEvent {
+            run_id: run_id.to_string(),
+            event_id: format!("{BACKPRESSURE_SOURCE_ID}:{seq}"),
+            source_id: BACKPRESSURE_SOURCE_ID.to_string(),
+            source_seq: None,
+            timestamp_ns,
+            tier: Tier::A,
+            payload: EventPayload::PolicyDecision {
+                from_level: self.from.to_string(),
+                to_level: self.to.to_string(),
+                trigger: QUEUE_PRESSURE_TRIGGER.to_string(),
+                queue_pressure: self.queue_pressure,
+            },
+            payload_ref: None,
+            synthesized: true,
+        }
+    }
+}
+
+/// Moves the degradation ladder one level at a time on queue pressure.
+#[derive(Debug, Clone)]
+pub struct BackpressureController {
+    config: BackpressureConfig,
+    level: LadderLevel,
+    raised: u32,
+    cleared: u32,
+}
+
+impl BackpressureController {
+    /// A controller at `L0` with the default thresholds.
+    pub fn new() -> Self {
+        Self::with_config(BackpressureConfig::new())
+    }
+
+    /// A controller at `L0` with `config`.
+    pub fn with_config(config: BackpressureConfig) -> Self {
+        BackpressureController {
+            config,
+            level: LadderLevel::L0,
+            raised: 0,
+            cleared: 0,
+        }
+    }
+
+    /// Current ladder level.
+    pub fn level(&self) -> LadderLevel {
+        self.level
+    }
+
+    /// Record one pressure observation, returning the transition it
+    /// completes, if any. `queue_pressure` is clamped to `[0.0, 1.0]`.
+    pub fn observe(&mut self, queue_pressure: f64) -> Option<LevelChange> {
+        let pressure = queue_pressure.clamp(0.0, 1.0);
+        if pressure > self.config.raise {
+            self.cleared = 0;
+            self.raised += 1;
+            if self.raised < self.config.escalate_after.max(1) {
+                return None;
+            }
+            self.raised = 0;
+            let to = self.level.escalate().filter(|to| !to.is_safe_failure())?;
+            Some(self.step(to, pressure))
+        } else if pressure < self.config.clear {
+            self.raised = 0;
+            self.cleared += 1;
+            if self.cleared < self.config.deescalate_after.max(1) {
+                return None;
+            }
+            self.cleared = 0;
+            let to = self.level.deescalate()?;
+            Some(self.step(to, pressure))
+        } else {
+            self.raised = 0;
+            self.cleared = 0;
+            None
+        }
+    }
+
+    /// Observe an empty queue until the level is back at `L0`, returning
+    /// the transitions in order. Used once input is exhausted, so a run
+    /// ends on the level its drained queue warrants.
+    pub fn drain(&mut self) -> Vec<LevelChange> {
+        let max_observations = LadderLevel::ALL.len() as u32 * self.config.deescalate_after.max(1);
+        let mut changes = Vec::new();
+        for _ in 0..max_observations {
+            if self.level == LadderLevel::L0 {
+                break;
+            }
+            changes.extend(self.observe(0.0));
+        }
+        changes
+    }
+
+    fn step(&mut self, to: LadderLevel, queue_pressure: f64) -> LevelChange {
+        let change = LevelChange {
+            from: self.level,
+            to,
+            queue_pressure,
+        };
+        self.level = to;
+        change
+    }
+}
+
+impl Default for BackpressureController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observe_all(controller: &mut BackpressureController, samples: &[f64]) -> Vec<LevelChange> {
+        samples
+            .iter()
+            .filter_map(|&p| controller.observe(p))
+            .collect()
+    }
+
+    #[test]
+    fn escalates_one_level_per_raised_streak_up_to_l4() {
+        let mut controller = BackpressureController::new();
+        let changes = observe_all(&mut controller, &[1.0; 30]);
+        let levels: Vec<_> = changes.iter().map(|c| (c.from, c.to)).collect();
+        assert_eq!(
+            levels,
+            [
+                (LadderLevel::L0, LadderLevel::L1),
+                (LadderLevel::L1, LadderLevel::L2),
+                (LadderLevel::L2, LadderLevel::L3),
+                (LadderLevel::L3, LadderLevel::L4),
+            ]
+        );
+        assert_eq!(controller.level(), LadderLevel::L4);
+        assert!(changes.iter().all(|c| c.queue_pressure == 1.0));
+    }
+
+    #[test]
+    fn pressure_between_thresholds_resets_both_streaks() {
+        let mut controller = BackpressureController::new();
+        // Two raised observations, then one in the band: no escalation.
+        assert!(observe_all(&mut controller, &[0.9, 0.9, 0.6, 0.9, 0.9]).is_empty());
+        assert_eq!(controller.level(), LadderLevel::L0);
+        assert!(controller.observe(0.9).is_some());
+
+        // Recovery needs the full dwell without interruption.
+        let mut samples = vec![0.1; 19];
+        samples.push(0.7);
+        samples.extend([0.1; 19]);
+        assert!(observe_all(&mut controller, &samples).is_empty());
+        let change = controller.observe(0.1).unwrap();
+        assert_eq!((change.from, change.to), (LadderLevel::L1, LadderLevel::L0));
+    }
+
+    #[test]
+    fn thresholds_are_strict() {
+        let mut controller = BackpressureController::new();
+        assert!(observe_all(&mut controller, &[QUEUE_PRESSURE_RAISE; 10]).is_empty());
+        assert_eq!(controller.level(), LadderLevel::L0);
+    }
+
+    #[test]
+    fn drain_steps_back_to_l0() {
+        let mut controller = BackpressureController::new();
+        observe_all(&mut controller, &[1.0; 9]);
+        assert_eq!(controller.level(), LadderLevel::L3);
+
+        let changes = controller.drain();
+        let levels: Vec<_> = changes.iter().map(|c| c.to).collect();
+        assert_eq!(levels, [LadderLevel::L2, LadderLevel::L1, LadderLevel::L0]);
+        assert!(changes.iter().all(|c| c.queue_pressure == 0.0));
+        assert!(controller.drain().is_empty());
+    }
+
+    #[test]
+    fn level_change_becomes_tier_a_policy_decision() {
+        let change = LevelChange {
+            from: LadderLevel::L1,
+            to: LadderLevel::L2,
+            queue_pressure: 0.875,
+        };
+        let event = change.import_event("run-1", 4, 7);
+        assert_eq!(event.tier, Tier::A);
+        assert!(event.synthesized);
+        assert_eq!(event.event_id, "vifei-backpressure:4");
+        assert_eq!(
+            event.payload,
+            EventPayload::PolicyDecision {
+                from_level: "L1".into(),
+                to_level: "L2".into(),
+                trigger: "queue_pressure".into(),
+                queue_pressure: 0.875,
+            }
+        );
+    }
+
+    #[test]
+    fn queue_pressure_is_clamped_ratio() {
+        assert_eq!(queue_pressure(0, 100), 0.0);
+        assert_eq!(queue_pressure(50, 100), 0.5);
+        assert_eq!(queue_pressure(500, 100), 1.0);
+        assert_eq!(queue_pressure(1, 0), 1.0);
+    }
+}
@@ -13,10 +13,29 @@
 //! blobs/
 //!   {first-2-hex-chars}/
 //!     {full-64-char-blake3-hex}
+//!     {full-64-char-blake3-hex}.zst   (compressed form)
 //! ```
 //!
 //! The two-character prefix directory reduces per-directory inode pressure.
 //!
+//! # Compression at rest
+//!
+//! A store opened [`with_compression`](BlobStore::with_compression) keeps
+//! blobs larger than the threshold zstd-compressed as `{hash}.zst`, when
+//! that is smaller. The hash is always of the uncompressed bytes, so a
+//! `payload_ref` does not depend on how its blob is stored; reads,
+//! verification, and GC accept either form and return logical content.
+//! [`BlobStore::migrate_compress`] converts existing plain blobs.
+//!
+//! # Streaming
+//!
+//! [`BlobStore::write_blob_from`] and [`BlobStore::open_blob`] move blob
+//! bytes through a reader, so a 500 MB artifact is never held in memory.
+//! Streamed writes land in a `.incoming-*.tmp` file in the store root while
+//! being hashed and are renamed into their prefix directory only once the
+//! digest is known; a crash mid-write leaves a `.tmp` file, never a
+//! wrongly named blob.
+//!
 //! # Deduplication
 //!
 //! Content-addressing provides natural deduplication: if the same payload
@@ -29,25 +48,181 @@
 //! `FM-BLOB-WRITE-FAIL` in `docs/BACKPRESSURE_POLICY.md`. The caller
 //! (append writer) is responsible for entering L5 safe failure posture.
 //!
+//! # Enumeration
+//!
+//! [`BlobStore::iter_refs`] lists the `payload_ref`s the store holds and
+//! [`BlobStore::stats`] counts them and their on-disk bytes. Both walk the
+//! layout above in sorted order and skip `.tmp` files and any entry whose
+//! name is not a `payload_ref`.
+//!
+//! # Garbage collection
+//!
+//! [`BlobStore::gc`] sweeps blobs that no event references. It only deletes
+//! files whose name is a valid `payload_ref` absent from the caller's
+//! referenced set; in-flight `.tmp` files and unrecognized entries are left
+//! alone. A blob written after the referenced set was computed but before
+//! its event is appended is indistinguishable from garbage, so run GC while
+//! no append writer is active on the same store.
+//!
+//! # Integrity verification
+//!
+//! [`BlobStore::verify_all`] re-hashes every stored blob and reports files
+//! whose bytes no longer match their `payload_ref` name, with the hash they
+//! do have; [`BlobStore::verify`] returns just those failures.
+//! [`BlobStore::verify_refs`]
+//! additionally reports refs an EventLog points to that are absent from the
+//! store, and [`BlobStore::verify_one`] checks a single ref. Blobs are
+//! streamed through the hasher, so memory does not grow with blob size.
+//!
 //! # Invariants
 //!
 //! - **I1 (Forensic truth):** Blob bytes are stored exactly as received.
 //! - **I5 (Loud failure):** Errors are returned, never silently swallowed.
 
+use std::collections::{BTreeSet, HashSet};
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Inline payload max bytes. Payloads at or below this size are stored
 /// inline in the JSONL event. Above this threshold, they go to the blob
 /// store. Value is from `docs/CAPACITY_ENVELOPE.md`.
 pub const INLINE_PAYLOAD_MAX_BYTES: usize = 16_384;
 
+/// Size above which [`BlobStore::migrate_compress`] compresses blobs when the
+/// store has no threshold of its own.
+pub const DEFAULT_COMPRESS_THRESHOLD_BYTES: u64 = 64 * 1024;
+
+/// zstd level for compressed blobs.
+const BLOB_ZSTD_LEVEL: i32 = 3;
+
+/// Outcome of [`BlobStore::migrate_compress`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompressReport {
+    /// Plain blobs above the threshold that were considered.
+    pub examined: u64,
+    /// Blobs now stored compressed.
+    pub compressed: u64,
+    /// Blobs left plain because compression did not make them smaller.
+    pub incompressible: u64,
+    /// Blobs left alone because their bytes do not match their name.
+    pub corrupt: u64,
+    /// On-disk bytes of the compressed blobs before migration.
+    pub bytes_before: u64,
+    /// On-disk bytes of the compressed blobs after migration.
+    pub bytes_after: u64,
+}
+
+/// Contents summary from [`BlobStore::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobStoreStats {
+    /// Distinct blobs stored, in either form.
+    pub count: u64,
+    /// On-disk bytes of the blob files (compressed size for `.zst` blobs).
+    pub total_bytes: u64,
+}
+
+/// Outcome of a [`BlobStore::gc`] sweep.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    /// Blob files examined (valid `payload_ref` names only).
+    pub scanned: u64,
+    /// Blobs kept because they are referenced.
+    pub kept: u64,
+    /// Unreferenced blobs deleted, or that would be on a dry run.
+    pub removed: u64,
+    /// In-flight `.tmp` files skipped.
+    pub skipped_in_flight: u64,
+    /// Total bytes freed by deleted blobs, or that would be on a dry run.
+    pub freed_bytes: u64,
+    /// `payload_ref`s of the unreferenced blobs, in sorted order.
+    pub unreferenced: Vec<String>,
+    /// True when nothing was deleted.
+    pub dry_run: bool,
+}
+
+/// Outcome of [`BlobStore::verify_all`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerifyReport {
+    /// Blob files re-hashed.
+    pub checked: u64,
+    /// Blobs whose bytes do not match their `payload_ref` (expected hash
+    /// vs actual in [`BlobIntegrityError::HashMismatch`]) or that could
+    /// not be read, in sorted path order.
+    pub failures: Vec<BlobIntegrityError>,
+}
+
+impl VerifyReport {
+    /// True when every checked blob matched its `payload_ref`.
+    pub fn is_clean(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// A single blob integrity failure reported by [`BlobStore::verify`] or
+/// [`BlobStore::verify_one`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlobIntegrityError {
+    /// Blob bytes hash to `actual_ref` instead of the file's `payload_ref`.
+    HashMismatch {
+        payload_ref: String,
+        actual_ref: String,
+    },
+    /// A referenced blob is not present in the store.
+    Missing { payload_ref: String },
+    /// A blob file exists but could not be read.
+    Unreadable {
+        payload_ref: String,
+        message: String,
+    },
+}
+
+impl BlobIntegrityError {
+    /// The `payload_ref` this error concerns.
+    pub fn payload_ref(&self) -> &str {
+        match self {
+            BlobIntegrityError::HashMismatch { payload_ref, .. }
+            | BlobIntegrityError::Missing { payload_ref }
+            | BlobIntegrityError::Unreadable { payload_ref, .. } => payload_ref,
+        }
+    }
+
+    /// Stable machine-readable kind: `hash_mismatch`, `missing`, or `unreadable`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BlobIntegrityError::HashMismatch { .. } => "hash_mismatch",
+            BlobIntegrityError::Missing { .. } => "missing",
+            BlobIntegrityError::Unreadable { .. } => "unreadable",
+        }
+    }
+}
+
+impl std::fmt::Display for BlobIntegrityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlobIntegrityError::HashMismatch {
+                payload_ref,
+                actual_ref,
+            } => write!(f, "blob {payload_ref} content hashes to {actual_ref}"),
+            BlobIntegrityError::Missing { payload_ref } => {
+                write!(f, "referenced blob {payload_ref} is missing")
+            }
+            BlobIntegrityError::Unreadable {
+                payload_ref,
+                message,
+            } => write!(f, "blob {payload_ref} could not be read: {message}"),
+        }
+    }
+}
+
 /// Content-addressed blob store backed by the filesystem.
 #[derive(Debug)]
 pub struct BlobStore {
     /// Root directory for blob storage.
     root: PathBuf,
+    /// New blobs larger than this many bytes are stored compressed.
+    compress_threshold: Option<u64>,
 }
 
 impl BlobStore {
@@ -57,14 +232,23 @@ impl BlobStore {
     pub fn open(root: impl Into<PathBuf>) -> io::Result<Self> {
         let root = root.into();
         fs::create_dir_all(&root)?;
-        Ok(BlobStore { root })
+        Ok(BlobStore {
+            root,
+            compress_threshold: None,
+        })
+    }
+
+    /// Store new blobs larger than `threshold_bytes` zstd-compressed.
+    pub fn with_compression(mut self, threshold_bytes: u64) -> Self {
+        self.compress_threshold = Some(threshold_bytes);
+        self
     }
 
     /// Write payload bytes to the blob store.
     ///
     /// Returns the `payload_ref` — lowercase hex BLAKE3 digest of the
-    /// stored bytes. If a blob with this digest already exists, the write
-    /// is a no-op (content-addressed deduplication).
+    /// stored bytes. If a blob with this digest already exists, in either
+    /// form, the write is a no-op (content-addressed deduplication).
     pub fn write_blob(&self, data: &[u8]) -> io::Result<String> {
         let hash = blake3::hash(data);
         let hex = hash.to_hex();
@@ -73,7 +257,7 @@ impl BlobStore {
         let blob_path = self.blob_path(payload_ref);
 
         // Deduplication: if blob already exists, skip the write.
-        if blob_path.exists() {
+        if self.stored_path(payload_ref).is_some() {
             return Ok(payload_ref.to_string());
         }
 
@@ -87,11 +271,120 @@ impl BlobStore {
         let mut file = fs::File::create(&tmp_path)?;
         file.write_all(data)?;
         file.sync_all()?;
-        fs::rename(&tmp_path, &blob_path)?;
+        self.place_blob(&tmp_path, payload_ref, data.len() as u64)?;
 
         Ok(payload_ref.to_string())
     }
 
+    /// Stream `reader` into the store, hashing as it is written.
+    ///
+    /// Returns the `payload_ref` of the bytes read. The temp file is synced
+    /// and renamed into place atomically; if the blob already exists the
+    /// temp file is discarded. On error the temp file is removed.
+    pub fn write_blob_from(&self, mut reader: impl Read) -> io::Result<String> {
+        static NEXT_INCOMING: AtomicU64 = AtomicU64::new(0);
+        let tmp_path = self.root.join(format!(
+            ".incoming-{}-{}.tmp",
+            std::process::id(),
+            NEXT_INCOMING.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = fs::File::create(&tmp_path)?;
+        let written = (|| {
+            let mut hasher = blake3::Hasher::new();
+            let mut buf = vec![0u8; 64 * 1024];
+            loop {
+                let n = match reader.read(&mut buf) {
+                    Ok(0) => break,
+                    Ok(n) => n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(e) => return Err(e),
+                };
+                hasher.update(&buf[..n]);
+                file.write_all(&buf[..n])?;
+            }
+            file.sync_all()?;
+            let payload_ref = hasher.finalize().to_hex().to_string();
+            if self.stored_path(&payload_ref).is_some() {
+                fs::remove_file(&tmp_path)?;
+            } else {
+                if let Some(parent) = self.blob_path(&payload_ref).parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                self.place_blob(&tmp_path, &payload_ref, file.metadata()?.len())?;
+            }
+            Ok(payload_ref)
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        written
+    }
+
+    /// Move the synced plain temp file `tmp_path` into place as
+    /// `payload_ref`, compressed when the store's threshold calls for it and
+    /// compression saves space.
+    fn place_blob(&self, tmp_path: &Path, payload_ref: &str, len: u64) -> io::Result<()> {
+        if self
+            .compress_threshold
+            .is_some_and(|threshold| len > threshold)
+            && self.compress_into_place(tmp_path, payload_ref)?.is_some()
+        {
+            return fs::remove_file(tmp_path);
+        }
+        fs::rename(tmp_path, self.blob_path(payload_ref))
+    }
+
+    /// Compress the plain file `src` to `{payload_ref}.zst`, renaming it into
+    /// place only if it is smaller than `src`. Returns the compressed size
+    /// when it was kept. `src` is left untouched.
+    fn compress_into_place(&self, src: &Path, payload_ref: &str) -> io::Result<Option<u64>> {
+        let zst_path = self.compressed_path(payload_ref);
+        let mut tmp = zst_path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        let compressed = (|| {
+            let mut out = fs::File::create(&tmp)?;
+            zstd::stream::copy_encode(fs::File::open(src)?, &mut out, BLOB_ZSTD_LEVEL)?;
+            out.sync_all()?;
+            Ok::<_, io::Error>(out.metadata()?.len())
+        })();
+        match compressed {
+            Ok(size) if size < fs::metadata(src)?.len() => {
+                fs::rename(&tmp, &zst_path)?;
+                Ok(Some(size))
+            }
+            Ok(_) => {
+                fs::remove_file(&tmp)?;
+                Ok(None)
+            }
+            Err(e) => {
+                let _ = fs::remove_file(&tmp);
+                Err(e)
+            }
+        }
+    }
+
+    /// Open the blob `payload_ref` for streaming reads of its uncompressed
+    /// content, whichever form it is stored in.
+    ///
+    /// Fails with `NotFound` if the blob does not exist and `InvalidInput`
+    /// if `payload_ref` is not 64 lowercase hex characters.
+    pub fn open_blob(&self, payload_ref: &str) -> io::Result<impl Read> {
+        if !Self::is_valid_payload_ref(payload_ref) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid payload_ref: {payload_ref:?}"),
+            ));
+        }
+        let Some((path, compressed)) = self.stored_path(payload_ref) else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("blob {payload_ref} not found"),
+            ));
+        };
+        open_blob_file(&path, compressed)
+    }
+
     /// Read blob bytes by `payload_ref` (BLAKE3 hex digest).
     ///
     /// Returns `None` if the blob does not exist.
@@ -102,20 +395,126 @@ impl BlobStore {
                 format!("invalid payload_ref: {payload_ref:?}"),
             ));
         }
-        let blob_path = self.blob_path(payload_ref);
-        if !blob_path.exists() {
+        let Some((path, compressed)) = self.stored_path(payload_ref) else {
             return Ok(None);
-        }
-        let data = fs::read(&blob_path)?;
+        };
+        let mut data = Vec::new();
+        open_blob_file(&path, compressed)?.read_to_end(&mut data)?;
         Ok(Some(data))
     }
 
-    /// Check if a blob exists.
+    /// Check if a blob exists, in either form.
     pub fn has_blob(&self, payload_ref: &str) -> bool {
         if !Self::is_valid_payload_ref(payload_ref) {
             return false;
         }
-        self.blob_path(payload_ref).exists()
+        self.stored_path(payload_ref).is_some()
+    }
+
+    /// The `payload_ref`s of the stored blobs, sorted, each once even when
+    /// both forms are present.
+    pub fn iter_refs(&self) -> io::Result<impl Iterator<Item = String>> {
+        let refs: BTreeSet<String> = self
+            .blob_files()?
+            .into_iter()
+            .map(|(_, payload_ref, _)| payload_ref)
+            .collect();
+        Ok(refs.into_iter())
+    }
+
+    /// On-disk size of the blob `payload_ref` in the form reads use (plain
+    /// first), or `None` if it is not stored.
+    pub fn stored_size(&self, payload_ref: &str) -> io::Result<Option<u64>> {
+        if !Self::is_valid_payload_ref(payload_ref) {
+            return Ok(None);
+        }
+        self.stored_path(payload_ref)
+            .map(|(path, _)| fs::metadata(path).map(|meta| meta.len()))
+            .transpose()
+    }
+
+    /// Number of stored blobs and their total on-disk size.
+    pub fn stats(&self) -> io::Result<BlobStoreStats> {
+        let mut refs = BTreeSet::new();
+        let mut total_bytes = 0;
+        for (path, payload_ref, _) in self.blob_files()? {
+            total_bytes += fs::metadata(&path)?.len();
+            refs.insert(payload_ref);
+        }
+        Ok(BlobStoreStats {
+            count: refs.len() as u64,
+            total_bytes,
+        })
+    }
+
+    /// Every blob file as `(path, payload_ref, compressed)`, in sorted path
+    /// order. `.tmp` files and foreign entries are skipped.
+    fn blob_files(&self) -> io::Result<Vec<(PathBuf, String, bool)>> {
+        let mut files = Vec::new();
+        for prefix_dir in sorted_dir_entries(&self.root)? {
+            if !prefix_dir.is_dir() {
+                continue;
+            }
+            for path in sorted_dir_entries(&prefix_dir)? {
+                let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                let Some((payload_ref, compressed)) = Self::parse_blob_name(name) else {
+                    continue;
+                };
+                if path.is_file() {
+                    let payload_ref = payload_ref.to_string();
+                    files.push((path, payload_ref, compressed));
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// Compress every plain blob larger than the store's threshold (or
+    /// [`DEFAULT_COMPRESS_THRESHOLD_BYTES`]) in place.
+    ///
+    /// Each blob is re-hashed while it is compressed; one whose bytes do not
+    /// match its name is left as is. The `.zst` file is renamed into place
+    /// before the plain file is removed, so an interrupted migration leaves
+    /// both forms, never neither. Blobs that do not shrink stay plain.
+    pub fn migrate_compress(&self) -> io::Result<CompressReport> {
+        let threshold = self
+            .compress_threshold
+            .unwrap_or(DEFAULT_COMPRESS_THRESHOLD_BYTES);
+        let mut report = CompressReport::default();
+        for prefix_dir in sorted_dir_entries(&self.root)? {
+            if !prefix_dir.is_dir() {
+                continue;
+            }
+            for path in sorted_dir_entries(&prefix_dir)? {
+                let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                if !path.is_file() || !Self::is_valid_payload_ref(name) {
+                    continue;
+                }
+                let size = fs::metadata(&path)?.len();
+                if size <= threshold {
+                    continue;
+                }
+                report.examined += 1;
+                if check_blob_file(&path, name, false).is_some() {
+                    report.corrupt += 1;
+                    continue;
+                }
+                match self.compress_into_place(&path, name)? {
+                    Some(compressed) => {
+                        fs::remove_file(&path)?;
+                        report.compressed += 1;
+                        report.bytes_before += size;
+                        report.bytes_after += compressed;
+                    }
+                    None => report.incompressible += 1,
+                }
+            }
+        }
+        Ok(report)
     }
 
     /// Returns true if `data` exceeds the inline payload threshold and
@@ -129,6 +528,134 @@ impl BlobStore {
         blake3::hash(data).to_hex().to_string()
     }
 
+    /// Delete blobs whose `payload_ref` is not in `referenced`, or with
+    /// `dry_run` only list them.
+    ///
+    /// Never deletes a referenced blob, a `.tmp` file from an in-progress
+    /// [`write_blob`](Self::write_blob) or
+    /// [`write_blob_from`](Self::write_blob_from), or any file whose name is not a
+    /// valid `payload_ref`. Entries are visited in sorted order so the
+    /// report is deterministic for a given store.
+    pub fn gc(&self, referenced: &HashSet<String>, dry_run: bool) -> io::Result<GcReport> {
+        let mut report = GcReport {
+            dry_run,
+            ..GcReport::default()
+        };
+        for prefix_dir in sorted_dir_entries(&self.root)? {
+            if !prefix_dir.is_dir() {
+                continue;
+            }
+            for path in sorted_dir_entries(&prefix_dir)? {
+                if !path.is_file() {
+                    continue;
+                }
+                let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                    continue;
+                };
+                if name.ends_with(".tmp") {
+                    report.skipped_in_flight += 1;
+                    continue;
+                }
+                let Some((name, _)) = Self::parse_blob_name(name) else {
+                    continue;
+                };
+                report.scanned += 1;
+                if referenced.contains(name) {
+                    report.kept += 1;
+                    continue;
+                }
+                let size = match fs::metadata(&path) {
+                    Ok(meta) => meta.len(),
+                    // Removed concurrently; nothing to free.
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                    Err(e) => return Err(e),
+                };
+                let removed = if dry_run {
+                    Ok(())
+                } else {
+                    fs::remove_file(&path)
+                };
+                match removed {
+                    Ok(()) => {
+                        report.removed += 1;
+                        report.freed_bytes += size;
+                        // Both forms of one blob sort next to each other.
+                        if report.unreferenced.last().map(String::as_str) != Some(name) {
+                            report.unreferenced.push(name.to_string());
+                        }
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Re-hash every stored blob, streaming it through BLAKE3, and report
+    /// how many were checked and which do not match their `payload_ref`.
+    ///
+    /// Only files named with a valid `payload_ref` (plain or `.zst`) are
+    /// checked, compressed ones by their uncompressed content; `.tmp` files
+    /// and foreign entries are ignored.
+    pub fn verify_all(&self) -> io::Result<VerifyReport> {
+        let mut report = VerifyReport::default();
+        for (path, payload_ref, compressed) in self.blob_files()? {
+            report.checked += 1;
+            report
+                .failures
+                .extend(check_blob_file(&path, &payload_ref, compressed));
+        }
+        Ok(report)
+    }
+
+    /// The failures of [`verify_all`](Self::verify_all). An empty result
+    /// means the store is intact.
+    pub fn verify(&self) -> io::Result<Vec<BlobIntegrityError>> {
+        Ok(self.verify_all()?.failures)
+    }
+
+    /// Re-hash the blob `payload_ref`, returning its integrity failure, if
+    /// any. An absent blob is [`BlobIntegrityError::Missing`].
+    ///
+    /// Fails with `InvalidInput` when `payload_ref` is not 64 lowercase hex
+    /// characters.
+    pub fn verify_one(&self, payload_ref: &str) -> io::Result<Option<BlobIntegrityError>> {
+        if !Self::is_valid_payload_ref(payload_ref) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid payload_ref: {payload_ref:?}"),
+            ));
+        }
+        let Some((path, compressed)) = self.stored_path(payload_ref) else {
+            return Ok(Some(BlobIntegrityError::Missing {
+                payload_ref: payload_ref.to_string(),
+            }));
+        };
+        Ok(check_blob_file(&path, payload_ref, compressed))
+    }
+
+    /// [`verify`](Self::verify) plus a [`BlobIntegrityError::Missing`] entry
+    /// for each ref in `referenced` that the store does not hold.
+    ///
+    /// Missing entries follow the content checks, in sorted ref order.
+    pub fn verify_refs(&self, referenced: &HashSet<String>) -> io::Result<Vec<BlobIntegrityError>> {
+        let mut errors = self.verify()?;
+        let mut missing: Vec<&String> = referenced
+            .iter()
+            .filter(|payload_ref| !self.has_blob(payload_ref))
+            .collect();
+        missing.sort();
+        errors.extend(
+            missing
+                .into_iter()
+                .map(|payload_ref| BlobIntegrityError::Missing {
+                    payload_ref: payload_ref.clone(),
+                }),
+        );
+        Ok(errors)
+    }
+
     /// Validate payload_ref format: 64 lowercase hex characters.
     fn is_valid_payload_ref(payload_ref: &str) -> bool {
         payload_ref.len() == 64
@@ -137,18 +664,88 @@ impl BlobStore {
                 .all(|c| matches!(c, '0'..='9' | 'a'..='f'))
     }
 
+    /// `payload_ref` and compressed flag of a blob file name, if it is one.
+    fn parse_blob_name(name: &str) -> Option<(&str, bool)> {
+        match name.strip_suffix(".zst") {
+            Some(stem) if Self::is_valid_payload_ref(stem) => Some((stem, true)),
+            Some(_) => None,
+            None => Self::is_valid_payload_ref(name).then_some((name, false)),
+        }
+    }
+
     /// Filesystem path for a blob given its `payload_ref`.
     fn blob_path(&self, payload_ref: &str) -> PathBuf {
         let prefix = &payload_ref[..2.min(payload_ref.len())];
         self.root.join(prefix).join(payload_ref)
     }
 
+    /// Filesystem path of the compressed form of `payload_ref`.
+    fn compressed_path(&self, payload_ref: &str) -> PathBuf {
+        let mut path = self.blob_path(payload_ref).into_os_string();
+        path.push(".zst");
+        PathBuf::from(path)
+    }
+
+    /// Existing file for `payload_ref`, plain form first, and whether it is
+    /// compressed.
+    fn stored_path(&self, payload_ref: &str) -> Option<(PathBuf, bool)> {
+        let plain = self.blob_path(payload_ref);
+        if plain.is_file() {
+            return Some((plain, false));
+        }
+        let compressed = self.compressed_path(payload_ref);
+        compressed.is_file().then_some((compressed, true))
+    }
+
     /// Root directory of the blob store.
     pub fn root(&self) -> &Path {
         &self.root
     }
 }
 
+/// Reader over the uncompressed content of the blob file at `path`.
+fn open_blob_file(path: &Path, compressed: bool) -> io::Result<Box<dyn Read + Send>> {
+    let file = fs::File::open(path)?;
+    if compressed {
+        Ok(Box::new(zstd::stream::read::Decoder::new(file)?))
+    } else {
+        Ok(Box::new(file))
+    }
+}
+
+/// Stream the uncompressed content of the blob file at `path` through
+/// BLAKE3 and compare the digest to its `payload_ref` name.
+fn check_blob_file(path: &Path, payload_ref: &str, compressed: bool) -> Option<BlobIntegrityError> {
+    let mut hasher = blake3::Hasher::new();
+    match open_blob_file(path, compressed).and_then(|mut file| io::copy(&mut file, &mut hasher)) {
+        Ok(_) => {
+            let actual_ref = hasher.finalize().to_hex().to_string();
+            (actual_ref != payload_ref).then(|| BlobIntegrityError::HashMismatch {
+                payload_ref: payload_ref.to_string(),
+                actual_ref,
+            })
+        }
+        Err(e) => Some(BlobIntegrityError::Unreadable {
+            payload_ref: payload_ref.to_string(),
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// List directory entries sorted by path. Missing directory yields empty.
+fn sorted_dir_entries(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut paths = entries
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.sort();
+    Ok(paths)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -270,4 +867,354 @@ mod tests {
         assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
         assert!(!store.has_blob(&uppercase));
     }
+
+    #[test]
+    fn gc_removes_only_unreferenced_blobs() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+
+        let keep = store.write_blob(b"referenced blob").unwrap();
+        let drop_a = store.write_blob(b"orphan blob a").unwrap();
+        let drop_b = store.write_blob(b"orphan blob bb").unwrap();
+
+        let referenced: HashSet<String> = [keep.clone()].into_iter().collect();
+        let report = store.gc(&referenced, false).unwrap();
+
+        assert_eq!(report.scanned, 3);
+        assert_eq!(report.kept, 1);
+        assert_eq!(report.removed, 2);
+        assert_eq!(
+            report.freed_bytes,
+            (b"orphan blob a".len() + b"orphan blob bb".len()) as u64
+        );
+        assert!(store.has_blob(&keep));
+        assert!(!store.has_blob(&drop_a));
+        assert!(!store.has_blob(&drop_b));
+    }
+
+    #[test]
+    fn compressed_and_plain_blobs_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs"))
+            .unwrap()
+            .with_compression(1024);
+        let small = b"short tool result".to_vec();
+        let large = "line of very repetitive tool output\n"
+            .repeat(500)
+            .into_bytes();
+
+        let small_ref = store.write_blob(&small).unwrap();
+        let large_ref = store.write_blob(&large).unwrap();
+        let streamed_ref = store.write_blob_from(large.repeat(2).as_slice()).unwrap();
+        assert_eq!(large_ref, BlobStore::compute_ref(&large));
+        assert!(store.blob_path(&small_ref).is_file());
+        assert!(!store.blob_path(&large_ref).exists());
+        assert!(store.compressed_path(&large_ref).is_file());
+        assert!(store.compressed_path(&streamed_ref).is_file());
+        assert!(
+            fs::metadata(store.compressed_path(&large_ref))
+                .unwrap()
+                .len()
+                < large.len() as u64
+        );
+
+        assert_eq!(store.read_blob(&small_ref).unwrap().unwrap(), small);
+        assert_eq!(store.read_blob(&large_ref).unwrap().unwrap(), large);
+        let mut streamed = Vec::new();
+        store
+            .open_blob(&streamed_ref)
+            .unwrap()
+            .read_to_end(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, large.repeat(2));
+        assert!(store.has_blob(&large_ref));
+        assert_eq!(store.write_blob(&large).unwrap(), large_ref);
+        assert!(store.verify().unwrap().is_empty());
+        assert_eq!(store.verify_one(&large_ref).unwrap(), None);
+
+        fs::write(
+            store.compressed_path(&large_ref),
+            zstd::encode_all(&b"bit rot"[..], 3).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(store.verify().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn migrate_compress_converts_plain_blobs_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let large = "{\"status\":\"ok\"}\n".repeat(10_000).into_bytes();
+        let large_ref = store.write_blob(&large).unwrap();
+        let small_ref = store.write_blob(b"tiny").unwrap();
+        assert!(store.blob_path(&large_ref).is_file());
+
+        let report = store.migrate_compress().unwrap();
+        assert_eq!(report.examined, 1);
+        assert_eq!(report.compressed, 1);
+        assert!(report.bytes_after < report.bytes_before);
+        assert!(!store.blob_path(&large_ref).exists());
+        assert!(store.blob_path(&small_ref).is_file());
+        assert_eq!(store.read_blob(&large_ref).unwrap().unwrap(), large);
+        assert_eq!(store.migrate_compress().unwrap().examined, 0);
+
+        let referenced: HashSet<String> = [small_ref].into_iter().collect();
+        let gc = store.gc(&referenced, false).unwrap();
+        assert_eq!(gc.unreferenced, vec![large_ref.clone()]);
+        assert!(!store.has_blob(&large_ref));
+    }
+
+    #[test]
+    fn streamed_write_and_read_match_buffered() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let data = vec![7u8; 200 * 1024];
+
+        let payload_ref = store.write_blob_from(data.as_slice()).unwrap();
+        assert_eq!(payload_ref, BlobStore::compute_ref(&data));
+        assert_eq!(store.write_blob_from(data.as_slice()).unwrap(), payload_ref);
+        let mut streamed = Vec::new();
+        store
+            .open_blob(&payload_ref)
+            .unwrap()
+            .read_to_end(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, data);
+
+        let missing = BlobStore::compute_ref(b"absent");
+        assert_eq!(
+            store.open_blob(&missing).err().map(|e| e.kind()),
+            Some(io::ErrorKind::NotFound)
+        );
+        let leftovers: Vec<_> = fs::read_dir(store.root())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_file())
+            .collect();
+        assert!(leftovers.is_empty(), "no temp files remain");
+    }
+
+    #[test]
+    fn failed_streamed_write_leaves_no_blob() {
+        struct Broken(usize);
+        impl Read for Broken {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::other("source went away"));
+                }
+                self.0 -= 1;
+                buf[0] = b'x';
+                Ok(1)
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        assert!(store.write_blob_from(Broken(3)).is_err());
+        assert_eq!(fs::read_dir(store.root()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn gc_dry_run_lists_without_deleting() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let keep = store.write_blob(b"referenced blob").unwrap();
+        let orphan_a = store.write_blob(b"orphan blob a").unwrap();
+        let orphan_b = store.write_blob(b"orphan blob bb").unwrap();
+        let referenced: HashSet<String> = [keep.clone()].into_iter().collect();
+
+        let dry = store.gc(&referenced, true).unwrap();
+        assert!(dry.dry_run);
+        assert_eq!(dry.removed, 2);
+        let mut expected = vec![orphan_a.clone(), orphan_b.clone()];
+        expected.sort();
+        assert_eq!(dry.unreferenced, expected);
+        assert!(store.has_blob(&orphan_a) && store.has_blob(&orphan_b));
+
+        let real = store.gc(&referenced, false).unwrap();
+        assert_eq!(
+            (real.removed, real.freed_bytes, &real.unreferenced),
+            (dry.removed, dry.freed_bytes, &dry.unreferenced)
+        );
+        assert!(!store.has_blob(&orphan_a));
+    }
+
+    #[test]
+    fn gc_skips_in_flight_and_foreign_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+
+        let payload_ref = BlobStore::compute_ref(b"still writing");
+        let prefix_dir = store.root().join(&payload_ref[..2]);
+        fs::create_dir_all(&prefix_dir).unwrap();
+        let tmp_path = prefix_dir.join(format!("{payload_ref}.tmp"));
+        fs::write(&tmp_path, b"still writing").unwrap();
+        let foreign_path = prefix_dir.join("README");
+        fs::write(&foreign_path, b"not a blob").unwrap();
+
+        let report = store.gc(&HashSet::new(), false).unwrap();
+        assert_eq!(report.skipped_in_flight, 1);
+        assert_eq!(report.scanned, 0);
+        assert_eq!(report.removed, 0);
+        assert!(tmp_path.exists());
+        assert!(foreign_path.exists());
+    }
+
+    #[test]
+    fn verify_clean_store_reports_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let a = store.write_blob(b"alpha").unwrap();
+        let b = store.write_blob(b"beta").unwrap();
+
+        assert!(store.verify().unwrap().is_empty());
+        let referenced: HashSet<String> = [a, b].into_iter().collect();
+        assert!(store.verify_refs(&referenced).unwrap().is_empty());
+    }
+
+    #[test]
+    fn verify_detects_corrupted_blob() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let intact = store.write_blob(b"intact").unwrap();
+        let corrupted = store.write_blob(b"original bytes").unwrap();
+        fs::write(store.blob_path(&corrupted), b"tampered bytes").unwrap();
+
+        let errors = store.verify().unwrap();
+        assert_eq!(
+            errors,
+            vec![BlobIntegrityError::HashMismatch {
+                payload_ref: corrupted.clone(),
+                actual_ref: BlobStore::compute_ref(b"tampered bytes"),
+            }]
+        );
+        assert_eq!(errors[0].kind(), "hash_mismatch");
+        assert_ne!(errors[0].payload_ref(), intact);
+    }
+
+    #[test]
+    fn verify_all_counts_checked_blobs_and_reports_expected_vs_actual() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs"))
+            .unwrap()
+            .with_compression(0);
+        store.write_blob(b"intact").unwrap();
+        let corrupted = store.write_blob(&[b'a'; 256]).unwrap();
+        assert!(store.verify_all().unwrap().is_clean());
+
+        let (path, compressed) = store.stored_path(&corrupted).unwrap();
+        assert!(compressed);
+        fs::write(path, zstd::encode_all(&[b'b'; 256][..], 0).unwrap()).unwrap();
+        fs::write(dir.path().join("blobs").join("stray.tmp"), b"in flight").unwrap();
+
+        let report = store.verify_all().unwrap();
+        assert_eq!(report.checked, 2);
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.failures,
+            vec![BlobIntegrityError::HashMismatch {
+                payload_ref: corrupted,
+                actual_ref: BlobStore::compute_ref(&[b'b'; 256]),
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_refs_reports_missing_referenced_blob() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let present = store.write_blob(b"present").unwrap();
+        let absent = BlobStore::compute_ref(b"never written");
+
+        let referenced: HashSet<String> = [present, absent.clone()].into_iter().collect();
+        let errors = store.verify_refs(&referenced).unwrap();
+        assert_eq!(
+            errors,
+            vec![BlobIntegrityError::Missing {
+                payload_ref: absent
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_one_checks_a_single_ref() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let intact = store.write_blob(b"intact").unwrap();
+        let corrupted = store.write_blob(b"original bytes").unwrap();
+        fs::write(store.blob_path(&corrupted), b"tampered bytes").unwrap();
+        let absent = BlobStore::compute_ref(b"never written");
+
+        assert_eq!(store.verify_one(&intact).unwrap(), None);
+        assert_eq!(
+            store.verify_one(&corrupted).unwrap(),
+            Some(BlobIntegrityError::HashMismatch {
+                payload_ref: corrupted.clone(),
+                actual_ref: BlobStore::compute_ref(b"tampered bytes"),
+            })
+        );
+        assert_eq!(
+            store.verify_one(&absent).unwrap().map(|e| e.kind()),
+            Some("missing")
+        );
+        let err = store.verify_one("../etc/passwd").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn iter_refs_and_stats_list_stored_blobs_in_sorted_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let blobs: [&[u8]; 3] = [b"first blob", b"second blob!", b"third"];
+        let mut expected: Vec<String> = blobs
+            .iter()
+            .map(|data| store.write_blob(data).unwrap())
+            .collect();
+        expected.sort();
+
+        // In-flight and foreign files are not blobs.
+        fs::write(dir.path().join("blobs").join(".incoming-1-0.tmp"), b"x").unwrap();
+        let prefix_dir = dir.path().join("blobs").join(&expected[0][..2]);
+        fs::write(prefix_dir.join(format!("{}.tmp", expected[0])), b"x").unwrap();
+        fs::write(prefix_dir.join("notes.txt"), b"x").unwrap();
+
+        assert_eq!(store.iter_refs().unwrap().collect::<Vec<_>>(), expected);
+        assert_eq!(
+            store
+                .stored_size(&BlobStore::compute_ref(b"third"))
+                .unwrap(),
+            Some(5)
+        );
+        assert_eq!(store.stored_size(&"0".repeat(64)).unwrap(), None);
+        let total_bytes = blobs.iter().map(|data| data.len() as u64).sum();
+        assert_eq!(
+            store.stats().unwrap(),
+            BlobStoreStats {
+                count: 3,
+                total_bytes,
+            }
+        );
+    }
+
+    #[test]
+    fn blob_in_both_forms_is_listed_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let data = vec![b'a'; 4096];
+        let payload_ref = store.write_blob(&data).unwrap();
+        // An interrupted migration leaves the plain and compressed forms.
+        let plain = store.blob_path(&payload_ref);
+        store.compress_into_place(&plain, &payload_ref).unwrap();
+
+        assert_eq!(
+            store.iter_refs().unwrap().collect::<Vec<_>>(),
+            vec![payload_ref.as_str()]
+        );
+        let stats = store.stats().unwrap();
+        assert_eq!(stats.count, 1);
+        let zst_len = fs::metadata(store.compressed_path(&payload_ref))
+            .unwrap()
+            .len();
+        assert_eq!(stats.total_bytes, 4096 + zst_len);
+    }
 }
@@ -0,0 +1,188 @@
+//! Canonical JSON bytes for hashing.
+//!
+//! `state_hash` and `viewmodel_hash` hash a JSON serialization, so their
+//! output must not depend on how the serialized type happens to be written.
+//! Plain `serde_json::to_vec` emits struct fields in declaration order and
+//! map entries in iteration order: reordering two fields, or a `HashMap`
+//! slipping into a hashed type, would silently change or destabilize the
+//! hash.
+//!
+//! [`canonical_json`] removes both dependencies. The encoding, versioned
+//! as [`CANONICAL_JSON_VERSION`], is specified so that another
+//! implementation can reproduce the bytes:
+//!
+//! - UTF-8 JSON with no whitespace between tokens.
+//! - Object keys sorted by their UTF-8 bytes, at every depth. Array order
+//!   is kept; it is data.
+//! - Strings escaped as `serde_json` escapes them: `"`, `\` and control
+//!   characters only, everything else written as is.
+//! - Numbers are integers in decimal. Floats are rejected, since their
+//!   formatting is not stable across implementations; hashed types store
+//!   fixed-point integers instead (see `queue_pressure_fixed`).
+//! - `null`, `true` and `false` as written.
+//!
+//! Both hashes mix the version string into their input, so a change to
+//! the encoding changes every hash rather than silently colliding. They
+//! use [`canonical_bytes`], which treats an encoding failure as a bug in
+//! the hashed type.
+
+use serde::ser::Error as _;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Version of the canonical encoding, mixed into `state_hash` and
+/// `viewmodel_hash`.
+pub const CANONICAL_JSON_VERSION: &str = "canonical-json-v1";
+
+/// Serialize `value` as canonical JSON: compact, with every object's keys
+/// in sorted order regardless of field declaration or map iteration order.
+///
+/// Fails for a float anywhere in the value, and where `serde_json` would,
+/// e.g. for a map with non-string keys.
+pub fn canonical_json<T: Serialize + ?Sized>(value: &T) -> serde_json::Result<Vec<u8>> {
+    let value = serde_json::to_value(value)?;
+    let mut out = Vec::new();
+    write_canonical(&value, &mut out)?;
+    Ok(out)
+}
+
+/// [`canonical_json`] for hashed types.
+///
+/// # Panics
+///
+/// Panics where [`canonical_json`] fails. Hashed types are built from
+/// integers, strings and string-keyed maps, so a failure is a bug in the
+/// hashed type rather than a property of the input.
+pub fn canonical_bytes<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+    canonical_json(value).unwrap_or_else(|e| panic!("canonical JSON encoding failed: {e}"))
+}
+
+fn write_canonical(value: &Value, out: &mut Vec<u8>) -> serde_json::Result<()> {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            out.push(b'{');
+            for (index, (key, item)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                serde_json::to_writer(&mut *out, key)?;
+                out.push(b':');
+                write_canonical(item, out)?;
+            }
+            out.push(b'}');
+        }
+        Value::Array(items) => {
+            out.push(b'[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(b',');
+                }
+                write_canonical(item, out)?;
+            }
+            out.push(b']');
+        }
+        Value::Number(number) if !(number.is_i64() || number.is_u64()) => {
+            return Err(serde_json::Error::custom(format!(
+                "canonical JSON allows integers only, found {number}"
+            )));
+        }
+        scalar => serde_json::to_writer(&mut *out, scalar)?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    struct Declared {
+        zeta: u32,
+        alpha: Vec<u32>,
+        nested: Inner,
+    }
+
+    #[derive(Serialize)]
+    struct Reordered {
+        nested: Inner,
+        alpha: Vec<u32>,
+        zeta: u32,
+    }
+
+    #[derive(Serialize)]
+    struct Inner {
+        b: &'static str,
+        a: Option<bool>,
+    }
+
+    #[test]
+    fn field_declaration_order_does_not_change_the_bytes() {
+        let declared = Declared {
+            zeta: 1,
+            alpha: vec![3, 2],
+            nested: Inner { b: "x", a: None },
+        };
+        let reordered = Reordered {
+            nested: Inner { b: "x", a: None },
+            alpha: vec![3, 2],
+            zeta: 1,
+        };
+        assert_eq!(
+            canonical_json(&declared).unwrap(),
+            canonical_json(&reordered).unwrap()
+        );
+    }
+
+    #[test]
+    fn map_insertion_order_does_not_change_the_bytes() {
+        let forward: HashMap<String, u32> = (0..64).map(|i| (format!("k{i}"), i)).collect();
+        let mut backward = HashMap::new();
+        for i in (0..64).rev() {
+            backward.insert(format!("k{i}"), i);
+        }
+        assert_eq!(
+            canonical_json(&forward).unwrap(),
+            canonical_json(&backward).unwrap()
+        );
+    }
+
+    #[test]
+    fn keys_are_sorted_at_every_depth() {
+        let value = json!({"zeta": 1, "alpha": [3, 2], "nested": {"b": "x", "a": null}});
+        assert_eq!(
+            String::from_utf8(canonical_bytes(&value)).unwrap(),
+            r#"{"alpha":[3,2],"nested":{"a":null,"b":"x"},"zeta":1}"#
+        );
+    }
+
+    #[test]
+    fn scalars_and_escapes_match_serde_json() {
+        let value =
+            json!({"s": "quote \" and \u{e9}", "u": u64::MAX, "n": -3, "t": true, "z": null});
+        assert_eq!(
+            String::from_utf8(canonical_bytes(&value)).unwrap(),
+            r#"{"n":-3,"s":"quote \" and é","t":true,"u":18446744073709551615,"z":null}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "integers only")]
+    fn struct_with_float_is_rejected() {
+        #[derive(Serialize)]
+        struct WithFloat {
+            ratio: f64,
+        }
+        canonical_bytes(&WithFloat { ratio: 0.5 });
+    }
+
+    #[test]
+    fn floats_are_rejected_at_any_depth() {
+        let err = canonical_json(&json!({"a": [{"b": 1.0}]})).unwrap_err();
+        assert!(err.to_string().contains("integers only"), "{err}");
+        assert!(canonical_json(&json!({"a": [{"b": 1}]})).is_ok());
+    }
+}
@@ -0,0 +1,416 @@
+//! On-disk reducer checkpoints, so replay can resume instead of starting
+//! from `commit_index` 0.
+//!
+//! A [`CheckpointStore`] is a directory of `checkpoint-<commit_index>.json`
+//! files, each a [`Checkpoint`] in the JSON form of
+//! [`serialize_checkpoint`]. The store of an EventLog lives beside it at
+//! [`checkpoint_dir`] (`eventlog.jsonl` → `eventlog.jsonl.checkpoints/`) and
+//! is opt-in: readers use it only when that directory exists. A store saves
+//! every [`CHECKPOINT_INTERVAL`] events unless opened
+//! [`with_checkpoint_interval`](CheckpointStore::with_checkpoint_interval),
+//! and each checkpoint records the interval it was saved at.
+//!
+//! Checkpoints are derived data. A checkpoint whose `reducer_version` does
+//! not match is skipped exactly as [`load_checkpoint`] discards it, and one
+//! whose `commit_index` is not in the events being replayed is ignored, so
+//! a bad store costs a longer replay but never changes the resulting State.
+//! A store describes one EventLog's history: delete it when the EventLog is
+//! replaced rather than appended to.
+
+use std::fs;
+use std::io;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
+use crate::event::CommittedEvent;
+use crate::eventlog::read_eventlog;
+use crate::reducer::{
+    create_checkpoint_with_interval, load_checkpoint, replay_from_with_progress,
+    serialize_checkpoint, validate_checkpoint_interval, Checkpoint, ReplayOutcome, ReplayProgress,
+    State, CHECKPOINT_INTERVAL,
+};
+
+/// Checkpoints kept by [`replay_with_checkpoints`] after it saves one.
+pub const DEFAULT_CHECKPOINT_KEEP: usize = 3;
+
+const FILE_PREFIX: &str = "checkpoint-";
+const FILE_SUFFIX: &str = ".json";
+
+/// Checkpoint directory of `eventlog` (`eventlog.jsonl` →
+/// `eventlog.jsonl.checkpoints`).
+pub fn checkpoint_dir(eventlog: &Path) -> PathBuf {
+    let mut name = eventlog.as_os_str().to_owned();
+    name.push(".checkpoints");
+    PathBuf::from(name)
+}
+
+/// Directory of reducer checkpoints named by `commit_index`.
+#[derive(Debug, Clone)]
+pub struct CheckpointStore {
+    dir: PathBuf,
+    checkpoint_interval: u64,
+}
+
+impl CheckpointStore {
+    /// Open the store at `dir`, creating the directory if needed. The store
+    /// checkpoints every [`CHECKPOINT_INTERVAL`] events.
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(CheckpointStore {
+            dir,
+            checkpoint_interval: CHECKPOINT_INTERVAL,
+        })
+    }
+
+    /// Checkpoint every `interval` events instead. Fails with
+    /// `InvalidInput` for 0.
+    pub fn with_checkpoint_interval(mut self, interval: u64) -> io::Result<Self> {
+        self.checkpoint_interval = validate_checkpoint_interval(interval)?;
+        Ok(self)
+    }
+
+    /// Directory of this store.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Events reduced past the last checkpoint before a new one is saved.
+    pub fn checkpoint_interval(&self) -> u64 {
+        self.checkpoint_interval
+    }
+
+    fn path_for(&self, commit_index: u64) -> PathBuf {
+        self.dir
+            .join(format!("{FILE_PREFIX}{commit_index}{FILE_SUFFIX}"))
+    }
+
+    /// Atomically write `checkpoint` as `checkpoint-<commit_index>.json`,
+    /// replacing any checkpoint at the same index.
+    pub fn save(&self, checkpoint: &Checkpoint) -> io::Result<PathBuf> {
+        let bytes = serialize_checkpoint(checkpoint)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let path = self.path_for(checkpoint.commit_index);
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, bytes)?;
+        fs::rename(tmp, &path)?;
+        Ok(path)
+    }
+
+    /// `commit_index` of every checkpoint file, ascending. Other files are
+    /// ignored.
+    pub fn commit_indices(&self) -> io::Result<Vec<u64>> {
+        let mut indices = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let name = entry?.file_name();
+            let index = name
+                .to_str()
+                .and_then(|n| n.strip_prefix(FILE_PREFIX))
+                .and_then(|n| n.strip_suffix(FILE_SUFFIX))
+                .and_then(|n| n.parse::<u64>().ok());
+            indices.extend(index);
+        }
+        indices.sort_unstable();
+        Ok(indices)
+    }
+
+    /// Newest valid checkpoint with `commit_index` at or before
+    /// `commit_index`.
+    ///
+    /// Files that do not parse, carry another `reducer_version`, or whose
+    /// contents disagree with their name are skipped in favour of older
+    /// ones.
+    pub fn latest_before(&self, commit_index: u64) -> io::Result<Option<Checkpoint>> {
+        for index in self.commit_indices()?.into_iter().rev() {
+            if index > commit_index {
+                continue;
+            }
+            let bytes = match fs::read(self.path_for(index)) {
+                Ok(bytes) => bytes,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e),
+            };
+            if let Some(checkpoint) = load_checkpoint(&bytes) {
+                if checkpoint.commit_index == index {
+                    return Ok(Some(checkpoint));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Delete all but the newest `keep_last` checkpoints. Returns how many
+    /// were deleted.
+    pub fn prune(&self, keep_last: usize) -> io::Result<usize> {
+        let indices = self.commit_indices()?;
+        let stale = indices.len().saturating_sub(keep_last);
+        for index in &indices[..stale] {
+            fs::remove_file(self.path_for(*index))?;
+        }
+        Ok(stale)
+    }
+}
+
+/// Reduce `events` (in `commit_index` order), resuming from the newest
+/// usable checkpoint in `store`.
+///
+/// Produces the same State as [`replay`](crate::reducer::replay) over all of
+/// `events`. When the events run at least the store's
+/// [`checkpoint_interval`](CheckpointStore::checkpoint_interval) past the
+/// checkpoint used (or past the start, without one), the final State is
+/// saved as a new checkpoint and the store is pruned to
+/// [`DEFAULT_CHECKPOINT_KEEP`]; errors while saving (e.g. a read-only
+/// directory) are ignored.
+pub fn replay_with_checkpoints(events: &[CommittedEvent], store: &CheckpointStore) -> State {
+    replay_with_checkpoints_progress(events, store, |_| ControlFlow::Continue(())).state
+}
+
+/// [`replay_with_checkpoints`] that calls `progress` after every event
+/// reduced, and stops when it returns `ControlFlow::Break`.
+///
+/// Positions count the events covered by the checkpoint resumed from, so
+/// `events_processed` is always a prefix length of `events` and `total` is
+/// `events.len()`. A cancelled replay saves no checkpoint.
+pub fn replay_with_checkpoints_progress(
+    events: &[CommittedEvent],
+    store: &CheckpointStore,
+    mut progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+) -> ReplayOutcome {
+    let Some(last) = events.last() else {
+        return ReplayOutcome {
+            state: State::new(),
+            checkpoint_indices: Vec::new(),
+            events_processed: 0,
+            cancelled_at: None,
+        };
+    };
+    let resume = store
+        .latest_before(last.commit_index)
+        .ok()
+        .flatten()
+        .and_then(|checkpoint| {
+            let position = events
+                .iter()
+                .rposition(|e| e.commit_index == checkpoint.commit_index)?;
+            Some((checkpoint.state, position + 1))
+        });
+    let (initial, start) = resume.unwrap_or_else(|| (State::new(), 0));
+    let skipped = start as u64;
+    let total = events.len() as u64;
+    let mut outcome = replay_from_with_progress(
+        initial,
+        &events[start..],
+        store.checkpoint_interval,
+        |position| {
+            progress(ReplayProgress {
+                events_processed: skipped + position.events_processed,
+                total: Some(total),
+                commit_index: position.commit_index,
+            })
+        },
+    );
+    outcome.events_processed += skipped;
+
+    if !outcome.is_cancelled() && (events.len() - start) as u64 >= store.checkpoint_interval {
+        let _ = store
+            .save(&create_checkpoint_with_interval(
+                &outcome.state,
+                store.checkpoint_interval,
+            ))
+            .and_then(|_| store.prune(DEFAULT_CHECKPOINT_KEEP));
+    }
+    outcome
+}
+
+/// Read `eventlog_path` and reduce it with [`replay_with_checkpoints`].
+///
+/// Returns the State and every event of the log.
+pub fn load_state_with_checkpoints(
+    eventlog_path: &Path,
+    store: &CheckpointStore,
+) -> io::Result<(State, Vec<CommittedEvent>)> {
+    let events = read_eventlog(eventlog_path)?;
+    let state = replay_with_checkpoints(&events, store);
+    Ok((state, events))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{EventPayload, ImportEvent, Tier};
+    use crate::eventlog::EventLogWriter;
+    use crate::reducer::{create_checkpoint, replay, state_hash};
+
+    fn tool_call(i: u64) -> ImportEvent {
+        ImportEvent {
+            run_id: "run-1".into(),
+            event_id: format!("e-{i}"),
+            source_id: "test".into(),
+            source_seq: Some(i),
+            timestamp_ns: 1_000_000_000 + i * 1_000_000,
+            tier: Tier::A,
+            payload: EventPayload::ToolCall {
+                tool: format!("tool-{}", i % 7),
+                args: None,
+                invocation_id: None,
+            },
+            payload_ref: None,
+            synthesized: false,
+        }
+    }
+
+    fn write_eventlog(path: &Path, range: std::ops::Range<u64>) {
+        let mut writer = EventLogWriter::open(path).unwrap();
+        writer.append_batch(range.map(tool_call).collect()).unwrap();
+    }
+
+    #[test]
+    fn checkpoint_dir_appends_checkpoints() {
+        assert_eq!(
+            checkpoint_dir(Path::new("run/eventlog.jsonl")),
+            PathBuf::from("run/eventlog.jsonl.checkpoints")
+        );
+    }
+
+    #[test]
+    fn save_latest_before_and_prune() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CheckpointStore::open(dir.path().join("cp")).unwrap();
+        for index in [4999, 9999, 14999] {
+            let mut state = State::new();
+            state.last_commit_index = index;
+            let path = store.save(&create_checkpoint(&state)).unwrap();
+            assert!(path.ends_with(format!("checkpoint-{index}.json")));
+        }
+        fs::write(store.dir().join("notes.txt"), "ignored").unwrap();
+
+        assert_eq!(store.commit_indices().unwrap(), vec![4999, 9999, 14999]);
+        assert_eq!(
+            store.latest_before(12_000).unwrap().unwrap().commit_index,
+            9999
+        );
+        assert_eq!(
+            store.latest_before(14_999).unwrap().unwrap().commit_index,
+            14999
+        );
+        assert!(store.latest_before(100).unwrap().is_none());
+
+        assert_eq!(store.prune(1).unwrap(), 2);
+        assert_eq!(store.commit_indices().unwrap(), vec![14999]);
+        assert_eq!(store.prune(5).unwrap(), 0);
+    }
+
+    #[test]
+    fn latest_before_skips_stale_and_corrupt_checkpoints() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CheckpointStore::open(dir.path()).unwrap();
+        let mut state = State::new();
+        state.last_commit_index = 10;
+        store.save(&create_checkpoint(&state)).unwrap();
+
+        let stale = Checkpoint {
+            reducer_version: "reducer-v0.0-stale".into(),
+            commit_index: 20,
+            checkpoint_interval: CHECKPOINT_INTERVAL,
+            state: State::new(),
+        };
+        store.save(&stale).unwrap();
+        fs::write(dir.path().join("checkpoint-30.json"), "not json").unwrap();
+
+        assert_eq!(store.latest_before(40).unwrap().unwrap().commit_index, 10);
+    }
+
+    #[test]
+    fn load_state_with_checkpoints_matches_full_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let eventlog = dir.path().join("eventlog.jsonl");
+        write_eventlog(&eventlog, 0..6000);
+        let store = CheckpointStore::open(checkpoint_dir(&eventlog)).unwrap();
+
+        // First load replays from zero and saves a checkpoint at the end.
+        let (state, events) = load_state_with_checkpoints(&eventlog, &store).unwrap();
+        assert_eq!(events.len(), 6000);
+        assert_eq!(store.commit_indices().unwrap(), vec![5999]);
+
+        // Grow the log; the second load resumes from that checkpoint.
+        drop(events);
+        write_eventlog(&eventlog, 6000..6500);
+        let (resumed, events) = load_state_with_checkpoints(&eventlog, &store).unwrap();
+        let (full, _) = replay(&events);
+        assert_eq!(resumed, full);
+        assert_eq!(state_hash(&resumed), state_hash(&full));
+        assert_ne!(state_hash(&resumed), state_hash(&state));
+        // Only 500 events past the checkpoint: nothing new is saved.
+        assert_eq!(store.commit_indices().unwrap(), vec![5999]);
+    }
+
+    #[test]
+    fn custom_interval_saves_sooner_and_is_recorded() {
+        let dir = tempfile::tempdir().unwrap();
+        let eventlog = dir.path().join("eventlog.jsonl");
+        write_eventlog(&eventlog, 0..150);
+        let store = CheckpointStore::open(checkpoint_dir(&eventlog))
+            .unwrap()
+            .with_checkpoint_interval(100)
+            .unwrap();
+        assert_eq!(store.checkpoint_interval(), 100);
+
+        load_state_with_checkpoints(&eventlog, &store).unwrap();
+        let checkpoint = store.latest_before(u64::MAX).unwrap().unwrap();
+        assert_eq!(checkpoint.commit_index, 149);
+        assert_eq!(checkpoint.checkpoint_interval, 100);
+
+        let err = CheckpointStore::open(dir.path())
+            .unwrap()
+            .with_checkpoint_interval(0)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn cancelled_replay_counts_resumed_events_and_saves_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CheckpointStore::open(dir.path())
+            .unwrap()
+            .with_checkpoint_interval(100)
+            .unwrap();
+        let events: Vec<_> = (0..300)
+            .map(|i| CommittedEvent::commit(tool_call(i), i))
+            .collect();
+        let (prefix, _) = replay(&events[..100]);
+        store.save(&create_checkpoint(&prefix)).unwrap();
+
+        let mut first = None;
+        let outcome = replay_with_checkpoints_progress(&events, &store, |p| {
+            first.get_or_insert(p);
+            if p.events_processed == 150 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        let first = first.unwrap();
+        assert_eq!((first.events_processed, first.total), (101, Some(300)));
+        assert_eq!(outcome.cancelled_at, Some(149));
+        assert_eq!(outcome.events_processed, 150);
+        assert_eq!(outcome.state, replay(&events[..150]).0);
+        assert_eq!(store.commit_indices().unwrap(), vec![99]);
+    }
+
+    #[test]
+    fn checkpoint_outside_the_events_is_ignored() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = CheckpointStore::open(dir.path()).unwrap();
+        let mut bogus = State::new();
+        bogus.last_commit_index = 3;
+        bogus.tier_a_count = 999;
+        store.save(&create_checkpoint(&bogus)).unwrap();
+
+        let events: Vec<_> = (5..10)
+            .map(|i| CommittedEvent::commit(tool_call(i), i))
+            .collect();
+        let (full, _) = replay(&events);
+        assert_eq!(replay_with_checkpoints(&events, &store), full);
+    }
+}
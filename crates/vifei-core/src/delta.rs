@@ -2,12 +2,42 @@
 //!
 //! Compares two committed event streams by canonical `commit_index` and emits
 //! stable divergence records keyed by `(commit_index, path, change_class)`.
+//!
+//! That positional matching is the default [`DiffAlignment`] and suits replay
+//! checks, where both sides must agree index for index. Two different runs
+//! rarely line up that way: one extra event near the start shifts every
+//! later index. [`DiffAlignment::ById`] matches events by `event_id` instead
+//! and reports them as added, removed, moved, or changed.
+//!
+//! Each divergence carries a [`DivergenceSeverity`]. Anything that touches a
+//! Tier A event, or the `tier`/`synthesized` honesty fields of any event, is
+//! `critical`: the two runs disagree about truth. A `timestamp_ns` change, a
+//! by-id move (same events, different order) and differences confined to
+//! Tier B/C events are `minor`. The delta's [`DeltaSummary`] counts
+//! divergences per severity and change class, so a delta of thousands of
+//! divergences can be read at a glance.
+//!
+//! [`DiffOptions::ignore_paths`] leaves fields out of the comparison, for
+//! fields expected to differ such as `synthesized` after a cassette
+//! re-import. The events are unchanged; only divergences at or under an
+//! ignored path are dropped, and the applied paths are recorded in the
+//! [`RunDelta`].
+//!
+//! [`crate::delta_render`] turns divergences into unified-diff snippets of
+//! the events involved, for `compare --detail diff`.
+//!
+//! [`diff_states`] compares two reduced [`State`]s the same way, field by
+//! field, so a replay disagreement can be traced to the part of State that
+//! diverged.
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
-use crate::event::CommittedEvent;
+use crate::delta_render::RenderedDivergence;
+use crate::event::{CommittedEvent, Tier};
+use crate::reducer::State;
 
 /// Change classification for a divergence record.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -18,6 +48,75 @@ pub enum ChangeClass {
     EventMissingRight,
     /// Field/value mismatch at the same canonical path.
     ValueMismatch,
+    /// By-id: event exists only on the right side.
+    Added,
+    /// By-id: event exists only on the left side.
+    Removed,
+    /// By-id: same event at a different `commit_index`.
+    Moved,
+    /// By-id: same event with a differing field.
+    Changed,
+}
+
+impl fmt::Display for ChangeClass {
+    /// The serde name, so text and HTML reports match `delta.json`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ChangeClass::EventMissingLeft => "EventMissingLeft",
+            ChangeClass::EventMissingRight => "EventMissingRight",
+            ChangeClass::ValueMismatch => "ValueMismatch",
+            ChangeClass::Added => "Added",
+            ChangeClass::Removed => "Removed",
+            ChangeClass::Moved => "Moved",
+            ChangeClass::Changed => "Changed",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How [`diff_runs_aligned`] pairs left events with right events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiffAlignment {
+    /// Pair events at the same `commit_index`. Strict; for replays.
+    #[default]
+    Positional,
+    /// Pair events with the same `event_id`, or the same
+    /// `(source_id, source_seq)` when `event_id` is empty. For different
+    /// runs of the same workload.
+    ById,
+}
+
+impl fmt::Display for DiffAlignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffAlignment::Positional => write!(f, "positional"),
+            DiffAlignment::ById => write!(f, "by-id"),
+        }
+    }
+}
+
+/// Whether a divergence breaks truth.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum DivergenceSeverity {
+    /// Timing, ordering, or Tier B/C detail; both runs still tell the
+    /// same story.
+    #[default]
+    Minor,
+    /// Tier A content or an honesty field differs.
+    Critical,
+}
+
+impl fmt::Display for DivergenceSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivergenceSeverity::Minor => write!(f, "minor"),
+            DivergenceSeverity::Critical => write!(f, "critical"),
+        }
+    }
 }
 
 /// One deterministic divergence keyed by `commit_index` and path.
@@ -26,6 +125,8 @@ pub struct Divergence {
     pub commit_index: u64,
     pub path: String,
     pub change_class: ChangeClass,
+    #[serde(default)]
+    pub severity: DivergenceSeverity,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
     pub left_value: Option<String>,
@@ -41,7 +142,146 @@ pub struct RunDelta {
     pub right_run_id: String,
     pub left_event_count: usize,
     pub right_event_count: usize,
+    /// How events were paired.
+    #[serde(default)]
+    pub alignment: DiffAlignment,
+    /// Paths left out of the comparison, normalized and sorted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore_paths: Vec<String>,
+    /// Counts over `divergences`.
+    #[serde(default)]
+    pub summary: DeltaSummary,
     pub divergences: Vec<Divergence>,
+    /// Diff snippets of the divergences, when rendered (see
+    /// [`render_divergences`](crate::delta_render::render_divergences)).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rendered: Vec<RenderedDivergence>,
+}
+
+/// Divergence counts of a [`RunDelta`]. Keys with no divergences are
+/// omitted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeltaSummary {
+    pub by_severity: BTreeMap<DivergenceSeverity, usize>,
+    pub by_change_class: BTreeMap<ChangeClass, usize>,
+}
+
+impl DeltaSummary {
+    /// Count `divergences`.
+    pub fn of(divergences: &[Divergence]) -> Self {
+        let mut summary = DeltaSummary::default();
+        for divergence in divergences {
+            *summary.by_severity.entry(divergence.severity).or_default() += 1;
+            *summary
+                .by_change_class
+                .entry(divergence.change_class)
+                .or_default() += 1;
+        }
+        summary
+    }
+}
+
+/// Options for [`diff_runs_with`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffOptions {
+    /// How events are paired.
+    pub alignment: DiffAlignment,
+    /// Dot-paths into an event (`timestamp_ns`, `payload.args`) whose
+    /// divergences are dropped, along with those of everything under them.
+    /// A leading `$.` is accepted. Whole-event additions, removals and
+    /// moves are never dropped, except a move through `commit_index`.
+    pub ignore_paths: Vec<String>,
+}
+
+impl DiffOptions {
+    /// Positional alignment, nothing ignored.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pair events per `alignment`.
+    pub fn with_alignment(mut self, alignment: DiffAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Ignore divergences at or under each of `paths`.
+    pub fn with_ignore_paths(mut self, paths: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.ignore_paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// `ignore_paths` without a leading `$.`, sorted and deduplicated.
+    fn normalized_ignore_paths(&self) -> Vec<String> {
+        let paths: BTreeSet<String> = self
+            .ignore_paths
+            .iter()
+            .map(|path| path.strip_prefix("$.").unwrap_or(path).to_string())
+            .filter(|path| !path.is_empty())
+            .collect();
+        paths.into_iter().collect()
+    }
+}
+
+/// Whether the divergence at `path` (`$.payload.args[0]`) falls at or
+/// under one of the normalized `ignore_paths`.
+fn is_ignored(path: &str, ignore_paths: &[String]) -> bool {
+    let Some(path) = path.strip_prefix("$.") else {
+        return false;
+    };
+    ignore_paths.iter().any(|ignored| {
+        path.strip_prefix(ignored.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(['.', '[']))
+    })
+}
+
+impl RunDelta {
+    /// Most severe divergence, or `None` when the runs agree.
+    pub fn severity(&self) -> Option<DivergenceSeverity> {
+        self.divergences.iter().map(|d| d.severity).max()
+    }
+
+    /// Number of divergences classified `critical`.
+    pub fn critical_count(&self) -> usize {
+        self.divergences
+            .iter()
+            .filter(|d| d.severity == DivergenceSeverity::Critical)
+            .count()
+    }
+
+    /// The first `n` divergences, most severe first and otherwise in delta
+    /// order.
+    pub fn most_severe(&self, n: usize) -> Vec<&Divergence> {
+        let mut divergences: Vec<&Divergence> = self.divergences.iter().collect();
+        divergences.sort_by_key(|d| std::cmp::Reverse(d.severity));
+        divergences.truncate(n);
+        divergences
+    }
+}
+
+/// One differing leaf between two reducer States.
+///
+/// `path` is a JSON path into the serialized State, such as
+/// `$.tool_summaries.grep.error_count` or `$.error_log[2].message`. A value
+/// is `None` when that side has no entry at the path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDivergence {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub left_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub right_value: Option<String>,
+}
+
+/// Deterministic structural delta between two reducer States.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDelta {
+    pub left_state_hash: String,
+    pub right_state_hash: String,
+    /// Differing leaves, sorted by `path`.
+    pub divergences: Vec<StateDivergence>,
 }
 
 /// Compute a deterministic delta over two committed streams.
@@ -50,7 +290,41 @@ pub struct RunDelta {
 /// - events are matched by canonical `commit_index` only.
 /// - order of output divergences is deterministic by construction.
 /// - input order does not matter; all access is via `BTreeMap` keyed by index.
+/// - severity depends only on the path and the tiers of the two events.
 pub fn diff_runs(left: &[CommittedEvent], right: &[CommittedEvent]) -> RunDelta {
+    diff_runs_aligned(left, right, DiffAlignment::Positional)
+}
+
+/// Compute a deterministic delta, pairing events per `alignment`.
+///
+/// With [`DiffAlignment::ById`]:
+/// - an event whose key appears on one side only is `Added` or `Removed`;
+/// - a pair whose order relative to the other pairs changed adds one
+///   `Moved` divergence at path `$.commit_index` (a pair only shifted by
+///   events added or removed before it is not moved);
+/// - each differing field of a pair is `Changed`, except `run_id` (reported
+///   once as `left_run_id`/`right_run_id`) and the positional `source_seq`;
+/// - a key repeated on one side pairs in `commit_index` order;
+/// - `commit_index` is the left event's for pairs and removals and the
+///   right event's for additions, and output is sorted by it, then path.
+pub fn diff_runs_aligned(
+    left: &[CommittedEvent],
+    right: &[CommittedEvent],
+    alignment: DiffAlignment,
+) -> RunDelta {
+    diff_runs_with(left, right, &DiffOptions::new().with_alignment(alignment))
+}
+
+/// Compute a deterministic delta per `options`: pair events as
+/// [`diff_runs_aligned`] does, then drop divergences under
+/// [`DiffOptions::ignore_paths`].
+pub fn diff_runs_with(
+    left: &[CommittedEvent],
+    right: &[CommittedEvent],
+    options: &DiffOptions,
+) -> RunDelta {
+    let alignment = options.alignment;
+    let ignore_paths = options.normalized_ignore_paths();
     let left_by_index = index_events_by_commit_index(left);
     let right_by_index = index_events_by_commit_index(right);
     let left_run_id = left_by_index
@@ -64,6 +338,57 @@ pub fn diff_runs(left: &[CommittedEvent], right: &[CommittedEvent]) -> RunDelta
         .map(|(_, e)| e.run_id.clone())
         .unwrap_or_default();
 
+    let mut divergences = match alignment {
+        DiffAlignment::Positional => diff_positional(&left_by_index, &right_by_index),
+        DiffAlignment::ById => diff_by_id(left, right),
+    };
+    divergences.retain(|d| !is_ignored(&d.path, &ignore_paths));
+
+    RunDelta {
+        left_run_id,
+        right_run_id,
+        left_event_count: left.len(),
+        right_event_count: right.len(),
+        alignment,
+        ignore_paths,
+        summary: DeltaSummary::of(&divergences),
+        divergences,
+        rendered: Vec::new(),
+    }
+}
+
+/// Compare two States leaf by leaf.
+///
+/// Both States are flattened to JSON paths, so map entries are compared by
+/// key and list entries by position. Fields omitted from serialization
+/// while empty (such as `notes`) show up as entries present on one side.
+pub fn diff_states(left: &State, right: &State) -> StateDelta {
+    let left_flat = flatten_json("$", &state_value(left));
+    let right_flat = flatten_json("$", &state_value(right));
+    let paths: BTreeSet<&String> = left_flat.keys().chain(right_flat.keys()).collect();
+    let divergences = paths
+        .into_iter()
+        .filter_map(|path| {
+            let l = left_flat.get(path).cloned();
+            let r = right_flat.get(path).cloned();
+            (l != r).then(|| StateDivergence {
+                path: path.clone(),
+                left_value: l,
+                right_value: r,
+            })
+        })
+        .collect();
+    StateDelta {
+        left_state_hash: crate::reducer::state_hash(left),
+        right_state_hash: crate::reducer::state_hash(right),
+        divergences,
+    }
+}
+
+fn diff_positional(
+    left_by_index: &BTreeMap<u64, &CommittedEvent>,
+    right_by_index: &BTreeMap<u64, &CommittedEvent>,
+) -> Vec<Divergence> {
     let all_indices: BTreeSet<u64> = left_by_index
         .keys()
         .chain(right_by_index.keys())
@@ -76,11 +401,13 @@ pub fn diff_runs(left: &[CommittedEvent], right: &[CommittedEvent]) -> RunDelta
         let left_event = left_by_index.get(&commit_index).copied();
         let right_event = right_by_index.get(&commit_index).copied();
 
+        let start = divergences.len();
         match (left_event, right_event) {
             (None, Some(_)) => divergences.push(Divergence {
                 commit_index,
                 path: "$event".to_string(),
                 change_class: ChangeClass::EventMissingLeft,
+                severity: DivergenceSeverity::Minor,
                 left_value: None,
                 right_value: Some("present".to_string()),
             }),
@@ -88,24 +415,199 @@ pub fn diff_runs(left: &[CommittedEvent], right: &[CommittedEvent]) -> RunDelta
                 commit_index,
                 path: "$event".to_string(),
                 change_class: ChangeClass::EventMissingRight,
+                severity: DivergenceSeverity::Minor,
                 left_value: Some("present".to_string()),
                 right_value: None,
             }),
             (Some(l), Some(r)) => compare_event(commit_index, l, r, &mut divergences),
             (None, None) => {}
         }
+        let tier_a = [left_event, right_event]
+            .into_iter()
+            .flatten()
+            .any(|e| e.tier == Tier::A);
+        for divergence in &mut divergences[start..] {
+            divergence.severity = classify_divergence(&divergence.path, tier_a);
+        }
     }
+    divergences
+}
 
-    RunDelta {
-        left_run_id,
-        right_run_id,
-        left_event_count: left.len(),
-        right_event_count: right.len(),
-        divergences,
+/// Fields a by-id pair is not compared on: `run_id` differs between runs by
+/// design, and `source_seq` is a position like `commit_index`, which the
+/// move detection already covers.
+const BY_ID_UNCOMPARED_PATHS: [&str; 2] = ["$.run_id", "$.source_seq"];
+
+/// Identity of an event for [`DiffAlignment::ById`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum AlignKey<'a> {
+    EventId(&'a str),
+    SourceSeq(&'a str, u64),
+    /// Neither identity is available: fall back to the position.
+    CommitIndex(u64),
+}
+
+fn align_key(event: &CommittedEvent) -> AlignKey<'_> {
+    if !event.event_id.is_empty() {
+        AlignKey::EventId(&event.event_id)
+    } else if let Some(seq) = event.source_seq {
+        AlignKey::SourceSeq(&event.source_id, seq)
+    } else {
+        AlignKey::CommitIndex(event.commit_index)
     }
 }
 
-fn index_events_by_commit_index(events: &[CommittedEvent]) -> BTreeMap<u64, &CommittedEvent> {
+/// Events keyed by `(align_key, occurrence)`, occurrences counted in
+/// `commit_index` order so repeated keys pair up deterministically.
+pub(crate) fn index_events_by_align_key(
+    events: &[CommittedEvent],
+) -> BTreeMap<(AlignKey<'_>, usize), &CommittedEvent> {
+    let mut ordered: Vec<&CommittedEvent> = events.iter().collect();
+    ordered.sort_by_cached_key(|e| (e.commit_index, event_stable_tiebreak_key(e)));
+    let mut occurrences: BTreeMap<AlignKey<'_>, usize> = BTreeMap::new();
+    let mut out = BTreeMap::new();
+    for event in ordered {
+        let count = occurrences.entry(align_key(event)).or_insert(0);
+        out.insert((align_key(event), *count), event);
+        *count += 1;
+    }
+    out
+}
+
+fn diff_by_id(left: &[CommittedEvent], right: &[CommittedEvent]) -> Vec<Divergence> {
+    let left_by_key = index_events_by_align_key(left);
+    let right_by_key = index_events_by_align_key(right);
+    let keys: BTreeSet<&(AlignKey<'_>, usize)> =
+        left_by_key.keys().chain(right_by_key.keys()).collect();
+    let pairs: Vec<(Option<&CommittedEvent>, Option<&CommittedEvent>)> = keys
+        .into_iter()
+        .map(|key| {
+            (
+                left_by_key.get(key).copied(),
+                right_by_key.get(key).copied(),
+            )
+        })
+        .collect();
+    let moved = moved_pairs(&pairs);
+
+    let mut divergences = Vec::new();
+    for (index, &(left_event, right_event)) in pairs.iter().enumerate() {
+        let start = divergences.len();
+        match (left_event, right_event) {
+            (Some(l), None) => divergences.push(Divergence {
+                commit_index: l.commit_index,
+                path: "$event".to_string(),
+                change_class: ChangeClass::Removed,
+                severity: DivergenceSeverity::Minor,
+                left_value: Some(l.event_id.clone()),
+                right_value: None,
+            }),
+            (None, Some(r)) => divergences.push(Divergence {
+                commit_index: r.commit_index,
+                path: "$event".to_string(),
+                change_class: ChangeClass::Added,
+                severity: DivergenceSeverity::Minor,
+                left_value: None,
+                right_value: Some(r.event_id.clone()),
+            }),
+            (Some(l), Some(r)) => {
+                if moved.contains(&index) {
+                    divergences.push(Divergence {
+                        commit_index: l.commit_index,
+                        path: "$.commit_index".to_string(),
+                        change_class: ChangeClass::Moved,
+                        severity: DivergenceSeverity::Minor,
+                        left_value: Some(l.commit_index.to_string()),
+                        right_value: Some(r.commit_index.to_string()),
+                    });
+                }
+                let fields_start = divergences.len();
+                compare_event(l.commit_index, l, r, &mut divergences);
+                let mut fields = divergences.split_off(fields_start);
+                fields.retain(|d| !BY_ID_UNCOMPARED_PATHS.contains(&d.path.as_str()));
+                for field in &mut fields {
+                    field.change_class = ChangeClass::Changed;
+                }
+                divergences.extend(fields);
+            }
+            (None, None) => {}
+        }
+        let tier_a = [left_event, right_event]
+            .into_iter()
+            .flatten()
+            .any(|e| e.tier == Tier::A);
+        for divergence in &mut divergences[start..] {
+            divergence.severity = classify_divergence(&divergence.path, tier_a);
+        }
+    }
+    divergences.sort_by(|a, b| (a.commit_index, &a.path).cmp(&(b.commit_index, &b.path)));
+    divergences
+}
+
+/// Indices into `pairs` of the matched pairs whose relative order differs
+/// between the sides.
+///
+/// A shift in absolute `commit_index` (one side has an extra event earlier)
+/// is not a move. The pairs, in left order, whose right indices form the
+/// longest increasing run kept their order; every other pair moved.
+fn moved_pairs(pairs: &[(Option<&CommittedEvent>, Option<&CommittedEvent>)]) -> BTreeSet<usize> {
+    let mut matched: Vec<(u64, u64, usize)> = pairs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, pair)| match pair {
+            (Some(l), Some(r)) => Some((l.commit_index, r.commit_index, index)),
+            _ => None,
+        })
+        .collect();
+    matched.sort_unstable();
+
+    // Patience sorting: tails[k] is the position in `matched` ending the
+    // best increasing run of length k + 1 found so far.
+    let mut tails: Vec<usize> = Vec::new();
+    let mut predecessor: Vec<Option<usize>> = vec![None; matched.len()];
+    for (position, &(_, right_index, _)) in matched.iter().enumerate() {
+        let k = tails.partition_point(|&t| matched[t].1 < right_index);
+        predecessor[position] = k.checked_sub(1).map(|p| tails[p]);
+        if k == tails.len() {
+            tails.push(position);
+        } else {
+            tails[k] = position;
+        }
+    }
+    let mut in_order = vec![false; matched.len()];
+    let mut cursor = tails.last().copied();
+    while let Some(position) = cursor {
+        in_order[position] = true;
+        cursor = predecessor[position];
+    }
+
+    matched
+        .iter()
+        .zip(in_order)
+        .filter(|(_, kept)| !kept)
+        .map(|(&(_, _, index), _)| index)
+        .collect()
+}
+
+/// Severity of a divergence at `path` between events of which at least one
+/// is Tier A when `tier_a` is set.
+fn classify_divergence(path: &str, tier_a: bool) -> DivergenceSeverity {
+    match path {
+        "$.timestamp_ns" | "$.commit_index" => DivergenceSeverity::Minor,
+        "$.tier" | "$.synthesized" => DivergenceSeverity::Critical,
+        _ if tier_a => DivergenceSeverity::Critical,
+        _ => DivergenceSeverity::Minor,
+    }
+}
+
+fn state_value(state: &State) -> serde_json::Value {
+    // Same reasoning as `state_hash`: State holds only primitives and maps.
+    serde_json::to_value(state).expect("State serialization should never fail")
+}
+
+pub(crate) fn index_events_by_commit_index(
+    events: &[CommittedEvent],
+) -> BTreeMap<u64, &CommittedEvent> {
     let mut out: BTreeMap<u64, &CommittedEvent> = BTreeMap::new();
     for event in events {
         match out.get(&event.commit_index) {
@@ -219,6 +721,7 @@ fn compare_event(
                 commit_index,
                 path: key,
                 change_class: ChangeClass::ValueMismatch,
+                severity: DivergenceSeverity::Minor,
                 left_value: l,
                 right_value: r,
             });
@@ -240,6 +743,7 @@ fn compare_scalar<T: ToString>(
             commit_index,
             path: path.to_string(),
             change_class: ChangeClass::ValueMismatch,
+            severity: DivergenceSeverity::Minor,
             left_value: Some(l),
             right_value: Some(r),
         });
@@ -260,13 +764,15 @@ fn compare_scalar_opt<T: ToString>(
             commit_index,
             path: path.to_string(),
             change_class: ChangeClass::ValueMismatch,
+            severity: DivergenceSeverity::Minor,
             left_value: l,
             right_value: r,
         });
     }
 }
 
-fn flatten_json(path: &str, value: &serde_json::Value) -> BTreeMap<String, String> {
+/// Leaves of `value` keyed by JSON path under `path`, in path order.
+pub(crate) fn flatten_json(path: &str, value: &serde_json::Value) -> BTreeMap<String, String> {
     let mut out = BTreeMap::new();
     flatten_json_inner(path, value, &mut out);
     out
@@ -332,6 +838,7 @@ mod tests {
                     tool: "search".to_string(),
                     result: Some("ok".to_string()),
                     status: Some("success".to_string()),
+                    invocation_id: None,
                 },
             ),
         ];
@@ -382,6 +889,7 @@ mod tests {
             EventPayload::ToolCall {
                 tool: "search".to_string(),
                 args: Some("{\"q\":\"left\"}".to_string()),
+                invocation_id: None,
             },
         )];
         let right = vec![committed(
@@ -389,6 +897,7 @@ mod tests {
             EventPayload::ToolCall {
                 tool: "search".to_string(),
                 args: Some("{\"q\":\"right\"}".to_string()),
+                invocation_id: None,
             },
         )];
         let delta = diff_runs(&left, &right);
@@ -400,6 +909,296 @@ mod tests {
         );
     }
 
+    fn tool_result(commit_index: u64, result: &str) -> CommittedEvent {
+        committed(
+            commit_index,
+            EventPayload::ToolResult {
+                tool: "search".to_string(),
+                result: Some(result.to_string()),
+                status: Some("success".to_string()),
+                invocation_id: None,
+            },
+        )
+    }
+
+    #[test]
+    fn timestamp_only_diff_is_minor() {
+        let left = vec![tool_result(0, "ok")];
+        let mut right = left.clone();
+        right[0].timestamp_ns += 500;
+        let delta = diff_runs(&left, &right);
+        assert_eq!(delta.divergences.len(), 1);
+        assert_eq!(delta.divergences[0].path, "$.timestamp_ns");
+        assert_eq!(delta.divergences[0].severity, DivergenceSeverity::Minor);
+        assert_eq!(delta.severity(), Some(DivergenceSeverity::Minor));
+        assert_eq!(delta.critical_count(), 0);
+    }
+
+    #[test]
+    fn tier_a_payload_diff_is_critical() {
+        let left = vec![tool_result(0, "ok")];
+        let right = vec![tool_result(0, "different")];
+        let delta = diff_runs(&left, &right);
+        assert_eq!(delta.divergences.len(), 1);
+        assert_eq!(delta.divergences[0].path, "$.payload.result");
+        assert_eq!(delta.divergences[0].severity, DivergenceSeverity::Critical);
+        assert_eq!(delta.severity(), Some(DivergenceSeverity::Critical));
+        assert_eq!(diff_runs(&left, &left).severity(), None);
+    }
+
+    #[test]
+    fn tier_bc_payload_diff_is_minor_unless_tier_changes() {
+        let mut left = vec![tool_result(0, "ok")];
+        left[0].tier = Tier::B;
+        let mut right = vec![tool_result(0, "different")];
+        right[0].tier = Tier::B;
+        assert_eq!(
+            diff_runs(&left, &right).severity(),
+            Some(DivergenceSeverity::Minor)
+        );
+
+        // Promoting the event to Tier A on one side is itself critical.
+        right[0].tier = Tier::A;
+        let delta = diff_runs(&left, &right);
+        assert!(delta
+            .divergences
+            .iter()
+            .all(|d| d.severity == DivergenceSeverity::Critical));
+        assert_eq!(delta.critical_count(), 2);
+    }
+
+    #[test]
+    fn missing_tier_a_event_is_critical_and_tier_c_is_minor() {
+        let left = vec![tool_result(0, "ok")];
+        let mut right = vec![tool_result(0, "ok"), tool_result(1, "ok")];
+        assert_eq!(
+            diff_runs(&left, &right).severity(),
+            Some(DivergenceSeverity::Critical)
+        );
+        right[1].tier = Tier::C;
+        assert_eq!(
+            diff_runs(&left, &right).severity(),
+            Some(DivergenceSeverity::Minor)
+        );
+    }
+
+    /// A ToolResult with `event_id` `id` at `commit_index`.
+    fn event_at(commit_index: u64, id: &str, result: &str) -> CommittedEvent {
+        let mut event = tool_result(commit_index, result);
+        event.event_id = id.to_string();
+        event.timestamp_ns = 1_000;
+        event
+    }
+
+    fn run_of(ids: &[&str]) -> Vec<CommittedEvent> {
+        ids.iter()
+            .enumerate()
+            .map(|(index, id)| event_at(index as u64, id, "ok"))
+            .collect()
+    }
+
+    fn classes(delta: &RunDelta) -> Vec<(u64, &str, ChangeClass)> {
+        delta
+            .divergences
+            .iter()
+            .map(|d| (d.commit_index, d.path.as_str(), d.change_class))
+            .collect()
+    }
+
+    #[test]
+    fn by_id_alignment_reports_one_insertion_as_one_addition() {
+        let ids: Vec<String> = (0..50).map(|i| format!("e-{i}")).collect();
+        let left_ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let mut right_ids = left_ids.clone();
+        right_ids.insert(1, "inserted");
+        let (left, right) = (run_of(&left_ids), run_of(&right_ids));
+
+        let positional = diff_runs(&left, &right);
+        assert_eq!(positional.alignment, DiffAlignment::Positional);
+        assert!(positional.divergences.len() > 49);
+
+        let by_id = diff_runs_aligned(&left, &right, DiffAlignment::ById);
+        assert_eq!(by_id.alignment, DiffAlignment::ById);
+        assert_eq!(classes(&by_id), [(1, "$event", ChangeClass::Added)]);
+        assert_eq!(
+            by_id.divergences[0].right_value.as_deref(),
+            Some("inserted")
+        );
+    }
+
+    #[test]
+    fn by_id_alignment_reports_reorder_as_move_and_edits_as_changes() {
+        let left = run_of(&["a", "b", "c", "d"]);
+        let mut right = run_of(&["a", "c", "b", "d"]);
+        right[3] = event_at(3, "d", "different");
+        for event in &mut right {
+            event.run_id = "other-run".to_string();
+        }
+
+        let delta = diff_runs_aligned(&left, &right, DiffAlignment::ById);
+        assert_eq!(
+            classes(&delta),
+            [
+                (1, "$.commit_index", ChangeClass::Moved),
+                (3, "$.payload.result", ChangeClass::Changed),
+            ]
+        );
+        assert_eq!(delta.divergences[0].left_value.as_deref(), Some("1"));
+        assert_eq!(delta.divergences[0].right_value.as_deref(), Some("2"));
+        assert_eq!(delta.right_run_id, "other-run");
+        // Reordering alone is minor; the Tier A payload edit is critical.
+        let severities: Vec<_> = delta.divergences.iter().map(|d| d.severity).collect();
+        assert_eq!(
+            severities,
+            [DivergenceSeverity::Minor, DivergenceSeverity::Critical]
+        );
+    }
+
+    #[test]
+    fn by_id_alignment_falls_back_to_source_seq_and_reports_removals() {
+        let mut left = run_of(&["", "", ""]);
+        let mut right = run_of(&["", ""]);
+        for (index, event) in left.iter_mut().enumerate() {
+            event.source_seq = Some(10 + index as u64);
+        }
+        // The right side lost seq 10, so its events sit one index earlier.
+        for (index, event) in right.iter_mut().enumerate() {
+            event.source_seq = Some(11 + index as u64);
+        }
+
+        let delta = diff_runs_aligned(&left, &right, DiffAlignment::ById);
+        assert_eq!(classes(&delta), [(0, "$event", ChangeClass::Removed)]);
+    }
+
+    #[test]
+    fn alignment_is_recorded_in_delta_json() {
+        let left = run_of(&["a"]);
+        let json =
+            serde_json::to_value(diff_runs_aligned(&left, &left, DiffAlignment::ById)).unwrap();
+        assert_eq!(json["alignment"], "by-id");
+        let json = serde_json::to_value(diff_runs(&left, &left)).unwrap();
+        assert_eq!(json["alignment"], "positional");
+    }
+
+    #[test]
+    fn ignore_paths_drop_exactly_the_divergences_under_them() {
+        let left = vec![tool_result(0, "ok"), tool_result(1, "ok")];
+        let mut right = vec![tool_result(0, "different"), tool_result(1, "ok")];
+        right[0].timestamp_ns += 1;
+        right[1].synthesized = true;
+        right[1].payload = EventPayload::ToolResult {
+            tool: "grep".to_string(),
+            result: Some("ok".to_string()),
+            status: Some("success".to_string()),
+            invocation_id: None,
+        };
+        right.push(tool_result(2, "ok"));
+
+        let full = diff_runs(&left, &right);
+        let options = DiffOptions::new().with_ignore_paths([
+            "$.synthesized",
+            "timestamp_ns",
+            "payload.result",
+            // A prefix of a field name is not a path above it.
+            "payload.too",
+        ]);
+        let ignored = diff_runs_with(&left, &right, &options);
+
+        let expected: Vec<Divergence> = full
+            .divergences
+            .iter()
+            .filter(|d| {
+                !["$.synthesized", "$.timestamp_ns", "$.payload.result"].contains(&d.path.as_str())
+            })
+            .cloned()
+            .collect();
+        assert_eq!(ignored.divergences, expected);
+        assert_eq!(
+            classes(&ignored),
+            [
+                (1, "$.payload.tool", ChangeClass::ValueMismatch),
+                (2, "$event", ChangeClass::EventMissingLeft),
+            ]
+        );
+        assert_eq!(
+            ignored.ignore_paths,
+            [
+                "payload.result",
+                "payload.too",
+                "synthesized",
+                "timestamp_ns"
+            ]
+        );
+
+        // Ignoring a parent drops every leaf under it.
+        let whole_payload = DiffOptions::new().with_ignore_paths(["payload"]);
+        assert!(diff_runs_with(&left, &right, &whole_payload)
+            .divergences
+            .iter()
+            .all(|d| !d.path.starts_with("$.payload")));
+    }
+
+    #[test]
+    fn ignore_paths_are_recorded_in_delta_json_only_when_set() {
+        let left = run_of(&["a"]);
+        let json = serde_json::to_value(diff_runs(&left, &left)).unwrap();
+        assert!(json.get("ignore_paths").is_none());
+        let options = DiffOptions::new()
+            .with_alignment(DiffAlignment::ById)
+            .with_ignore_paths(["timestamp_ns", "$.timestamp_ns"]);
+        let json = serde_json::to_value(diff_runs_with(&left, &left, &options)).unwrap();
+        assert_eq!(json["ignore_paths"], serde_json::json!(["timestamp_ns"]));
+        assert_eq!(json["alignment"], "by-id");
+    }
+
+    #[test]
+    fn summary_counts_per_severity_and_class_and_top_list_puts_critical_first() {
+        let left = run_of(&["a", "b", "c", "d"]);
+        let mut right = run_of(&["a", "c", "b", "d", "e"]);
+        right[3] = event_at(3, "d", "different");
+        right[4].tier = Tier::C;
+
+        let delta = diff_runs_aligned(&left, &right, DiffAlignment::ById);
+        assert_eq!(
+            delta.summary.by_severity,
+            BTreeMap::from([
+                (DivergenceSeverity::Minor, 2),
+                (DivergenceSeverity::Critical, 1),
+            ])
+        );
+        assert_eq!(
+            delta.summary.by_change_class,
+            BTreeMap::from([
+                (ChangeClass::Added, 1),
+                (ChangeClass::Moved, 1),
+                (ChangeClass::Changed, 1),
+            ])
+        );
+        assert_eq!(delta.summary, DeltaSummary::of(&delta.divergences));
+
+        let top: Vec<_> = delta
+            .most_severe(2)
+            .iter()
+            .map(|d| (d.commit_index, d.change_class))
+            .collect();
+        assert_eq!(top, [(3, ChangeClass::Changed), (1, ChangeClass::Moved)]);
+
+        let json = serde_json::to_value(&delta).unwrap();
+        assert_eq!(json["summary"]["by_severity"]["critical"], 1);
+        assert_eq!(json["summary"]["by_change_class"]["Added"], 1);
+        assert_eq!(
+            serde_json::to_value(diff_runs(&left, &left)).unwrap()["summary"],
+            serde_json::json!({"by_severity": {}, "by_change_class": {}})
+        );
+    }
+
+    #[test]
+    fn severity_serializes_lowercase() {
+        let json = serde_json::to_string(&DivergenceSeverity::Critical).unwrap();
+        assert_eq!(json, "\"critical\"");
+        assert_eq!(DivergenceSeverity::Minor.to_string(), "minor");
+    }
+
     #[test]
     fn output_is_byte_stable_across_unsorted_inputs() {
         let left = vec![
@@ -539,6 +1338,7 @@ mod tests {
                 tool: "search".to_string(),
                 result: Some("ok".to_string()),
                 status: Some("success".to_string()),
+                invocation_id: None,
             },
         );
         let key = event_stable_tiebreak_key(&event);
@@ -549,4 +1349,90 @@ mod tests {
             "payload component should be explicit and non-empty in tie-break key"
         );
     }
+
+    #[test]
+    fn identical_states_have_no_divergence() {
+        let events = vec![committed(
+            0,
+            EventPayload::RunStart {
+                agent: "a".to_string(),
+                args: None,
+            },
+        )];
+        let state = crate::reducer::replay(&events).0;
+        let delta = diff_states(&state, &state.clone());
+        assert!(delta.divergences.is_empty());
+        assert_eq!(delta.left_state_hash, delta.right_state_hash);
+    }
+
+    #[test]
+    fn state_divergence_names_the_differing_tool_and_counter() {
+        let result = |status: &str| {
+            committed(
+                0,
+                EventPayload::ToolResult {
+                    tool: "grep".to_string(),
+                    result: None,
+                    status: Some(status.to_string()),
+                    invocation_id: None,
+                },
+            )
+        };
+        let left = crate::reducer::replay(&[result("success")]).0;
+        let right = crate::reducer::replay(&[result("error")]).0;
+
+        let delta = diff_states(&left, &right);
+        let paths: Vec<&str> = delta.divergences.iter().map(|d| d.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "$.tool_summaries.grep.error_count",
+                "$.tool_summaries.grep.success_count"
+            ]
+        );
+        assert_eq!(delta.divergences[0].left_value.as_deref(), Some("0"));
+        assert_eq!(delta.divergences[0].right_value.as_deref(), Some("1"));
+        assert_ne!(delta.left_state_hash, delta.right_state_hash);
+    }
+
+    #[test]
+    fn state_list_entries_missing_on_one_side_have_no_value() {
+        let error = committed(
+            0,
+            EventPayload::Error {
+                kind: "io".to_string(),
+                message: "disk full".to_string(),
+                severity: None,
+            },
+        );
+        let left = crate::reducer::replay(&[error]).0;
+        let right = State::new();
+
+        let delta = diff_states(&left, &right);
+        let message = delta
+            .divergences
+            .iter()
+            .find(|d| d.path == "$.error_log[0].message")
+            .expect("error_log entry divergence");
+        assert_eq!(message.left_value.as_deref(), Some("\"disk full\""));
+        assert_eq!(message.right_value, None);
+    }
+
+    #[test]
+    fn change_class_display_matches_serde_name() {
+        for class in [
+            ChangeClass::EventMissingLeft,
+            ChangeClass::EventMissingRight,
+            ChangeClass::ValueMismatch,
+            ChangeClass::Added,
+            ChangeClass::Removed,
+            ChangeClass::Moved,
+            ChangeClass::Changed,
+        ] {
+            assert_eq!(
+                serde_json::to_value(class).unwrap(),
+                serde_json::Value::String(class.to_string())
+            );
+        }
+    }
 }
@@ -0,0 +1,436 @@
+//! Unified-diff rendering of [`RunDelta`] divergences.
+//!
+//! A divergence record says where two runs differ (`commit_index`, path,
+//! class) but not what the events look like. [`render_divergences`] pairs
+//! each divergence with its left and right events, pretty-prints both as
+//! JSON, and renders a unified diff with [`DIFF_CONTEXT_LINES`] lines of
+//! context. Consecutive divergences on the same event pair share one
+//! snippet.
+//!
+//! Event text passes through a caller-supplied redaction function before
+//! it is diffed, so a secret is masked on both sides alike and never
+//! reaches the output. Core has no scanner of its own; the CLI passes the
+//! export scanner's display redaction.
+//!
+//! Output is deterministic: the divergences rendered are the ones
+//! [`RunDelta::most_severe`] selects, taken in delta order; the line diff
+//! breaks ties the same way every time; and events are printed in their
+//! struct field order.
+
+use crate::delta::{
+    index_events_by_align_key, index_events_by_commit_index, AlignKey, ChangeClass, DiffAlignment,
+    Divergence, DivergenceSeverity, RunDelta,
+};
+use crate::event::CommittedEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+/// Unchanged lines shown around each change.
+pub const DIFF_CONTEXT_LINES: usize = 3;
+
+/// Line-pair budget of the diff table. Events beyond it are rendered as a
+/// whole-event replacement instead of a minimal diff.
+const MAX_DIFF_CELLS: usize = 1 << 22;
+
+/// Diff snippet of one event pair and the divergences it covers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedDivergence {
+    /// `commit_index` of the first covered divergence.
+    pub commit_index: u64,
+    /// `commit_index` of the left event, if there is one.
+    pub left_commit_index: Option<u64>,
+    /// `commit_index` of the right event, if there is one.
+    pub right_commit_index: Option<u64>,
+    /// Paths of the covered divergences, in delta order.
+    pub paths: Vec<String>,
+    /// Most severe covered divergence.
+    pub severity: DivergenceSeverity,
+    /// Unified diff of the redacted, pretty-printed events.
+    pub diff: String,
+}
+
+/// Render the `max_divergences` most severe divergences of `delta` (the
+/// same selection as [`RunDelta::most_severe`]), which was computed from
+/// `left` and `right`. `redact` is applied to each event's JSON text
+/// before diffing.
+pub fn render_divergences(
+    left: &[CommittedEvent],
+    right: &[CommittedEvent],
+    delta: &RunDelta,
+    max_divergences: usize,
+    redact: impl Fn(&str) -> String,
+) -> Vec<RenderedDivergence> {
+    let pairs = EventPairs::new(left, right, delta.alignment);
+    let mut groups: Vec<(EventPair<'_>, RenderedDivergence)> = Vec::new();
+    let selected = delta.most_severe(max_divergences);
+    for divergence in delta
+        .divergences
+        .iter()
+        .filter(|d| selected.iter().any(|s| std::ptr::eq(*s, *d)))
+    {
+        let pair = pairs.resolve(divergence);
+        if let Some((last_pair, rendered)) = groups.last_mut() {
+            if same_pair(last_pair, &pair) {
+                rendered.paths.push(divergence.path.clone());
+                rendered.severity = rendered.severity.max(divergence.severity);
+                continue;
+            }
+        }
+        let rendered = RenderedDivergence {
+            commit_index: divergence.commit_index,
+            left_commit_index: pair.0.map(|e| e.commit_index),
+            right_commit_index: pair.1.map(|e| e.commit_index),
+            paths: vec![divergence.path.clone()],
+            severity: divergence.severity,
+            diff: String::new(),
+        };
+        groups.push((pair, rendered));
+    }
+
+    groups
+        .into_iter()
+        .map(|((left_event, right_event), mut rendered)| {
+            let left_text = event_text(left_event, &redact);
+            let right_text = event_text(right_event, &redact);
+            rendered.diff = unified_diff(
+                &side_label("left", left_event),
+                &side_label("right", right_event),
+                &left_text,
+                &right_text,
+            );
+            rendered
+        })
+        .collect()
+}
+
+type EventPair<'a> = (Option<&'a CommittedEvent>, Option<&'a CommittedEvent>);
+
+fn same_pair(a: &EventPair<'_>, b: &EventPair<'_>) -> bool {
+    let same = |x: Option<&CommittedEvent>, y: Option<&CommittedEvent>| match (x, y) {
+        (Some(x), Some(y)) => std::ptr::eq(x, y),
+        (None, None) => true,
+        _ => false,
+    };
+    same(a.0, b.0) && same(a.1, b.1)
+}
+
+/// Finds the events a divergence was computed from, pairing them the way
+/// the delta's alignment did.
+struct EventPairs<'a> {
+    alignment: DiffAlignment,
+    left_by_index: BTreeMap<u64, &'a CommittedEvent>,
+    right_by_index: BTreeMap<u64, &'a CommittedEvent>,
+    /// By-id only: alignment key of each left event, by `commit_index`.
+    left_keys: BTreeMap<u64, (AlignKey<'a>, usize)>,
+    /// By-id only: right events by alignment key.
+    right_by_key: BTreeMap<(AlignKey<'a>, usize), &'a CommittedEvent>,
+}
+
+impl<'a> EventPairs<'a> {
+    fn new(
+        left: &'a [CommittedEvent],
+        right: &'a [CommittedEvent],
+        alignment: DiffAlignment,
+    ) -> Self {
+        let (left_keys, right_by_key) = match alignment {
+            DiffAlignment::Positional => (BTreeMap::new(), BTreeMap::new()),
+            DiffAlignment::ById => (
+                index_events_by_align_key(left)
+                    .into_iter()
+                    .map(|(key, event)| (event.commit_index, key))
+                    .collect(),
+                index_events_by_align_key(right),
+            ),
+        };
+        EventPairs {
+            alignment,
+            left_by_index: index_events_by_commit_index(left),
+            right_by_index: index_events_by_commit_index(right),
+            left_keys,
+            right_by_key,
+        }
+    }
+
+    fn resolve(&self, divergence: &Divergence) -> EventPair<'a> {
+        let index = divergence.commit_index;
+        let left = self.left_by_index.get(&index).copied();
+        let right = self.right_by_index.get(&index).copied();
+        match (self.alignment, divergence.change_class) {
+            (DiffAlignment::Positional, _) => (left, right),
+            (DiffAlignment::ById, ChangeClass::Added) => (None, right),
+            (DiffAlignment::ById, ChangeClass::Removed) => (left, None),
+            (DiffAlignment::ById, _) => {
+                let right = self
+                    .left_keys
+                    .get(&index)
+                    .and_then(|key| self.right_by_key.get(key).copied());
+                (left, right)
+            }
+        }
+    }
+}
+
+/// Redacted, pretty-printed JSON of `event`; empty for an absent event,
+/// so its side of the diff has no lines.
+fn event_text(event: Option<&CommittedEvent>, redact: &impl Fn(&str) -> String) -> String {
+    let Some(event) = event else {
+        return String::new();
+    };
+    let json = serde_json::to_string_pretty(event)
+        .expect("CommittedEvent serialization should never fail");
+    redact(&json)
+}
+
+fn side_label(side: &str, event: Option<&CommittedEvent>) -> String {
+    match event {
+        Some(e) => format!("{side} commit_index={}", e.commit_index),
+        None => format!("{side} (absent)"),
+    }
+}
+
+/// One line of a line diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op<'a> {
+    Keep(&'a str),
+    Remove(&'a str),
+    Add(&'a str),
+}
+
+/// Longest-common-subsequence line diff. Removals are emitted before
+/// additions where both are possible.
+fn diff_lines<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<Op<'a>> {
+    let (n, m) = (left.len(), right.len());
+    if (n + 1).saturating_mul(m + 1) > MAX_DIFF_CELLS {
+        return left
+            .iter()
+            .map(|line| Op::Remove(line))
+            .chain(right.iter().map(|line| Op::Add(line)))
+            .collect();
+    }
+    // lcs[i * (m + 1) + j]: common subsequence length of left[i..], right[j..].
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * (m + 1) + j] = if left[i] == right[j] {
+                lcs[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+            };
+        }
+    }
+    let mut ops = Vec::with_capacity(n + m);
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && left[i] == right[j] {
+            ops.push(Op::Keep(left[i]));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1]) {
+            ops.push(Op::Remove(left[i]));
+            i += 1;
+        } else {
+            ops.push(Op::Add(right[j]));
+            j += 1;
+        }
+    }
+    ops
+}
+
+/// Unified diff of `left` and `right` with `---`/`+++` headers and
+/// `@@ -start,count +start,count @@` hunks. Identical texts render as the
+/// headers alone.
+fn unified_diff(left_label: &str, right_label: &str, left: &str, right: &str) -> String {
+    let left_lines: Vec<&str> = left.lines().collect();
+    let right_lines: Vec<&str> = right.lines().collect();
+    let ops = diff_lines(&left_lines, &right_lines);
+
+    let mut out = format!("--- {left_label}\n+++ {right_label}\n");
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, Op::Keep(_)))
+        .map(|(index, _)| index)
+        .collect();
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(DIFF_CONTEXT_LINES);
+        let end = (index + DIFF_CONTEXT_LINES + 1).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    // Lines of each side consumed before each op.
+    let mut consumed = Vec::with_capacity(ops.len() + 1);
+    let (mut old, mut new) = (0usize, 0usize);
+    for op in &ops {
+        consumed.push((old, new));
+        match op {
+            Op::Keep(_) => {
+                old += 1;
+                new += 1;
+            }
+            Op::Remove(_) => old += 1,
+            Op::Add(_) => new += 1,
+        }
+    }
+    consumed.push((old, new));
+
+    for (start, end) in hunks {
+        let (old_before, new_before) = consumed[start];
+        let (old_after, new_after) = consumed[end];
+        let (old_count, new_count) = (old_after - old_before, new_after - new_before);
+        let old_start = if old_count > 0 {
+            old_before + 1
+        } else {
+            old_before
+        };
+        let new_start = if new_count > 0 {
+            new_before + 1
+        } else {
+            new_before
+        };
+        let _ = writeln!(
+            out,
+            "@@ -{old_start},{old_count} +{new_start},{new_count} @@"
+        );
+        for op in &ops[start..end] {
+            let _ = match op {
+                Op::Keep(line) => writeln!(out, " {line}"),
+                Op::Remove(line) => writeln!(out, "-{line}"),
+                Op::Add(line) => writeln!(out, "+{line}"),
+            };
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delta::{diff_runs_with, DiffOptions};
+    use crate::event::{EventPayload, ImportEvent, Tier};
+
+    fn event(commit_index: u64, event_id: &str, tool: &str, args: &str) -> CommittedEvent {
+        CommittedEvent::commit(
+            ImportEvent {
+                run_id: "run".into(),
+                event_id: event_id.into(),
+                source_id: "test".into(),
+                source_seq: Some(commit_index),
+                timestamp_ns: 1_000 + commit_index,
+                tier: Tier::A,
+                payload: EventPayload::ToolCall {
+                    tool: tool.into(),
+                    args: Some(args.into()),
+                    invocation_id: None,
+                },
+                payload_ref: None,
+                synthesized: false,
+            },
+            commit_index,
+        )
+    }
+
+    #[test]
+    fn renders_one_hunk_per_changed_event_pair() {
+        let left = vec![event(0, "a", "grep", "x"), event(1, "b", "ls", "-l")];
+        let right = vec![event(0, "a", "grep", "x"), event(1, "b", "cat", "-n")];
+        let delta = diff_runs_with(&left, &right, &DiffOptions::new());
+        let rendered = render_divergences(&left, &right, &delta, 10, str::to_string);
+
+        assert_eq!(rendered.len(), 1);
+        let snippet = &rendered[0];
+        assert_eq!(snippet.commit_index, 1);
+        assert_eq!(snippet.paths, ["$.payload.args", "$.payload.tool"]);
+        assert!(snippet
+            .diff
+            .starts_with("--- left commit_index=1\n+++ right commit_index=1\n@@ -"));
+        assert!(snippet.diff.contains("\n-    \"tool\": \"ls\",\n"));
+        assert!(snippet.diff.contains("\n+    \"tool\": \"cat\",\n"));
+        assert!(snippet.diff.contains("\n     \"type\": \"ToolCall\",\n"));
+        // Identical leading fields stay out of the hunk beyond the context.
+        assert!(!snippet.diff.contains("\"run_id\""));
+
+        let again = render_divergences(&left, &right, &delta, 10, str::to_string);
+        assert_eq!(rendered, again);
+    }
+
+    #[test]
+    fn cap_limits_divergences_and_redaction_applies_to_both_sides() {
+        let left = vec![
+            event(0, "a", "grep", "SECRET1"),
+            event(1, "b", "ls", "SECRET2"),
+        ];
+        let right = vec![
+            event(0, "a", "grep", "SECRET9"),
+            event(1, "b", "ls", "SECRET8"),
+        ];
+        let delta = diff_runs_with(&left, &right, &DiffOptions::new());
+        let rendered = render_divergences(&left, &right, &delta, 1, |text| {
+            text.replace("SECRET", "S***")
+        });
+
+        assert_eq!(rendered.len(), 1);
+        assert!(!rendered[0].diff.contains("SECRET"));
+        assert!(rendered[0].diff.contains("-    \"args\": \"S***1\""));
+    }
+
+    #[test]
+    fn cap_renders_the_most_severe_divergences() {
+        let left = vec![event(0, "a", "grep", "x"), event(1, "b", "ls", "-l")];
+        let mut right = left.clone();
+        right[0].timestamp_ns += 1;
+        right[1] = event(1, "b", "cat", "-l");
+        let delta = diff_runs_with(&left, &right, &DiffOptions::new());
+        assert_eq!(delta.divergences[0].commit_index, 0);
+        let top = delta.most_severe(1);
+
+        let rendered = render_divergences(&left, &right, &delta, 1, str::to_string);
+        assert_eq!(rendered.len(), 1);
+        assert_eq!(rendered[0].commit_index, top[0].commit_index);
+        assert_eq!(rendered[0].commit_index, 1);
+        assert_eq!(rendered[0].severity, top[0].severity);
+    }
+
+    #[test]
+    fn by_id_pairs_added_and_changed_events() {
+        let left = vec![event(0, "a", "grep", "x")];
+        let right = vec![event(0, "new", "ls", ""), event(1, "a", "grep", "y")];
+        let options = DiffOptions::new().with_alignment(DiffAlignment::ById);
+        let delta = diff_runs_with(&left, &right, &options);
+        let rendered = render_divergences(&left, &right, &delta, 10, str::to_string);
+
+        let added = rendered
+            .iter()
+            .find(|r| r.left_commit_index.is_none())
+            .expect("added event snippet");
+        assert_eq!(added.right_commit_index, Some(0));
+        assert!(added
+            .diff
+            .starts_with("--- left (absent)\n+++ right commit_index=0\n@@ -0,0 +1,"));
+
+        let changed = rendered
+            .iter()
+            .find(|r| r.paths.contains(&"$.payload.args".to_string()))
+            .expect("changed event snippet");
+        assert_eq!(
+            (changed.left_commit_index, changed.right_commit_index),
+            (Some(0), Some(1))
+        );
+        assert!(changed.diff.contains("-    \"args\": \"x\""));
+        assert!(changed.diff.contains("+    \"args\": \"y\""));
+    }
+
+    #[test]
+    fn line_diff_is_minimal() {
+        let ops = diff_lines(&["a", "b", "c"], &["a", "x", "c"]);
+        assert_eq!(
+            ops,
+            [Op::Keep("a"), Op::Remove("b"), Op::Add("x"), Op::Keep("c")]
+        );
+        assert_eq!(unified_diff("l", "r", "same\n", "same\n"), "--- l\n+++ r\n");
+    }
+}
@@ -0,0 +1,123 @@
+//! Typed errors for the public pipeline entry points.
+//!
+//! [`read_eventlog`](crate::eventlog::read_eventlog), `run_tour`, and
+//! `run_export` return [`VifeiError`] so callers can tell an empty fixture
+//! or a malformed EventLog line from a plain I/O failure without matching
+//! on message text.
+//!
+//! Conversion goes both ways. `From<VifeiError> for io::Error` keeps the
+//! variant as the error's inner value, and `From<io::Error>` unwraps it
+//! again, so a `VifeiError` passed through an `io::Result` function comes
+//! back as the same variant. Any other `io::Error` becomes
+//! [`VifeiError::Io`].
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Error from a Vifei pipeline entry point.
+#[derive(Debug)]
+pub enum VifeiError {
+    /// The fixture imported no events.
+    EmptyFixture,
+    /// An EventLog line could not be parsed.
+    MalformedEvent {
+        /// 1-based physical line number, counted across segments.
+        line: usize,
+        /// Parser message.
+        message: String,
+    },
+    /// Export found secrets and wrote no bundle.
+    ExportRefused {
+        /// Findings in the refusal report.
+        blocked_items: usize,
+    },
+    /// Any other I/O failure.
+    Io(io::Error),
+}
+
+impl VifeiError {
+    /// The `io::ErrorKind` this error converts to.
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            VifeiError::EmptyFixture | VifeiError::MalformedEvent { .. } => {
+                io::ErrorKind::InvalidData
+            }
+            VifeiError::ExportRefused { .. } => io::ErrorKind::PermissionDenied,
+            VifeiError::Io(e) => e.kind(),
+        }
+    }
+}
+
+impl fmt::Display for VifeiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VifeiError::EmptyFixture => write!(f, "Fixture contains no events"),
+            VifeiError::MalformedEvent { line, message } => {
+                write!(f, "failed to parse EventLog line {line}: {message}")
+            }
+            VifeiError::ExportRefused { blocked_items } => {
+                write!(f, "export refused: {blocked_items} blocked item(s)")
+            }
+            VifeiError::Io(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for VifeiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            VifeiError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for VifeiError {
+    fn from(e: io::Error) -> Self {
+        if e.get_ref().is_some_and(|inner| inner.is::<VifeiError>()) {
+            let inner = e.into_inner().expect("checked above");
+            return *inner.downcast::<VifeiError>().expect("checked above");
+        }
+        VifeiError::Io(e)
+    }
+}
+
+impl From<VifeiError> for io::Error {
+    fn from(e: VifeiError) -> Self {
+        match e {
+            VifeiError::Io(e) => e,
+            other => io::Error::new(other.kind(), other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant_survives_a_round_trip_through_io_error() {
+        let err = VifeiError::MalformedEvent {
+            line: 3,
+            message: "expected value".into(),
+        };
+        let io_err = io::Error::from(err);
+        assert_eq!(io_err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            io_err.to_string(),
+            "failed to parse EventLog line 3: expected value"
+        );
+
+        let back = VifeiError::from(io_err);
+        assert!(matches!(back, VifeiError::MalformedEvent { line: 3, .. }));
+    }
+
+    #[test]
+    fn plain_io_error_becomes_io() {
+        let err = VifeiError::from(io::Error::from(io::ErrorKind::NotFound));
+        assert!(matches!(&err, VifeiError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.source().is_some());
+    }
+}
@@ -40,11 +40,19 @@
 //!
 //! ```text
 //! commit_index, run_id, event_id, source_id, [source_seq], timestamp_ns,
-//! tier, payload, [payload_ref], [synthesized]
+//! tier, payload, [payload_ref], [synthesized], [prev_hash], schema_version
 //! ```
 //!
 //! Fields in brackets are omitted when `None` / `false`.
 //!
+//! # Schema versions
+//!
+//! Each line carries the [`EVENT_SCHEMA_VERSION`] it was written under.
+//! A line without the field, written before it existed, is read as
+//! version 1. Reads are forward compatible: a payload `type`
+//! this build does not know is read as [`EventPayload::Generic`] (see
+//! [`CommittedEvent::from_jsonl_line`]) instead of failing the whole read.
+//!
 //! # Payload variants
 //!
 //! [`EventPayload`] uses `#[serde(tag = "type")]` (internally tagged). The
@@ -202,10 +210,16 @@ pub enum EventPayload {
     ToolCall {
         /// Tool name.
         tool: String,
-        /// Tool arguments (inline). Omit if blobbed via `payload_ref`.
+        /// Tool arguments (inline). Omitted, or a truncated preview, if
+        /// blobbed via `payload_ref`.
         #[serde(skip_serializing_if = "Option::is_none")]
         #[serde(default)]
         args: Option<String>,
+        /// Source identifier of this invocation, echoed by its
+        /// `ToolResult`. Used to pair calls with results.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        invocation_id: Option<String>,
     },
 
     /// Tool returns a result.
@@ -214,7 +228,8 @@ pub enum EventPayload {
     ToolResult {
         /// Tool name.
         tool: String,
-        /// Result content (inline). Omit if blobbed via `payload_ref`.
+        /// Result content (inline). Omitted, or a truncated preview, if
+        /// blobbed via `payload_ref`.
         #[serde(skip_serializing_if = "Option::is_none")]
         #[serde(default)]
         result: Option<String>,
@@ -222,6 +237,10 @@ pub enum EventPayload {
         #[serde(skip_serializing_if = "Option::is_none")]
         #[serde(default)]
         status: Option<String>,
+        /// `invocation_id` of the `ToolCall` this result answers.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(default)]
+        invocation_id: Option<String>,
     },
 
     /// Backpressure or policy decision made by the system.
@@ -279,6 +298,39 @@ pub enum EventPayload {
         delta_ns: u64,
     },
 
+    /// Human-added context attached to an earlier event.
+    ///
+    /// Appended by `vifei annotate`. The annotated event is never modified;
+    /// notes are new events with their own `commit_index`.
+    Note {
+        /// `commit_index` of the annotated event.
+        target_commit_index: u64,
+        /// Who wrote the note.
+        author: String,
+        /// Note text.
+        text: String,
+    },
+
+    /// Model token consumption reported by the agent.
+    TokenUsage {
+        /// Model that consumed the tokens.
+        model: String,
+        /// Prompt (input) tokens.
+        input_tokens: u64,
+        /// Completion (output) tokens.
+        output_tokens: u64,
+    },
+
+    /// A run started a subagent run.
+    SubagentSpawned {
+        /// `run_id` of the spawning run.
+        parent_run_id: String,
+        /// `run_id` of the subagent's run.
+        child_run_id: String,
+        /// Subagent identifier.
+        agent: String,
+    },
+
     /// Generic event for future Tier B/C extension.
     ///
     /// New event types can be added here without schema-breaking changes.
@@ -297,6 +349,22 @@ pub enum EventPayload {
 }
 
 impl EventPayload {
+    /// Every `type` value this build can read, in declaration order.
+    pub const TYPE_NAMES: &'static [&'static str] = &[
+        "RunStart",
+        "RunEnd",
+        "ToolCall",
+        "ToolResult",
+        "PolicyDecision",
+        "RedactionApplied",
+        "Error",
+        "ClockSkewDetected",
+        "Note",
+        "TokenUsage",
+        "SubagentSpawned",
+        "Generic",
+    ];
+
     /// Returns the event type name as it appears in the JSON `type` field.
     pub fn event_type_name(&self) -> &str {
         match self {
@@ -308,6 +376,9 @@ impl EventPayload {
             EventPayload::RedactionApplied { .. } => "RedactionApplied",
             EventPayload::Error { .. } => "Error",
             EventPayload::ClockSkewDetected { .. } => "ClockSkewDetected",
+            EventPayload::Note { .. } => "Note",
+            EventPayload::TokenUsage { .. } => "TokenUsage",
+            EventPayload::SubagentSpawned { .. } => "SubagentSpawned",
             EventPayload::Generic { .. } => "Generic",
         }
     }
@@ -371,7 +442,7 @@ pub struct ImportEvent {
 ///
 /// ```text
 /// commit_index, run_id, event_id, source_id, [source_seq], timestamp_ns,
-/// tier, payload, [payload_ref], [synthesized]
+/// tier, payload, [payload_ref], [synthesized], [prev_hash], schema_version
 /// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CommittedEvent {
@@ -402,8 +473,30 @@ pub struct CommittedEvent {
     #[serde(skip_serializing_if = "is_false")]
     #[serde(default)]
     pub synthesized: bool,
+    /// BLAKE3 hex digest of the previous EventLog line, or
+    /// [`GENESIS_PREV_HASH`] for the first event. Set by the append writer;
+    /// absent in EventLogs written before hash chaining.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub prev_hash: Option<String>,
+    /// EventLog line schema version; [`EVENT_SCHEMA_VERSION`] for events
+    /// committed by this build. Always written; 1 when absent.
+    #[serde(default = "schema_v1")]
+    pub schema_version: u32,
+}
+
+/// Schema version of the EventLog lines this build writes.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Helper for `#[serde(default)]` on `schema_version`.
+fn schema_v1() -> u32 {
+    1
 }
 
+/// `prev_hash` of the first event in a hash-chained EventLog.
+pub const GENESIS_PREV_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
 /// Helper for `#[serde(skip_serializing_if)]` on bool fields.
 fn is_false(v: &bool) -> bool {
     !v
@@ -426,10 +519,44 @@ impl CommittedEvent {
             payload: event.payload,
             payload_ref: event.payload_ref,
             synthesized: event.synthesized,
+            prev_hash: None,
+            schema_version: EVENT_SCHEMA_VERSION,
         }
     }
 
-    /// Extract the import event, discarding the `commit_index`.
+    /// Parse one EventLog line, reading payloads from newer schema
+    /// versions in degraded form.
+    ///
+    /// A payload whose `type` is not one of [`EventPayload::TYPE_NAMES`] is
+    /// read as [`EventPayload::Generic`] with that `event_type` and the
+    /// original payload JSON (keys sorted) under the `raw` key of `data`,
+    /// and its type name is returned so readers can count it. Any other
+    /// malformed line fails with the strict parse error.
+    pub fn from_jsonl_line(line: &[u8]) -> serde_json::Result<(Self, Option<String>)> {
+        let strict = match serde_json::from_slice::<CommittedEvent>(line) {
+            Ok(event) => return Ok((event, None)),
+            Err(e) => e,
+        };
+        let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(line) else {
+            return Err(strict);
+        };
+        let Some(payload) = value.get_mut("payload") else {
+            return Err(strict);
+        };
+        let type_name = match payload.get("type").and_then(|t| t.as_str()) {
+            Some(name) if !EventPayload::TYPE_NAMES.contains(&name) => name.to_string(),
+            _ => return Err(strict),
+        };
+        *payload = serde_json::json!({
+            "type": "Generic",
+            "event_type": type_name,
+            "data": { "raw": payload.to_string() },
+        });
+        let event = serde_json::from_value(value).map_err(|_| strict)?;
+        Ok((event, Some(type_name)))
+    }
+
+    /// Extract the import event, discarding the `commit_index` and `prev_hash`.
     pub fn into_import_event(self) -> ImportEvent {
         ImportEvent {
             run_id: self.run_id,
@@ -445,6 +572,25 @@ impl CommittedEvent {
     }
 }
 
+/// Whether every event survives a JSONL round trip: serialize to one line,
+/// parse it back, and serialize again, expecting an equal event and the
+/// same bytes.
+///
+/// This is the contract between the EventLog writers and
+/// [`read_eventlog`](crate::eventlog::read_eventlog): a field that
+/// serializes but does not parse back (or parses to something else) would
+/// silently change the canonical format.
+pub fn roundtrip_check(events: &[CommittedEvent]) -> bool {
+    events.iter().all(|event| {
+        let Ok(line) = serde_json::to_string(event) else {
+            return false;
+        };
+        serde_json::from_str::<CommittedEvent>(&line).is_ok_and(|parsed| {
+            parsed == *event && serde_json::to_string(&parsed).is_ok_and(|again| again == line)
+        })
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests (M1.4 serde audit + M1.5 round-trip byte stability)
 // ---------------------------------------------------------------------------
@@ -561,6 +707,7 @@ mod tests {
         let event = make_import_event(EventPayload::ToolCall {
             tool: "bash".into(),
             args: Some("ls -la".into()),
+            invocation_id: None,
         });
         assert_roundtrip(&event, "ImportEvent::ToolCall");
         let committed = CommittedEvent::commit(event, 2);
@@ -573,6 +720,7 @@ mod tests {
             tool: "bash".into(),
             result: Some("total 42".into()),
             status: Some("success".into()),
+            invocation_id: None,
         });
         assert_roundtrip(&event, "ImportEvent::ToolResult");
         let committed = CommittedEvent::commit(event, 3);
@@ -628,6 +776,35 @@ mod tests {
         assert_roundtrip(&committed, "CommittedEvent::ClockSkewDetected");
     }
 
+    #[test]
+    fn roundtrip_token_usage() {
+        let event = make_import_event(EventPayload::TokenUsage {
+            model: "model-large".into(),
+            input_tokens: 1_200,
+            output_tokens: 345,
+        });
+        assert_roundtrip(&event, "ImportEvent::TokenUsage");
+        let json = serde_json::to_string(&event.payload).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"TokenUsage","model":"model-large","input_tokens":1200,"output_tokens":345}"#
+        );
+        let committed = CommittedEvent::commit(event, 9);
+        assert_roundtrip(&committed, "CommittedEvent::TokenUsage");
+    }
+
+    #[test]
+    fn roundtrip_subagent_spawned() {
+        let event = make_import_event(EventPayload::SubagentSpawned {
+            parent_run_id: "run-1".into(),
+            child_run_id: "run-2".into(),
+            agent: "explorer".into(),
+        });
+        assert_roundtrip(&event, "ImportEvent::SubagentSpawned");
+        let committed = CommittedEvent::commit(event, 10);
+        assert_roundtrip(&committed, "CommittedEvent::SubagentSpawned");
+    }
+
     #[test]
     fn roundtrip_generic() {
         let mut data = BTreeMap::new();
@@ -664,6 +841,7 @@ mod tests {
             payload: EventPayload::ToolCall {
                 tool: "read".into(),
                 args: None, // blobbed; content is in blob store
+                invocation_id: None,
             },
             payload_ref: Some(
                 "a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4e5f6a1b2".into(),
@@ -708,6 +886,7 @@ mod tests {
             payload: EventPayload::ToolCall {
                 tool: "bash".into(),
                 args: Some("echo hello".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -869,7 +1048,8 @@ mod tests {
         assert_eq!(
             EventPayload::ToolCall {
                 tool: String::new(),
-                args: None
+                args: None,
+                invocation_id: None,
             }
             .event_type_name(),
             "ToolCall"
@@ -878,7 +1058,8 @@ mod tests {
             EventPayload::ToolResult {
                 tool: String::new(),
                 result: None,
-                status: None
+                status: None,
+                invocation_id: None,
             }
             .event_type_name(),
             "ToolResult"
@@ -920,6 +1101,33 @@ mod tests {
             .event_type_name(),
             "ClockSkewDetected"
         );
+        assert_eq!(
+            EventPayload::Note {
+                target_commit_index: 0,
+                author: String::new(),
+                text: String::new()
+            }
+            .event_type_name(),
+            "Note"
+        );
+        assert_eq!(
+            EventPayload::TokenUsage {
+                model: String::new(),
+                input_tokens: 0,
+                output_tokens: 0
+            }
+            .event_type_name(),
+            "TokenUsage"
+        );
+        assert_eq!(
+            EventPayload::SubagentSpawned {
+                parent_run_id: String::new(),
+                child_run_id: String::new(),
+                agent: String::new()
+            }
+            .event_type_name(),
+            "SubagentSpawned"
+        );
         assert_eq!(
             EventPayload::Generic {
                 event_type: "X".into(),
@@ -939,6 +1147,7 @@ mod tests {
         let event = make_import_event(EventPayload::ToolCall {
             tool: "".into(),
             args: Some("".into()),
+            invocation_id: None,
         });
         assert_roundtrip(&event, "empty strings");
     }
@@ -948,6 +1157,7 @@ mod tests {
         let event = make_import_event(EventPayload::ToolCall {
             tool: "\u{5DE5}\u{5177}".into(),
             args: Some("args with \u{00e9}mojis and \u{00f1}".into()),
+            invocation_id: None,
         });
         assert_roundtrip(&event, "unicode content");
     }
@@ -1041,4 +1251,232 @@ mod tests {
         assert!(!json.contains('\n'), "JSONL must not contain newlines");
         assert!(!json.contains("  "), "JSONL must not be pretty-printed");
     }
+
+    // -----------------------------------------------------------------------
+    // JSONL round trip across every payload variant
+    // -----------------------------------------------------------------------
+
+    /// Number of `EventPayload` variants; see [`variant_ordinal`].
+    const PAYLOAD_VARIANTS: usize = 12;
+
+    /// Position of `payload`'s variant. The match has no wildcard arm, so a
+    /// new variant fails to compile here until it is given a sample in
+    /// [`sample_payloads`].
+    fn variant_ordinal(payload: &EventPayload) -> usize {
+        match payload {
+            EventPayload::RunStart { .. } => 0,
+            EventPayload::RunEnd { .. } => 1,
+            EventPayload::ToolCall { .. } => 2,
+            EventPayload::ToolResult { .. } => 3,
+            EventPayload::PolicyDecision { .. } => 4,
+            EventPayload::RedactionApplied { .. } => 5,
+            EventPayload::Error { .. } => 6,
+            EventPayload::ClockSkewDetected { .. } => 7,
+            EventPayload::Note { .. } => 8,
+            EventPayload::TokenUsage { .. } => 9,
+            EventPayload::SubagentSpawned { .. } => 10,
+            EventPayload::Generic { .. } => 11,
+        }
+    }
+
+    /// Every variant, with optional fields both set and unset.
+    fn sample_payloads() -> Vec<EventPayload> {
+        vec![
+            EventPayload::RunStart {
+                agent: "agent".into(),
+                args: Some("--flag \"quoted\"".into()),
+            },
+            EventPayload::RunStart {
+                agent: String::new(),
+                args: None,
+            },
+            EventPayload::RunEnd {
+                exit_code: Some(-1),
+                reason: Some("killed".into()),
+            },
+            EventPayload::RunEnd {
+                exit_code: None,
+                reason: None,
+            },
+            EventPayload::ToolCall {
+                tool: "grep".into(),
+                args: Some("{\"q\":\"α\"}".into()),
+                invocation_id: None,
+            },
+            EventPayload::ToolCall {
+                tool: "grep".into(),
+                args: None,
+                invocation_id: None,
+            },
+            EventPayload::ToolResult {
+                tool: "grep".into(),
+                result: Some("line 1\nline 2".into()),
+                status: Some("success".into()),
+                invocation_id: None,
+            },
+            EventPayload::ToolResult {
+                tool: "grep".into(),
+                result: None,
+                status: None,
+                invocation_id: None,
+            },
+            EventPayload::PolicyDecision {
+                from_level: "L0".into(),
+                to_level: "L1".into(),
+                trigger: "queue_pressure".into(),
+                queue_pressure: 0.1 + 0.2,
+            },
+            EventPayload::RedactionApplied {
+                target_event_id: "e-1".into(),
+                field_path: "payload.args".into(),
+                reason: "secret".into(),
+            },
+            EventPayload::Error {
+                kind: "io".into(),
+                message: "disk full".into(),
+                severity: Some("critical".into()),
+            },
+            EventPayload::Error {
+                kind: "parse".into(),
+                message: String::new(),
+                severity: None,
+            },
+            EventPayload::ClockSkewDetected {
+                expected_ns: u64::MAX,
+                actual_ns: 0,
+                delta_ns: u64::MAX,
+            },
+            EventPayload::Note {
+                target_commit_index: 7,
+                author: "reviewer".into(),
+                text: "🔍 look here".into(),
+            },
+            EventPayload::TokenUsage {
+                model: "model-large".into(),
+                input_tokens: u64::MAX,
+                output_tokens: 0,
+            },
+            EventPayload::SubagentSpawned {
+                parent_run_id: "run-1".into(),
+                child_run_id: "run-1/sub-1".into(),
+                agent: "explorer".into(),
+            },
+            EventPayload::Generic {
+                event_type: "MetricSnapshot".into(),
+                data: BTreeMap::from([
+                    ("z".to_string(), "last".to_string()),
+                    ("a".to_string(), "first".to_string()),
+                ]),
+            },
+            EventPayload::Generic {
+                event_type: "HeartBeat".into(),
+                data: BTreeMap::new(),
+            },
+        ]
+    }
+
+    #[test]
+    fn sample_payloads_cover_every_variant() {
+        let mut covered = [false; PAYLOAD_VARIANTS];
+        for payload in sample_payloads() {
+            covered[variant_ordinal(&payload)] = true;
+            assert_eq!(
+                EventPayload::TYPE_NAMES[variant_ordinal(&payload)],
+                payload.event_type_name()
+            );
+        }
+        assert!(covered.iter().all(|c| *c), "missing samples: {covered:?}");
+        assert_eq!(EventPayload::TYPE_NAMES.len(), PAYLOAD_VARIANTS);
+    }
+
+    #[test]
+    fn every_payload_variant_roundtrips_through_jsonl() {
+        for (i, payload) in sample_payloads().into_iter().enumerate() {
+            let name = payload.event_type_name().to_string();
+            let mut bare = CommittedEvent::commit(make_import_event(payload), i as u64);
+            bare.source_seq = None;
+            let mut full = bare.clone();
+            full.source_seq = Some(3);
+            full.payload_ref = Some("ab".repeat(32));
+            full.synthesized = true;
+            full.prev_hash = Some(GENESIS_PREV_HASH.to_string());
+            for event in [bare, full] {
+                assert!(
+                    roundtrip_check(std::slice::from_ref(&event)),
+                    "{name} (sample {i}) is not JSONL round-trip stable: {}",
+                    serde_json::to_string(&event).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn roundtrip_check_rejects_lossy_events() {
+        // NaN serializes as `null`, which does not parse back as f64.
+        let event = CommittedEvent::commit(
+            make_import_event(EventPayload::PolicyDecision {
+                from_level: "L0".into(),
+                to_level: "L1".into(),
+                trigger: "t".into(),
+                queue_pressure: f64::NAN,
+            }),
+            0,
+        );
+        assert!(!roundtrip_check(&[event]));
+        assert!(roundtrip_check(&[]));
+    }
+
+    // -----------------------------------------------------------------------
+    // Schema version and forward-compatible reads
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn schema_version_is_always_written_and_defaults_to_one() {
+        let event = CommittedEvent::commit(
+            make_import_event(EventPayload::RunStart {
+                agent: "test".into(),
+                args: None,
+            }),
+            0,
+        );
+        assert_eq!(event.schema_version, EVENT_SCHEMA_VERSION);
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.ends_with(r#","schema_version":1}"#));
+        let legacy = json.replace(r#","schema_version":1"#, "");
+        let (read, _) = CommittedEvent::from_jsonl_line(legacy.as_bytes()).unwrap();
+        assert_eq!(read, event);
+
+        let mut newer = event.clone();
+        newer.schema_version = 2;
+        let json = serde_json::to_string(&newer).unwrap();
+        assert!(json.ends_with(r#","schema_version":2}"#));
+        assert_roundtrip(&newer, "schema_version 2");
+    }
+
+    #[test]
+    fn unknown_payload_type_reads_as_generic() {
+        let line = br#"{"commit_index":4,"run_id":"run-1","event_id":"e-4","source_id":"future","timestamp_ns":5,"tier":"B","payload":{"type":"Thought","text":"hmm","tokens":12},"schema_version":2}"#;
+        let (event, unknown) = CommittedEvent::from_jsonl_line(line).unwrap();
+        assert_eq!(unknown.as_deref(), Some("Thought"));
+        assert_eq!(event.commit_index, 4);
+        assert_eq!(event.schema_version, 2);
+        assert_eq!(
+            event.payload,
+            EventPayload::Generic {
+                event_type: "Thought".into(),
+                data: BTreeMap::from([(
+                    "raw".to_string(),
+                    r#"{"text":"hmm","tokens":12,"type":"Thought"}"#.to_string()
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn known_payload_type_with_bad_fields_still_fails() {
+        let line = br#"{"commit_index":0,"run_id":"r","event_id":"e","source_id":"s","timestamp_ns":1,"tier":"A","payload":{"type":"RunStart"}}"#;
+        let err = CommittedEvent::from_jsonl_line(line).unwrap_err();
+        assert!(err.to_string().contains("agent"), "{err}");
+        assert!(CommittedEvent::from_jsonl_line(b"not json").is_err());
+    }
 }
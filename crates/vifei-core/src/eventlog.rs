@@ -9,7 +9,9 @@
 //!   exactly 1 per appended event. Assigned here and nowhere else (D6).
 //! - **JSONL format:** One JSON object per line, newline-terminated, no
 //!   pretty printing, UTF-8 bytes.
-//! - **Fsync per Tier A event:** See `docs/CAPACITY_ENVELOPE.md`.
+//! - **Fsync per Tier A event:** See `docs/CAPACITY_ENVELOPE.md`. Tier B/C
+//!   lines are synced per the writer's [`DurabilityPolicy`]. A batch from
+//!   [`EventLogWriter::append_batch`] is written and synced as one unit.
 //! - **Line size limit:** Rejects serialized events exceeding the max line
 //!   bytes budget in `docs/CAPACITY_ENVELOPE.md`.
 //!
@@ -20,7 +22,60 @@
 //! `docs/CAPACITY_ENVELOPE.md`, emits a `ClockSkewDetected` Tier A event
 //! **before** the triggering event. Both events get their own
 //! `commit_index`. Timestamps are metadata only (D6) — skew is surfaced,
-//! never corrected.
+//! never corrected. [`EventLogWriter::with_skew_tolerance`] widens or
+//! narrows the window per writer; `delta_ns` always records the real
+//! backward delta.
+//!
+//! [`commit_in_memory`] runs the same detection and `commit_index`
+//! assignment without a file, for viewers that load a raw recording.
+//!
+//! # Hash chain
+//!
+//! Each appended event carries `prev_hash`: the BLAKE3 digest of the
+//! previous line's bytes (without the newline), or [`GENESIS_PREV_HASH`]
+//! for the first event. Editing, inserting, or removing any line breaks
+//! the chain at the next line; [`verify_eventlog_chain`] reports the first
+//! break. Truncating the tail of the log is not detectable from the chain
+//! alone. A log is chained when its first event carries `prev_hash`;
+//! resuming a log written before chaining keeps appending without it, so
+//! a file is never half-chained.
+//!
+//! # Segments
+//!
+//! [`EventLogWriter::open_segmented`] writes a directory of
+//! `eventlog.00001.jsonl`, `eventlog.00002.jsonl`, … segments, rolling to a
+//! new segment before a line would push the current one past the byte
+//! threshold. `commit_index` and the hash chain continue across segments,
+//! so the concatenated segments are byte-identical to a single-file log.
+//! [`SEGMENT_INDEX_FILE`] lists the segments in order with their commit
+//! ranges; it is rewritten atomically on every roll, sync, and drop, and
+//! rebuilt from the segments on reopen. Every reader accepts either the
+//! segment directory or a single EventLog file.
+//!
+//! # Sidecar index
+//!
+//! [`EventLogWriter::with_index`] keeps a `<file>.idx` byte-offset index
+//! beside the file being written (per segment, if segmented), and
+//! [`read_eventlog_range`] seeks through it instead of parsing from the
+//! first line. The index is derived data: see [`crate::eventlog_index`].
+//!
+//! # Compressed input
+//!
+//! Readers accept gzip-compressed EventLog files, detected by a `.gz`
+//! extension or the gzip magic bytes, and decompress them on the fly (see
+//! [`open_eventlog_file`]); a segment directory may mix plain and
+//! compressed segments. Compressed files have no sidecar index, so
+//! [`read_eventlog_range`] reads them from the start. The writer only
+//! writes plain JSONL and refuses to append to a compressed file.
+//!
+//! # Writer lock
+//!
+//! Opening a writer takes an exclusive advisory lock on a sibling lock
+//! file ([`writer_lock_path`]) and records the pid in it; a second writer,
+//! in this or another process, fails fast with `WouldBlock` naming that
+//! pid. The lock is released on drop, and by the OS if the holder dies.
+//! Readers never take it. [`EventLogWriter::force_unlock`] removes a lock
+//! file whose holder is known to be gone.
 //!
 //! # Blob integration
 //!
@@ -36,18 +91,31 @@
 //! caller is responsible for entering L5 safe failure posture per
 //! `FM-APPEND-FAIL` in `docs/BACKPRESSURE_POLICY.md`.
 //!
+//! A crash mid-append can leave a partial final line. Readers fail on it
+//! by default; [`read_eventlog_recovering`] returns the valid prefix and
+//! reports the torn tail. A bad line anywhere else is always an error.
+//!
 //! # Invariants
 //!
 //! - **I1 (Forensic truth):** EventLog is append-only canonical truth.
 //! - **I5 (Loud failure):** Write errors are returned, never swallowed.
 //! - **D6 (Canonical ordering):** `commit_index` assigned here only.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ffi::OsStr;
 use std::fs::{self, File, OpenOptions};
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use crate::event::{CommittedEvent, EventPayload, ImportEvent, Tier};
+use flate2::read::MultiGzDecoder;
+use serde::{Deserialize, Serialize};
+
+use crate::error::VifeiError;
+use crate::event::{CommittedEvent, EventPayload, ImportEvent, Tier, GENESIS_PREV_HASH};
+use crate::eventlog_index::{
+    build_index, entry_bytes, header_bytes, index_path, is_sampled, open_near, IndexEntry,
+};
 
 /// Maximum serialized event line size in bytes. Events serializing to more
 /// than this are rejected to prevent unbounded memory usage.
@@ -59,6 +127,87 @@ const EVENTLOG_MAX_LINE_BYTES: usize = 1_048_576;
 /// Value from `docs/CAPACITY_ENVELOPE.md`.
 const CLOCK_SKEW_TOLERANCE_NS: u64 = 50_000_000;
 
+/// Default clock skew tolerance of a new [`EventLogWriter`].
+pub const DEFAULT_CLOCK_SKEW_TOLERANCE: Duration = Duration::from_nanos(CLOCK_SKEW_TOLERANCE_NS);
+
+/// When an [`EventLogWriter`] syncs appended Tier B/C lines to stable
+/// storage.
+///
+/// Tier A lines are synced as they are written under every policy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DurabilityPolicy {
+    /// `sync_data` after every line.
+    EveryAppend,
+    /// `sync_data` once `n` lines have been written since the last sync
+    /// (`EveryN(0)` behaves like `EveryN(1)`), and on drop.
+    EveryN(u32),
+    /// Sync only on drop or [`EventLogWriter::sync`].
+    #[default]
+    OnDrop,
+}
+
+/// Writer lock file name inside a segmented EventLog directory.
+pub const WRITER_LOCK_FILE: &str = "eventlog.lock";
+
+/// Segment index file name inside a segmented EventLog directory.
+pub const SEGMENT_INDEX_FILE: &str = "eventlog.index.json";
+
+/// First two bytes of every gzip member.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Contents of [`SEGMENT_INDEX_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentIndex {
+    /// Byte threshold the writer rolls segments at.
+    pub max_segment_bytes: u64,
+    /// Segments in commit order.
+    pub segments: Vec<SegmentEntry>,
+}
+
+/// One segment listed in a [`SegmentIndex`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SegmentEntry {
+    /// Segment file name, relative to the index.
+    pub file: String,
+    /// `commit_index` of the segment's first event, if it has any.
+    pub first_commit_index: Option<u64>,
+    /// `commit_index` of the segment's last event, if it has any.
+    pub last_commit_index: Option<u64>,
+}
+
+/// Segment file name for 1-based segment `number`.
+fn segment_file_name(number: usize) -> String {
+    format!("eventlog.{number:05}.jsonl")
+}
+
+/// Writer state for a segmented EventLog.
+struct Segments {
+    /// Segment directory.
+    dir: PathBuf,
+    /// Index as it will next be written.
+    index: SegmentIndex,
+}
+
+/// Writer state for the sidecar index of the current file.
+struct IndexSink {
+    /// Sidecar index file, opened for append.
+    file: File,
+    /// Sampling interval, in events.
+    interval: u64,
+}
+
+/// Lines of an in-progress [`EventLogWriter::append_batch`], not yet
+/// written.
+#[derive(Default)]
+struct PendingBatch {
+    /// Serialized lines, newline-terminated.
+    lines: Vec<u8>,
+    /// Sidecar index entries for those lines.
+    index_entries: Vec<IndexEntry>,
+    /// Whether a Tier A line is pending, forcing a full sync.
+    lossless: bool,
+}
+
 /// Append-only EventLog writer.
 ///
 /// The sole assigner of `commit_index`. Pass explicitly, not a global.
@@ -72,6 +221,27 @@ pub struct EventLogWriter {
     next_index: u64,
     /// Last-seen `timestamp_ns` per `source_id` for clock skew detection.
     source_timestamps: HashMap<String, u64>,
+    /// Backward timestamp delta allowed before `ClockSkewDetected`.
+    skew_tolerance_ns: u64,
+    /// `prev_hash` for the next event, or `None` for an unchained log.
+    prev_hash: Option<String>,
+    /// When Tier B/C lines are synced.
+    durability: DurabilityPolicy,
+    /// Lines written since the last sync.
+    unsynced_lines: u32,
+    /// Syncs issued so far.
+    sync_count: u64,
+    /// Segment state, or `None` for a single-file log.
+    segments: Option<Segments>,
+    /// Bytes in the current file (the last segment, if segmented).
+    file_len: u64,
+    /// Sidecar index of the current file, if maintained.
+    index: Option<IndexSink>,
+    /// Lines buffered by an in-progress batch append.
+    batch: Option<PendingBatch>,
+    /// Exclusive advisory lock on the writer lock file, released on drop.
+    #[allow(dead_code)] // Held, never read
+    lock: File,
 }
 
 /// Result of appending an event, including any generated detection events.
@@ -102,34 +272,203 @@ impl AppendResult {
 struct ScanMetadata {
     highest_commit_index: Option<u64>,
     source_timestamps: HashMap<String, u64>,
+    /// Whether the first event carries `prev_hash`; `None` for an empty log.
+    chained: Option<bool>,
+    /// Hash of the last non-blank line.
+    last_line_hash: Option<String>,
 }
 
 impl EventLogWriter {
     /// Open or create an EventLog at the given path.
     ///
     /// If the file exists, scans it to find the highest `commit_index` and
-    /// resumes from there. If new, starts at `commit_index = 0`.
+    /// resumes from there, continuing its hash chain if it has one. If new,
+    /// starts at `commit_index = 0` with a chained first event.
+    ///
+    /// A segmented EventLog directory is resumed with the threshold from
+    /// its index. Uses [`DurabilityPolicy::OnDrop`].
     pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        Self::open_with_policy(path, DurabilityPolicy::OnDrop)
+    }
+
+    /// Open or create an EventLog like [`EventLogWriter::open`], syncing
+    /// Tier B/C lines per `durability`.
+    pub fn open_with_policy(
+        path: impl Into<PathBuf>,
+        durability: DurabilityPolicy,
+    ) -> io::Result<Self> {
         let path = path.into();
-        let metadata = if path.exists() {
-            Self::scan_metadata(&path)?
+        if path.is_dir() {
+            let max_segment_bytes = read_segment_index(&path)?.max_segment_bytes;
+            return Self::open_segmented(path, max_segment_bytes, durability);
+        }
+        if is_gzip_eventlog(&path)? {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "cannot append to gzip-compressed EventLog {}; decompress it first",
+                    path.display()
+                ),
+            ));
+        }
+        let lock = acquire_writer_lock(&writer_lock_path(&path))?;
+        let mut metadata = ScanMetadata::default();
+        if path.exists() {
+            Self::scan_file(&path, &mut metadata)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Self::resume(file, lock, path, metadata, durability, None)
+    }
+
+    /// Open or create a segmented EventLog in directory `dir`, rolling to a
+    /// new segment before a line would take the current one past
+    /// `max_segment_bytes`. A segment always holds at least one line.
+    ///
+    /// Resumes an existing directory from its index, recording the new
+    /// threshold; the index's commit ranges are rebuilt from the segments.
+    pub fn open_segmented(
+        dir: impl Into<PathBuf>,
+        max_segment_bytes: u64,
+        durability: DurabilityPolicy,
+    ) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        let lock = acquire_writer_lock(&writer_lock_path(&dir))?;
+        let mut index = if dir.join(SEGMENT_INDEX_FILE).exists() {
+            read_segment_index(&dir)?
         } else {
-            ScanMetadata::default()
+            SegmentIndex {
+                max_segment_bytes,
+                segments: Vec::new(),
+            }
+        };
+        index.max_segment_bytes = max_segment_bytes;
+
+        let mut metadata = ScanMetadata::default();
+        for entry in &mut index.segments {
+            let segment = dir.join(&entry.file);
+            if segment.exists() {
+                let range = Self::scan_file(&segment, &mut metadata)?;
+                entry.first_commit_index = range.map(|(first, _)| first);
+                entry.last_commit_index = range.map(|(_, last)| last);
+            }
+        }
+        if index.segments.is_empty() {
+            index.segments.push(SegmentEntry {
+                file: segment_file_name(1),
+                first_commit_index: None,
+                last_commit_index: None,
+            });
+        }
+
+        let current = dir.join(&index.segments[index.segments.len() - 1].file);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&current)?;
+        write_segment_index(&dir, &index)?;
+        let segments = Segments {
+            dir: dir.clone(),
+            index,
         };
+        Self::resume(file, lock, dir, metadata, durability, Some(segments))
+    }
+
+    /// Remove the writer lock file of the EventLog at `path`, returning
+    /// whether there was one.
+    ///
+    /// The OS releases the lock when its holder exits, even after a crash,
+    /// so this is only for locks that outlive their holder (e.g. on some
+    /// network filesystems). A writer still holding the old lock is not
+    /// stopped: only call this once the process named in the lock file is
+    /// known to be gone.
+    pub fn force_unlock(path: &Path) -> io::Result<bool> {
+        match fs::remove_file(writer_lock_path(path)) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn resume(
+        file: File,
+        lock: File,
+        path: PathBuf,
+        metadata: ScanMetadata,
+        durability: DurabilityPolicy,
+        segments: Option<Segments>,
+    ) -> io::Result<Self> {
+        let file_len = file.metadata()?.len();
         let next_index = metadata
             .highest_commit_index
             .map_or(0, |highest| highest + 1);
-
-        let file = OpenOptions::new().create(true).append(true).open(&path)?;
-
+        let prev_hash = match metadata.chained {
+            None => Some(GENESIS_PREV_HASH.to_string()),
+            Some(true) => metadata.last_line_hash,
+            Some(false) => None,
+        };
         Ok(EventLogWriter {
             file,
             path,
             next_index,
             source_timestamps: metadata.source_timestamps,
+            skew_tolerance_ns: CLOCK_SKEW_TOLERANCE_NS,
+            prev_hash,
+            durability,
+            unsynced_lines: 0,
+            sync_count: 0,
+            segments,
+            file_len,
+            index: None,
+            batch: None,
+            lock,
         })
     }
 
+    /// Maintain a sidecar byte-offset index (see [`crate::eventlog_index`])
+    /// for the file being appended to (the current segment, if segmented),
+    /// sampling every `interval`-th `commit_index`; later segments get their
+    /// own index as they roll.
+    ///
+    /// The index is rebuilt from the file first, so it never inherits
+    /// gaps from appends made without one.
+    pub fn with_index(mut self, interval: u64) -> io::Result<Self> {
+        let current = self.current_file_path();
+        let index = build_index(&current, interval)?;
+        let file = OpenOptions::new().append(true).open(index_path(&current))?;
+        self.index = Some(IndexSink {
+            file,
+            interval: index.interval,
+        });
+        Ok(self)
+    }
+
+    /// Path of the file being appended to.
+    fn current_file_path(&self) -> PathBuf {
+        match &self.segments {
+            Some(segments) => segments
+                .dir
+                .join(&segments.index.segments[segments.index.segments.len() - 1].file),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Set the backward timestamp delta tolerated before a
+    /// `ClockSkewDetected` event is emitted (default
+    /// [`DEFAULT_CLOCK_SKEW_TOLERANCE`]).
+    ///
+    /// High-jitter sources may need a wider window; a zero tolerance flags
+    /// every backward step.
+    pub fn with_skew_tolerance(mut self, tolerance: Duration) -> Self {
+        self.skew_tolerance_ns = u64::try_from(tolerance.as_nanos()).unwrap_or(u64::MAX);
+        self
+    }
+
+    /// Clock skew tolerance in effect.
+    pub fn skew_tolerance(&self) -> Duration {
+        Duration::from_nanos(self.skew_tolerance_ns)
+    }
+
     /// Append an import event to the EventLog.
     ///
     /// Assigns the next monotonic `commit_index`. May emit
@@ -156,13 +495,111 @@ impl EventLogWriter {
         })
     }
 
+    /// Append several import events with one write and at most one sync.
+    ///
+    /// Each event goes through the same clock skew detection and
+    /// `commit_index` assignment as [`EventLogWriter::append`], in order, so
+    /// the resulting lines are byte-identical to appending one at a time.
+    /// Lines are buffered and written together (plus one write per segment
+    /// roll); Tier A lines and the [`DurabilityPolicy`] are honored with a
+    /// single sync at the end, before this returns.
+    ///
+    /// If an event fails (e.g. it exceeds the max line bytes), the events
+    /// before it are still written, as they would be by repeated `append`.
+    pub fn append_batch(&mut self, events: Vec<ImportEvent>) -> io::Result<Vec<AppendResult>> {
+        self.batch = Some(PendingBatch::default());
+        let mut results = Vec::with_capacity(events.len());
+        let mut appended = Ok(());
+        for event in events {
+            match self.append(event) {
+                Ok(result) => results.push(result),
+                Err(e) => {
+                    appended = Err(e);
+                    break;
+                }
+            }
+        }
+        let flushed = self.finish_batch();
+        appended?;
+        flushed?;
+        Ok(results)
+    }
+
+    /// Write the pending batch, then sync it as its lines require.
+    fn finish_batch(&mut self) -> io::Result<()> {
+        let written = self.write_pending_batch();
+        self.batch = None;
+        if written? {
+            self.file.sync_all()?;
+            self.mark_synced();
+        } else if self.unsynced_lines > 0 && self.policy_sync_due() {
+            self.file.sync_data()?;
+            self.mark_synced();
+        }
+        Ok(())
+    }
+
+    /// Write buffered batch lines and their index entries to the current
+    /// file, keeping the batch open. Returns whether a Tier A line was
+    /// among them.
+    fn write_pending_batch(&mut self) -> io::Result<bool> {
+        let Some(batch) = &mut self.batch else {
+            return Ok(false);
+        };
+        let pending = std::mem::take(batch);
+        self.file.write_all(&pending.lines)?;
+        if let Some(sink) = &mut self.index {
+            for entry in pending.index_entries {
+                sink.file.write_all(&entry_bytes(entry))?;
+            }
+        }
+        Ok(pending.lossless)
+    }
+
+    fn policy_sync_due(&self) -> bool {
+        match self.durability {
+            DurabilityPolicy::EveryAppend => true,
+            DurabilityPolicy::EveryN(n) => self.unsynced_lines >= n.max(1),
+            DurabilityPolicy::OnDrop => false,
+        }
+    }
+
+    /// Flush every appended line to stable storage.
+    ///
+    /// Tier A appends are already synced; this also covers Tier B/C lines.
+    /// Used by long-running writers so concurrent readers see durable data.
+    pub fn sync(&mut self) -> io::Result<()> {
+        self.file.sync_all()?;
+        self.mark_synced();
+        if let Some(segments) = &self.segments {
+            write_segment_index(&segments.dir, &segments.index)?;
+        }
+        Ok(())
+    }
+
+    /// Durability policy in effect.
+    pub fn durability(&self) -> DurabilityPolicy {
+        self.durability
+    }
+
+    /// Number of syncs issued so far, including Tier A syncs and explicit
+    /// [`EventLogWriter::sync`] calls. Excludes the sync on drop.
+    pub fn sync_count(&self) -> u64 {
+        self.sync_count
+    }
+
+    fn mark_synced(&mut self) {
+        self.unsynced_lines = 0;
+        self.sync_count += 1;
+    }
+
     /// The `commit_index` that will be assigned to the next appended event.
     #[allow(dead_code)] // Will be used for recovery/resume
     pub(crate) fn next_index(&self) -> u64 {
         self.next_index
     }
 
-    /// Path to the EventLog file.
+    /// Path to the EventLog file, or the segment directory.
     #[allow(dead_code)] // Will be used for recovery/reload
     pub(crate) fn path(&self) -> &Path {
         &self.path
@@ -170,88 +607,131 @@ impl EventLogWriter {
 
     /// Commit and write a single event to the JSONL file.
     fn write_committed(&mut self, event: ImportEvent) -> io::Result<CommittedEvent> {
-        let committed = CommittedEvent::commit(event, self.next_index);
-        let mut line = serde_json::to_string(&committed).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("event serialization failed: {e}"),
-            )
-        })?;
-
-        // Line size check (before adding newline).
-        if line.len() > EVENTLOG_MAX_LINE_BYTES {
-            return Err(io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!(
-                    "serialized event exceeds max line bytes ({} > {})",
-                    line.len(),
-                    EVENTLOG_MAX_LINE_BYTES
-                ),
-            ));
-        }
-
+        let mut committed = CommittedEvent::commit(event, self.next_index);
+        committed.prev_hash = self.prev_hash.clone();
+        let mut line = serialize_line(&committed)?;
+        let line_hash = blake3::hash(line.as_bytes()).to_hex().to_string();
         line.push('\n');
-        self.file.write_all(line.as_bytes())?;
+        self.roll_segment_if_full(line.len() as u64)?;
+        let offset = self.file_len;
+        let index_entry = self
+            .index
+            .as_ref()
+            .filter(|sink| is_sampled(sink.interval, committed.commit_index, offset == 0))
+            .map(|_| IndexEntry {
+                commit_index: committed.commit_index,
+                offset,
+            });
+        if let Some(batch) = &mut self.batch {
+            batch.lines.extend_from_slice(line.as_bytes());
+            batch.index_entries.extend(index_entry);
+        } else {
+            self.file.write_all(line.as_bytes())?;
+            // The entry follows its line, so a torn write can only drop an
+            // entry.
+            if let (Some(sink), Some(entry)) = (&mut self.index, index_entry) {
+                sink.file.write_all(&entry_bytes(entry))?;
+            }
+        }
+        self.file_len += line.len() as u64;
+        if let Some(segments) = &mut self.segments {
+            if let Some(entry) = segments.index.segments.last_mut() {
+                entry
+                    .first_commit_index
+                    .get_or_insert(committed.commit_index);
+                entry.last_commit_index = Some(committed.commit_index);
+            }
+        }
 
-        // Fsync per Tier A event.
-        if committed.tier.is_lossless() {
+        // Fsync per Tier A event; Tier B/C per durability policy. A batch
+        // defers both to its end.
+        self.unsynced_lines = self.unsynced_lines.saturating_add(1);
+        if let Some(batch) = &mut self.batch {
+            batch.lossless |= committed.tier.is_lossless();
+        } else if committed.tier.is_lossless() {
             self.file.sync_all()?;
+            self.mark_synced();
+        } else if self.policy_sync_due() {
+            self.file.sync_data()?;
+            self.mark_synced();
         }
 
         self.next_index += 1;
+        if self.prev_hash.is_some() {
+            self.prev_hash = Some(line_hash);
+        }
         Ok(committed)
     }
 
+    /// Start a new segment if appending `line_bytes` would take a non-empty
+    /// current segment past the threshold.
+    ///
+    /// The new segment file is created before the index lists it, so an
+    /// interrupted roll leaves at most an empty, unlisted file.
+    fn roll_segment_if_full(&mut self, line_bytes: u64) -> io::Result<()> {
+        let Some(segments) = &mut self.segments else {
+            return Ok(());
+        };
+        if self.file_len == 0 || self.file_len + line_bytes <= segments.index.max_segment_bytes {
+            return Ok(());
+        }
+        let file_name = segment_file_name(segments.index.segments.len() + 1);
+        let next_path = segments.dir.join(&file_name);
+        // Buffered batch lines belong to the segment being closed.
+        let lossless = self.write_pending_batch()?;
+        let Some(segments) = &mut self.segments else {
+            return Ok(());
+        };
+        let next = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&next_path)?;
+        if let Some(sink) = &mut self.index {
+            let mut index_file = File::create(index_path(&next_path))?;
+            index_file.write_all(&header_bytes(sink.interval))?;
+            sink.file = index_file;
+        }
+        segments.index.segments.push(SegmentEntry {
+            file: file_name,
+            first_commit_index: None,
+            last_commit_index: None,
+        });
+        write_segment_index(&segments.dir, &segments.index)?;
+        self.file_len = 0;
+
+        let previous = std::mem::replace(&mut self.file, next);
+        if lossless {
+            previous.sync_all()?;
+            self.mark_synced();
+        } else if self.unsynced_lines > 0 {
+            previous.sync_data()?;
+            self.mark_synced();
+        }
+        Ok(())
+    }
+
     /// Check for clock skew and return a `ClockSkewDetected` import event
     /// if the source's timestamp moved backward beyond tolerance.
     fn check_clock_skew(&mut self, event: &ImportEvent) -> Option<ImportEvent> {
-        let last_ts = self
-            .source_timestamps
-            .get(&event.source_id)
-            .copied()
-            .unwrap_or(0);
-
-        // Update last-seen timestamp (even if skewed, we track the latest
-        // seen value to avoid repeated detections for the same plateau).
-        if event.timestamp_ns > last_ts {
-            self.source_timestamps
-                .insert(event.source_id.clone(), event.timestamp_ns);
-        }
-
-        // Detect backward movement beyond tolerance.
-        if last_ts > 0 && event.timestamp_ns < last_ts {
-            let delta = last_ts - event.timestamp_ns;
-            if delta > CLOCK_SKEW_TOLERANCE_NS {
-                return Some(ImportEvent {
-                    run_id: event.run_id.clone(),
-                    event_id: format!("clock-skew:{}:{}", event.source_id, self.next_index),
-                    source_id: event.source_id.clone(),
-                    source_seq: None,
-                    timestamp_ns: event.timestamp_ns,
-                    tier: Tier::A,
-                    payload: EventPayload::ClockSkewDetected {
-                        expected_ns: last_ts,
-                        actual_ns: event.timestamp_ns,
-                        delta_ns: delta,
-                    },
-                    payload_ref: None,
-                    synthesized: true,
-                });
-            }
-        }
-
-        None
+        clock_skew_event(
+            &mut self.source_timestamps,
+            self.skew_tolerance_ns,
+            event,
+            self.next_index,
+        )
     }
 
-    /// Scan existing EventLog data needed to resume writer state.
+    /// Scan one existing EventLog file for the data needed to resume writer
+    /// state, returning its first and last `commit_index`.
     ///
-    /// Includes:
+    /// Accumulates into `metadata`, so segments are scanned in order:
     /// - highest committed index for monotonic continuation
     /// - latest timestamp per source for skew detection across restarts
-    fn scan_metadata(path: &Path) -> io::Result<ScanMetadata> {
+    /// - whether the log is hash-chained, and the hash of its last line
+    fn scan_file(path: &Path, metadata: &mut ScanMetadata) -> io::Result<Option<(u64, u64)>> {
         let file = File::open(path)?;
         let reader = BufReader::new(file);
-        let mut metadata = ScanMetadata::default();
+        let mut range = None;
 
         for (line_no, line) in reader.lines().enumerate() {
             let line = line?;
@@ -265,8 +745,9 @@ impl EventLogWriter {
                 io::Error::new(
                     io::ErrorKind::InvalidData,
                     format!(
-                        "failed to parse EventLog line {} while resuming writer: {e}",
-                        line_no + 1
+                        "failed to parse EventLog line {} of {} while resuming writer: {e}",
+                        line_no + 1,
+                        path.display()
                     ),
                 )
             })?;
@@ -274,491 +755,2402 @@ impl EventLogWriter {
                 Some(h) => h.max(event.commit_index),
                 None => event.commit_index,
             });
+            range = Some((
+                range.map_or(event.commit_index, |(first, _)| first),
+                event.commit_index,
+            ));
             metadata
                 .source_timestamps
                 .entry(event.source_id)
                 .and_modify(|existing| *existing = (*existing).max(event.timestamp_ns))
                 .or_insert(event.timestamp_ns);
+            metadata.chained.get_or_insert(event.prev_hash.is_some());
+            metadata.last_line_hash = Some(blake3::hash(trimmed.as_bytes()).to_hex().to_string());
         }
 
-        Ok(metadata)
+        Ok(range)
     }
 }
 
-/// Read all committed events from an EventLog file.
-///
-/// Returns events in file order (which should be `commit_index` order).
-pub fn read_eventlog(path: &Path) -> io::Result<Vec<CommittedEvent>> {
-    let content = fs::read_to_string(path)?;
-    let mut events = Vec::new();
-    for line in content.lines() {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
+impl Drop for EventLogWriter {
+    /// Sync lines written since the last sync. Errors cannot be reported
+    /// from drop; call [`EventLogWriter::sync`] first to observe them.
+    fn drop(&mut self) {
+        if self.unsynced_lines > 0 {
+            let _ = self.file.sync_data();
+        }
+        if let Some(segments) = &self.segments {
+            let _ = write_segment_index(&segments.dir, &segments.index);
         }
-        let event: CommittedEvent = serde_json::from_str(trimmed).map_err(|e| {
-            io::Error::new(
-                io::ErrorKind::InvalidData,
-                format!("failed to parse EventLog line: {e}"),
-            )
-        })?;
-        events.push(event);
     }
-    Ok(events)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::event::{EventPayload, ImportEvent, Tier};
-
-    /// Helper to create a minimal ImportEvent.
-    fn make_event(source_id: &str, timestamp_ns: u64) -> ImportEvent {
-        ImportEvent {
-            run_id: "run-1".into(),
-            event_id: format!("{source_id}:{timestamp_ns}"),
-            source_id: source_id.into(),
-            source_seq: Some(0),
-            timestamp_ns,
-            tier: Tier::A,
-            payload: EventPayload::RunStart {
-                agent: "test".into(),
-                args: None,
-            },
-            payload_ref: None,
-            synthesized: false,
-        }
+/// Serialize `committed` as one EventLog line, without the newline.
+/// Fails with `InvalidData` past [`EVENTLOG_MAX_LINE_BYTES`].
+fn serialize_line(committed: &CommittedEvent) -> io::Result<String> {
+    let line = serde_json::to_string(committed).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("event serialization failed: {e}"),
+        )
+    })?;
+    if line.len() > EVENTLOG_MAX_LINE_BYTES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "serialized event exceeds max line bytes ({} > {})",
+                line.len(),
+                EVENTLOG_MAX_LINE_BYTES
+            ),
+        ));
     }
+    Ok(line)
+}
 
-    // -------------------------------------------------------------------
-    // M2.4: Append writer monotonicity tests
-    // -------------------------------------------------------------------
-
-    #[test]
-    fn append_1000_events_monotonic() {
-        let dir = tempfile::tempdir().unwrap();
-        let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+/// The `ClockSkewDetected` import event to commit at `next_index` before
+/// `event`, if its source's timestamp moved backward by more than
+/// `tolerance_ns` since the latest one in `source_timestamps`.
+fn clock_skew_event(
+    source_timestamps: &mut HashMap<String, u64>,
+    tolerance_ns: u64,
+    event: &ImportEvent,
+    next_index: u64,
+) -> Option<ImportEvent> {
+    let last_ts = source_timestamps
+        .get(&event.source_id)
+        .copied()
+        .unwrap_or(0);
+
+    // Update last-seen timestamp (even if skewed, we track the latest
+    // seen value to avoid repeated detections for the same plateau).
+    if event.timestamp_ns > last_ts {
+        source_timestamps.insert(event.source_id.clone(), event.timestamp_ns);
+    }
 
-        for i in 0..1000 {
-            let event = ImportEvent {
-                run_id: "run-1".into(),
-                event_id: format!("e-{i}"),
-                source_id: "test".into(),
-                source_seq: Some(i),
-                timestamp_ns: 1_000_000_000 + i * 1_000_000,
+    // Detect backward movement beyond tolerance.
+    if last_ts > 0 && event.timestamp_ns < last_ts {
+        let delta = last_ts - event.timestamp_ns;
+        if delta > tolerance_ns {
+            return Some(ImportEvent {
+                run_id: event.run_id.clone(),
+                event_id: format!("clock-skew:{}:{next_index}", event.source_id),
+                source_id: event.source_id.clone(),
+                source_seq: None,
+                timestamp_ns: event.timestamp_ns,
                 tier: Tier::A,
-                payload: EventPayload::ToolCall {
-                    tool: "bash".into(),
-                    args: Some(format!("cmd-{i}")),
+                payload: EventPayload::ClockSkewDetected {
+                    expected_ns: last_ts,
+                    actual_ns: event.timestamp_ns,
+                    delta_ns: delta,
                 },
                 payload_ref: None,
-                synthesized: false,
-            };
-            let result = writer.append(event).unwrap();
-            assert_eq!(result.committed.commit_index, i);
-        }
-
-        // Read back and verify full sequence.
-        let events = read_eventlog(&path).unwrap();
-        assert_eq!(events.len(), 1000);
-        for (i, event) in events.iter().enumerate() {
-            assert_eq!(
-                event.commit_index, i as u64,
-                "event {i} has wrong commit_index"
-            );
+                synthesized: true,
+            });
         }
     }
 
-    #[test]
-    fn resume_from_existing_eventlog() {
-        let dir = tempfile::tempdir().unwrap();
-        let path = dir.path().join("eventlog.jsonl");
+    None
+}
 
-        // Write 10 events.
-        {
-            let mut writer = EventLogWriter::open(&path).unwrap();
-            for i in 0..10 {
-                let event = ImportEvent {
-                    run_id: "run-1".into(),
-                    event_id: format!("e-{i}"),
-                    source_id: "test".into(),
-                    source_seq: Some(i),
-                    timestamp_ns: 1_000_000_000 + i * 1_000_000,
-                    tier: Tier::A,
-                    payload: EventPayload::RunStart {
-                        agent: "test".into(),
-                        args: None,
-                    },
-                    payload_ref: None,
-                    synthesized: false,
-                };
-                writer.append(event).unwrap();
-            }
+/// Commit `events` in memory exactly as appending them to a new EventLog
+/// would: same `commit_index` values, `ClockSkewDetected` events, and
+/// hash chain, so the result equals reading that EventLog back. Nothing is
+/// written to disk.
+///
+/// Fails like [`EventLogWriter::append`] on an event over the max line
+/// bytes.
+pub fn commit_in_memory(events: Vec<ImportEvent>) -> io::Result<Vec<CommittedEvent>> {
+    let mut source_timestamps = HashMap::new();
+    let mut prev_hash = GENESIS_PREV_HASH.to_string();
+    let mut committed = Vec::with_capacity(events.len());
+    for event in events {
+        let next_index = committed.len() as u64;
+        let skew = clock_skew_event(
+            &mut source_timestamps,
+            CLOCK_SKEW_TOLERANCE_NS,
+            &event,
+            next_index,
+        );
+        for event in skew.into_iter().chain([event]) {
+            let mut next = CommittedEvent::commit(event, committed.len() as u64);
+            next.prev_hash = Some(prev_hash);
+            let line = serialize_line(&next)?;
+            prev_hash = blake3::hash(line.as_bytes()).to_hex().to_string();
+            committed.push(next);
         }
-
-        // Re-open and continue.
-        let mut writer = EventLogWriter::open(&path).unwrap();
-        assert_eq!(writer.next_index(), 10);
-
-        let result = writer.append(make_event("test", 2_000_000_000)).unwrap();
-        assert_eq!(result.committed.commit_index, 10);
     }
+    Ok(committed)
+}
 
-    #[test]
-    fn new_eventlog_starts_at_zero() {
-        let dir = tempfile::tempdir().unwrap();
-        let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
-        assert_eq!(writer.next_index(), 0);
-
-        let result = writer.append(make_event("test", 1_000_000_000)).unwrap();
-        assert_eq!(result.committed.commit_index, 0);
+/// Writer lock file of the EventLog at `path`: `<path>.lock` for a single
+/// file, [`WRITER_LOCK_FILE`] inside a segment directory.
+pub fn writer_lock_path(path: &Path) -> PathBuf {
+    match segment_dir(path) {
+        Some(dir) => dir.join(WRITER_LOCK_FILE),
+        None => {
+            let mut name = path.as_os_str().to_owned();
+            name.push(".lock");
+            PathBuf::from(name)
+        }
     }
+}
 
-    #[test]
+/// Take the exclusive advisory lock on `lock_path` without blocking and
+/// record this process's pid in it.
+///
+/// Fails with `WouldBlock`, naming the holder's pid, if another writer
+/// holds the lock — including one in this process.
+fn acquire_writer_lock(lock_path: &Path) -> io::Result<File> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(lock_path)?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(fs::TryLockError::WouldBlock) => {
+            let mut holder = String::new();
+            let _ = file.read_to_string(&mut holder);
+            let holder = match holder.trim() {
+                "" => "another process".to_string(),
+                pid => format!("another process (pid {pid})"),
+            };
+            return Err(io::Error::new(
+                io::ErrorKind::WouldBlock,
+                format!(
+                    "EventLog is locked by {holder}; remove {} only if that process is gone",
+                    lock_path.display()
+                ),
+            ));
+        }
+        Err(fs::TryLockError::Error(e)) => return Err(e),
+    }
+    file.set_len(0)?;
+    writeln!(file, "{}", std::process::id())?;
+    Ok(file)
+}
+
+/// Read the [`SegmentIndex`] of segmented EventLog directory `dir`.
+///
+/// Fails with `InvalidData` if the index does not parse or names a segment
+/// outside `dir`.
+pub fn read_segment_index(dir: &Path) -> io::Result<SegmentIndex> {
+    let path = dir.join(SEGMENT_INDEX_FILE);
+    let bytes = fs::read(&path)?;
+    let index: SegmentIndex = serde_json::from_slice(&bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse segment index {}: {e}", path.display()),
+        )
+    })?;
+    for entry in &index.segments {
+        let name = Path::new(&entry.file);
+        if name.file_name() != Some(name.as_os_str()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "segment index {} names a file outside its directory: {}",
+                    path.display(),
+                    entry.file
+                ),
+            ));
+        }
+    }
+    Ok(index)
+}
+
+/// Atomically replace the segment index in `dir`.
+fn write_segment_index(dir: &Path, index: &SegmentIndex) -> io::Result<()> {
+    let mut bytes = serde_json::to_vec_pretty(index).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("segment index serialization failed: {e}"),
+        )
+    })?;
+    bytes.push(b'\n');
+    let tmp = dir.join(format!("{SEGMENT_INDEX_FILE}.tmp"));
+    fs::write(&tmp, bytes)?;
+    fs::rename(tmp, dir.join(SEGMENT_INDEX_FILE))
+}
+
+/// Files making up the EventLog at `path`, in commit order.
+///
+/// `path` may be a segmented EventLog directory, its
+/// [`SEGMENT_INDEX_FILE`], or a single EventLog file (returned as is).
+pub fn eventlog_segments(path: &Path) -> io::Result<Vec<PathBuf>> {
+    let Some(dir) = segment_dir(path) else {
+        return Ok(vec![path.to_path_buf()]);
+    };
+    let index = read_segment_index(dir)?;
+    Ok(index.segments.iter().map(|s| dir.join(&s.file)).collect())
+}
+
+/// Whether `path` is a gzip-compressed EventLog file: named `*.gz` or
+/// starting with the gzip magic bytes. A missing file is not.
+pub fn is_gzip_eventlog(path: &Path) -> io::Result<bool> {
+    if path.extension() == Some(OsStr::new("gz")) {
+        return Ok(true);
+    }
+    match File::open(path) {
+        Ok(mut file) => has_gzip_magic(&mut file),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Whether `file` starts with the gzip magic bytes; leaves it rewound.
+fn has_gzip_magic(file: &mut File) -> io::Result<bool> {
+    let mut magic = Vec::with_capacity(GZIP_MAGIC.len());
+    Read::by_ref(file)
+        .take(GZIP_MAGIC.len() as u64)
+        .read_to_end(&mut magic)?;
+    file.seek(SeekFrom::Start(0))?;
+    Ok(magic == GZIP_MAGIC)
+}
+
+/// Buffered reader over the JSONL content of one EventLog file,
+/// decompressing it if gzip-compressed. See [`open_eventlog_file`].
+pub struct EventLogFile {
+    inner: Box<dyn BufRead + Send>,
+}
+
+impl std::fmt::Debug for EventLogFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventLogFile").finish_non_exhaustive()
+    }
+}
+
+impl Read for EventLogFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+impl BufRead for EventLogFile {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt)
+    }
+}
+
+/// Open one EventLog file (not a segment directory) for reading its JSONL
+/// lines, transparently decompressing it when [`is_gzip_eventlog`] holds.
+/// Concatenated gzip members are read as one stream.
+pub fn open_eventlog_file(path: &Path) -> io::Result<EventLogFile> {
+    let mut file = File::open(path)?;
+    let inner: Box<dyn BufRead + Send> =
+        if has_gzip_magic(&mut file)? || path.extension() == Some(OsStr::new("gz")) {
+            Box::new(BufReader::new(MultiGzDecoder::new(file)))
+        } else {
+            Box::new(BufReader::new(file))
+        };
+    Ok(EventLogFile { inner })
+}
+
+/// Segment directory named by `path`, or `None` for a single EventLog file.
+fn segment_dir(path: &Path) -> Option<&Path> {
+    if path.is_dir() {
+        Some(path)
+    } else if path.file_name() == Some(OsStr::new(SEGMENT_INDEX_FILE)) {
+        Some(path.parent().unwrap_or(Path::new("")))
+    } else {
+        None
+    }
+}
+
+/// Read all committed events from an EventLog file or segment directory.
+///
+/// Returns events in file order (which should be `commit_index` order).
+/// Collects an [`EventLogReader`], failing on the first bad line with
+/// [`VifeiError::MalformedEvent`].
+pub fn read_eventlog(path: &Path) -> Result<Vec<CommittedEvent>, VifeiError> {
+    Ok(EventLogReader::open(path)?.collect::<io::Result<_>>()?)
+}
+
+/// Events read by [`read_eventlog_recovering`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadOutcome {
+    /// Committed events of the valid prefix, in file order.
+    pub events: Vec<CommittedEvent>,
+    /// Length of the partial final record that was dropped, if the log
+    /// ends with one.
+    pub truncated_tail_bytes: Option<u64>,
+    /// `commit_index` sequence check of the events returned.
+    pub integrity: EventLogIntegrityReport,
+}
+
+impl ReadOutcome {
+    /// Whether the log ends with a truncated record.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated_tail_bytes.is_some()
+    }
+}
+
+/// Read all committed events, tolerating a record torn by a crash
+/// mid-append.
+///
+/// A final line with no terminating newline that fails to parse is dropped
+/// and reported in [`ReadOutcome::truncated_tail_bytes`]; the events before
+/// it are returned. Any other bad line is still an error, as in
+/// [`read_eventlog`].
+pub fn read_eventlog_recovering(path: &Path) -> io::Result<ReadOutcome> {
+    let mut reader = EventLogReader::open(path)?.with_tail_recovery();
+    let events = reader.by_ref().collect::<io::Result<Vec<_>>>()?;
+    Ok(ReadOutcome {
+        events,
+        truncated_tail_bytes: reader.truncated_tail_bytes(),
+        integrity: *reader.integrity(),
+    })
+}
+
+/// Stream committed events from an EventLog file or segment directory one
+/// line at a time.
+///
+/// Same as [`EventLogReader::open`].
+pub fn read_eventlog_iter(path: &Path) -> io::Result<EventLogReader> {
+    EventLogReader::open(path)
+}
+
+/// Streaming EventLog reader: an iterator of committed events.
+///
+/// Reads and deserializes one line at a time into a single reusable
+/// buffer, so memory stays bounded by the longest line rather than the
+/// file size. Segments are read in index order as if concatenated. Blank
+/// lines are skipped. A line that fails to parse yields an `Err` naming its
+/// 1-based line number and iteration continues with the next line, so
+/// callers decide whether to stop or skip. A read error ends the iteration
+/// after it is yielded. With [`EventLogReader::with_tail_recovery`], a
+/// truncated final record ends the iteration instead of yielding an `Err`.
+/// Payloads of unknown type are read as `Generic` and counted in
+/// [`EventLogIntegrityReport::unknown_payloads`] (see
+/// [`CommittedEvent::from_jsonl_line`]).
+#[derive(Debug)]
+pub struct EventLogReader {
+    reader: Option<EventLogFile>,
+    pending: VecDeque<PathBuf>,
+    buf: Vec<u8>,
+    line_num: usize,
+    done: bool,
+    recover_tail: bool,
+    truncated_tail_bytes: Option<u64>,
+    integrity: IntegrityTracker,
+    /// Problem of the most recently yielded event, for [`validate_eventlog`].
+    last_issue: Option<EventLogIssueKind>,
+}
+
+impl EventLogReader {
+    /// Open `path` (see [`eventlog_segments`]) for reading from its first
+    /// line. Only the first segment is opened up front.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Self::from_segments(eventlog_segments(path)?)
+    }
+
+    fn from_segments(segments: Vec<PathBuf>) -> io::Result<Self> {
+        let mut pending = VecDeque::from(segments);
+        let reader = match pending.pop_front() {
+            Some(first) => Some(open_eventlog_file(&first)?),
+            None => None,
+        };
+        Ok(Self::from_parts(reader, pending))
+    }
+
+    fn from_parts(reader: Option<EventLogFile>, pending: VecDeque<PathBuf>) -> Self {
+        EventLogReader {
+            reader,
+            pending,
+            buf: Vec::new(),
+            line_num: 0,
+            done: false,
+            recover_tail: false,
+            truncated_tail_bytes: None,
+            integrity: IntegrityTracker::default(),
+            last_issue: None,
+        }
+    }
+
+    /// Treat a final line with no terminating newline that fails to parse
+    /// as a record torn by a crash: end the iteration and report its length
+    /// via [`EventLogReader::truncated_tail_bytes`] instead of yielding an
+    /// `Err`.
+    pub fn with_tail_recovery(mut self) -> Self {
+        self.recover_tail = true;
+        self
+    }
+
+    /// Length of the truncated final record, once one has been reached.
+    /// Detected with or without tail recovery.
+    pub fn truncated_tail_bytes(&self) -> Option<u64> {
+        self.truncated_tail_bytes
+    }
+
+    /// 1-based physical line number of the most recently yielded item,
+    /// counted across segments.
+    pub fn line_number(&self) -> usize {
+        self.line_num
+    }
+
+    /// `commit_index` sequence check of the events yielded so far, which
+    /// are expected to start at 0. Unparseable lines are not counted.
+    pub fn integrity(&self) -> &EventLogIntegrityReport {
+        &self.integrity.report
+    }
+}
+
+impl Iterator for EventLogReader {
+    type Item = io::Result<CommittedEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let Some(reader) = self.reader.as_mut() else {
+                match self.pending.pop_front().map(|p| open_eventlog_file(&p)) {
+                    None => self.done = true,
+                    Some(Ok(file)) => self.reader = Some(file),
+                    Some(Err(e)) => {
+                        self.done = true;
+                        return Some(Err(e));
+                    }
+                }
+                continue;
+            };
+            self.buf.clear();
+            match reader.read_until(b'\n', &mut self.buf) {
+                Ok(0) => self.reader = None,
+                Ok(_) => {
+                    self.line_num += 1;
+                    let trimmed = self.buf.trim_ascii();
+                    if trimmed.is_empty() {
+                        continue;
+                    }
+                    let parsed = CommittedEvent::from_jsonl_line(trimmed);
+                    // No newline means EOF of this file; only the last
+                    // file's tail can be a torn append.
+                    if parsed.is_err() && !self.buf.ends_with(b"\n") && self.pending.is_empty() {
+                        self.truncated_tail_bytes = Some(self.buf.len() as u64);
+                        if self.recover_tail {
+                            self.done = true;
+                            return None;
+                        }
+                    }
+                    self.last_issue = None;
+                    if let Ok((event, unknown)) = &parsed {
+                        self.last_issue = self.integrity.observe(event.commit_index);
+                        if unknown.is_some() {
+                            self.integrity.report.unknown_payloads += 1;
+                        }
+                    }
+                    return Some(parsed.map(|(event, _)| event).map_err(|e| {
+                        VifeiError::MalformedEvent {
+                            line: self.line_num,
+                            message: e.to_string(),
+                        }
+                        .into()
+                    }));
+                }
+                Err(e) => {
+                    self.line_num += 1;
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Read the committed events with `from <= commit_index <= to`.
+///
+/// Parsing stops at the first event at or past `to`. For a segmented EventLog,
+/// segments whose indexed range ends before `from` are skipped without
+/// being opened. Within the first file read, the sidecar index (see
+/// [`crate::eventlog_index`]) is used to seek near `from` unless the file
+/// is gzip-compressed; a missing or stale index falls back to a linear
+/// scan from the start of the file and is left as found. Bounds outside
+/// the log yield an empty vec; `from > to` is an `InvalidInput` error.
+pub fn read_eventlog_range(path: &Path, from: u64, to: u64) -> io::Result<Vec<CommittedEvent>> {
+    if from > to {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("inverted commit_index range: from {from} > to {to}"),
+        ));
+    }
+    let segments = match segment_dir(path) {
+        None => vec![path.to_path_buf()],
+        Some(dir) => {
+            let index = read_segment_index(dir)?;
+            let last = index.segments.len().saturating_sub(1);
+            // The last segment's range may lag its file until the next
+            // sync, so it is never skipped.
+            index
+                .segments
+                .iter()
+                .enumerate()
+                .skip_while(|(i, s)| *i < last && s.last_commit_index.is_some_and(|l| l < from))
+                .take_while(|(_, s)| s.first_commit_index.is_none_or(|f| f <= to))
+                .map(|(_, s)| dir.join(&s.file))
+                .collect()
+        }
+    };
+
+    let mut pending = VecDeque::from(segments);
+    let first = match pending.pop_front() {
+        Some(first) if is_gzip_eventlog(&first)? => Some(open_eventlog_file(&first)?),
+        Some(first) => Some(EventLogFile {
+            inner: Box::new(open_near(&first, from)?),
+        }),
+        None => None,
+    };
+    let mut events = Vec::new();
+    for event in EventLogReader::from_parts(first, pending) {
+        let event = event?;
+        let index = event.commit_index;
+        if index >= from && index <= to {
+            events.push(event);
+        }
+        if index >= to {
+            break;
+        }
+    }
+    Ok(events)
+}
+
+/// Kind of problem found by [`validate_eventlog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventLogIssueKind {
+    /// One or more `commit_index` values were skipped.
+    Gap,
+    /// A `commit_index` value appeared on an earlier line too.
+    Duplicate,
+    /// A `commit_index` lower than the highest seen so far that is not a
+    /// duplicate (e.g. lines swapped).
+    NonMonotonic,
+    /// The line is not a valid committed event.
+    Unparseable,
+}
+
+/// One problem found by [`validate_eventlog`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventLogIssue {
+    /// 1-based physical line number of the offending line.
+    pub line: usize,
+    /// Problem kind.
+    pub kind: EventLogIssueKind,
+    /// `commit_index` on the offending line, if it parsed.
+    pub commit_index: Option<u64>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Result of [`validate_eventlog`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventLogValidation {
+    /// Committed events parsed.
+    pub events_checked: usize,
+    /// Highest `commit_index` seen, if any event parsed.
+    pub highest_commit_index: Option<u64>,
+    /// Problems in file order. Empty means the log is dense and monotonic.
+    pub issues: Vec<EventLogIssue>,
+    /// Per-kind counts of the `commit_index` problems in `issues`.
+    pub integrity: EventLogIntegrityReport,
+}
+
+impl EventLogValidation {
+    /// True when no issue was found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Counts of `commit_index` sequence problems per [`EventLogIssueKind`].
+///
+/// Computed as a by-product of [`EventLogReader`] (see
+/// [`EventLogReader::integrity`]), which also classifies the issues of
+/// [`validate_eventlog`]; memory grows with the number of problems, not the
+/// number of events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct EventLogIntegrityReport {
+    /// Committed events checked.
+    pub total_events: u64,
+    /// [`EventLogIssueKind::Gap`] issues.
+    pub gaps: u64,
+    /// [`EventLogIssueKind::Duplicate`] issues.
+    pub duplicates: u64,
+    /// [`EventLogIssueKind::NonMonotonic`] issues.
+    pub non_monotonic: u64,
+    /// Events whose payload `type` is unknown to this build (written by a
+    /// newer schema version), read as `Generic`. A warning, not a sequence
+    /// problem: [`EventLogIntegrityReport::is_clean`] ignores it. Omitted
+    /// from JSON when 0.
+    #[serde(skip_serializing_if = "is_zero")]
+    pub unknown_payloads: u64,
+}
+
+/// Helper for `#[serde(skip_serializing_if)]` on counters.
+fn is_zero(n: &u64) -> bool {
+    *n == 0
+}
+
+impl EventLogIntegrityReport {
+    /// Check a `commit_index` sequence that should run 0, 1, 2, ...
+    pub fn from_commit_indices(indices: impl IntoIterator<Item = u64>) -> Self {
+        let mut tracker = IntegrityTracker::default();
+        for index in indices {
+            tracker.observe(index);
+        }
+        tracker.report
+    }
+
+    /// True when the sequence is dense and monotonic.
+    pub fn is_clean(&self) -> bool {
+        self.gaps == 0 && self.duplicates == 0 && self.non_monotonic == 0
+    }
+
+    fn record(&mut self, kind: EventLogIssueKind) {
+        match kind {
+            EventLogIssueKind::Gap => self.gaps += 1,
+            EventLogIssueKind::Duplicate => self.duplicates += 1,
+            EventLogIssueKind::NonMonotonic => self.non_monotonic += 1,
+            EventLogIssueKind::Unparseable => {}
+        }
+    }
+}
+
+/// Incremental [`EventLogIntegrityReport`] builder.
+///
+/// Every index below `next` was seen unless it lies in a recorded gap, so
+/// only gaps and the out-of-order fills of them are kept.
+#[derive(Debug, Default)]
+struct IntegrityTracker {
+    report: EventLogIntegrityReport,
+    next: u64,
+    /// Skipped index ranges `(first, last)`, in increasing order.
+    missing: Vec<(u64, u64)>,
+    filled: HashSet<u64>,
+}
+
+impl IntegrityTracker {
+    /// Count `index` and return the problem it is, if any.
+    fn observe(&mut self, index: u64) -> Option<EventLogIssueKind> {
+        self.report.total_events += 1;
+        let kind = if index >= self.next {
+            let gap = index > self.next;
+            if gap {
+                self.missing.push((self.next, index - 1));
+            }
+            self.next = index + 1;
+            gap.then_some(EventLogIssueKind::Gap)
+        } else {
+            let after = self.missing.partition_point(|&(first, _)| first <= index);
+            let in_gap = after
+                .checked_sub(1)
+                .is_some_and(|i| index <= self.missing[i].1);
+            if in_gap && self.filled.insert(index) {
+                Some(EventLogIssueKind::NonMonotonic)
+            } else {
+                Some(EventLogIssueKind::Duplicate)
+            }
+        };
+        if let Some(kind) = kind {
+            self.report.record(kind);
+        }
+        kind
+    }
+}
+
+/// Check that an EventLog's `commit_index` values are dense, start at 0,
+/// and increase by exactly 1 per line.
+///
+/// Gaps, duplicates, out-of-order indices, and unparseable lines are
+/// reported with their line numbers rather than failing the read, so one
+/// pass surfaces every problem. Only opening or reading the file is an
+/// error.
+pub fn validate_eventlog(path: &Path) -> io::Result<EventLogValidation> {
+    let mut iter = EventLogReader::open(path)?;
+    let mut first_seen: HashMap<u64, usize> = HashMap::new();
+    let mut events_checked = 0;
+    let mut highest_commit_index = None;
+    let mut issues = Vec::new();
+
+    while let Some(result) = iter.next() {
+        let line = iter.line_number();
+        let event = match result {
+            Ok(event) => event,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData => {
+                issues.push(EventLogIssue {
+                    line,
+                    kind: EventLogIssueKind::Unparseable,
+                    commit_index: None,
+                    message: e.to_string(),
+                });
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        events_checked += 1;
+        let index = event.commit_index;
+        let first_line = *first_seen.entry(index).or_insert(line);
+
+        // The reader classified the event; only the messages are built here.
+        if let Some(kind) = iter.last_issue {
+            let message = match kind {
+                EventLogIssueKind::Duplicate => {
+                    format!("duplicate commit_index {index} (first at line {first_line})")
+                }
+                EventLogIssueKind::Gap => match iter.integrity.missing.last() {
+                    Some(&(first, last)) if first == last => {
+                        format!("missing commit_index {first} before {index}")
+                    }
+                    Some(&(first, last)) => {
+                        format!("missing commit_index {first}..={last} before {index}")
+                    }
+                    None => format!("missing commit_index before {index}"),
+                },
+                _ => format!(
+                    "commit_index {index} after {}",
+                    highest_commit_index.unwrap_or(0)
+                ),
+            };
+            issues.push(EventLogIssue {
+                line,
+                kind,
+                commit_index: Some(index),
+                message,
+            });
+        }
+        highest_commit_index = Some(highest_commit_index.map_or(index, |h: u64| h.max(index)));
+    }
+
+    Ok(EventLogValidation {
+        events_checked,
+        highest_commit_index,
+        issues,
+        integrity: *iter.integrity(),
+    })
+}
+
+/// Distinct blob `payload_ref`s of an EventLog's events, streamed one line
+/// at a time: the referenced set for
+/// [`BlobStore::gc`](crate::blob_store::BlobStore::gc).
+///
+/// Any unreadable line is an error, since its reference is unknown.
+pub fn referenced_payload_refs(path: &Path) -> io::Result<HashSet<String>> {
+    let mut refs = HashSet::new();
+    for event in EventLogReader::open(path)? {
+        refs.extend(event?.payload_ref);
+    }
+    Ok(refs)
+}
+
+/// First hash chain break found by [`verify_eventlog_chain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainBreak {
+    /// 1-based physical line number of the first line whose `prev_hash`
+    /// does not match.
+    pub line: usize,
+    /// `commit_index` on that line, if it parsed.
+    pub commit_index: Option<u64>,
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Result of [`verify_eventlog_chain`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChainReport {
+    /// Whether the log is hash-chained (its first event carries `prev_hash`).
+    pub chained: bool,
+    /// Lines checked up to and including the first break.
+    pub events_checked: usize,
+    /// First break, or `None` when the chain is intact.
+    pub first_break: Option<ChainBreak>,
+}
+
+impl ChainReport {
+    /// True when the chain has no break. Unchained logs are trivially intact.
+    pub fn is_intact(&self) -> bool {
+        self.first_break.is_none()
+    }
+}
+
+/// Recompute an EventLog's hash chain and report the first break.
+///
+/// An unchained log (written before hash chaining) is reported with
+/// `chained: false` and no break, unless a later line carries `prev_hash`.
+/// Segments are checked as one chain. Only opening or reading the file is
+/// an error; unparseable lines are breaks.
+pub fn verify_eventlog_chain(path: &Path) -> io::Result<ChainReport> {
+    let mut chained = None;
+    let mut expected = GENESIS_PREV_HASH.to_string();
+    let mut events_checked = 0;
+    let mut line_no = 0;
+
+    for segment in eventlog_segments(path)? {
+        for line in open_eventlog_file(&segment)?.lines() {
+            line_no += 1;
+            let line = line?;
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            events_checked += 1;
+            let problem = match CommittedEvent::from_jsonl_line(trimmed.as_bytes()) {
+                Err(e) => Some((None, format!("unparseable line: {e}"))),
+                Ok((event, _)) => {
+                    let is_chained = *chained.get_or_insert(event.prev_hash.is_some());
+                    match (is_chained, event.prev_hash.as_deref()) {
+                        (false, None) => None,
+                        (false, Some(_)) => Some((
+                            Some(event.commit_index),
+                            "prev_hash appears in an unchained EventLog".to_string(),
+                        )),
+                        (true, Some(found)) if found == expected => None,
+                        (true, found) => Some((
+                            Some(event.commit_index),
+                            format!(
+                                "prev_hash {} does not match expected {expected}",
+                                found.unwrap_or("(missing)")
+                            ),
+                        )),
+                    }
+                }
+            };
+            if let Some((commit_index, message)) = problem {
+                return Ok(ChainReport {
+                    chained: chained.unwrap_or(false),
+                    events_checked,
+                    first_break: Some(ChainBreak {
+                        line: line_no,
+                        commit_index,
+                        message,
+                    }),
+                });
+            }
+            expected = blake3::hash(trimmed.as_bytes()).to_hex().to_string();
+        }
+    }
+
+    Ok(ChainReport {
+        chained: chained.unwrap_or(false),
+        events_checked,
+        first_break: None,
+    })
+}
+
+/// Read at most `limit` committed events from the start of an EventLog.
+///
+/// Stops parsing once `limit` events are collected, so the cost is bounded
+/// by `limit` rather than the file size. The flag is `true` when at least
+/// one further non-blank line follows (the result is a strict prefix).
+pub fn read_eventlog_head(path: &Path, limit: usize) -> io::Result<(Vec<CommittedEvent>, bool)> {
+    let mut iter = EventLogReader::open(path)?;
+    let events = iter.by_ref().take(limit).collect::<io::Result<Vec<_>>>()?;
+    let more = events.len() == limit && iter.next().is_some();
+    Ok((events, more))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{EventPayload, ImportEvent, Tier};
+    use crate::eventlog_index::EventLogIndex;
+
+    /// Helper to create a minimal ImportEvent.
+    fn make_event(source_id: &str, timestamp_ns: u64) -> ImportEvent {
+        ImportEvent {
+            run_id: "run-1".into(),
+            event_id: format!("{source_id}:{timestamp_ns}"),
+            source_id: source_id.into(),
+            source_seq: Some(0),
+            timestamp_ns,
+            tier: Tier::A,
+            payload: EventPayload::RunStart {
+                agent: "test".into(),
+                args: None,
+            },
+            payload_ref: None,
+            synthesized: false,
+        }
+    }
+
+    // -------------------------------------------------------------------
+    // M2.4: Append writer monotonicity tests
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn append_1000_events_monotonic() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        for i in 0..1000 {
+            let event = ImportEvent {
+                run_id: "run-1".into(),
+                event_id: format!("e-{i}"),
+                source_id: "test".into(),
+                source_seq: Some(i),
+                timestamp_ns: 1_000_000_000 + i * 1_000_000,
+                tier: Tier::A,
+                payload: EventPayload::ToolCall {
+                    tool: "bash".into(),
+                    args: Some(format!("cmd-{i}")),
+                    invocation_id: None,
+                },
+                payload_ref: None,
+                synthesized: false,
+            };
+            let result = writer.append(event).unwrap();
+            assert_eq!(result.committed.commit_index, i);
+        }
+
+        // Read back and verify full sequence.
+        let events = read_eventlog(&path).unwrap();
+        assert_eq!(events.len(), 1000);
+        for (i, event) in events.iter().enumerate() {
+            assert_eq!(
+                event.commit_index, i as u64,
+                "event {i} has wrong commit_index"
+            );
+        }
+    }
+
+    #[test]
+    fn resume_from_existing_eventlog() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+
+        // Write 10 events.
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            for i in 0..10 {
+                let event = ImportEvent {
+                    run_id: "run-1".into(),
+                    event_id: format!("e-{i}"),
+                    source_id: "test".into(),
+                    source_seq: Some(i),
+                    timestamp_ns: 1_000_000_000 + i * 1_000_000,
+                    tier: Tier::A,
+                    payload: EventPayload::RunStart {
+                        agent: "test".into(),
+                        args: None,
+                    },
+                    payload_ref: None,
+                    synthesized: false,
+                };
+                writer.append(event).unwrap();
+            }
+        }
+
+        // Re-open and continue.
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        assert_eq!(writer.next_index(), 10);
+
+        let result = writer.append(make_event("test", 2_000_000_000)).unwrap();
+        assert_eq!(result.committed.commit_index, 10);
+    }
+
+    #[test]
+    fn new_eventlog_starts_at_zero() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        assert_eq!(writer.next_index(), 0);
+
+        let result = writer.append(make_event("test", 1_000_000_000)).unwrap();
+        assert_eq!(result.committed.commit_index, 0);
+    }
+
+    #[test]
     fn existing_empty_eventlog_starts_at_zero() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
-        std::fs::File::create(&path).unwrap();
+        std::fs::File::create(&path).unwrap();
+
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        assert_eq!(writer.next_index(), 0);
+
+        let result = writer.append(make_event("test", 1_000_000_000)).unwrap();
+        assert_eq!(result.committed.commit_index, 0);
+    }
+
+    #[test]
+    fn commit_index_is_writer_assigned() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        // ImportEvent has no commit_index. Writer assigns it.
+        let import = make_event("test", 1_000_000_000);
+        let result = writer.append(import).unwrap();
+        assert_eq!(result.committed.commit_index, 0);
+    }
+
+    #[test]
+    fn max_line_bytes_rejection() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        // Create an event with a huge inline payload.
+        let huge_args = "x".repeat(EVENTLOG_MAX_LINE_BYTES + 1);
+        let event = ImportEvent {
+            run_id: "run-1".into(),
+            event_id: "e-huge".into(),
+            source_id: "test".into(),
+            source_seq: Some(0),
+            timestamp_ns: 1_000_000_000,
+            tier: Tier::A,
+            payload: EventPayload::ToolCall {
+                tool: "bash".into(),
+                args: Some(huge_args),
+                invocation_id: None,
+            },
+            payload_ref: None,
+            synthesized: false,
+        };
+
+        let result = writer.append(event);
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("max line bytes"));
+    }
+
+    #[test]
+    fn jsonl_format_one_line_per_event() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        for i in 0..5 {
+            writer
+                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 5);
+        for line in &lines {
+            assert!(!line.is_empty());
+            // Each line must be valid JSON.
+            serde_json::from_str::<CommittedEvent>(line).unwrap();
+        }
+    }
+
+    // -------------------------------------------------------------------
+    // M2.5: Blob store integration tests (via append writer)
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn blob_store_with_writer() {
+        use crate::blob_store::BlobStore;
+
+        let dir = tempfile::tempdir().unwrap();
+        let eventlog_path = dir.path().join("eventlog.jsonl");
+        let blob_store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+
+        // Create a payload above inline threshold.
+        let large_payload = vec![b'A'; crate::blob_store::INLINE_PAYLOAD_MAX_BYTES + 100];
+        let payload_ref = blob_store.write_blob(&large_payload).unwrap();
+
+        // Create event with payload_ref (inline args omitted).
+        let event = ImportEvent {
+            run_id: "run-1".into(),
+            event_id: "e-blob".into(),
+            source_id: "test".into(),
+            source_seq: Some(0),
+            timestamp_ns: 1_000_000_000,
+            tier: Tier::A,
+            payload: EventPayload::ToolCall {
+                tool: "bash".into(),
+                args: None,
+                invocation_id: None,
+            },
+            payload_ref: Some(payload_ref.clone()),
+            synthesized: false,
+        };
+        writer.append(event).unwrap();
+
+        // Read back EventLog and verify payload_ref resolves.
+        let events = read_eventlog(&eventlog_path).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].payload_ref.as_deref(), Some(payload_ref.as_str()));
+
+        // Resolve blob content.
+        let blob_data = blob_store.read_blob(&payload_ref).unwrap().unwrap();
+        assert_eq!(blob_data, large_payload);
+
+        let referenced = referenced_payload_refs(&eventlog_path).unwrap();
+        assert_eq!(referenced, HashSet::from([payload_ref]));
+    }
+
+    // -------------------------------------------------------------------
+    // M2.6: Clock skew detection tests
+    // -------------------------------------------------------------------
 
+    #[test]
+    fn clock_skew_detected_beyond_tolerance() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&path).unwrap();
-        assert_eq!(writer.next_index(), 0);
 
-        let result = writer.append(make_event("test", 1_000_000_000)).unwrap();
-        assert_eq!(result.committed.commit_index, 0);
+        // First event at t=2s.
+        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+
+        // Second event at t=1s (1s backward > 50ms tolerance).
+        let result = writer.append(make_event("src-1", 1_000_000_000)).unwrap();
+
+        assert_eq!(
+            result.detection_events.len(),
+            1,
+            "should emit ClockSkewDetected"
+        );
+        let skew = &result.detection_events[0];
+        assert_eq!(skew.tier, Tier::A);
+        assert!(skew.synthesized);
+        assert!(
+            matches!(&skew.payload, EventPayload::ClockSkewDetected { .. }),
+            "expected ClockSkewDetected payload"
+        );
+        if let EventPayload::ClockSkewDetected {
+            expected_ns,
+            actual_ns,
+            delta_ns,
+        } = &skew.payload
+        {
+            assert_eq!(*expected_ns, 2_000_000_000);
+            assert_eq!(*actual_ns, 1_000_000_000);
+            assert_eq!(*delta_ns, 1_000_000_000);
+        }
+
+        // Skew event gets commit_index before the main event.
+        assert_eq!(skew.commit_index, 1); // after first event (index 0)
+        assert_eq!(result.committed.commit_index, 2);
+    }
+
+    #[test]
+    fn clock_skew_within_tolerance_no_detection() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        // First event at t=2s.
+        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+
+        // Second event backward by exactly 50ms (at tolerance boundary).
+        let result = writer
+            .append(make_event("src-1", 2_000_000_000 - CLOCK_SKEW_TOLERANCE_NS))
+            .unwrap();
+
+        assert!(
+            result.detection_events.is_empty(),
+            "at-tolerance should not trigger detection"
+        );
+    }
+
+    #[test]
+    fn clock_skew_just_beyond_tolerance() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        // First event at t=2s.
+        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+
+        // Backward by tolerance + 1 ns.
+        let result = writer
+            .append(make_event(
+                "src-1",
+                2_000_000_000 - CLOCK_SKEW_TOLERANCE_NS - 1,
+            ))
+            .unwrap();
+
+        assert_eq!(
+            result.detection_events.len(),
+            1,
+            "just beyond tolerance should trigger"
+        );
+    }
+
+    #[test]
+    fn clock_skew_tolerance_is_configurable() {
+        let dir = tempfile::tempdir().unwrap();
+        let backward_40ms = 2_000_000_000 - 40_000_000;
+
+        let default_path = dir.path().join("default.jsonl");
+        let mut writer = EventLogWriter::open(&default_path).unwrap();
+        assert_eq!(writer.skew_tolerance(), DEFAULT_CLOCK_SKEW_TOLERANCE);
+        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+        let result = writer.append(make_event("src-1", backward_40ms)).unwrap();
+        assert!(result.detection_events.is_empty());
+
+        let strict_path = dir.path().join("strict.jsonl");
+        let mut writer = EventLogWriter::open(&strict_path)
+            .unwrap()
+            .with_skew_tolerance(Duration::from_millis(10));
+        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+        let result = writer.append(make_event("src-1", backward_40ms)).unwrap();
+        assert_eq!(result.detection_events.len(), 1);
+        match &result.detection_events[0].payload {
+            EventPayload::ClockSkewDetected { delta_ns, .. } => {
+                assert_eq!(*delta_ns, 40_000_000);
+            }
+            other => panic!("expected ClockSkewDetected, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn wide_skew_tolerance_suppresses_detection() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("wide.jsonl");
+        let mut writer = EventLogWriter::open(&path)
+            .unwrap()
+            .with_skew_tolerance(Duration::from_secs(2));
+        writer.append(make_event("src-1", 3_000_000_000)).unwrap();
+        let result = writer.append(make_event("src-1", 1_500_000_000)).unwrap();
+        assert!(result.detection_events.is_empty());
+    }
+
+    #[test]
+    fn clock_skew_multiple_sources_independent() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        // Source A at t=2s, source B at t=3s.
+        writer.append(make_event("src-a", 2_000_000_000)).unwrap();
+        writer.append(make_event("src-b", 3_000_000_000)).unwrap();
+
+        // Source A goes backward (triggers), source B goes forward (no trigger).
+        let result_a = writer.append(make_event("src-a", 1_000_000_000)).unwrap();
+        let result_b = writer.append(make_event("src-b", 4_000_000_000)).unwrap();
+
+        assert_eq!(
+            result_a.detection_events.len(),
+            1,
+            "source A should trigger"
+        );
+        assert!(
+            result_b.detection_events.is_empty(),
+            "source B should not trigger"
+        );
+    }
+
+    #[test]
+    fn clock_skew_event_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        writer
+            .append(make_event("my-source", 5_000_000_000))
+            .unwrap();
+        let result = writer
+            .append(make_event("my-source", 3_000_000_000))
+            .unwrap();
+
+        let skew = &result.detection_events[0];
+        assert_eq!(skew.source_id, "my-source");
+        assert_eq!(skew.run_id, "run-1");
+        assert!(skew.event_id.starts_with("clock-skew:my-source:"));
+        assert_eq!(skew.tier, Tier::A);
+        assert!(skew.synthesized);
+
+        assert!(
+            matches!(&skew.payload, EventPayload::ClockSkewDetected { .. }),
+            "wrong payload type"
+        );
+        if let EventPayload::ClockSkewDetected {
+            expected_ns,
+            actual_ns,
+            delta_ns,
+        } = &skew.payload
+        {
+            assert_eq!(*expected_ns, 5_000_000_000);
+            assert_eq!(*actual_ns, 3_000_000_000);
+            assert_eq!(*delta_ns, 2_000_000_000);
+        }
+    }
+
+    #[test]
+    fn commit_in_memory_matches_appended_eventlog() {
+        let events = vec![
+            make_event("src-1", 2_000_000_000),
+            make_event("src-2", 1_500_000_000),
+            make_event("src-1", 1_000_000_000),
+            make_event("src-1", 3_000_000_000),
+        ];
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for event in events.clone() {
+            writer.append(event).unwrap();
+        }
+        drop(writer);
+
+        let committed = commit_in_memory(events).unwrap();
+        assert_eq!(committed, read_eventlog(&path).unwrap());
+        assert_eq!(committed.len(), 5);
+        assert!(matches!(
+            committed[2].payload,
+            EventPayload::ClockSkewDetected { .. }
+        ));
+    }
+
+    #[test]
+    fn read_eventlog_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+
+        for i in 0..5 {
+            writer
+                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let events = read_eventlog(&path).unwrap();
+        assert_eq!(events.len(), 5);
+        for (i, event) in events.iter().enumerate() {
+            assert_eq!(event.commit_index, i as u64);
+            assert_eq!(event.source_id, "test");
+        }
     }
 
     #[test]
-    fn commit_index_is_writer_assigned() {
+    fn read_eventlog_head_stops_at_limit() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5 {
+            writer
+                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
+                .unwrap();
+        }
+        drop(writer);
 
-        // ImportEvent has no commit_index. Writer assigns it.
-        let import = make_event("test", 1_000_000_000);
-        let result = writer.append(import).unwrap();
-        assert_eq!(result.committed.commit_index, 0);
+        let (events, truncated) = read_eventlog_head(&path, 2).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(truncated);
+        assert_eq!(events[1].commit_index, 1);
+
+        let (events, truncated) = read_eventlog_head(&path, 5).unwrap();
+        assert_eq!(events.len(), 5);
+        assert!(!truncated, "exact-length limit is not a truncation");
+
+        // Lines past the limit are never parsed.
+        let mut content = std::fs::read_to_string(&path).unwrap();
+        content.push_str("not json\n");
+        std::fs::write(&path, content).unwrap();
+        let (events, truncated) = read_eventlog_head(&path, 5).unwrap();
+        assert_eq!(events.len(), 5);
+        assert!(truncated);
     }
 
     #[test]
-    fn max_line_bytes_rejection() {
+    fn read_eventlog_iter_matches_vec_and_reports_bad_lines_in_place() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..4 {
+            writer
+                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
+                .unwrap();
+        }
+        drop(writer);
 
-        // Create an event with a huge inline payload.
-        let huge_args = "x".repeat(EVENTLOG_MAX_LINE_BYTES + 1);
-        let event = ImportEvent {
-            run_id: "run-1".into(),
-            event_id: "e-huge".into(),
-            source_id: "test".into(),
-            source_seq: Some(0),
-            timestamp_ns: 1_000_000_000,
-            tier: Tier::A,
-            payload: EventPayload::ToolCall {
-                tool: "bash".into(),
-                args: Some(huge_args),
-            },
-            payload_ref: None,
-            synthesized: false,
-        };
-
-        let result = writer.append(event);
-        assert!(result.is_err());
-        let err = result.unwrap_err();
+        let streamed: Vec<_> = read_eventlog_iter(&path)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(streamed, read_eventlog(&path).unwrap());
+
+        // Corrupt physical line 3 (after a blank line 2) and keep going.
+        let lines: Vec<String> = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let corrupted = format!("{}\n\nnot json\n{}\n{}\n", lines[0], lines[2], lines[3]);
+        std::fs::write(&path, corrupted).unwrap();
+
+        let results: Vec<_> = read_eventlog_iter(&path).unwrap().collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(results[0].as_ref().unwrap().commit_index, 0);
+        let err = results[1].as_ref().unwrap_err();
         assert_eq!(err.kind(), io::ErrorKind::InvalidData);
-        assert!(err.to_string().contains("max line bytes"));
+        assert!(err.to_string().contains("line 3"), "{err}");
+        assert_eq!(results[2].as_ref().unwrap().commit_index, 2);
+        assert_eq!(results[3].as_ref().unwrap().commit_index, 3);
+
+        assert!(matches!(
+            read_eventlog(&path),
+            Err(VifeiError::MalformedEvent { line: 3, .. })
+        ));
     }
 
     #[test]
-    fn jsonl_format_one_line_per_event() {
+    fn read_eventlog_missing_file_is_io_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = read_eventlog(&dir.path().join("missing.jsonl")).unwrap_err();
+        assert!(matches!(&err, VifeiError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn eventlog_reader_buffer_is_bounded_by_longest_line() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..2_000 {
+            writer
+                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
+                .unwrap();
+        }
+        drop(writer);
+        let file_len = std::fs::metadata(&path).unwrap().len() as usize;
+        let longest_line = std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(str::len)
+            .max()
+            .unwrap();
 
-        for i in 0..5 {
+        let mut reader = EventLogReader::open(&path).unwrap();
+        let mut count = 0;
+        let mut max_capacity = 0;
+        while let Some(event) = reader.next() {
+            assert_eq!(event.unwrap().commit_index, count);
+            count += 1;
+            max_capacity = max_capacity.max(reader.buf.capacity());
+        }
+        assert_eq!(count, 2_000);
+        assert_eq!(reader.line_number(), 2_000);
+        // The reusable buffer only ever holds one line, never the file.
+        assert!(max_capacity >= longest_line);
+        assert!(
+            max_capacity * 100 < file_len,
+            "buffer grew to {max_capacity} bytes for a {file_len} byte file"
+        );
+    }
+
+    /// Write `count` events and return their JSONL lines.
+    fn committed_lines(dir: &Path, count: u64) -> Vec<String> {
+        let path = dir.join("source.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..count {
             writer
                 .append(make_event("test", 1_000_000_000 + i * 1_000_000))
                 .unwrap();
         }
         drop(writer);
+        std::fs::read_to_string(&path)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
 
-        let content = std::fs::read_to_string(&path).unwrap();
-        let lines: Vec<&str> = content.lines().collect();
-        assert_eq!(lines.len(), 5);
-        for line in &lines {
-            assert!(!line.is_empty());
-            // Each line must be valid JSON.
-            serde_json::from_str::<CommittedEvent>(line).unwrap();
+    #[test]
+    fn validate_eventlog_accepts_dense_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = committed_lines(dir.path(), 5);
+        let path = dir.path().join("dense.jsonl");
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+        let validation = validate_eventlog(&path).unwrap();
+        assert!(validation.is_valid(), "{validation:?}");
+        assert_eq!(validation.events_checked, 5);
+        assert_eq!(validation.highest_commit_index, Some(4));
+    }
+
+    #[test]
+    fn validate_eventlog_reports_missing_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut lines = committed_lines(dir.path(), 6);
+        lines.remove(3);
+        let path = dir.path().join("gap.jsonl");
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+        let validation = validate_eventlog(&path).unwrap();
+        assert_eq!(validation.issues.len(), 1, "{validation:?}");
+        let issue = &validation.issues[0];
+        assert_eq!(issue.kind, EventLogIssueKind::Gap);
+        assert_eq!(issue.line, 4);
+        assert_eq!(issue.commit_index, Some(4));
+        assert!(issue.message.contains("missing commit_index 3"));
+    }
+
+    #[test]
+    fn validate_eventlog_reports_duplicate_and_out_of_order_indices() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = committed_lines(dir.path(), 4);
+        let path = dir.path().join("dup.jsonl");
+        // 0, 1, 1 (duplicate), 3, 2 (out of order), then garbage.
+        let body = [
+            &lines[0], &lines[1], &lines[1], &lines[3], &lines[2], "{oops",
+        ]
+        .map(|l| l.to_string())
+        .join("\n");
+        std::fs::write(&path, body + "\n").unwrap();
+
+        let validation = validate_eventlog(&path).unwrap();
+        let kinds: Vec<_> = validation
+            .issues
+            .iter()
+            .map(|i| (i.line, i.kind, i.commit_index))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (3, EventLogIssueKind::Duplicate, Some(1)),
+                (4, EventLogIssueKind::Gap, Some(3)),
+                (5, EventLogIssueKind::NonMonotonic, Some(2)),
+                (6, EventLogIssueKind::Unparseable, None),
+            ]
+        );
+        assert!(validation.issues[0].message.contains("first at line 2"));
+        assert_eq!(validation.events_checked, 5);
+        assert_eq!(
+            validation.integrity,
+            EventLogIntegrityReport {
+                total_events: 5,
+                gaps: 1,
+                duplicates: 1,
+                non_monotonic: 1,
+                unknown_payloads: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn integrity_report_classifies_gaps_duplicates_and_non_monotonic() {
+        let report = EventLogIntegrityReport::from_commit_indices([0, 1, 2, 5, 4]);
+        assert_eq!(
+            (report.gaps, report.duplicates, report.non_monotonic),
+            (1, 0, 1)
+        );
+        // 3 fills the same gap; a second 4 and a repeated 0 are duplicates.
+        let report = EventLogIntegrityReport::from_commit_indices([0, 1, 2, 5, 4, 3, 4, 0, 9]);
+        assert_eq!(report.total_events, 9);
+        assert_eq!(
+            (report.gaps, report.duplicates, report.non_monotonic),
+            (2, 2, 2)
+        );
+        assert!(!report.is_clean());
+        assert!(EventLogIntegrityReport::from_commit_indices(0..100).is_clean());
+        assert!(!EventLogIntegrityReport::from_commit_indices([1, 2]).is_clean());
+    }
+
+    #[test]
+    fn reader_reports_integrity_of_events_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = committed_lines(dir.path(), 6);
+        let path = dir.path().join("regressed.jsonl");
+        // 0, 1, 2, 5, 4: the sequence read_eventlog used to accept silently.
+        let body = [&lines[0], &lines[1], &lines[2], &lines[5], &lines[4]]
+            .map(|l| l.to_string())
+            .join("\n");
+        std::fs::write(&path, body + "\n").unwrap();
+
+        let outcome = read_eventlog_recovering(&path).unwrap();
+        assert_eq!(outcome.events.len(), 5);
+        assert_eq!(
+            outcome.integrity,
+            EventLogIntegrityReport {
+                total_events: 5,
+                gaps: 1,
+                duplicates: 0,
+                non_monotonic: 1,
+                unknown_payloads: 0,
+            }
+        );
+        let json = serde_json::to_string(&outcome.integrity).unwrap();
+        assert_eq!(
+            json,
+            r#"{"total_events":5,"gaps":1,"duplicates":0,"non_monotonic":1}"#
+        );
+    }
+
+    #[test]
+    fn clock_skew_detected_after_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+        }
+
+        // Reopen writer and ensure historical source timestamp is restored.
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        let result = writer.append(make_event("src-1", 1_000_000_000)).unwrap();
+
+        assert_eq!(
+            result.detection_events.len(),
+            1,
+            "clock skew should still be detected after writer reopen"
+        );
+        assert!(
+            matches!(
+                &result.detection_events[0].payload,
+                EventPayload::ClockSkewDetected { .. }
+            ),
+            "expected ClockSkewDetected payload"
+        );
+        if let EventPayload::ClockSkewDetected {
+            expected_ns,
+            actual_ns,
+            delta_ns,
+        } = &result.detection_events[0].payload
+        {
+            assert_eq!(*expected_ns, 2_000_000_000);
+            assert_eq!(*actual_ns, 1_000_000_000);
+            assert_eq!(*delta_ns, 1_000_000_000);
         }
     }
 
-    // -------------------------------------------------------------------
-    // M2.5: Blob store integration tests (via append writer)
-    // -------------------------------------------------------------------
+    #[test]
+    fn appended_events_form_a_hash_chain_across_reopen() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            writer.append(make_event("src", 1_000_000_000)).unwrap();
+            writer.append(make_event("src", 2_000_000_000)).unwrap();
+        }
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            writer.append(make_event("src", 3_000_000_000)).unwrap();
+        }
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        let events = read_eventlog(&path).unwrap();
+        assert_eq!(events[0].prev_hash.as_deref(), Some(GENESIS_PREV_HASH));
+        for i in 1..events.len() {
+            let expected = blake3::hash(lines[i - 1].as_bytes()).to_hex().to_string();
+            assert_eq!(events[i].prev_hash.as_deref(), Some(expected.as_str()));
+        }
+
+        let report = verify_eventlog_chain(&path).unwrap();
+        assert!(report.chained);
+        assert!(report.is_intact(), "{report:?}");
+        assert_eq!(report.events_checked, 3);
+    }
 
     #[test]
-    fn blob_store_with_writer() {
-        use crate::blob_store::BlobStore;
+    fn edited_line_breaks_chain_at_next_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 1..=4 {
+            writer.append(make_event("src", i * 1_000_000_000)).unwrap();
+        }
+        drop(writer);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        let tampered = text.replacen("\"run-1\"", "\"run-X\"", 2);
+        std::fs::write(&path, tampered).unwrap();
+
+        // Both edited lines still parse and keep dense indices.
+        assert!(validate_eventlog(&path).unwrap().is_valid());
+        let report = verify_eventlog_chain(&path).unwrap();
+        let chain_break = report.first_break.expect("chain must break");
+        assert_eq!(chain_break.line, 2);
+        assert_eq!(chain_break.commit_index, Some(1));
+        assert_eq!(report.events_checked, 2);
+    }
 
+    #[test]
+    fn unchained_eventlog_stays_unchained_on_resume() {
         let dir = tempfile::tempdir().unwrap();
-        let eventlog_path = dir.path().join("eventlog.jsonl");
-        let blob_store = BlobStore::open(dir.path().join("blobs")).unwrap();
-        let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let legacy: String = committed_lines(dir.path(), 2)
+            .iter()
+            .map(|line| {
+                let mut event: CommittedEvent = serde_json::from_str(line).unwrap();
+                event.prev_hash = None;
+                serde_json::to_string(&event).unwrap() + "\n"
+            })
+            .collect();
+        std::fs::write(&path, legacy).unwrap();
 
-        // Create a payload above inline threshold.
-        let large_payload = vec![b'A'; crate::blob_store::INLINE_PAYLOAD_MAX_BYTES + 100];
-        let payload_ref = blob_store.write_blob(&large_payload).unwrap();
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        let appended = writer.append(make_event("src", 9_000_000_000)).unwrap();
+        assert_eq!(appended.committed_event().prev_hash, None);
+        drop(writer);
 
-        // Create event with payload_ref (inline args omitted).
-        let event = ImportEvent {
-            run_id: "run-1".into(),
-            event_id: "e-blob".into(),
-            source_id: "test".into(),
-            source_seq: Some(0),
-            timestamp_ns: 1_000_000_000,
-            tier: Tier::A,
-            payload: EventPayload::ToolCall {
-                tool: "bash".into(),
-                args: None,
-            },
-            payload_ref: Some(payload_ref.clone()),
-            synthesized: false,
-        };
-        writer.append(event).unwrap();
+        assert!(read_eventlog(&path)
+            .unwrap()
+            .iter()
+            .all(|event| event.prev_hash.is_none()));
+        let report = verify_eventlog_chain(&path).unwrap();
+        assert!(!report.chained);
+        assert!(report.is_intact());
+        assert_eq!(report.events_checked, 3);
+    }
 
-        // Read back EventLog and verify payload_ref resolves.
-        let events = read_eventlog(&eventlog_path).unwrap();
-        assert_eq!(events.len(), 1);
-        assert_eq!(events[0].payload_ref.as_deref(), Some(payload_ref.as_str()));
+    /// Append `count` Tier B events and return the writer's sync count.
+    fn tier_b_sync_count(durability: DurabilityPolicy, count: u64) -> u64 {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open_with_policy(&path, durability).unwrap();
+        assert_eq!(writer.durability(), durability);
+        for i in 0..count {
+            let mut event = make_event("src", 1_000_000_000 + i);
+            event.tier = Tier::B;
+            writer.append(event).unwrap();
+        }
+        writer.sync_count()
+    }
+
+    #[test]
+    fn durability_policy_controls_tier_b_syncs() {
+        assert_eq!(tier_b_sync_count(DurabilityPolicy::EveryAppend, 10), 10);
+        assert_eq!(tier_b_sync_count(DurabilityPolicy::EveryN(4), 10), 2);
+        assert_eq!(tier_b_sync_count(DurabilityPolicy::EveryN(0), 3), 3);
+        assert_eq!(tier_b_sync_count(DurabilityPolicy::OnDrop, 10), 0);
+    }
+
+    #[test]
+    fn tier_a_is_synced_under_every_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer =
+            EventLogWriter::open_with_policy(&path, DurabilityPolicy::EveryN(3)).unwrap();
+        writer.append(make_event("src", 1_000_000_000)).unwrap();
+        let mut tier_b = make_event("src", 2_000_000_000);
+        tier_b.tier = Tier::B;
+        writer.append(tier_b.clone()).unwrap();
+        writer.append(tier_b.clone()).unwrap();
+        assert_eq!(writer.sync_count(), 1);
+        // The Tier A sync reset the counter, so the third Tier B line syncs.
+        writer.append(tier_b).unwrap();
+        assert_eq!(writer.sync_count(), 2);
+        drop(writer);
+        assert_eq!(
+            EventLogWriter::open(&path).unwrap().durability(),
+            DurabilityPolicy::OnDrop
+        );
+    }
+
+    #[test]
+    fn open_fails_loudly_on_malformed_existing_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        std::fs::write(&path, "{\"not\":\"a-committed-event\"}\n").unwrap();
+
+        let result = EventLogWriter::open(&path);
+        assert!(
+            result.is_err(),
+            "open() should fail for malformed existing EventLog"
+        );
+        let err = result.err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("failed to parse EventLog line 1"));
+    }
+
+    #[test]
+    fn segmented_writer_rolls_and_matches_single_file_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        let single = dir.path().join("single.jsonl");
+        let segmented = dir.path().join("segmented");
+        {
+            let mut one = EventLogWriter::open(&single).unwrap();
+            let mut many =
+                EventLogWriter::open_segmented(&segmented, 1_000, DurabilityPolicy::OnDrop)
+                    .unwrap();
+            for i in 0..20 {
+                one.append(make_event("src", 1_000_000_000 + i)).unwrap();
+                many.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+
+        let index = read_segment_index(&segmented).unwrap();
+        assert!(index.segments.len() > 2, "{index:?}");
+        assert_eq!(index.segments[0].file, "eventlog.00001.jsonl");
+        let mut next = 0;
+        let mut concatenated = Vec::new();
+        for entry in &index.segments {
+            assert_eq!(entry.first_commit_index, Some(next));
+            let bytes = std::fs::read(segmented.join(&entry.file)).unwrap();
+            assert!(
+                bytes.len() <= 1_000,
+                "{} is {} bytes",
+                entry.file,
+                bytes.len()
+            );
+            next = entry.last_commit_index.unwrap() + 1;
+            concatenated.extend(bytes);
+        }
+        assert_eq!(next, 20);
+        assert_eq!(concatenated, std::fs::read(&single).unwrap());
+
+        assert_eq!(
+            read_eventlog(&segmented).unwrap(),
+            read_eventlog(&single).unwrap()
+        );
+        assert_eq!(
+            read_eventlog(&segmented.join(SEGMENT_INDEX_FILE)).unwrap(),
+            read_eventlog(&single).unwrap()
+        );
+        assert!(verify_eventlog_chain(&segmented).unwrap().is_intact());
+        assert!(validate_eventlog(&segmented).unwrap().is_valid());
+    }
+
+    #[test]
+    fn segmented_log_resumes_from_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let segmented = dir.path().join("segmented");
+        {
+            let mut writer =
+                EventLogWriter::open_segmented(&segmented, 1_000, DurabilityPolicy::OnDrop)
+                    .unwrap();
+            for i in 0..6 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        let segments_before = read_segment_index(&segmented).unwrap().segments.len();
 
-        // Resolve blob content.
-        let blob_data = blob_store.read_blob(&payload_ref).unwrap().unwrap();
-        assert_eq!(blob_data, large_payload);
+        let mut writer = EventLogWriter::open(&segmented).unwrap();
+        assert_eq!(writer.next_index(), 6);
+        for i in 6..12 {
+            writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+        }
+        writer.sync().unwrap();
+
+        let index = read_segment_index(&segmented).unwrap();
+        assert_eq!(index.max_segment_bytes, 1_000);
+        assert!(index.segments.len() > segments_before);
+        assert_eq!(index.segments.last().unwrap().last_commit_index, Some(11));
+        let indices: Vec<u64> = read_eventlog(&segmented)
+            .unwrap()
+            .iter()
+            .map(|e| e.commit_index)
+            .collect();
+        assert_eq!(indices, (0..12).collect::<Vec<_>>());
+        assert!(verify_eventlog_chain(&segmented).unwrap().is_intact());
     }
 
-    // -------------------------------------------------------------------
-    // M2.6: Clock skew detection tests
-    // -------------------------------------------------------------------
-
     #[test]
-    fn clock_skew_detected_beyond_tolerance() {
+    fn segment_index_rejects_paths_outside_directory() {
         let dir = tempfile::tempdir().unwrap();
-        let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+        std::fs::write(
+            dir.path().join(SEGMENT_INDEX_FILE),
+            r#"{"max_segment_bytes":10,"segments":[{"file":"../x.jsonl","first_commit_index":null,"last_commit_index":null}]}"#,
+        )
+        .unwrap();
+        let err = read_eventlog(dir.path()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
 
-        // First event at t=2s.
-        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+    #[test]
+    fn range_read_returns_inclusive_window() {
+        let dir = tempfile::tempdir().unwrap();
+        let single = dir.path().join("single.jsonl");
+        let segmented = dir.path().join("segmented");
+        {
+            let mut one = EventLogWriter::open(&single).unwrap();
+            let mut many =
+                EventLogWriter::open_segmented(&segmented, 1_000, DurabilityPolicy::OnDrop)
+                    .unwrap();
+            for i in 0..20 {
+                one.append(make_event("src", 1_000_000_000 + i)).unwrap();
+                many.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
 
-        // Second event at t=1s (1s backward > 50ms tolerance).
-        let result = writer.append(make_event("src-1", 1_000_000_000)).unwrap();
+        for path in [&single, &segmented] {
+            let indices: Vec<u64> = read_eventlog_range(path, 7, 12)
+                .unwrap()
+                .iter()
+                .map(|e| e.commit_index)
+                .collect();
+            assert_eq!(indices, (7..=12).collect::<Vec<_>>());
+            assert_eq!(read_eventlog_range(path, 19, u64::MAX).unwrap().len(), 1);
+            assert!(read_eventlog_range(path, 20, 30).unwrap().is_empty());
+        }
+    }
 
-        assert_eq!(
-            result.detection_events.len(),
-            1,
-            "should emit ClockSkewDetected"
-        );
-        let skew = &result.detection_events[0];
-        assert_eq!(skew.tier, Tier::A);
-        assert!(skew.synthesized);
-        assert!(
-            matches!(&skew.payload, EventPayload::ClockSkewDetected { .. }),
-            "expected ClockSkewDetected payload"
-        );
-        if let EventPayload::ClockSkewDetected {
-            expected_ns,
-            actual_ns,
-            delta_ns,
-        } = &skew.payload
+    #[test]
+    fn range_read_skips_segments_before_from() {
+        let dir = tempfile::tempdir().unwrap();
+        let segmented = dir.path().join("segmented");
         {
-            assert_eq!(*expected_ns, 2_000_000_000);
-            assert_eq!(*actual_ns, 1_000_000_000);
-            assert_eq!(*delta_ns, 1_000_000_000);
+            let mut writer =
+                EventLogWriter::open_segmented(&segmented, 1, DurabilityPolicy::OnDrop).unwrap();
+            for i in 0..4 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
         }
+        // Corrupt an early segment: a skipped segment is never parsed.
+        std::fs::write(segmented.join("eventlog.00001.jsonl"), "not json\n").unwrap();
 
-        // Skew event gets commit_index before the main event.
-        assert_eq!(skew.commit_index, 1); // after first event (index 0)
-        assert_eq!(result.committed.commit_index, 2);
+        let events = read_eventlog_range(&segmented, 2, 3).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(read_eventlog_range(&segmented, 0, 3).is_err());
     }
 
     #[test]
-    fn clock_skew_within_tolerance_no_detection() {
+    fn range_read_stops_after_to_and_rejects_inverted_bounds() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            for i in 0..3 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        // Lines past `to` are never parsed.
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"not json\n").unwrap();
+        assert_eq!(read_eventlog_range(&path, 0, 2).unwrap().len(), 3);
 
-        // First event at t=2s.
-        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+        let err = read_eventlog_range(&path, 2, 1).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
 
-        // Second event backward by exactly 50ms (at tolerance boundary).
-        let result = writer
-            .append(make_event("src-1", 2_000_000_000 - CLOCK_SKEW_TOLERANCE_NS))
-            .unwrap();
+    /// Append `count` events, then half of one more line with no newline.
+    fn write_log_with_torn_tail(path: &Path, count: u64) -> u64 {
+        {
+            let mut writer = EventLogWriter::open(path).unwrap();
+            for i in 0..count {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        let line = std::fs::read_to_string(path).unwrap();
+        let half = &line.lines().next().unwrap()[..40];
+        let mut file = OpenOptions::new().append(true).open(path).unwrap();
+        file.write_all(half.as_bytes()).unwrap();
+        half.len() as u64
+    }
 
-        assert!(
-            result.detection_events.is_empty(),
-            "at-tolerance should not trigger detection"
-        );
+    #[test]
+    fn recovering_read_returns_prefix_of_torn_log() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let torn = write_log_with_torn_tail(&path, 3);
+
+        assert!(read_eventlog(&path).is_err());
+        let outcome = read_eventlog_recovering(&path).unwrap();
+        assert_eq!(outcome.events.len(), 3);
+        assert_eq!(outcome.truncated_tail_bytes, Some(torn));
+        assert!(outcome.is_truncated());
+
+        let clean = dir.path().join("clean.jsonl");
+        {
+            let mut writer = EventLogWriter::open(&clean).unwrap();
+            writer.append(make_event("src", 1_000_000_000)).unwrap();
+        }
+        let outcome = read_eventlog_recovering(&clean).unwrap();
+        assert_eq!(outcome.events.len(), 1);
+        assert!(!outcome.is_truncated());
     }
 
     #[test]
-    fn clock_skew_just_beyond_tolerance() {
+    fn recovering_read_still_fails_on_malformed_middle_line() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            writer.append(make_event("src", 1_000_000_000)).unwrap();
+        }
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        // Newline-terminated garbage is corruption, not a torn append.
+        file.write_all(b"{\"commit_index\":\n").unwrap();
+        assert!(read_eventlog_recovering(&path).is_err());
+
+        // Garbage before a torn tail is still an error.
+        file.write_all(b"{\"commit").unwrap();
+        let err = read_eventlog_recovering(&path).unwrap_err();
+        assert!(err.to_string().contains("line 2"), "{err}");
+    }
 
-        // First event at t=2s.
-        writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+    /// Events whose third line moves the source clock back past tolerance.
+    fn batch_events() -> Vec<ImportEvent> {
+        [1_000, 3_000, 2_000, 4_000, 5_000, 6_000, 7_000]
+            .iter()
+            .enumerate()
+            .map(|(i, ms)| {
+                let mut event = make_event("src", ms * 1_000_000);
+                if i % 2 == 1 {
+                    event.tier = Tier::B;
+                }
+                event
+            })
+            .collect()
+    }
 
-        // Backward by tolerance + 1 ns.
-        let result = writer
-            .append(make_event(
-                "src-1",
-                2_000_000_000 - CLOCK_SKEW_TOLERANCE_NS - 1,
-            ))
-            .unwrap();
+    #[test]
+    fn append_batch_matches_one_by_one_bytes_and_results() {
+        let dir = tempfile::tempdir().unwrap();
+        let single = dir.path().join("single");
+        let batched = dir.path().join("batched");
+        let open = |path: &Path| {
+            EventLogWriter::open_segmented(path, 1_200, DurabilityPolicy::OnDrop)
+                .unwrap()
+                .with_index(2)
+                .unwrap()
+        };
 
+        let mut one = open(&single);
+        let mut one_results = Vec::new();
+        for event in batch_events() {
+            one_results.push(one.append(event).unwrap());
+        }
+        drop(one);
+        let mut many = open(&batched);
+        let mut events = batch_events();
+        let rest = events.split_off(3);
+        let mut many_results = many.append_batch(events).unwrap();
+        many_results.extend(many.append_batch(rest).unwrap());
+        drop(many);
+
+        assert_eq!(one_results.len(), many_results.len());
+        for (one, many) in one_results.iter().zip(&many_results) {
+            assert_eq!(one.committed_event(), many.committed_event());
+            assert_eq!(one.detection_events(), many.detection_events());
+        }
         assert_eq!(
-            result.detection_events.len(),
-            1,
-            "just beyond tolerance should trigger"
+            one_results
+                .iter()
+                .map(|r| r.detection_events().len())
+                .sum::<usize>(),
+            1
         );
+        let one_segments = eventlog_segments(&single).unwrap();
+        let many_segments = eventlog_segments(&batched).unwrap();
+        assert!(one_segments.len() > 1);
+        assert_eq!(one_segments.len(), many_segments.len());
+        for (one, many) in one_segments.iter().zip(&many_segments) {
+            assert_eq!(std::fs::read(one).unwrap(), std::fs::read(many).unwrap());
+            assert_eq!(
+                std::fs::read(index_path(one)).unwrap(),
+                std::fs::read(index_path(many)).unwrap()
+            );
+        }
+        assert!(verify_eventlog_chain(&batched).unwrap().is_intact());
     }
 
     #[test]
-    fn clock_skew_multiple_sources_independent() {
+    fn append_batch_syncs_once_when_required() {
+        let dir = tempfile::tempdir().unwrap();
+        let tier_b = |ms: u64| {
+            let mut event = make_event("src", ms * 1_000_000);
+            event.tier = Tier::B;
+            event
+        };
+
+        let path = dir.path().join("on-drop.jsonl");
+        let mut writer = EventLogWriter::open_with_policy(&path, DurabilityPolicy::OnDrop).unwrap();
+        writer.append_batch(vec![tier_b(1), tier_b(2)]).unwrap();
+        assert_eq!(writer.sync_count(), 0);
+        writer.append_batch(batch_events()).unwrap();
+        assert_eq!(writer.sync_count(), 1);
+
+        let path = dir.path().join("every.jsonl");
+        let mut writer =
+            EventLogWriter::open_with_policy(&path, DurabilityPolicy::EveryAppend).unwrap();
+        writer.append_batch(vec![tier_b(1), tier_b(2)]).unwrap();
+        assert_eq!(writer.sync_count(), 1);
+        writer.append_batch(Vec::new()).unwrap();
+        assert_eq!(writer.sync_count(), 1);
+    }
+
+    #[test]
+    fn append_batch_writes_prefix_before_failing_event() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&path).unwrap();
+        let mut oversized = make_event("src", 2_000_000_000);
+        oversized.payload = EventPayload::ToolCall {
+            tool: "big".into(),
+            args: Some("x".repeat(EVENTLOG_MAX_LINE_BYTES)),
+            invocation_id: None,
+        };
+        let events = vec![
+            make_event("src", 1_000_000_000),
+            oversized,
+            make_event("src", 3_000_000_000),
+        ];
+        assert!(writer.append_batch(events).is_err());
+        let next = writer.append(make_event("src", 4_000_000_000)).unwrap();
+        assert_eq!(next.committed_event().commit_index, 1);
+        drop(writer);
+        assert_eq!(read_eventlog(&path).unwrap().len(), 2);
+        assert!(verify_eventlog_chain(&path).unwrap().is_intact());
+    }
 
-        // Source A at t=2s, source B at t=3s.
-        writer.append(make_event("src-a", 2_000_000_000)).unwrap();
-        writer.append(make_event("src-b", 3_000_000_000)).unwrap();
-
-        // Source A goes backward (triggers), source B goes forward (no trigger).
-        let result_a = writer.append(make_event("src-a", 1_000_000_000)).unwrap();
-        let result_b = writer.append(make_event("src-b", 4_000_000_000)).unwrap();
+    #[test]
+    fn second_writer_is_refused_until_first_drops() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut first = EventLogWriter::open(&path).unwrap();
+        first.append(make_event("src", 1_000_000_000)).unwrap();
 
-        assert_eq!(
-            result_a.detection_events.len(),
-            1,
-            "source A should trigger"
-        );
+        let err = EventLogWriter::open(&path).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        let message = err.to_string();
         assert!(
-            result_b.detection_events.is_empty(),
-            "source B should not trigger"
+            message.contains(&format!(
+                "EventLog is locked by another process (pid {})",
+                std::process::id()
+            )),
+            "{message}"
         );
+        assert_eq!(read_eventlog(&path).unwrap().len(), 1);
+
+        drop(first);
+        let mut second = EventLogWriter::open(&path).unwrap();
+        let next = second.append(make_event("src", 2_000_000_000)).unwrap();
+        assert_eq!(next.committed_event().commit_index, 1);
     }
 
     #[test]
-    fn clock_skew_event_fields() {
+    fn segmented_writer_lock_covers_the_directory() {
         let dir = tempfile::tempdir().unwrap();
-        let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+        let segmented = dir.path().join("segmented");
+        let first =
+            EventLogWriter::open_segmented(&segmented, 2_000, DurabilityPolicy::OnDrop).unwrap();
+        assert!(segmented.join(WRITER_LOCK_FILE).exists());
+        let err = EventLogWriter::open(&segmented).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::WouldBlock);
+        drop(first);
+        EventLogWriter::open(&segmented).unwrap();
+    }
 
-        writer
-            .append(make_event("my-source", 5_000_000_000))
-            .unwrap();
-        let result = writer
-            .append(make_event("my-source", 3_000_000_000))
-            .unwrap();
+    #[test]
+    fn force_unlock_admits_a_new_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let stale = EventLogWriter::open(&path).unwrap();
+        assert!(EventLogWriter::open(&path).is_err());
 
-        let skew = &result.detection_events[0];
-        assert_eq!(skew.source_id, "my-source");
-        assert_eq!(skew.run_id, "run-1");
-        assert!(skew.event_id.starts_with("clock-skew:my-source:"));
-        assert_eq!(skew.tier, Tier::A);
-        assert!(skew.synthesized);
+        assert!(EventLogWriter::force_unlock(&path).unwrap());
+        let _writer = EventLogWriter::open(&path).unwrap();
+        drop(stale);
+        assert!(!EventLogWriter::force_unlock(&dir.path().join("missing.jsonl")).unwrap());
+    }
 
-        assert!(
-            matches!(&skew.payload, EventPayload::ClockSkewDetected { .. }),
-            "wrong payload type"
-        );
-        if let EventPayload::ClockSkewDetected {
-            expected_ns,
-            actual_ns,
-            delta_ns,
-        } = &skew.payload
+    #[test]
+    fn writer_index_matches_rebuilt_index_across_rolls() {
+        let dir = tempfile::tempdir().unwrap();
+        let single = dir.path().join("eventlog.jsonl");
+        let segmented = dir.path().join("segmented");
         {
-            assert_eq!(*expected_ns, 5_000_000_000);
-            assert_eq!(*actual_ns, 3_000_000_000);
-            assert_eq!(*delta_ns, 2_000_000_000);
+            let mut one = EventLogWriter::open(&single)
+                .unwrap()
+                .with_index(4)
+                .unwrap();
+            let mut many =
+                EventLogWriter::open_segmented(&segmented, 2_000, DurabilityPolicy::OnDrop)
+                    .unwrap()
+                    .with_index(4)
+                    .unwrap();
+            for i in 0..20 {
+                one.append(make_event("src", 1_000_000_000 + i)).unwrap();
+                many.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        // Reopening resumes the index without gaps.
+        {
+            let mut one = EventLogWriter::open(&single)
+                .unwrap()
+                .with_index(4)
+                .unwrap();
+            one.append(make_event("src", 2_000_000_000)).unwrap();
+        }
+
+        let mut files = vec![single];
+        for segment in eventlog_segments(&segmented).unwrap() {
+            files.push(segment);
+        }
+        assert!(files.len() > 2);
+        for file in files {
+            let written = EventLogIndex::load(&file).unwrap().unwrap();
+            assert_eq!(
+                build_index(&file, 4).unwrap(),
+                written,
+                "{}",
+                file.display()
+            );
         }
     }
 
     #[test]
-    fn read_eventlog_roundtrip() {
+    fn range_read_seeks_past_early_lines_through_index() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
-        let mut writer = EventLogWriter::open(&path).unwrap();
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap().with_index(4).unwrap();
+            for i in 0..12 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        // Overwrite the first line with same-length garbage: a seek skips it.
+        let mut bytes = std::fs::read(&path).unwrap();
+        let first_line = bytes.iter().position(|b| *b == b'\n').unwrap();
+        bytes[..first_line].fill(b'x');
+        std::fs::write(&path, &bytes).unwrap();
+
+        let indices: Vec<u64> = read_eventlog_range(&path, 9, 10)
+            .unwrap()
+            .iter()
+            .map(|e| e.commit_index)
+            .collect();
+        assert_eq!(indices, vec![9, 10]);
+        assert!(read_eventlog_range(&path, 0, 10).is_err());
+    }
 
-        for i in 0..5 {
-            writer
-                .append(make_event("test", 1_000_000_000 + i * 1_000_000))
-                .unwrap();
+    #[test]
+    fn range_read_scans_past_corrupt_or_stale_index_without_rewriting_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap().with_index(4).unwrap();
+            for i in 0..12 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
         }
-        drop(writer);
+        let good = EventLogIndex::load(&path).unwrap().unwrap();
+        let range = |from, to| -> Vec<u64> {
+            read_eventlog_range(&path, from, to)
+                .unwrap()
+                .iter()
+                .map(|e| e.commit_index)
+                .collect()
+        };
 
-        let events = read_eventlog(&path).unwrap();
-        assert_eq!(events.len(), 5);
-        for (i, event) in events.iter().enumerate() {
-            assert_eq!(event.commit_index, i as u64);
-            assert_eq!(event.source_id, "test");
+        // Offsets that land mid-line are caught and the file scanned; the
+        // index is left as found.
+        let mut bad = good.clone();
+        for entry in bad.entries.iter_mut().skip(1) {
+            entry.offset += 3;
         }
+        bad.save(&path).unwrap();
+        assert_eq!(range(8, 9), vec![8, 9]);
+        assert_eq!(EventLogIndex::load(&path).unwrap().unwrap(), bad);
+
+        // An explicit rebuild restores it.
+        assert_eq!(build_index(&path, 4).unwrap(), good);
+
+        // An index pointing past a truncated file is not used.
+        let bytes = std::fs::read(&path).unwrap();
+        let keep = bytes
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| **b == b'\n')
+            .nth(5)
+            .unwrap()
+            .0
+            + 1;
+        std::fs::write(&path, &bytes[..keep]).unwrap();
+        assert_eq!(range(4, 9), vec![4, 5]);
+        assert_eq!(EventLogIndex::load(&path).unwrap().unwrap(), good);
+
+        // Garbage bytes are ignored, not replaced.
+        std::fs::write(index_path(&path), b"garbage").unwrap();
+        assert_eq!(range(4, 5), vec![4, 5]);
+        assert_eq!(std::fs::read(index_path(&path)).unwrap(), b"garbage");
     }
 
     #[test]
-    fn clock_skew_detected_after_reopen() {
+    fn range_read_beside_live_writer_does_not_touch_its_index() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap().with_index(4).unwrap();
+        for i in 0..6 {
+            writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+        }
+        std::fs::write(index_path(&path), b"garbage").unwrap();
+
+        assert_eq!(read_eventlog_range(&path, 4, 5).unwrap().len(), 2);
+        assert_eq!(std::fs::read(index_path(&path)).unwrap(), b"garbage");
+    }
+
+    fn gzip_file(src: &Path, dst: &Path) {
+        use flate2::{write::GzEncoder, Compression};
+        let mut encoder = GzEncoder::new(File::create(dst).unwrap(), Compression::default());
+        encoder.write_all(&std::fs::read(src).unwrap()).unwrap();
+        encoder.finish().unwrap();
+    }
 
+    #[test]
+    fn gzipped_eventlog_reads_like_plain() {
+        let dir = tempfile::tempdir().unwrap();
+        let plain = dir.path().join("eventlog.jsonl");
         {
-            let mut writer = EventLogWriter::open(&path).unwrap();
-            writer.append(make_event("src-1", 2_000_000_000)).unwrap();
+            let mut writer = EventLogWriter::open(&plain).unwrap();
+            for i in 0..10 {
+                writer.append(make_event("src", 1_000_000_000 + i)).unwrap();
+            }
+        }
+        let gz = dir.path().join("eventlog.jsonl.gz");
+        gzip_file(&plain, &gz);
+        // Detected by magic bytes alone, too.
+        let disguised = dir.path().join("disguised.jsonl");
+        std::fs::copy(&gz, &disguised).unwrap();
+
+        let expected = read_eventlog(&plain).unwrap();
+        for path in [&gz, &disguised] {
+            assert!(is_gzip_eventlog(path).unwrap());
+            assert_eq!(read_eventlog(path).unwrap(), expected);
+            assert_eq!(read_eventlog_range(path, 3, 5).unwrap(), expected[3..=5]);
+            assert!(validate_eventlog(path).unwrap().is_valid());
+            assert!(verify_eventlog_chain(path).unwrap().is_intact());
         }
+        assert!(!is_gzip_eventlog(&plain).unwrap());
+        assert!(!index_path(&gz).exists());
+    }
 
-        // Reopen writer and ensure historical source timestamp is restored.
-        let mut writer = EventLogWriter::open(&path).unwrap();
-        let result = writer.append(make_event("src-1", 1_000_000_000)).unwrap();
+    #[test]
+    fn writer_refuses_gzipped_eventlog() {
+        let dir = tempfile::tempdir().unwrap();
+        let plain = dir.path().join("eventlog.jsonl");
+        EventLogWriter::open(&plain)
+            .unwrap()
+            .append(make_event("src", 1_000_000_000))
+            .unwrap();
+        let disguised = dir.path().join("disguised.jsonl");
+        gzip_file(&plain, &disguised);
 
-        assert_eq!(
-            result.detection_events.len(),
-            1,
-            "clock skew should still be detected after writer reopen"
-        );
-        assert!(
-            matches!(
-                &result.detection_events[0].payload,
-                EventPayload::ClockSkewDetected { .. }
-            ),
-            "expected ClockSkewDetected payload"
-        );
-        if let EventPayload::ClockSkewDetected {
-            expected_ns,
-            actual_ns,
-            delta_ns,
-        } = &result.detection_events[0].payload
-        {
-            assert_eq!(*expected_ns, 2_000_000_000);
-            assert_eq!(*actual_ns, 1_000_000_000);
-            assert_eq!(*delta_ns, 1_000_000_000);
+        for path in [dir.path().join("new.jsonl.gz"), disguised] {
+            let err = EventLogWriter::open(&path).err().unwrap();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
         }
+        assert!(!dir.path().join("new.jsonl.gz").exists());
     }
 
     #[test]
-    fn open_fails_loudly_on_malformed_existing_line() {
+    fn future_payload_type_degrades_to_generic_with_warning() {
         let dir = tempfile::tempdir().unwrap();
         let path = dir.path().join("eventlog.jsonl");
-        std::fs::write(&path, "{\"not\":\"a-committed-event\"}\n").unwrap();
-
-        let result = EventLogWriter::open(&path);
-        assert!(
-            result.is_err(),
-            "open() should fail for malformed existing EventLog"
-        );
-        let err = result.err().unwrap();
-        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
-        assert!(err.to_string().contains("failed to parse EventLog line 1"));
+        {
+            let mut writer = EventLogWriter::open(&path).unwrap();
+            writer.append(make_event("src", 1_000_000_000)).unwrap();
+        }
+        let future = r#"{"commit_index":1,"run_id":"run-1","event_id":"src:2","source_id":"src","timestamp_ns":2000000000,"tier":"B","payload":{"type":"Thought","text":"hmm"},"schema_version":2}"#;
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes.extend_from_slice(future.as_bytes());
+        bytes.push(b'\n');
+        std::fs::write(&path, bytes).unwrap();
+
+        let outcome = read_eventlog_recovering(&path).unwrap();
+        assert_eq!(outcome.events.len(), 2);
+        assert_eq!(outcome.events[1].schema_version, 2);
+        assert_eq!(outcome.events[1].payload.event_type_name(), "Generic");
+        assert_eq!(outcome.integrity.unknown_payloads, 1);
+        assert!(outcome.integrity.is_clean());
+
+        let validation = validate_eventlog(&path).unwrap();
+        assert!(validation.is_valid());
+        assert_eq!(validation.integrity.unknown_payloads, 1);
+        assert_eq!(read_eventlog(&path).unwrap(), outcome.events);
     }
 }
@@ -0,0 +1,312 @@
+//! Sidecar byte-offset index for seeking into an EventLog file.
+//!
+//! `<eventlog>.idx` maps sampled `commit_index` values to the byte offset
+//! of their line: the first event of the file and every event whose
+//! `commit_index` is a multiple of the interval. The index is derived data.
+//! Readers check that the line found at an offset parses and carries the
+//! expected `commit_index` before trusting it, and scan from the start of
+//! the file when it is missing, corrupt, or stale, so a bad index costs a
+//! linear scan but never returns wrong events.
+//!
+//! Readers never write the index: a reader may run beside a live writer
+//! that is appending to it. Rebuilding is an explicit step, either
+//! [`build_index`] or opening a writer [`EventLogWriter::with_index`].
+//!
+//! # Format
+//!
+//! Fixed-width little-endian binary: the 8-byte magic `VIFEIDX1`, the
+//! interval as a `u64`, then one 16-byte entry per sampled event
+//! (`commit_index`, then `offset`, both `u64`) in file order. Entries are
+//! only ever appended, so [`EventLogWriter::with_index`] extends the index
+//! without rewriting it.
+//!
+//! [`EventLogWriter::with_index`]: crate::eventlog::EventLogWriter::with_index
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+/// Default sampling interval, in events.
+pub const DEFAULT_INDEX_INTERVAL: u64 = 1024;
+
+const INDEX_MAGIC: &[u8; 8] = b"VIFEIDX1";
+const HEADER_BYTES: usize = 16;
+const ENTRY_BYTES: usize = 16;
+
+/// One sampled event: its `commit_index` and the byte offset of its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexEntry {
+    pub commit_index: u64,
+    pub offset: u64,
+}
+
+/// In-memory form of a sidecar index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventLogIndex {
+    /// Sampling interval, in events (at least 1).
+    pub interval: u64,
+    /// Entries in file order; both fields strictly increase.
+    pub entries: Vec<IndexEntry>,
+}
+
+/// Sidecar index path for `eventlog` (`eventlog.jsonl` → `eventlog.jsonl.idx`).
+pub fn index_path(eventlog: &Path) -> PathBuf {
+    let mut name = eventlog.as_os_str().to_owned();
+    name.push(".idx");
+    PathBuf::from(name)
+}
+
+/// Whether the event at `commit_index` gets an entry.
+pub(crate) fn is_sampled(interval: u64, commit_index: u64, first_in_file: bool) -> bool {
+    first_in_file || commit_index.is_multiple_of(interval)
+}
+
+pub(crate) fn header_bytes(interval: u64) -> [u8; HEADER_BYTES] {
+    let mut bytes = [0; HEADER_BYTES];
+    bytes[..8].copy_from_slice(INDEX_MAGIC);
+    bytes[8..].copy_from_slice(&interval.to_le_bytes());
+    bytes
+}
+
+pub(crate) fn entry_bytes(entry: IndexEntry) -> [u8; ENTRY_BYTES] {
+    let mut bytes = [0; ENTRY_BYTES];
+    bytes[..8].copy_from_slice(&entry.commit_index.to_le_bytes());
+    bytes[8..].copy_from_slice(&entry.offset.to_le_bytes());
+    bytes
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(&bytes[..8]);
+    u64::from_le_bytes(buf)
+}
+
+impl EventLogIndex {
+    /// Empty index sampling every `interval` events (0 is treated as 1).
+    pub fn new(interval: u64) -> Self {
+        EventLogIndex {
+            interval: interval.max(1),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Decode an index, failing with `InvalidData` on a bad header, a
+    /// partial entry, or entries that do not strictly increase.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        if bytes.len() < HEADER_BYTES || &bytes[..8] != INDEX_MAGIC {
+            return Err(invalid("EventLog index has no VIFEIDX1 header"));
+        }
+        let interval = read_u64(&bytes[8..]);
+        let body = &bytes[HEADER_BYTES..];
+        if interval == 0 || !body.len().is_multiple_of(ENTRY_BYTES) {
+            return Err(invalid("EventLog index header or entries are truncated"));
+        }
+        let mut index = EventLogIndex::new(interval);
+        for chunk in body.chunks_exact(ENTRY_BYTES) {
+            let entry = IndexEntry {
+                commit_index: read_u64(chunk),
+                offset: read_u64(&chunk[8..]),
+            };
+            if let Some(last) = index.entries.last() {
+                if entry.commit_index <= last.commit_index || entry.offset <= last.offset {
+                    return Err(invalid("EventLog index entries are out of order"));
+                }
+            }
+            index.entries.push(entry);
+        }
+        Ok(index)
+    }
+
+    /// Encode in the sidecar format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_BYTES + self.entries.len() * ENTRY_BYTES);
+        bytes.extend(header_bytes(self.interval));
+        for entry in &self.entries {
+            bytes.extend(entry_bytes(*entry));
+        }
+        bytes
+    }
+
+    /// Load the sidecar index of `eventlog`, or `None` if there is none.
+    pub fn load(eventlog: &Path) -> io::Result<Option<Self>> {
+        match fs::read(index_path(eventlog)) {
+            Ok(bytes) => Self::from_bytes(&bytes).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Atomically replace the sidecar index of `eventlog`.
+    pub fn save(&self, eventlog: &Path) -> io::Result<()> {
+        let path = index_path(eventlog);
+        let mut tmp = path.clone().into_os_string();
+        tmp.push(".tmp");
+        fs::write(&tmp, self.to_bytes())?;
+        fs::rename(tmp, path)
+    }
+
+    /// Last entry at or before `commit_index`.
+    pub fn seek_entry(&self, commit_index: u64) -> Option<IndexEntry> {
+        let after = self
+            .entries
+            .partition_point(|e| e.commit_index <= commit_index);
+        after.checked_sub(1).map(|i| self.entries[i])
+    }
+}
+
+/// Just the field the index needs, so building skips full event parsing.
+#[derive(Deserialize)]
+struct CommitIndexOnly {
+    commit_index: u64,
+}
+
+/// Scan `eventlog` and write a fresh sidecar index beside it.
+///
+/// Unparseable lines are skipped; they are never seek targets.
+pub fn build_index(eventlog: &Path, interval: u64) -> io::Result<EventLogIndex> {
+    let mut reader = BufReader::new(File::open(eventlog)?);
+    let mut index = EventLogIndex::new(interval);
+    let mut buf = Vec::new();
+    let mut offset = 0u64;
+    loop {
+        buf.clear();
+        let read = reader.read_until(b'\n', &mut buf)?;
+        if read == 0 {
+            break;
+        }
+        if let Ok(line) = serde_json::from_slice::<CommitIndexOnly>(buf.trim_ascii()) {
+            let first_in_file = index.entries.is_empty();
+            let increasing = index
+                .entries
+                .last()
+                .is_none_or(|last| last.commit_index < line.commit_index);
+            if increasing && is_sampled(index.interval, line.commit_index, first_in_file) {
+                index.entries.push(IndexEntry {
+                    commit_index: line.commit_index,
+                    offset,
+                });
+            }
+        }
+        offset += read as u64;
+    }
+    index.save(eventlog)?;
+    Ok(index)
+}
+
+/// Open `eventlog` positioned at the start of a line at or before the event
+/// with `commit_index`, seeking through its sidecar index when one checks
+/// out.
+///
+/// Falls back to the start of the file when the index is missing, corrupt,
+/// points past the end of the file, or names the wrong line. The index is
+/// only read; an index that is behind the file still serves the events it
+/// covers.
+pub(crate) fn open_near(eventlog: &Path, commit_index: u64) -> io::Result<BufReader<File>> {
+    let file = File::open(eventlog)?;
+    let len = file.metadata()?.len();
+    let mut reader = BufReader::new(file);
+
+    let index = match EventLogIndex::load(eventlog) {
+        Ok(Some(index)) if index.entries.last().is_none_or(|e| e.offset < len) => index,
+        _ => return Ok(reader),
+    };
+    let Some(entry) = index.seek_entry(commit_index) else {
+        return Ok(reader);
+    };
+    let offset = if line_has_commit_index(&mut reader, entry)? {
+        entry.offset
+    } else {
+        0
+    };
+    reader.seek(SeekFrom::Start(offset))?;
+    Ok(reader)
+}
+
+/// Whether the line at `entry.offset` parses with `entry.commit_index`.
+fn line_has_commit_index(reader: &mut BufReader<File>, entry: IndexEntry) -> io::Result<bool> {
+    reader.seek(SeekFrom::Start(entry.offset))?;
+    let mut buf = Vec::new();
+    reader.read_until(b'\n', &mut buf)?;
+    Ok(serde_json::from_slice::<CommitIndexOnly>(buf.trim_ascii())
+        .is_ok_and(|line| line.commit_index == entry.commit_index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip() {
+        let index = EventLogIndex {
+            interval: 4,
+            entries: vec![
+                IndexEntry {
+                    commit_index: 0,
+                    offset: 0,
+                },
+                IndexEntry {
+                    commit_index: 4,
+                    offset: 812,
+                },
+            ],
+        };
+        assert_eq!(EventLogIndex::from_bytes(&index.to_bytes()).unwrap(), index);
+    }
+
+    #[test]
+    fn from_bytes_rejects_corruption() {
+        let mut bytes = EventLogIndex {
+            interval: 4,
+            entries: vec![
+                IndexEntry {
+                    commit_index: 4,
+                    offset: 100,
+                },
+                IndexEntry {
+                    commit_index: 8,
+                    offset: 50,
+                },
+            ],
+        }
+        .to_bytes();
+        assert!(EventLogIndex::from_bytes(&bytes).is_err());
+        bytes.truncate(HEADER_BYTES + 3);
+        assert!(EventLogIndex::from_bytes(&bytes).is_err());
+        assert!(EventLogIndex::from_bytes(b"not an index at all").is_err());
+    }
+
+    #[test]
+    fn seek_entry_picks_last_entry_at_or_before() {
+        let index = EventLogIndex {
+            interval: 10,
+            entries: vec![
+                IndexEntry {
+                    commit_index: 3,
+                    offset: 0,
+                },
+                IndexEntry {
+                    commit_index: 10,
+                    offset: 700,
+                },
+            ],
+        };
+        assert_eq!(index.seek_entry(2), None);
+        assert_eq!(index.seek_entry(9).unwrap().commit_index, 3);
+        assert_eq!(index.seek_entry(10).unwrap().offset, 700);
+        assert_eq!(index.seek_entry(99).unwrap().commit_index, 10);
+    }
+
+    #[test]
+    fn index_path_appends_idx() {
+        assert_eq!(
+            index_path(Path::new("run/eventlog.jsonl")),
+            PathBuf::from("run/eventlog.jsonl.idx")
+        );
+    }
+}
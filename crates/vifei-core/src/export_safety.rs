@@ -0,0 +1,138 @@
+//! Persisted export scan result for the Truth HUD.
+//!
+//! `run_export` records its verdict in a sidecar next to the EventLog,
+//! `<eventlog>.export-safety.json`, holding the [`ExportSafetyState`], the
+//! scanner version, and the BLAKE3 of the EventLog bytes it scanned. A
+//! viewer reads it back with [`load_export_safety`] and passes the state to
+//! projection through [`ProjectionInvariants::with_export_safety`].
+//!
+//! The verdict is only as good as the bytes it was computed over. A sidecar
+//! whose hash no longer matches the EventLog (it was appended to, or
+//! replaced) is stale, and so is one that is missing or unreadable: all
+//! three load as [`ExportSafetyState::Unknown`].
+//!
+//! [`ProjectionInvariants::with_export_safety`]: crate::projection::ProjectionInvariants::with_export_safety
+
+use crate::eventlog::eventlog_segments;
+use crate::projection::ExportSafetyState;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Contents of `<eventlog>.export-safety.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportSafetySidecar {
+    /// Verdict of the scan.
+    pub state: ExportSafetyState,
+    /// Scanner version that produced the verdict.
+    pub scanner_version: String,
+    /// BLAKE3 hex digest of the EventLog bytes at scan time.
+    pub eventlog_blake3: String,
+}
+
+/// Sidecar path of `eventlog` (`eventlog.jsonl` →
+/// `eventlog.jsonl.export-safety.json`).
+pub fn export_safety_path(eventlog: &Path) -> PathBuf {
+    let mut name = eventlog.as_os_str().to_owned();
+    name.push(".export-safety.json");
+    PathBuf::from(name)
+}
+
+/// BLAKE3 hex digest of the EventLog at `eventlog`: the bytes of its
+/// segments in commit order, or of the single file.
+pub fn eventlog_blake3(eventlog: &Path) -> io::Result<String> {
+    let mut hasher = blake3::Hasher::new();
+    for segment in eventlog_segments(eventlog)? {
+        io::copy(&mut File::open(segment)?, &mut hasher)?;
+    }
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Record `state` for the current bytes of `eventlog`, replacing any
+/// earlier sidecar.
+pub fn write_export_safety(
+    eventlog: &Path,
+    state: ExportSafetyState,
+    scanner_version: &str,
+) -> io::Result<()> {
+    let sidecar = ExportSafetySidecar {
+        state,
+        scanner_version: scanner_version.to_string(),
+        eventlog_blake3: eventlog_blake3(eventlog)?,
+    };
+    let json = serde_json::to_string_pretty(&sidecar).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("JSON serialization failed: {e}"),
+        )
+    })?;
+    fs::write(export_safety_path(eventlog), json)
+}
+
+/// Read the sidecar of `eventlog`. `Ok(None)` when there is none.
+pub fn read_export_safety(eventlog: &Path) -> io::Result<Option<ExportSafetySidecar>> {
+    let bytes = match fs::read(export_safety_path(eventlog)) {
+        Ok(bytes) => bytes,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    serde_json::from_slice(&bytes)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Export safety of `eventlog` as recorded by its sidecar, or
+/// [`ExportSafetyState::Unknown`] when the sidecar is missing, unreadable,
+/// or was written for different EventLog bytes.
+pub fn load_export_safety(eventlog: &Path) -> ExportSafetyState {
+    let Ok(Some(sidecar)) = read_export_safety(eventlog) else {
+        return ExportSafetyState::Unknown;
+    };
+    match eventlog_blake3(eventlog) {
+        Ok(hash) if hash == sidecar.eventlog_blake3 => sidecar.state,
+        _ => ExportSafetyState::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn sidecar_round_trips_while_eventlog_is_unchanged() {
+        let dir = tempdir().unwrap();
+        let eventlog = dir.path().join("eventlog.jsonl");
+        fs::write(&eventlog, "{}\n").unwrap();
+        assert_eq!(load_export_safety(&eventlog), ExportSafetyState::Unknown);
+
+        for state in ExportSafetyState::ALL {
+            write_export_safety(&eventlog, state, "secret-scanner-v0.1").unwrap();
+            assert_eq!(load_export_safety(&eventlog), state);
+        }
+        let sidecar = read_export_safety(&eventlog).unwrap().unwrap();
+        assert_eq!(sidecar.scanner_version, "secret-scanner-v0.1");
+        assert_eq!(
+            export_safety_path(&eventlog),
+            dir.path().join("eventlog.jsonl.export-safety.json")
+        );
+    }
+
+    #[test]
+    fn stale_or_corrupt_sidecar_loads_as_unknown() {
+        let dir = tempdir().unwrap();
+        let eventlog = dir.path().join("eventlog.jsonl");
+        fs::write(&eventlog, "{}\n").unwrap();
+        write_export_safety(&eventlog, ExportSafetyState::Clean, "v").unwrap();
+
+        let mut file = fs::OpenOptions::new().append(true).open(&eventlog).unwrap();
+        file.write_all(b"{}\n").unwrap();
+        assert_eq!(load_export_safety(&eventlog), ExportSafetyState::Unknown);
+
+        fs::write(export_safety_path(&eventlog), "not json").unwrap();
+        assert!(read_export_safety(&eventlog).is_err());
+        assert_eq!(load_export_safety(&eventlog), ExportSafetyState::Unknown);
+    }
+}
@@ -1,6 +1,14 @@
+pub mod backpressure;
 pub mod blob_store;
+pub mod canonical;
+pub mod checkpoint_store;
 pub mod delta;
+pub mod delta_render;
+pub mod error;
 pub mod event;
 pub mod eventlog;
+pub mod eventlog_index;
+pub mod export_safety;
 pub mod projection;
 pub mod reducer;
+pub mod time;
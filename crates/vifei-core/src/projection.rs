@@ -27,9 +27,16 @@
 //! - `tier_a_summaries` (BTreeMap)
 //! - `aggregation_mode` (String)
 //! - `aggregation_bin_size` (Option<u64>)
+//! - `tier_bc_bins` (Vec<BinSummary>, filled at L1)
+//! - `tier_bc_collapsed` (BTreeMap, filled at L2 through L4)
 //! - `degradation_level` (LadderLevel)
 //! - `queue_pressure_fixed` (i64, quantized from f64 for determinism)
 //! - `tier_a_drops` (u64)
+//! - `source_gap_count` (u64)
+//! - `tool_error_rate_micro` (BTreeMap, error rate in millionths per tool)
+//! - `top_tools` (Vec, the [`TOP_TOOLS_LIMIT`] most-called tools)
+//! - `error_count` (u64)
+//! - `last_error_kind` (Option<String>)
 //! - `export_safety_state` (ExportSafetyState)
 //! - `projection_invariants_version` (String)
 //!
@@ -40,6 +47,9 @@
 //! - wall clock / timestamps
 //! - random values
 //!
+//! When two hashes disagree, [`diff_viewmodels`] names the fields that
+//! differ.
+//!
 //! # Invariants enforced
 //!
 //! - **I2 (Deterministic projection):** ViewModel is deterministic given State + invariants.
@@ -51,9 +61,11 @@
 //! - "Projection invariants v0.1" — honesty mechanics rules.
 //! - "Degradation ladder" — L0 through L5 definitions.
 
-use crate::reducer::State;
+use crate::canonical::{canonical_bytes, CANONICAL_JSON_VERSION};
+use crate::delta::flatten_json;
+use crate::reducer::{State, TIER_BC_BIN_SIZE};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 use std::str::FromStr;
 
@@ -68,7 +80,25 @@ use std::str::FromStr;
 /// - The ViewModel include/exclude list for hashing changes.
 ///
 /// Embedded in ViewModel, `metrics.json`, and `timetravel.capture`.
-pub const PROJECTION_INVARIANTS_VERSION: &str = "projection-invariants-v0.1";
+pub const PROJECTION_INVARIANTS_VERSION: &str = "projection-invariants-v0.7";
+
+/// Event types summarized as Tier A by default.
+///
+/// PLANS.md D2: RunStart, RunEnd, ToolCall, ToolResult, PolicyDecision,
+/// RedactionApplied, Error, ClockSkewDetected; TokenUsage and
+/// SubagentSpawned joined in projection-invariants-v0.3.
+pub const DEFAULT_TIER_A_TYPES: [&str; 10] = [
+    "RunStart",
+    "RunEnd",
+    "ToolCall",
+    "ToolResult",
+    "PolicyDecision",
+    "RedactionApplied",
+    "Error",
+    "ClockSkewDetected",
+    "TokenUsage",
+    "SubagentSpawned",
+];
 
 // ---------------------------------------------------------------------------
 // LadderLevel (M5.1)
@@ -242,6 +272,9 @@ impl FromStr for LadderLevel {
 ///   invariant rules change. Embedded in ViewModel and proof artifacts.
 /// - `degradation_level`: Current position on the degradation ladder.
 ///   Controls how Tier B/C events are rendered (aggregated, collapsed, etc.).
+/// - `tier_a_types`: Event types summarized in `tier_a_summaries`. A list
+///   other than [`DEFAULT_TIER_A_TYPES`] changes the projected version (see
+///   [`ProjectionInvariants::effective_version`]).
 ///
 /// # Constitution
 ///
@@ -256,15 +289,27 @@ pub struct ProjectionInvariants {
     /// Current degradation ladder level.
     /// Default: [`LadderLevel::L0`] (normal operation).
     pub degradation_level: LadderLevel,
+
+    /// Export safety read from a persisted scan result (see
+    /// [`crate::export_safety`]). `None` projects as
+    /// [`ExportSafetyState::Unknown`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_safety: Option<ExportSafetyState>,
+
+    /// Event type names summarized in `tier_a_summaries`.
+    /// Default: [`DEFAULT_TIER_A_TYPES`].
+    #[serde(default = "default_tier_a_types")]
+    pub tier_a_types: Vec<String>,
+}
+
+fn default_tier_a_types() -> Vec<String> {
+    DEFAULT_TIER_A_TYPES.iter().map(|t| t.to_string()).collect()
 }
 
 impl ProjectionInvariants {
     /// Create projection invariants with default version and L0 (normal).
     pub fn new() -> Self {
-        ProjectionInvariants {
-            version: PROJECTION_INVARIANTS_VERSION.to_string(),
-            degradation_level: LadderLevel::L0,
-        }
+        Self::with_level(LadderLevel::L0)
     }
 
     /// Create projection invariants with a specific degradation level.
@@ -272,7 +317,49 @@ impl ProjectionInvariants {
         ProjectionInvariants {
             version: PROJECTION_INVARIANTS_VERSION.to_string(),
             degradation_level: level,
+            export_safety: None,
+            tier_a_types: default_tier_a_types(),
+        }
+    }
+
+    /// Summarize `types` as Tier A instead of [`DEFAULT_TIER_A_TYPES`],
+    /// for example to add an org-specific `Generic` type.
+    pub fn with_tier_a_types<I, T>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        self.tier_a_types = types.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Version string embedded in the projected ViewModel.
+    ///
+    /// `version` itself while `tier_a_types` holds the default set, and
+    /// `<version>+custom-<hash>` otherwise, where `<hash>` is the first 12
+    /// hex digits of the BLAKE3 of the sorted, deduplicated list. Two
+    /// different lists therefore never share a version string. Order and
+    /// duplicates do not matter, as they do not change the projection.
+    pub fn effective_version(&self) -> String {
+        let mut types: Vec<&str> = self.tier_a_types.iter().map(String::as_str).collect();
+        types.sort_unstable();
+        types.dedup();
+        let mut default = DEFAULT_TIER_A_TYPES;
+        default.sort_unstable();
+        if types == default {
+            return self.version.clone();
         }
+        let bytes =
+            serde_json::to_vec(&types).expect("string list serialization should never fail");
+        let hash = blake3::hash(&bytes).to_hex();
+        format!("{}+custom-{}", self.version, &hash[..12])
+    }
+
+    /// Project `state` as the export safety instead of
+    /// [`ExportSafetyState::Unknown`].
+    pub fn with_export_safety(mut self, state: ExportSafetyState) -> Self {
+        self.export_safety = Some(state);
+        self
     }
 
     /// Returns true if operating at normal level.
@@ -298,8 +385,9 @@ impl Default for ProjectionInvariants {
 
 /// Export safety state for the Truth HUD.
 ///
-/// Indicates whether the EventLog is safe to share externally. Until
-/// M8 (share-safe export) is implemented, the state remains [`Unknown`].
+/// Indicates whether the EventLog is safe to share externally. The state
+/// comes from [`ProjectionInvariants::export_safety`], which viewers fill
+/// from the sidecar `run_export` writes; without one it is [`Unknown`].
 ///
 /// # Constitution
 ///
@@ -307,7 +395,7 @@ impl Default for ProjectionInvariants {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ExportSafetyState {
-    /// Export safety has not been evaluated. Default until M8 export scan.
+    /// Export safety has not been evaluated, or the scan is stale.
     #[default]
     Unknown,
     /// No secrets detected. Safe to export.
@@ -398,6 +486,29 @@ impl FromStr for ExportSafetyState {
 /// 6 decimal places = 1,000,000.
 pub(crate) const QUEUE_PRESSURE_PRECISION: i64 = 1_000_000;
 
+/// Tier B/C events committed in one aggregation bin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinSummary {
+    /// First `commit_index` the bin covers.
+    pub first_commit_index: u64,
+    /// Last `commit_index` the bin covers.
+    pub last_commit_index: u64,
+    /// Tier B/C events committed in the range.
+    pub count: u64,
+}
+
+/// Tools kept in [`ViewModel::top_tools`].
+pub const TOP_TOOLS_LIMIT: usize = 10;
+
+/// Call and error totals of one tool, as carried in the ViewModel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ToolSummaryLite {
+    /// ToolCall events for the tool.
+    pub call_count: u64,
+    /// ToolResult events with error status.
+    pub error_count: u64,
+}
+
 /// The hashable data structure that drives the TUI.
 ///
 /// ViewModel is the output of the projection function and the input to
@@ -439,6 +550,13 @@ pub struct ViewModel {
     /// Bin size when aggregating, or None for 1:1 mode.
     pub aggregation_bin_size: Option<u64>,
 
+    /// Tier B/C event counts per `commit_index` bin, in commit order.
+    /// Filled at L1 only; bins with no Tier B/C events are omitted.
+    pub tier_bc_bins: Vec<BinSummary>,
+
+    /// Tier B/C event totals by type. Filled at L2 through L4 only.
+    pub tier_bc_collapsed: BTreeMap<String, u64>,
+
     /// Current degradation ladder level.
     pub degradation_level: LadderLevel,
 
@@ -451,6 +569,25 @@ pub struct ViewModel {
     /// MUST be 0 in normal operation (invariant I1).
     pub tier_a_drops: u64,
 
+    /// Sequence gaps across all sources: messages the recorder lost
+    /// before they reached the EventLog. Confessed next to `tier_a_drops`.
+    pub source_gap_count: u64,
+
+    /// Tool error rate in millionths (`error_count * 1_000_000 / result_count`),
+    /// keyed by tool name. Integer so it hashes deterministically.
+    /// Tools with no results yet have no rate and are omitted.
+    pub tool_error_rate_micro: BTreeMap<String, u64>,
+
+    /// The [`TOP_TOOLS_LIMIT`] most-called tools, by `call_count`
+    /// descending, then name.
+    pub top_tools: Vec<(String, ToolSummaryLite)>,
+
+    /// Error events in the log.
+    pub error_count: u64,
+
+    /// `kind` of the last Error event, if any.
+    pub last_error_kind: Option<String>,
+
     /// Export safety state for the Truth HUD.
     pub export_safety_state: ExportSafetyState,
 
@@ -465,14 +602,38 @@ impl ViewModel {
             tier_a_summaries: BTreeMap::new(),
             aggregation_mode: "1:1".to_string(),
             aggregation_bin_size: None,
+            tier_bc_bins: Vec::new(),
+            tier_bc_collapsed: BTreeMap::new(),
             degradation_level: LadderLevel::L0,
             queue_pressure_fixed: 0,
             tier_a_drops: 0,
+            source_gap_count: 0,
+            tool_error_rate_micro: BTreeMap::new(),
+            top_tools: Vec::new(),
+            error_count: 0,
+            last_error_kind: None,
             export_safety_state: ExportSafetyState::Unknown,
             projection_invariants_version: PROJECTION_INVARIANTS_VERSION.to_string(),
         }
     }
 
+    /// Aggregation mode with what it actually aggregated: the bin count
+    /// at L1 and the collapsed event total at L2 through L4.
+    pub fn aggregation_summary(&self) -> String {
+        if let Some(bin) = self.aggregation_bin_size {
+            return format!(
+                "{} (bin={bin}, {} bins)",
+                self.aggregation_mode,
+                self.tier_bc_bins.len()
+            );
+        }
+        if self.degradation_level.should_collapse() && !self.degradation_level.is_safe_failure() {
+            let total: u64 = self.tier_bc_collapsed.values().sum();
+            return format!("{} ({total} events)", self.aggregation_mode);
+        }
+        self.aggregation_mode.clone()
+    }
+
     /// Get queue pressure as f64 in range [0.0, 1.0].
     pub fn queue_pressure(&self) -> f64 {
         self.queue_pressure_fixed as f64 / QUEUE_PRESSURE_PRECISION as f64
@@ -504,6 +665,17 @@ impl ViewModel {
     pub fn is_ui_frozen(&self) -> bool {
         self.degradation_level.is_ui_frozen()
     }
+
+    /// Tool with the highest nonzero error rate, ties broken by tool name.
+    pub fn worst_tool_error_rate(&self) -> Option<(&str, u64)> {
+        self.tool_error_rate_micro
+            .iter()
+            .filter(|(_, &rate)| rate > 0)
+            .fold(None, |worst, (tool, &rate)| match worst {
+                Some((_, worst_rate)) if worst_rate >= rate => worst,
+                _ => Some((tool.as_str(), rate)),
+            })
+    }
 }
 
 impl Default for ViewModel {
@@ -544,34 +716,38 @@ impl Default for ViewModel {
 /// - L4 (Freeze UI): Same as L3, UI frozen except Truth HUD.
 /// - L5 (Safe Failure): Minimal data, failure state indication.
 pub fn project(state: &State, invariants: &ProjectionInvariants) -> ViewModel {
-    // Build tier_a_summaries from event_counts_by_type, filtering for Tier A types.
-    // Tier A event types from PLANS.md D2: RunStart, RunEnd, ToolCall, ToolResult,
-    // PolicyDecision, RedactionApplied, Error, ClockSkewDetected.
-    let tier_a_types = [
-        "RunStart",
-        "RunEnd",
-        "ToolCall",
-        "ToolResult",
-        "PolicyDecision",
-        "RedactionApplied",
-        "Error",
-        "ClockSkewDetected",
-    ];
-
+    // Build tier_a_summaries from event_counts_by_type, filtering for the
+    // configured Tier A types (DEFAULT_TIER_A_TYPES unless overridden).
     let mut tier_a_summaries = BTreeMap::new();
-    for type_name in &tier_a_types {
-        if let Some(&count) = state.event_counts_by_type.get(*type_name) {
+    for type_name in &invariants.tier_a_types {
+        if let Some(&count) = state.event_counts_by_type.get(type_name) {
             if count > 0 {
                 tier_a_summaries.insert(type_name.to_string(), count);
             }
         }
     }
 
-    // Determine aggregation mode based on degradation level.
+    // Aggregate Tier B/C events based on degradation level.
+    let mut tier_bc_bins = Vec::new();
+    let mut tier_bc_collapsed = BTreeMap::new();
     let (aggregation_mode, aggregation_bin_size) = match invariants.degradation_level {
         LadderLevel::L0 => ("1:1".to_string(), None),
-        LadderLevel::L1 => ("10:1".to_string(), Some(10)),
-        LadderLevel::L2 | LadderLevel::L3 | LadderLevel::L4 => ("collapsed".to_string(), None),
+        LadderLevel::L1 => {
+            tier_bc_bins = state
+                .tier_bc_bins
+                .iter()
+                .map(|(&bin, &count)| BinSummary {
+                    first_commit_index: bin * TIER_BC_BIN_SIZE,
+                    last_commit_index: bin * TIER_BC_BIN_SIZE + TIER_BC_BIN_SIZE - 1,
+                    count,
+                })
+                .collect();
+            (format!("{TIER_BC_BIN_SIZE}:1"), Some(TIER_BC_BIN_SIZE))
+        }
+        LadderLevel::L2 | LadderLevel::L3 | LadderLevel::L4 => {
+            tier_bc_collapsed = state.tier_bc_by_type.clone();
+            ("collapsed".to_string(), None)
+        }
         LadderLevel::L5 => ("frozen".to_string(), None),
     };
 
@@ -583,15 +759,48 @@ pub fn project(state: &State, invariants: &ProjectionInvariants) -> ViewModel {
         .map(|pd| pd.queue_pressure_micro as i64)
         .unwrap_or(0);
 
+    // Integer rate per tool; tools with no results would divide by zero.
+    let tool_error_rate_micro = state
+        .tool_summaries
+        .iter()
+        .filter(|(_, summary)| summary.result_count > 0)
+        .map(|(tool, summary)| {
+            let rate = summary.error_count.saturating_mul(1_000_000) / summary.result_count;
+            (tool.clone(), rate)
+        })
+        .collect();
+
+    let mut top_tools: Vec<(String, ToolSummaryLite)> = state
+        .tool_summaries
+        .iter()
+        .map(|(tool, summary)| {
+            let lite = ToolSummaryLite {
+                call_count: summary.call_count,
+                error_count: summary.error_count,
+            };
+            (tool.clone(), lite)
+        })
+        .collect();
+    // tool_summaries is name-ordered, so the stable sort breaks ties by name.
+    top_tools.sort_by_key(|(_, tool)| std::cmp::Reverse(tool.call_count));
+    top_tools.truncate(TOP_TOOLS_LIMIT);
+
     ViewModel {
         tier_a_summaries,
         aggregation_mode,
         aggregation_bin_size,
+        tier_bc_bins,
+        tier_bc_collapsed,
         degradation_level: invariants.degradation_level,
         queue_pressure_fixed,
         tier_a_drops: state.tier_a_drops,
-        export_safety_state: ExportSafetyState::Unknown, // Until M8 export scan
-        projection_invariants_version: invariants.version.clone(),
+        source_gap_count: state.source_gap_count(),
+        tool_error_rate_micro,
+        top_tools,
+        error_count: state.error_log.len() as u64,
+        last_error_kind: state.error_log.last().map(|e| e.kind.clone()),
+        export_safety_state: invariants.export_safety.unwrap_or_default(),
+        projection_invariants_version: invariants.effective_version(),
     }
 }
 
@@ -624,15 +833,22 @@ pub fn project_with_pressure(
 /// - `tier_a_summaries`: BTreeMap<String, u64>
 /// - `aggregation_mode`: String
 /// - `aggregation_bin_size`: Option<u64>
+/// - `tier_bc_bins`: Vec<BinSummary> (since v0.5)
+/// - `tier_bc_collapsed`: BTreeMap<String, u64> (since v0.5)
 /// - `degradation_level`: LadderLevel
 /// - `queue_pressure_fixed`: i64 (quantized from f64, avoids float nondeterminism)
 /// - `tier_a_drops`: u64
+/// - `source_gap_count`: u64 (since v0.4)
+/// - `tool_error_rate_micro`: BTreeMap<String, u64> (since v0.2)
+/// - `top_tools`: Vec<(String, ToolSummaryLite)> (since v0.6)
+/// - `error_count`: u64 (since v0.6)
+/// - `last_error_kind`: Option<String> (since v0.6)
 /// - `export_safety_state`: ExportSafetyState
 /// - `projection_invariants_version`: String
 ///
 /// # Exclude list
 ///
-/// Nothing is excluded in v0.1. All ViewModel fields are meaningful for
+/// Nothing is excluded. All ViewModel fields are meaningful for
 /// determinism verification.
 ///
 /// # Float handling
@@ -644,8 +860,9 @@ pub fn project_with_pressure(
 /// # Determinism
 ///
 /// - ViewModel uses BTreeMap (not HashMap) for `tier_a_summaries`.
-/// - Serialization uses `serde_json::to_vec` which produces deterministic
-///   output for structs with stable field order.
+/// - Serialization uses [`canonical_bytes`], which sorts keys at every
+///   depth, so reordering ViewModel fields does not change the hash.
+///   [`CANONICAL_JSON_VERSION`] is hashed ahead of the bytes.
 /// - `projection_invariants_version` is embedded in the ViewModel, so
 ///   changes to projection rules produce different hashes.
 ///
@@ -654,16 +871,14 @@ pub fn project_with_pressure(
 /// Returns a 64-character lowercase hex string (BLAKE3 produces 256-bit hash).
 /// For file output, the caller should append a newline.
 pub fn viewmodel_hash(vm: &ViewModel) -> String {
-    // Serialize ViewModel to canonical JSON bytes.
-    // ViewModel contains only primitive types and BTreeMaps, so serialization
-    // should never fail.
-    let bytes = serde_json::to_vec(vm).expect("ViewModel serialization should never fail");
-
-    // Compute BLAKE3 hash
-    let hash = blake3::hash(&bytes);
-
-    // Return lowercase hex string
-    hash.to_hex().to_string()
+    // ViewModel contains only integers, strings and BTreeMaps, so canonical
+    // encoding should never fail.
+    let bytes = canonical_bytes(vm);
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(CANONICAL_JSON_VERSION.as_bytes());
+    hasher.update(&bytes);
+    hasher.finalize().to_hex().to_string()
 }
 
 /// Compute viewmodel.hash and return as a newline-terminated string for file output.
@@ -673,6 +888,58 @@ pub fn viewmodel_hash_for_file(vm: &ViewModel) -> String {
     format!("{}\n", viewmodel_hash(vm))
 }
 
+// ---------------------------------------------------------------------------
+// ViewModel diff
+// ---------------------------------------------------------------------------
+
+/// One differing field between two ViewModels.
+///
+/// `path` is a JSON path into the serialized ViewModel, such as
+/// `$.degradation_level` or `$.tier_a_summaries.ToolCall`. A value is
+/// `None` when that side has no entry at the path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ViewModelDivergence {
+    /// JSON path of the differing leaf in the serialized ViewModel.
+    pub path: String,
+    /// The left ViewModel's value at `path` as compact JSON text, or `None`
+    /// when the left side has no entry there. Not redacted: ViewModel
+    /// leaves are counts, levels, tool names and error kinds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub left_value: Option<String>,
+    /// The right ViewModel's value at `path`, in the same form as
+    /// `left_value` and likewise not redacted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub right_value: Option<String>,
+}
+
+/// Fields that differ between two ViewModels, sorted by `path`.
+///
+/// Explains a `viewmodel.hash` mismatch: maps are compared per key and
+/// lists per position, and every differing leaf is reported once with both
+/// values. Empty when the ViewModels are equal.
+pub fn diff_viewmodels(left: &ViewModel, right: &ViewModel) -> Vec<ViewModelDivergence> {
+    let value = |vm: &ViewModel| {
+        serde_json::to_value(vm).expect("ViewModel serialization should never fail")
+    };
+    let left_flat = flatten_json("$", &value(left));
+    let right_flat = flatten_json("$", &value(right));
+    let paths: BTreeSet<&String> = left_flat.keys().chain(right_flat.keys()).collect();
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let l = left_flat.get(path).cloned();
+            let r = right_flat.get(path).cloned();
+            (l != r).then(|| ViewModelDivergence {
+                path: path.clone(),
+                left_value: l,
+                right_value: r,
+            })
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Tests (M5.1, M5.2, M5.3, M5.4)
 // ---------------------------------------------------------------------------
@@ -839,7 +1106,7 @@ mod tests {
     fn test_projection_invariants_serialize_json() {
         let inv = ProjectionInvariants::new();
         let json = serde_json::to_string(&inv).unwrap();
-        assert!(json.contains("projection-invariants-v0.1"));
+        assert!(json.contains("projection-invariants-v0.7"));
         assert!(json.contains("\"degradation_level\":\"L0\""));
     }
 
@@ -855,7 +1122,63 @@ mod tests {
 
     #[test]
     fn test_projection_invariants_version_constant() {
-        assert_eq!(PROJECTION_INVARIANTS_VERSION, "projection-invariants-v0.1");
+        assert_eq!(PROJECTION_INVARIANTS_VERSION, "projection-invariants-v0.7");
+    }
+
+    #[test]
+    fn test_projection_invariants_without_tier_a_types_deserialize_to_default() {
+        let json = r#"{"version":"projection-invariants-v0.7","degradation_level":"L0"}"#;
+        let parsed: ProjectionInvariants = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, ProjectionInvariants::new());
+    }
+
+    #[test]
+    fn test_custom_tier_a_types_filter_summaries_and_suffix_version() {
+        let mut state = State::new();
+        state.event_counts_by_type.insert("ToolCall".into(), 4);
+        state.event_counts_by_type.insert("Generic".into(), 2);
+
+        let default = project(&state, &ProjectionInvariants::new());
+        assert_eq!(
+            default.tier_a_summaries,
+            BTreeMap::from([("ToolCall".to_string(), 4)])
+        );
+        assert_eq!(
+            default.projection_invariants_version,
+            PROJECTION_INVARIANTS_VERSION
+        );
+
+        let mut types = DEFAULT_TIER_A_TYPES.to_vec();
+        types.push("Generic");
+        let custom_inv = ProjectionInvariants::new().with_tier_a_types(types);
+        let custom = project(&state, &custom_inv);
+        assert_eq!(custom.tier_a_summaries.get("Generic"), Some(&2));
+        let version = custom.projection_invariants_version.clone();
+        let suffix = version
+            .strip_prefix("projection-invariants-v0.7+custom-")
+            .expect("custom list must suffix the version");
+        assert_eq!(suffix.len(), 12);
+        assert_ne!(viewmodel_hash(&default), viewmodel_hash(&custom));
+    }
+
+    #[test]
+    fn test_tier_a_types_version_ignores_order_and_duplicates_only() {
+        let mut reversed = DEFAULT_TIER_A_TYPES.to_vec();
+        reversed.reverse();
+        reversed.push("ToolCall");
+        assert_eq!(
+            ProjectionInvariants::new()
+                .with_tier_a_types(reversed)
+                .effective_version(),
+            PROJECTION_INVARIANTS_VERSION
+        );
+
+        let a = ProjectionInvariants::new().with_tier_a_types(["RunStart", "Error"]);
+        let b = ProjectionInvariants::new().with_tier_a_types(["Error", "RunStart"]);
+        let c = ProjectionInvariants::new().with_tier_a_types(["RunStart"]);
+        assert_eq!(a.effective_version(), b.effective_version());
+        assert_ne!(a.effective_version(), c.effective_version());
+        assert_ne!(a.effective_version(), PROJECTION_INVARIANTS_VERSION);
     }
 
     // -----------------------------------------------------------------------
@@ -949,6 +1272,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_export_safety_invariant_is_projected_and_hashed() {
+        let state = State::new();
+        let unknown = project(&state, &ProjectionInvariants::new());
+        assert_eq!(unknown.export_safety_state, ExportSafetyState::Unknown);
+
+        for safety in ExportSafetyState::ALL {
+            let invariants = ProjectionInvariants::new().with_export_safety(safety);
+            let vm = project(&state, &invariants);
+            assert_eq!(vm.export_safety_state, safety);
+            assert_eq!(
+                viewmodel_hash(&vm) == viewmodel_hash(&unknown),
+                safety.is_unknown()
+            );
+        }
+    }
+
     #[test]
     fn test_export_safety_state_predicates() {
         assert!(ExportSafetyState::Unknown.is_unknown());
@@ -1087,7 +1427,7 @@ mod tests {
         assert!(json.contains("\"queue_pressure_fixed\":750000"));
         assert!(json.contains("\"tier_a_drops\":0"));
         assert!(json.contains("\"export_safety_state\":\"UNKNOWN\""));
-        assert!(json.contains("\"projection_invariants_version\":\"projection-invariants-v0.1\""));
+        assert!(json.contains("\"projection_invariants_version\":\"projection-invariants-v0.7\""));
     }
 
     #[test]
@@ -1163,7 +1503,7 @@ mod tests {
     // project() function tests (M5.3)
     // -----------------------------------------------------------------------
 
-    use crate::reducer::{PolicyTransition, State};
+    use crate::reducer::{ErrorEntry, PolicyTransition, State, ToolSummary};
 
     #[test]
     fn test_project_empty_state() {
@@ -1247,6 +1587,85 @@ mod tests {
         assert_eq!(vm.degradation_level, LadderLevel::L5);
     }
 
+    fn state_with_tier_bc() -> State {
+        let mut state = State::new();
+        state.tier_bc_bins = BTreeMap::from([(0, 4), (3, 7)]);
+        state.tier_bc_by_type = BTreeMap::from([
+            ("Generic:HeartBeat".to_string(), 9),
+            ("Note".to_string(), 2),
+        ]);
+        state
+    }
+
+    #[test]
+    fn test_project_aggregates_tier_bc_per_level() {
+        let state = state_with_tier_bc();
+
+        let vm = project(&state, &ProjectionInvariants::with_level(LadderLevel::L0));
+        assert!(vm.tier_bc_bins.is_empty());
+        assert!(vm.tier_bc_collapsed.is_empty());
+        assert_eq!(vm.aggregation_summary(), "1:1");
+
+        let vm = project(&state, &ProjectionInvariants::with_level(LadderLevel::L1));
+        assert_eq!(
+            vm.tier_bc_bins,
+            vec![
+                BinSummary {
+                    first_commit_index: 0,
+                    last_commit_index: 9,
+                    count: 4,
+                },
+                BinSummary {
+                    first_commit_index: 30,
+                    last_commit_index: 39,
+                    count: 7,
+                },
+            ]
+        );
+        assert!(vm.tier_bc_collapsed.is_empty());
+        assert_eq!(vm.aggregation_summary(), "10:1 (bin=10, 2 bins)");
+
+        for level in [LadderLevel::L2, LadderLevel::L3, LadderLevel::L4] {
+            let vm = project(&state, &ProjectionInvariants::with_level(level));
+            assert!(vm.tier_bc_bins.is_empty());
+            assert_eq!(vm.tier_bc_collapsed, state.tier_bc_by_type);
+            assert_eq!(vm.aggregation_summary(), "collapsed (11 events)");
+        }
+
+        let vm = project(&state, &ProjectionInvariants::with_level(LadderLevel::L5));
+        assert!(vm.tier_bc_bins.is_empty());
+        assert!(vm.tier_bc_collapsed.is_empty());
+        assert_eq!(vm.aggregation_summary(), "frozen");
+    }
+
+    #[test]
+    fn test_tier_bc_aggregation_changes_hash_only_where_aggregated() {
+        let empty = State::new();
+        let busy = state_with_tier_bc();
+        for level in [
+            LadderLevel::L0,
+            LadderLevel::L1,
+            LadderLevel::L2,
+            LadderLevel::L3,
+            LadderLevel::L4,
+            LadderLevel::L5,
+        ] {
+            let invariants = ProjectionInvariants::with_level(level);
+            let quiet = viewmodel_hash(&project(&empty, &invariants));
+            let loud = viewmodel_hash(&project(&busy, &invariants));
+            assert_eq!(
+                quiet != loud,
+                level.should_aggregate() && !level.is_safe_failure(),
+                "{level}"
+            );
+            assert_eq!(
+                loud,
+                viewmodel_hash(&project(&busy, &invariants)),
+                "{level}"
+            );
+        }
+    }
+
     #[test]
     fn test_project_with_policy_decision() {
         let mut state = State::new();
@@ -1278,6 +1697,154 @@ mod tests {
         assert!(!vm.is_healthy());
     }
 
+    fn tool_summary(result_count: u64, error_count: u64) -> ToolSummary {
+        ToolSummary {
+            call_count: result_count,
+            result_count,
+            success_count: result_count - error_count,
+            error_count,
+            ..ToolSummary::default()
+        }
+    }
+
+    #[test]
+    fn test_project_tool_error_rates() {
+        let mut state = State::new();
+        state
+            .tool_summaries
+            .insert("Bash".to_string(), tool_summary(5, 2));
+        state
+            .tool_summaries
+            .insert("Read".to_string(), tool_summary(4, 0));
+        state
+            .tool_summaries
+            .insert("Pending".to_string(), tool_summary(0, 0));
+
+        let vm = project(&state, &ProjectionInvariants::new());
+
+        assert_eq!(vm.tool_error_rate_micro.get("Bash"), Some(&400_000));
+        assert_eq!(vm.tool_error_rate_micro.get("Read"), Some(&0));
+        // No results yet: no rate, and no divide-by-zero.
+        assert_eq!(vm.tool_error_rate_micro.get("Pending"), None);
+        assert_eq!(vm.worst_tool_error_rate(), Some(("Bash", 400_000)));
+    }
+
+    #[test]
+    fn test_worst_tool_error_rate_ties_break_by_name() {
+        let mut vm = ViewModel::new();
+        assert_eq!(vm.worst_tool_error_rate(), None);
+        vm.tool_error_rate_micro
+            .insert("Write".to_string(), 500_000);
+        vm.tool_error_rate_micro.insert("Edit".to_string(), 500_000);
+        vm.tool_error_rate_micro.insert("Read".to_string(), 0);
+        assert_eq!(vm.worst_tool_error_rate(), Some(("Edit", 500_000)));
+    }
+
+    #[test]
+    fn test_tool_error_rates_change_hash_from_v0_1() {
+        let mut state = State::new();
+        state
+            .tool_summaries
+            .insert("Bash".to_string(), tool_summary(5, 2));
+        let vm = project(&state, &ProjectionInvariants::new());
+
+        let mut v0_1 = vm.clone();
+        v0_1.tool_error_rate_micro.clear();
+        v0_1.projection_invariants_version = "projection-invariants-v0.1".to_string();
+
+        assert_ne!(PROJECTION_INVARIANTS_VERSION, "projection-invariants-v0.1");
+        assert_ne!(viewmodel_hash(&vm), viewmodel_hash(&v0_1));
+
+        let mut without_rates = vm.clone();
+        without_rates.tool_error_rate_micro.clear();
+        assert_ne!(viewmodel_hash(&vm), viewmodel_hash(&without_rates));
+    }
+
+    #[test]
+    fn test_project_top_tools_by_calls_then_name_capped() {
+        let mut state = State::new();
+        for i in 0..12u64 {
+            state
+                .tool_summaries
+                .insert(format!("tool-{i:02}"), tool_summary(i % 4, 0));
+        }
+        state
+            .tool_summaries
+            .insert("Bash".to_string(), tool_summary(5, 2));
+
+        let vm = project(&state, &ProjectionInvariants::new());
+        let names: Vec<&str> = vm.top_tools.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Bash", "tool-03", "tool-07", "tool-11", "tool-02", "tool-06", "tool-10",
+                "tool-01", "tool-05", "tool-09",
+            ]
+        );
+        assert_eq!(vm.top_tools.len(), TOP_TOOLS_LIMIT);
+        assert_eq!(
+            vm.top_tools[0].1,
+            ToolSummaryLite {
+                call_count: 5,
+                error_count: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_project_error_count_and_last_kind() {
+        let mut state = State::new();
+        let vm = project(&state, &ProjectionInvariants::new());
+        assert_eq!(vm.error_count, 0);
+        assert_eq!(vm.last_error_kind, None);
+
+        for (commit_index, kind) in [(3, "timeout"), (8, "tool_crash")] {
+            state.error_log.push(ErrorEntry {
+                commit_index,
+                kind: kind.to_string(),
+                message: "boom".to_string(),
+                severity: None,
+            });
+        }
+        let vm = project(&state, &ProjectionInvariants::new());
+        assert_eq!(vm.error_count, 2);
+        assert_eq!(vm.last_error_kind.as_deref(), Some("tool_crash"));
+    }
+
+    #[test]
+    fn test_top_tools_and_errors_change_hash() {
+        let mut state = State::new();
+        state
+            .tool_summaries
+            .insert("Bash".to_string(), tool_summary(5, 2));
+        state.error_log.push(ErrorEntry {
+            commit_index: 1,
+            kind: "timeout".to_string(),
+            message: "slow".to_string(),
+            severity: None,
+        });
+        let vm = project(&state, &ProjectionInvariants::new());
+        let hash = viewmodel_hash(&vm);
+
+        let mut other = vm.clone();
+        other.top_tools[0].1.call_count += 1;
+        assert_ne!(hash, viewmodel_hash(&other));
+
+        let mut other = vm.clone();
+        other
+            .top_tools
+            .push(("Read".to_string(), vm.top_tools[0].1));
+        assert_ne!(hash, viewmodel_hash(&other));
+
+        let mut other = vm.clone();
+        other.error_count = 0;
+        assert_ne!(hash, viewmodel_hash(&other));
+
+        let mut other = vm.clone();
+        other.last_error_kind = Some("tool_crash".to_string());
+        assert_ne!(hash, viewmodel_hash(&other));
+    }
+
     #[test]
     fn test_project_with_pressure() {
         let state = State::new();
@@ -1373,6 +1940,22 @@ mod tests {
         assert_eq!(vm.tier_a_summaries.get("ClockSkewDetected"), Some(&1));
     }
 
+    #[test]
+    fn project_counts_token_usage_and_subagent_spawns_as_tier_a() {
+        let mut state = State::new();
+        state
+            .event_counts_by_type
+            .insert("TokenUsage".to_string(), 4);
+        state
+            .event_counts_by_type
+            .insert("SubagentSpawned".to_string(), 2);
+
+        let vm = project(&state, &ProjectionInvariants::new());
+
+        assert_eq!(vm.tier_a_summaries.get("TokenUsage"), Some(&4));
+        assert_eq!(vm.tier_a_summaries.get("SubagentSpawned"), Some(&2));
+    }
+
     // -----------------------------------------------------------------------
     // viewmodel_hash tests (M5.4)
     // -----------------------------------------------------------------------
@@ -1390,6 +1973,18 @@ mod tests {
         assert_eq!(hash, hash.to_lowercase());
     }
 
+    /// Pinned hash of the empty projection. A change here means every
+    /// recorded `viewmodel_hash` is now different: bump
+    /// `PROJECTION_INVARIANTS_VERSION` and update the pin together.
+    #[test]
+    fn test_viewmodel_hash_golden_value() {
+        let vm = project(&State::new(), &ProjectionInvariants::new());
+        assert_eq!(
+            viewmodel_hash(&vm),
+            "eaa3cee43688ca3eb3436f1bf568e1ad8e43bd499b7f918933cf5f6d94b8b1c9"
+        );
+    }
+
     #[test]
     fn test_viewmodel_hash_for_file_format() {
         let vm = ViewModel::new();
@@ -1485,7 +2080,7 @@ mod tests {
         vm1.projection_invariants_version = "projection-invariants-v0.1".to_string();
 
         let mut vm2 = ViewModel::new();
-        vm2.projection_invariants_version = "projection-invariants-v0.2".to_string();
+        vm2.projection_invariants_version = "projection-invariants-v0.7".to_string();
 
         // Different versions should produce different hashes
         assert_ne!(viewmodel_hash(&vm1), viewmodel_hash(&vm2));
@@ -1576,6 +2171,7 @@ mod tests {
                     payload: EventPayload::ToolCall {
                         tool: "grep".to_string(),
                         args: Some("pattern file.txt".to_string()),
+                        invocation_id: None,
                     },
                     payload_ref: None,
                     synthesized: false,
@@ -1594,6 +2190,7 @@ mod tests {
                         tool: "grep".to_string(),
                         result: Some("match found".to_string()),
                         status: Some("success".to_string()),
+                        invocation_id: None,
                     },
                     payload_ref: None,
                     synthesized: false,
@@ -1695,4 +2292,44 @@ mod tests {
             }
         }
     }
+
+    // -----------------------------------------------------------------------
+    // ViewModel diff tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_diff_viewmodels_empty_iff_hashes_match() {
+        let state = state_with_tier_bc();
+        let a = project(&state, &ProjectionInvariants::new());
+        let b = project(&state, &ProjectionInvariants::new());
+        assert_eq!(viewmodel_hash(&a), viewmodel_hash(&b));
+        assert!(diff_viewmodels(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn test_diff_viewmodels_names_each_differing_field() {
+        let state = State::new();
+        let a = project(&state, &ProjectionInvariants::new());
+        let mut b = a.clone();
+        b.degradation_level = LadderLevel::L2;
+        b.tier_a_summaries.insert("ToolCall".into(), 3);
+
+        let divergences = diff_viewmodels(&a, &b);
+        assert_eq!(
+            divergences,
+            vec![
+                ViewModelDivergence {
+                    path: "$.degradation_level".into(),
+                    left_value: Some("\"L0\"".into()),
+                    right_value: Some("\"L2\"".into()),
+                },
+                ViewModelDivergence {
+                    path: "$.tier_a_summaries.ToolCall".into(),
+                    left_value: None,
+                    right_value: Some("3".into()),
+                },
+            ]
+        );
+        assert_ne!(viewmodel_hash(&a), viewmodel_hash(&b));
+    }
 }
@@ -19,12 +19,99 @@
 //! All map-like containers in [`State`] are [`BTreeMap`] (never `HashMap`).
 //! No floats in State. All iteration is deterministic.
 //!
+//! # Tool latency
+//!
+//! A ToolCall with an `invocation_id` waits in
+//! [`State::pending_tool_calls`] until a ToolResult with the same id
+//! arrives; the gap between their `timestamp_ns` is recorded in the
+//! result tool's [`LatencyHistogram`]. Results whose id matches no pending
+//! call count as [`ToolSummary::unmatched_results`]. Events without an id
+//! are counted as before and never paired.
+//!
+//! # Source gaps
+//!
+//! Each `source_id` should number its events with a contiguous
+//! `source_seq`. When a source's sequence skips forward, the skipped
+//! range lands in [`State::source_gaps`]: the recorder lost those messages
+//! before they reached the EventLog. Repeated or late sequence numbers are
+//! not gaps.
+//!
+//! # Tier B/C aggregation
+//!
+//! Tier B and C events are also counted per [`TIER_BC_BIN_SIZE`]-wide
+//! `commit_index` bin ([`State::tier_bc_bins`]) and per payload type
+//! ([`State::tier_bc_by_type`]), so the projection can aggregate them at
+//! L1 and collapse them at L2 without the events themselves.
+//!
+//! # Redactions
+//!
+//! A RedactionApplied event names an earlier event by `event_id` and a
+//! `field_path` within it. Besides the entry in [`State::redaction_log`],
+//! the target's `event_id` maps to the `commit_index` of each redaction in
+//! [`State::redacted_event_ids`], so readers can tell an event was
+//! redacted without scanning the whole log.
+//!
+//! # Incomplete runs
+//!
+//! A run with no `RunEnd` by the end of the log is incomplete
+//! ([`State::incomplete_runs`]), typically because the agent crashed.
+//! [`finalize_runs`] synthesizes a `RunEnd { exit_code: None, reason:
+//! "truncated" }` for each one, for the caller to append through the
+//! append writer; the reducer itself never invents events.
+//!
 //! # Checkpoint semantics
 //!
-//! Every 5000 events (from `docs/CAPACITY_ENVELOPE.md`), save current State.
-//! Checkpoint includes `reducer_version`, `commit_index` of last event
-//! reduced, and the serialized State. Checkpoints are derived artifacts --
-//! deletable, always rebuildable from EventLog.
+//! Every 5000 events by default (from `docs/CAPACITY_ENVELOPE.md`), save
+//! current State; [`replay_with_interval`] takes another interval, such as
+//! 100_000 for very long runs or 100 for tiny test runs. Checkpoint includes
+//! `reducer_version`, `commit_index` of last event reduced, the interval it
+//! was written at, and the serialized State. Checkpoints are derived
+//! artifacts -- deletable, always rebuildable from EventLog.
+//!
+//! Checkpoints serialize as JSON ([`serialize_checkpoint`]) or in a compact
+//! binary form ([`serialize_checkpoint_binary`]) for large States: the
+//! 8-byte magic `VIFEICK2`, the `reducer_version` as a little-endian `u32`
+//! length and UTF-8 bytes, `commit_index` and `checkpoint_interval` as
+//! little-endian `u64`s, then the State as CBOR. The header lets a stale checkpoint be rejected
+//! before its State is decoded. JSON stays the canonical form hashed by
+//! [`state_hash`]; the binary form never feeds a hash.
+//!
+//! # Progress and cancellation
+//!
+//! [`replay_with_progress`] and [`try_replay_with_progress`] call back
+//! after every reduced event with a [`ReplayProgress`]; returning
+//! `ControlFlow::Break` stops the replay and yields the partial State with
+//! the last `commit_index` reduced. The callback is the only side channel:
+//! the State itself is the same as a plain replay of the same prefix.
+//!
+//! # Parallel replay
+//!
+//! [`replay_parallel`] is an explicit opt-in for very long logs. It cuts the
+//! events into contiguous chunks of a given size, replays each on a worker
+//! thread with [`replay_chunk`], and folds the chunk States together in
+//! commit order with [`State::merge_chunk`]. Counts add and logs concatenate; the
+//! few things a chunk cannot settle alone (a ToolResult whose call is in an
+//! earlier chunk, a source gap across the boundary, which chunk's RunStart
+//! wins) travel beside the chunk State in [`ChunkReplay`]. The result has
+//! the same `state_hash` as [`replay`] whatever the chunk size.
+//! [`replay_parallel_sharded`] cuts the events into a given number of
+//! shards instead, and [`replay_parallel_with`] takes a
+//! [`ParallelReplayConfig`] that also caps the worker threads and sets the
+//! input length below which the replay stays serial.
+//!
+//! # Shard merge
+//!
+//! [`State::merge`] combines States reduced from shards of a log that was
+//! partitioned rather than cut into contiguous chunks, e.g. by run. A
+//! shard is reduced with [`replay_shard`] (or from [`State::new_shard`]),
+//! which also records the commit positions of what a reduction resolves in
+//! commit order: each run's latest RunStart and RunEnd, each spawn, every
+//! `source_seq`, and every ToolCall and ToolResult with an
+//! `invocation_id`. The merge settles those again from the combined
+//! positions, so it is exact however the shards interleave: associative,
+//! commutative, and equal to reducing all events in `commit_index` order.
+//! The recorded positions grow with the shard, are not serialized, and do
+//! not affect `state_hash` or equality.
 //!
 //! # state_hash
 //!
@@ -32,7 +119,9 @@
 //!
 //! INCLUDE list (all State fields): run_metadata, event_counts_by_type,
 //! event_counts_by_tier, tool_summaries, policy_decisions, error_log,
-//! clock_skew_events, redaction_log, last_commit_index, tier_a_count,
+//! clock_skew_events, redaction_log, redacted_event_ids, notes, token_usage,
+//! subagent_runs, pending_tool_calls, source_last_seq, source_gaps,
+//! tier_bc_bins, tier_bc_by_type, last_commit_index, tier_a_count,
 //! tier_a_drops.
 //!
 //! EXCLUDE list: nothing. All State fields affect replay correctness.
@@ -42,9 +131,16 @@
 //! - **I2 (Deterministic projection):** State is the input to projection.
 //! - **I4 (Testable determinism):** `state_hash` stability across runs.
 
-use crate::event::{CommittedEvent, EventPayload, Tier};
+use crate::canonical::{canonical_bytes, CANONICAL_JSON_VERSION};
+use crate::event::{CommittedEvent, EventPayload, ImportEvent, Tier};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::borrow::Borrow;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -52,10 +148,32 @@ use std::collections::BTreeMap;
 
 /// Reducer logic version. Included in state_hash so that reducer changes
 /// produce visibly different hashes.
-pub(crate) const REDUCER_VERSION: &str = "reducer-v0.1";
+pub const REDUCER_VERSION: &str = "reducer-v0.9";
 
-/// Checkpoint interval from `docs/CAPACITY_ENVELOPE.md`.
-pub(crate) const CHECKPOINT_INTERVAL: u64 = 5000;
+/// The [`REDUCER_VERSION`] of this build, for callers that report it (Tour
+/// metrics, the Truth HUD) rather than compare against it.
+pub fn reducer_version() -> &'static str {
+    REDUCER_VERSION
+}
+
+/// Width, in `commit_index` values, of each [`State::tier_bc_bins`] bin.
+pub const TIER_BC_BIN_SIZE: u64 = 10;
+
+/// Default checkpoint interval from `docs/CAPACITY_ENVELOPE.md`.
+pub const CHECKPOINT_INTERVAL: u64 = 5000;
+
+/// Check that `interval` is usable as a checkpoint interval.
+///
+/// Fails with `InvalidInput` for 0, which would never checkpoint.
+pub fn validate_checkpoint_interval(interval: u64) -> io::Result<u64> {
+    if interval == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "checkpoint interval must be at least 1 event",
+        ));
+    }
+    Ok(interval)
+}
 
 // ---------------------------------------------------------------------------
 // State (M4.1)
@@ -89,12 +207,70 @@ pub struct State {
     pub clock_skew_events: Vec<ClockSkewEntry>,
     /// Redactions applied in order.
     pub redaction_log: Vec<RedactionEntry>,
+    /// `commit_index` of every redaction of each event, keyed by the
+    /// redacted `event_id`, in commit order.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub redacted_event_ids: BTreeMap<String, Vec<u64>>,
+    /// Human-added notes in order.
+    ///
+    /// Omitted from serialization while empty, so logs without notes keep
+    /// their state hash and older checkpoints still load.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<NoteEntry>,
+    /// Token totals per model, from TokenUsage events.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub token_usage: BTreeMap<String, TokenTotals>,
+    /// Child `run_id`s of each parent `run_id`, in spawn order, from
+    /// SubagentSpawned events.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub subagent_runs: BTreeMap<String, Vec<String>>,
+    /// `timestamp_ns` of each ToolCall still awaiting its ToolResult,
+    /// keyed by `invocation_id`. Calls left here at the end of the log
+    /// never returned.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pending_tool_calls: BTreeMap<String, u64>,
+    /// Highest `source_seq` seen from each `source_id`.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_last_seq: BTreeMap<String, u64>,
+    /// Forward skips in each source's `source_seq`, as
+    /// `(expected_seq, actual_seq)` in commit order. Each one means the
+    /// recorder lost `actual_seq - expected_seq` messages.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub source_gaps: BTreeMap<String, Vec<(u64, u64)>>,
+    /// Tier B/C event counts keyed by `commit_index / TIER_BC_BIN_SIZE`.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tier_bc_bins: BTreeMap<u64, u64>,
+    /// Tier B/C event counts by payload type name, with `Generic` events
+    /// counted as `Generic:{event_type}`.
+    ///
+    /// Omitted from serialization while empty, like `notes`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tier_bc_by_type: BTreeMap<String, u64>,
     /// `commit_index` of the last event reduced. 0 if no events.
     pub last_commit_index: u64,
     /// Total Tier A events processed.
     pub tier_a_count: u64,
     /// Tier A drops (should always be 0 in v0.1).
     pub tier_a_drops: u64,
+    /// Commit positions [`State::merge`] needs, kept only by States started
+    /// with [`State::new_shard`]. Not part of the State's value: skipped by
+    /// serialization (and so by `state_hash`) and ignored by equality.
+    #[serde(skip)]
+    shard_history: ShardHistory,
 }
 
 impl State {
@@ -109,11 +285,451 @@ impl State {
             error_log: Vec::new(),
             clock_skew_events: Vec::new(),
             redaction_log: Vec::new(),
+            redacted_event_ids: BTreeMap::new(),
+            notes: Vec::new(),
+            token_usage: BTreeMap::new(),
+            subagent_runs: BTreeMap::new(),
+            pending_tool_calls: BTreeMap::new(),
+            source_last_seq: BTreeMap::new(),
+            source_gaps: BTreeMap::new(),
+            tier_bc_bins: BTreeMap::new(),
+            tier_bc_by_type: BTreeMap::new(),
             last_commit_index: 0,
             tier_a_count: 0,
             tier_a_drops: 0,
+            shard_history: ShardHistory::default(),
+        }
+    }
+
+    /// Create an empty State that records the commit positions
+    /// [`State::merge`] needs (see [`replay_shard`]).
+    pub fn new_shard() -> Self {
+        State {
+            shard_history: ShardHistory(Some(Box::default())),
+            ..State::new()
         }
     }
+
+    /// Total sequence gaps across all sources.
+    pub fn source_gap_count(&self) -> u64 {
+        self.source_gaps
+            .values()
+            .map(|gaps| gaps.len() as u64)
+            .sum()
+    }
+
+    /// Redactions applied to `event_id`, in commit order.
+    pub fn redactions_of<'a>(&'a self, event_id: &str) -> impl Iterator<Item = &'a RedactionEntry> {
+        self.redacted_event_ids
+            .get(event_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|commit_index| {
+                self.redaction_log
+                    .binary_search_by_key(commit_index, |entry| entry.commit_index)
+                    .ok()
+                    .map(|i| &self.redaction_log[i])
+            })
+    }
+
+    /// Merge the State of another shard of the same log.
+    ///
+    /// Both States must come from [`State::new_shard`] (or be empty) and
+    /// hold disjoint events of one log, each reduced in commit order; the
+    /// shards may interleave freely. Counts add, commit-ordered logs
+    /// interleave by `commit_index`, and everything a reduction resolves in
+    /// commit order (a run's latest RunStart and RunEnd, spawn order, tool
+    /// call pairing, source gaps) is settled again from the recorded commit
+    /// positions. The merge is associative and commutative, and merging
+    /// every shard gives the State of reducing all events in commit order.
+    ///
+    /// # Panics
+    ///
+    /// If either State has events but was not started with
+    /// [`State::new_shard`].
+    pub fn merge(mut self, mut other: State) -> State {
+        let mut history = self.take_shard_steps();
+        let other_history = other.take_shard_steps();
+        // Destructured in full so a new State field cannot be forgotten here.
+        let State {
+            run_metadata,
+            event_counts_by_type,
+            event_counts_by_tier,
+            tool_summaries,
+            policy_decisions,
+            error_log,
+            clock_skew_events,
+            redaction_log,
+            redacted_event_ids,
+            notes,
+            token_usage,
+            subagent_runs,
+            // Settled again below from the merged history.
+            pending_tool_calls: _,
+            source_last_seq: _,
+            source_gaps: _,
+            tier_bc_bins,
+            tier_bc_by_type,
+            last_commit_index,
+            tier_a_count,
+            tier_a_drops,
+            shard_history: _,
+        } = other;
+
+        for (run_id, other_run) in run_metadata {
+            let later = |positions: &BTreeMap<String, u64>, other: &BTreeMap<String, u64>| {
+                other.get(&run_id) > positions.get(&run_id)
+            };
+            let started_later = later(&history.run_starts, &other_history.run_starts);
+            let ended_later = later(&history.run_ends, &other_history.run_ends);
+            let run = match self.run_metadata.entry(run_id) {
+                Entry::Vacant(slot) => {
+                    slot.insert(other_run);
+                    continue;
+                }
+                Entry::Occupied(slot) => slot.into_mut(),
+            };
+            if started_later {
+                run.agent = other_run.agent;
+                run.args = other_run.args;
+            }
+            if ended_later {
+                run.ended = true;
+                run.exit_code = other_run.exit_code;
+                run.reason = other_run.reason;
+            }
+            run.event_count += other_run.event_count;
+            run.first_timestamp_ns = match (run.first_timestamp_ns, other_run.first_timestamp_ns) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+            run.last_timestamp_ns = run.last_timestamp_ns.max(other_run.last_timestamp_ns);
+        }
+
+        for (type_name, count) in event_counts_by_type {
+            *self.event_counts_by_type.entry(type_name).or_insert(0) += count;
+        }
+        for (tier, count) in event_counts_by_tier {
+            *self.event_counts_by_tier.entry(tier).or_insert(0) += count;
+        }
+        for (tool, other_summary) in tool_summaries {
+            let summary = self.tool_summaries.entry(tool).or_default();
+            summary.call_count += other_summary.call_count;
+            summary.result_count += other_summary.result_count;
+            summary.success_count += other_summary.success_count;
+            summary.error_count += other_summary.error_count;
+        }
+
+        interleave_by_commit(&mut self.policy_decisions, policy_decisions, |d| {
+            d.commit_index
+        });
+        interleave_by_commit(&mut self.error_log, error_log, |e| e.commit_index);
+        interleave_by_commit(&mut self.clock_skew_events, clock_skew_events, |e| {
+            e.commit_index
+        });
+        interleave_by_commit(&mut self.redaction_log, redaction_log, |r| r.commit_index);
+        interleave_by_commit(&mut self.notes, notes, |n| n.commit_index);
+        for (event_id, commit_indexes) in redacted_event_ids {
+            let merged = self.redacted_event_ids.entry(event_id).or_default();
+            interleave_by_commit(merged, commit_indexes, |&i| i);
+        }
+
+        for (model, other_totals) in token_usage {
+            let totals = self.token_usage.entry(model).or_default();
+            totals.input_tokens = totals
+                .input_tokens
+                .saturating_add(other_totals.input_tokens);
+            totals.output_tokens = totals
+                .output_tokens
+                .saturating_add(other_totals.output_tokens);
+        }
+        for (parent, children) in subagent_runs {
+            let spawned = |history: &ShardSteps, children: Vec<String>| {
+                history.spawns[&parent]
+                    .iter()
+                    .copied()
+                    .zip(children)
+                    .collect::<Vec<_>>()
+            };
+            let mut merged = spawned(&other_history, children);
+            if let Some(earlier) = self.subagent_runs.remove(&parent) {
+                interleave_by_commit(&mut merged, spawned(&history, earlier), |&(i, _)| i);
+            }
+            self.subagent_runs
+                .insert(parent, merged.into_iter().map(|(_, child)| child).collect());
+        }
+        for (bin, count) in tier_bc_bins {
+            *self.tier_bc_bins.entry(bin).or_insert(0) += count;
+        }
+        for (type_name, count) in tier_bc_by_type {
+            *self.tier_bc_by_type.entry(type_name).or_insert(0) += count;
+        }
+
+        self.last_commit_index = self.last_commit_index.max(last_commit_index);
+        self.tier_a_count += tier_a_count;
+        self.tier_a_drops += tier_a_drops;
+
+        history.merge(other_history);
+        self.settle_invocations(&history);
+        self.settle_sources(&history);
+        self.shard_history = ShardHistory(Some(Box::new(history)));
+        self
+    }
+
+    /// The recorded shard history, leaving none behind. An empty State
+    /// without one has an empty history.
+    fn take_shard_steps(&mut self) -> ShardSteps {
+        match self.shard_history.0.take() {
+            Some(steps) => *steps,
+            None => {
+                assert!(
+                    self.event_counts_by_tier.is_empty(),
+                    "State::merge needs States started with State::new_shard"
+                );
+                ShardSteps::default()
+            }
+        }
+    }
+
+    /// Pair every recorded ToolCall and ToolResult again, as a reduction
+    /// in commit order would.
+    fn settle_invocations(&mut self, history: &ShardSteps) {
+        for summary in self.tool_summaries.values_mut() {
+            summary.latency = LatencyHistogram::default();
+            summary.unmatched_results = 0;
+        }
+        self.pending_tool_calls.clear();
+        for (id, steps) in &history.invocations {
+            let mut pending = None;
+            for step in steps {
+                let Some(tool) = &step.result_tool else {
+                    pending = Some(step.timestamp_ns);
+                    continue;
+                };
+                let summary = self
+                    .tool_summaries
+                    .get_mut(tool)
+                    .expect("recorded result's tool is summarized");
+                match pending.take() {
+                    Some(called_ns) => summary
+                        .latency
+                        .record(step.timestamp_ns.saturating_sub(called_ns)),
+                    None => summary.unmatched_results += 1,
+                }
+            }
+            if let Some(called_ns) = pending {
+                self.pending_tool_calls.insert(id.clone(), called_ns);
+            }
+        }
+    }
+
+    /// Recompute every source's high-water mark and gaps from its recorded
+    /// `source_seq` values, as a reduction in commit order would.
+    fn settle_sources(&mut self, history: &ShardSteps) {
+        self.source_last_seq.clear();
+        self.source_gaps.clear();
+        for (source_id, seqs) in &history.source_seqs {
+            let mut last: Option<u64> = None;
+            let mut gaps = Vec::new();
+            for &(_, seq) in seqs {
+                if let Some(last) = last {
+                    let expected = last.saturating_add(1);
+                    if seq > expected {
+                        gaps.push((expected, seq));
+                    }
+                }
+                last = Some(last.map_or(seq, |last| last.max(seq)));
+            }
+            if let Some(last) = last {
+                self.source_last_seq.insert(source_id.clone(), last);
+            }
+            if !gaps.is_empty() {
+                self.source_gaps.insert(source_id.clone(), gaps);
+            }
+        }
+    }
+
+    /// Fold in the replay of the chunk that directly follows the events
+    /// already reduced into `self`.
+    ///
+    /// Only valid for contiguous chunks merged in commit order: `self` must
+    /// be the State after every event before `later`'s first. Then the
+    /// result equals reducing `later`'s events into `self` one by one.
+    pub fn merge_chunk(&mut self, later: ChunkReplay) {
+        let ChunkReplay {
+            state,
+            first_source_seq,
+            first_invocations,
+            started_runs,
+        } = later;
+        // Destructured in full so a new State field cannot be forgotten here.
+        let State {
+            run_metadata,
+            event_counts_by_type,
+            event_counts_by_tier,
+            tool_summaries,
+            policy_decisions,
+            error_log,
+            clock_skew_events,
+            redaction_log,
+            redacted_event_ids,
+            notes,
+            token_usage,
+            subagent_runs,
+            pending_tool_calls,
+            source_last_seq,
+            mut source_gaps,
+            tier_bc_bins,
+            tier_bc_by_type,
+            last_commit_index,
+            tier_a_count,
+            tier_a_drops,
+            shard_history: _,
+        } = state;
+
+        if !event_counts_by_tier.is_empty() {
+            self.last_commit_index = last_commit_index;
+        }
+
+        for (run_id, later_run) in run_metadata {
+            let started = started_runs.contains(&run_id);
+            let run = match self.run_metadata.entry(run_id) {
+                Entry::Vacant(slot) => {
+                    slot.insert(later_run);
+                    continue;
+                }
+                Entry::Occupied(slot) => slot.into_mut(),
+            };
+            if started {
+                run.agent = later_run.agent;
+                run.args = later_run.args;
+            }
+            if later_run.ended {
+                run.ended = true;
+                run.exit_code = later_run.exit_code;
+                run.reason = later_run.reason;
+            }
+            run.event_count += later_run.event_count;
+            run.first_timestamp_ns = match (run.first_timestamp_ns, later_run.first_timestamp_ns) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
+            run.last_timestamp_ns = run.last_timestamp_ns.max(later_run.last_timestamp_ns);
+        }
+
+        for (type_name, count) in event_counts_by_type {
+            *self.event_counts_by_type.entry(type_name).or_insert(0) += count;
+        }
+        for (tier, count) in event_counts_by_tier {
+            *self.event_counts_by_tier.entry(tier).or_insert(0) += count;
+        }
+
+        for (tool, later_summary) in tool_summaries {
+            let summary = self.tool_summaries.entry(tool).or_default();
+            summary.call_count += later_summary.call_count;
+            summary.result_count += later_summary.result_count;
+            summary.success_count += later_summary.success_count;
+            summary.error_count += later_summary.error_count;
+            summary.latency.merge(&later_summary.latency);
+            summary.unmatched_results += later_summary.unmatched_results;
+        }
+
+        // A chunk's first event for an invocation_id meets whatever this
+        // State still has pending under that id: a result pairs with it, a
+        // call replaces it.
+        for (id, first_result) in first_invocations {
+            let Some(called_ns) = self.pending_tool_calls.remove(&id) else {
+                continue;
+            };
+            if let Some((tool, result_ns)) = first_result {
+                let summary = self
+                    .tool_summaries
+                    .get_mut(&tool)
+                    .expect("chunk result's tool was merged above");
+                summary.unmatched_results -= 1;
+                summary.latency.record(result_ns.saturating_sub(called_ns));
+            }
+        }
+        self.pending_tool_calls.extend(pending_tool_calls);
+
+        self.policy_decisions.extend(policy_decisions);
+        self.error_log.extend(error_log);
+        self.clock_skew_events.extend(clock_skew_events);
+        self.redaction_log.extend(redaction_log);
+        for (event_id, commit_indexes) in redacted_event_ids {
+            self.redacted_event_ids
+                .entry(event_id)
+                .or_default()
+                .extend(commit_indexes);
+        }
+        self.notes.extend(notes);
+
+        for (model, later_totals) in token_usage {
+            let totals = self.token_usage.entry(model).or_default();
+            totals.input_tokens = totals
+                .input_tokens
+                .saturating_add(later_totals.input_tokens);
+            totals.output_tokens = totals
+                .output_tokens
+                .saturating_add(later_totals.output_tokens);
+        }
+        for (parent, children) in subagent_runs {
+            self.subagent_runs
+                .entry(parent)
+                .or_default()
+                .extend(children);
+        }
+
+        // The chunk measured gaps against its own high-water mark; the real
+        // one is at least this State's, which can only close or shrink them.
+        for (source_id, later_last) in source_last_seq {
+            let later_gaps = source_gaps.remove(&source_id).unwrap_or_default();
+            let Some(&last) = self.source_last_seq.get(&source_id) else {
+                self.source_last_seq.insert(source_id.clone(), later_last);
+                if !later_gaps.is_empty() {
+                    self.source_gaps.insert(source_id, later_gaps);
+                }
+                continue;
+            };
+            let floor = last.saturating_add(1);
+            let first = first_source_seq[&source_id];
+            let gaps: Vec<_> = (first > floor)
+                .then_some((floor, first))
+                .into_iter()
+                .chain(
+                    later_gaps
+                        .into_iter()
+                        .map(|(expected, actual)| (expected.max(floor), actual))
+                        .filter(|(expected, actual)| actual > expected),
+                )
+                .collect();
+            if !gaps.is_empty() {
+                self.source_gaps
+                    .entry(source_id.clone())
+                    .or_default()
+                    .extend(gaps);
+            }
+            self.source_last_seq.insert(source_id, last.max(later_last));
+        }
+
+        for (bin, count) in tier_bc_bins {
+            *self.tier_bc_bins.entry(bin).or_insert(0) += count;
+        }
+        for (type_name, count) in tier_bc_by_type {
+            *self.tier_bc_by_type.entry(type_name).or_insert(0) += count;
+        }
+
+        self.tier_a_count += tier_a_count;
+        self.tier_a_drops += tier_a_drops;
+    }
+
+    /// `run_id`s of runs without a `RunEnd`, in `run_id` order.
+    pub fn incomplete_runs(&self) -> impl Iterator<Item = &str> {
+        self.run_metadata
+            .iter()
+            .filter(|(_, run)| !run.ended)
+            .map(|(run_id, _)| run_id.as_str())
+    }
 }
 
 impl Default for State {
@@ -137,10 +753,16 @@ pub struct RunInfo {
     pub reason: Option<String>,
     /// Total events in this run.
     pub event_count: u64,
+    /// Earliest `timestamp_ns` of the run's events, whatever their order.
+    #[serde(default)]
+    pub first_timestamp_ns: Option<u64>,
+    /// Latest `timestamp_ns` of the run's events, whatever their order.
+    #[serde(default)]
+    pub last_timestamp_ns: Option<u64>,
 }
 
 /// Tool usage summary accumulated from ToolCall/ToolResult events.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ToolSummary {
     /// Total number of calls to this tool.
     pub call_count: u64,
@@ -150,16 +772,73 @@ pub struct ToolSummary {
     pub success_count: u64,
     /// Number of error results.
     pub error_count: u64,
+    /// Call-to-result latency of results paired with their call by
+    /// `invocation_id`.
+    ///
+    /// Omitted from serialization while empty, like `State::notes`.
+    #[serde(default, skip_serializing_if = "LatencyHistogram::is_empty")]
+    pub latency: LatencyHistogram,
+    /// Results carrying an `invocation_id` that matched no pending call.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub unmatched_results: u64,
+}
+
+fn is_zero(n: &u64) -> bool {
+    *n == 0
 }
 
-impl ToolSummary {
-    fn new() -> Self {
-        ToolSummary {
-            call_count: 0,
-            result_count: 0,
-            success_count: 0,
-            error_count: 0,
+/// Histogram of latencies in nanoseconds, in power-of-two buckets.
+///
+/// Integer-only, like the rest of State: a latency of `n` ns is counted
+/// in the bucket keyed by the smallest power of two `>= n`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    /// Sample count per bucket, keyed by the bucket's inclusive upper
+    /// bound in nanoseconds.
+    pub buckets: BTreeMap<u64, u64>,
+    /// Largest latency recorded, in nanoseconds.
+    pub max_ns: u64,
+}
+
+impl LatencyHistogram {
+    /// Count one latency of `latency_ns`.
+    pub fn record(&mut self, latency_ns: u64) {
+        let bound = latency_ns.checked_next_power_of_two().unwrap_or(u64::MAX);
+        *self.buckets.entry(bound).or_insert(0) += 1;
+        self.max_ns = self.max_ns.max(latency_ns);
+    }
+
+    /// Add every latency recorded in `other`.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (bound, n) in &other.buckets {
+            *self.buckets.entry(*bound).or_insert(0) += n;
         }
+        self.max_ns = self.max_ns.max(other.max_ns);
+    }
+
+    /// Number of latencies recorded.
+    pub fn count(&self) -> u64 {
+        self.buckets.values().sum()
+    }
+
+    /// True when nothing was recorded.
+    pub fn is_empty(&self) -> bool {
+        self.buckets.is_empty()
+    }
+
+    /// Upper bound of the bucket holding the `percent`th percentile
+    /// latency, capped at [`max_ns`](Self::max_ns). `None` when empty.
+    pub fn percentile_ns(&self, percent: u64) -> Option<u64> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+        let rank = (count * percent.min(100)).div_ceil(100).max(1);
+        let mut seen = 0;
+        self.buckets.iter().find_map(|(bound, n)| {
+            seen += n;
+            (seen >= rank).then(|| (*bound).min(self.max_ns))
+        })
     }
 }
 
@@ -205,6 +884,153 @@ pub struct ClockSkewEntry {
     pub delta_ns: u64,
 }
 
+/// A recorded note.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NoteEntry {
+    /// `commit_index` of the Note event.
+    pub commit_index: u64,
+    /// `commit_index` of the annotated event.
+    pub target_commit_index: u64,
+    /// Who wrote the note.
+    pub author: String,
+    /// Note text.
+    pub text: String,
+}
+
+/// Token totals for one model, summed (saturating) over TokenUsage events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenTotals {
+    /// Prompt (input) tokens.
+    pub input_tokens: u64,
+    /// Completion (output) tokens.
+    pub output_tokens: u64,
+}
+
+impl TokenTotals {
+    /// Input plus output tokens.
+    pub fn total(&self) -> u64 {
+        self.input_tokens.saturating_add(self.output_tokens)
+    }
+}
+
+/// Merge `other` into `log`, both sorted by `commit_index`, keeping the
+/// order. Entries of distinct shards never share a `commit_index`.
+fn interleave_by_commit<T>(log: &mut Vec<T>, other: Vec<T>, commit_index: impl Fn(&T) -> u64) {
+    if other.is_empty() {
+        return;
+    }
+    log.extend(other);
+    log.sort_by_key(|entry| commit_index(entry));
+}
+
+/// Commit positions of a shard State, for [`State::merge`]. `None` in a
+/// State not started with [`State::new_shard`].
+///
+/// Not part of the State's value, so every two compare equal.
+#[derive(Debug, Clone, Default)]
+struct ShardHistory(Option<Box<ShardSteps>>);
+
+impl PartialEq for ShardHistory {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for ShardHistory {}
+
+/// What a shard reduced in commit order, kept by `commit_index`.
+#[derive(Debug, Clone, Default)]
+struct ShardSteps {
+    /// `commit_index` of each run's latest RunStart.
+    run_starts: BTreeMap<String, u64>,
+    /// `commit_index` of each run's latest RunEnd.
+    run_ends: BTreeMap<String, u64>,
+    /// `commit_index` of each SubagentSpawned, keyed and ordered like
+    /// [`State::subagent_runs`].
+    spawns: BTreeMap<String, Vec<u64>>,
+    /// `(commit_index, source_seq)` of every event with a `source_seq`,
+    /// per `source_id`.
+    source_seqs: BTreeMap<String, Vec<(u64, u64)>>,
+    /// Every ToolCall and ToolResult with an `invocation_id`, per id.
+    invocations: BTreeMap<String, Vec<InvocationStep>>,
+}
+
+/// A ToolCall or ToolResult in [`ShardSteps::invocations`].
+#[derive(Debug, Clone)]
+struct InvocationStep {
+    commit_index: u64,
+    timestamp_ns: u64,
+    /// `None` for a ToolCall, the tool for a ToolResult.
+    result_tool: Option<String>,
+}
+
+impl ShardSteps {
+    fn record(&mut self, event: &CommittedEvent) {
+        let at = event.commit_index;
+        if let Some(seq) = event.source_seq {
+            self.source_seqs
+                .entry(event.source_id.clone())
+                .or_default()
+                .push((at, seq));
+        }
+        let (id, result_tool) = match &event.payload {
+            EventPayload::RunStart { .. } => {
+                self.run_starts.insert(event.run_id.clone(), at);
+                return;
+            }
+            EventPayload::RunEnd { .. } => {
+                self.run_ends.insert(event.run_id.clone(), at);
+                return;
+            }
+            EventPayload::SubagentSpawned { parent_run_id, .. } => {
+                self.spawns.entry(parent_run_id.clone()).or_default().push(at);
+                return;
+            }
+            EventPayload::ToolCall {
+                invocation_id: Some(id),
+                ..
+            } => (id, None),
+            EventPayload::ToolResult {
+                tool,
+                invocation_id: Some(id),
+                ..
+            } => (id, Some(tool.clone())),
+            _ => return,
+        };
+        self.invocations
+            .entry(id.clone())
+            .or_default()
+            .push(InvocationStep {
+                commit_index: at,
+                timestamp_ns: event.timestamp_ns,
+                result_tool,
+            });
+    }
+
+    fn merge(&mut self, other: ShardSteps) {
+        let latest = |positions: &mut BTreeMap<String, u64>, other: BTreeMap<String, u64>| {
+            for (run_id, at) in other {
+                let merged = positions.entry(run_id).or_insert(at);
+                *merged = (*merged).max(at);
+            }
+        };
+        latest(&mut self.run_starts, other.run_starts);
+        latest(&mut self.run_ends, other.run_ends);
+        for (parent, positions) in other.spawns {
+            let merged = self.spawns.entry(parent).or_default();
+            interleave_by_commit(merged, positions, |&i| i);
+        }
+        for (source_id, seqs) in other.source_seqs {
+            let merged = self.source_seqs.entry(source_id).or_default();
+            interleave_by_commit(merged, seqs, |&(i, _)| i);
+        }
+        for (id, steps) in other.invocations {
+            let merged = self.invocations.entry(id).or_default();
+            interleave_by_commit(merged, steps, |step| step.commit_index);
+        }
+    }
+}
+
 /// A recorded redaction.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RedactionEntry {
@@ -218,6 +1044,16 @@ pub struct RedactionEntry {
     pub reason: String,
 }
 
+impl RedactionEntry {
+    /// True when `field_path` is the redacted field or nested under it,
+    /// e.g. `payload.args.env` under `payload.args`.
+    pub fn covers(&self, field_path: &str) -> bool {
+        field_path
+            .strip_prefix(self.field_path.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Checkpoint (M4.3)
 // ---------------------------------------------------------------------------
@@ -233,10 +1069,19 @@ pub struct Checkpoint {
     pub reducer_version: String,
     /// `commit_index` of the last event reduced into this state.
     pub commit_index: u64,
+    /// Events between checkpoints in the replay that wrote this one.
+    /// Checkpoints written before the interval was recorded read as
+    /// [`CHECKPOINT_INTERVAL`].
+    #[serde(default = "default_checkpoint_interval")]
+    pub checkpoint_interval: u64,
     /// The accumulated state.
     pub state: State,
 }
 
+fn default_checkpoint_interval() -> u64 {
+    CHECKPOINT_INTERVAL
+}
+
 // ---------------------------------------------------------------------------
 // Pure reduce function (M4.2)
 // ---------------------------------------------------------------------------
@@ -259,6 +1104,10 @@ pub fn reduce(state: &State, event: &CommittedEvent) -> State {
 /// This applies the exact same state transition as [`reduce`] without cloning
 /// the full state per event.
 pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
+    if let Some(steps) = s.shard_history.0.as_deref_mut() {
+        steps.record(event);
+    }
+
     // Update last_commit_index.
     s.last_commit_index = event.commit_index;
 
@@ -270,9 +1119,38 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
     // Count by tier.
     *s.event_counts_by_tier.entry(event.tier).or_insert(0) += 1;
 
-    // Count Tier A.
+    // Count Tier A; bin Tier B/C for aggregation.
     if event.tier == Tier::A {
         s.tier_a_count += 1;
+    } else {
+        *s.tier_bc_bins
+            .entry(event.commit_index / TIER_BC_BIN_SIZE)
+            .or_insert(0) += 1;
+        let type_name = match &event.payload {
+            EventPayload::Generic { event_type, .. } => format!("Generic:{event_type}"),
+            payload => payload.event_type_name().to_string(),
+        };
+        *s.tier_bc_by_type.entry(type_name).or_insert(0) += 1;
+    }
+
+    // Per-source continuity. A repeated or older `source_seq` is not a
+    // gap, and never moves the high-water mark back.
+    if let Some(seq) = event.source_seq {
+        match s.source_last_seq.get_mut(&event.source_id) {
+            Some(last) => {
+                let expected = last.saturating_add(1);
+                if seq > expected {
+                    s.source_gaps
+                        .entry(event.source_id.clone())
+                        .or_default()
+                        .push((expected, seq));
+                }
+                *last = (*last).max(seq);
+            }
+            None => {
+                s.source_last_seq.insert(event.source_id.clone(), seq);
+            }
+        }
     }
 
     // Per-run event counting.
@@ -286,8 +1164,19 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
             exit_code: None,
             reason: None,
             event_count: 0,
+            first_timestamp_ns: None,
+            last_timestamp_ns: None,
         });
     run.event_count += 1;
+    // Min/max rather than first/last seen, so skewed logs still span the run.
+    run.first_timestamp_ns = Some(
+        run.first_timestamp_ns
+            .map_or(event.timestamp_ns, |ts| ts.min(event.timestamp_ns)),
+    );
+    run.last_timestamp_ns = Some(
+        run.last_timestamp_ns
+            .map_or(event.timestamp_ns, |ts| ts.max(event.timestamp_ns)),
+    );
 
     // Dispatch on payload variant.
     match &event.payload {
@@ -302,23 +1191,38 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
             run.exit_code = *exit_code;
             run.reason = reason.clone();
         }
-        EventPayload::ToolCall { tool, .. } => {
-            s.tool_summaries
-                .entry(tool.clone())
-                .or_insert_with(ToolSummary::new)
-                .call_count += 1;
-        }
-        EventPayload::ToolResult { tool, status, .. } => {
-            let summary = s
-                .tool_summaries
-                .entry(tool.clone())
-                .or_insert_with(ToolSummary::new);
+        EventPayload::ToolCall {
+            tool,
+            invocation_id,
+            ..
+        } => {
+            s.tool_summaries.entry(tool.clone()).or_default().call_count += 1;
+            if let Some(id) = invocation_id {
+                s.pending_tool_calls.insert(id.clone(), event.timestamp_ns);
+            }
+        }
+        EventPayload::ToolResult {
+            tool,
+            status,
+            invocation_id,
+            ..
+        } => {
+            let summary = s.tool_summaries.entry(tool.clone()).or_default();
             summary.result_count += 1;
             match status.as_deref() {
                 Some("success") => summary.success_count += 1,
                 Some("error") => summary.error_count += 1,
                 _ => {} // unknown or absent status -- counted in result_count only
             }
+            if let Some(id) = invocation_id {
+                match s.pending_tool_calls.remove(id) {
+                    // A result stamped before its call counts as zero latency.
+                    Some(called_ns) => summary
+                        .latency
+                        .record(event.timestamp_ns.saturating_sub(called_ns)),
+                    None => summary.unmatched_results += 1,
+                }
+            }
         }
         EventPayload::PolicyDecision {
             from_level,
@@ -349,6 +1253,10 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
                 field_path: field_path.clone(),
                 reason: reason.clone(),
             });
+            s.redacted_event_ids
+                .entry(target_event_id.clone())
+                .or_default()
+                .push(event.commit_index);
         }
         EventPayload::Error {
             kind,
@@ -374,6 +1282,37 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
                 delta_ns: *delta_ns,
             });
         }
+        EventPayload::Note {
+            target_commit_index,
+            author,
+            text,
+        } => {
+            s.notes.push(NoteEntry {
+                commit_index: event.commit_index,
+                target_commit_index: *target_commit_index,
+                author: author.clone(),
+                text: text.clone(),
+            });
+        }
+        EventPayload::TokenUsage {
+            model,
+            input_tokens,
+            output_tokens,
+        } => {
+            let totals = s.token_usage.entry(model.clone()).or_default();
+            totals.input_tokens = totals.input_tokens.saturating_add(*input_tokens);
+            totals.output_tokens = totals.output_tokens.saturating_add(*output_tokens);
+        }
+        EventPayload::SubagentSpawned {
+            parent_run_id,
+            child_run_id,
+            ..
+        } => {
+            s.subagent_runs
+                .entry(parent_run_id.clone())
+                .or_default()
+                .push(child_run_id.clone());
+        }
         EventPayload::Generic { event_type, .. } => {
             // Generic events are counted by type name in event_counts_by_type
             // (already handled above via event_type_name()). Also count by
@@ -385,6 +1324,72 @@ pub fn reduce_in_place(s: &mut State, event: &CommittedEvent) {
     }
 }
 
+/// `RunEnd.reason` of a run end synthesized by [`finalize_runs`].
+pub const TRUNCATED_RUN_REASON: &str = "truncated";
+
+/// Synthesize a `RunEnd` for every run in `events` that has none.
+///
+/// One Tier A `RunEnd { exit_code: None, reason: "truncated" }` per
+/// incomplete run, in `run_id` order, marked `synthesized: true`. All carry
+/// the log's latest `timestamp_ns` so appending them never trips clock skew
+/// detection. Append them through the append writer, which places them at
+/// the next `commit_index` values. Deterministic: the same events always
+/// yield the same output, and a finalized log yields nothing.
+pub fn finalize_runs(events: &[CommittedEvent]) -> Vec<ImportEvent> {
+    let (state, _) = replay(events);
+    let timestamp_ns = events.iter().map(|e| e.timestamp_ns).max().unwrap_or(0);
+    state
+        .incomplete_runs()
+        .map(|run_id| ImportEvent {
+            run_id: run_id.to_string(),
+            event_id: format!("truncated:{run_id}"),
+            source_id: "vifei-finalize".to_string(),
+            source_seq: None,
+            timestamp_ns,
+            tier: Tier::A,
+            payload: EventPayload::RunEnd {
+                exit_code: None,
+                reason: Some(TRUNCATED_RUN_REASON.to_string()),
+            },
+            payload_ref: None,
+            synthesized: true,
+        })
+        .collect()
+}
+
+/// Position of a replay, passed to the [`replay_with_progress`] callback
+/// after each event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayProgress {
+    /// Events reduced so far, including this one.
+    pub events_processed: u64,
+    /// Events in the replay, if known up front.
+    pub total: Option<u64>,
+    /// `commit_index` of the event just reduced.
+    pub commit_index: u64,
+}
+
+/// Result of [`replay_with_progress`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayOutcome {
+    /// State after the last event reduced.
+    pub state: State,
+    /// Checkpoint `commit_index` values reached, as from [`replay`].
+    pub checkpoint_indices: Vec<u64>,
+    /// Events reduced.
+    pub events_processed: u64,
+    /// `commit_index` of the last event reduced, when the callback stopped
+    /// the replay early. `None` when every event was reduced.
+    pub cancelled_at: Option<u64>,
+}
+
+impl ReplayOutcome {
+    /// True when the callback stopped the replay before the last event.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled_at.is_some()
+    }
+}
+
 /// Replay a sequence of committed events from an initial state.
 ///
 /// Returns the final state plus a list of commit_index values where
@@ -393,40 +1398,375 @@ pub fn replay(events: &[CommittedEvent]) -> (State, Vec<u64>) {
     replay_from(State::new(), events)
 }
 
+/// [`replay`] with checkpoints every `interval` events instead of every
+/// [`CHECKPOINT_INTERVAL`].
+///
+/// The State does not depend on the interval. Fails with `InvalidInput`
+/// when `interval` is 0 (see [`validate_checkpoint_interval`]).
+pub fn replay_with_interval(
+    events: &[CommittedEvent],
+    interval: u64,
+) -> io::Result<(State, Vec<u64>)> {
+    let interval = validate_checkpoint_interval(interval)?;
+    let outcome = replay_with_progress(events, interval, |_| ControlFlow::Continue(()))?;
+    Ok((outcome.state, outcome.checkpoint_indices))
+}
+
+/// Replay one shard of a partitioned log, for [`State::merge`].
+///
+/// `events` must be in commit order but need not be contiguous. The State
+/// is the same as [`replay`]'s, plus the commit positions the merge needs.
+pub fn replay_shard(events: &[CommittedEvent]) -> State {
+    replay_from(State::new_shard(), events).0
+}
+
 /// Replay from a given state (e.g., loaded from a checkpoint).
 pub fn replay_from(initial: State, events: &[CommittedEvent]) -> (State, Vec<u64>) {
-    let mut state = initial;
-    let mut checkpoint_indices = Vec::new();
+    let outcome = replay_from_with_progress(initial, events, CHECKPOINT_INTERVAL, |_| {
+        ControlFlow::Continue(())
+    });
+    (outcome.state, outcome.checkpoint_indices)
+}
 
+/// [`replay_with_interval`] that calls `progress` after every event.
+///
+/// Returning `ControlFlow::Break` from `progress` stops the replay; the
+/// outcome then holds the State reduced so far and
+/// [`cancelled_at`](ReplayOutcome::cancelled_at). `total` in each
+/// [`ReplayProgress`] is `events.len()`.
+pub fn replay_with_progress(
+    events: &[CommittedEvent],
+    interval: u64,
+    progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+) -> io::Result<ReplayOutcome> {
+    let interval = validate_checkpoint_interval(interval)?;
+    Ok(replay_from_with_progress(
+        State::new(),
+        events,
+        interval,
+        progress,
+    ))
+}
+
+/// [`replay_with_progress`] from a given state, with a checked interval.
+pub(crate) fn replay_from_with_progress(
+    initial: State,
+    events: &[CommittedEvent],
+    interval: u64,
+    progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+) -> ReplayOutcome {
+    let total = Some(events.len() as u64);
+    let result: Result<_, std::convert::Infallible> =
+        try_replay_from(initial, events.iter().map(Ok), interval, total, progress);
+    match result {
+        Ok(outcome) => outcome,
+        Err(never) => match never {},
+    }
+}
+
+/// [`replay_with_progress`] over a fallible event stream, such as
+/// [`EventLogReader`](crate::eventlog::EventLogReader), so the events need
+/// not be held in memory.
+///
+/// `total` is passed through to each [`ReplayProgress`]; give `None` when
+/// the length of the stream is not known. Read errors are returned as is.
+pub fn try_replay_with_progress<I>(
+    events: I,
+    total: Option<u64>,
+    interval: u64,
+    progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+) -> io::Result<ReplayOutcome>
+where
+    I: IntoIterator<Item = io::Result<CommittedEvent>>,
+{
+    let interval = validate_checkpoint_interval(interval)?;
+    try_replay_from(State::new(), events, interval, total, progress)
+}
+
+/// Replay a fallible event stream, such as
+/// [`read_eventlog_iter`](crate::eventlog::read_eventlog_iter), without
+/// materializing it.
+///
+/// Stops at the first `Err` and returns it. Produces the same state and
+/// checkpoint indices as [`replay`] over the same events.
+pub fn try_replay<I, E>(events: I) -> Result<(State, Vec<u64>), E>
+where
+    I: IntoIterator<Item = Result<CommittedEvent, E>>,
+{
+    let outcome = try_replay_from(State::new(), events, CHECKPOINT_INTERVAL, None, |_| {
+        ControlFlow::Continue(())
+    })?;
+    Ok((outcome.state, outcome.checkpoint_indices))
+}
+
+fn try_replay_from<I, B, E>(
+    initial: State,
+    events: I,
+    interval: u64,
+    total: Option<u64>,
+    mut progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+) -> Result<ReplayOutcome, E>
+where
+    I: IntoIterator<Item = Result<B, E>>,
+    B: Borrow<CommittedEvent>,
+{
+    let mut outcome = ReplayOutcome {
+        state: initial,
+        checkpoint_indices: Vec::new(),
+        events_processed: 0,
+        cancelled_at: None,
+    };
+
+    let mut events = events.into_iter().peekable();
+    while let Some(event) = events.next() {
+        let event = event?;
+        let event = event.borrow();
+        reduce_in_place(&mut outcome.state, event);
+        outcome.events_processed += 1;
+
+        // Check if we should checkpoint. Checkpoint at every interval
+        // boundary. commit_index is 0-based, so with the default interval
+        // checkpoint after index 4999, 9999, etc.
+        if (event.commit_index + 1) % interval == 0 {
+            outcome.checkpoint_indices.push(event.commit_index);
+        }
+
+        let position = ReplayProgress {
+            events_processed: outcome.events_processed,
+            total,
+            commit_index: event.commit_index,
+        };
+        if progress(position).is_break() {
+            if events.peek().is_some() {
+                outcome.cancelled_at = Some(event.commit_index);
+            }
+            break;
+        }
+    }
+
+    Ok(outcome)
+}
+
+/// One chunk of a log replayed from [`State::new`], with what the chunk
+/// could not resolve without the events before it. Input to
+/// [`State::merge_chunk`].
+#[derive(Debug, Clone)]
+pub struct ChunkReplay {
+    /// State of the chunk's events alone.
+    pub state: State,
+    /// First `source_seq` of each source in the chunk.
+    first_source_seq: BTreeMap<String, u64>,
+    /// First event of each `invocation_id` in the chunk: `None` for a
+    /// ToolCall, the tool and `timestamp_ns` for a ToolResult (which found
+    /// no call in the chunk).
+    first_invocations: BTreeMap<String, Option<(String, u64)>>,
+    /// Runs with a RunStart in the chunk.
+    started_runs: BTreeSet<String>,
+}
+
+/// Replay one chunk of a log on its own, for [`State::merge_chunk`].
+pub fn replay_chunk(events: &[CommittedEvent]) -> ChunkReplay {
+    let mut chunk = ChunkReplay {
+        state: State::new(),
+        first_source_seq: BTreeMap::new(),
+        first_invocations: BTreeMap::new(),
+        started_runs: BTreeSet::new(),
+    };
     for event in events {
-        reduce_in_place(&mut state, event);
+        if let Some(seq) = event.source_seq {
+            chunk
+                .first_source_seq
+                .entry(event.source_id.clone())
+                .or_insert(seq);
+        }
+        match &event.payload {
+            EventPayload::RunStart { .. } => {
+                chunk.started_runs.insert(event.run_id.clone());
+            }
+            EventPayload::ToolCall {
+                invocation_id: Some(id),
+                ..
+            } => {
+                chunk.first_invocations.entry(id.clone()).or_insert(None);
+            }
+            EventPayload::ToolResult {
+                tool,
+                invocation_id: Some(id),
+                ..
+            } => {
+                chunk
+                    .first_invocations
+                    .entry(id.clone())
+                    .or_insert_with(|| Some((tool.clone(), event.timestamp_ns)));
+            }
+            _ => {}
+        }
+        reduce_in_place(&mut chunk.state, event);
+    }
+    chunk
+}
 
-        // Check if we should checkpoint. Checkpoint at every CHECKPOINT_INTERVAL
-        // boundary. commit_index is 0-based, so checkpoint after index 4999, 9999, etc.
-        if (event.commit_index + 1) % CHECKPOINT_INTERVAL == 0 {
-            checkpoint_indices.push(event.commit_index);
+/// Inputs shorter than this replay serially by default: spawning workers
+/// costs more than it saves.
+pub const PARALLEL_REPLAY_MIN_EVENTS: usize = 4 * CHECKPOINT_INTERVAL as usize;
+
+/// Configuration for [`replay_parallel_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParallelReplayConfig {
+    /// Contiguous chunks the events are cut into.
+    pub shards: usize,
+    /// Worker threads; `None` uses `available_parallelism`.
+    pub threads: Option<usize>,
+    /// Inputs with fewer events than this replay serially.
+    pub min_events: usize,
+}
+
+impl ParallelReplayConfig {
+    /// Cut into `shards` chunks, with the default worker count and
+    /// [`PARALLEL_REPLAY_MIN_EVENTS`] serial fallback.
+    pub fn new(shards: usize) -> Self {
+        ParallelReplayConfig {
+            shards,
+            threads: None,
+            min_events: PARALLEL_REPLAY_MIN_EVENTS,
         }
     }
 
-    (state, checkpoint_indices)
+    /// Use at most `threads` worker threads.
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    /// Replay serially when there are fewer than `min_events` events.
+    pub fn with_min_events(mut self, min_events: usize) -> Self {
+        self.min_events = min_events;
+        self
+    }
+}
+
+/// [`replay`] on worker threads, `chunk_size` events per chunk.
+///
+/// Each chunk is replayed with [`replay_chunk`] by one of up to
+/// `available_parallelism` workers, and the chunks are merged in commit
+/// order with [`State::merge_chunk`]. The State is the same as [`replay`]'s;
+/// only the wall-clock time differs. [`CHECKPOINT_INTERVAL`] is a
+/// reasonable `chunk_size`. Fails with `InvalidInput` when `chunk_size`
+/// is 0.
+pub fn replay_parallel(events: &[CommittedEvent], chunk_size: usize) -> io::Result<State> {
+    if chunk_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "parallel replay chunk size must be at least 1 event",
+        ));
+    }
+    let chunks: Vec<_> = events.chunks(chunk_size).collect();
+    Ok(replay_chunks(&chunks, available_threads()))
+}
+
+/// [`replay`] on worker threads, cut into `shards` contiguous chunks.
+///
+/// Shorthand for [`replay_parallel_with`] with
+/// [`ParallelReplayConfig::new`]. The State is the same as [`replay`]'s
+/// for every shard count; only the wall-clock time differs.
+pub fn replay_parallel_sharded(events: &[CommittedEvent], shards: usize) -> io::Result<State> {
+    replay_parallel_with(events, &ParallelReplayConfig::new(shards))
+}
+
+/// [`replay`] on worker threads, as configured by `config`.
+///
+/// The events are cut into `config.shards` contiguous chunks of nearly
+/// equal length. Each chunk is replayed with [`replay_chunk`] by one of the
+/// workers, and the chunks are merged in commit order with
+/// [`State::merge_chunk`]. Inputs shorter than `config.min_events`, a single
+/// shard, or a single thread replay serially. Fails with `InvalidInput`
+/// when `shards` or `threads` is 0.
+pub fn replay_parallel_with(
+    events: &[CommittedEvent],
+    config: &ParallelReplayConfig,
+) -> io::Result<State> {
+    if config.shards == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "parallel replay needs at least 1 shard",
+        ));
+    }
+    if config.threads == Some(0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "parallel replay needs at least 1 thread",
+        ));
+    }
+    let threads = config.threads.unwrap_or_else(available_threads);
+    if events.len() < config.min_events || config.shards == 1 || threads == 1 {
+        return Ok(replay(events).0);
+    }
+
+    let chunk_size = events.len().div_ceil(config.shards).max(1);
+    let chunks: Vec<_> = events.chunks(chunk_size).collect();
+    Ok(replay_chunks(&chunks, threads))
+}
+
+fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+/// Replay `chunks` on up to `threads` workers and merge them in order.
+fn replay_chunks(chunks: &[&[CommittedEvent]], threads: usize) -> State {
+    let workers = threads.min(chunks.len());
+    let next = AtomicUsize::new(0);
+
+    let mut replayed: Vec<(usize, ChunkReplay)> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(chunk) = chunks.get(i) else {
+                            break done;
+                        };
+                        done.push((i, replay_chunk(chunk)));
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("replay worker panicked"))
+            .collect()
+    });
+    replayed.sort_by_key(|(i, _)| *i);
+
+    let mut state = State::new();
+    for (_, chunk) in replayed {
+        state.merge_chunk(chunk);
+    }
+    state
 }
 
 // ---------------------------------------------------------------------------
 // state_hash (M4.4)
 // ---------------------------------------------------------------------------
 
-/// Compute the state hash: `BLAKE3(reducer_version + canonical_serialize(State))`.
+/// Compute the state hash:
+/// `BLAKE3(reducer_version + canonical_json_version + canonical_bytes(State))`.
 ///
 /// # Hash input composition
 ///
 /// 1. `REDUCER_VERSION` as UTF-8 bytes.
-/// 2. Deterministic JSON serialization of the entire `State` struct.
+/// 2. [`CANONICAL_JSON_VERSION`] as UTF-8 bytes.
+/// 3. [`canonical_bytes`] of the entire `State` struct: keys sorted at
+///    every depth, independent of field declaration order.
 ///
 /// # INCLUDE list
 ///
 /// All fields of [`State`]: `run_metadata`, `event_counts_by_type`,
 /// `event_counts_by_tier`, `tool_summaries`, `policy_decisions`,
-/// `error_log`, `clock_skew_events`, `redaction_log`, `last_commit_index`,
+/// `error_log`, `clock_skew_events`, `redaction_log`,
+/// `redacted_event_ids`, `notes`, `token_usage`, `subagent_runs`,
+/// `pending_tool_calls`, `source_last_seq`, `source_gaps`,
+/// `tier_bc_bins`, `tier_bc_by_type`, `last_commit_index`,
 /// `tier_a_count`, `tier_a_drops`.
 ///
 /// # EXCLUDE list
@@ -435,11 +1775,10 @@ pub fn replay_from(initial: State, events: &[CommittedEvent]) -> (State, Vec<u64
 pub fn state_hash(state: &State) -> String {
     let mut hasher = blake3::Hasher::new();
     hasher.update(REDUCER_VERSION.as_bytes());
-    // Canonical JSON serialization. serde_json serializes struct fields in
-    // declaration order, BTreeMap keys in sorted order.
-    // State contains only primitive types and BTreeMaps, so serialization
-    // should never fail.
-    let state_bytes = serde_json::to_vec(state).expect("State serialization should never fail");
+    hasher.update(CANONICAL_JSON_VERSION.as_bytes());
+    // State contains only integers, strings and string-keyed BTreeMaps, so
+    // canonical encoding should never fail.
+    let state_bytes = canonical_bytes(state);
     hasher.update(&state_bytes);
     hasher.finalize().to_hex().to_string()
 }
@@ -448,19 +1787,25 @@ pub fn state_hash(state: &State) -> String {
 // Checkpoint serialization (M4.3)
 // ---------------------------------------------------------------------------
 
-/// Create a checkpoint for the current state.
-#[allow(dead_code)] // Used in tests, will be used for checkpoint persistence
-pub(crate) fn create_checkpoint(state: &State) -> Checkpoint {
+/// Create a checkpoint for the current state, written at the default
+/// [`CHECKPOINT_INTERVAL`].
+pub fn create_checkpoint(state: &State) -> Checkpoint {
+    create_checkpoint_with_interval(state, CHECKPOINT_INTERVAL)
+}
+
+/// Create a checkpoint for the current state, written by a replay that
+/// checkpoints every `interval` events.
+pub fn create_checkpoint_with_interval(state: &State, interval: u64) -> Checkpoint {
     Checkpoint {
         reducer_version: REDUCER_VERSION.to_string(),
         commit_index: state.last_commit_index,
+        checkpoint_interval: interval,
         state: state.clone(),
     }
 }
 
 /// Serialize a checkpoint to JSON bytes.
-#[allow(dead_code)] // Used in tests, will be used for checkpoint persistence
-pub(crate) fn serialize_checkpoint(checkpoint: &Checkpoint) -> Result<Vec<u8>, serde_json::Error> {
+pub fn serialize_checkpoint(checkpoint: &Checkpoint) -> Result<Vec<u8>, serde_json::Error> {
     serde_json::to_vec_pretty(checkpoint)
 }
 
@@ -468,8 +1813,7 @@ pub(crate) fn serialize_checkpoint(checkpoint: &Checkpoint) -> Result<Vec<u8>, s
 ///
 /// Returns `None` if deserialization fails or if the `reducer_version`
 /// doesn't match the current version (stale checkpoint).
-#[allow(dead_code)] // Used in tests, will be used for checkpoint loading
-pub(crate) fn load_checkpoint(data: &[u8]) -> Option<Checkpoint> {
+pub fn load_checkpoint(data: &[u8]) -> Option<Checkpoint> {
     let checkpoint: Checkpoint = serde_json::from_slice(data).ok()?;
     if checkpoint.reducer_version != REDUCER_VERSION {
         return None;
@@ -477,6 +1821,52 @@ pub(crate) fn load_checkpoint(data: &[u8]) -> Option<Checkpoint> {
     Some(checkpoint)
 }
 
+const CHECKPOINT_MAGIC: &[u8; 8] = b"VIFEICK2";
+
+/// Serialize a checkpoint to the binary checkpoint format (see the module
+/// docs).
+pub fn serialize_checkpoint_binary(checkpoint: &Checkpoint) -> io::Result<Vec<u8>> {
+    let version = checkpoint.reducer_version.as_bytes();
+    let version_len = u32::try_from(version.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "reducer_version is too long"))?;
+    let mut bytes = Vec::with_capacity(CHECKPOINT_MAGIC.len() + 20 + version.len());
+    bytes.extend_from_slice(CHECKPOINT_MAGIC);
+    bytes.extend_from_slice(&version_len.to_le_bytes());
+    bytes.extend_from_slice(version);
+    bytes.extend_from_slice(&checkpoint.commit_index.to_le_bytes());
+    bytes.extend_from_slice(&checkpoint.checkpoint_interval.to_le_bytes());
+    ciborium::into_writer(&checkpoint.state, &mut bytes)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+    Ok(bytes)
+}
+
+/// Deserialize a checkpoint from the binary checkpoint format.
+///
+/// Returns `None` under the same conditions as [`load_checkpoint`]: the
+/// bytes do not decode, or the `reducer_version` doesn't match the current
+/// version. A stale checkpoint is rejected without decoding its State.
+pub fn load_checkpoint_binary(data: &[u8]) -> Option<Checkpoint> {
+    let rest = data.strip_prefix(CHECKPOINT_MAGIC)?;
+    let (version_len, rest) = rest.split_first_chunk::<4>()?;
+    let version_len = usize::try_from(u32::from_le_bytes(*version_len)).ok()?;
+    if rest.len() < version_len {
+        return None;
+    }
+    let (version, rest) = rest.split_at(version_len);
+    if version != REDUCER_VERSION.as_bytes() {
+        return None;
+    }
+    let (commit_index, rest) = rest.split_first_chunk::<8>()?;
+    let (checkpoint_interval, body) = rest.split_first_chunk::<8>()?;
+    let state: State = ciborium::from_reader(body).ok()?;
+    Some(Checkpoint {
+        reducer_version: REDUCER_VERSION.to_string(),
+        commit_index: u64::from_le_bytes(*commit_index),
+        checkpoint_interval: u64::from_le_bytes(*checkpoint_interval),
+        state,
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -623,6 +2013,107 @@ mod tests {
         assert_eq!(state.event_counts_by_tier[&Tier::A], 1);
     }
 
+    #[test]
+    fn run_timestamps_span_min_to_max_despite_skew() {
+        // Clock-skewed log: commit order is not timestamp order.
+        let stamped = |commit_index: u64, run_id: &str, timestamp_ns: u64| {
+            let mut event = make_committed_with_run(
+                commit_index,
+                run_id,
+                EventPayload::ToolCall {
+                    tool: "Bash".into(),
+                    args: None,
+                    invocation_id: None,
+                },
+            );
+            event.timestamp_ns = timestamp_ns;
+            event
+        };
+        let events = vec![
+            stamped(0, "run-a", 5_000),
+            stamped(1, "run-b", 2_000),
+            stamped(2, "run-a", 3_000),
+            stamped(3, "run-a", 9_000),
+            stamped(4, "run-b", 8_000),
+            stamped(5, "run-a", 7_000),
+        ];
+        let (state, _) = replay(&events);
+        let a = &state.run_metadata["run-a"];
+        assert_eq!(
+            (a.first_timestamp_ns, a.last_timestamp_ns),
+            (Some(3_000), Some(9_000))
+        );
+        let b = &state.run_metadata["run-b"];
+        assert_eq!(
+            (b.first_timestamp_ns, b.last_timestamp_ns),
+            (Some(2_000), Some(8_000))
+        );
+
+        // Same answer whatever order the skewed events arrive in.
+        let mut reversed = events.clone();
+        reversed.reverse();
+        assert_eq!(replay(&reversed).0.run_metadata, state.run_metadata);
+    }
+
+    #[test]
+    fn source_gaps_record_forward_skips_per_source() {
+        let from = |commit_index: u64, source_id: &str, seq: Option<u64>| {
+            let mut event = make_committed(
+                commit_index,
+                EventPayload::ToolCall {
+                    tool: "Bash".into(),
+                    args: None,
+                    invocation_id: None,
+                },
+            );
+            event.source_id = source_id.into();
+            event.source_seq = seq;
+            event
+        };
+        let events = vec![
+            from(0, "agent", Some(0)),
+            from(1, "hook", Some(7)),
+            from(2, "agent", Some(1)),
+            from(3, "agent", Some(4)),
+            from(4, "hook", Some(8)),
+            from(5, "agent", Some(4)), // repeat: not a gap
+            from(6, "agent", Some(2)), // late arrival: not a gap
+            from(7, "hook", None),
+            from(8, "agent", Some(6)),
+            from(9, "hook", Some(10)),
+        ];
+        let (state, _) = replay(&events);
+        assert_eq!(state.source_gaps["agent"], vec![(2, 4), (5, 6)]);
+        assert_eq!(state.source_gaps["hook"], vec![(9, 10)]);
+        assert_eq!(state.source_last_seq["agent"], 6);
+        assert_eq!(state.source_last_seq["hook"], 10);
+        assert_eq!(state.source_gap_count(), 3);
+    }
+
+    #[test]
+    fn contiguous_sources_have_no_gaps() {
+        let events: Vec<_> = (0..5)
+            .map(|i| {
+                make_committed(
+                    i,
+                    EventPayload::ToolCall {
+                        tool: "Bash".into(),
+                        args: None,
+                        invocation_id: None,
+                    },
+                )
+            })
+            .collect();
+        let (state, _) = replay(&events);
+        assert!(state.source_gaps.is_empty());
+        assert_eq!(state.source_gap_count(), 0);
+        let json = serde_json::to_string(&state).unwrap();
+        assert!(
+            !json.contains("source_gaps"),
+            "empty gaps stay out of the hash"
+        );
+    }
+
     #[test]
     fn reduce_run_end() {
         let start = make_committed(
@@ -654,6 +2145,7 @@ mod tests {
             EventPayload::ToolCall {
                 tool: "Read".into(),
                 args: Some("/path".into()),
+                invocation_id: None,
             },
         );
         let result = make_committed(
@@ -662,6 +2154,7 @@ mod tests {
                 tool: "Read".into(),
                 result: Some("content".into()),
                 status: Some("success".into()),
+                invocation_id: None,
             },
         );
         let state = reduce(&reduce(&State::new(), &call), &result);
@@ -680,6 +2173,7 @@ mod tests {
                 tool: "Bash".into(),
                 result: Some("command failed".into()),
                 status: Some("error".into()),
+                invocation_id: None,
             },
         );
         let state = reduce(&State::new(), &result);
@@ -689,6 +2183,84 @@ mod tests {
         assert_eq!(summary.error_count, 1);
     }
 
+    #[test]
+    fn tool_results_pair_with_calls_by_invocation_id() {
+        let call = |i, id: &str| {
+            make_committed(
+                i,
+                EventPayload::ToolCall {
+                    tool: "Bash".into(),
+                    args: None,
+                    invocation_id: Some(id.into()),
+                },
+            )
+        };
+        let result = |i, id: &str| {
+            make_committed(
+                i,
+                EventPayload::ToolResult {
+                    tool: "Bash".into(),
+                    result: None,
+                    status: Some("success".into()),
+                    invocation_id: Some(id.into()),
+                },
+            )
+        };
+        // make_committed spaces events 1ms apart.
+        let events = vec![
+            call(0, "a"),
+            call(1, "b"),
+            result(3, "a"),
+            result(4, "nope"),
+        ];
+        let (state, _) = replay(&events);
+
+        let summary = &state.tool_summaries["Bash"];
+        assert_eq!(summary.latency.count(), 1);
+        assert_eq!(summary.latency.max_ns, 3_000_000);
+        assert_eq!(summary.latency.buckets, BTreeMap::from([(4_194_304, 1)]));
+        assert_eq!(summary.latency.percentile_ns(50), Some(3_000_000));
+        assert_eq!(summary.unmatched_results, 1);
+        assert_eq!(
+            state.pending_tool_calls,
+            BTreeMap::from([("b".to_string(), 1_001_000_000)])
+        );
+    }
+
+    #[test]
+    fn latency_histogram_percentiles_use_bucket_bounds() {
+        let mut histogram = LatencyHistogram::default();
+        assert_eq!(histogram.percentile_ns(50), None);
+        for _ in 0..9 {
+            histogram.record(1_000);
+        }
+        histogram.record(100_000);
+        histogram.record(0);
+
+        assert_eq!(histogram.count(), 11);
+        assert_eq!(histogram.buckets[&1], 1);
+        assert_eq!(histogram.percentile_ns(0), Some(1));
+        assert_eq!(histogram.percentile_ns(50), Some(1_024));
+        assert_eq!(histogram.percentile_ns(95), Some(100_000));
+        assert_eq!(histogram.percentile_ns(100), Some(100_000));
+    }
+
+    #[test]
+    fn unpaired_tool_events_keep_their_serialized_form() {
+        let call = make_committed(
+            0,
+            EventPayload::ToolCall {
+                tool: "Read".into(),
+                args: None,
+                invocation_id: None,
+            },
+        );
+        let json = serde_json::to_string(&reduce(&State::new(), &call)).unwrap();
+        assert!(!json.contains("pending_tool_calls"), "{json}");
+        assert!(!json.contains("latency"), "{json}");
+        assert!(!json.contains("unmatched_results"), "{json}");
+    }
+
     #[test]
     fn reduce_policy_decision() {
         let event = make_committed(
@@ -756,6 +2328,47 @@ mod tests {
         assert_eq!(r.target_event_id, "e-5");
         assert_eq!(r.field_path, "payload.args");
         assert_eq!(r.reason, "contains API key");
+        assert_eq!(state.redacted_event_ids["e-5"], vec![0]);
+    }
+
+    #[test]
+    fn redactions_of_lists_each_redaction_of_an_event() {
+        let redaction = |commit_index, target: &str, field_path: &str| {
+            make_committed(
+                commit_index,
+                EventPayload::RedactionApplied {
+                    target_event_id: target.into(),
+                    field_path: field_path.into(),
+                    reason: "secret".into(),
+                },
+            )
+        };
+        let events = [
+            redaction(3, "e-1", "payload.args"),
+            redaction(4, "e-2", "payload.result"),
+            redaction(7, "e-1", "payload.result"),
+        ];
+        let (state, _) = replay(&events);
+
+        assert_eq!(state.redacted_event_ids["e-1"], vec![3, 7]);
+        let paths: Vec<_> = state
+            .redactions_of("e-1")
+            .map(|entry| entry.field_path.as_str())
+            .collect();
+        assert_eq!(paths, ["payload.args", "payload.result"]);
+        assert_eq!(state.redactions_of("e-3").count(), 0);
+
+        let args = state.redactions_of("e-1").next().unwrap();
+        assert!(args.covers("payload.args"));
+        assert!(args.covers("payload.args.env.TOKEN"));
+        assert!(!args.covers("payload.argsx"));
+        assert!(!args.covers("payload"));
+
+        let json = serde_json::to_string(&State::new()).unwrap();
+        assert!(
+            !json.contains("redacted_event_ids"),
+            "empty map must not change the serialized form"
+        );
     }
 
     #[test]
@@ -800,6 +2413,40 @@ mod tests {
         assert_eq!(state.event_counts_by_tier[&Tier::B], 1);
     }
 
+    #[test]
+    fn tier_bc_events_are_binned_and_counted_by_type() {
+        let events = [
+            make_tier_b_committed(0, "HeartBeat"),
+            make_committed(
+                1,
+                EventPayload::RunStart {
+                    agent: "a".into(),
+                    args: None,
+                },
+            ),
+            make_tier_b_committed(9, "HeartBeat"),
+            make_tier_b_committed(10, "Progress"),
+            make_tier_b_committed(35, "HeartBeat"),
+        ];
+        let (state, _) = replay(&events);
+
+        assert_eq!(state.tier_bc_bins, BTreeMap::from([(0, 2), (1, 1), (3, 1)]));
+        assert_eq!(
+            state.tier_bc_by_type,
+            BTreeMap::from([
+                ("Generic:HeartBeat".to_string(), 3),
+                ("Generic:Progress".to_string(), 1),
+            ])
+        );
+
+        let tier_a_only = replay(&events[1..2]).0;
+        let json = serde_json::to_string(&tier_a_only).unwrap();
+        assert!(
+            !json.contains("tier_bc"),
+            "empty maps must not change the serialized form"
+        );
+    }
+
     #[test]
     fn reduce_multiple_runs() {
         let start1 = make_committed_with_run(
@@ -860,6 +2507,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "Bash".into(),
                         args: Some(format!("cmd-{i}")),
+                        invocation_id: None,
                     },
                 )
             })
@@ -872,6 +2520,32 @@ mod tests {
         assert!(checkpoints.is_empty()); // 1000 < 5000
     }
 
+    #[test]
+    fn try_replay_matches_replay_and_stops_at_first_error() {
+        let events: Vec<_> = (0..5001)
+            .map(|i| {
+                make_committed(
+                    i,
+                    EventPayload::ToolCall {
+                        tool: "Bash".into(),
+                        args: None,
+                        invocation_id: None,
+                    },
+                )
+            })
+            .collect();
+        let streamed: Result<_, String> = try_replay(events.iter().cloned().map(Ok));
+        assert_eq!(streamed.unwrap(), replay(&events));
+
+        let with_error = events
+            .iter()
+            .cloned()
+            .map(Ok)
+            .take(3)
+            .chain(std::iter::once(Err("bad line 4".to_string())));
+        assert_eq!(try_replay(with_error).unwrap_err(), "bad line 4");
+    }
+
     #[test]
     fn replay_matches_clone_based_reduce_path() {
         let events = vec![
@@ -887,6 +2561,7 @@ mod tests {
                 EventPayload::ToolCall {
                     tool: "Read".into(),
                     args: Some("file.txt".into()),
+                    invocation_id: None,
                 },
             ),
             make_committed(
@@ -895,6 +2570,7 @@ mod tests {
                     tool: "Read".into(),
                     status: Some("success".into()),
                     result: Some("ok".into()),
+                    invocation_id: None,
                 },
             ),
             make_committed(
@@ -958,6 +2634,7 @@ mod tests {
         let checkpoint = Checkpoint {
             reducer_version: "reducer-v0.0-stale".into(),
             commit_index: 100,
+            checkpoint_interval: CHECKPOINT_INTERVAL,
             state: State::new(),
         };
         let bytes = serde_json::to_vec(&checkpoint).unwrap();
@@ -970,6 +2647,105 @@ mod tests {
         assert!(load_checkpoint(b"{}").is_none());
     }
 
+    /// Deterministic pseudo-random event stream (64-bit LCG) covering the
+    /// payload variants the reducer accumulates.
+    fn seeded_events(seed: u64, count: u64) -> Vec<CommittedEvent> {
+        let mut x = seed;
+        let mut next = move |bound: u64| {
+            x = x
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            (x >> 33) % bound
+        };
+        (0..count)
+            .map(|i| {
+                let run = format!("run-{}", next(4));
+                let payload = match next(9) {
+                    0 => EventPayload::RunStart {
+                        agent: format!("agent-{}", next(3)),
+                        args: None,
+                    },
+                    1 => EventPayload::RunEnd {
+                        exit_code: Some(next(3) as i32),
+                        reason: None,
+                    },
+                    2 => EventPayload::ToolCall {
+                        tool: format!("tool-{}", next(500)),
+                        args: Some(format!("arg-{}", next(1000))),
+                        invocation_id: None,
+                    },
+                    3 => EventPayload::ToolResult {
+                        tool: format!("tool-{}", next(500)),
+                        result: None,
+                        status: Some(["success", "error"][next(2) as usize].into()),
+                        invocation_id: None,
+                    },
+                    4 => EventPayload::Error {
+                        kind: "test".into(),
+                        message: format!("err-{i}"),
+                        severity: Some("warning".into()),
+                    },
+                    5 => EventPayload::PolicyDecision {
+                        from_level: "L0".into(),
+                        to_level: "L1".into(),
+                        trigger: "pressure".into(),
+                        queue_pressure: next(1000) as f64 / 1000.0,
+                    },
+                    6 => EventPayload::TokenUsage {
+                        model: format!("model-{}", next(2)),
+                        input_tokens: next(5000),
+                        output_tokens: next(500),
+                    },
+                    7 => EventPayload::SubagentSpawned {
+                        parent_run_id: run.clone(),
+                        child_run_id: format!("{run}-sub-{i}"),
+                        agent: "helper".into(),
+                    },
+                    _ => {
+                        return make_tier_b_committed(i, "Metric");
+                    }
+                };
+                make_committed_with_run(i, &run, payload)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn binary_checkpoint_roundtrips_seeded_states() {
+        for seed in [1, 7, 42, 1_000_003] {
+            let (state, _) = replay(&seeded_events(seed, 3000));
+            let checkpoint = create_checkpoint(&state);
+            let bytes = serialize_checkpoint_binary(&checkpoint).unwrap();
+            let loaded = load_checkpoint_binary(&bytes).unwrap();
+            assert_eq!(loaded, checkpoint, "seed {seed}");
+            assert_eq!(state_hash(&loaded.state), state_hash(&state));
+            assert!(bytes.len() < serialize_checkpoint(&checkpoint).unwrap().len());
+        }
+    }
+
+    #[test]
+    fn binary_checkpoint_version_mismatch_returns_none() {
+        let checkpoint = Checkpoint {
+            reducer_version: "reducer-v0.0-stale".into(),
+            commit_index: 100,
+            checkpoint_interval: CHECKPOINT_INTERVAL,
+            state: State::new(),
+        };
+        let bytes = serialize_checkpoint_binary(&checkpoint).unwrap();
+        assert!(load_checkpoint_binary(&bytes).is_none());
+    }
+
+    #[test]
+    fn binary_checkpoint_corrupt_data_returns_none() {
+        let bytes = serialize_checkpoint_binary(&create_checkpoint(&State::new())).unwrap();
+        assert!(load_checkpoint_binary(b"not a checkpoint").is_none());
+        assert!(load_checkpoint_binary(&bytes[..bytes.len() - 1]).is_none());
+        assert!(load_checkpoint_binary(
+            &serialize_checkpoint(&create_checkpoint(&State::new())).unwrap()
+        )
+        .is_none());
+    }
+
     #[test]
     fn checkpoint_interval_at_5000() {
         let events: Vec<_> = (0..5001)
@@ -979,6 +2755,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "T".into(),
                         args: None,
+                        invocation_id: None,
                     },
                 )
             })
@@ -998,6 +2775,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "T".into(),
                         args: None,
+                        invocation_id: None,
                     },
                 )
             })
@@ -1006,6 +2784,127 @@ mod tests {
         assert_eq!(checkpoints, vec![4999, 9999]);
     }
 
+    #[test]
+    fn replay_with_interval_uses_custom_cadence() {
+        let events = seeded_events(3, 250);
+        let (state, checkpoints) = replay_with_interval(&events, 100).unwrap();
+        assert_eq!(checkpoints, vec![99, 199]);
+        assert_eq!(state, replay(&events).0);
+
+        let (_, default) = replay_with_interval(&events, CHECKPOINT_INTERVAL).unwrap();
+        assert_eq!(default, replay(&events).1);
+    }
+
+    #[test]
+    fn replay_progress_reports_every_event_and_can_cancel() {
+        let events = seeded_events(5, 250);
+        let mut seen = Vec::new();
+        let outcome = replay_with_progress(&events, 100, |p| {
+            seen.push(p);
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+        assert!(!outcome.is_cancelled());
+        assert_eq!(outcome.events_processed, 250);
+        assert_eq!(
+            (outcome.state.clone(), outcome.checkpoint_indices),
+            replay_with_interval(&events, 100).unwrap()
+        );
+        assert_eq!(seen.len(), 250);
+        assert_eq!(
+            seen[249],
+            ReplayProgress {
+                events_processed: 250,
+                total: Some(250),
+                commit_index: 249,
+            }
+        );
+
+        let cancelled = replay_with_progress(&events, 100, |p| {
+            if p.events_processed == 120 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert_eq!(cancelled.cancelled_at, Some(119));
+        assert_eq!(cancelled.events_processed, 120);
+        assert_eq!(cancelled.checkpoint_indices, vec![99]);
+        assert_eq!(cancelled.state, replay(&events[..120]).0);
+    }
+
+    #[test]
+    fn break_on_last_event_is_not_a_cancellation() {
+        let events = seeded_events(6, 10);
+        let outcome = replay_with_progress(&events, 100, |_| ControlFlow::Break(())).unwrap();
+        assert_eq!(outcome.cancelled_at, Some(0));
+
+        let outcome = replay_with_progress(&events[..1], 100, |_| ControlFlow::Break(())).unwrap();
+        assert!(!outcome.is_cancelled());
+    }
+
+    #[test]
+    fn try_replay_with_progress_streams_without_a_total() {
+        let events = seeded_events(7, 50);
+        let mut totals = Vec::new();
+        let outcome = try_replay_with_progress(
+            events.iter().cloned().map(Ok),
+            None,
+            CHECKPOINT_INTERVAL,
+            |p| {
+                totals.push(p.total);
+                ControlFlow::Continue(())
+            },
+        )
+        .unwrap();
+        assert_eq!(outcome.state, replay(&events).0);
+        assert!(totals.iter().all(Option::is_none));
+
+        let with_error = events
+            .iter()
+            .cloned()
+            .map(Ok)
+            .take(3)
+            .chain(std::iter::once(Err(io::Error::other("bad line 4"))));
+        let err = try_replay_with_progress(with_error, None, CHECKPOINT_INTERVAL, |_| {
+            ControlFlow::Continue(())
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "bad line 4");
+    }
+
+    #[test]
+    fn zero_checkpoint_interval_is_rejected() {
+        let err = replay_with_interval(&[], 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(validate_checkpoint_interval(1).is_ok());
+    }
+
+    #[test]
+    fn checkpoint_records_its_interval() {
+        let mut state = State::new();
+        state.last_commit_index = 99;
+        let checkpoint = create_checkpoint_with_interval(&state, 100);
+        assert_eq!(create_checkpoint(&state).checkpoint_interval, 5000);
+
+        let json = load_checkpoint(&serialize_checkpoint(&checkpoint).unwrap()).unwrap();
+        assert_eq!(json.checkpoint_interval, 100);
+        let binary =
+            load_checkpoint_binary(&serialize_checkpoint_binary(&checkpoint).unwrap()).unwrap();
+        assert_eq!(binary.checkpoint_interval, 100);
+
+        // Checkpoints written before the interval was recorded read as the
+        // default.
+        let mut legacy = serde_json::to_value(&checkpoint).unwrap();
+        legacy
+            .as_object_mut()
+            .unwrap()
+            .remove("checkpoint_interval");
+        let legacy = load_checkpoint(&serde_json::to_vec(&legacy).unwrap()).unwrap();
+        assert_eq!(legacy.checkpoint_interval, CHECKPOINT_INTERVAL);
+    }
+
     // -----------------------------------------------------------------------
     // M4.4: state_hash tests
     // -----------------------------------------------------------------------
@@ -1048,6 +2947,222 @@ mod tests {
         assert_eq!(hash1, hash2);
     }
 
+    #[test]
+    fn note_changes_state_hash_deterministically() {
+        let events = vec![
+            make_committed(
+                0,
+                EventPayload::RunStart {
+                    agent: "test".into(),
+                    args: None,
+                },
+            ),
+            make_committed(
+                1,
+                EventPayload::ToolCall {
+                    tool: "Read".into(),
+                    args: None,
+                    invocation_id: None,
+                },
+            ),
+        ];
+        let mut annotated = events.clone();
+        annotated.push(make_committed(
+            2,
+            EventPayload::Note {
+                target_commit_index: 1,
+                author: "oncall".into(),
+                text: "suspicious read".into(),
+            },
+        ));
+
+        let (plain, _) = replay(&events);
+        let (noted, _) = replay(&annotated);
+        assert!(plain.notes.is_empty());
+        assert_eq!(
+            noted.notes,
+            vec![NoteEntry {
+                commit_index: 2,
+                target_commit_index: 1,
+                author: "oncall".into(),
+                text: "suspicious read".into(),
+            }]
+        );
+        assert_ne!(state_hash(&plain), state_hash(&noted));
+        assert_eq!(state_hash(&noted), state_hash(&replay(&annotated).0));
+    }
+
+    #[test]
+    fn token_usage_accumulates_per_model() {
+        let usage = |i, model: &str, input_tokens, output_tokens| {
+            make_committed(
+                i,
+                EventPayload::TokenUsage {
+                    model: model.into(),
+                    input_tokens,
+                    output_tokens,
+                },
+            )
+        };
+        let events = vec![
+            usage(0, "model-large", 1_000, 200),
+            usage(1, "model-small", 50, 5),
+            usage(2, "model-large", 300, u64::MAX),
+        ];
+        let (state, _) = replay(&events);
+
+        assert_eq!(state.token_usage.len(), 2);
+        assert_eq!(
+            state.token_usage["model-large"],
+            TokenTotals {
+                input_tokens: 1_300,
+                output_tokens: u64::MAX,
+            }
+        );
+        assert_eq!(state.token_usage["model-small"].total(), 55);
+        assert_eq!(state.event_counts_by_type["TokenUsage"], 3);
+    }
+
+    #[test]
+    fn subagent_spawns_build_parent_child_map() {
+        let spawn = |i, parent: &str, child: &str| {
+            make_committed(
+                i,
+                EventPayload::SubagentSpawned {
+                    parent_run_id: parent.into(),
+                    child_run_id: child.into(),
+                    agent: "explorer".into(),
+                },
+            )
+        };
+        let events = vec![
+            spawn(0, "run-1", "run-1b"),
+            spawn(1, "run-1", "run-1a"),
+            spawn(2, "run-1a", "run-1a-x"),
+        ];
+        let (state, _) = replay(&events);
+
+        assert_eq!(state.subagent_runs["run-1"], vec!["run-1b", "run-1a"]);
+        assert_eq!(state.subagent_runs["run-1a"], vec!["run-1a-x"]);
+    }
+
+    #[test]
+    fn empty_token_and_subagent_maps_are_not_serialized() {
+        let json = serde_json::to_string(&State::new()).unwrap();
+        assert!(!json.contains("token_usage"), "{json}");
+        assert!(!json.contains("subagent_runs"), "{json}");
+        let back: State = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, State::new());
+    }
+
+    #[test]
+    fn run_without_run_end_is_incomplete() {
+        let events = vec![
+            make_committed_with_run(
+                0,
+                "run-a",
+                EventPayload::RunStart {
+                    agent: "a".into(),
+                    args: None,
+                },
+            ),
+            make_committed_with_run(
+                1,
+                "run-b",
+                EventPayload::RunStart {
+                    agent: "b".into(),
+                    args: None,
+                },
+            ),
+            make_committed_with_run(
+                2,
+                "run-a",
+                EventPayload::RunEnd {
+                    exit_code: Some(0),
+                    reason: None,
+                },
+            ),
+        ];
+        let (state, _) = replay(&events);
+        assert_eq!(state.incomplete_runs().collect::<Vec<_>>(), vec!["run-b"]);
+    }
+
+    #[test]
+    fn finalize_runs_synthesizes_stable_truncated_run_ends() {
+        let mut events = vec![
+            make_committed_with_run(
+                0,
+                "run-b",
+                EventPayload::RunStart {
+                    agent: "b".into(),
+                    args: None,
+                },
+            ),
+            make_committed_with_run(
+                1,
+                "run-a",
+                EventPayload::ToolCall {
+                    tool: "Read".into(),
+                    args: None,
+                    invocation_id: None,
+                },
+            ),
+        ];
+
+        let synthesized = finalize_runs(&events);
+        assert_eq!(synthesized, finalize_runs(&events));
+        let run_ids: Vec<_> = synthesized.iter().map(|e| e.run_id.as_str()).collect();
+        assert_eq!(run_ids, vec!["run-a", "run-b"]);
+        assert!(synthesized.iter().all(|e| e.synthesized
+            && e.timestamp_ns == 1_001_000_000
+            && e.payload
+                == EventPayload::RunEnd {
+                    exit_code: None,
+                    reason: Some(TRUNCATED_RUN_REASON.into()),
+                }));
+
+        for event in synthesized {
+            let next = events.len() as u64;
+            events.push(CommittedEvent::commit(event, next));
+        }
+        let (state, _) = replay(&events);
+        assert_eq!(state.incomplete_runs().count(), 0);
+        assert_eq!(
+            state.run_metadata["run-b"].reason.as_deref(),
+            Some(TRUNCATED_RUN_REASON)
+        );
+        assert_eq!(state_hash(&state), state_hash(&replay(&events).0));
+        assert!(finalize_runs(&events).is_empty());
+    }
+
+    /// Pinned hashes. A change here means every recorded `state_hash` is
+    /// now different: bump `REDUCER_VERSION` and update the pins together.
+    #[test]
+    fn state_hash_golden_values() {
+        let event = make_committed(
+            0,
+            EventPayload::RunStart {
+                agent: "test".into(),
+                args: None,
+            },
+        );
+        let state = reduce(&State::new(), &event);
+        assert_eq!(
+            state_hash(&State::new()),
+            "c082e0c9b3d60b6d30c1cc7d3f4047138be1323edf471447e472f97b25904e69"
+        );
+        assert_eq!(
+            state_hash(&state),
+            "465134d7775455e05ec96d28f9a1586f4d46cf12ea1489a7c092b876ba8d5c6d"
+        );
+
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(REDUCER_VERSION.as_bytes());
+        hasher.update(CANONICAL_JSON_VERSION.as_bytes());
+        hasher.update(&canonical_bytes(&state));
+        assert_eq!(state_hash(&state), hasher.finalize().to_hex().to_string());
+    }
+
     #[test]
     fn state_hash_includes_reducer_version() {
         // Verify that state_hash would change if REDUCER_VERSION changed
@@ -1084,6 +3199,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "Read".into(),
                         args: Some(format!("/path/{i}")),
+                        invocation_id: None,
                     },
                 ),
                 2 => make_committed(
@@ -1092,6 +3208,7 @@ mod tests {
                         tool: "Read".into(),
                         result: Some(format!("content-{i}")),
                         status: Some("success".into()),
+                        invocation_id: None,
                     },
                 ),
                 3 => make_committed(
@@ -1133,6 +3250,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "T".into(),
                         args: None,
+                        invocation_id: None,
                     },
                 )
             })
@@ -1160,6 +3278,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "T".into(),
                         args: None,
+                        invocation_id: None,
                     },
                 )
             })
@@ -1187,6 +3306,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: "T".into(),
                         args: None,
+                        invocation_id: None,
                     },
                 )
             })
@@ -1228,6 +3348,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: format!("tool-{}", i % 3),
                         args: Some(format!("args-{i}")),
+                        invocation_id: None,
                     },
                 ),
                 2 => make_committed(
@@ -1236,6 +3357,7 @@ mod tests {
                         tool: format!("tool-{}", i % 3),
                         result: Some(format!("result-{i}")),
                         status: Some("success".into()),
+                        invocation_id: None,
                     },
                 ),
                 3 => make_committed(
@@ -1310,6 +3432,7 @@ mod tests {
                     EventPayload::ToolCall {
                         tool: format!("tool-{}", i % 10),
                         args: Some(format!("arg-{i}")),
+                        invocation_id: None,
                     },
                 )
             })
@@ -1328,4 +3451,357 @@ mod tests {
             );
         }
     }
+
+    // -----------------------------------------------------------------------
+    // Parallel replay
+    // -----------------------------------------------------------------------
+
+    /// Events whose reduction depends on earlier events: paired tool calls,
+    /// repeated RunStart/RunEnd, and sources with gaps, repeats and late
+    /// sequence numbers, interleaved across two runs.
+    fn boundary_sensitive_events() -> Vec<CommittedEvent> {
+        let call = |tool: &str, id: &str| EventPayload::ToolCall {
+            tool: tool.into(),
+            args: None,
+            invocation_id: Some(id.into()),
+        };
+        let result = |tool: &str, id: &str, status: &str| EventPayload::ToolResult {
+            tool: tool.into(),
+            result: None,
+            status: Some(status.into()),
+            invocation_id: Some(id.into()),
+        };
+        let start = |agent: &str| EventPayload::RunStart {
+            agent: agent.into(),
+            args: Some(format!("--as {agent}")),
+        };
+        let end = |code: i32| EventPayload::RunEnd {
+            exit_code: Some(code),
+            reason: None,
+        };
+        let progress = || EventPayload::Generic {
+            event_type: "Progress".into(),
+            data: BTreeMap::new(),
+        };
+        let redact = |field_path: &str| EventPayload::RedactionApplied {
+            target_event_id: "e-1".into(),
+            field_path: field_path.into(),
+            reason: "secret".into(),
+        };
+        let script = vec![
+            ("run-a", "agent", Some(0), start("first")),
+            ("run-a", "agent", Some(1), call("Bash", "c1")),
+            ("run-b", "hook", Some(3), start("hooked")),
+            ("run-a", "agent", Some(2), call("Read", "c2")),
+            ("run-a", "agent", Some(6), result("Bash", "c1", "success")),
+            ("run-b", "hook", Some(4), call("Grep", "c3")),
+            ("run-b", "hook", Some(5), redact("payload.args")),
+            ("run-a", "agent", Some(3), call("Read", "c2")),
+            ("run-a", "agent", Some(7), result("Read", "c2", "error")),
+            ("run-a", "agent", Some(7), result("Read", "c2", "error")),
+            (
+                "run-a",
+                "agent",
+                None,
+                EventPayload::TokenUsage {
+                    model: "m".into(),
+                    input_tokens: 10,
+                    output_tokens: u64::MAX,
+                },
+            ),
+            ("run-b", "hook", Some(9), result("Grep", "c3", "success")),
+            ("run-a", "agent", Some(8), end(1)),
+            (
+                "run-a",
+                "agent",
+                Some(12),
+                EventPayload::SubagentSpawned {
+                    parent_run_id: "run-a".into(),
+                    child_run_id: "run-c".into(),
+                    agent: "sub".into(),
+                },
+            ),
+            ("run-b", "hook", Some(2), result("Bash", "c9", "success")),
+            ("run-a", "agent", Some(13), start("second")),
+            (
+                "run-a",
+                "agent",
+                Some(14),
+                EventPayload::TokenUsage {
+                    model: "m".into(),
+                    input_tokens: 5,
+                    output_tokens: 5,
+                },
+            ),
+            ("run-b", "hook", Some(10), end(0)),
+            ("run-b", "hook", Some(11), redact("payload.result")),
+            ("run-b", "hook", Some(12), progress()),
+            (
+                "run-a",
+                "agent",
+                Some(16),
+                EventPayload::Error {
+                    kind: "io".into(),
+                    message: "disk".into(),
+                    severity: None,
+                },
+            ),
+            ("run-a", "agent", Some(17), call("Bash", "c4")),
+            ("run-b", "hook", Some(13), progress()),
+            ("run-a", "agent", Some(18), end(0)),
+        ];
+        script
+            .into_iter()
+            .enumerate()
+            .map(|(i, (run_id, source_id, seq, payload))| {
+                let mut event = make_committed_with_run(i as u64, run_id, payload);
+                if matches!(event.payload, EventPayload::Generic { .. }) {
+                    event.tier = Tier::B;
+                }
+                event.source_id = source_id.into();
+                event.source_seq = seq;
+                // Mildly skewed clock so run timestamps are not first/last seen.
+                event.timestamp_ns = 1_000 + (i as u64 * 7_919) % 101;
+                event
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parallel_replay_matches_serial_at_every_chunk_size() {
+        let events = boundary_sensitive_events();
+        let (serial, _) = replay(&events);
+        assert!(!serial.source_gaps.is_empty());
+        assert!(!serial.tool_summaries["Read"].latency.is_empty());
+        assert_eq!(
+            serial.tier_bc_bins.len(),
+            2,
+            "Progress events straddle a bin"
+        );
+
+        for chunk_size in 1..=events.len() {
+            let parallel = replay_parallel(&events, chunk_size).unwrap();
+            assert_eq!(parallel, serial, "chunk_size {chunk_size}");
+        }
+        for shards in 1..=events.len() + 1 {
+            let config = ParallelReplayConfig::new(shards).with_min_events(0);
+            let parallel = replay_parallel_with(&events, &config).unwrap();
+            assert_eq!(parallel, serial, "{shards} shards");
+            assert_eq!(state_hash(&parallel), state_hash(&serial));
+        }
+    }
+
+    #[test]
+    fn parallel_replay_hash_is_independent_of_shard_and_thread_count() {
+        let events = boundary_sensitive_events();
+        let serial_hash = state_hash(&replay(&events).0);
+        for shards in [1, 2, 7] {
+            for threads in [1, 2, 3] {
+                let config = ParallelReplayConfig::new(shards)
+                    .with_threads(threads)
+                    .with_min_events(0);
+                let parallel = replay_parallel_with(&events, &config).unwrap();
+                assert_eq!(
+                    state_hash(&parallel),
+                    serial_hash,
+                    "{shards} shards on {threads} threads"
+                );
+            }
+            // Below the default minimum this takes the serial fallback.
+            let parallel = replay_parallel_sharded(&events, shards).unwrap();
+            assert_eq!(state_hash(&parallel), serial_hash, "{shards} shards");
+        }
+    }
+
+    #[test]
+    fn merge_pairs_result_with_call_from_earlier_chunk() {
+        let events = boundary_sensitive_events();
+        // c1 is called at index 1 and answered at index 4.
+        let mut state = replay_chunk(&events[..3]).state;
+        let later = replay_chunk(&events[3..6]);
+        assert_eq!(later.state.tool_summaries["Bash"].unmatched_results, 1);
+
+        state.merge_chunk(later);
+        let bash = &state.tool_summaries["Bash"];
+        assert_eq!(bash.unmatched_results, 0);
+        assert_eq!(bash.latency.count(), 1);
+        assert!(!state.pending_tool_calls.contains_key("c1"));
+        assert_eq!(state, replay(&events[..6]).0);
+    }
+
+    #[test]
+    fn merge_keeps_earlier_run_start_when_chunk_has_none() {
+        let events = boundary_sensitive_events();
+        let mut state = replay_chunk(&events[..2]).state;
+        state.merge_chunk(replay_chunk(&events[2..]));
+        assert_eq!(state.run_metadata["run-a"].agent, "second");
+
+        let mut state = replay_chunk(&events[..12]).state;
+        state.merge_chunk(replay_chunk(&events[12..14]));
+        assert_eq!(state.run_metadata["run-a"].agent, "first");
+        assert!(state.run_metadata["run-a"].ended);
+    }
+
+    /// Three runs interleaved in commit order, each with its own source
+    /// and invocation ids.
+    fn interleaved_run_events() -> Vec<CommittedEvent> {
+        let runs = ["run-a", "run-b", "run-c"];
+        (0..60u64)
+            .map(|i| {
+                let run = runs[(i % 3) as usize];
+                let step = i / 3;
+                let payload = match step % 10 {
+                    0 => EventPayload::RunStart {
+                        agent: format!("agent-{run}-{step}"),
+                        args: None,
+                    },
+                    1 => EventPayload::ToolCall {
+                        tool: "Bash".into(),
+                        args: None,
+                        invocation_id: Some(format!("{run}-{step}")),
+                    },
+                    2 => EventPayload::ToolResult {
+                        tool: "Bash".into(),
+                        result: None,
+                        status: Some(if i % 2 == 0 { "success" } else { "error" }.into()),
+                        invocation_id: Some(format!("{run}-{}", step - 1)),
+                    },
+                    3 => EventPayload::Error {
+                        kind: "io".into(),
+                        message: format!("error-{i}"),
+                        severity: None,
+                    },
+                    4 => EventPayload::PolicyDecision {
+                        from_level: "L0".into(),
+                        to_level: "L1".into(),
+                        trigger: format!("trigger-{i}"),
+                        queue_pressure: 0.5,
+                    },
+                    5 => EventPayload::RedactionApplied {
+                        target_event_id: format!("e-{}", i - 4),
+                        field_path: "payload.args".into(),
+                        reason: "secret".into(),
+                    },
+                    6 => EventPayload::TokenUsage {
+                        model: "m".into(),
+                        input_tokens: i,
+                        output_tokens: 1,
+                    },
+                    7 => EventPayload::Generic {
+                        event_type: "Progress".into(),
+                        data: BTreeMap::new(),
+                    },
+                    8 => EventPayload::ClockSkewDetected {
+                        expected_ns: 2_000,
+                        actual_ns: 1_000,
+                        delta_ns: 1_000,
+                    },
+                    _ => EventPayload::RunEnd {
+                        exit_code: Some(step as i32),
+                        reason: None,
+                    },
+                };
+                let mut event = make_committed_with_run(i, run, payload);
+                if matches!(event.payload, EventPayload::Generic { .. }) {
+                    event.tier = Tier::B;
+                }
+                event.source_id = format!("source-{run}");
+                // Skip one seq per run so each source has a gap.
+                event.source_seq = Some(step + u64::from(step >= 15));
+                event.timestamp_ns = 1_000 + (i * 7_919) % 101;
+                event
+            })
+            .collect()
+    }
+
+    fn shard(events: &[CommittedEvent], run_id: &str) -> State {
+        let shard: Vec<_> = events
+            .iter()
+            .filter(|event| event.run_id == run_id)
+            .cloned()
+            .collect();
+        replay_shard(&shard)
+    }
+
+    #[test]
+    fn merging_run_shards_equals_sequential_replay() {
+        let events = interleaved_run_events();
+        let (serial, _) = replay(&events);
+        assert!(!serial.source_gaps.is_empty());
+        assert!(!serial.redaction_log.is_empty());
+
+        let a = || shard(&events, "run-a");
+        let b = || shard(&events, "run-b");
+        let c = || shard(&events, "run-c");
+        let merged = a().merge(b()).merge(c());
+        assert_eq!(merged, serial);
+        assert_eq!(state_hash(&merged), state_hash(&serial));
+
+        assert_eq!(a().merge(b().merge(c())), serial);
+        assert_eq!(c().merge(a()).merge(b()), serial);
+        assert_eq!(State::new().merge(replay_shard(&events)), serial);
+        assert_eq!(replay_shard(&events).merge(State::new()), serial);
+    }
+
+    /// Shards of `events` picked by `shard_of(commit_index)`.
+    fn split(events: &[CommittedEvent], shard_of: impl Fn(u64) -> usize) -> Vec<State> {
+        let mut shards = vec![Vec::new(); 3];
+        for event in events {
+            shards[shard_of(event.commit_index)].push(event.clone());
+        }
+        shards.iter().map(|shard| replay_shard(shard)).collect()
+    }
+
+    #[test]
+    fn merging_interleaved_shards_equals_sequential_replay() {
+        // Shards that split runs, sources, and invocations: a result lands
+        // in another shard than its call, a RunStart wins across shards, and
+        // source gaps open and close at shard edges.
+        for events in [boundary_sensitive_events(), interleaved_run_events()] {
+            let (serial, _) = replay(&events);
+            let serial_hash = state_hash(&serial);
+            for mask in 0..64u64 {
+                let [a, b, _] = <[State; 3]>::try_from(split(&events, |i| {
+                    usize::from(mask >> (i % 6) & 1 == 1)
+                }))
+                .unwrap();
+                let merged = a.merge(b);
+                assert_eq!(state_hash(&merged), serial_hash, "mask {mask:06b}");
+                assert_eq!(merged, serial, "mask {mask:06b}");
+            }
+
+            let shard_of = |i: u64| ((i * 7) % 3) as usize;
+            let shards = || split(&events, shard_of);
+            let [a, b, c] = <[State; 3]>::try_from(shards()).unwrap();
+            let left = a.merge(b).merge(c);
+            let [a, b, c] = <[State; 3]>::try_from(shards()).unwrap();
+            let right = a.merge(b.merge(c));
+            let [a, b, c] = <[State; 3]>::try_from(shards()).unwrap();
+            let shuffled = c.merge(a).merge(b);
+            assert_eq!(state_hash(&left), serial_hash);
+            assert_eq!(left, serial);
+            assert_eq!(right, serial);
+            assert_eq!(shuffled, serial);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "State::new_shard")]
+    fn merge_rejects_a_state_without_shard_history() {
+        let events = boundary_sensitive_events();
+        let _ = replay(&events).0.merge(replay_shard(&events[..0]));
+    }
+
+    #[test]
+    fn parallel_replay_rejects_zero_chunk_size_shards_or_threads() {
+        let err = replay_parallel(&[], 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let err = replay_parallel_sharded(&[], 0).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let config = ParallelReplayConfig::new(4).with_threads(0);
+        let err = replay_parallel_with(&[], &config).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(replay_parallel(&[], 10).unwrap(), State::new());
+        assert_eq!(replay_parallel_sharded(&[], 10).unwrap(), State::new());
+    }
 }
@@ -0,0 +1,141 @@
+//! Deterministic UTC rendering of Unix epoch timestamps.
+//!
+//! Pure integer arithmetic on the proleptic Gregorian calendar: no clock
+//! reads, no locale, no time zone database. The same integer renders to the
+//! same string on every platform, so these are safe in snapshots and other
+//! deterministic surfaces. Every civil-calendar rendering in the workspace
+//! (Forensic Lens timestamps, export manifests) goes through this module
+//! rather than pulling in a date crate.
+
+/// Convert days since the Unix epoch to `(year, month, day)`.
+pub fn days_to_ymd(mut days: u64) -> (u64, u64, u64) {
+    // Civil calendar algorithm
+    let mut year = 1970u64;
+    loop {
+        let days_in_year = if is_leap(year) { 366 } else { 365 };
+        if days < days_in_year {
+            break;
+        }
+        days -= days_in_year;
+        year += 1;
+    }
+    let days_in_months: [u64; 12] = if is_leap(year) {
+        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    } else {
+        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    };
+    let mut month = 1u64;
+    for &dm in &days_in_months {
+        if days < dm {
+            break;
+        }
+        days -= dm;
+        month += 1;
+    }
+    (year, month, days + 1)
+}
+
+fn is_leap(y: u64) -> bool {
+    y.is_multiple_of(4) && (!y.is_multiple_of(100) || y.is_multiple_of(400))
+}
+
+/// `YYYY-MM-DDTHH:MM:SS` for `secs` since the epoch, without a zone suffix.
+fn format_secs(secs: u64) -> String {
+    let (year, month, day) = days_to_ymd(secs / 86400);
+    let remaining = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        remaining / 3600,
+        (remaining % 3600) / 60,
+        remaining % 60
+    )
+}
+
+/// `YYYY-MM-DDTHH:MM:SSZ` for `secs` since the epoch.
+pub fn format_utc_secs(secs: u64) -> String {
+    format!("{}Z", format_secs(secs))
+}
+
+/// `YYYY-MM-DDTHH:MM:SS.fffffffffZ` for `timestamp_ns` since the epoch.
+pub fn format_ns_utc(timestamp_ns: u64) -> String {
+    format!(
+        "{}.{:09}Z",
+        format_secs(timestamp_ns / 1_000_000_000),
+        timestamp_ns % 1_000_000_000
+    )
+}
+
+/// `YYYY-MM-DDTHH:MM:SS.fffZ` for `timestamp_ns` since the epoch, with the
+/// sub-millisecond digits truncated.
+pub fn format_ms_utc(timestamp_ns: u64) -> String {
+    format!(
+        "{}.{:03}Z",
+        format_secs(timestamp_ns / 1_000_000_000),
+        timestamp_ns % 1_000_000_000 / 1_000_000
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_timestamps_render_to_fixed_utc_strings() {
+        assert_eq!(format_ns_utc(0), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(
+            format_ns_utc(1_768_435_201_358_000_000),
+            "2026-01-15T00:00:01.358000000Z"
+        );
+        assert_eq!(
+            format_ns_utc(1_709_210_096_789_012_345),
+            "2024-02-29T12:34:56.789012345Z"
+        );
+        assert_eq!(format_ns_utc(u64::MAX), "2554-07-21T23:34:33.709551615Z");
+    }
+
+    #[test]
+    fn millisecond_form_truncates() {
+        assert_eq!(
+            format_ms_utc(1_709_210_096_789_999_999),
+            "2024-02-29T12:34:56.789Z"
+        );
+        assert_eq!(format_utc_secs(1_709_210_096), "2024-02-29T12:34:56Z");
+    }
+
+    #[test]
+    fn days_to_ymd_handles_year_and_leap_boundaries() {
+        assert_eq!(days_to_ymd(0), (1970, 1, 1));
+        assert_eq!(days_to_ymd(365), (1971, 1, 1));
+        // 2000 is a leap year (divisible by 400), 2100 is not.
+        assert_eq!(days_to_ymd(11_016), (2000, 2, 29));
+        assert_eq!(days_to_ymd(47_541), (2100, 3, 1));
+        // 1972 is the first leap year after the epoch.
+        assert_eq!(days_to_ymd(789), (1972, 2, 29));
+        assert_eq!(days_to_ymd(1_095), (1972, 12, 31));
+    }
+
+    #[test]
+    fn month_boundaries_roll_over_at_midnight() {
+        // 1970-01-31T23:59:59Z and the next second.
+        assert_eq!(format_utc_secs(2_678_399), "1970-01-31T23:59:59Z");
+        assert_eq!(format_utc_secs(2_678_400), "1970-02-01T00:00:00Z");
+        // 2023-02-28 is followed by March 1 in a common year.
+        assert_eq!(days_to_ymd(19_416), (2023, 2, 28));
+        assert_eq!(days_to_ymd(19_417), (2023, 3, 1));
+        // 2024-02-28 is followed by February 29 in a leap year.
+        assert_eq!(days_to_ymd(19_781), (2024, 2, 28));
+        assert_eq!(days_to_ymd(19_782), (2024, 2, 29));
+        // Year end.
+        assert_eq!(
+            format_ns_utc(1_704_067_199_999_999_999),
+            "2023-12-31T23:59:59.999999999Z"
+        );
+        assert_eq!(
+            format_ns_utc(1_704_067_200_000_000_000),
+            "2024-01-01T00:00:00.000000000Z"
+        );
+    }
+}
@@ -1,8 +1,73 @@
-use crate::{BundleManifest, DiscoveredContent, ExportSuccess, ManifestEntry};
-use std::io;
-use std::path::Path;
+use crate::{
+    BundleManifest, DiscoveredContent, ExportSuccess, ManifestEntry, OversizeBlob, MANIFEST_VERSION,
+};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 use vifei_core::blob_store::BlobStore;
+use vifei_core::event::CommittedEvent;
+use vifei_core::eventlog::{eventlog_segments, open_eventlog_file};
 use vifei_core::projection::PROJECTION_INVARIANTS_VERSION;
+use vifei_core::reducer::{
+    create_checkpoint, reduce_in_place, serialize_checkpoint, Checkpoint, State,
+    CHECKPOINT_INTERVAL, REDUCER_VERSION,
+};
+
+/// Archive path of the optional reducer checkpoint.
+pub(crate) const CHECKPOINT_ARCHIVE_PATH: &str = "checkpoint.json";
+
+/// Replay `events` and return the checkpoint at the last
+/// [`CHECKPOINT_INTERVAL`] boundary.
+///
+/// An EventLog shorter than one interval yields an omission note instead;
+/// checkpoints are derived artifacts, so bundles stay valid without one.
+pub(crate) fn latest_checkpoint(events: &[CommittedEvent]) -> Result<Checkpoint, String> {
+    let mut state = State::new();
+    let mut latest = None;
+    for event in events {
+        reduce_in_place(&mut state, event);
+        if (event.commit_index + 1) % CHECKPOINT_INTERVAL == 0 {
+            latest = Some(create_checkpoint(&state));
+        }
+    }
+    latest.ok_or_else(|| {
+        format!(
+            "checkpoint omitted: EventLog has {} event(s), below the {CHECKPOINT_INTERVAL}-event checkpoint interval",
+            events.len()
+        )
+    })
+}
+
+/// What [`create_bundle`] packs beside `eventlog.jsonl`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct BundleOptions<'a> {
+    /// Store holding the referenced blobs, if any.
+    pub blob_store: Option<&'a BlobStore>,
+    /// When false, blob files are left out and the number of referenced
+    /// blobs is recorded as `blobs_excluded` in the manifest.
+    pub include_blobs: bool,
+    /// Blobs left out for exceeding the size limit, one note each.
+    pub oversize: &'a [OversizeBlob],
+    /// When `Some`, a valid checkpoint (current [`REDUCER_VERSION`],
+    /// `commit_index` inside the EventLog) is added as `checkpoint.json`
+    /// and its `commit_index` recorded in the manifest. An invalid one, or
+    /// `Some(Err(note))`, is omitted and the reason is returned in
+    /// [`ExportSuccess::notes`].
+    pub checkpoint: Option<&'a Result<Checkpoint, String>>,
+}
+
+impl<'a> BundleOptions<'a> {
+    /// Include every blob found in `blob_store`, no checkpoint.
+    pub(crate) fn new(blob_store: Option<&'a BlobStore>) -> Self {
+        BundleOptions {
+            blob_store,
+            include_blobs: true,
+            oversize: &[],
+            checkpoint: None,
+        }
+    }
+}
 
 /// Bundle discovered content into a deterministic tar+zstd archive.
 ///
@@ -14,43 +79,133 @@ use vifei_core::projection::PROJECTION_INVARIANTS_VERSION;
 /// - Tar username/groupname: empty
 /// - Entries sorted alphabetically by path
 /// - bundle_hash = BLAKE3 of final .tar.zst bytes
+///
+/// Blobs and the checkpoint are added as `options` says; see
+/// [`BundleOptions`].
+///
+/// A truncated final record is cut from `eventlog.jsonl`, recorded as
+/// `truncated_tail_bytes` in the manifest, and noted.
+///
+/// For an incremental delta (`base_commit_index_range` set) `eventlog.jsonl`
+/// starts at the first line past the base range, so it is the byte suffix
+/// that follows the base bundle's `eventlog.jsonl`.
+///
+/// Blob files are streamed: hashed once for the manifest, then copied into
+/// the archive, which is compressed straight to a `.partial` file beside
+/// `output_path` and renamed into place when complete. A blob that changes
+/// between the two passes fails the export.
 pub(crate) fn create_bundle(
     content: &DiscoveredContent,
-    blob_store: Option<&BlobStore>,
+    options: &BundleOptions<'_>,
     output_path: &Path,
 ) -> io::Result<ExportSuccess> {
-    // Collect all entries as (archive_path, data) for deterministic sorting
-    let mut entries: Vec<(String, Vec<u8>)> = Vec::new();
+    let BundleOptions {
+        blob_store,
+        include_blobs,
+        oversize,
+        checkpoint,
+    } = *options;
+    // Collect all entries as (archive_path, source) for deterministic sorting
+    let mut entries: Vec<(String, EntrySource)> = Vec::new();
+    let mut notes = Vec::new();
 
-    // Add EventLog
-    let eventlog_bytes = std::fs::read(&content.eventlog_path)?;
-    entries.push(("eventlog.jsonl".to_string(), eventlog_bytes));
+    // Add EventLog, concatenating (decompressed) segments into one file
+    let mut eventlog_bytes = Vec::new();
+    for segment in eventlog_segments(&content.eventlog_path)? {
+        open_eventlog_file(&segment)?.read_to_end(&mut eventlog_bytes)?;
+    }
+    if let Some(tail_bytes) = content.truncated_tail_bytes {
+        let keep = eventlog_bytes.len().saturating_sub(tail_bytes as usize);
+        eventlog_bytes.truncate(keep);
+        notes.push(format!(
+            "EventLog ends with a truncated record; {tail_bytes} bytes left out of eventlog.jsonl"
+        ));
+    }
+    if let Some([first, last]) = content.base_commit_index_range {
+        eventlog_bytes.drain(..delta_start(&eventlog_bytes, last));
+        notes.push(format!(
+            "incremental bundle: events after base commit_index range {first}..={last}"
+        ));
+    }
+    entries.push((
+        "eventlog.jsonl".to_string(),
+        EntrySource::Bytes(eventlog_bytes),
+    ));
 
     // Add blobs (sorted by ref for deterministic ordering)
     let mut blob_count = 0usize;
-    if let Some(store) = blob_store {
+    let blobs_excluded = if include_blobs {
+        oversize.len()
+    } else {
+        content.blob_refs.len()
+    };
+    if let Some(store) = blob_store.filter(|_| include_blobs) {
         let mut sorted_refs: Vec<&str> = content.blob_refs.iter().map(|s| s.as_str()).collect();
         sorted_refs.sort();
         for blob_ref in sorted_refs {
-            if let Some(data) = store.read_blob(blob_ref)? {
-                entries.push((format!("blobs/{}", blob_ref), data));
+            if let Some(blob) = oversize.iter().find(|blob| blob.payload_ref == blob_ref) {
+                notes.push(format!(
+                    "blob {} ({} bytes) exceeds the blob size limit; left out of the bundle",
+                    blob.payload_ref, blob.size
+                ));
+            } else if store.has_blob(blob_ref) {
+                entries.push((
+                    format!("blobs/{}", blob_ref),
+                    EntrySource::Blob(blob_ref.to_string()),
+                ));
                 blob_count += 1;
             }
         }
     }
 
+    // Add reducer checkpoint if requested and valid
+    let mut checkpoint_commit_index = None;
+    match checkpoint {
+        None => {}
+        Some(Err(note)) => notes.push(note.clone()),
+        Some(Ok(checkpoint)) => match validate_checkpoint(checkpoint, content) {
+            Ok(()) => {
+                let bytes = serialize_checkpoint(checkpoint).map_err(|e| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("checkpoint serialization: {e}"),
+                    )
+                })?;
+                entries.push((
+                    CHECKPOINT_ARCHIVE_PATH.to_string(),
+                    EntrySource::Bytes(bytes),
+                ));
+                checkpoint_commit_index = Some(checkpoint.commit_index);
+            }
+            Err(note) => notes.push(note),
+        },
+    }
+
     // Sort all entries alphabetically by path (deterministic archive order)
     entries.sort_by(|a, b| a.0.cmp(&b.0));
 
     // Build manifest entries with BLAKE3 digests for each file
-    let manifest_file_entries: Vec<ManifestEntry> = entries
+    let manifest_file_entries = entries
         .iter()
-        .map(|(path, data)| ManifestEntry {
-            path: path.clone(),
-            blake3: blake3::hash(data).to_hex().to_string(),
-            size: data.len() as u64,
+        .map(|(path, source)| {
+            let (blake3, size) = match source {
+                EntrySource::Bytes(data) => {
+                    (blake3::hash(data).to_hex().to_string(), data.len() as u64)
+                }
+                EntrySource::Blob(blob_ref) => {
+                    let store = blob_store.expect("blob entries imply a store");
+                    let mut reader = HashingReader::new(store.open_blob(blob_ref)?);
+                    io::copy(&mut reader, &mut io::sink())?;
+                    reader.finish()
+                }
+            };
+            Ok(ManifestEntry {
+                path: path.clone(),
+                blake3,
+                size,
+            })
         })
-        .collect();
+        .collect::<io::Result<Vec<_>>>()?;
 
     // Compute commit_index range from events
     let commit_index_range = content.events.iter().map(|event| event.commit_index).fold(
@@ -63,10 +218,19 @@ pub(crate) fn create_bundle(
 
     // Build the manifest
     let manifest = BundleManifest {
-        manifest_version: "manifest-v0.1".to_string(),
+        manifest_version: MANIFEST_VERSION.to_string(),
         files: manifest_file_entries,
         commit_index_range,
         projection_invariants_version: PROJECTION_INVARIANTS_VERSION.to_string(),
+        reducer_version: REDUCER_VERSION.to_string(),
+        checkpoint_commit_index,
+        blobs_excluded,
+        hash_chained: content
+            .events
+            .first()
+            .is_some_and(|event| event.prev_hash.is_some()),
+        truncated_tail_bytes: content.truncated_tail_bytes,
+        base_commit_index_range: content.base_commit_index_range,
     };
     let manifest_json = serde_json::to_string_pretty(&manifest).map_err(|e| {
         io::Error::new(
@@ -76,50 +240,205 @@ pub(crate) fn create_bundle(
     })?;
 
     // Add manifest to entries (will be sorted into correct position)
-    entries.push(("manifest.json".to_string(), manifest_json.into_bytes()));
+    let expected: HashMap<&str, &ManifestEntry> = manifest
+        .files
+        .iter()
+        .map(|entry| (entry.path.as_str(), entry))
+        .collect();
+    entries.push((
+        "manifest.json".to_string(),
+        EntrySource::Bytes(manifest_json.into_bytes()),
+    ));
     entries.sort_by(|a, b| a.0.cmp(&b.0));
 
-    // Build tar+zstd into a memory buffer so we can BLAKE3-hash the result
-    let mut compressed_bytes: Vec<u8> = Vec::new();
-    {
-        // Zstd level 3 (pinned per CAPACITY_ENVELOPE)
-        let encoder = zstd::stream::write::Encoder::new(&mut compressed_bytes, 3)
-            .map_err(|e| io::Error::other(format!("zstd init: {e}")))?;
-        let mut tar_builder = tar::Builder::new(encoder);
-
-        for (path, data) in &entries {
-            append_tar_entry(&mut tar_builder, path, data)?;
+    // Stream tar+zstd to a partial file, BLAKE3-hashing the compressed bytes
+    let mut partial = output_path.as_os_str().to_owned();
+    partial.push(".partial");
+    let partial = PathBuf::from(partial);
+    let written = write_archive(&entries, blob_store, &expected, &partial);
+    let bundle_hash = match written {
+        Ok(hash) => hash,
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            return Err(e);
         }
-
-        // Finish tar (writes final blocks), then finish zstd (flushes frame)
-        let encoder = tar_builder.into_inner()?;
-        encoder.finish()?;
-    }
-
-    // bundle_hash = BLAKE3 of final .tar.zst bytes
-    let bundle_hash = blake3::hash(&compressed_bytes).to_hex().to_string();
-
-    // Write the completed bundle to disk
-    std::fs::write(output_path, &compressed_bytes)?;
+    };
+    std::fs::rename(&partial, output_path)?;
 
     Ok(ExportSuccess {
         bundle_path: output_path.to_path_buf(),
         bundle_hash,
         event_count: content.event_count(),
         blob_count,
+        blobs_excluded,
+        checkpoint_commit_index,
+        base_commit_index_range: content.base_commit_index_range,
+        notes,
+        signature_path: None,
+        signer_fingerprint: None,
     })
 }
 
+/// Just the field the delta cut needs.
+#[derive(Deserialize)]
+struct CommitIndexOnly {
+    commit_index: u64,
+}
+
+/// Byte offset of the first line whose `commit_index` is past `base_last`,
+/// or the end of `bytes` when there is none.
+fn delta_start(bytes: &[u8], base_last: u64) -> usize {
+    let mut offset = 0;
+    for line in bytes.split_inclusive(|&b| b == b'\n') {
+        if serde_json::from_slice::<CommitIndexOnly>(line.trim_ascii())
+            .is_ok_and(|line| line.commit_index > base_last)
+        {
+            return offset;
+        }
+        offset += line.len();
+    }
+    bytes.len()
+}
+
+/// Check a checkpoint is usable with this EventLog and reducer build.
+fn validate_checkpoint(checkpoint: &Checkpoint, content: &DiscoveredContent) -> Result<(), String> {
+    if checkpoint.reducer_version != REDUCER_VERSION {
+        return Err(format!(
+            "checkpoint omitted: reducer_version {} does not match {REDUCER_VERSION}",
+            checkpoint.reducer_version
+        ));
+    }
+    if !content
+        .events
+        .iter()
+        .any(|event| event.commit_index == checkpoint.commit_index)
+    {
+        return Err(format!(
+            "checkpoint omitted: commit_index {} is not in the EventLog",
+            checkpoint.commit_index
+        ));
+    }
+    Ok(())
+}
+
+/// Where an archive entry's bytes come from.
+enum EntrySource {
+    /// Small, already in memory (EventLog, checkpoint, manifest).
+    Bytes(Vec<u8>),
+    /// A blob streamed from the store by `payload_ref`.
+    Blob(String),
+}
+
+/// Write `entries` as tar+zstd to `path`, returning the BLAKE3 hex digest
+/// of the compressed bytes. Streamed blobs must still match `expected`.
+fn write_archive(
+    entries: &[(String, EntrySource)],
+    blob_store: Option<&BlobStore>,
+    expected: &HashMap<&str, &ManifestEntry>,
+    path: &Path,
+) -> io::Result<String> {
+    let file = io::BufWriter::new(std::fs::File::create(path)?);
+    // Zstd level 3 (pinned per CAPACITY_ENVELOPE)
+    let encoder = zstd::stream::write::Encoder::new(HashingWriter::new(file), 3)
+        .map_err(|e| io::Error::other(format!("zstd init: {e}")))?;
+    let mut tar_builder = tar::Builder::new(encoder);
+
+    for (entry_path, source) in entries {
+        match source {
+            EntrySource::Bytes(data) => {
+                append_tar_entry(&mut tar_builder, entry_path, data.len() as u64, &data[..])?
+            }
+            EntrySource::Blob(blob_ref) => {
+                let store = blob_store.expect("blob entries imply a store");
+                let want = expected[entry_path.as_str()];
+                let mut reader = HashingReader::new(store.open_blob(blob_ref)?.take(want.size));
+                append_tar_entry(&mut tar_builder, entry_path, want.size, &mut reader)?;
+                if reader.finish() != (want.blake3.clone(), want.size) {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("blob {blob_ref} changed while it was being bundled"),
+                    ));
+                }
+            }
+        }
+    }
+
+    // Finish tar (writes final blocks), then finish zstd (flushes frame)
+    let encoder = tar_builder.into_inner()?;
+    let HashingWriter { inner, hasher } = encoder.finish()?;
+    inner.into_inner().map_err(|e| e.into_error())?;
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// Reader that BLAKE3-hashes and counts the bytes passing through.
+struct HashingReader<R> {
+    inner: R,
+    hasher: blake3::Hasher,
+    len: u64,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        HashingReader {
+            inner,
+            hasher: blake3::Hasher::new(),
+            len: 0,
+        }
+    }
+
+    /// Hex digest and length of everything read so far.
+    fn finish(&self) -> (String, u64) {
+        (self.hasher.finalize().to_hex().to_string(), self.len)
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        self.len += n as u64;
+        Ok(n)
+    }
+}
+
+/// Writer that BLAKE3-hashes the bytes passing through.
+struct HashingWriter<W> {
+    inner: W,
+    hasher: blake3::Hasher,
+}
+
+impl<W> HashingWriter<W> {
+    fn new(inner: W) -> Self {
+        HashingWriter {
+            inner,
+            hasher: blake3::Hasher::new(),
+        }
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 /// Append a single entry to a tar archive with normalized metadata.
 ///
 /// All metadata is normalized per CAPACITY_ENVELOPE Export determinism targets.
 fn append_tar_entry<W: io::Write>(
     builder: &mut tar::Builder<W>,
     path: &str,
-    data: &[u8],
+    size: u64,
+    data: impl Read,
 ) -> io::Result<()> {
     let mut header = tar::Header::new_ustar();
-    header.set_size(data.len() as u64);
+    header.set_size(size);
     header.set_mtime(0);
     header.set_uid(0);
     header.set_gid(0);
@@ -2,24 +2,31 @@ use crate::DiscoveredContent;
 use std::collections::HashSet;
 use std::io;
 use std::path::Path;
-use vifei_core::eventlog::read_eventlog;
+use vifei_core::eventlog::read_eventlog_iter;
 
 /// Discover all content referenced by an EventLog.
 ///
-/// Reads the EventLog and identifies all blob references.
+/// Streams the EventLog once, collecting events and all blob references.
+/// A truncated final record is recorded rather than failing discovery, so
+/// the caller decides whether to refuse.
 pub(crate) fn discover_content(eventlog_path: &Path) -> io::Result<DiscoveredContent> {
-    let events = read_eventlog(eventlog_path)?;
+    let mut events = Vec::new();
     let mut blob_refs = HashSet::new();
 
-    for event in &events {
+    let mut reader = read_eventlog_iter(eventlog_path)?.with_tail_recovery();
+    for event in reader.by_ref() {
+        let event = event?;
         if let Some(ref payload_ref) = event.payload_ref {
             blob_refs.insert(payload_ref.clone());
         }
+        events.push(event);
     }
 
     Ok(DiscoveredContent {
         eventlog_path: eventlog_path.to_path_buf(),
         events,
         blob_refs,
+        truncated_tail_bytes: reader.truncated_tail_bytes(),
+        base_commit_index_range: None,
     })
 }
@@ -17,6 +17,30 @@
 //! 2. **Scan**: Check event payloads and blob contents for secrets (M8.2)
 //! 3. **Decide**: If secrets found → refuse with report; otherwise → bundle
 //! 4. **Bundle**: Create deterministic tar.zstd archive (M8.4)
+//! 5. **Sign** (optional): Write a detached ed25519 signature over
+//!    `bundle_hash` when a signing key is configured ([`signing`])
+//!
+//! [`ExportConfig::with_max_blob_size`] caps the size of bundled blobs. A
+//! referenced blob over the cap ends the export with
+//! [`ExportResult::OversizeBlob`] before the scan, or, with
+//! [`ExportConfig::with_oversize_blobs_excluded`], is left out of the bundle
+//! and counted in `blobs_excluded` with a note.
+//!
+//! With a base manifest ([`ExportConfig::with_base_manifest`]) the bundle is
+//! incremental: only events after the base bundle's last `commit_index`
+//! are discovered, scanned, and bundled, and the base range is recorded as
+//! `base_commit_index_range`. The delta's `eventlog.jsonl` is the exact byte
+//! suffix of the EventLog, so appending it to the base's reproduces the log.
+//!
+//! Every full export records its verdict, `CLEAN` or `REFUSED`, in the
+//! EventLog's export safety sidecar (see [`vifei_core::export_safety`]) so
+//! the Truth HUD can show it. Incremental exports scanned only part of the
+//! log and record nothing on success.
+//!
+//! [`run_export_with_progress`] reports each stage to a callback as an
+//! [`ExportProgress`], for wrappers that show progress without scraping
+//! output. The callback only observes: the bundle and report are the same
+//! with or without it.
 //!
 //! # CLI
 //!
@@ -32,18 +56,25 @@
 
 mod bundle;
 mod discover;
+pub mod redact;
 mod scanner;
 mod secret_scan;
+pub mod signing;
+pub mod verify;
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::io;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
-use vifei_core::blob_store::BlobStore;
+use vifei_core::blob_store::{BlobIntegrityError, BlobStore};
+use vifei_core::error::VifeiError;
 use vifei_core::event::CommittedEvent;
+use vifei_core::export_safety::write_export_safety;
+use vifei_core::projection::ExportSafetyState;
+use vifei_core::time::format_utc_secs;
 
-pub(crate) use bundle::create_bundle;
+pub(crate) use bundle::{create_bundle, latest_checkpoint, BundleOptions};
 pub(crate) use discover::discover_content;
 pub(crate) use secret_scan::scan_for_secrets;
 
@@ -58,53 +89,40 @@ fn format_utc_now() -> String {
     let dur = SystemTime::now()
         .duration_since(SystemTime::UNIX_EPOCH)
         .unwrap_or_default();
-    let secs = dur.as_secs();
-    // Simple UTC formatting: seconds since epoch → ISO 8601
-    // Good enough for informational timestamp (not used in deterministic surfaces)
-    let days = secs / 86400;
-    let remaining = secs % 86400;
-    let hours = remaining / 3600;
-    let minutes = (remaining % 3600) / 60;
-    let seconds = remaining % 60;
-
-    // Days since 1970-01-01
-    let (year, month, day) = days_to_ymd(days);
-    format!(
-        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
-        year, month, day, hours, minutes, seconds
-    )
-}
-
-/// Convert days since Unix epoch to (year, month, day).
-fn days_to_ymd(mut days: u64) -> (u64, u64, u64) {
-    // Civil calendar algorithm
-    let mut year = 1970u64;
-    loop {
-        let days_in_year = if is_leap(year) { 366 } else { 365 };
-        if days < days_in_year {
-            break;
-        }
-        days -= days_in_year;
-        year += 1;
-    }
-    let days_in_months: [u64; 12] = if is_leap(year) {
-        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    } else {
-        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
-    };
-    let mut month = 1u64;
-    for &dm in &days_in_months {
-        if days < dm {
-            break;
-        }
-        days -= dm;
-        month += 1;
-    }
-    (year, month, days + 1)
+    format_utc_secs(dur.as_secs())
 }
 
-fn is_leap(y: u64) -> bool {
-    y.is_multiple_of(4) && (!y.is_multiple_of(100) || y.is_multiple_of(400))
+/// Stage transition reported by [`run_export_with_progress`], in pipeline
+/// order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExportProgress {
+    /// Reading the EventLog is about to start.
+    DiscoverStarted,
+    /// The EventLog was read (after any incremental cut).
+    Discovered {
+        /// Events to scan and bundle.
+        events: usize,
+        /// Distinct blobs they reference.
+        blob_refs: usize,
+    },
+    /// One more event or blob was scanned for secrets.
+    ScanProgress {
+        /// Events and blobs scanned so far.
+        scanned: usize,
+        /// Events and blobs to scan in all.
+        total: usize,
+    },
+    /// The secret scan finished; any finding means the export is refused.
+    ScanFinished {
+        /// Blocked items found.
+        findings: usize,
+    },
+    /// The refusal report was written to the configured path.
+    RefusalReportWritten,
+    /// The bundle was written to `output_path`.
+    BundleWritten,
+    /// The detached signature was written next to the bundle.
+    Signed,
 }
 
 /// Result of an export attempt.
@@ -114,6 +132,25 @@ pub enum ExportResult {
     Success(ExportSuccess),
     /// Export refused due to detected secrets.
     Refused(RefusalReport),
+    /// Export refused because referenced blobs exceed
+    /// [`ExportConfig::max_blob_size`]; nothing was written.
+    OversizeBlob(OversizeBlobs),
+}
+
+impl ExportResult {
+    /// The successful export, or [`VifeiError::ExportRefused`] for callers
+    /// that treat a refusal as an error.
+    pub fn into_success(self) -> Result<ExportSuccess, VifeiError> {
+        match self {
+            ExportResult::Success(success) => Ok(success),
+            ExportResult::Refused(report) => Err(VifeiError::ExportRefused {
+                blocked_items: report.blocked_items.len(),
+            }),
+            ExportResult::OversizeBlob(oversize) => {
+                Err(io::Error::new(io::ErrorKind::InvalidData, oversize).into())
+            }
+        }
+    }
 }
 
 /// Successful export result.
@@ -127,8 +164,26 @@ pub struct ExportSuccess {
     pub event_count: usize,
     /// Number of blobs in the bundle.
     pub blob_count: usize,
+    /// Number of referenced blobs left out because blobs were excluded.
+    pub blobs_excluded: usize,
+    /// `commit_index` of the reducer checkpoint in the bundle, if included.
+    pub checkpoint_commit_index: Option<u64>,
+    /// Range of the base bundle, for an incremental export.
+    pub base_commit_index_range: Option<[u64; 2]>,
+    /// Informational notes (e.g. why a requested checkpoint was omitted).
+    pub notes: Vec<String>,
+    /// Path of the detached signature, if the bundle was signed.
+    pub signature_path: Option<PathBuf>,
+    /// BLAKE3 fingerprint of the signer public key, if signed.
+    pub signer_fingerprint: Option<String>,
 }
 
+/// `report_version` of refusal reports written by this build.
+pub const REFUSAL_REPORT_VERSION: &str = "refusal-v0.1";
+
+/// `manifest_version` of bundle manifests written by this build.
+pub const MANIFEST_VERSION: &str = "manifest-v0.1";
+
 /// Refusal report when export is blocked due to secrets.
 ///
 /// Schema contract defined in PLANS.md § "Artifact schema contracts".
@@ -152,7 +207,8 @@ impl RefusalReport {
     /// Create a new refusal report from blocked items.
     ///
     /// Items are stably sorted by
-    /// (event_id, field_path, matched_pattern, blob_ref, redacted_match)
+    /// (event_id, field_path, matched_pattern, blob_ref, byte_offset,
+    /// redacted_match)
     /// for deterministic output per M8.3 requirements.
     pub fn new(eventlog_path: &str, mut items: Vec<BlockedItem>) -> Self {
         // Deterministic sort: include blob_ref and redacted_match as tie-breakers
@@ -164,6 +220,7 @@ impl RefusalReport {
                 .then_with(|| a.field_path.cmp(&b.field_path))
                 .then_with(|| a.matched_pattern.cmp(&b.matched_pattern))
                 .then_with(|| a.blob_ref.cmp(&b.blob_ref))
+                .then_with(|| a.byte_offset.cmp(&b.byte_offset))
                 .then_with(|| a.redacted_match.cmp(&b.redacted_match))
         });
 
@@ -179,7 +236,7 @@ impl RefusalReport {
         );
 
         RefusalReport {
-            report_version: "refusal-v0.1".into(),
+            report_version: REFUSAL_REPORT_VERSION.into(),
             eventlog_path: eventlog_path.to_string(),
             blocked_items: items,
             scan_timestamp_utc: format_utc_now(),
@@ -202,7 +259,8 @@ impl RefusalReport {
 
 /// A single blocked item in a refusal report.
 ///
-/// Schema contract: event_id, field_path, matched_pattern, blob_ref (optional).
+/// Schema contract: event_id, field_path, matched_pattern, blob_ref
+/// (optional), byte_offset (optional).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockedItem {
     /// Event ID where the secret was found.
@@ -214,6 +272,10 @@ pub struct BlockedItem {
     /// Blob reference, if the secret was found in a blob rather than inline.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub blob_ref: Option<String>,
+    /// Byte offset of the match from the start of the blob, for blob
+    /// findings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub byte_offset: Option<u64>,
     /// Snippet of the matched content (redacted for safe display).
     pub redacted_match: String,
 }
@@ -229,6 +291,30 @@ pub struct ExportConfig {
     pub refusal_report_path: Option<PathBuf>,
     /// Enable share-safe scanning (mandatory in v0.1).
     pub share_safe: bool,
+    /// Include the latest reducer checkpoint as `checkpoint.json`.
+    pub include_checkpoint: bool,
+    /// Include referenced blob files in the bundle.
+    pub include_blobs: bool,
+    /// Scan blob contents for secrets (independent of `include_blobs`).
+    pub scan_blobs: bool,
+    /// Also refuse on opt-in PII patterns (email addresses).
+    pub scan_pii: bool,
+    /// Hex ed25519 seed file; when set, the bundle is signed.
+    pub signing_key_path: Option<PathBuf>,
+    /// Export the valid prefix of an EventLog that ends with a truncated
+    /// record instead of refusing.
+    pub allow_truncated: bool,
+    /// Rehash every referenced blob before bundling and refuse on a
+    /// mismatch or a missing blob.
+    pub verify_blobs: bool,
+    /// Manifest of a previous bundle of the same EventLog; when set, only
+    /// events after its `commit_index_range` are exported.
+    pub base_manifest: Option<BundleManifest>,
+    /// Largest blob, in uncompressed bytes, the bundle may carry.
+    pub max_blob_size: Option<u64>,
+    /// Leave blobs over `max_blob_size` out of the bundle with a note
+    /// instead of refusing.
+    pub exclude_oversize_blobs: bool,
 }
 
 impl ExportConfig {
@@ -239,6 +325,16 @@ impl ExportConfig {
             output_path: output_path.into(),
             refusal_report_path: None,
             share_safe: true,
+            include_checkpoint: false,
+            include_blobs: true,
+            scan_blobs: true,
+            scan_pii: false,
+            signing_key_path: None,
+            allow_truncated: false,
+            verify_blobs: false,
+            base_manifest: None,
+            max_blob_size: None,
+            exclude_oversize_blobs: false,
         }
     }
 
@@ -247,8 +343,162 @@ impl ExportConfig {
         self.refusal_report_path = Some(path.into());
         self
     }
+
+    /// Include the latest reducer checkpoint in the bundle.
+    pub fn with_checkpoint(mut self) -> Self {
+        self.include_checkpoint = true;
+        self
+    }
+
+    /// Omit blob files from the bundle; the manifest records how many
+    /// referenced blobs were left out.
+    pub fn without_blobs(mut self) -> Self {
+        self.include_blobs = false;
+        self
+    }
+
+    /// Skip secret scanning of blob contents. Event payloads are still
+    /// scanned.
+    pub fn without_blob_scan(mut self) -> Self {
+        self.scan_blobs = false;
+        self
+    }
+
+    /// Refuse on opt-in PII patterns (email addresses) as well as secrets.
+    pub fn with_pii_scan(mut self) -> Self {
+        self.scan_pii = true;
+        self
+    }
+
+    /// Sign the bundle with the ed25519 key at `path`.
+    pub fn with_signing_key(mut self, path: impl Into<PathBuf>) -> Self {
+        self.signing_key_path = Some(path.into());
+        self
+    }
+
+    /// Bundle the valid prefix of an EventLog whose final record was torn
+    /// by a crash; the dropped byte count is recorded in the manifest.
+    pub fn with_truncated_allowed(mut self) -> Self {
+        self.allow_truncated = true;
+        self
+    }
+
+    /// Rehash referenced blobs before bundling; see [`CorruptBlobs`].
+    pub fn with_blob_verification(mut self) -> Self {
+        self.verify_blobs = true;
+        self
+    }
+
+    /// Export only the events appended since the bundle `base` describes
+    /// (see [`verify::read_bundle_manifest`]).
+    pub fn with_base_manifest(mut self, base: BundleManifest) -> Self {
+        self.base_manifest = Some(base);
+        self
+    }
+
+    /// Refuse to bundle a referenced blob larger than `bytes`; see
+    /// [`OversizeBlobs`].
+    pub fn with_max_blob_size(mut self, bytes: u64) -> Self {
+        self.max_blob_size = Some(bytes);
+        self
+    }
+
+    /// Leave blobs over [`max_blob_size`](Self::max_blob_size) out of the
+    /// bundle, noting each one, instead of refusing the export.
+    pub fn with_oversize_blobs_excluded(mut self) -> Self {
+        self.exclude_oversize_blobs = true;
+        self
+    }
+}
+
+/// Error returned by [`run_export`] for an EventLog that ends with a
+/// truncated record when [`ExportConfig::allow_truncated`] is not set.
+///
+/// Carried inside an `io::Error` of kind `InvalidData`; recover it with
+/// `get_ref()` and `downcast_ref`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TruncatedEventLog {
+    /// Length of the partial final record.
+    pub tail_bytes: u64,
+}
+
+impl std::fmt::Display for TruncatedEventLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EventLog ends with a truncated record ({} bytes); refusing to export a partial log",
+            self.tail_bytes
+        )
+    }
+}
+
+impl std::error::Error for TruncatedEventLog {}
+
+/// Error returned by [`run_export`] when [`ExportConfig::verify_blobs`] is
+/// set and a referenced blob is missing or no longer matches its
+/// `payload_ref`.
+///
+/// Carried inside an `io::Error` of kind `InvalidData`, like
+/// [`TruncatedEventLog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptBlobs {
+    /// One error per failing blob, sorted by `payload_ref`.
+    pub errors: Vec<BlobIntegrityError>,
+}
+
+impl std::fmt::Display for CorruptBlobs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} referenced blob(s) failed verification; refusing to export",
+            self.errors.len()
+        )?;
+        if let Some(first) = self.errors.first() {
+            write!(f, " (first: {first})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for CorruptBlobs {}
+
+/// A referenced blob larger than [`ExportConfig::max_blob_size`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OversizeBlob {
+    /// The blob's `payload_ref`.
+    pub payload_ref: String,
+    /// Uncompressed size in bytes.
+    pub size: u64,
+}
+
+/// Referenced blobs over [`ExportConfig::max_blob_size`], returned as
+/// [`ExportResult::OversizeBlob`] unless
+/// [`ExportConfig::exclude_oversize_blobs`] is set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OversizeBlobs {
+    /// The configured limit in bytes.
+    pub max_blob_size: u64,
+    /// The blobs over it, sorted by `payload_ref`.
+    pub blobs: Vec<OversizeBlob>,
+}
+
+impl std::fmt::Display for OversizeBlobs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} referenced blob(s) exceed the {}-byte blob size limit; refusing to export",
+            self.blobs.len(),
+            self.max_blob_size
+        )?;
+        if let Some(first) = self.blobs.first() {
+            write!(f, " (first: {} is {} bytes)", first.payload_ref, first.size)?;
+        }
+        Ok(())
+    }
 }
 
+impl std::error::Error for OversizeBlobs {}
+
 /// Discovered content from an EventLog ready for export.
 #[derive(Debug)]
 pub(crate) struct DiscoveredContent {
@@ -258,6 +508,10 @@ pub(crate) struct DiscoveredContent {
     pub events: Vec<CommittedEvent>,
     /// Set of blob payload_refs referenced by events.
     pub blob_refs: HashSet<String>,
+    /// Length of a truncated final record left out of `events`, if any.
+    pub truncated_tail_bytes: Option<u64>,
+    /// Range of the base bundle when `events` is an incremental delta.
+    pub base_commit_index_range: Option<[u64; 2]>,
 }
 
 impl DiscoveredContent {
@@ -265,6 +519,40 @@ impl DiscoveredContent {
     pub fn event_count(&self) -> usize {
         self.events.len()
     }
+
+    /// Keep only the events after `base`'s last `commit_index`, and the blob
+    /// refs they use.
+    ///
+    /// Fails with `InvalidInput` when `base` has no events or ends past this
+    /// EventLog, i.e. it cannot be a bundle of an earlier state of it.
+    pub fn retain_after(&mut self, base: &BundleManifest) -> io::Result<()> {
+        let Some(range) = base.commit_index_range else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "base bundle has no events; export without a base instead",
+            ));
+        };
+        let last = self.events.last().map(|event| event.commit_index);
+        if last.is_none_or(|last| last < range[1]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "base bundle ends at commit_index {} but the EventLog ends at {}; \
+                     it is not an earlier export of this EventLog",
+                    range[1],
+                    last.map_or_else(|| "no event".to_string(), |last| last.to_string())
+                ),
+            ));
+        }
+        self.events.retain(|event| event.commit_index > range[1]);
+        self.blob_refs = self
+            .events
+            .iter()
+            .filter_map(|event| event.payload_ref.clone())
+            .collect();
+        self.base_commit_index_range = Some(range);
+        Ok(())
+    }
 }
 
 /// Integrity manifest embedded in export bundles (M8.5).
@@ -286,6 +574,34 @@ pub struct BundleManifest {
     pub commit_index_range: Option<[u64; 2]>,
     /// Projection invariants version for context.
     pub projection_invariants_version: String,
+    /// Reducer version of the exporting build, for context. Empty for
+    /// older manifests.
+    #[serde(default)]
+    pub reducer_version: String,
+    /// `commit_index` of `checkpoint.json`, present only when the bundle
+    /// carries a reducer checkpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint_commit_index: Option<u64>,
+    /// Number of referenced blobs deliberately left out of the bundle.
+    /// Nonzero means the bundle is partial; omitted when zero.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub blobs_excluded: usize,
+    /// Whether the bundled EventLog carries a `prev_hash` chain. False for
+    /// EventLogs written before hash chaining and for older manifests.
+    #[serde(default)]
+    pub hash_chained: bool,
+    /// Bytes of a truncated final record left out of `eventlog.jsonl`.
+    /// Present only for bundles exported with `--allow-truncated`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated_tail_bytes: Option<u64>,
+    /// `commit_index_range` of the bundle this one extends. Present only for
+    /// incremental bundles, whose events all follow that range.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_commit_index_range: Option<[u64; 2]>,
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 /// A single file entry in the bundle manifest.
@@ -299,21 +615,108 @@ pub struct ManifestEntry {
     pub size: u64,
 }
 
+/// Rehash every blob `content` references, in `payload_ref` order. With no
+/// blob store every reference is missing.
+fn verify_referenced_blobs(
+    content: &DiscoveredContent,
+    store: Option<&BlobStore>,
+) -> io::Result<Vec<BlobIntegrityError>> {
+    let refs: BTreeSet<&String> = content.blob_refs.iter().collect();
+    let mut errors = Vec::new();
+    for payload_ref in refs {
+        let error = match store {
+            Some(store) => store.verify_one(payload_ref)?,
+            None => Some(BlobIntegrityError::Missing {
+                payload_ref: payload_ref.clone(),
+            }),
+        };
+        errors.extend(error);
+    }
+    Ok(errors)
+}
+
+/// Referenced blobs in `store` larger than `max_blob_size`, in
+/// `payload_ref` order. Missing blobs are not bundled and are skipped.
+fn find_oversize_blobs(
+    content: &DiscoveredContent,
+    store: &BlobStore,
+    max_blob_size: u64,
+) -> io::Result<Vec<OversizeBlob>> {
+    let refs: BTreeSet<&String> = content.blob_refs.iter().collect();
+    let mut oversize = Vec::new();
+    for payload_ref in refs {
+        if !store.has_blob(payload_ref) {
+            continue;
+        }
+        let size = io::copy(&mut store.open_blob(payload_ref)?, &mut io::sink())?;
+        if size > max_blob_size {
+            oversize.push(OversizeBlob {
+                payload_ref: payload_ref.clone(),
+                size,
+            });
+        }
+    }
+    Ok(oversize)
+}
+
 /// Run the full export pipeline.
 ///
 /// This is the main entry point for the export CLI.
-pub fn run_export(config: &ExportConfig) -> io::Result<ExportResult> {
+pub fn run_export(config: &ExportConfig) -> Result<ExportResult, VifeiError> {
+    run_export_with_progress(config, |_| {})
+}
+
+/// [`run_export`] that reports each stage to `progress` as it happens.
+///
+/// The callback sees [`ExportProgress::DiscoverStarted`] first and one
+/// `ScanProgress` per scanned event and blob. It cannot change the outcome:
+/// the bundle, manifest and refusal report are byte-identical to
+/// [`run_export`]'s.
+pub fn run_export_with_progress(
+    config: &ExportConfig,
+    mut progress: impl FnMut(ExportProgress),
+) -> Result<ExportResult, VifeiError> {
     // Validate --share-safe is enabled (mandatory in v0.1)
     if !config.share_safe {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
             "Export requires --share-safe flag in v0.1. \
              Unscanned exports are not supported.",
-        ));
+        )
+        .into());
     }
 
+    // Load the signing key up front so a bad key fails before any output.
+    let signing_key = config
+        .signing_key_path
+        .as_deref()
+        .map(signing::load_signing_key)
+        .transpose()?;
+
     // Stage 1: Discover content
-    let content = discover_content(&config.eventlog_path)?;
+    progress(ExportProgress::DiscoverStarted);
+    let mut content = discover_content(&config.eventlog_path)?;
+    if let Some(tail_bytes) = content.truncated_tail_bytes {
+        if !config.allow_truncated {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                TruncatedEventLog { tail_bytes },
+            )
+            .into());
+        }
+    }
+    // The checkpoint replays the whole log, so it is taken before any
+    // incremental cut; one that falls inside the base is omitted with a note.
+    let checkpoint = config
+        .include_checkpoint
+        .then(|| latest_checkpoint(&content.events));
+    if let Some(base) = &config.base_manifest {
+        content.retain_after(base)?;
+    }
+    progress(ExportProgress::Discovered {
+        events: content.events.len(),
+        blob_refs: content.blob_refs.len(),
+    });
 
     // Try to open blob store (sibling to eventlog)
     let blob_store = config
@@ -322,8 +725,35 @@ pub fn run_export(config: &ExportConfig) -> io::Result<ExportResult> {
         .map(|p| p.join("blobs"))
         .and_then(|p| BlobStore::open(p).ok());
 
+    if config.verify_blobs {
+        let errors = verify_referenced_blobs(&content, blob_store.as_ref())?;
+        if !errors.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, CorruptBlobs { errors }).into());
+        }
+    }
+
+    // Oversize blobs are refused before the scan, so an enormous blob is
+    // not scanned only to be rejected.
+    let mut oversize = Vec::new();
+    if let (Some(max_blob_size), Some(store)) = (
+        config.max_blob_size,
+        blob_store.as_ref().filter(|_| config.include_blobs),
+    ) {
+        oversize = find_oversize_blobs(&content, store, max_blob_size)?;
+        if !oversize.is_empty() && !config.exclude_oversize_blobs {
+            return Ok(ExportResult::OversizeBlob(OversizeBlobs {
+                max_blob_size,
+                blobs: oversize,
+            }));
+        }
+    }
+
     // Stage 2: Scan for secrets
-    let findings = scan_for_secrets(&content, blob_store.as_ref())?;
+    let scan_store = blob_store.as_ref().filter(|_| config.scan_blobs);
+    let findings = scan_for_secrets(&content, scan_store, config.scan_pii, &mut progress)?;
+    progress(ExportProgress::ScanFinished {
+        findings: findings.len(),
+    });
 
     // Stage 3: Decide
     if !findings.is_empty() {
@@ -333,13 +763,45 @@ pub fn run_export(config: &ExportConfig) -> io::Result<ExportResult> {
         // Write refusal report if path configured
         if let Some(ref report_path) = config.refusal_report_path {
             report.write_to(report_path)?;
+            progress(ExportProgress::RefusalReportWritten);
         }
 
+        write_export_safety(
+            &config.eventlog_path,
+            ExportSafetyState::Refused,
+            SCANNER_VERSION,
+        )?;
         return Ok(ExportResult::Refused(report));
     }
 
     // Stage 4: Bundle (clean export)
-    let success = create_bundle(&content, blob_store.as_ref(), &config.output_path)?;
+    let options = BundleOptions {
+        include_blobs: config.include_blobs,
+        oversize: &oversize,
+        checkpoint: checkpoint.as_ref(),
+        ..BundleOptions::new(blob_store.as_ref())
+    };
+    let mut success = create_bundle(&content, &options, &config.output_path)?;
+    progress(ExportProgress::BundleWritten);
+    // An incremental export scanned only the new events, which says
+    // nothing about the rest of the log.
+    if config.base_manifest.is_none() {
+        write_export_safety(
+            &config.eventlog_path,
+            ExportSafetyState::Clean,
+            SCANNER_VERSION,
+        )?;
+    }
+
+    // Stage 5: Sign (optional)
+    if let Some(key) = signing_key {
+        let signature = signing::sign_bundle_hash(&success.bundle_hash, &key);
+        let sig_path = signing::signature_path(&config.output_path);
+        signing::write_signature(&signature, &sig_path)?;
+        success.signature_path = Some(sig_path);
+        success.signer_fingerprint = Some(signature.public_key_fingerprint);
+        progress(ExportProgress::Signed);
+    }
 
     Ok(ExportResult::Success(success))
 }
@@ -371,6 +833,7 @@ mod tests {
             payload: EventPayload::ToolCall {
                 tool: "test".into(),
                 args: Some(args.into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -593,6 +1056,7 @@ mod tests {
             field_path: "payload.args".into(),
             matched_pattern: "aws_access_key".into(),
             blob_ref: None,
+            byte_offset: None,
             redacted_match: "AKIA***MPLE".into(),
         };
         let report = RefusalReport::new("/tmp/test.jsonl", vec![item]);
@@ -677,6 +1141,7 @@ mod tests {
                 field_path: "payload".into(),
                 matched_pattern: "password".into(),
                 blob_ref: None,
+                byte_offset: None,
                 redacted_match: "pass***rd12".into(),
             },
             BlockedItem {
@@ -684,6 +1149,7 @@ mod tests {
                 field_path: "payload".into(),
                 matched_pattern: "aws_access_key".into(),
                 blob_ref: None,
+                byte_offset: None,
                 redacted_match: "AKIA***MPLE".into(),
             },
             BlockedItem {
@@ -691,6 +1157,7 @@ mod tests {
                 field_path: "payload".into(),
                 matched_pattern: "bearer_token".into(),
                 blob_ref: None,
+                byte_offset: None,
                 redacted_match: "Bear***en12".into(),
             },
         ];
@@ -712,6 +1179,7 @@ mod tests {
             field_path: "content".into(),
             matched_pattern: "private_key".into(),
             blob_ref: Some("abc123".into()),
+            byte_offset: None,
             redacted_match: "----***Y---".into(),
         }];
         let report = RefusalReport::new("/tmp/test.jsonl", items);
@@ -727,6 +1195,7 @@ mod tests {
             field_path: "payload".into(),
             matched_pattern: "password".into(),
             blob_ref: None,
+            byte_offset: None,
             redacted_match: "pass***rd12".into(),
         }];
         let report = RefusalReport::new("/tmp/test.jsonl", items);
@@ -744,6 +1213,7 @@ mod tests {
                 field_path: "content".into(),
                 matched_pattern: "private_key".into(),
                 blob_ref: Some("z-blob".into()),
+                byte_offset: None,
                 redacted_match: "----***z---".into(),
             },
             BlockedItem {
@@ -751,6 +1221,7 @@ mod tests {
                 field_path: "content".into(),
                 matched_pattern: "private_key".into(),
                 blob_ref: Some("a-blob".into()),
+                byte_offset: None,
                 redacted_match: "----***a---".into(),
             },
         ];
@@ -775,7 +1246,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        let result = create_bundle(&content, None, &bundle_path).unwrap();
+        let result = create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         assert!(bundle_path.exists());
         assert_eq!(result.event_count, 1);
@@ -813,8 +1284,8 @@ mod tests {
         // Create bundle twice
         let bundle1_path = dir.path().join("bundle1.tar.zst");
         let bundle2_path = dir.path().join("bundle2.tar.zst");
-        let result1 = create_bundle(&content, None, &bundle1_path).unwrap();
-        let result2 = create_bundle(&content, None, &bundle2_path).unwrap();
+        let result1 = create_bundle(&content, &BundleOptions::new(None), &bundle1_path).unwrap();
+        let result2 = create_bundle(&content, &BundleOptions::new(None), &bundle2_path).unwrap();
 
         // Same inputs must produce identical bytes
         let bytes1 = std::fs::read(&bundle1_path).unwrap();
@@ -826,6 +1297,51 @@ mod tests {
         assert_eq!(result1.bundle_hash, result2.bundle_hash);
     }
 
+    #[test]
+    fn bundle_omits_stale_checkpoint_with_note() {
+        let dir = tempdir().unwrap();
+        let eventlog_path = dir.path().join("eventlog.jsonl");
+
+        let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+        writer
+            .append(make_event("e1", 1_000_000_000, "checkpoint"))
+            .unwrap();
+        drop(writer);
+
+        let content = discover_content(&eventlog_path).unwrap();
+        let (state, _) = vifei_core::reducer::replay(&content.events);
+        let mut stale = vifei_core::reducer::create_checkpoint(&state);
+        stale.reducer_version = "reducer-v0.0".to_string();
+
+        let bundle_path = dir.path().join("bundle.tar.zst");
+        let result = create_bundle(
+            &content,
+            &BundleOptions {
+                checkpoint: Some(&Ok(stale)),
+                ..BundleOptions::new(None)
+            },
+            &bundle_path,
+        )
+        .unwrap();
+        assert_eq!(result.checkpoint_commit_index, None);
+        assert_eq!(result.notes.len(), 1);
+        assert!(result.notes[0].contains("reducer_version reducer-v0.0"));
+
+        // A current checkpoint for the same content is accepted.
+        let current = vifei_core::reducer::create_checkpoint(&state);
+        let result = create_bundle(
+            &content,
+            &BundleOptions {
+                checkpoint: Some(&Ok(current)),
+                ..BundleOptions::new(None)
+            },
+            &bundle_path,
+        )
+        .unwrap();
+        assert_eq!(result.checkpoint_commit_index, Some(0));
+        assert!(result.notes.is_empty());
+    }
+
     #[test]
     fn bundle_metadata_normalized() {
         let dir = tempdir().unwrap();
@@ -839,7 +1355,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         // Decompress and verify metadata
         let compressed = std::fs::read(&bundle_path).unwrap();
@@ -889,7 +1405,12 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, Some(&blob_store), &bundle_path).unwrap();
+        create_bundle(
+            &content,
+            &BundleOptions::new(Some(&blob_store)),
+            &bundle_path,
+        )
+        .unwrap();
 
         // Verify entry ordering
         let compressed = std::fs::read(&bundle_path).unwrap();
@@ -928,7 +1449,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        let result = create_bundle(&content, None, &bundle_path).unwrap();
+        let result = create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         // Independently hash the file bytes
         let file_bytes = std::fs::read(&bundle_path).unwrap();
@@ -951,7 +1472,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         // Extract manifest.json from the bundle
         let compressed = std::fs::read(&bundle_path).unwrap();
@@ -989,7 +1510,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         // Extract and verify manifest
         let compressed = std::fs::read(&bundle_path).unwrap();
@@ -1035,7 +1556,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         // Extract manifest and check commit_index_range
         let compressed = std::fs::read(&bundle_path).unwrap();
@@ -1073,10 +1594,12 @@ mod tests {
                 CommittedEvent::commit(make_event("e-mid", 2_000_000_000, "c"), 15),
             ],
             blob_refs: HashSet::new(),
+            truncated_tail_bytes: None,
+            base_commit_index_range: None,
         };
 
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         let compressed = std::fs::read(&bundle_path).unwrap();
         let decompressed = zstd::decode_all(compressed.as_slice()).unwrap();
@@ -1112,7 +1635,7 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, None, &bundle_path).unwrap();
+        create_bundle(&content, &BundleOptions::new(None), &bundle_path).unwrap();
 
         let compressed = std::fs::read(&bundle_path).unwrap();
         let decompressed = zstd::decode_all(compressed.as_slice()).unwrap();
@@ -1129,6 +1652,7 @@ mod tests {
                     manifest.projection_invariants_version,
                     PROJECTION_INVARIANTS_VERSION
                 );
+                assert_eq!(manifest.reducer_version, "reducer-v0.9");
                 break;
             }
         }
@@ -1155,7 +1679,12 @@ mod tests {
 
         let content = discover_content(&eventlog_path).unwrap();
         let bundle_path = dir.path().join("bundle.tar.zst");
-        create_bundle(&content, Some(&blob_store), &bundle_path).unwrap();
+        create_bundle(
+            &content,
+            &BundleOptions::new(Some(&blob_store)),
+            &bundle_path,
+        )
+        .unwrap();
 
         let compressed = std::fs::read(&bundle_path).unwrap();
         let decompressed = zstd::decode_all(compressed.as_slice()).unwrap();
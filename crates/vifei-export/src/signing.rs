@@ -0,0 +1,236 @@
+//! Detached ed25519 signatures for export bundles.
+//!
+//! # Overview
+//!
+//! `bundle_hash` proves integrity (the bytes did not change) but not
+//! authenticity (who produced them). When [`ExportConfig`] carries a signing
+//! key, export signs the bundle hash and writes a detached signature file
+//! next to the bundle (`bundle.tar.zst.sig`). Unsigned exports are unchanged.
+//!
+//! # Signed message
+//!
+//! The signature covers `"vifei-bundle-sig-v0.1:" + bundle_hash`, where
+//! `bundle_hash` is the BLAKE3 hex digest of the bundle bytes. Verifiers
+//! always recompute the hash from the bundle; the hash recorded in the
+//! signature file is informational, so editing a bundle and rewriting that
+//! field does not produce a valid signature.
+//!
+//! # Key files
+//!
+//! Keys are plain text files holding 64 hex characters: the 32-byte ed25519
+//! secret seed for signing, or the 32-byte public key for verification.
+//! Surrounding whitespace is ignored.
+//!
+//! [`ExportConfig`]: crate::ExportConfig
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Signature file format version.
+pub const SIGNATURE_VERSION: &str = "bundle-sig-v0.1";
+
+/// Signature algorithm identifier recorded in the signature file.
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+/// Domain-separation prefix of the signed message.
+const SIGNED_MESSAGE_PREFIX: &str = "vifei-bundle-sig-v0.1:";
+
+/// Detached signature file contents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleSignature {
+    /// Signature format version ([`SIGNATURE_VERSION`]).
+    pub signature_version: String,
+    /// Signature algorithm ([`SIGNATURE_ALGORITHM`]).
+    pub algorithm: String,
+    /// BLAKE3 hex digest of the bundle at signing time (informational).
+    pub bundle_hash: String,
+    /// Signer public key, lowercase hex.
+    pub public_key: String,
+    /// BLAKE3 hex digest of the signer public key bytes.
+    pub public_key_fingerprint: String,
+    /// ed25519 signature over the signed message, lowercase hex.
+    pub signature: String,
+}
+
+/// Path of the detached signature for `bundle_path` (`<bundle>.sig`).
+pub fn signature_path(bundle_path: &Path) -> PathBuf {
+    let mut path = bundle_path.as_os_str().to_owned();
+    path.push(".sig");
+    PathBuf::from(path)
+}
+
+/// Load an ed25519 signing key from a hex seed file.
+pub fn load_signing_key(path: &Path) -> io::Result<SigningKey> {
+    let bytes = read_hex_key(path, "signing key")?;
+    Ok(SigningKey::from_bytes(&bytes))
+}
+
+/// Load an ed25519 public key from a hex file.
+pub fn load_verifying_key(path: &Path) -> io::Result<VerifyingKey> {
+    let bytes = read_hex_key(path, "public key")?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid public key {}: {e}", path.display()),
+        )
+    })
+}
+
+/// BLAKE3 hex fingerprint of a public key.
+pub fn key_fingerprint(key: &VerifyingKey) -> String {
+    blake3::hash(key.as_bytes()).to_hex().to_string()
+}
+
+/// Sign `bundle_hash` with `key`.
+pub fn sign_bundle_hash(bundle_hash: &str, key: &SigningKey) -> BundleSignature {
+    let verifying_key = key.verifying_key();
+    let signature = key.sign(signed_message(bundle_hash).as_bytes());
+    BundleSignature {
+        signature_version: SIGNATURE_VERSION.to_string(),
+        algorithm: SIGNATURE_ALGORITHM.to_string(),
+        bundle_hash: bundle_hash.to_string(),
+        public_key: to_hex(verifying_key.as_bytes()),
+        public_key_fingerprint: key_fingerprint(&verifying_key),
+        signature: to_hex(&signature.to_bytes()),
+    }
+}
+
+/// Check `signature` against the recomputed `bundle_hash` and `key`.
+///
+/// Returns a human-readable reason on failure.
+pub fn verify_bundle_hash(
+    signature: &BundleSignature,
+    bundle_hash: &str,
+    key: &VerifyingKey,
+) -> Result<(), String> {
+    if signature.algorithm != SIGNATURE_ALGORITHM {
+        return Err(format!(
+            "unsupported signature algorithm {:?}",
+            signature.algorithm
+        ));
+    }
+    let bytes: [u8; 64] = parse_hex(&signature.signature)
+        .ok_or_else(|| "signature is not 128 hex characters".to_string())?;
+    let parsed = Signature::from_bytes(&bytes);
+    key.verify(signed_message(bundle_hash).as_bytes(), &parsed)
+        .map_err(|_| {
+            format!(
+                "signature does not match bundle_hash {bundle_hash} for public key {}",
+                key_fingerprint(key)
+            )
+        })
+}
+
+/// Write `signature` as pretty JSON to `path`.
+pub fn write_signature(signature: &BundleSignature, path: &Path) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(signature).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("signature serialization: {e}"),
+        )
+    })?;
+    std::fs::write(path, json + "\n")
+}
+
+/// Read a signature file written by [`write_signature`].
+pub fn read_signature(path: &Path) -> io::Result<BundleSignature> {
+    let text = std::fs::read_to_string(path)?;
+    serde_json::from_str(&text).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid signature file {}: {e}", path.display()),
+        )
+    })
+}
+
+fn signed_message(bundle_hash: &str) -> String {
+    format!("{SIGNED_MESSAGE_PREFIX}{bundle_hash}")
+}
+
+fn read_hex_key(path: &Path, label: &str) -> io::Result<[u8; 32]> {
+    let text = std::fs::read_to_string(path)?;
+    parse_hex(text.trim()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "invalid {label} {}: expected 64 hex characters",
+                path.display()
+            ),
+        )
+    })
+}
+
+fn parse_hex<const N: usize>(text: &str) -> Option<[u8; N]> {
+    if text.len() != N * 2 || !text.is_ascii() {
+        return None;
+    }
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&text[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(out)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> SigningKey {
+        SigningKey::from_bytes(&[7u8; 32])
+    }
+
+    #[test]
+    fn sign_then_verify_round_trips() {
+        let key = test_key();
+        let signature = sign_bundle_hash("abc123", &key);
+        assert_eq!(signature.signature_version, SIGNATURE_VERSION);
+        assert_eq!(
+            signature.public_key_fingerprint,
+            key_fingerprint(&key.verifying_key())
+        );
+        assert!(verify_bundle_hash(&signature, "abc123", &key.verifying_key()).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_other_hash_and_other_key() {
+        let key = test_key();
+        let signature = sign_bundle_hash("abc123", &key);
+        assert!(verify_bundle_hash(&signature, "abc124", &key.verifying_key()).is_err());
+
+        let other = SigningKey::from_bytes(&[9u8; 32]);
+        assert!(verify_bundle_hash(&signature, "abc123", &other.verifying_key()).is_err());
+    }
+
+    #[test]
+    fn signing_is_deterministic() {
+        let key = test_key();
+        assert_eq!(sign_bundle_hash("h", &key), sign_bundle_hash("h", &key));
+    }
+
+    #[test]
+    fn key_files_parse_hex_and_reject_garbage() {
+        let dir = tempfile::tempdir().unwrap();
+        let good = dir.path().join("signing.key");
+        std::fs::write(&good, format!("{}\n", to_hex(&[7u8; 32]))).unwrap();
+        assert_eq!(load_signing_key(&good).unwrap().to_bytes(), [7u8; 32]);
+
+        let bad = dir.path().join("bad.key");
+        std::fs::write(&bad, "not-hex").unwrap();
+        let err = load_signing_key(&bad).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn signature_path_appends_sig_suffix() {
+        assert_eq!(
+            signature_path(Path::new("out/bundle.tar.zst")),
+            PathBuf::from("out/bundle.tar.zst.sig")
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Bundle verification: manifest integrity and optional signature check.
+//!
+//! [`verify_bundle`] unpacks a bundle, re-hashes every file against
+//! `manifest.json`, and, when a public key is given, checks the detached
+//! signature (see [`crate::signing`]) against the recomputed `bundle_hash`.
+//! [`read_bundle_manifest`] reads just the manifest, and
+//! [`read_bundle_eventlog`] the committed events of a bundle whose files
+//! match it.
+
+use crate::signing::{
+    key_fingerprint, load_verifying_key, read_signature, signature_path, verify_bundle_hash,
+};
+use crate::BundleManifest;
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+use std::path::Path;
+use vifei_core::event::CommittedEvent;
+
+/// Outcome of [`verify_bundle`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleVerification {
+    /// BLAKE3 hex digest recomputed from the bundle bytes.
+    pub bundle_hash: String,
+    /// Number of manifest entries checked.
+    pub files_checked: usize,
+    /// Fingerprint of the public key whose signature verified, if checked.
+    pub signer_fingerprint: Option<String>,
+    /// Human-readable failures, in deterministic order. Empty means valid.
+    pub failures: Vec<String>,
+}
+
+impl BundleVerification {
+    /// True when no check failed.
+    pub fn is_valid(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Verify `bundle_path` against its manifest, and its signature when
+/// `public_key_path` is given.
+///
+/// Unreadable bundles and key files are errors; content mismatches are
+/// reported in [`BundleVerification::failures`].
+pub fn verify_bundle(
+    bundle_path: &Path,
+    public_key_path: Option<&Path>,
+) -> io::Result<BundleVerification> {
+    let bundle_bytes = std::fs::read(bundle_path)?;
+    let bundle_hash = blake3::hash(&bundle_bytes).to_hex().to_string();
+    let mut failures = Vec::new();
+
+    let entries = unpack_entries(&bundle_bytes)?;
+    let files_checked = match entries.get("manifest.json") {
+        None => {
+            failures.push("manifest.json missing from bundle".to_string());
+            0
+        }
+        Some(bytes) => match serde_json::from_slice::<BundleManifest>(bytes) {
+            Ok(manifest) => {
+                check_manifest(&manifest, &entries, &mut failures);
+                manifest.files.len()
+            }
+            Err(e) => {
+                failures.push(format!("manifest.json is invalid: {e}"));
+                0
+            }
+        },
+    };
+
+    let mut signer_fingerprint = None;
+    if let Some(key_path) = public_key_path {
+        let key = load_verifying_key(key_path)?;
+        let sig_path = signature_path(bundle_path);
+        if !sig_path.exists() {
+            failures.push(format!("signature file {} not found", sig_path.display()));
+        } else {
+            let signature = read_signature(&sig_path)?;
+            match verify_bundle_hash(&signature, &bundle_hash, &key) {
+                Ok(()) => signer_fingerprint = Some(key_fingerprint(&key)),
+                Err(reason) => failures.push(reason),
+            }
+        }
+    }
+
+    Ok(BundleVerification {
+        bundle_hash,
+        files_checked,
+        signer_fingerprint,
+        failures,
+    })
+}
+
+/// Read `manifest.json` out of the bundle at `bundle_path`, e.g. as the base
+/// of an incremental export. File hashes are not checked; use
+/// [`verify_bundle`] for that.
+pub fn read_bundle_manifest(bundle_path: &Path) -> io::Result<BundleManifest> {
+    let entries = unpack_entries(&std::fs::read(bundle_path)?)?;
+    let bytes = entries.get("manifest.json").ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "manifest.json missing from bundle",
+        )
+    })?;
+    serde_json::from_slice(bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("manifest.json is invalid: {e}"),
+        )
+    })
+}
+
+/// Read the committed events of the bundle's `eventlog.jsonl`.
+///
+/// Fails with `InvalidData` unless every bundle file matches
+/// `manifest.json` (the checks of [`verify_bundle`], without a signature),
+/// or when an event line does not parse. An incremental bundle holds only
+/// the events after its base.
+pub fn read_bundle_eventlog(bundle_path: &Path) -> io::Result<Vec<CommittedEvent>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let entries = unpack_entries(&std::fs::read(bundle_path)?)?;
+    let manifest_bytes = entries
+        .get("manifest.json")
+        .ok_or_else(|| invalid("manifest.json missing from bundle".to_string()))?;
+    let manifest: BundleManifest = serde_json::from_slice(manifest_bytes)
+        .map_err(|e| invalid(format!("manifest.json is invalid: {e}")))?;
+    let mut failures = Vec::new();
+    check_manifest(&manifest, &entries, &mut failures);
+    if !failures.is_empty() {
+        return Err(invalid(format!(
+            "bundle does not match its manifest: {}",
+            failures.join("; ")
+        )));
+    }
+
+    let eventlog = entries
+        .get("eventlog.jsonl")
+        .ok_or_else(|| invalid("eventlog.jsonl missing from bundle".to_string()))?;
+    eventlog
+        .split(|&b| b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim_ascii().is_empty())
+        .map(|(i, line)| {
+            CommittedEvent::from_jsonl_line(line.trim_ascii())
+                .map(|(event, _)| event)
+                .map_err(|e| invalid(format!("eventlog.jsonl line {}: {e}", i + 1)))
+        })
+        .collect()
+}
+
+fn check_manifest(
+    manifest: &BundleManifest,
+    entries: &BTreeMap<String, Vec<u8>>,
+    failures: &mut Vec<String>,
+) {
+    for file in &manifest.files {
+        match entries.get(&file.path) {
+            None => failures.push(format!("{} listed in manifest but missing", file.path)),
+            Some(data) => {
+                let actual = blake3::hash(data).to_hex().to_string();
+                if actual != file.blake3 || data.len() as u64 != file.size {
+                    failures.push(format!(
+                        "{} does not match manifest (blake3 {actual}, size {})",
+                        file.path,
+                        data.len()
+                    ));
+                }
+            }
+        }
+    }
+    for path in entries.keys() {
+        if path != "manifest.json" && !manifest.files.iter().any(|f| &f.path == path) {
+            failures.push(format!("{path} present in bundle but not in manifest"));
+        }
+    }
+}
+
+fn unpack_entries(bundle_bytes: &[u8]) -> io::Result<BTreeMap<String, Vec<u8>>> {
+    let decompressed = zstd::decode_all(bundle_bytes)?;
+    let mut archive = tar::Archive::new(decompressed.as_slice());
+    let mut entries = BTreeMap::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().to_string();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+        entries.insert(path, data);
+    }
+    Ok(entries)
+}
@@ -11,9 +11,14 @@
 use std::collections::HashMap;
 use tempfile::tempdir;
 use vifei_core::blob_store::BlobStore;
+use vifei_core::error::VifeiError;
 use vifei_core::event::{EventPayload, ImportEvent, Tier};
-use vifei_core::eventlog::EventLogWriter;
-use vifei_export::{BundleManifest, ExportConfig, ExportResult, ExportSuccess, RefusalReport};
+use vifei_core::eventlog::{DurabilityPolicy, EventLogWriter};
+use vifei_export::redact::RedactConfig;
+use vifei_export::{
+    BundleManifest, CorruptBlobs, ExportConfig, ExportProgress, ExportResult, ExportSuccess,
+    OversizeBlob, OversizeBlobs, RefusalReport, TruncatedEventLog,
+};
 
 /// Create a clean event (no secrets).
 fn clean_event(id: &str, ts: u64, args: &str) -> ImportEvent {
@@ -27,6 +32,7 @@ fn clean_event(id: &str, ts: u64, args: &str) -> ImportEvent {
         payload: EventPayload::ToolCall {
             tool: "test_tool".into(),
             args: Some(args.into()),
+            invocation_id: None,
         },
         payload_ref: None,
         synthesized: false,
@@ -45,6 +51,7 @@ fn secret_event(id: &str, ts: u64, secret: &str) -> ImportEvent {
         payload: EventPayload::ToolCall {
             tool: "leaked".into(),
             args: Some(secret.into()),
+            invocation_id: None,
         },
         payload_ref: None,
         synthesized: false,
@@ -748,6 +755,1127 @@ fn export_empty_eventlog_deterministic() {
 fn run_export_success(config: &ExportConfig) -> Option<ExportSuccess> {
     match vifei_export::run_export(config).unwrap() {
         ExportResult::Success(s) => Some(s),
-        ExportResult::Refused(_) => None,
+        ExportResult::Refused(_) | ExportResult::OversizeBlob(_) => None,
     }
 }
+
+// ---- Reducer checkpoint in bundle ----
+
+/// Write an EventLog just past one checkpoint interval, using Tier B events
+/// written directly as committed lines to keep the test fast.
+fn write_checkpoint_sized_eventlog(dir: &std::path::Path) -> std::path::PathBuf {
+    use std::io::Write;
+    use vifei_core::event::CommittedEvent;
+    use vifei_core::reducer::CHECKPOINT_INTERVAL;
+
+    let eventlog_path = dir.join("eventlog.jsonl");
+    let mut file = std::io::BufWriter::new(std::fs::File::create(&eventlog_path).unwrap());
+    for idx in 0..CHECKPOINT_INTERVAL + 3 {
+        let mut data = std::collections::BTreeMap::new();
+        data.insert("n".to_string(), idx.to_string());
+        let event = CommittedEvent::commit(
+            ImportEvent {
+                run_id: "test-run".into(),
+                event_id: format!("e{idx}"),
+                source_id: "test".into(),
+                source_seq: Some(idx),
+                timestamp_ns: 1_000_000_000 + idx,
+                tier: Tier::B,
+                payload: EventPayload::Generic {
+                    event_type: "tick".into(),
+                    data,
+                },
+                payload_ref: None,
+                synthesized: false,
+            },
+            idx,
+        );
+        writeln!(file, "{}", serde_json::to_string(&event).unwrap()).unwrap();
+    }
+    eventlog_path
+}
+
+#[test]
+fn checkpoint_included_in_manifest_and_matches_replay() {
+    use vifei_core::eventlog::read_eventlog;
+    use vifei_core::reducer::{load_checkpoint, replay, state_hash, CHECKPOINT_INTERVAL};
+
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_checkpoint_sized_eventlog(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+
+    let config = ExportConfig::new(&eventlog_path, &bundle_path).with_checkpoint();
+    let success = run_export_success(&config).expect("expected success export");
+    assert_eq!(
+        success.checkpoint_commit_index,
+        Some(CHECKPOINT_INTERVAL - 1)
+    );
+    assert!(success.notes.is_empty());
+
+    let entries = extract_bundle(&bundle_path);
+    let checkpoint_bytes = entries.get("checkpoint.json").expect("checkpoint.json");
+    let manifest = extract_manifest(&bundle_path);
+    assert_eq!(
+        manifest.checkpoint_commit_index,
+        Some(CHECKPOINT_INTERVAL - 1)
+    );
+    let entry = manifest
+        .files
+        .iter()
+        .find(|f| f.path == "checkpoint.json")
+        .expect("checkpoint.json manifest entry");
+    assert_eq!(
+        entry.blake3,
+        blake3::hash(checkpoint_bytes).to_hex().to_string()
+    );
+    assert_eq!(entry.size, checkpoint_bytes.len() as u64);
+
+    let checkpoint = load_checkpoint(checkpoint_bytes).expect("checkpoint loads");
+    let events = read_eventlog(&eventlog_path).unwrap();
+    let prefix: Vec<_> = events
+        .iter()
+        .filter(|e| e.commit_index <= checkpoint.commit_index)
+        .cloned()
+        .collect();
+    let (replayed, _) = replay(&prefix);
+    assert_eq!(state_hash(&checkpoint.state), state_hash(&replayed));
+
+    // Determinism: same inputs, same bundle bytes.
+    let bundle2_path = dir.path().join("bundle2.tar.zst");
+    let config2 = ExportConfig::new(&eventlog_path, &bundle2_path).with_checkpoint();
+    let success2 = run_export_success(&config2).expect("expected success export");
+    assert_eq!(success.bundle_hash, success2.bundle_hash);
+}
+
+#[test]
+fn checkpoint_omitted_with_note_for_short_eventlog() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_clean_fixture(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+
+    let config = ExportConfig::new(&eventlog_path, &bundle_path).with_checkpoint();
+    let success = run_export_success(&config).expect("expected success export");
+    assert_eq!(success.checkpoint_commit_index, None);
+    assert_eq!(success.notes.len(), 1);
+    assert!(success.notes[0].contains("checkpoint omitted"));
+    assert!(!extract_entry_paths(&bundle_path).contains(&"checkpoint.json".to_string()));
+    assert_eq!(extract_manifest(&bundle_path).checkpoint_commit_index, None);
+}
+
+// ---- Import-time payload offload → export ----
+
+/// Import a cassette whose tool result exceeds the default offload threshold,
+/// offloading it to the sibling blob store, and append it to an EventLog.
+fn import_large_result_cassette(dir: &std::path::Path, result: &str) -> std::path::PathBuf {
+    use vifei_import::offload::{offload_large_payloads, DEFAULT_OFFLOAD_THRESHOLD_BYTES};
+
+    let records = [
+        serde_json::json!({"type": "session_start", "session_id": "big-1", "timestamp": "2026-01-01T00:00:00Z", "agent": "test"}),
+        serde_json::json!({"type": "tool_result", "session_id": "big-1", "timestamp": "2026-01-01T00:00:01Z", "tool": "Read", "id": "t1", "result": result}),
+        serde_json::json!({"type": "session_end", "session_id": "big-1", "timestamp": "2026-01-01T00:00:02Z"}),
+    ];
+    let cassette: String = records.iter().map(|r| format!("{r}\n")).collect();
+
+    let mut events = vifei_import::cassette::parse_cassette(cassette.as_bytes());
+    let store = BlobStore::open(dir.join("blobs")).unwrap();
+    let offloaded =
+        offload_large_payloads(&mut events, &store, DEFAULT_OFFLOAD_THRESHOLD_BYTES).unwrap();
+    assert_eq!(offloaded, 1);
+
+    let eventlog_path = dir.join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    for event in events {
+        writer.append(event).unwrap();
+    }
+    eventlog_path
+}
+
+#[test]
+fn offloaded_import_payload_exports_as_bundle_blob() {
+    let dir = tempdir().unwrap();
+    let large_result = "line of tool output\n".repeat(5_000);
+    let eventlog_path = import_large_result_cassette(dir.path(), &large_result);
+
+    let eventlog_bytes = std::fs::read(&eventlog_path).unwrap();
+    assert!(
+        eventlog_bytes.len() < large_result.len() / 10,
+        "offloaded payload must not stay inline"
+    );
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path);
+    let result = run_export_success(&config).expect("expected success export");
+    assert_eq!(result.blob_count, 1);
+
+    let payload_ref = BlobStore::compute_ref(large_result.as_bytes());
+    let entries = extract_bundle(&bundle_path);
+    let blob = entries
+        .iter()
+        .find(|(path, _)| path.ends_with(&payload_ref))
+        .map(|(_, bytes)| bytes)
+        .expect("offloaded blob missing from bundle");
+    assert_eq!(blob.as_slice(), large_result.as_bytes());
+}
+
+#[test]
+fn secret_in_offloaded_payload_is_refused_with_blob_ref() {
+    let dir = tempdir().unwrap();
+    // Place the secret past the inline preview so only the blob contains it.
+    let large_result = format!(
+        "{}my key is {}\n",
+        "padding line\n".repeat(6_000),
+        sample_aws_access_key()
+    );
+    let eventlog_path = import_large_result_cassette(dir.path(), &large_result);
+    assert!(!std::fs::read_to_string(&eventlog_path)
+        .unwrap()
+        .contains(&sample_aws_access_key()));
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path);
+    let ExportResult::Refused(report) = vifei_export::run_export(&config).unwrap() else {
+        panic!("expected export to be refused");
+    };
+
+    let payload_ref = BlobStore::compute_ref(large_result.as_bytes());
+    assert!(report
+        .blocked_items
+        .iter()
+        .any(|item| item.blob_ref.as_deref() == Some(payload_ref.as_str())));
+    assert!(!bundle_path.exists());
+}
+
+// ---- Blob exclusion ----
+
+#[test]
+fn excluded_blobs_bundle_has_only_eventlog_and_manifest() {
+    let dir = tempdir().unwrap();
+    let (eventlog_path, _store) = write_clean_fixture_with_blobs(dir.path());
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path).without_blobs();
+    let result = run_export_success(&config).expect("expected success export");
+
+    assert_eq!(result.blob_count, 0);
+    assert_eq!(result.blobs_excluded, 2);
+    assert_eq!(
+        extract_entry_paths(&bundle_path),
+        vec!["eventlog.jsonl".to_string(), "manifest.json".to_string()]
+    );
+
+    let manifest = extract_manifest(&bundle_path);
+    assert_eq!(manifest.blobs_excluded, 2);
+    let paths: Vec<_> = manifest.files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["eventlog.jsonl"]);
+}
+
+#[test]
+fn full_bundle_manifest_omits_blobs_excluded() {
+    let dir = tempdir().unwrap();
+    let (eventlog_path, _store) = write_clean_fixture_with_blobs(dir.path());
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path);
+    run_export_success(&config).expect("expected success export");
+
+    let entries = extract_bundle(&bundle_path);
+    let manifest_json = String::from_utf8(entries["manifest.json"].clone()).unwrap();
+    assert!(!manifest_json.contains("blobs_excluded"));
+}
+
+#[test]
+fn manifest_records_whether_eventlog_is_hash_chained() {
+    let dir = tempdir().unwrap();
+    let chained_path = write_clean_fixture(dir.path());
+    let chained_bundle = dir.path().join("chained.tar.zst");
+    run_export_success(&ExportConfig::new(&chained_path, &chained_bundle))
+        .expect("expected success export");
+    assert!(extract_manifest(&chained_bundle).hash_chained);
+
+    // Strip prev_hash to mimic an EventLog written before hash chaining.
+    let legacy_path = dir.path().join("legacy.jsonl");
+    let legacy: String = std::fs::read_to_string(&chained_path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let mut value: serde_json::Value = serde_json::from_str(line).unwrap();
+            value.as_object_mut().unwrap().remove("prev_hash");
+            format!("{value}\n")
+        })
+        .collect();
+    std::fs::write(&legacy_path, legacy).unwrap();
+    let legacy_bundle = dir.path().join("legacy.tar.zst");
+    run_export_success(&ExportConfig::new(&legacy_path, &legacy_bundle))
+        .expect("expected success export");
+    assert!(!extract_manifest(&legacy_bundle).hash_chained);
+}
+
+#[test]
+fn segmented_eventlog_exports_as_one_eventlog_entry() {
+    let dir = tempdir().unwrap();
+    let single_path = write_clean_fixture(dir.path());
+    let segmented_path = dir.path().join("segmented");
+    let mut writer =
+        EventLogWriter::open_segmented(&segmented_path, 1, DurabilityPolicy::OnDrop).unwrap();
+    writer
+        .append(clean_event("e1", 1_000_000_000, "hello world"))
+        .unwrap();
+    writer
+        .append(clean_event("e2", 2_000_000_000, "testing export"))
+        .unwrap();
+    writer
+        .append(clean_event("e3", 3_000_000_000, "determinism check"))
+        .unwrap();
+    drop(writer);
+    assert!(segmented_path.join("eventlog.00003.jsonl").exists());
+
+    let single_bundle = dir.path().join("single.tar.zst");
+    let segmented_bundle = dir.path().join("segmented.tar.zst");
+    run_export_success(&ExportConfig::new(&single_path, &single_bundle))
+        .expect("expected success export");
+    run_export_success(&ExportConfig::new(&segmented_path, &segmented_bundle))
+        .expect("expected success export");
+
+    assert_eq!(
+        extract_bundle(&segmented_bundle)["eventlog.jsonl"],
+        extract_bundle(&single_bundle)["eventlog.jsonl"]
+    );
+    assert!(extract_manifest(&segmented_bundle).hash_chained);
+}
+
+#[test]
+fn truncated_eventlog_is_refused_unless_allowed() {
+    let dir = tempdir().unwrap();
+    let path = write_clean_fixture(dir.path());
+    let clean = std::fs::read(&path).unwrap();
+    // Half-written final record, as left by a crash mid-append.
+    let mut torn = clean.clone();
+    torn.extend_from_slice(br#"{"run_id":"test-run","event_id":"e4","sour"#);
+    std::fs::write(&path, &torn).unwrap();
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    let VifeiError::Io(err) =
+        vifei_export::run_export(&ExportConfig::new(&path, &bundle)).unwrap_err()
+    else {
+        panic!("truncation is an I/O error");
+    };
+    let truncated = err
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<TruncatedEventLog>())
+        .expect("truncation error");
+    assert_eq!(truncated.tail_bytes, (torn.len() - clean.len()) as u64);
+    assert!(!bundle.exists());
+
+    let success = run_export_success(&ExportConfig::new(&path, &bundle).with_truncated_allowed())
+        .expect("expected success export");
+    assert_eq!(success.event_count, 3);
+    assert!(success.notes.iter().any(|n| n.contains("truncated record")));
+    assert_eq!(extract_bundle(&bundle)["eventlog.jsonl"], clean);
+    assert_eq!(
+        extract_manifest(&bundle).truncated_tail_bytes,
+        Some(truncated.tail_bytes)
+    );
+}
+
+#[test]
+fn corrupt_blob_is_refused_only_when_verification_is_requested() {
+    let dir = tempdir().unwrap();
+    let (path, store) = write_clean_fixture_with_blobs(dir.path());
+    let blob_ref = blake3::hash(b"blob content alpha").to_hex().to_string();
+    let blob_path = store.root().join(&blob_ref[..2]).join(&blob_ref);
+    std::fs::write(&blob_path, b"bit rot").unwrap();
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&path, &bundle).with_blob_verification();
+    let VifeiError::Io(err) = vifei_export::run_export(&config).unwrap_err() else {
+        panic!("a corrupt blob is an I/O error");
+    };
+    let corrupt = err
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<CorruptBlobs>())
+        .expect("corrupt blob error");
+    assert_eq!(corrupt.errors.len(), 1);
+    assert_eq!(corrupt.errors[0].payload_ref(), blob_ref);
+    assert_eq!(corrupt.errors[0].kind(), "hash_mismatch");
+    assert!(!bundle.exists());
+
+    run_export_success(&ExportConfig::new(&path, &bundle)).expect("unverified export");
+}
+
+#[test]
+fn oversize_blob_is_refused_and_under_limit_blobs_bundle_normally() {
+    let dir = tempdir().unwrap();
+    let (path, _store) = write_clean_fixture_with_blobs(dir.path());
+    let alpha_ref = BlobStore::compute_ref(b"blob content alpha");
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&path, &bundle).with_max_blob_size(17);
+    let ExportResult::OversizeBlob(oversize) = vifei_export::run_export(&config).unwrap() else {
+        panic!("expected an oversize blob refusal");
+    };
+    assert_eq!(
+        oversize,
+        OversizeBlobs {
+            max_blob_size: 17,
+            blobs: vec![OversizeBlob {
+                payload_ref: alpha_ref,
+                size: 18,
+            }],
+        }
+    );
+    assert!(!bundle.exists());
+    let VifeiError::Io(err) = vifei_export::run_export(&config)
+        .unwrap()
+        .into_success()
+        .unwrap_err()
+    else {
+        panic!("an oversize blob refusal converts to an I/O error");
+    };
+    assert!(err
+        .get_ref()
+        .is_some_and(|inner| inner.is::<OversizeBlobs>()));
+
+    let config = ExportConfig::new(&path, &bundle).with_max_blob_size(18);
+    let result = run_export_success(&config).expect("blobs at the limit bundle");
+    assert_eq!(result.blob_count, 2);
+    assert_eq!(result.blobs_excluded, 0);
+    assert!(result.notes.is_empty());
+}
+
+#[test]
+fn oversize_blob_is_left_out_with_a_note_when_exclusion_is_configured() {
+    let dir = tempdir().unwrap();
+    let (path, _store) = write_clean_fixture_with_blobs(dir.path());
+    let alpha_ref = BlobStore::compute_ref(b"blob content alpha");
+    let beta_ref = BlobStore::compute_ref(b"blob content beta");
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&path, &bundle)
+        .with_max_blob_size(17)
+        .with_oversize_blobs_excluded();
+    let result = run_export_success(&config).expect("oversize blob excluded");
+    assert_eq!(result.blob_count, 1);
+    assert_eq!(result.blobs_excluded, 1);
+    assert_eq!(result.notes.len(), 1);
+    assert!(result.notes[0].contains(&alpha_ref));
+    assert!(result.notes[0].contains("18 bytes"));
+
+    assert_eq!(
+        extract_entry_paths(&bundle),
+        vec![
+            format!("blobs/{beta_ref}"),
+            "eventlog.jsonl".to_string(),
+            "manifest.json".to_string(),
+        ]
+    );
+    assert_eq!(extract_manifest(&bundle).blobs_excluded, 1);
+}
+
+// ---- Incremental export ----
+
+#[test]
+fn incremental_bundle_holds_only_appended_events_and_composes_with_base() {
+    let dir = tempdir().unwrap();
+    let (path, store) = write_clean_fixture_with_blobs(dir.path());
+    let base_bundle = dir.path().join("base.tar.zst");
+    run_export_success(&ExportConfig::new(&path, &base_bundle)).expect("base export");
+    let base = vifei_export::verify::read_bundle_manifest(&base_bundle).unwrap();
+    assert_eq!(base.commit_index_range, Some([0, 2]));
+
+    let new_blob = store.write_blob(b"blob content gamma").unwrap();
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    let mut ev4 = clean_event("e4", 4_000_000_000, "appended with blob");
+    ev4.payload_ref = Some(new_blob.clone());
+    writer.append(ev4).unwrap();
+    writer
+        .append(clean_event("e5", 5_000_000_000, "appended inline"))
+        .unwrap();
+    drop(writer);
+
+    let delta_bundle = dir.path().join("delta.tar.zst");
+    let config = ExportConfig::new(&path, &delta_bundle).with_base_manifest(base.clone());
+    let success = run_export_success(&config).expect("delta export");
+    assert_eq!(success.event_count, 2);
+    assert_eq!(success.blob_count, 1);
+
+    let manifest = extract_manifest(&delta_bundle);
+    assert_eq!(manifest.commit_index_range, Some([3, 4]));
+    assert_eq!(manifest.base_commit_index_range, Some([0, 2]));
+    let entries = extract_bundle(&delta_bundle);
+    assert!(entries.contains_key(&format!("blobs/{new_blob}")));
+    assert_eq!(
+        entries.keys().filter(|k| k.starts_with("blobs/")).count(),
+        1
+    );
+
+    let mut composed = extract_bundle(&base_bundle)["eventlog.jsonl"].clone();
+    composed.extend_from_slice(&entries["eventlog.jsonl"]);
+    assert_eq!(composed, std::fs::read(&path).unwrap());
+
+    let again = dir.path().join("delta-again.tar.zst");
+    let rerun = run_export_success(&ExportConfig::new(&path, &again).with_base_manifest(base))
+        .expect("repeat delta export");
+    assert_eq!(rerun.bundle_hash, success.bundle_hash);
+}
+
+#[test]
+fn incremental_export_scans_new_events_and_rejects_foreign_base() {
+    let dir = tempdir().unwrap();
+    let path = write_clean_fixture(dir.path());
+    let base_bundle = dir.path().join("base.tar.zst");
+    run_export_success(&ExportConfig::new(&path, &base_bundle)).expect("base export");
+    let mut base = vifei_export::verify::read_bundle_manifest(&base_bundle).unwrap();
+
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer
+        .append(secret_event("e4", 4_000_000_000, &sample_aws_access_key()))
+        .unwrap();
+    drop(writer);
+
+    let delta_bundle = dir.path().join("delta.tar.zst");
+    let config = ExportConfig::new(&path, &delta_bundle).with_base_manifest(base.clone());
+    let ExportResult::Refused(report) = vifei_export::run_export(&config).unwrap() else {
+        panic!("secret in an appended event must refuse the delta");
+    };
+    assert_eq!(report.blocked_items[0].event_id, "e4");
+
+    base.commit_index_range = Some([0, 9]);
+    let config = ExportConfig::new(&path, &delta_bundle).with_base_manifest(base);
+    let err = vifei_export::run_export(&config).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(!delta_bundle.exists());
+}
+
+// ---- Streamed blobs ----
+
+#[test]
+fn large_blobs_stream_through_scan_and_bundle() {
+    let dir = tempdir().unwrap();
+    let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+    // Several 1 MiB scan windows; the secret sits in the last one.
+    let mut body = vec![b'.'; 3 * 1024 * 1024 + 17];
+    let clean_ref = store.write_blob_from(body.as_slice()).unwrap();
+    let tail = format!(" {}", sample_aws_access_key());
+    body.extend_from_slice(tail.as_bytes());
+    let secret_ref = store.write_blob_from(body.as_slice()).unwrap();
+
+    let path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    let mut event = clean_event("e1", 1_000_000_000, "large blob");
+    event.payload_ref = Some(clean_ref.clone());
+    writer.append(event).unwrap();
+    drop(writer);
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    run_export_success(&ExportConfig::new(&path, &bundle)).expect("clean large blob exports");
+    let entries = extract_bundle(&bundle);
+    assert_eq!(
+        blake3::hash(&entries[&format!("blobs/{clean_ref}")])
+            .to_hex()
+            .to_string(),
+        clean_ref
+    );
+    let mut partial = bundle.clone().into_os_string();
+    partial.push(".partial");
+    assert!(!std::path::Path::new(&partial).exists());
+
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    let mut event = clean_event("e2", 2_000_000_000, "large secret blob");
+    event.payload_ref = Some(secret_ref.clone());
+    writer.append(event).unwrap();
+    drop(writer);
+
+    let ExportResult::Refused(report) =
+        vifei_export::run_export(&ExportConfig::new(&path, &bundle)).unwrap()
+    else {
+        panic!("secret at the end of a large blob must refuse export");
+    };
+    assert_eq!(
+        report.blocked_items[0].blob_ref.as_deref(),
+        Some(secret_ref.as_str())
+    );
+}
+
+#[test]
+fn compressed_blob_store_bundles_logical_content() {
+    let bundle_for = |compress: bool| {
+        let dir = tempdir().unwrap();
+        let mut store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        if compress {
+            store = store.with_compression(1024);
+        }
+        let blob_ref = store
+            .write_blob("tool output line\n".repeat(4096).as_bytes())
+            .unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        let mut event = clean_event("e1", 1_000_000_000, "compressible blob");
+        event.payload_ref = Some(blob_ref.clone());
+        writer.append(event).unwrap();
+        drop(writer);
+        let compressed_file = store
+            .root()
+            .join(&blob_ref[..2])
+            .join(format!("{blob_ref}.zst"));
+        assert_eq!(compressed_file.exists(), compress);
+        let bundle = dir.path().join("bundle.tar.zst");
+        let success = run_export_success(&ExportConfig::new(&path, &bundle)).unwrap();
+        (success.bundle_hash, extract_bundle(&bundle), dir)
+    };
+    let (plain_hash, plain_entries, _plain_dir) = bundle_for(false);
+    let (compressed_hash, compressed_entries, _compressed_dir) = bundle_for(true);
+    assert_eq!(compressed_hash, plain_hash);
+    assert_eq!(compressed_entries, plain_entries);
+}
+
+#[test]
+fn gzipped_eventlog_exports_same_bundle_as_plain() {
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    let dir = tempdir().unwrap();
+    let plain = write_clean_fixture(dir.path());
+    let gz = dir.path().join("eventlog.jsonl.gz");
+    let mut encoder = GzEncoder::new(std::fs::File::create(&gz).unwrap(), Compression::best());
+    encoder.write_all(&std::fs::read(&plain).unwrap()).unwrap();
+    encoder.finish().unwrap();
+
+    let plain_bundle = dir.path().join("plain.tar.zst");
+    let gz_bundle = dir.path().join("gz.tar.zst");
+    let plain_result = run_export_success(&ExportConfig::new(&plain, &plain_bundle))
+        .expect("expected success export");
+    let gz_result =
+        run_export_success(&ExportConfig::new(&gz, &gz_bundle)).expect("expected success export");
+
+    assert_eq!(gz_result.bundle_hash, plain_result.bundle_hash);
+    assert_eq!(
+        extract_bundle(&gz_bundle)["eventlog.jsonl"],
+        std::fs::read(&plain).unwrap()
+    );
+}
+
+// ---- At-rest redaction ----
+
+#[test]
+fn redacted_eventlog_exports_cleanly_and_records_each_redaction() {
+    let dir = tempdir().unwrap();
+    let (input, _store, _) = write_mixed_secret_fixture_with_blobs(dir.path());
+    let original = std::fs::read(&input).unwrap();
+    let out_dir = dir.path().join("redacted");
+    std::fs::create_dir(&out_dir).unwrap();
+    let output = out_dir.join("eventlog.jsonl");
+
+    let summary = vifei_export::redact::run_redact(&RedactConfig::new(&input, &output)).unwrap();
+    assert_eq!(std::fs::read(&input).unwrap(), original);
+    assert_eq!(summary.events_copied, 4);
+    assert_eq!(summary.blobs_redacted, 1);
+    let targets: Vec<(&str, &str)> = summary
+        .redactions
+        .iter()
+        .map(|r| (r.target_event_id.as_str(), r.field_path.as_str()))
+        .collect();
+    assert_eq!(
+        targets,
+        [
+            ("e-inline-aws", "payload.args"),
+            ("e-inline-secret", "payload.args"),
+            ("e-blob-secret", "payload_ref"),
+        ]
+    );
+
+    let events = vifei_core::eventlog::read_eventlog(&output).unwrap();
+    let applied: Vec<(String, String)> = events
+        .iter()
+        .filter_map(|event| match &event.payload {
+            EventPayload::RedactionApplied {
+                target_event_id,
+                field_path,
+                ..
+            } => Some((target_event_id.clone(), field_path.clone())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(applied.len(), summary.redactions.len());
+    for (redaction, (target, field_path)) in summary.redactions.iter().zip(&applied) {
+        assert_eq!(&redaction.target_event_id, target);
+        assert_eq!(&redaction.field_path, field_path);
+    }
+
+    let bundle = dir.path().join("bundle.tar.zst");
+    run_export_success(&ExportConfig::new(&output, &bundle)).expect("redacted log exports");
+
+    let again = out_dir.join("again.jsonl");
+    vifei_export::redact::run_redact(&RedactConfig::new(&input, &again)).unwrap();
+    assert_eq!(
+        std::fs::read(&again).unwrap(),
+        std::fs::read(&output).unwrap()
+    );
+}
+
+#[test]
+fn redact_limits_to_named_patterns_and_never_overwrites() {
+    let dir = tempdir().unwrap();
+    let (input, _store, _) = write_mixed_secret_fixture_with_blobs(dir.path());
+    let output = dir.path().join("redacted.jsonl");
+
+    let config = RedactConfig::new(&input, &output).with_patterns(["aws_access_key".to_string()]);
+    let summary = vifei_export::redact::run_redact(&config).unwrap();
+    assert_eq!(summary.redactions.len(), 1);
+    assert_eq!(summary.redactions[0].pattern, "aws_access_key");
+
+    let err = vifei_export::redact::run_redact(&config).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+}
+
+#[test]
+fn redact_leaves_numeric_fields_alone() {
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("eventlog.jsonl");
+    let mut usage = clean_event("e-usage", 1_000_000_000, "");
+    // Seven digits match the phone pattern as text.
+    usage.payload = EventPayload::TokenUsage {
+        model: "m".into(),
+        input_tokens: 1_234_567,
+        output_tokens: 89,
+    };
+    let mut writer = EventLogWriter::open(&input).unwrap();
+    writer.append(usage.clone()).unwrap();
+    writer
+        .append(secret_event("e-aws", 2_000_000_000, &sample_aws_access_key()))
+        .unwrap();
+    drop(writer);
+
+    let output = dir.path().join("redacted.jsonl");
+    let summary = vifei_export::redact::run_redact(&RedactConfig::new(&input, &output)).unwrap();
+    assert_eq!(summary.redactions.len(), 1);
+    assert_eq!(summary.redactions[0].target_event_id, "e-aws");
+    let events = vifei_core::eventlog::read_eventlog(&output).unwrap();
+    assert_eq!(events[0].payload, usage.payload);
+}
+
+#[test]
+fn redacting_twice_continues_redaction_event_ids() {
+    let dir = tempdir().unwrap();
+    let (input, _store, _) = write_mixed_secret_fixture_with_blobs(dir.path());
+    let first_dir = dir.path().join("first");
+    let second_dir = dir.path().join("second");
+    std::fs::create_dir(&first_dir).unwrap();
+    std::fs::create_dir(&second_dir).unwrap();
+    let first = first_dir.join("eventlog.jsonl");
+    let second = second_dir.join("eventlog.jsonl");
+
+    let config = RedactConfig::new(&input, &first).with_patterns(["aws_access_key".to_string()]);
+    vifei_export::redact::run_redact(&config).unwrap();
+    let summary = vifei_export::redact::run_redact(&RedactConfig::new(&first, &second)).unwrap();
+    assert_eq!(summary.redactions.len(), 2);
+
+    let applied: Vec<(String, Option<u64>, bool)> = vifei_core::eventlog::read_eventlog(&second)
+        .unwrap()
+        .into_iter()
+        .filter(|event| event.source_id == vifei_export::redact::REDACT_SOURCE_ID)
+        .map(|event| (event.event_id, event.source_seq, event.synthesized))
+        .collect();
+    assert_eq!(
+        applied,
+        [
+            ("vifei-redact:0".to_string(), Some(0), true),
+            ("vifei-redact:1".to_string(), Some(1), true),
+            ("vifei-redact:2".to_string(), Some(2), true),
+        ]
+    );
+}
+
+#[test]
+fn excluded_blobs_are_still_scanned_unless_disabled() {
+    let dir = tempdir().unwrap();
+    let blobs_dir = dir.path().join("blobs");
+    let store = BlobStore::open(&blobs_dir).unwrap();
+    let blob_ref = store
+        .write_blob(format!("key {}", sample_aws_access_key()).as_bytes())
+        .unwrap();
+
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    let mut event = clean_event("e1", 1_000_000_000, "blob-backed");
+    event.payload_ref = Some(blob_ref.clone());
+    writer.append(event).unwrap();
+    drop(writer);
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path).without_blobs();
+    let ExportResult::Refused(report) = vifei_export::run_export(&config).unwrap() else {
+        panic!("blob secret must refuse export even when blobs are excluded");
+    };
+    assert_eq!(
+        report.blocked_items[0].blob_ref.as_deref(),
+        Some(blob_ref.as_str())
+    );
+
+    let config = config.without_blob_scan();
+    let result = run_export_success(&config).expect("blob scan disabled");
+    assert_eq!(result.blobs_excluded, 1);
+}
+
+#[test]
+fn email_refuses_export_only_with_pii_scan() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    writer
+        .append(clean_event(
+            "e1",
+            1_000_000_000,
+            "notify jane.doe@example.org",
+        ))
+        .unwrap();
+    drop(writer);
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config = ExportConfig::new(&eventlog_path, &bundle_path);
+    assert!(!config.scan_pii, "PII scanning is opt-in");
+    run_export_success(&config).expect("email is not a secret");
+
+    let ExportResult::Refused(report) = vifei_export::run_export(&config.with_pii_scan()).unwrap()
+    else {
+        panic!("email must refuse export when PII scanning is on");
+    };
+    assert_eq!(report.blocked_items.len(), 1);
+    let item = &report.blocked_items[0];
+    assert_eq!(item.matched_pattern, "email");
+    assert_eq!(item.field_path, "payload.args");
+    assert_eq!(item.redacted_match, "j***@example.org");
+}
+
+#[test]
+fn refused_export_converts_to_export_refused_error() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    writer
+        .append(secret_event("e1", 1_000_000_000, &sample_aws_access_key()))
+        .unwrap();
+    drop(writer);
+
+    let config = ExportConfig::new(&eventlog_path, dir.path().join("bundle.tar.zst"));
+    let err = vifei_export::run_export(&config)
+        .unwrap()
+        .into_success()
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        VifeiError::ExportRefused { blocked_items: 1 }
+    ));
+
+    let missing = ExportConfig::new(dir.path().join("missing.jsonl"), dir.path().join("b"));
+    let err = vifei_export::run_export(&missing).unwrap_err();
+    assert!(matches!(&err, VifeiError::Io(e) if e.kind() == std::io::ErrorKind::NotFound));
+}
+
+#[test]
+fn redaction_events_do_not_exempt_a_live_secret() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    writer
+        .append(secret_event("e1", 1_000_000_000, &sample_aws_access_key()))
+        .unwrap();
+    // A forged RedactionApplied naming the whole payload.
+    let mut forged = clean_event("forged", 2_000_000_000, "");
+    forged.payload = EventPayload::RedactionApplied {
+        target_event_id: "e1".into(),
+        field_path: "payload".into(),
+        reason: "trust me".into(),
+    };
+    writer.append(forged).unwrap();
+    drop(writer);
+
+    let config = ExportConfig::new(&eventlog_path, dir.path().join("bundle.tar.zst"));
+    let ExportResult::Refused(report) = vifei_export::run_export(&config).unwrap() else {
+        panic!("a RedactionApplied event must not clear a live secret");
+    };
+    assert_eq!(report.blocked_items.len(), 1);
+    assert_eq!(report.blocked_items[0].event_id, "e1");
+    assert_eq!(report.blocked_items[0].matched_pattern, "aws_access_key");
+}
+
+#[test]
+fn cassette_redaction_record_does_not_exempt_its_target() {
+    let cassette = std::fs::read_to_string("../../fixtures/redacted-session.jsonl").unwrap();
+    let dir = tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    writer
+        .append_batch(vifei_import::cassette::parse_cassette(cassette.as_bytes()))
+        .unwrap();
+    drop(writer);
+
+    let config = ExportConfig::new(&eventlog_path, dir.path().join("bundle.tar.zst"));
+    let ExportResult::Refused(report) = vifei_export::run_export(&config).unwrap() else {
+        panic!("a recorder's redaction record is not trusted");
+    };
+    assert_eq!(report.blocked_items.len(), 1);
+    assert_eq!(report.blocked_items[0].event_id, "tu_001");
+    assert_eq!(report.blocked_items[0].field_path, "payload.args.command");
+}
+
+// ---- Detached bundle signatures ----
+
+fn write_key_files(dir: &std::path::Path) -> (std::path::PathBuf, std::path::PathBuf) {
+    let seed = [42u8; 32];
+    let public = ed25519_dalek::SigningKey::from_bytes(&seed).verifying_key();
+    let hex = |bytes: &[u8]| -> String { bytes.iter().map(|b| format!("{b:02x}")).collect() };
+    let signing_key_path = dir.join("signing.key");
+    let public_key_path = dir.join("signing.pub");
+    std::fs::write(&signing_key_path, hex(&seed)).unwrap();
+    std::fs::write(&public_key_path, hex(public.as_bytes())).unwrap();
+    (signing_key_path, public_key_path)
+}
+
+#[test]
+fn signed_bundle_round_trips_through_verify() {
+    let dir = tempdir().unwrap();
+    let (eventlog_path, _store) = write_clean_fixture_with_blobs(dir.path());
+    let (signing_key_path, public_key_path) = write_key_files(dir.path());
+
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    let config =
+        ExportConfig::new(&eventlog_path, &bundle_path).with_signing_key(&signing_key_path);
+    let result = run_export_success(&config).expect("expected success export");
+
+    let sig_path = result.signature_path.clone().expect("signature written");
+    assert_eq!(sig_path, dir.path().join("bundle.tar.zst.sig"));
+    let fingerprint = result.signer_fingerprint.clone().expect("fingerprint");
+
+    let verification =
+        vifei_export::verify::verify_bundle(&bundle_path, Some(&public_key_path)).unwrap();
+    assert!(verification.is_valid(), "{:?}", verification.failures);
+    assert_eq!(verification.bundle_hash, result.bundle_hash);
+    assert_eq!(verification.signer_fingerprint, Some(fingerprint));
+    assert_eq!(verification.files_checked, 3);
+}
+
+#[test]
+fn unsigned_export_writes_no_signature() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_clean_fixture(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+
+    let result = run_export_success(&ExportConfig::new(&eventlog_path, &bundle_path))
+        .expect("expected success export");
+    assert_eq!(result.signature_path, None);
+    assert_eq!(result.signer_fingerprint, None);
+    assert!(!dir.path().join("bundle.tar.zst.sig").exists());
+
+    let verification = vifei_export::verify::verify_bundle(&bundle_path, None).unwrap();
+    assert!(verification.is_valid());
+    assert_eq!(verification.signer_fingerprint, None);
+}
+
+#[test]
+fn tampered_bundle_fails_signature_even_with_recomputed_hash() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_clean_fixture(dir.path());
+    let (signing_key_path, public_key_path) = write_key_files(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+
+    let config =
+        ExportConfig::new(&eventlog_path, &bundle_path).with_signing_key(&signing_key_path);
+    let signed = run_export_success(&config).expect("expected success export");
+
+    // Replace the bundle with a self-consistent one built from different
+    // content, and rewrite the recorded hash in the signature file.
+    let other_dir = dir.path().join("other");
+    std::fs::create_dir_all(&other_dir).unwrap();
+    let other_eventlog = other_dir.join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&other_eventlog).unwrap();
+    writer
+        .append(clean_event("e1", 1_000_000_000, "altered history"))
+        .unwrap();
+    drop(writer);
+    let forged = run_export_success(&ExportConfig::new(&other_eventlog, &bundle_path))
+        .expect("expected success export");
+    assert_ne!(forged.bundle_hash, signed.bundle_hash);
+
+    let sig_path = signed.signature_path.unwrap();
+    let mut signature = vifei_export::signing::read_signature(&sig_path).unwrap();
+    signature.bundle_hash = forged.bundle_hash.clone();
+    vifei_export::signing::write_signature(&signature, &sig_path).unwrap();
+
+    let verification =
+        vifei_export::verify::verify_bundle(&bundle_path, Some(&public_key_path)).unwrap();
+    assert!(!verification.is_valid());
+    assert_eq!(verification.signer_fingerprint, None);
+    assert!(verification.failures[0].contains("signature does not match"));
+}
+
+#[test]
+fn verify_bundle_flags_missing_signature_when_key_given() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_clean_fixture(dir.path());
+    let (_, public_key_path) = write_key_files(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    run_export_success(&ExportConfig::new(&eventlog_path, &bundle_path))
+        .expect("expected success export");
+
+    let verification =
+        vifei_export::verify::verify_bundle(&bundle_path, Some(&public_key_path)).unwrap();
+    assert!(!verification.is_valid());
+    assert!(verification.failures[0].contains("not found"));
+}
+
+#[test]
+fn read_bundle_eventlog_returns_source_events_and_refuses_edits() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = write_clean_fixture(dir.path());
+    let bundle_path = dir.path().join("bundle.tar.zst");
+    run_export_success(&ExportConfig::new(&eventlog_path, &bundle_path))
+        .expect("expected success export");
+
+    let events = vifei_export::verify::read_bundle_eventlog(&bundle_path).unwrap();
+    assert_eq!(
+        events,
+        vifei_core::eventlog::read_eventlog(&eventlog_path).unwrap()
+    );
+
+    // Repack with an edited eventlog.jsonl but the original manifest.
+    let mut entries: Vec<_> = extract_bundle(&bundle_path).into_iter().collect();
+    entries.sort();
+    let mut archive = tar::Builder::new(Vec::new());
+    for (path, mut data) in entries {
+        if path == "eventlog.jsonl" {
+            data = String::from_utf8(data)
+                .unwrap()
+                .replacen("e1", "eX", 1)
+                .into_bytes();
+        }
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive
+            .append_data(&mut header, &path, data.as_slice())
+            .unwrap();
+    }
+    let tampered = dir.path().join("tampered.tar.zst");
+    std::fs::write(
+        &tampered,
+        zstd::encode_all(archive.into_inner().unwrap().as_slice(), 0).unwrap(),
+    )
+    .unwrap();
+
+    let err = vifei_export::verify::read_bundle_eventlog(&tampered).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert!(err
+        .to_string()
+        .contains("eventlog.jsonl does not match manifest"));
+}
+
+// ---- Progress callback ----
+
+#[test]
+fn progress_callback_sees_every_stage_in_order() {
+    let dir = tempdir().unwrap();
+    let (eventlog_path, _store) = write_clean_fixture_with_blobs(dir.path());
+    let observed_path = dir.path().join("observed.tar.zst");
+    let plain_path = dir.path().join("plain.tar.zst");
+
+    let mut seen = Vec::new();
+    let result = vifei_export::run_export_with_progress(
+        &ExportConfig::new(&eventlog_path, &observed_path),
+        |event| seen.push(event),
+    )
+    .unwrap();
+    assert!(matches!(result, ExportResult::Success(_)));
+
+    let mut expected = vec![
+        ExportProgress::DiscoverStarted,
+        ExportProgress::Discovered {
+            events: 3,
+            blob_refs: 2,
+        },
+    ];
+    expected.extend((1..=5).map(|scanned| ExportProgress::ScanProgress { scanned, total: 5 }));
+    expected.push(ExportProgress::ScanFinished { findings: 0 });
+    expected.push(ExportProgress::BundleWritten);
+    assert_eq!(seen, expected);
+
+    // Observing does not change the artifact.
+    run_export_success(&ExportConfig::new(&eventlog_path, &plain_path)).unwrap();
+    assert_eq!(
+        std::fs::read(&observed_path).unwrap(),
+        std::fs::read(&plain_path).unwrap()
+    );
+}
+
+#[test]
+fn progress_callback_reports_refusal_report() {
+    let dir = tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    writer
+        .append(secret_event(
+            "e1",
+            1_000_000_000,
+            &format!("my key is {}", sample_aws_access_key()),
+        ))
+        .unwrap();
+    drop(writer);
+
+    let config = ExportConfig::new(&eventlog_path, dir.path().join("bundle.tar.zst"))
+        .with_refusal_report(dir.path().join("refusal-report.json"));
+    let mut seen = Vec::new();
+    vifei_export::run_export_with_progress(&config, |event| seen.push(event)).unwrap();
+    assert_eq!(
+        seen[seen.len() - 2..],
+        [
+            ExportProgress::ScanFinished { findings: 1 },
+            ExportProgress::RefusalReportWritten,
+        ]
+    );
+}
+
+// ---- Export safety sidecar ----
+
+#[test]
+fn export_records_its_verdict_for_the_truth_hud() {
+    use vifei_core::export_safety::{load_export_safety, read_export_safety};
+    use vifei_core::projection::ExportSafetyState;
+
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer
+        .append(clean_event("e1", 1_000_000_000, "hello"))
+        .unwrap();
+    drop(writer);
+    assert_eq!(load_export_safety(&path), ExportSafetyState::Unknown);
+
+    let base_bundle = dir.path().join("base.tar.zst");
+    run_export_success(&ExportConfig::new(&path, &base_bundle)).expect("clean export");
+    assert_eq!(load_export_safety(&path), ExportSafetyState::Clean);
+    let sidecar = read_export_safety(&path).unwrap().unwrap();
+    assert_eq!(sidecar.scanner_version, "secret-scanner-v0.1");
+
+    // A clean incremental export vouches only for the appended events, so
+    // the sidecar stays as it was and is now stale.
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer
+        .append(clean_event("e2", 2_000_000_000, "world"))
+        .unwrap();
+    drop(writer);
+    let base = vifei_export::verify::read_bundle_manifest(&base_bundle).unwrap();
+    let config =
+        ExportConfig::new(&path, dir.path().join("delta.tar.zst")).with_base_manifest(base);
+    run_export_success(&config).expect("delta export");
+    assert_eq!(read_export_safety(&path).unwrap().unwrap(), sidecar);
+    assert_eq!(load_export_safety(&path), ExportSafetyState::Unknown);
+
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer
+        .append(secret_event("e3", 3_000_000_000, &sample_aws_access_key()))
+        .unwrap();
+    drop(writer);
+    let refused = ExportConfig::new(&path, dir.path().join("refused.tar.zst"));
+    assert!(run_export_success(&refused).is_none());
+    assert_eq!(load_export_safety(&path), ExportSafetyState::Refused);
+}
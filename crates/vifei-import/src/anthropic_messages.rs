@@ -202,7 +202,18 @@ fn map_tool_payload(record: &AnthropicRecord) -> Option<(EventPayload, Tier)> {
                     .get("input")
                     .or_else(|| value.get("arguments"))
                     .and_then(json_value_to_string);
-                return Some((EventPayload::ToolCall { tool, args }, Tier::A));
+                let invocation_id = value
+                    .get("id")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string);
+                return Some((
+                    EventPayload::ToolCall {
+                        tool,
+                        args,
+                        invocation_id,
+                    },
+                    Tier::A,
+                ));
             }
             "tool_result" => {
                 let tool = value
@@ -220,11 +231,16 @@ fn map_tool_payload(record: &AnthropicRecord) -> Option<(EventPayload, Tier)> {
                     Some(true) => Some("error".to_string()),
                     _ => Some("success".to_string()),
                 };
+                let invocation_id = value
+                    .get("tool_use_id")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string);
                 return Some((
                     EventPayload::ToolResult {
                         tool,
                         result,
                         status,
+                        invocation_id,
                     },
                     Tier::A,
                 ));
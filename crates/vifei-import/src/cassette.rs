@@ -18,10 +18,11 @@
 //!
 //! | Field | Required | Description |
 //! |-------|----------|-------------|
-//! | `type` | yes | Record type: `session_start`, `session_end`, `tool_use`, `tool_result`, `error` |
+//! | `type` | yes | Record type: `session_start`, `session_end`, `tool_use`, `tool_result`, `error`, `token_usage`, `subagent_spawn`, `policy_decision` |
 //! | `session_id` | yes | Unique session identifier (maps to `run_id`) |
-//! | `timestamp` | yes | ISO 8601 timestamp (maps to `timestamp_ns`) |
+//! | `timestamp` | yes | RFC 3339 string or epoch seconds/millis (maps to `timestamp_ns`) |
 //! | `id` | no | Record identifier (maps to `event_id`) |
+//! | `source` | no | Recorder that wrote the line (maps to `source_id`; default `agent-cassette`) |
 //!
 //! # Mapping summary
 //!
@@ -32,19 +33,81 @@
 //! | `tool_use` | `ToolCall` | A |
 //! | `tool_result` | `ToolResult` | A |
 //! | `error` | `Error` | A |
+//! | `token_usage` | `TokenUsage` | A |
+//! | `subagent_spawn` | `SubagentSpawned` | A |
+//! | `policy_decision` | `PolicyDecision` | A |
 //! | (unknown) | `Generic` | B |
 //!
+//! A `tool_use` record's `id` becomes the `ToolCall`'s `invocation_id`, and
+//! a `tool_result` record's `tool_use_id` becomes the `ToolResult`'s, so the
+//! reducer can pair each result with its call.
+//!
+//! `token_usage` records carry `model`, `input_tokens`, and `output_tokens`
+//! (missing counts read as 0). `subagent_spawn` records carry
+//! `child_session_id` and `agent`; `parent_session_id` defaults to the
+//! record's own `session_id`. `policy_decision` records carry a recorder's
+//! backpressure transition as `from_level`, `to_level`, `trigger`, and
+//! `queue_pressure` (missing levels and trigger read as `unknown`, missing
+//! pressure as 0.0).
+//!
+//! A recorder's `redaction` record is imported as `Generic` like any other
+//! unknown type, never as `RedactionApplied`: only `vifei redact`, which
+//! masks the field itself, records a redaction.
+//!
 //! # Synthesized fields
 //!
 //! The `synthesized` flag is set on an event when any field is inferred:
 //! - `event_id`: synthesized as `"cassette:{seq}"` when no `id` field
-//! - `source_seq`: always synthesized (Agent Cassette has no sequence field)
+//! - `source_seq`: always synthesized (Agent Cassette has no sequence field),
+//!   counted independently for each `source_id`
 //!
 //! Since `source_seq` is always synthesized, every event from this importer
 //! has `synthesized: true`. This is honest: the sequence number is our
 //! invention, not present in the source data.
+//!
+//! # Multi-session cassettes
+//!
+//! A single cassette may interleave several sessions. Each record's
+//! `session_id` becomes its `run_id`, so every `session_start`/`session_end`
+//! pair yields its own `RunStart`/`RunEnd` run. Records that reference a
+//! `session_id` whose `session_start` has not been seen are imported under a
+//! synthetic `orphan-<session_id>` run and reported as an [`ImportWarning`]
+//! in the [`ImportReport`] from [`parse_cassette_with_report`]. Records are
+//! never reordered.
+//!
+//! # Multi-source cassettes
+//!
+//! When several recorders (e.g. an agent and a sidecar proxy) write into one
+//! cassette, each line's `source` field becomes its `source_id`. Every source
+//! gets its own `source_seq` counter starting at 0, so gaps can be detected
+//! per source, while events keep their global file order. Fallback
+//! `event_id`s stay numbered by file position, so they never collide across
+//! sources.
+//!
+//! # Nonstandard schemas
+//!
+//! Recordings that keep these fields under other names or nested paths can
+//! be imported with [`parse_cassette_with_mapping`] and a
+//! [`Mapping`](crate::mapping::Mapping). The default mapping is the table
+//! above.
+//!
+//! # Line handling
+//!
+//! A leading UTF-8 BOM on the first line and CRLF line endings are removed
+//! before parsing. Blank lines are skipped without emitting events. A single
+//! trailing comma after a record (`{...},`, as left by copying from a JSON
+//! array) is dropped and reported as a `trailing_comma` warning. Lines that
+//! are not valid UTF-8 or not valid JSON emit `Error` events and are counted
+//! separately in the [`ImportReport`].
+//!
+//! # Timestamp range
+//!
+//! Timestamps before the Unix epoch or past [`DEFAULT_TIMESTAMP_HORIZON_NS`]
+//! (year 2200) are never wrapped or imported as-is. The record keeps the
+//! latest in-range timestamp seen so far and is followed by a warning
+//! `Error` event; [`parse_cassette_strict`] reports them as line errors.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::BufRead;
 
 use serde::Deserialize;
@@ -54,10 +117,28 @@ use crate::contract::{
     contract_error_payload, normalize_event_id, normalize_run_id, reject_source_commit_index,
     validate_schema_version, AGENT_CASSETTE_SCHEMA_VERSION,
 };
+use crate::lines::{LineError, SourceLines};
+use crate::mapping::{Mapping, PayloadVariant};
+use crate::report::{
+    ImportReport, ImportWarning, SKIP_BLANK_LINE, SKIP_CONTRACT_VIOLATION, SKIP_INVALID_UTF8,
+    SKIP_IO_ERROR, SKIP_MALFORMED_JSON,
+};
 
 /// Source identifier for events produced by this importer.
 pub const SOURCE_ID: &str = "agent-cassette";
 
+/// Run id prefix for records whose session was never started.
+pub const ORPHAN_RUN_PREFIX: &str = "orphan-";
+
+/// Events and import receipt produced by [`parse_cassette_with_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CassetteImport {
+    /// Imported events in source order.
+    pub events: Vec<ImportEvent>,
+    /// Receipt for this import pass, including warnings.
+    pub report: ImportReport,
+}
+
 #[derive(Debug, Deserialize)]
 struct CassetteRecord {
     #[serde(rename = "type")]
@@ -65,8 +146,9 @@ struct CassetteRecord {
     schema_version: Option<String>,
     session_id: Option<String>,
     id: Option<String>,
+    source: Option<String>,
     commit_index: Option<u64>,
-    timestamp: Option<String>,
+    timestamp: Option<serde_json::Value>,
     agent: Option<String>,
     model: Option<String>,
     tool: Option<String>,
@@ -78,6 +160,15 @@ struct CassetteRecord {
     kind: Option<String>,
     message: Option<String>,
     severity: Option<String>,
+    input_tokens: Option<u64>,
+    output_tokens: Option<u64>,
+    parent_session_id: Option<String>,
+    child_session_id: Option<String>,
+    tool_use_id: Option<String>,
+    from_level: Option<String>,
+    to_level: Option<String>,
+    trigger: Option<String>,
+    queue_pressure: Option<f64>,
 }
 
 /// Parse an Agent Cassette JSONL stream into an iterator of [`ImportEvent`].
@@ -85,49 +176,306 @@ struct CassetteRecord {
 /// Reads line-by-line. Malformed lines produce `Error` events rather than
 /// aborting the parse. Source order is preserved exactly.
 pub fn parse_cassette<R: BufRead>(reader: R) -> Vec<ImportEvent> {
+    parse_cassette_with_report(reader).events
+}
+
+/// Parse an Agent Cassette JSONL stream, failing with every per-line parse
+/// error if any line could not be read, was not valid JSON, or carried a
+/// timestamp outside the accepted range.
+pub fn parse_cassette_strict<R: BufRead>(reader: R) -> Result<Vec<ImportEvent>, Vec<String>> {
+    let mut parser = CassetteParser::new();
     let mut events = Vec::new();
-    let mut seq: u64 = 0;
+    for (line_num, line_result) in SourceLines::new(reader) {
+        events.extend(parser.push_line_result(line_num, line_result));
+    }
+    if parser.errors.is_empty() {
+        Ok(events)
+    } else {
+        Err(parser.errors)
+    }
+}
+
+/// Parse an Agent Cassette JSONL stream, also returning an [`ImportReport`].
+///
+/// Same event stream as [`parse_cassette`]. The report counts lines read,
+/// records mapped per source type, lines skipped by reason, source timestamp
+/// bounds, and derived run ids. One `orphan_session` warning is added per
+/// `session_id` that had events before its `session_start` (or none at all).
+pub fn parse_cassette_with_report<R: BufRead>(reader: R) -> CassetteImport {
+    parse_cassette_with_mapping(reader, &Mapping::default())
+}
 
-    for (line_num, line_result) in reader.lines().enumerate() {
+/// Parse cassette-like JSONL whose fields follow `mapping`.
+///
+/// With [`Mapping::default`] this is exactly [`parse_cassette_with_report`].
+/// See [`crate::mapping`] for the mapping file format.
+pub fn parse_cassette_with_mapping<R: BufRead>(reader: R, mapping: &Mapping) -> CassetteImport {
+    let mut parser = CassetteParser::with_mapping(mapping.clone());
+    let mut events = Vec::new();
+    for (line_num, line_result) in SourceLines::new(reader) {
+        events.extend(parser.push_line_result(line_num, line_result));
+    }
+    CassetteImport {
+        events,
+        report: parser.finish(),
+    }
+}
+
+/// Incremental Agent Cassette parser.
+///
+/// Maps one line at a time with the same rules as
+/// [`parse_cassette_with_report`], which is built on it. Used directly when
+/// lines arrive over time (see [`crate::follow`]). Sequence numbers and
+/// session tracking carry across calls.
+#[derive(Debug)]
+pub struct CassetteParser {
+    mapping: Mapping,
+    report: ImportReport,
+    seq: u64,
+    source_seqs: BTreeMap<String, u64>,
+    started_sessions: BTreeSet<String>,
+    orphan_counts: BTreeMap<String, u64>,
+    trailing_commas: u64,
+    timestamp_horizon_ns: u64,
+    latest_timestamp_ns: BTreeMap<String, u64>,
+    clamped_timestamps: u64,
+    errors: Vec<String>,
+}
+
+impl Default for CassetteParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CassetteParser {
+    /// Create a parser positioned before the first line.
+    pub fn new() -> Self {
+        Self::with_mapping(Mapping::default())
+    }
+
+    /// Create a parser that reads fields according to `mapping`.
+    pub fn with_mapping(mapping: Mapping) -> Self {
+        CassetteParser {
+            mapping,
+            report: ImportReport::new(SOURCE_ID),
+            seq: 0,
+            source_seqs: BTreeMap::new(),
+            started_sessions: BTreeSet::new(),
+            orphan_counts: BTreeMap::new(),
+            trailing_commas: 0,
+            timestamp_horizon_ns: DEFAULT_TIMESTAMP_HORIZON_NS,
+            latest_timestamp_ns: BTreeMap::new(),
+            clamped_timestamps: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Accept record timestamps up to `horizon_ns` instead of
+    /// [`DEFAULT_TIMESTAMP_HORIZON_NS`].
+    ///
+    /// A record dated before the Unix epoch or past the horizon is imported
+    /// with the latest in-range timestamp seen so far, followed by a warning
+    /// `Error` event, so one corrupted line cannot skew the clock of the
+    /// rest of the run.
+    pub fn with_timestamp_horizon(mut self, horizon_ns: u64) -> Self {
+        self.timestamp_horizon_ns = horizon_ns;
+        self
+    }
+
+    /// Map one line of text (without its terminator) to events.
+    ///
+    /// `line_num` is the 1-based physical line number used in messages.
+    /// Returns no events for blank lines, and the record followed by a
+    /// warning for a record whose timestamp is out of range.
+    pub fn push_line(&mut self, line_num: usize, line: &str) -> Vec<ImportEvent> {
+        self.push_line_result(line_num, Ok(line.to_string()))
+    }
+
+    /// Map one decoded line, emitting an `Error` event for lines that could
+    /// not be read.
+    pub(crate) fn push_line_result(
+        &mut self,
+        line_num: usize,
+        line_result: Result<String, LineError>,
+    ) -> Vec<ImportEvent> {
+        self.report.lines_read += 1;
         let line = match line_result {
             Ok(l) => l,
-            Err(e) => {
-                events.push(make_error_event(
-                    seq,
-                    &format!("IO error reading line {}: {e}", line_num + 1),
-                ));
-                seq += 1;
-                continue;
+            Err(LineError::InvalidUtf8(e)) => {
+                self.report.record_skipped(SKIP_INVALID_UTF8);
+                return vec![self.emit_error(&format!("Invalid UTF-8 at line {line_num}: {e}"))];
+            }
+            Err(LineError::Io(e)) => {
+                self.report.record_skipped(SKIP_IO_ERROR);
+                return vec![self.emit_error(&format!("IO error reading line {line_num}: {e}"))];
             }
         };
 
-        let trimmed = line.trim();
+        let mut trimmed = line.trim();
         if trimmed.is_empty() {
-            continue;
+            self.report.record_skipped(SKIP_BLANK_LINE);
+            return Vec::new();
+        }
+        if let Some(record_text) = strip_trailing_comma(trimmed) {
+            trimmed = record_text;
+            self.trailing_commas += 1;
         }
 
-        let record: CassetteRecord = match serde_json::from_str(trimmed) {
+        let parsed = if self.mapping.has_standard_fields() {
+            serde_json::from_str(trimmed)
+        } else {
+            serde_json::from_str(trimmed)
+                .and_then(|value| serde_json::from_value(self.mapping.apply(value)))
+        };
+        let record: CassetteRecord = match parsed {
             Ok(v) => v,
             Err(e) => {
-                events.push(make_error_event(
-                    seq,
-                    &format!("Malformed JSON at line {}: {e}", line_num + 1),
-                ));
-                seq += 1;
-                continue;
+                self.report.record_skipped(SKIP_MALFORMED_JSON);
+                return vec![self.emit_error(&format!("Malformed JSON at line {line_num}: {e}"))];
             }
         };
 
-        let mapped = map_record(&record, seq, line_num + 1);
-        seq += 1;
-        events.push(mapped);
+        let variant = self
+            .mapping
+            .variant_for(record.record_type.as_deref().unwrap_or("unknown"));
+        let source_id = record
+            .source
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(SOURCE_ID)
+            .to_string();
+        let source_seq = self.next_source_seq(&source_id);
+        let (mut mapped, contract_rejected) =
+            map_record(&record, variant, self.seq, &source_id, source_seq, line_num);
+        self.seq += 1;
+        let range_error = timestamp_value_ns(record.timestamp.as_ref())
+            .and_then(|ns| timestamp_range_violation(ns, self.timestamp_horizon_ns))
+            .map(|reason| {
+                let raw = record.timestamp.as_ref().map(ToString::to_string);
+                let raw = raw.unwrap_or_default();
+                format!("Timestamp out of range at line {line_num}: {raw} {reason}")
+            });
+        let latest = self
+            .latest_timestamp_ns
+            .entry(source_id.clone())
+            .or_insert(0);
+        if range_error.is_some() {
+            mapped.timestamp_ns = *latest;
+        } else {
+            *latest = (*latest).max(mapped.timestamp_ns);
+        }
+        if contract_rejected {
+            self.report.record_skipped(SKIP_CONTRACT_VIOLATION);
+        } else {
+            self.report
+                .record_parsed(record.record_type.as_deref().unwrap_or("unknown"));
+            self.report.observe_timestamp(mapped.timestamp_ns);
+        }
+        if let Some(session_id) = record.session_id.as_deref() {
+            if matches!(mapped.payload, EventPayload::RunStart { .. }) {
+                self.started_sessions.insert(mapped.run_id.clone());
+            } else if !session_id.trim().is_empty() && !self.started_sessions.contains(session_id) {
+                *self
+                    .orphan_counts
+                    .entry(session_id.to_string())
+                    .or_insert(0) += 1;
+                mapped.run_id = format!("{ORPHAN_RUN_PREFIX}{session_id}");
+            }
+        }
+        let (run_id, timestamp_ns) = (mapped.run_id.clone(), mapped.timestamp_ns);
+        let mut events = vec![self.emitted(mapped)];
+        if let Some(message) = range_error {
+            self.clamped_timestamps += 1;
+            let mut warning =
+                make_error_event(self.seq, self.next_source_seq(&source_id), &message);
+            self.seq += 1;
+            self.errors.push(message);
+            warning.run_id = run_id;
+            warning.source_id = source_id;
+            warning.timestamp_ns = timestamp_ns;
+            events.push(self.emitted(warning));
+        }
+        events
+    }
+
+    /// Finish parsing and return the import report, including warnings.
+    pub fn finish(mut self) -> ImportReport {
+        self.report.warnings = self
+            .orphan_counts
+            .into_iter()
+            .map(|(session_id, count)| ImportWarning {
+                code: "orphan_session".to_string(),
+                message: format!(
+                    "{count} event(s) for session_id {session_id} had no preceding session_start; imported under run {ORPHAN_RUN_PREFIX}{session_id}"
+                ),
+                count,
+            })
+            .collect();
+        if self.trailing_commas > 0 {
+            let trailing_commas = self.trailing_commas;
+            self.report.warnings.push(ImportWarning {
+                code: "trailing_comma".to_string(),
+                message: format!(
+                    "{trailing_commas} record line(s) ended with a trailing comma that was ignored"
+                ),
+                count: trailing_commas,
+            });
+        }
+        if self.clamped_timestamps > 0 {
+            let clamped = self.clamped_timestamps;
+            self.report.warnings.push(ImportWarning {
+                code: "timestamp_out_of_range".to_string(),
+                message: format!(
+                    "{clamped} record(s) had a timestamp before the Unix epoch or past the horizon and were clamped"
+                ),
+                count: clamped,
+            });
+        }
+        self.report
+    }
+
+    fn emit_error(&mut self, message: &str) -> ImportEvent {
+        let event = make_error_event(self.seq, self.next_source_seq(SOURCE_ID), message);
+        self.seq += 1;
+        self.errors.push(message.to_string());
+        self.emitted(event)
+    }
+
+    /// Next `source_seq` for `source_id`; each source counts from 0.
+    fn next_source_seq(&mut self, source_id: &str) -> u64 {
+        let next = self.source_seqs.entry(source_id.to_string()).or_insert(0);
+        let seq = *next;
+        *next += 1;
+        seq
     }
 
-    events
+    fn emitted(&mut self, event: ImportEvent) -> ImportEvent {
+        self.report.events_emitted += 1;
+        self.report.run_ids.insert(event.run_id.clone());
+        event
+    }
+}
+
+/// Return the record text without a trailing `,` after its closing brace.
+fn strip_trailing_comma(line: &str) -> Option<&str> {
+    let record = line.strip_suffix(',')?.trim_end();
+    record.ends_with('}').then_some(record)
 }
 
 /// Map a single Cassette JSON record to an [`ImportEvent`].
-fn map_record(record: &CassetteRecord, seq: u64, line_num: usize) -> ImportEvent {
+///
+/// The flag is true when the record violated the adapter contract and the
+/// returned event is the contract `Error` emitted in its place.
+fn map_record(
+    record: &CassetteRecord,
+    variant: Option<PayloadVariant>,
+    seq: u64,
+    source_id: &str,
+    source_seq: u64,
+    line_num: usize,
+) -> (ImportEvent, bool) {
     let record_type = record.record_type.as_deref().unwrap_or("unknown");
     let (session_id, _run_synthesized) =
         normalize_run_id(record.session_id.as_deref(), "unknown-session");
@@ -135,68 +483,72 @@ fn map_record(record: &CassetteRecord, seq: u64, line_num: usize) -> ImportEvent
     let (event_id, _event_id_synthesized) =
         normalize_event_id(record.id.as_deref(), &fallback_event_id);
 
-    let timestamp_ns = parse_timestamp_ns(record.timestamp.as_deref());
+    let timestamp_ns = parse_timestamp_value(record.timestamp.as_ref());
 
     if let Err(message) = validate_schema_version(
         record.schema_version.as_deref(),
         AGENT_CASSETTE_SCHEMA_VERSION,
     ) {
         let (payload, tier) = contract_error_payload(message);
-        return ImportEvent {
+        let event = ImportEvent {
             run_id: session_id,
             event_id,
-            source_id: SOURCE_ID.to_string(),
-            source_seq: Some(seq),
+            source_id: source_id.to_string(),
+            source_seq: Some(source_seq),
             timestamp_ns,
             tier,
             payload,
             payload_ref: None,
             synthesized: true,
         };
+        return (event, true);
     }
 
     if let Err(message) = reject_source_commit_index(record.commit_index) {
         let (payload, tier) = contract_error_payload(message);
-        return ImportEvent {
+        let event = ImportEvent {
             run_id: session_id,
             event_id,
-            source_id: SOURCE_ID.to_string(),
-            source_seq: Some(seq),
+            source_id: source_id.to_string(),
+            source_seq: Some(source_seq),
             timestamp_ns,
             tier,
             payload,
             payload_ref: None,
             synthesized: true,
         };
+        return (event, true);
     }
 
-    let (payload, tier) = map_payload(record_type, record, seq, line_num);
+    let (payload, tier) = map_payload(record_type, variant, record, source_seq, line_num);
 
-    ImportEvent {
+    let event = ImportEvent {
         run_id: session_id,
         event_id,
-        source_id: SOURCE_ID.to_string(),
+        source_id: source_id.to_string(),
         // source_seq is always synthesized: Agent Cassette has no sequence
-        // field. We assign monotonically based on parse order.
-        source_seq: Some(seq),
+        // field. We assign monotonically per source based on parse order.
+        source_seq: Some(source_seq),
         timestamp_ns,
         tier,
         payload,
         payload_ref: None,
         // Always true: source_seq is synthesized for every event.
         synthesized: true,
-    }
+    };
+    (event, false)
 }
 
 /// Map a Cassette record type to an [`EventPayload`] and [`Tier`].
 fn map_payload(
     record_type: &str,
+    variant: Option<PayloadVariant>,
     record: &CassetteRecord,
     seq: u64,
     line_num: usize,
 ) -> (EventPayload, Tier) {
-    match record_type {
-        "session_start" => {
+    match variant {
+        Some(PayloadVariant::RunStart) => {
             let agent = record
                 .agent
                 .clone()
@@ -206,33 +558,43 @@ fn map_payload(
             (EventPayload::RunStart { agent, args }, Tier::A)
         }
 
-        "session_end" => {
+        Some(PayloadVariant::RunEnd) => {
             let exit_code = record.exit_code;
             let reason = record.reason.clone();
             (EventPayload::RunEnd { exit_code, reason }, Tier::A)
         }
 
-        "tool_use" => {
+        Some(PayloadVariant::ToolCall) => {
             let tool = record.tool.clone().unwrap_or_else(|| "unknown".to_string());
             let args = record.args.as_ref().and_then(json_value_to_string);
-            (EventPayload::ToolCall { tool, args }, Tier::A)
+            let invocation_id = record.id.clone();
+            (
+                EventPayload::ToolCall {
+                    tool,
+                    args,
+                    invocation_id,
+                },
+                Tier::A,
+            )
         }
 
-        "tool_result" => {
+        Some(PayloadVariant::ToolResult) => {
             let tool = record.tool.clone().unwrap_or_else(|| "unknown".to_string());
             let result = record.result.as_ref().and_then(json_value_to_string);
             let status = record.status.clone();
+            let invocation_id = record.tool_use_id.clone();
             (
                 EventPayload::ToolResult {
                     tool,
                     result,
                     status,
+                    invocation_id,
                 },
                 Tier::A,
             )
         }
 
-        "error" => {
+        Some(PayloadVariant::Error) => {
             let kind = record.kind.clone().unwrap_or_else(|| "unknown".to_string());
             let message = record.message.clone().unwrap_or_default();
             let severity = record.severity.clone();
@@ -246,7 +608,61 @@ fn map_payload(
             )
         }
 
-        _ => {
+        Some(PayloadVariant::TokenUsage) => {
+            let model = record
+                .model
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string());
+            (
+                EventPayload::TokenUsage {
+                    model,
+                    input_tokens: record.input_tokens.unwrap_or(0),
+                    output_tokens: record.output_tokens.unwrap_or(0),
+                },
+                Tier::A,
+            )
+        }
+
+        Some(PayloadVariant::SubagentSpawned) => {
+            let parent_run_id = record
+                .parent_session_id
+                .as_deref()
+                .or(record.session_id.as_deref())
+                .unwrap_or("unknown-session")
+                .to_string();
+            let child_run_id = record
+                .child_session_id
+                .clone()
+                .unwrap_or_else(|| "unknown-session".to_string());
+            let agent = record
+                .agent
+                .clone()
+                .unwrap_or_else(|| "unknown".to_string());
+            (
+                EventPayload::SubagentSpawned {
+                    parent_run_id,
+                    child_run_id,
+                    agent,
+                },
+                Tier::A,
+            )
+        }
+
+        Some(PayloadVariant::PolicyDecision) => {
+            let or_unknown =
+                |level: &Option<String>| level.clone().unwrap_or_else(|| "unknown".to_string());
+            (
+                EventPayload::PolicyDecision {
+                    from_level: or_unknown(&record.from_level),
+                    to_level: or_unknown(&record.to_level),
+                    trigger: or_unknown(&record.trigger),
+                    queue_pressure: record.queue_pressure.unwrap_or(0.0),
+                },
+                Tier::A,
+            )
+        }
+
+        None => {
             // Unknown record type: map to Generic with Tier B.
             let mut data = BTreeMap::new();
             data.insert("original_type".to_string(), record_type.to_string());
@@ -278,27 +694,116 @@ fn json_value_to_string(value: &serde_json::Value) -> Option<String> {
     }
 }
 
-/// Parse ISO 8601 timestamp to nanoseconds since Unix epoch.
+/// Integer epoch values below this are seconds; at or above it, milliseconds.
 ///
-/// Handles formats like `"2026-02-16T10:00:00.000Z"` and
-/// `"2026-02-16T10:00:00Z"`. Falls back to 0 if unparseable.
-fn parse_timestamp_ns(ts_str: Option<&str>) -> u64 {
-    let ts_str = match ts_str {
-        Some(s) => s,
-        None => return 0,
+/// `100_000_000_000` seconds is year 5138, far beyond any real recording,
+/// while `100_000_000_000` milliseconds is 1973-03-03, earlier than any
+/// agent recording, so the two ranges cannot collide in practice. Seconds
+/// values past the `u64` nanosecond range (~year 2554) are rejected.
+pub const EPOCH_MILLIS_CUTOFF: u64 = 100_000_000_000;
+
+/// Latest accepted record timestamp by default: 2200-01-01T00:00:00Z.
+///
+/// Timestamps past the horizon, like those before the Unix epoch, come from
+/// corrupted sources and are never imported as-is. See
+/// [`CassetteParser::with_timestamp_horizon`].
+pub const DEFAULT_TIMESTAMP_HORIZON_NS: u64 = 7_258_118_400_000_000_000;
+
+/// Parse a cassette `timestamp` field to nanoseconds since Unix epoch.
+///
+/// Accepts:
+/// - JSON strings in RFC 3339 form (see [`parse_iso8601_ns`])
+/// - JSON numbers or digit-only strings as epoch seconds or epoch
+///   milliseconds, disambiguated by [`EPOCH_MILLIS_CUTOFF`]; a fractional
+///   part is honored down to nanoseconds.
+///
+/// Falls back to 0 if missing, unparseable, or outside the `u64`
+/// nanosecond range.
+fn parse_timestamp_value(ts: Option<&serde_json::Value>) -> u64 {
+    timestamp_value_ns(ts)
+        .and_then(|ns| u64::try_from(ns).ok())
+        .unwrap_or(0)
+}
+
+/// Parse a cassette `timestamp` field to signed nanoseconds since Unix
+/// epoch, so values before 1970 can be told apart from missing ones.
+fn timestamp_value_ns(ts: Option<&serde_json::Value>) -> Option<i128> {
+    match ts? {
+        serde_json::Value::String(s) => timestamp_text_ns(s.trim()),
+        serde_json::Value::Number(n) => parse_epoch_ns(&n.to_string()),
+        _ => None,
+    }
+}
+
+/// Parse a textual timestamp: RFC 3339 forms like
+/// `"2026-02-16T10:00:00.000Z"` and `"2026-02-16T12:00:00+02:00"` (offsets
+/// are normalized to UTC), plus bare epoch numbers such as
+/// `"1735689600123"`.
+fn timestamp_text_ns(ts_str: &str) -> Option<i128> {
+    let unsigned = ts_str.strip_prefix('-').unwrap_or(ts_str);
+    if unsigned.starts_with(|c: char| c.is_ascii_digit()) && !unsigned.contains('-') {
+        return parse_epoch_ns(ts_str);
+    }
+    parse_iso8601_ns(ts_str)
+}
+
+/// Describe why `ns` is outside `0..=horizon_ns`, or `None` if it is not.
+fn timestamp_range_violation(ns: i128, horizon_ns: u64) -> Option<String> {
+    if ns < 0 {
+        Some("is before the Unix epoch".to_string())
+    } else if ns > i128::from(horizon_ns) {
+        Some(format!("is past the timestamp horizon ({horizon_ns} ns)"))
+    } else {
+        None
+    }
+}
+
+/// Parse a decimal epoch value (`"1735689600"`, `"1735689600123"`,
+/// `"1735689600123.5"`, `"-5"`) to nanoseconds.
+///
+/// Works on the decimal text rather than `f64` so fractional millis are
+/// exact. Exponent notation is rejected.
+fn parse_epoch_ns(text: &str) -> Option<i128> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
     };
+    let (whole, frac) = match text.split_once('.') {
+        Some((w, f)) => (w, Some(f)),
+        None => (text, None),
+    };
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let whole: i128 = whole.parse().ok()?;
 
-    // Minimal ISO 8601 parser for the subset we expect.
-    // Format: YYYY-MM-DDThh:mm:ss[.fff]Z
-    parse_iso8601_ns(ts_str).unwrap_or(0)
+    let (unit_ns, scale) = if whole < i128::from(EPOCH_MILLIS_CUTOFF) {
+        (1_000_000_000, 9)
+    } else {
+        (1_000_000, 6)
+    };
+    let frac_ns = match frac {
+        Some(f) => parse_fraction_scaled(f, scale)?,
+        None => 0,
+    };
+    let ns = whole
+        .checked_mul(unit_ns)?
+        .checked_add(i128::from(frac_ns))?;
+    Some(if negative { -ns } else { ns })
 }
 
-/// Parse a subset of ISO 8601 to nanoseconds.
-fn parse_iso8601_ns(s: &str) -> Option<u64> {
-    // Strip trailing Z.
-    let s = s.strip_suffix('Z').or_else(|| s.strip_suffix('z'))?;
+/// Parse a subset of ISO 8601 / RFC 3339 to nanoseconds since epoch (UTC).
+///
+/// Format: `YYYY-MM-DDThh:mm:ss[.fffffffff](Z|+hh:mm|-hh:mm)`. A zone
+/// designator is required; local times without one are rejected. Dates
+/// before 1970 yield negative values.
+fn parse_iso8601_ns(s: &str) -> Option<i128> {
+    let (date_part, time_part) = s.split_once('T').or_else(|| s.split_once('t'))?;
 
-    let (date_part, time_part) = s.split_once('T')?;
+    // Split off the zone designator: Z, or a signed offset.
+    let zone_start = time_part.find(['Z', 'z', '+', '-'])?;
+    let (time_part, zone) = time_part.split_at(zone_start);
+    let offset_secs = parse_utc_offset_secs(zone)?;
 
     // Parse date: YYYY-MM-DD
     let mut date_parts = date_part.split('-');
@@ -317,6 +822,9 @@ fn parse_iso8601_ns(s: &str) -> Option<u64> {
     let hour: u64 = time_parts.next()?.parse().ok()?;
     let minute: u64 = time_parts.next()?.parse().ok()?;
     let second: u64 = time_parts.next()?.parse().ok()?;
+    if hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
 
     // Fractional seconds → nanoseconds.
     let frac_ns: u64 = if let Some(f) = frac_str {
@@ -327,30 +835,64 @@ fn parse_iso8601_ns(s: &str) -> Option<u64> {
 
     // Days from epoch (simplified: no leap second handling).
     let days = days_from_epoch(year, month, day)?;
-    let secs = days * 86400 + hour * 3600 + minute * 60 + second;
-    Some(secs * 1_000_000_000 + frac_ns)
+    let local_secs = i128::from(days) * 86400 + i128::from(hour * 3600 + minute * 60 + second);
+    let utc_secs = local_secs - i128::from(offset_secs);
+    Some(utc_secs * 1_000_000_000 + i128::from(frac_ns))
 }
 
-/// Days from Unix epoch (1970-01-01) to the given date.
-/// Simplified calculation, adequate for v0.1.
-fn days_from_epoch(year: u64, month: u64, day: u64) -> Option<u64> {
-    if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+/// Parse an RFC 3339 zone designator to an offset east of UTC in seconds.
+///
+/// Accepts `Z`/`z`, `+hh:mm`, `-hh:mm`, `+hhmm`, and `+hh`.
+fn parse_utc_offset_secs(zone: &str) -> Option<i64> {
+    if zone == "Z" || zone == "z" {
+        return Some(0);
+    }
+    let sign: i64 = match zone.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits: String = zone[1..].chars().filter(|c| *c != ':').collect();
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i64>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i64>().ok()?,
+            digits[2..].parse::<i64>().ok()?,
+        ),
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Days from Unix epoch (1970-01-01) to the given date, negative before it.
+/// Simplified calculation for four-digit years, adequate for v0.1.
+fn days_from_epoch(year: u64, month: u64, day: u64) -> Option<i64> {
+    if year > 9999 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
         return None;
     }
 
-    let mut days: u64 = 0;
+    let mut days: i64 = 0;
     for y in 1970..year {
         days += if is_leap(y) { 366 } else { 365 };
     }
+    for y in year..1970 {
+        days -= if is_leap(y) { 366 } else { 365 };
+    }
 
     let days_in_month = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
     for m in 1..month {
-        days += days_in_month[m as usize] as u64;
+        days += days_in_month[m as usize];
         if m == 2 && is_leap(year) {
             days += 1;
         }
     }
-    days += day - 1;
+    days += day as i64 - 1;
 
     Some(days)
 }
@@ -364,6 +906,12 @@ fn is_leap(year: u64) -> bool {
 /// Accepts 1..N decimal digits. Uses the first 9 digits and ignores extra
 /// precision, matching the previous truncate-to-9 behavior.
 fn parse_fractional_ns(s: &str) -> Option<u64> {
+    parse_fraction_scaled(s, 9)
+}
+
+/// Parse decimal fraction digits into an integer with `scale` digits of
+/// precision (truncating extra digits, right-padding short input).
+fn parse_fraction_scaled(s: &str, scale: usize) -> Option<u64> {
     if s.is_empty() {
         return Some(0);
     }
@@ -375,25 +923,25 @@ fn parse_fractional_ns(s: &str) -> Option<u64> {
         if !b.is_ascii_digit() {
             return None;
         }
-        if digits_seen < 9 {
+        if digits_seen < scale {
             value = value * 10 + u64::from(b - b'0');
         }
         digits_seen += 1;
     }
 
-    if digits_seen < 9 {
-        value *= 10_u64.pow((9 - digits_seen) as u32);
+    if digits_seen < scale {
+        value *= 10_u64.pow((scale - digits_seen) as u32);
     }
     Some(value)
 }
 
 /// Create an Error ImportEvent for parse failures.
-fn make_error_event(seq: u64, message: &str) -> ImportEvent {
+fn make_error_event(seq: u64, source_seq: u64, message: &str) -> ImportEvent {
     ImportEvent {
         run_id: "unknown-session".to_string(),
         event_id: format!("cassette:{seq}"),
         source_id: SOURCE_ID.to_string(),
-        source_seq: Some(seq),
+        source_seq: Some(source_seq),
         timestamp_ns: 0,
         tier: Tier::A,
         payload: EventPayload::Error {
@@ -409,12 +957,61 @@ fn make_error_event(seq: u64, message: &str) -> ImportEvent {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
     use std::io::Cursor;
 
     // -------------------------------------------------------------------
     // M3.1: Parser tests
     // -------------------------------------------------------------------
 
+    #[test]
+    fn blank_crlf_lines_are_skipped_not_errors() {
+        let input = "\r\n{\"type\":\"session_start\",\"session_id\":\"s1\",\"timestamp\":\"2026-01-01T00:00:00Z\"}\r\n  \r\n";
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        assert_eq!(parsed.events.len(), 1);
+        assert!(matches!(
+            parsed.events[0].payload,
+            EventPayload::RunStart { .. }
+        ));
+        assert_eq!(parsed.report.skipped.get(SKIP_BLANK_LINE), Some(&2));
+        assert_eq!(parsed.report.skipped.get(SKIP_MALFORMED_JSON), None);
+    }
+
+    #[test]
+    fn trailing_comma_record_is_parsed_with_warning() {
+        let input = "{\"type\":\"session_start\",\"session_id\":\"s1\",\"timestamp\":\"2026-01-01T00:00:00Z\"},\n";
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        assert!(matches!(
+            parsed.events[0].payload,
+            EventPayload::RunStart { .. }
+        ));
+        assert!(parsed.report.skipped.is_empty());
+        assert_eq!(parsed.report.warnings.len(), 1);
+        assert_eq!(parsed.report.warnings[0].code, "trailing_comma");
+        assert_eq!(parsed.report.warnings[0].count, 1);
+    }
+
+    #[test]
+    fn invalid_utf8_line_is_reported_and_parsing_continues() {
+        let mut input = b"\xFF\xFE garbage\n".to_vec();
+        input.extend_from_slice(
+            b"{\"type\":\"session_start\",\"session_id\":\"s1\",\"timestamp\":\"2026-01-01T00:00:00Z\"}\n",
+        );
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        assert_eq!(parsed.events.len(), 2);
+        match &parsed.events[0].payload {
+            EventPayload::Error { message, .. } => {
+                assert!(message.contains("Invalid UTF-8 at line 1"), "{message}")
+            }
+            other => panic!("expected Error, got {other:?}"),
+        }
+        assert!(matches!(
+            parsed.events[1].payload,
+            EventPayload::RunStart { .. }
+        ));
+        assert_eq!(parsed.report.skipped.get(SKIP_INVALID_UTF8), Some(&1));
+    }
+
     #[test]
     fn parse_empty_input() {
         let events = parse_cassette(Cursor::new(""));
@@ -548,11 +1145,17 @@ mod tests {
         assert_eq!(events[0].event_id, "tu_001");
         assert_eq!(events[0].tier, Tier::A);
         assert!(matches!(&events[0].payload, EventPayload::ToolCall { .. }));
-        if let EventPayload::ToolCall { tool, args } = &events[0].payload {
+        if let EventPayload::ToolCall {
+            tool,
+            args,
+            invocation_id,
+        } = &events[0].payload
+        {
             assert_eq!(tool, "Read");
             assert!(args
                 .as_deref()
                 .is_some_and(|text| text.contains("file_path")));
+            assert_eq!(invocation_id.as_deref(), Some("tu_001"));
         }
     }
 
@@ -569,7 +1172,7 @@ mod tests {
 
     #[test]
     fn map_tool_result() {
-        let input = r#"{"type":"tool_result","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","tool":"Read","id":"tr_001","status":"success","result":"file contents"}"#;
+        let input = r#"{"type":"tool_result","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","tool":"Read","id":"tr_001","tool_use_id":"tu_001","status":"success","result":"file contents"}"#;
         let events = parse_cassette(Cursor::new(input));
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].tier, Tier::A);
@@ -581,11 +1184,13 @@ mod tests {
             tool,
             result,
             status,
+            invocation_id,
         } = &events[0].payload
         {
             assert_eq!(tool, "Read");
             assert_eq!(result.as_deref(), Some("file contents"));
             assert_eq!(status.as_deref(), Some("success"));
+            assert_eq!(invocation_id.as_deref(), Some("tu_001"));
         }
     }
 
@@ -626,6 +1231,91 @@ mod tests {
         }
     }
 
+    #[test]
+    fn map_token_usage() {
+        let input = r#"{"type":"token_usage","session_id":"s1","timestamp":"2026-02-16T10:00:06Z","model":"model-large","input_tokens":1200,"output_tokens":345}
+{"type":"token_usage","session_id":"s1","timestamp":"2026-02-16T10:00:07Z","model":"model-large"}"#;
+        let events = parse_cassette(Cursor::new(input));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].tier, Tier::A);
+        assert_eq!(
+            events[0].payload,
+            EventPayload::TokenUsage {
+                model: "model-large".into(),
+                input_tokens: 1200,
+                output_tokens: 345,
+            }
+        );
+        assert!(matches!(
+            &events[1].payload,
+            EventPayload::TokenUsage {
+                input_tokens: 0,
+                output_tokens: 0,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn map_subagent_spawn_defaults_parent_to_session() {
+        let input = r#"{"type":"subagent_spawn","session_id":"s1","timestamp":"2026-02-16T10:00:08Z","child_session_id":"s1-sub1","agent":"researcher"}
+{"type":"subagent_spawn","session_id":"s1","timestamp":"2026-02-16T10:00:09Z","parent_session_id":"s1-sub1","child_session_id":"s1-sub2","agent":"reviewer"}"#;
+        let events = parse_cassette(Cursor::new(input));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].tier, Tier::A);
+        assert_eq!(
+            events[0].payload,
+            EventPayload::SubagentSpawned {
+                parent_run_id: "s1".into(),
+                child_run_id: "s1-sub1".into(),
+                agent: "researcher".into(),
+            }
+        );
+        assert!(matches!(
+            &events[1].payload,
+            EventPayload::SubagentSpawned { parent_run_id, .. } if parent_run_id == "s1-sub1"
+        ));
+    }
+
+    #[test]
+    fn map_policy_decision() {
+        let input = r#"{"type":"policy_decision","session_id":"s1","timestamp":"2026-02-16T10:00:10Z","from_level":"L0","to_level":"L2","trigger":"queue_pressure","queue_pressure":0.85}
+{"type":"policy_decision","session_id":"s1","timestamp":"2026-02-16T10:00:11Z","to_level":"L0"}"#;
+        let events = parse_cassette(Cursor::new(input));
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].tier, Tier::A);
+        assert_eq!(
+            events[0].payload,
+            EventPayload::PolicyDecision {
+                from_level: "L0".into(),
+                to_level: "L2".into(),
+                trigger: "queue_pressure".into(),
+                queue_pressure: 0.85,
+            }
+        );
+        assert_eq!(
+            events[1].payload,
+            EventPayload::PolicyDecision {
+                from_level: "unknown".into(),
+                to_level: "L0".into(),
+                trigger: "unknown".into(),
+                queue_pressure: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn redaction_record_is_not_trusted() {
+        let input = r#"{"type":"redaction","session_id":"s1","timestamp":"2026-02-16T10:00:10Z","target_id":"tu_001","field_path":"payload","reason":"secret"}"#;
+        let events = parse_cassette(Cursor::new(input));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].tier, Tier::B);
+        assert!(matches!(
+            &events[0].payload,
+            EventPayload::Generic { event_type, .. } if event_type == "redaction"
+        ));
+    }
+
     #[test]
     fn map_unknown_type_to_generic() {
         let input = r#"{"type":"heartbeat","session_id":"s1","timestamp":"2026-02-16T10:00:00Z"}"#;
@@ -685,6 +1375,10 @@ mod tests {
     // Timestamp parsing tests
     // -------------------------------------------------------------------
 
+    fn parse_timestamp_ns(ts_str: Option<&str>) -> u64 {
+        parse_timestamp_value(ts_str.map(|s| json!(s)).as_ref())
+    }
+
     #[test]
     fn parse_timestamp_with_millis() {
         let ns = parse_timestamp_ns(Some("2026-02-16T10:00:01.500Z"));
@@ -726,6 +1420,180 @@ mod tests {
         assert_eq!(with_invalid_fraction, with_no_fraction);
     }
 
+    #[test]
+    fn parse_timestamp_forms_table() {
+        // 2025-01-01T00:00:00Z
+        const BASE_NS: u64 = 1_735_689_600_000_000_000;
+        let cases: &[(serde_json::Value, u64)] = &[
+            // RFC 3339, UTC designator.
+            (json!("2025-01-01T00:00:00Z"), BASE_NS),
+            (json!("2025-01-01T00:00:00.123Z"), BASE_NS + 123_000_000),
+            (
+                json!("2025-01-01T00:00:00.123456789Z"),
+                BASE_NS + 123_456_789,
+            ),
+            (json!("2025-01-01t00:00:00z"), BASE_NS),
+            // RFC 3339, offsets normalized to UTC.
+            (json!("2025-01-01T02:00:00+02:00"), BASE_NS),
+            (json!("2024-12-31T19:00:00-05:00"), BASE_NS),
+            (json!("2025-01-01T05:30:00.5+05:30"), BASE_NS + 500_000_000),
+            (json!("2025-01-01T02:00:00+0200"), BASE_NS),
+            (json!("2025-01-01T02:00:00+02"), BASE_NS),
+            // Integer epoch seconds.
+            (json!(1_735_689_600_u64), BASE_NS),
+            (json!("1735689600"), BASE_NS),
+            // Integer epoch millis.
+            (json!(1_735_689_600_123_u64), BASE_NS + 123_000_000),
+            (json!("1735689600123"), BASE_NS + 123_000_000),
+            // Float epoch millis and seconds.
+            (json!(1_735_689_600_123.5_f64), BASE_NS + 123_500_000),
+            (json!(1_735_689_600.25_f64), BASE_NS + 250_000_000),
+            // Below the cutoff is seconds; at the cutoff is millis.
+            (json!(10_000_000_000_u64), 10_000_000_000 * 1_000_000_000),
+            (json!(EPOCH_MILLIS_CUTOFF), EPOCH_MILLIS_CUTOFF * 1_000_000),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                parse_timestamp_value(Some(input)),
+                *expected,
+                "timestamp {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_unsupported_forms() {
+        let cases = [
+            json!("2025-01-01T00:00:00"),
+            json!("2025-01-01T00:00:00+25:00"),
+            json!("1970-01-01T00:00:00+01:00"),
+            json!("2025-01-01T24:00:00Z"),
+            json!(-5),
+            // Seconds below the cutoff but past the u64 nanosecond range.
+            json!(EPOCH_MILLIS_CUTOFF - 1),
+            json!(1.5e300),
+            json!(true),
+            serde_json::Value::Null,
+        ];
+        for input in &cases {
+            assert_eq!(parse_timestamp_value(Some(input)), 0, "timestamp {input}");
+        }
+        assert_eq!(parse_timestamp_value(None), 0);
+    }
+
+    #[test]
+    fn timestamp_range_boundaries() {
+        let horizon = DEFAULT_TIMESTAMP_HORIZON_NS;
+        let in_range = [
+            json!("1970-01-01T00:00:00Z"),
+            json!(0),
+            json!("2200-01-01T00:00:00Z"),
+            json!("2199-12-31T23:59:59.999999999Z"),
+        ];
+        for input in &in_range {
+            let ns = timestamp_value_ns(Some(input)).unwrap();
+            assert_eq!(timestamp_range_violation(ns, horizon), None, "{input}");
+        }
+        assert_eq!(
+            timestamp_value_ns(Some(&json!("2200-01-01T00:00:00Z"))),
+            Some(i128::from(horizon))
+        );
+
+        let before_epoch = [
+            json!("1969-12-31T23:59:59.999999999Z"),
+            json!("1970-01-01T00:00:00+01:00"),
+            json!(-5),
+        ];
+        for input in &before_epoch {
+            let ns = timestamp_value_ns(Some(input)).unwrap();
+            let reason = timestamp_range_violation(ns, horizon).unwrap();
+            assert!(reason.contains("before the Unix epoch"), "{input}");
+        }
+
+        let past_horizon = [
+            json!("2200-01-01T00:00:00.000000001Z"),
+            json!("9999-12-31T23:59:59Z"),
+            json!(EPOCH_MILLIS_CUTOFF - 1),
+        ];
+        for input in &past_horizon {
+            let ns = timestamp_value_ns(Some(input)).unwrap();
+            let reason = timestamp_range_violation(ns, horizon).unwrap();
+            assert!(reason.contains("past the timestamp horizon"), "{input}");
+        }
+    }
+
+    #[test]
+    fn out_of_range_timestamp_is_clamped_with_warning() {
+        let input = r#"{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"s1","timestamp":"1969-12-31T23:59:59Z","tool":"Read"}
+{"type":"tool_use","session_id":"s1","timestamp":"2500-01-01T00:00:00Z","tool":"Write"}
+{"type":"session_end","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","exit_code":0}
+"#;
+        let start_ns = parse_timestamp_ns(Some("2026-02-16T10:00:00Z"));
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        assert_eq!(parsed.events.len(), 6);
+
+        for (record, warning) in [(1, 2), (3, 4)] {
+            assert!(matches!(
+                parsed.events[record].payload,
+                EventPayload::ToolCall { .. }
+            ));
+            assert_eq!(parsed.events[record].timestamp_ns, start_ns);
+            match &parsed.events[warning].payload {
+                EventPayload::Error {
+                    message, severity, ..
+                } => {
+                    assert!(message.starts_with("Timestamp out of range at line"));
+                    assert_eq!(severity.as_deref(), Some("warning"));
+                }
+                other => panic!("expected warning Error, got {other:?}"),
+            }
+            assert_eq!(parsed.events[warning].run_id, "s1");
+            assert_eq!(parsed.events[warning].timestamp_ns, start_ns);
+        }
+
+        let warning = parsed
+            .report
+            .warnings
+            .iter()
+            .find(|w| w.code == "timestamp_out_of_range")
+            .expect("timestamp warning");
+        assert_eq!(warning.count, 2);
+        assert_eq!(
+            parsed.report.max_timestamp_ns,
+            Some(start_ns + 1_000_000_000)
+        );
+
+        let errors = parse_cassette_strict(Cursor::new(input)).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("line 2") && errors[0].contains("before the Unix epoch"));
+        assert!(errors[1].contains("line 3") && errors[1].contains("past the timestamp horizon"));
+    }
+
+    #[test]
+    fn timestamp_horizon_is_configurable() {
+        let line = r#"{"type":"tool_use","session_id":"s1","timestamp":"2100-01-01T00:00:00Z","tool":"Read"}"#;
+        let horizon = parse_timestamp_ns(Some("2050-01-01T00:00:00Z"));
+        let mut parser = CassetteParser::new().with_timestamp_horizon(horizon);
+        let events = parser.push_line(1, line);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].timestamp_ns, 0);
+
+        let mut parser = CassetteParser::new();
+        assert_eq!(parser.push_line(1, line).len(), 1);
+        assert!(parse_cassette_strict(Cursor::new(line)).is_ok());
+    }
+
+    #[test]
+    fn numeric_timestamp_record_is_not_malformed() {
+        let input =
+            r#"{"type":"tool_use","session_id":"s1","timestamp":1735689600123,"tool":"Read"}"#;
+        let events = parse_cassette(Cursor::new(input));
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0].payload, EventPayload::ToolCall { .. }));
+        assert_eq!(events[0].timestamp_ns, 1_735_689_600_123_000_000);
+    }
+
     // -------------------------------------------------------------------
     // Fixture parsing test
     // -------------------------------------------------------------------
@@ -770,4 +1638,104 @@ mod tests {
             assert_eq!(event.run_id, "sess-001");
         }
     }
+
+    // -------------------------------------------------------------------
+    // Multi-session splitting
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn interleaved_sessions_keep_distinct_run_ids() {
+        let fixture = include_str!("../../../fixtures/multi-session.jsonl");
+        let parsed = parse_cassette_with_report(Cursor::new(fixture));
+
+        assert_eq!(parsed.events.len(), 11);
+        assert!(parsed.report.warnings.is_empty());
+        let run_ids: Vec<&str> = parsed.events.iter().map(|e| e.run_id.as_str()).collect();
+        assert_eq!(
+            run_ids,
+            vec![
+                "sess-alpha",
+                "sess-alpha",
+                "sess-beta",
+                "sess-alpha",
+                "sess-beta",
+                "sess-alpha",
+                "sess-beta",
+                "sess-alpha",
+                "sess-beta",
+                "sess-alpha",
+                "sess-beta",
+            ]
+        );
+    }
+
+    #[test]
+    fn events_before_session_start_go_to_orphan_run() {
+        let input = r#"{"type":"tool_use","session_id":"s2","timestamp":"2026-02-16T10:00:00Z","tool":"Read"}
+{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","agent":"test"}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","tool":"Bash"}
+{"type":"tool_result","session_id":"s2","timestamp":"2026-02-16T10:00:03Z","tool":"Read","status":"success"}
+"#;
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        let run_ids: Vec<&str> = parsed.events.iter().map(|e| e.run_id.as_str()).collect();
+        assert_eq!(run_ids, vec!["orphan-s2", "s1", "s1", "orphan-s2"]);
+
+        assert_eq!(parsed.report.warnings.len(), 1);
+        assert_eq!(parsed.report.warnings[0].code, "orphan_session");
+        assert_eq!(parsed.report.warnings[0].count, 2);
+        assert!(parsed.report.warnings[0].message.contains("orphan-s2"));
+
+        // parse_cassette yields the same event stream.
+        assert_eq!(parse_cassette(Cursor::new(input)), parsed.events);
+    }
+
+    // -------------------------------------------------------------------
+    // Import report
+    // -------------------------------------------------------------------
+
+    #[test]
+    fn report_counts_parsed_skipped_and_bounds() {
+        let input = r#"{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","agent":"test"}
+
+not json
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","tool":"Read"}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:05Z","tool":"Read","commit_index":3}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:03Z","tool":"Bash"}
+"#;
+        let parsed = parse_cassette_with_report(Cursor::new(input));
+        let report = &parsed.report;
+
+        assert_eq!(report.source_id, SOURCE_ID);
+        assert_eq!(report.lines_read, 6);
+        assert_eq!(report.events_emitted, 5);
+        assert_eq!(report.parsed_by_type.get("session_start"), Some(&1));
+        assert_eq!(report.parsed_by_type.get("tool_use"), Some(&2));
+        assert_eq!(report.skipped.get(SKIP_BLANK_LINE), Some(&1));
+        assert_eq!(report.skipped.get(SKIP_MALFORMED_JSON), Some(&1));
+        assert_eq!(report.skipped.get(SKIP_CONTRACT_VIOLATION), Some(&1));
+        assert_eq!(report.skipped_total(), 3);
+        assert_eq!(
+            report.min_timestamp_ns,
+            Some(parse_timestamp_ns(Some("2026-02-16T10:00:01Z")))
+        );
+        assert_eq!(
+            report.max_timestamp_ns,
+            Some(parse_timestamp_ns(Some("2026-02-16T10:00:03Z")))
+        );
+        let run_ids: Vec<&str> = report.run_ids.iter().map(String::as_str).collect();
+        assert_eq!(run_ids, vec!["s1", "unknown-session"]);
+    }
+
+    #[test]
+    fn report_is_deterministic_for_fixture() {
+        let fixture = include_str!("../../../fixtures/multi-session.jsonl");
+        let first = parse_cassette_with_report(Cursor::new(fixture)).report;
+        let second = parse_cassette_with_report(Cursor::new(fixture)).report;
+        assert_eq!(
+            first.to_json_pretty().unwrap(),
+            second.to_json_pretty().unwrap()
+        );
+        assert_eq!(first.lines_read, 11);
+        assert_eq!(first.skipped_total(), 0);
+    }
 }
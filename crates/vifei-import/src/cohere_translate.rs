@@ -156,6 +156,7 @@ fn map_payload(
                 tool: "translate".to_string(),
                 result: record.translated_text.clone(),
                 status: Some("success".to_string()),
+                invocation_id: None,
             },
             Tier::A,
         ),
@@ -0,0 +1,460 @@
+//! Deterministic synthetic cassette fixtures for capacity testing.
+//!
+//! # Overview
+//!
+//! A [`FixtureSpec`] describes the shape of a recording: number of sessions
+//! and tool calls, tool mix, error rate, payload sizes, and where to inject
+//! clock skew. [`write_fixture`] renders it as Agent Cassette JSONL and
+//! [`generate_fixture`] returns the [`ImportEvent`] values that importing
+//! that JSONL yields, so both views always agree.
+//!
+//! # Determinism
+//!
+//! Every random choice comes from a xorshift64 PRNG seeded with
+//! [`FixtureSpec::seed`]. No wall clock, hash-map order, or platform state
+//! is consulted, so the same spec yields identical bytes on every machine.
+//!
+//! # Spec file
+//!
+//! ```json
+//! {
+//!   "seed": 42,
+//!   "sessions": 4,
+//!   "tool_calls": 1000,
+//!   "tool_mix": { "Read": 5, "Bash": 3, "Edit": 2 },
+//!   "error_rate": 0.05,
+//!   "skew_at": [100, 700],
+//!   "payload_bytes": { "min": 16, "max": 4096 }
+//! }
+//! ```
+//!
+//! Omitted keys keep their [`FixtureSpec::default`] values. Tool calls are
+//! split across sessions as evenly as possible, earlier sessions taking the
+//! remainder. A recording has `2 * sessions + 2 * tool_calls` events plus
+//! one `error` record per failed call.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use vifei_core::event::ImportEvent;
+
+use crate::cassette::parse_cassette;
+
+/// Inclusive byte length range for generated tool result text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ByteRange {
+    /// Smallest result length in bytes.
+    pub min: usize,
+    /// Largest result length in bytes.
+    pub max: usize,
+}
+
+/// Shape of a synthetic recording.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FixtureSpec {
+    /// PRNG seed; the only source of variation between fixtures.
+    pub seed: u64,
+    /// Number of sessions (`session_start` .. `session_end` pairs).
+    pub sessions: u32,
+    /// Total tool call / result pairs across all sessions.
+    pub tool_calls: u64,
+    /// Relative weight of each tool name.
+    pub tool_mix: BTreeMap<String, u32>,
+    /// Probability in `[0, 1]` that a tool call fails and emits an `error`.
+    pub error_rate: f64,
+    /// Zero-based tool call ordinals whose result is stamped before the
+    /// call, by [`skew_backward_ms`](Self::skew_backward_ms).
+    pub skew_at: Vec<u64>,
+    /// How far an injected skew moves the result timestamp backward.
+    pub skew_backward_ms: u64,
+    /// Tool result text length range.
+    pub payload_bytes: ByteRange,
+    /// Timestamp of the first record, in epoch milliseconds.
+    pub start_timestamp_ms: u64,
+}
+
+impl Default for FixtureSpec {
+    fn default() -> Self {
+        let tool_mix = [
+            ("Read", 4),
+            ("Edit", 2),
+            ("Bash", 2),
+            ("Grep", 1),
+            ("Write", 1),
+        ]
+        .into_iter()
+        .map(|(tool, weight)| (tool.to_string(), weight))
+        .collect();
+        FixtureSpec {
+            seed: 0,
+            sessions: 1,
+            tool_calls: 100,
+            tool_mix,
+            error_rate: 0.0,
+            skew_at: Vec::new(),
+            skew_backward_ms: 2_000,
+            payload_bytes: ByteRange { min: 2, max: 512 },
+            // 2026-01-15T00:00:00Z
+            start_timestamp_ms: 1_768_435_200_000,
+        }
+    }
+}
+
+impl FixtureSpec {
+    /// Load and validate a spec from a JSON file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let spec: FixtureSpec = serde_json::from_str(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid fixture spec {}: {e}", path.display()),
+            )
+        })?;
+        spec.validate().map_err(|msg| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid fixture spec {}: {msg}", path.display()),
+            )
+        })?;
+        Ok(spec)
+    }
+
+    /// Check value ranges. Generation clamps whatever this rejects.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.sessions == 0 {
+            return Err("sessions must be at least 1".to_string());
+        }
+        if self.tool_mix.values().all(|weight| *weight == 0) {
+            return Err("tool_mix needs at least one tool with a nonzero weight".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.error_rate) {
+            return Err(format!("error_rate {} is outside [0, 1]", self.error_rate));
+        }
+        if self.payload_bytes.min > self.payload_bytes.max {
+            return Err(format!(
+                "payload_bytes.min {} exceeds max {}",
+                self.payload_bytes.min, self.payload_bytes.max
+            ));
+        }
+        if let Some(ordinal) = self.skew_at.iter().find(|o| **o >= self.tool_calls) {
+            return Err(format!(
+                "skew_at ordinal {ordinal} is past the last tool call ({})",
+                self.tool_calls
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Write the spec's recording to `writer` as Agent Cassette JSONL.
+///
+/// Returns the number of lines written.
+pub fn write_fixture<W: Write>(spec: &FixtureSpec, mut writer: W) -> io::Result<u64> {
+    let records = fixture_records(spec);
+    for record in &records {
+        writeln!(writer, "{record}")?;
+    }
+    writer.flush()?;
+    Ok(records.len() as u64)
+}
+
+/// Generate the spec's recording as import events.
+///
+/// Identical to importing the output of [`write_fixture`].
+pub fn generate_fixture(spec: &FixtureSpec) -> Vec<ImportEvent> {
+    let mut bytes = Vec::new();
+    write_fixture(spec, &mut bytes).expect("writing to a Vec cannot fail");
+    parse_cassette(bytes.as_slice())
+}
+
+/// Xorshift64 PRNG — deterministic, no external dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // Xorshift never leaves the all-zero state, so remap seed 0.
+        Rng(if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        })
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Random value in `[min, max]`.
+    fn range_inclusive(&mut self, min: u64, max: u64) -> u64 {
+        match (max - min).checked_add(1) {
+            Some(span) => min + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// True with probability `p` (resolution 1e-6).
+    fn chance(&mut self, p: f64) -> bool {
+        let threshold = (p.clamp(0.0, 1.0) * 1_000_000.0) as u64;
+        self.next_u64() % 1_000_000 < threshold
+    }
+}
+
+/// Weighted tool table with cumulative weights.
+struct ToolTable {
+    tools: Vec<(String, u64)>,
+    total: u64,
+}
+
+impl ToolTable {
+    fn new(mix: &BTreeMap<String, u32>) -> Self {
+        let mut total = 0;
+        let mut tools = Vec::new();
+        for (tool, weight) in mix.iter().filter(|(_, w)| **w > 0) {
+            total += u64::from(*weight);
+            tools.push((tool.clone(), total));
+        }
+        if tools.is_empty() {
+            tools.push(("Bash".to_string(), 1));
+            total = 1;
+        }
+        ToolTable { tools, total }
+    }
+
+    fn pick(&self, rng: &mut Rng) -> &str {
+        let roll = rng.next_u64() % self.total;
+        let idx = self
+            .tools
+            .partition_point(|(_, cumulative)| *cumulative <= roll);
+        &self.tools[idx].0
+    }
+}
+
+fn fixture_records(spec: &FixtureSpec) -> Vec<Value> {
+    let mut rng = Rng::new(spec.seed);
+    let tools = ToolTable::new(&spec.tool_mix);
+    let (min_bytes, max_bytes) = (
+        spec.payload_bytes.min.min(spec.payload_bytes.max),
+        spec.payload_bytes.max.max(spec.payload_bytes.min),
+    );
+    let sessions = u64::from(spec.sessions.max(1));
+    let mut now_ms = spec.start_timestamp_ms;
+    let mut ordinal = 0u64;
+    let mut records = Vec::new();
+
+    for session in 0..sessions {
+        let session_id = format!("fixture-{session:03}");
+        let calls = spec.tool_calls / sessions + u64::from(session < spec.tool_calls % sessions);
+        let mut failures = 0u64;
+
+        records.push(json!({
+            "type": "session_start",
+            "session_id": session_id,
+            "timestamp": now_ms,
+            "agent": "fixture-generator",
+        }));
+        now_ms += rng.range_inclusive(100, 1_000);
+
+        for call in 0..calls {
+            let tool = tools.pick(&mut rng).to_string();
+            let call_id = format!("tu_{session:03}_{call:05}");
+            let call_ms = now_ms;
+            records.push(json!({
+                "type": "tool_use",
+                "session_id": session_id,
+                "timestamp": call_ms,
+                "tool": tool,
+                "id": call_id,
+                "args": { "n": ordinal },
+            }));
+            now_ms += rng.range_inclusive(10, 2_000);
+
+            let failed = rng.chance(spec.error_rate);
+            let len = rng.range_inclusive(min_bytes as u64, max_bytes as u64) as usize;
+            let result_ms = if spec.skew_at.contains(&ordinal) {
+                call_ms.saturating_sub(spec.skew_backward_ms)
+            } else {
+                now_ms
+            };
+            records.push(json!({
+                "type": "tool_result",
+                "session_id": session_id,
+                "timestamp": result_ms,
+                "tool": tool,
+                "id": format!("tr_{session:03}_{call:05}"),
+                "tool_use_id": call_id,
+                "status": if failed { "error" } else { "success" },
+                "result": filler(&mut rng, len),
+            }));
+            now_ms += rng.range_inclusive(10, 500);
+
+            if failed {
+                failures += 1;
+                records.push(json!({
+                    "type": "error",
+                    "session_id": session_id,
+                    "timestamp": now_ms,
+                    "id": format!("err_{session:03}_{call:05}"),
+                    "kind": "tool",
+                    "message": format!("{tool} failed"),
+                    "severity": "error",
+                }));
+                now_ms += rng.range_inclusive(10, 200);
+            }
+            ordinal += 1;
+        }
+
+        records.push(json!({
+            "type": "session_end",
+            "session_id": session_id,
+            "timestamp": now_ms,
+            "exit_code": i32::from(failures > 0),
+        }));
+        now_ms += rng.range_inclusive(1_000, 10_000);
+    }
+    records
+}
+
+/// `len` bytes of lowercase ASCII text broken into short words.
+fn filler(rng: &mut Rng, len: usize) -> String {
+    let mut text = String::with_capacity(len);
+    while text.len() < len {
+        if !text.is_empty() && rng.next_u64().is_multiple_of(6) {
+            text.push(' ');
+        } else {
+            text.push(char::from(b'a' + (rng.next_u64() % 26) as u8));
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vifei_core::event::EventPayload;
+
+    fn spec() -> FixtureSpec {
+        FixtureSpec {
+            seed: 7,
+            sessions: 3,
+            tool_calls: 50,
+            error_rate: 0.2,
+            skew_at: vec![10],
+            payload_bytes: ByteRange { min: 8, max: 64 },
+            ..FixtureSpec::default()
+        }
+    }
+
+    fn render(spec: &FixtureSpec) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        write_fixture(spec, &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn same_spec_yields_identical_bytes() {
+        assert_eq!(render(&spec()), render(&spec()));
+        let other = FixtureSpec { seed: 8, ..spec() };
+        assert_ne!(render(&spec()), render(&other));
+    }
+
+    #[test]
+    fn counts_follow_the_spec() {
+        let events = generate_fixture(&spec());
+        let count =
+            |pred: fn(&EventPayload) -> bool| events.iter().filter(|e| pred(&e.payload)).count();
+        assert_eq!(count(|p| matches!(p, EventPayload::RunStart { .. })), 3);
+        assert_eq!(count(|p| matches!(p, EventPayload::RunEnd { .. })), 3);
+        assert_eq!(count(|p| matches!(p, EventPayload::ToolCall { .. })), 50);
+        let errors = count(|p| matches!(p, EventPayload::Error { .. }));
+        let failed = events
+            .iter()
+            .filter(|e| {
+                matches!(&e.payload, EventPayload::ToolResult { status, .. }
+                    if status.as_deref() == Some("error"))
+            })
+            .count();
+        assert_eq!(errors, failed);
+        assert!(errors > 0);
+        assert_eq!(events.len(), 3 * 2 + 50 * 2 + errors);
+    }
+
+    #[test]
+    fn payload_lengths_stay_in_range() {
+        for event in generate_fixture(&spec()) {
+            if let EventPayload::ToolResult {
+                result: Some(text), ..
+            } = &event.payload
+            {
+                assert!((8..=64).contains(&text.len()), "{}", text.len());
+            }
+        }
+    }
+
+    #[test]
+    fn skew_point_moves_result_before_its_call() {
+        let events = generate_fixture(&spec());
+        let calls: Vec<_> = events
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| matches!(e.payload, EventPayload::ToolCall { .. }))
+            .collect();
+        let (idx, call) = calls[10];
+        let result = &events[idx + 1];
+        assert!(matches!(result.payload, EventPayload::ToolResult { .. }));
+        assert_eq!(result.timestamp_ns + 2_000_000_000, call.timestamp_ns);
+    }
+
+    #[test]
+    fn tool_mix_weights_are_honored() {
+        let only_read = FixtureSpec {
+            tool_mix: [("Read".to_string(), 1), ("Bash".to_string(), 0)]
+                .into_iter()
+                .collect(),
+            ..spec()
+        };
+        assert!(generate_fixture(&only_read)
+            .iter()
+            .all(|e| match &e.payload {
+                EventPayload::ToolCall { tool, .. } => tool == "Read",
+                _ => true,
+            }));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_values() {
+        assert!(spec().validate().is_ok());
+        assert!(FixtureSpec {
+            error_rate: 1.5,
+            ..spec()
+        }
+        .validate()
+        .is_err());
+        assert!(FixtureSpec {
+            sessions: 0,
+            ..spec()
+        }
+        .validate()
+        .is_err());
+        assert!(FixtureSpec {
+            skew_at: vec![50],
+            ..spec()
+        }
+        .validate()
+        .is_err());
+        let inverted = ByteRange { min: 10, max: 1 };
+        assert!(FixtureSpec {
+            payload_bytes: inverted,
+            ..spec()
+        }
+        .validate()
+        .is_err());
+    }
+}
@@ -0,0 +1,232 @@
+//! Follow mode -- import a cassette that is still being written.
+//!
+//! # Overview
+//!
+//! [`CassetteFollower`] tails a growing Agent Cassette file, like `tail -f`.
+//! Each [`CassetteFollower::poll`] reads the bytes appended since the
+//! previous poll, starting at the remembered byte offset, and maps every
+//! newly completed line with the same rules as
+//! [`parse_cassette_with_report`](crate::cassette::parse_cassette_with_report).
+//! Once a recording is finished, following it yields the same events as a
+//! one-shot import of the file.
+//!
+//! # Partial lines
+//!
+//! A trailing line without its `\n` is buffered, not parsed, until the
+//! newline arrives, so a record is never mapped half-written. When following
+//! stops, [`CassetteFollower::drain_unterminated`] imports a final line that
+//! was never terminated, but only if it is already a complete JSON record.
+//!
+//! # Truncation
+//!
+//! The source is treated as append-only. If the file shrinks below the
+//! consumed offset, [`CassetteFollower::poll`] returns an `InvalidData`
+//! error instead of re-reading from an unknown position.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+use std::time::Duration;
+
+use vifei_core::event::ImportEvent;
+
+use crate::cassette::CassetteParser;
+use crate::lines::decode_line;
+use crate::mapping::Mapping;
+use crate::report::ImportReport;
+
+/// Default delay between polls when no new data arrived.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Polling reader that maps lines appended to a cassette file.
+#[derive(Debug)]
+pub struct CassetteFollower {
+    file: File,
+    offset: u64,
+    partial: Vec<u8>,
+    line_num: usize,
+    parser: CassetteParser,
+}
+
+impl CassetteFollower {
+    /// Open `path` for following from its first byte.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Ok(CassetteFollower {
+            file: File::open(path)?,
+            offset: 0,
+            partial: Vec::new(),
+            line_num: 0,
+            parser: CassetteParser::new(),
+        })
+    }
+
+    /// Read fields according to `mapping` (see [`crate::mapping`]).
+    ///
+    /// Call before the first poll.
+    pub fn with_mapping(mut self, mapping: Mapping) -> Self {
+        self.parser = CassetteParser::with_mapping(mapping);
+        self
+    }
+
+    /// Byte offset of the next unread byte in the source.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Bytes read but not yet parsed because their line is unterminated.
+    pub fn pending_bytes(&self) -> usize {
+        self.partial.len()
+    }
+
+    /// Read newly appended bytes and map every completed line.
+    ///
+    /// Returns an empty vector when nothing new was completed.
+    pub fn poll(&mut self) -> io::Result<Vec<ImportEvent>> {
+        let len = self.file.metadata()?.len();
+        if len < self.offset {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "source shrank from {} to {len} bytes while following",
+                    self.offset
+                ),
+            ));
+        }
+        if len == self.offset {
+            return Ok(Vec::new());
+        }
+
+        self.file.seek(SeekFrom::Start(self.offset))?;
+        let read = (&mut self.file)
+            .take(len - self.offset)
+            .read_to_end(&mut self.partial)?;
+        self.offset += read as u64;
+
+        let mut events = Vec::new();
+        let mut consumed = 0;
+        while let Some(pos) = self.partial[consumed..].iter().position(|&b| b == b'\n') {
+            let end = consumed + pos + 1;
+            self.line_num += 1;
+            let line = decode_line(&self.partial[consumed..end], self.line_num);
+            events.extend(self.parser.push_line_result(self.line_num, line));
+            consumed = end;
+        }
+        self.partial.drain(..consumed);
+        Ok(events)
+    }
+
+    /// Map the buffered unterminated line if it is a complete JSON record.
+    ///
+    /// Call once when following stops. An incomplete record stays buffered
+    /// and is still counted by [`pending_bytes`](Self::pending_bytes).
+    pub fn drain_unterminated(&mut self) -> Vec<ImportEvent> {
+        let Ok(text) = decode_line(&self.partial, self.line_num + 1) else {
+            return Vec::new();
+        };
+        let record = text.trim();
+        let record = record.strip_suffix(',').unwrap_or(record);
+        if record.is_empty() || serde_json::from_str::<serde_json::Value>(record).is_err() {
+            return Vec::new();
+        }
+        self.partial.clear();
+        self.line_num += 1;
+        self.parser.push_line(self.line_num, &text)
+    }
+
+    /// Stop following and return the import report for all mapped lines.
+    pub fn finish(self) -> ImportReport {
+        self.parser.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cassette::parse_cassette_with_report;
+    use std::fs::OpenOptions;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    const START: &str = r#"{"type":"session_start","session_id":"s1","timestamp":"2026-01-01T00:00:00Z","agent":"test"}"#;
+    const END: &str = r#"{"type":"session_end","session_id":"s1","timestamp":"2026-01-01T00:00:01Z","exit_code":0}"#;
+
+    fn append(path: &Path, bytes: &[u8]) {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        file.write_all(bytes).unwrap();
+    }
+
+    #[test]
+    fn partial_line_waits_for_newline() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("live.jsonl");
+        let (head, tail) = START.split_at(20);
+        append(&path, head.as_bytes());
+
+        let mut follower = CassetteFollower::open(&path).unwrap();
+        assert!(follower.poll().unwrap().is_empty());
+        assert_eq!(follower.pending_bytes(), head.len());
+
+        append(&path, format!("{tail}\n").as_bytes());
+        let events = follower.poll().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].run_id, "s1");
+        assert_eq!(follower.pending_bytes(), 0);
+        assert_eq!(follower.offset(), (START.len() + 1) as u64);
+    }
+
+    #[test]
+    fn followed_fixture_matches_one_shot_import() {
+        let fixture = include_str!("../../../fixtures/small-session.jsonl");
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("live.jsonl");
+        append(&path, b"");
+
+        let mut follower = CassetteFollower::open(&path).unwrap();
+        let mut followed = Vec::new();
+        for line in fixture.split_inclusive('\n') {
+            append(&path, line.as_bytes());
+            followed.extend(follower.poll().unwrap());
+        }
+        followed.extend(follower.drain_unterminated());
+
+        let expected = parse_cassette_with_report(fixture.as_bytes());
+        assert_eq!(followed, expected.events);
+        assert_eq!(follower.finish(), expected.report);
+    }
+
+    #[test]
+    fn drain_unterminated_keeps_incomplete_record() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("live.jsonl");
+        append(&path, format!("{START}\n{}", &END[..10]).as_bytes());
+
+        let mut follower = CassetteFollower::open(&path).unwrap();
+        assert_eq!(follower.poll().unwrap().len(), 1);
+        assert!(follower.drain_unterminated().is_empty());
+        assert_eq!(follower.pending_bytes(), 10);
+
+        append(&path, &END.as_bytes()[10..]);
+        assert!(follower.poll().unwrap().is_empty());
+        let drained = follower.drain_unterminated();
+        assert_eq!(drained.len(), 1);
+        assert_eq!(drained[0].event_id, "cassette:1");
+        assert_eq!(follower.pending_bytes(), 0);
+    }
+
+    #[test]
+    fn shrinking_source_is_an_error() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("live.jsonl");
+        append(&path, format!("{START}\n").as_bytes());
+
+        let mut follower = CassetteFollower::open(&path).unwrap();
+        follower.poll().unwrap();
+        std::fs::write(&path, b"").unwrap();
+        let err = follower.poll().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -2,4 +2,11 @@ pub mod anthropic_messages;
 pub mod cassette;
 pub mod cohere_translate;
 pub mod contract;
+pub mod fixture;
+pub mod follow;
+mod lines;
+pub mod mapping;
+pub mod offload;
 pub mod openai_responses;
+pub mod otel;
+pub mod report;
@@ -0,0 +1,138 @@
+//! Encoding-tolerant JSONL line reader shared by importers.
+//!
+//! Recordings exported from Windows tooling often start with a UTF-8 byte
+//! order mark and end lines with CRLF. [`SourceLines`] normalizes both so
+//! the first record is not lost to a parse error, and reports lines that
+//! are not valid UTF-8 instead of failing the whole import.
+
+use std::io::{self, BufRead};
+
+/// UTF-8 byte order mark.
+const UTF8_BOM: &str = "\u{feff}";
+
+/// Why a physical line could not be turned into text.
+#[derive(Debug)]
+pub(crate) enum LineError {
+    /// The line bytes were not valid UTF-8. Reading continues.
+    InvalidUtf8(std::str::Utf8Error),
+    /// The underlying reader failed. Reading stops after this line.
+    Io(io::Error),
+}
+
+/// Iterator over `(line_number, text)` pairs of a JSONL stream.
+///
+/// Line numbers are 1-based physical line numbers. Text excludes the line
+/// terminator (`\n` or `\r\n`), and the first line has any leading BOM
+/// removed.
+pub(crate) struct SourceLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    line_num: usize,
+    done: bool,
+}
+
+impl<R: BufRead> SourceLines<R> {
+    pub(crate) fn new(reader: R) -> Self {
+        SourceLines {
+            reader,
+            buf: Vec::new(),
+            line_num: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for SourceLines<R> {
+    type Item = (usize, Result<String, LineError>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        self.buf.clear();
+        match self.reader.read_until(b'\n', &mut self.buf) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.done = true;
+                self.line_num += 1;
+                return Some((self.line_num, Err(LineError::Io(e))));
+            }
+        }
+        self.line_num += 1;
+        Some((self.line_num, decode_line(&self.buf, self.line_num)))
+    }
+}
+
+/// Decode one physical line: drop the `\n` / `\r\n` terminator, check
+/// UTF-8, and strip a leading BOM when `line_num` is 1.
+pub(crate) fn decode_line(bytes: &[u8], line_num: usize) -> Result<String, LineError> {
+    let mut bytes = bytes;
+    if let Some(rest) = bytes.strip_suffix(b"\n") {
+        bytes = rest;
+    }
+    if let Some(rest) = bytes.strip_suffix(b"\r") {
+        bytes = rest;
+    }
+    let text = std::str::from_utf8(bytes).map_err(LineError::InvalidUtf8)?;
+    let text = if line_num == 1 {
+        text.strip_prefix(UTF8_BOM).unwrap_or(text)
+    } else {
+        text
+    };
+    Ok(text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn collect(input: &[u8]) -> Vec<(usize, Result<String, String>)> {
+        SourceLines::new(Cursor::new(input.to_vec()))
+            .map(|(n, r)| {
+                (
+                    n,
+                    r.map_err(|e| match e {
+                        LineError::InvalidUtf8(_) => "utf8".to_string(),
+                        LineError::Io(_) => "io".to_string(),
+                    }),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn strips_bom_and_crlf() {
+        let lines = collect(b"\xEF\xBB\xBF{\"a\":1}\r\n{\"b\":2}\r\n");
+        assert_eq!(
+            lines,
+            vec![
+                (1, Ok("{\"a\":1}".to_string())),
+                (2, Ok("{\"b\":2}".to_string()))
+            ]
+        );
+    }
+
+    #[test]
+    fn bom_is_only_stripped_on_first_line() {
+        let lines = collect(b"x\n\xEF\xBB\xBFy");
+        assert_eq!(lines[1], (2, Ok("\u{feff}y".to_string())));
+    }
+
+    #[test]
+    fn invalid_utf8_is_reported_and_reading_continues() {
+        let lines = collect(b"ok\n\xFF\xFE\nafter\n");
+        assert_eq!(
+            lines,
+            vec![
+                (1, Ok("ok".to_string())),
+                (2, Err("utf8".to_string())),
+                (3, Ok("after".to_string())),
+            ]
+        );
+    }
+}
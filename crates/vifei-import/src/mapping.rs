@@ -0,0 +1,267 @@
+//! Field mapping for cassette-like JSONL with nonstandard field names.
+//!
+//! # Overview
+//!
+//! Some recorders emit records shaped like Agent Cassette lines but keep
+//! fields elsewhere, e.g. the tool name at `payload.tool_name` and the
+//! timestamp at `ts`. A [`Mapping`] names the source path of each field the
+//! cassette importer reads, plus a table from source record type to payload
+//! variant. [`Mapping::default`] is the standard cassette layout, so
+//! [`parse_cassette_with_mapping`](crate::cassette::parse_cassette_with_mapping)
+//! with the default mapping is exactly
+//! [`parse_cassette_with_report`](crate::cassette::parse_cassette_with_report).
+//!
+//! # Mapping file
+//!
+//! ```json
+//! {
+//!   "fields": { "timestamp": "ts", "tool": "payload.tool_name" },
+//!   "types": { "call": "tool_call", "return": "tool_result" }
+//! }
+//! ```
+//!
+//! Field paths are dot-separated object keys. Omitted fields keep their
+//! standard path. A `types` table replaces the default table entirely;
+//! record types missing from it import as `Generic` (Tier B). Fields not
+//! listed under `fields` (`agent`, `status`, `exit_code`, ...) are read
+//! from their standard top-level names. Unknown keys are rejected so a
+//! misspelled field does not silently fall back to the default.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Source field paths read by the cassette importer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FieldPaths {
+    /// Record type discriminator (standard: `type`).
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Session identifier, mapped to `run_id` (standard: `session_id`).
+    pub session_id: String,
+    /// Record timestamp (standard: `timestamp`).
+    pub timestamp: String,
+    /// Record identifier, mapped to `event_id` (standard: `id`).
+    pub id: String,
+    /// Tool name (standard: `tool`).
+    pub tool: String,
+    /// Tool call arguments (standard: `args`).
+    pub args: String,
+    /// Tool result (standard: `result`).
+    pub result: String,
+}
+
+impl Default for FieldPaths {
+    fn default() -> Self {
+        FieldPaths {
+            record_type: "type".to_string(),
+            session_id: "session_id".to_string(),
+            timestamp: "timestamp".to_string(),
+            id: "id".to_string(),
+            tool: "tool".to_string(),
+            args: "args".to_string(),
+            result: "result".to_string(),
+        }
+    }
+}
+
+impl FieldPaths {
+    /// `(standard key, source path)` pairs in declaration order.
+    fn pairs(&self) -> [(&'static str, &str); 7] {
+        [
+            ("type", &self.record_type),
+            ("session_id", &self.session_id),
+            ("timestamp", &self.timestamp),
+            ("id", &self.id),
+            ("tool", &self.tool),
+            ("args", &self.args),
+            ("result", &self.result),
+        ]
+    }
+}
+
+/// Payload variant a source record type maps to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayloadVariant {
+    /// `RunStart` (Tier A).
+    RunStart,
+    /// `RunEnd` (Tier A).
+    RunEnd,
+    /// `ToolCall` (Tier A).
+    ToolCall,
+    /// `ToolResult` (Tier A).
+    ToolResult,
+    /// `Error` (Tier A).
+    Error,
+    /// `TokenUsage` (Tier A).
+    TokenUsage,
+    /// `SubagentSpawned` (Tier A).
+    SubagentSpawned,
+    /// `PolicyDecision` (Tier A).
+    PolicyDecision,
+}
+
+/// Cassette field mapping configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Mapping {
+    /// Source path of each field the importer reads.
+    pub fields: FieldPaths,
+    /// Source record type to payload variant.
+    pub types: BTreeMap<String, PayloadVariant>,
+}
+
+impl Default for Mapping {
+    fn default() -> Self {
+        let types = [
+            ("session_start", PayloadVariant::RunStart),
+            ("session_end", PayloadVariant::RunEnd),
+            ("tool_use", PayloadVariant::ToolCall),
+            ("tool_result", PayloadVariant::ToolResult),
+            ("error", PayloadVariant::Error),
+            ("token_usage", PayloadVariant::TokenUsage),
+            ("subagent_spawn", PayloadVariant::SubagentSpawned),
+            ("policy_decision", PayloadVariant::PolicyDecision),
+        ]
+        .into_iter()
+        .map(|(name, variant)| (name.to_string(), variant))
+        .collect();
+        Mapping {
+            fields: FieldPaths::default(),
+            types,
+        }
+    }
+}
+
+impl Mapping {
+    /// Load a mapping from a JSON file.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        let mapping: Mapping = serde_json::from_str(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid mapping {}: {e}", path.display()),
+            )
+        })?;
+        if let Some((key, _)) = mapping
+            .fields
+            .pairs()
+            .into_iter()
+            .find(|(_, path)| path.split('.').any(str::is_empty))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "invalid mapping {}: field path for {key:?} has an empty segment",
+                    path.display()
+                ),
+            ));
+        }
+        Ok(mapping)
+    }
+
+    /// Payload variant for a source record type, or `None` for `Generic`.
+    pub fn variant_for(&self, record_type: &str) -> Option<PayloadVariant> {
+        self.types.get(record_type).copied()
+    }
+
+    /// True when every field is read from its standard path.
+    pub(crate) fn has_standard_fields(&self) -> bool {
+        self.fields == FieldPaths::default()
+    }
+
+    /// Rewrite a source record so mapped fields sit at their standard keys.
+    ///
+    /// Other top-level keys are kept. A standard key whose mapped path is
+    /// absent is removed. Non-object values are returned unchanged.
+    pub fn apply(&self, record: Value) -> Value {
+        let Value::Object(source) = &record else {
+            return record;
+        };
+        let mut out = source.clone();
+        for (key, path) in self.fields.pairs() {
+            match lookup(&record, path) {
+                Some(value) => {
+                    out.insert(key.to_string(), value.clone());
+                }
+                None => {
+                    out.remove(key);
+                }
+            }
+        }
+        Value::Object(out)
+    }
+}
+
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, key| current.get(key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn default_apply_is_identity_on_fixture_lines() {
+        let mapping = Mapping::default();
+        for fixture in [
+            include_str!("../../../fixtures/small-session.jsonl"),
+            include_str!("../../../fixtures/multi-session.jsonl"),
+        ] {
+            for line in fixture.lines().filter(|l| !l.trim().is_empty()) {
+                let value: Value = serde_json::from_str(line).unwrap();
+                assert_eq!(mapping.apply(value.clone()), value);
+            }
+        }
+    }
+
+    #[test]
+    fn apply_moves_nested_fields_to_standard_keys() {
+        let mapping: Mapping = serde_json::from_value(json!({
+            "fields": { "timestamp": "ts", "tool": "payload.tool_name" }
+        }))
+        .unwrap();
+        let record = json!({
+            "type": "tool_use",
+            "ts": 1_700_000_000,
+            "payload": { "tool_name": "Read" },
+            "tool": "shadowed"
+        });
+        let applied = mapping.apply(record);
+        assert_eq!(applied["timestamp"], 1_700_000_000);
+        assert_eq!(applied["tool"], "Read");
+        assert!(applied.get("args").is_none());
+    }
+
+    #[test]
+    fn types_table_replaces_defaults() {
+        let mapping: Mapping = serde_json::from_value(json!({
+            "types": { "call": "tool_call" }
+        }))
+        .unwrap();
+        assert_eq!(mapping.variant_for("call"), Some(PayloadVariant::ToolCall));
+        assert_eq!(mapping.variant_for("tool_use"), None);
+        assert_eq!(mapping.fields, FieldPaths::default());
+    }
+
+    #[test]
+    fn load_rejects_unknown_keys_and_empty_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let typo = dir.path().join("typo.json");
+        std::fs::write(&typo, r#"{"fields":{"timestmp":"ts"}}"#).unwrap();
+        let err = Mapping::load(&typo).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let empty = dir.path().join("empty.json");
+        std::fs::write(&empty, r#"{"fields":{"tool":"payload..name"}}"#).unwrap();
+        let err = Mapping::load(&empty).unwrap_err();
+        assert!(err.to_string().contains("empty segment"));
+    }
+}
@@ -0,0 +1,212 @@
+//! Oversized payload offload -- moves large inline text into the BlobStore.
+//!
+//! # Overview
+//!
+//! Recorded tool results can be megabytes long. Keeping them inline bloats
+//! the EventLog and slows every downstream read. After an importer produces
+//! [`ImportEvent`] values, [`offload_large_payloads`] writes each oversized
+//! `ToolCall::args` / `ToolResult::result` to the [`BlobStore`], sets the
+//! event's `payload_ref` to the blob hash, and replaces the inline text with
+//! a short preview.
+//!
+//! # Determinism
+//!
+//! The offload decision is a pure function of the text's byte length and the
+//! configured threshold. The preview is a fixed-size prefix cut at a UTF-8
+//! character boundary, and the blob ref is the BLAKE3 digest of the original
+//! bytes, so the same input always yields the same events and blobs.
+//!
+//! # Scope
+//!
+//! Events that already carry a `payload_ref` are left untouched; an event
+//! has at most one blobbed payload. Other payload variants are never
+//! offloaded.
+
+use std::io;
+
+use vifei_core::blob_store::BlobStore;
+use vifei_core::event::{EventPayload, ImportEvent};
+
+/// Default offload threshold in bytes. Payload text longer than this is
+/// moved to the blob store during import.
+pub const DEFAULT_OFFLOAD_THRESHOLD_BYTES: usize = 64 * 1024;
+
+/// Maximum bytes of original text kept inline as the preview.
+pub const OFFLOAD_PREVIEW_BYTES: usize = 256;
+
+/// Offload every oversized payload in `events` to `blob_store`.
+///
+/// Returns the number of events whose payload was offloaded.
+pub fn offload_large_payloads(
+    events: &mut [ImportEvent],
+    blob_store: &BlobStore,
+    threshold_bytes: usize,
+) -> io::Result<usize> {
+    let mut offloaded = 0;
+    for event in events.iter_mut() {
+        if offload_event(event, blob_store, threshold_bytes)? {
+            offloaded += 1;
+        }
+    }
+    Ok(offloaded)
+}
+
+/// Offload a single event's payload text if it exceeds `threshold_bytes`.
+///
+/// Returns `true` when the payload was written to the blob store.
+pub fn offload_event(
+    event: &mut ImportEvent,
+    blob_store: &BlobStore,
+    threshold_bytes: usize,
+) -> io::Result<bool> {
+    if !needs_offload(event, threshold_bytes) {
+        return Ok(false);
+    }
+    let text = match &mut event.payload {
+        EventPayload::ToolCall {
+            args: Some(text), ..
+        } => text,
+        EventPayload::ToolResult {
+            result: Some(text), ..
+        } => text,
+        _ => return Ok(false),
+    };
+
+    let payload_ref = blob_store.write_blob(text.as_bytes())?;
+    *text = payload_preview(text);
+    event.payload_ref = Some(payload_ref);
+    Ok(true)
+}
+
+/// Returns `true` when `event` carries payload text longer than
+/// `threshold_bytes` and no existing `payload_ref`.
+pub fn needs_offload(event: &ImportEvent, threshold_bytes: usize) -> bool {
+    if event.payload_ref.is_some() {
+        return false;
+    }
+    match &event.payload {
+        EventPayload::ToolCall {
+            args: Some(text), ..
+        }
+        | EventPayload::ToolResult {
+            result: Some(text), ..
+        } => text.len() > threshold_bytes,
+        _ => false,
+    }
+}
+
+/// Build the inline preview left in place of an offloaded payload.
+///
+/// Keeps at most [`OFFLOAD_PREVIEW_BYTES`] of the original text and appends
+/// a marker recording the full length.
+pub fn payload_preview(text: &str) -> String {
+    let mut end = text.len().min(OFFLOAD_PREVIEW_BYTES);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}... [truncated; {} bytes in payload_ref]",
+        &text[..end],
+        text.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+    use vifei_core::event::Tier;
+
+    fn tool_result(result: &str) -> ImportEvent {
+        ImportEvent {
+            run_id: "run-1".into(),
+            event_id: "e1".into(),
+            source_id: "test".into(),
+            source_seq: Some(0),
+            timestamp_ns: 1_000_000_000,
+            tier: Tier::A,
+            payload: EventPayload::ToolResult {
+                tool: "Read".into(),
+                result: Some(result.into()),
+                status: Some("success".into()),
+                invocation_id: None,
+            },
+            payload_ref: None,
+            synthesized: false,
+        }
+    }
+
+    #[test]
+    fn offload_replaces_text_with_preview_and_ref() {
+        let dir = tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let original = "x".repeat(1_000);
+        let mut events = vec![tool_result(&original)];
+
+        let count = offload_large_payloads(&mut events, &store, 512).unwrap();
+        assert_eq!(count, 1);
+
+        let payload_ref = events[0].payload_ref.clone().expect("payload_ref set");
+        assert_eq!(payload_ref, BlobStore::compute_ref(original.as_bytes()));
+        assert_eq!(
+            store.read_blob(&payload_ref).unwrap().unwrap(),
+            original.as_bytes()
+        );
+        match &events[0].payload {
+            EventPayload::ToolResult { result, .. } => {
+                let preview = result.as_deref().unwrap();
+                assert!(preview.starts_with(&"x".repeat(OFFLOAD_PREVIEW_BYTES)));
+                assert!(preview.ends_with("[truncated; 1000 bytes in payload_ref]"));
+            }
+            other => panic!("expected ToolResult, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn payload_at_threshold_stays_inline() {
+        let dir = tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let mut events = vec![tool_result(&"y".repeat(512))];
+        let before = events.clone();
+
+        let count = offload_large_payloads(&mut events, &store, 512).unwrap();
+        assert_eq!(count, 0);
+        assert_eq!(events, before);
+    }
+
+    #[test]
+    fn existing_payload_ref_is_not_replaced() {
+        let dir = tempdir().unwrap();
+        let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+        let mut event = tool_result(&"z".repeat(1_000));
+        event.payload_ref = Some("a".repeat(64));
+        let before = event.clone();
+
+        assert!(!offload_event(&mut event, &store, 8).unwrap());
+        assert_eq!(event, before);
+    }
+
+    #[test]
+    fn preview_cuts_at_char_boundary() {
+        // Each 'é' is two bytes, so byte OFFLOAD_PREVIEW_BYTES + 1 falls
+        // mid-character when prefixed with one ASCII byte.
+        let text = format!("a{}", "é".repeat(OFFLOAD_PREVIEW_BYTES));
+        let preview = payload_preview(&text);
+        let kept = preview.split("...").next().unwrap();
+        assert!(kept.len() <= OFFLOAD_PREVIEW_BYTES);
+        assert_eq!(kept.len(), OFFLOAD_PREVIEW_BYTES - 1);
+    }
+
+    #[test]
+    fn offload_is_deterministic() {
+        let original = "payload line\n".repeat(200);
+        let run = || {
+            let dir = tempdir().unwrap();
+            let store = BlobStore::open(dir.path().join("blobs")).unwrap();
+            let mut events = vec![tool_result(&original)];
+            offload_large_payloads(&mut events, &store, 100).unwrap();
+            events
+        };
+        assert_eq!(run(), run());
+    }
+}
@@ -187,7 +187,15 @@ fn map_item_payload(item: Option<&serde_json::Value>) -> Option<(EventPayload, T
                 .unwrap_or("unknown")
                 .to_string();
             let args = item.get("arguments").and_then(json_value_to_string);
-            Some((EventPayload::ToolCall { tool, args }, Tier::A))
+            let invocation_id = call_id(item);
+            Some((
+                EventPayload::ToolCall {
+                    tool,
+                    args,
+                    invocation_id,
+                },
+                Tier::A,
+            ))
         }
         "function_call_output" => {
             let tool = item
@@ -201,6 +209,7 @@ fn map_item_payload(item: Option<&serde_json::Value>) -> Option<(EventPayload, T
                     tool,
                     result,
                     status: Some("success".to_string()),
+                    invocation_id: call_id(item),
                 },
                 Tier::A,
             ))
@@ -209,6 +218,13 @@ fn map_item_payload(item: Option<&serde_json::Value>) -> Option<(EventPayload, T
     }
 }
 
+/// `call_id` shared by a `function_call` and its `function_call_output`.
+fn call_id(item: &serde_json::Value) -> Option<String> {
+    item.get("call_id")
+        .and_then(serde_json::Value::as_str)
+        .map(str::to_string)
+}
+
 fn item_id(item: &serde_json::Value) -> Option<&str> {
     item.get("id").and_then(serde_json::Value::as_str)
 }
@@ -0,0 +1,419 @@
+//! OpenTelemetry OTLP/JSON span importer (v1).
+//!
+//! # Overview
+//!
+//! Reads OTLP/JSON trace exports (`resourceSpans` → `scopeSpans` → `spans`),
+//! either one pretty-printed document or one document per line as written by
+//! the collector file exporter. Each span becomes two events:
+//!
+//! - a `ToolCall` at `startTimeUnixNano`, with the span attributes
+//!   flattened into a JSON object in `args`;
+//! - a `ToolResult` at `endTimeUnixNano`, with `status.code` mapped to
+//!   `"success"` (OK), `"error"` (ERROR), or `"unset"`, and `status.message`
+//!   as the result text.
+//!
+//! The span `name` is the tool name and `traceId` is the `run_id`.
+//!
+//! # Ordering
+//!
+//! OTLP exports do not order spans in time. Events are stably sorted by
+//! timestamp, so ties keep document order, and `source_seq` is assigned
+//! after sorting. The same input always yields the same event sequence.
+//!
+//! # Errors
+//!
+//! A span missing `traceId`, `spanId`, `name`, or a valid time range is not
+//! imported. [`parse_otel`] emits a Tier A parse `Error` event in its place;
+//! [`parse_otel_strict`] returns every such message as an error instead.
+//! Malformed JSON stops the parse, since the stream cannot be resynced.
+//! In the [`ImportReport`], `lines_read` counts spans examined.
+
+use std::collections::BTreeMap;
+use std::io::BufRead;
+
+use serde_json::Value;
+use vifei_core::event::{EventPayload, ImportEvent, Tier};
+
+use crate::report::{ImportReport, SKIP_MALFORMED_JSON, SKIP_MALFORMED_SPAN};
+
+/// Source identifier for events produced by this importer.
+pub const SOURCE_ID: &str = "otel";
+
+/// Run id used for parse errors that belong to no trace.
+const UNKNOWN_RUN_ID: &str = "unknown-otel-trace";
+
+/// Events, import receipt, and span errors produced by
+/// [`parse_otel_with_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OtelImport {
+    /// Imported events, ordered by timestamp.
+    pub events: Vec<ImportEvent>,
+    /// Receipt for this import pass.
+    pub report: ImportReport,
+    /// One message per span or document that could not be imported.
+    pub errors: Vec<String>,
+}
+
+/// Parse OTLP/JSON spans into [`ImportEvent`] values.
+pub fn parse_otel<R: BufRead>(reader: R) -> Vec<ImportEvent> {
+    parse_otel_with_report(reader).events
+}
+
+/// Parse OTLP/JSON spans, failing with every span error if any span is
+/// unparseable.
+pub fn parse_otel_strict<R: BufRead>(reader: R) -> Result<Vec<ImportEvent>, Vec<String>> {
+    let parsed = parse_otel_with_report(reader);
+    if parsed.errors.is_empty() {
+        Ok(parsed.events)
+    } else {
+        Err(parsed.errors)
+    }
+}
+
+/// Parse OTLP/JSON spans, also returning an [`ImportReport`] and the list of
+/// span errors.
+pub fn parse_otel_with_report<R: BufRead>(reader: R) -> OtelImport {
+    let mut report = ImportReport::new(SOURCE_ID);
+    let mut errors = Vec::new();
+    let mut events = Vec::new();
+
+    let documents = serde_json::Deserializer::from_reader(reader).into_iter::<Value>();
+    for (doc_index, document) in documents.enumerate() {
+        let doc_num = doc_index + 1;
+        let document = match document {
+            Ok(document) => document,
+            Err(e) => {
+                report.record_skipped(SKIP_MALFORMED_JSON);
+                let message = format!("Malformed OTLP JSON in document {doc_num}: {e}");
+                events.push(error_event(UNKNOWN_RUN_ID, &message));
+                errors.push(message);
+                break;
+            }
+        };
+        for (path, span) in spans_of(&document, doc_num) {
+            report.lines_read += 1;
+            match map_span(span) {
+                Ok(pair) => {
+                    report.record_parsed("span");
+                    events.extend(pair);
+                }
+                Err(reason) => {
+                    report.record_skipped(SKIP_MALFORMED_SPAN);
+                    let message = format!("Unparseable span at {path}: {reason}");
+                    let run_id = span
+                        .get("traceId")
+                        .and_then(Value::as_str)
+                        .unwrap_or(UNKNOWN_RUN_ID);
+                    events.push(error_event(run_id, &message));
+                    errors.push(message);
+                }
+            }
+        }
+    }
+
+    events.sort_by_key(|event| event.timestamp_ns);
+    for (seq, event) in events.iter_mut().enumerate() {
+        event.source_seq = Some(seq as u64);
+        if event.event_id.is_empty() {
+            // Error events have no span id to derive one from.
+            event.event_id = format!("otel:{seq}");
+        }
+        report.observe_timestamp(event.timestamp_ns);
+        report.run_ids.insert(event.run_id.clone());
+    }
+    report.events_emitted = events.len() as u64;
+
+    OtelImport {
+        events,
+        report,
+        errors,
+    }
+}
+
+/// Every span in `document`, with a path naming its position.
+fn spans_of(document: &Value, doc_num: usize) -> Vec<(String, &Value)> {
+    let mut spans = Vec::new();
+    for (r, resource) in array_at(document, "resourceSpans").iter().enumerate() {
+        for (s, scope) in array_at(resource, "scopeSpans").iter().enumerate() {
+            for (i, span) in array_at(scope, "spans").iter().enumerate() {
+                let path =
+                    format!("document {doc_num} resourceSpans[{r}].scopeSpans[{s}].spans[{i}]");
+                spans.push((path, span));
+            }
+        }
+    }
+    spans
+}
+
+fn array_at<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Map one span to its `ToolCall` / `ToolResult` pair.
+fn map_span(span: &Value) -> Result<[ImportEvent; 2], String> {
+    let trace_id = required_str(span, "traceId")?;
+    let span_id = required_str(span, "spanId")?;
+    let name = required_str(span, "name")?;
+    let start_ns = unix_nanos(span, "startTimeUnixNano")?;
+    let end_ns = unix_nanos(span, "endTimeUnixNano")?;
+    if end_ns < start_ns {
+        return Err(format!(
+            "endTimeUnixNano {end_ns} is before startTimeUnixNano {start_ns}"
+        ));
+    }
+    let status = span_status(span.get("status"))?;
+
+    let mut attributes = BTreeMap::new();
+    if let Some(list) = span.get("attributes") {
+        flatten_attributes("", list, &mut attributes);
+    }
+    let args = if attributes.is_empty() {
+        None
+    } else {
+        serde_json::to_string(&attributes).ok()
+    };
+    let result = span
+        .get("status")
+        .and_then(|s| s.get("message"))
+        .and_then(Value::as_str)
+        .filter(|m| !m.is_empty())
+        .map(str::to_string);
+
+    let run_id = trace_id.to_string();
+    Ok([
+        as_event(
+            run_id.clone(),
+            format!("{span_id}:start"),
+            start_ns,
+            EventPayload::ToolCall {
+                tool: name.to_string(),
+                args,
+                invocation_id: Some(span_id.to_string()),
+            },
+        ),
+        as_event(
+            run_id,
+            format!("{span_id}:end"),
+            end_ns,
+            EventPayload::ToolResult {
+                tool: name.to_string(),
+                result,
+                status: Some(status.to_string()),
+                invocation_id: Some(span_id.to_string()),
+            },
+        ),
+    ])
+}
+
+fn required_str<'a>(span: &'a Value, key: &str) -> Result<&'a str, String> {
+    span.get(key)
+        .and_then(Value::as_str)
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| format!("missing {key}"))
+}
+
+/// OTLP/JSON encodes 64-bit integers as decimal strings; accept numbers too.
+fn unix_nanos(span: &Value, key: &str) -> Result<u64, String> {
+    match span.get(key) {
+        Some(Value::String(text)) => text
+            .parse()
+            .map_err(|_| format!("{key} is not an unsigned integer: {text:?}")),
+        Some(Value::Number(number)) => number
+            .as_u64()
+            .ok_or_else(|| format!("{key} is not an unsigned integer: {number}")),
+        _ => Err(format!("missing {key}")),
+    }
+}
+
+fn span_status(status: Option<&Value>) -> Result<&'static str, String> {
+    let code = status.and_then(|s| s.get("code"));
+    match code {
+        None | Some(Value::Null) => Ok("unset"),
+        Some(Value::Number(n)) if n.as_u64() == Some(0) => Ok("unset"),
+        Some(Value::Number(n)) if n.as_u64() == Some(1) => Ok("success"),
+        Some(Value::Number(n)) if n.as_u64() == Some(2) => Ok("error"),
+        Some(Value::String(s)) if s == "STATUS_CODE_UNSET" => Ok("unset"),
+        Some(Value::String(s)) if s == "STATUS_CODE_OK" => Ok("success"),
+        Some(Value::String(s)) if s == "STATUS_CODE_ERROR" => Ok("error"),
+        Some(other) => Err(format!("unknown status.code {other}")),
+    }
+}
+
+/// Flatten an OTLP `KeyValue` list into dotted keys and string values.
+fn flatten_attributes(prefix: &str, list: &Value, out: &mut BTreeMap<String, String>) {
+    for entry in list.as_array().map_or(&[][..], Vec::as_slice) {
+        let Some(key) = entry.get("key").and_then(Value::as_str) else {
+            continue;
+        };
+        let key = if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        let value = entry.get("value").unwrap_or(&Value::Null);
+        if let Some(values) = value.get("kvlistValue").and_then(|kv| kv.get("values")) {
+            flatten_attributes(&key, values, out);
+        } else {
+            out.insert(key, any_value_text(value));
+        }
+    }
+}
+
+/// Text form of an OTLP `AnyValue`.
+fn any_value_text(value: &Value) -> String {
+    let scalar = [
+        "stringValue",
+        "intValue",
+        "doubleValue",
+        "boolValue",
+        "bytesValue",
+    ]
+    .iter()
+    .find_map(|key| value.get(*key));
+    match scalar {
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+        None => match value
+            .get("arrayValue")
+            .and_then(|a| a.get("values"))
+            .and_then(Value::as_array)
+        {
+            Some(items) => {
+                let items: Vec<Value> = items
+                    .iter()
+                    .map(|item| Value::String(any_value_text(item)))
+                    .collect();
+                Value::Array(items).to_string()
+            }
+            None => String::new(),
+        },
+    }
+}
+
+fn as_event(
+    run_id: String,
+    event_id: String,
+    timestamp_ns: u64,
+    payload: EventPayload,
+) -> ImportEvent {
+    ImportEvent {
+        run_id,
+        event_id,
+        source_id: SOURCE_ID.to_string(),
+        source_seq: None,
+        timestamp_ns,
+        tier: Tier::A,
+        payload,
+        payload_ref: None,
+        synthesized: false,
+    }
+}
+
+fn error_event(run_id: &str, message: &str) -> ImportEvent {
+    let mut event = as_event(
+        run_id.to_string(),
+        String::new(),
+        0,
+        EventPayload::Error {
+            kind: "parse".to_string(),
+            message: message.to_string(),
+            severity: Some("warning".to_string()),
+        },
+    );
+    event.synthesized = true;
+    event
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const TWO_SPANS: &str = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[
+        {"traceId":"t1","spanId":"b","name":"Bash","startTimeUnixNano":"300","endTimeUnixNano":"400",
+         "status":{"code":2,"message":"exit 1"}},
+        {"traceId":"t1","spanId":"a","name":"Read","startTimeUnixNano":"100","endTimeUnixNano":"200",
+         "attributes":[{"key":"path","value":{"stringValue":"src/lib.rs"}},
+                       {"key":"limit","value":{"intValue":"40"}},
+                       {"key":"opts","value":{"kvlistValue":{"values":[{"key":"raw","value":{"boolValue":true}}]}}}],
+         "status":{"code":"STATUS_CODE_OK"}}
+    ]}]}]}"#;
+
+    #[test]
+    fn spans_map_to_call_and_result_sorted_by_time() {
+        let events = parse_otel(Cursor::new(TWO_SPANS));
+        let ids: Vec<_> = events.iter().map(|e| e.event_id.as_str()).collect();
+        assert_eq!(ids, ["a:start", "a:end", "b:start", "b:end"]);
+        assert!(events.iter().all(|e| e.run_id == "t1"));
+        for (idx, event) in events.iter().enumerate() {
+            assert_eq!(event.source_seq, Some(idx as u64));
+        }
+
+        match &events[0].payload {
+            EventPayload::ToolCall {
+                tool,
+                args,
+                invocation_id,
+            } => {
+                assert_eq!(tool, "Read");
+                assert_eq!(invocation_id.as_deref(), Some("a"));
+                assert_eq!(
+                    args.as_deref(),
+                    Some(r#"{"limit":"40","opts.raw":"true","path":"src/lib.rs"}"#)
+                );
+            }
+            other => panic!("expected ToolCall, got {other:?}"),
+        }
+        match &events[3].payload {
+            EventPayload::ToolResult { result, status, .. } => {
+                assert_eq!(result.as_deref(), Some("exit 1"));
+                assert_eq!(status.as_deref(), Some("error"));
+            }
+            other => panic!("expected ToolResult, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn unparseable_span_is_error_event_and_strict_error() {
+        let input = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[
+            {"traceId":"t1","spanId":"a","name":"Read","startTimeUnixNano":"100","endTimeUnixNano":"200"},
+            {"traceId":"t1","spanId":"b","startTimeUnixNano":"300","endTimeUnixNano":"400"}
+        ]}]}]}"#;
+        let parsed = parse_otel_with_report(Cursor::new(input));
+        assert_eq!(parsed.events.len(), 3);
+        assert!(matches!(
+            &parsed.events[0].payload,
+            EventPayload::Error { kind, message, .. }
+                if kind == "parse" && message.contains("spans[1]: missing name")
+        ));
+        assert_eq!(parsed.report.parsed_by_type.get("span"), Some(&1));
+        assert_eq!(parsed.report.skipped.get(SKIP_MALFORMED_SPAN), Some(&1));
+
+        let errors = parse_otel_strict(Cursor::new(input)).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(parse_otel_strict(Cursor::new(TWO_SPANS)).is_ok());
+    }
+
+    #[test]
+    fn one_document_per_line_is_accepted() {
+        let one = TWO_SPANS.replace('\n', " ");
+        let input = format!("{one}\n{}\n", one.replace("t1", "t2"));
+        let parsed = parse_otel_with_report(Cursor::new(input));
+        assert!(parsed.errors.is_empty());
+        assert_eq!(parsed.events.len(), 8);
+        assert_eq!(parsed.report.run_ids.len(), 2);
+        assert_eq!(parsed.report.lines_read, 4);
+    }
+
+    #[test]
+    fn end_before_start_is_rejected() {
+        let input = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[
+            {"traceId":"t1","spanId":"a","name":"Read","startTimeUnixNano":"200","endTimeUnixNano":"100"}
+        ]}]}]}"#;
+        let errors = parse_otel_strict(Cursor::new(input)).unwrap_err();
+        assert!(errors[0].contains("before startTimeUnixNano"));
+    }
+}
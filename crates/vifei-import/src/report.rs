@@ -0,0 +1,145 @@
+//! Import receipt: what an importer read, mapped, and skipped.
+//!
+//! An [`ImportReport`] is a deterministic summary of a single import pass.
+//! Counts, run ids, and source timestamps depend only on the input bytes, so
+//! the same input always produces the same report. It is informational: it
+//! never feeds into `state_hash` or `viewmodel_hash`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::Serialize;
+
+/// Import report format version.
+pub const IMPORT_REPORT_VERSION: &str = "import-report-v0.1";
+
+/// Skip reason: line contained only whitespace.
+pub const SKIP_BLANK_LINE: &str = "blank_line";
+/// Skip reason: line could not be read from the source.
+pub const SKIP_IO_ERROR: &str = "io_error";
+/// Skip reason: line bytes were not valid UTF-8 (an `Error` event is emitted).
+pub const SKIP_INVALID_UTF8: &str = "invalid_utf8";
+/// Skip reason: line was not valid JSON (a parse `Error` event is emitted).
+pub const SKIP_MALFORMED_JSON: &str = "malformed_json";
+/// Skip reason: OTLP span lacked required fields or a valid time range (a
+/// parse `Error` event is emitted).
+pub const SKIP_MALFORMED_SPAN: &str = "malformed_span";
+/// Skip reason: record violated the adapter contract (a contract `Error`
+/// event is emitted in its place).
+pub const SKIP_CONTRACT_VIOLATION: &str = "contract_violation";
+
+/// Non-fatal condition observed while importing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportWarning {
+    /// Stable machine-readable warning code (e.g. `"orphan_session"`).
+    pub code: String,
+    /// Human-readable description.
+    pub message: String,
+    /// Number of events affected.
+    pub count: u64,
+}
+
+/// Deterministic summary of one import pass.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportReport {
+    /// Report format version ([`IMPORT_REPORT_VERSION`]).
+    pub report_version: String,
+    /// Importer source identifier (e.g. `"agent-cassette"`).
+    pub source_id: String,
+    /// Physical lines read, including blank and malformed lines.
+    pub lines_read: u64,
+    /// Events produced, including synthesized `Error` events.
+    pub events_emitted: u64,
+    /// Successfully mapped records, keyed by source record type.
+    pub parsed_by_type: BTreeMap<String, u64>,
+    /// Lines that did not map to a source record, keyed by reason.
+    pub skipped: BTreeMap<String, u64>,
+    /// Smallest parsed source timestamp, if any parsed.
+    pub min_timestamp_ns: Option<u64>,
+    /// Largest parsed source timestamp, if any parsed.
+    pub max_timestamp_ns: Option<u64>,
+    /// Distinct `run_id`s assigned to emitted events, sorted.
+    pub run_ids: BTreeSet<String>,
+    /// Warnings in deterministic order.
+    pub warnings: Vec<ImportWarning>,
+}
+
+impl ImportReport {
+    /// Create an empty report for the given source.
+    pub fn new(source_id: &str) -> Self {
+        ImportReport {
+            report_version: IMPORT_REPORT_VERSION.to_string(),
+            source_id: source_id.to_string(),
+            lines_read: 0,
+            events_emitted: 0,
+            parsed_by_type: BTreeMap::new(),
+            skipped: BTreeMap::new(),
+            min_timestamp_ns: None,
+            max_timestamp_ns: None,
+            run_ids: BTreeSet::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Total skipped lines across all reasons.
+    pub fn skipped_total(&self) -> u64 {
+        self.skipped.values().sum()
+    }
+
+    /// Serialize as pretty JSON (two-space indent, no trailing newline).
+    pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub(crate) fn record_parsed(&mut self, record_type: &str) {
+        *self
+            .parsed_by_type
+            .entry(record_type.to_string())
+            .or_insert(0) += 1;
+    }
+
+    pub(crate) fn record_skipped(&mut self, reason: &str) {
+        *self.skipped.entry(reason.to_string()).or_insert(0) += 1;
+    }
+
+    /// Track a parsed source timestamp. Zero means "unparsed" and is ignored.
+    pub(crate) fn observe_timestamp(&mut self, timestamp_ns: u64) {
+        if timestamp_ns == 0 {
+            return;
+        }
+        self.min_timestamp_ns = Some(
+            self.min_timestamp_ns
+                .map_or(timestamp_ns, |min| min.min(timestamp_ns)),
+        );
+        self.max_timestamp_ns = Some(
+            self.max_timestamp_ns
+                .map_or(timestamp_ns, |max| max.max(timestamp_ns)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_report_serializes_in_field_order() {
+        let report = ImportReport::new("agent-cassette");
+        let json = report.to_json_pretty().unwrap();
+        assert!(json.starts_with("{\n  \"report_version\": \"import-report-v0.1\""));
+        let source_pos = json.find("\"source_id\"").unwrap();
+        let warnings_pos = json.find("\"warnings\"").unwrap();
+        assert!(source_pos < warnings_pos);
+    }
+
+    #[test]
+    fn observe_timestamp_ignores_zero_and_tracks_bounds() {
+        let mut report = ImportReport::new("test");
+        report.observe_timestamp(0);
+        assert_eq!(report.min_timestamp_ns, None);
+        report.observe_timestamp(20);
+        report.observe_timestamp(10);
+        report.observe_timestamp(30);
+        assert_eq!(report.min_timestamp_ns, Some(10));
+        assert_eq!(report.max_timestamp_ns, Some(30));
+    }
+}
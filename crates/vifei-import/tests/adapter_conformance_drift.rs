@@ -4,6 +4,7 @@ use vifei_core::event::ImportEvent;
 use vifei_import::anthropic_messages::parse_anthropic_messages;
 use vifei_import::cohere_translate::parse_cohere_translate;
 use vifei_import::openai_responses::parse_openai_responses;
+use vifei_import::otel::parse_otel;
 
 #[derive(Clone, Copy)]
 enum AdapterCase {
@@ -11,15 +12,17 @@ enum AdapterCase {
     OpenAiNoisy,
     AnthropicSmall,
     CohereSmall,
+    OtelSmall,
 }
 
 impl AdapterCase {
-    fn all() -> [Self; 4] {
+    fn all() -> [Self; 5] {
         [
             Self::OpenAiSmall,
             Self::OpenAiNoisy,
             Self::AnthropicSmall,
             Self::CohereSmall,
+            Self::OtelSmall,
         ]
     }
 
@@ -29,6 +32,7 @@ impl AdapterCase {
             Self::OpenAiNoisy => "openai-noisy",
             Self::AnthropicSmall => "anthropic-small",
             Self::CohereSmall => "cohere-small",
+            Self::OtelSmall => "otel-small",
         }
     }
 }
@@ -86,6 +90,9 @@ fn parse_case(case: AdapterCase) -> Vec<ImportEvent> {
         AdapterCase::CohereSmall => parse_cohere_translate(Cursor::new(include_str!(
             "../../../fixtures/cohere-translate-small.jsonl"
         ))),
+        AdapterCase::OtelSmall => parse_otel(Cursor::new(include_str!(
+            "../../../fixtures/otel-spans-small.json"
+        ))),
     }
 }
 
@@ -70,6 +70,9 @@ fn payload_shape(event: &vifei_core::event::ImportEvent) -> &'static str {
         EventPayload::PolicyDecision { .. } => "policy_decision",
         EventPayload::RedactionApplied { .. } => "redaction",
         EventPayload::ClockSkewDetected { .. } => "clock_skew",
+        EventPayload::Note { .. } => "note",
+        EventPayload::TokenUsage { .. } => "token_usage",
+        EventPayload::SubagentSpawned { .. } => "subagent_spawned",
         EventPayload::Generic { .. } => "generic",
     }
 }
@@ -5,9 +5,12 @@
 
 use std::io::Cursor;
 
-use vifei_core::event::{EventPayload, Tier};
+use vifei_core::delta::{diff_runs, ChangeClass};
+use vifei_core::event::{CommittedEvent, EventPayload, Tier};
 use vifei_core::eventlog::{read_eventlog, EventLogWriter};
+use vifei_core::reducer::replay;
 use vifei_import::cassette;
+use vifei_import::mapping::Mapping;
 
 #[test]
 fn import_fixture_full_pipeline() {
@@ -131,3 +134,202 @@ fn import_single_event() {
     assert_eq!(committed.len(), 1);
     assert_eq!(committed[0].commit_index, 0);
 }
+
+#[test]
+fn import_multi_session_fixture_yields_one_run_per_session() {
+    let fixture = include_str!("../../../fixtures/multi-session.jsonl");
+    let import_events = cassette::parse_cassette(Cursor::new(fixture));
+
+    let dir = tempfile::tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    for event in import_events {
+        writer.append(event).unwrap();
+    }
+    drop(writer);
+
+    let committed = read_eventlog(&eventlog_path).unwrap();
+    let (state, _) = replay(&committed);
+
+    let keys: Vec<&str> = state.run_metadata.keys().map(String::as_str).collect();
+    assert_eq!(keys, vec!["sess-alpha", "sess-beta"]);
+
+    let alpha = &state.run_metadata["sess-alpha"];
+    assert!(alpha.ended);
+    assert_eq!(alpha.exit_code, Some(0));
+    assert_eq!(alpha.event_count, 6);
+
+    let beta = &state.run_metadata["sess-beta"];
+    assert!(beta.ended);
+    assert_eq!(beta.exit_code, Some(1));
+    assert_eq!(beta.event_count, 5);
+}
+
+/// Import cassette bytes through the append writer and return the EventLog bytes.
+#[test]
+fn out_of_range_timestamps_do_not_trigger_clock_skew() {
+    let input = r#"{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"s1","timestamp":"1969-12-31T23:59:59.999Z","tool":"Read"}
+{"type":"tool_use","session_id":"s1","timestamp":"2999-01-01T00:00:00Z","tool":"Write"}
+{"type":"tool_result","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","tool":"Write","result":"ok"}
+{"type":"session_end","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","exit_code":0}
+"#;
+    let import_events = cassette::parse_cassette(Cursor::new(input));
+
+    let dir = tempfile::tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    for event in import_events {
+        let result = writer.append(event).unwrap();
+        assert!(result.detection_events().is_empty());
+    }
+    drop(writer);
+
+    let committed = read_eventlog(&eventlog_path).unwrap();
+    assert!(!committed
+        .iter()
+        .any(|e| matches!(e.payload, EventPayload::ClockSkewDetected { .. })));
+    let warnings = committed
+        .iter()
+        .filter(|e| matches!(&e.payload, EventPayload::Error { message, .. } if message.starts_with("Timestamp out of range")))
+        .count();
+    assert_eq!(warnings, 2);
+}
+
+const TWO_SOURCE_CASSETTE: &str = r#"{"type":"session_start","session_id":"s1","source":"agent","timestamp":"2026-02-16T10:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"s1","source":"proxy","timestamp":"2026-02-16T10:00:01Z","tool":"http","args":{"url":"/v1"}}
+{"type":"tool_use","session_id":"s1","source":"agent","timestamp":"2026-02-16T10:00:02Z","tool":"Read"}
+{"type":"tool_result","session_id":"s1","source":"proxy","timestamp":"2026-02-16T10:00:03Z","tool":"http","result":"200"}
+{"type":"tool_result","session_id":"s1","source":"agent","timestamp":"2026-02-16T10:00:04Z","tool":"Read","result":"ok"}
+{"type":"session_end","session_id":"s1","source":"agent","timestamp":"2026-02-16T10:00:05Z","exit_code":0}
+"#;
+
+fn commit_cassette(input: &str) -> Vec<CommittedEvent> {
+    let dir = tempfile::tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    for event in cassette::parse_cassette(Cursor::new(input)) {
+        writer.append(event).unwrap();
+    }
+    drop(writer);
+    read_eventlog(&eventlog_path).unwrap()
+}
+
+#[test]
+fn interleaved_sources_keep_per_source_sequences() {
+    let committed = commit_cassette(TWO_SOURCE_CASSETTE);
+    let order: Vec<(&str, Option<u64>)> = committed
+        .iter()
+        .map(|e| (e.source_id.as_str(), e.source_seq))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("agent", Some(0)),
+            ("proxy", Some(0)),
+            ("agent", Some(1)),
+            ("proxy", Some(1)),
+            ("agent", Some(2)),
+            ("agent", Some(3)),
+        ]
+    );
+    for (i, event) in committed.iter().enumerate() {
+        assert_eq!(event.commit_index, i as u64);
+        assert_eq!(event.event_id, format!("cassette:{i}"));
+    }
+
+    assert!(diff_runs(&committed, &committed).divergences.is_empty());
+    let changed = commit_cassette(&TWO_SOURCE_CASSETTE.replace("\"200\"", "\"503\""));
+    let delta = diff_runs(&committed, &changed);
+    assert_eq!(delta.divergences.len(), 1);
+    let divergence = &delta.divergences[0];
+    assert_eq!(divergence.commit_index, 3);
+    assert_eq!(divergence.path, "$.payload.result");
+    assert_eq!(divergence.change_class, ChangeClass::ValueMismatch);
+}
+
+fn committed_eventlog_bytes(fixture: &[u8]) -> Vec<u8> {
+    let dir = tempfile::tempdir().unwrap();
+    let eventlog_path = dir.path().join("eventlog.jsonl");
+    let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+    for event in cassette::parse_cassette(Cursor::new(fixture)) {
+        writer.append(event).unwrap();
+    }
+    drop(writer);
+    std::fs::read(&eventlog_path).unwrap()
+}
+
+#[test]
+fn bom_crlf_fixture_commits_identically_to_clean_fixture() {
+    let windows = include_bytes!("../../../fixtures/small-session-bom-crlf.jsonl");
+    let clean = include_bytes!("../../../fixtures/small-session.jsonl");
+    assert!(
+        windows.starts_with(b"\xEF\xBB\xBF"),
+        "fixture must keep its BOM"
+    );
+    assert!(
+        windows.windows(2).any(|w| w == b"\r\n"),
+        "fixture must keep CRLF"
+    );
+
+    let report = cassette::parse_cassette_with_report(Cursor::new(&windows[..])).report;
+    assert!(
+        report.skipped.is_empty(),
+        "no line may be skipped: {report:?}"
+    );
+    assert_eq!(report.parsed_by_type.get("session_start"), Some(&1));
+
+    assert_eq!(
+        committed_eventlog_bytes(windows),
+        committed_eventlog_bytes(clean)
+    );
+}
+
+#[test]
+fn default_mapping_reproduces_standard_import_on_all_fixtures() {
+    let dir = tempfile::tempdir().unwrap();
+    let mapping_path = dir.path().join("default.mapping.json");
+    std::fs::write(
+        &mapping_path,
+        serde_json::to_string_pretty(&Mapping::default()).unwrap(),
+    )
+    .unwrap();
+    let loaded = Mapping::load(&mapping_path).unwrap();
+    assert_eq!(loaded, Mapping::default());
+
+    for fixture in [
+        &include_bytes!("../../../fixtures/small-session.jsonl")[..],
+        &include_bytes!("../../../fixtures/multi-session.jsonl")[..],
+        &include_bytes!("../../../fixtures/small-session-bom-crlf.jsonl")[..],
+        &include_bytes!("../../../fixtures/large-stress.jsonl")[..],
+    ] {
+        let standard = cassette::parse_cassette_with_report(Cursor::new(fixture));
+        let mapped = cassette::parse_cassette_with_mapping(Cursor::new(fixture), &loaded);
+        assert_eq!(mapped, standard);
+    }
+}
+
+#[test]
+fn vendor_mapping_imports_same_events_as_standard_fixture() {
+    let mapping = Mapping::load(std::path::Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../../fixtures/vendor-session.mapping.json"
+    )))
+    .unwrap();
+    let vendor = include_str!("../../../fixtures/vendor-session.jsonl");
+    let standard = include_str!("../../../fixtures/small-session.jsonl");
+
+    // Without the mapping nothing maps: every record is Generic.
+    let unmapped = cassette::parse_cassette(Cursor::new(vendor));
+    assert!(unmapped
+        .iter()
+        .all(|e| matches!(e.payload, EventPayload::Generic { .. })));
+
+    let mapped = cassette::parse_cassette_with_mapping(Cursor::new(vendor), &mapping);
+    assert_eq!(
+        mapped.events,
+        cassette::parse_cassette(Cursor::new(standard))
+    );
+    assert_eq!(mapped.report.parsed_by_type.get("call"), Some(&4));
+    assert_eq!(mapped.report.skipped_total(), 0);
+}
@@ -0,0 +1,62 @@
+use std::io::{BufReader, Cursor};
+
+use vifei_core::event::EventPayload;
+use vifei_import::otel::{parse_otel, parse_otel_strict};
+
+#[test]
+fn import_otel_fixture() {
+    let fixture = include_str!("../../../fixtures/otel-spans-small.json");
+    let events = parse_otel(BufReader::new(Cursor::new(fixture)));
+    assert_eq!(events.len(), 8);
+
+    let order: Vec<_> = events
+        .iter()
+        .map(|event| match &event.payload {
+            EventPayload::ToolCall { tool, .. } => format!("call:{tool}"),
+            EventPayload::ToolResult { tool, status, .. } => {
+                format!("result:{tool}:{}", status.as_deref().unwrap_or("-"))
+            }
+            other => panic!("unexpected payload {other:?}"),
+        })
+        .collect();
+    assert_eq!(
+        order,
+        [
+            "call:Read",
+            "result:Read:success",
+            "call:Edit",
+            "result:Edit:unset",
+            "call:Bash",
+            "result:Bash:error",
+            "call:Write",
+            "result:Write:success",
+        ]
+    );
+
+    for (idx, event) in events.iter().enumerate() {
+        assert_eq!(event.source_seq, Some(idx as u64));
+        assert_eq!(event.source_id, "otel");
+        assert_eq!(event.run_id, "5b8efff798038103d269b633813fc60c");
+    }
+    assert!(events
+        .windows(2)
+        .all(|pair| pair[0].timestamp_ns <= pair[1].timestamp_ns));
+}
+
+#[test]
+fn fixture_parses_strictly() {
+    let fixture = include_str!("../../../fixtures/otel-spans-small.json");
+    let events = parse_otel_strict(Cursor::new(fixture)).expect("no span errors");
+    assert_eq!(events.len(), 8);
+}
+
+#[test]
+fn malformed_json_yields_parse_error() {
+    let events = parse_otel(Cursor::new(r#"{"resourceSpans": ["#));
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        &events[0].payload,
+        EventPayload::Error { kind, .. } if kind == "parse"
+    ));
+    assert!(parse_otel_strict(Cursor::new(r#"{"resourceSpans": ["#)).is_err());
+}
@@ -1,10 +1,11 @@
-use crate::TourMetrics;
+use crate::{DegradationTransition, TourMetrics};
 use serde::{Deserialize, Serialize};
 use std::fmt::Write as _;
 use std::fs;
 use std::io;
 use std::path::Path;
 use vifei_core::projection::{ExportSafetyState, LadderLevel, ViewModel};
+use vifei_import::report::ImportReport;
 
 /// Time-travel capture artifact.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +25,25 @@ pub struct SeekPoint {
     pub state_hash: String,
     /// ViewModel hash at this point.
     pub viewmodel_hash: String,
+    /// Projected queue pressure at this point, in millionths (the
+    /// ViewModel's `queue_pressure_fixed`, clamped at 0). Absent in
+    /// captures written before it was recorded; read as 0.
+    #[serde(default)]
+    pub queue_pressure_micro: u64,
+    /// Projected degradation level at this point. Absent in captures
+    /// written before it was recorded; read as `L0`.
+    #[serde(default)]
+    pub degradation_level: LadderLevel,
+}
+
+/// `degradation-timeline.json`: the run's ladder transitions, for charting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DegradationTimeline {
+    /// Projection invariants version.
+    pub projection_invariants_version: String,
+    /// Transitions in `commit_index` order; the same list as
+    /// `metrics.json`'s `degradation_transitions`.
+    pub transitions: Vec<DegradationTransition>,
 }
 
 // --- ANSI escape helpers (deterministic, no external dependencies) ---
@@ -37,6 +57,19 @@ const FG_WHITE: &str = "\x1b[37m";
 const FG_MAGENTA: &str = "\x1b[35m";
 const FG_GRAY: &str = "\x1b[90m";
 
+/// Default `ansi.capture` terminal width, in columns.
+pub const DEFAULT_ANSI_WIDTH: u16 = 80;
+/// Default `ansi.capture` terminal height, in rows.
+pub const DEFAULT_ANSI_HEIGHT: u16 = 24;
+
+/// How `ansi.capture` is laid out, and whether `hud.txt` is written.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CaptureOptions {
+    pub width: u16,
+    pub height: u16,
+    pub plain_hud: bool,
+}
+
 /// Emit all Tour proof artifacts to the output directory.
 pub(crate) fn emit_artifacts(
     output_dir: &Path,
@@ -45,6 +78,7 @@ pub(crate) fn emit_artifacts(
     vm_hash: &str,
     event_count: usize,
     seek_points: Vec<SeekPoint>,
+    capture: CaptureOptions,
 ) -> io::Result<()> {
     // Write metrics.json
     let metrics_path = output_dir.join("metrics.json");
@@ -62,9 +96,18 @@ pub(crate) fn emit_artifacts(
 
     // Write ansi.capture — deterministic ANSI rendering of ViewModel state
     let ansi_path = output_dir.join("ansi.capture");
-    let ansi_content = render_ansi_capture(viewmodel, event_count, vm_hash);
+    let ansi_content = fit_to_terminal(
+        &render_ansi_capture(viewmodel, event_count, vm_hash),
+        capture.width,
+        capture.height,
+    );
     fs::write(&ansi_path, &ansi_content)?;
 
+    // Write hud.txt — the same capture with escape sequences stripped
+    if capture.plain_hud {
+        fs::write(output_dir.join("hud.txt"), strip_sgr(&ansi_content))?;
+    }
+
     // Write timetravel.capture with ordered seek points
     let timetravel = TimeTravelCapture {
         projection_invariants_version: viewmodel.projection_invariants_version.clone(),
@@ -79,9 +122,36 @@ pub(crate) fn emit_artifacts(
     })?;
     fs::write(&timetravel_path, timetravel_json)?;
 
+    // Write degradation-timeline.json
+    let timeline = DegradationTimeline {
+        projection_invariants_version: metrics.projection_invariants_version.clone(),
+        transitions: metrics.degradation_transitions.clone(),
+    };
+    let timeline_json = serde_json::to_string_pretty(&timeline).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Failed to serialize degradation timeline: {e}"),
+        )
+    })?;
+    fs::write(output_dir.join("degradation-timeline.json"), timeline_json)?;
+
     Ok(())
 }
 
+/// Write `import-report.json` (pretty JSON).
+///
+/// Informational receipt only: it is not an input to `viewmodel.hash` or
+/// any seek-point hash, but its counts are deterministic per fixture.
+pub(crate) fn write_import_report(output_dir: &Path, report: &ImportReport) -> io::Result<()> {
+    let report_json = report.to_json_pretty().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Failed to serialize import report: {e}"),
+        )
+    })?;
+    fs::write(output_dir.join("import-report.json"), report_json)
+}
+
 /// ANSI color for degradation level (mirrors Truth HUD semantics).
 fn ansi_level(level: LadderLevel) -> &'static str {
     match level {
@@ -152,10 +222,7 @@ fn render_ansi_capture(vm: &ViewModel, event_count: usize, vm_hash: &str) -> Str
         vm.degradation_level,
     );
 
-    let agg_display = vm
-        .aggregation_bin_size
-        .map(|bin| format!("{} (bin={bin})", vm.aggregation_mode))
-        .unwrap_or_else(|| vm.aggregation_mode.clone());
+    let agg_display = vm.aggregation_summary();
     let _ = writeln!(buf, "  {FG_WHITE}Agg:{RESET}      {agg_display}");
 
     let pressure_pct = (vm.queue_pressure() * 100.0) as u32;
@@ -191,6 +258,98 @@ fn render_ansi_capture(vm: &ViewModel, event_count: usize, vm_hash: &str) -> Str
     let _ = writeln!(buf, "{FG_MAGENTA}{BOLD}── Summary ──{RESET}");
     let _ = writeln!(buf, "  {FG_WHITE}Events:{RESET}   {event_count}");
     let _ = writeln!(buf, "  {FG_WHITE}Hash:{RESET}     {vm_hash}");
+    let errors_color = ansi_drops(vm.error_count);
+    let last_kind = vm
+        .last_error_kind
+        .as_deref()
+        .map(|kind| format!(" (last: {kind})"))
+        .unwrap_or_default();
+    let _ = writeln!(
+        buf,
+        "  {FG_WHITE}Errors:{RESET}   {errors_color}{}{RESET}{last_kind}",
+        vm.error_count,
+    );
+
+    if !vm.top_tools.is_empty() {
+        let _ = writeln!(buf);
+        let _ = writeln!(buf, "{FG_MAGENTA}{BOLD}── Top tools ──{RESET}");
+        let name_width = vm
+            .top_tools
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (name, tool) in &vm.top_tools {
+            let errors_color = ansi_drops(tool.error_count);
+            let _ = writeln!(
+                buf,
+                "  {FG_WHITE}{name:<name_width$}{RESET}  {} calls  {errors_color}{} errors{RESET}",
+                tool.call_count, tool.error_count,
+            );
+        }
+    }
+
+    buf
+}
 
+/// Lay `capture` out on a `width` × `height` terminal (0 is treated as 1).
+///
+/// Lines wider than `width` visible columns wrap the way a terminal wraps
+/// them; escape sequences take no columns and color carries across the
+/// wrap. Rows past `height` are clipped and the last row kept ends with a
+/// reset. A capture
+/// that already fits is returned unchanged.
+fn fit_to_terminal(capture: &str, width: u16, height: u16) -> String {
+    let width = usize::from(width.max(1));
+    let mut rows = Vec::new();
+    for line in capture.lines() {
+        let mut row = String::new();
+        let mut columns = 0;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' {
+                row.push(c);
+                for c in chars.by_ref() {
+                    row.push(c);
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+                continue;
+            }
+            if columns == width {
+                rows.push(std::mem::take(&mut row));
+                columns = 0;
+            }
+            row.push(c);
+            columns += 1;
+        }
+        rows.push(row);
+    }
+
+    let height = usize::from(height.max(1));
+    if rows.len() > height {
+        rows.truncate(height);
+        rows[height - 1].push_str(RESET);
+    }
+    let mut buf = String::new();
+    for row in rows {
+        buf.push_str(&row);
+        buf.push('\n');
+    }
     buf
 }
+
+/// `text` without escape sequences, for tools that cannot read them.
+pub(crate) fn strip_sgr(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
@@ -3,7 +3,7 @@ use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
-use vifei_tour::TourConfig;
+use vifei_tour::{TourConfig, TourStageProfile};
 
 fn fixture_path() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -22,6 +22,49 @@ fn percentile(sorted: &[Duration], p: f64) -> Duration {
     sorted[idx]
 }
 
+/// Tour stages reported per iteration, in pipeline order.
+const STAGE_NAMES: [&str; 6] = [
+    "parse_fixture",
+    "append_writer",
+    "reducer",
+    "projection",
+    "metrics_emit",
+    "total",
+];
+
+fn stage_durations(profile: &TourStageProfile) -> [Duration; 6] {
+    [
+        profile.parse_fixture,
+        profile.append_writer,
+        profile.reducer,
+        profile.projection,
+        profile.metrics_emit,
+        profile.total,
+    ]
+}
+
+/// Distribution of one stage's duration across iterations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct StageStats {
+    stage: String,
+    ms_min: f64,
+    ms_p50: f64,
+    ms_p95: f64,
+    ms_max: f64,
+}
+
+fn stage_stats(stage: &str, samples: &mut [Duration]) -> StageStats {
+    samples.sort_unstable();
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    StageStats {
+        stage: stage.to_string(),
+        ms_min: ms(percentile(samples, 0.0)),
+        ms_p50: ms(percentile(samples, 0.50)),
+        ms_p95: ms(percentile(samples, 0.95)),
+        ms_max: ms(percentile(samples, 1.0)),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct BenchmarkStats {
     iters: usize,
@@ -33,6 +76,11 @@ struct BenchmarkStats {
     throughput_events_per_sec_p95: f64,
     throughput_events_per_sec_p99: f64,
     peak_rss_kib: Option<u64>,
+    /// Per-stage distributions from `run_tour_with_profile`, in
+    /// [`STAGE_NAMES`] order. Absent from artifacts written before stages
+    /// were recorded.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    stages: Vec<StageStats>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -216,6 +264,7 @@ fn main() -> Result<(), String> {
     let fixture_line_count = fixture_text.lines().count() as u64;
 
     let mut samples = Vec::with_capacity(iters);
+    let mut stage_samples: [Vec<Duration>; 6] = Default::default();
     let mut peak_rss_kib: Option<u64> = None;
     let mut event_count = None;
 
@@ -224,11 +273,14 @@ fn main() -> Result<(), String> {
         let output_dir = tmp.path().join("tour-output");
         let config = TourConfig::new(fixture.clone()).with_output_dir(output_dir);
         let start = Instant::now();
-        let result = vifei_tour::run_tour(&config)
+        let (result, profile) = vifei_tour::run_tour_with_profile(&config)
             .map_err(|e| format!("tour run failed for fixture {}: {e}", fixture.display()))?;
         event_count.get_or_insert(result.metrics.event_count_total);
         std::hint::black_box(&result.viewmodel_hash);
         samples.push(start.elapsed());
+        for (stage, duration) in stage_samples.iter_mut().zip(stage_durations(&profile)) {
+            stage.push(duration);
+        }
         if let Some(rss) = read_current_rss_kib() {
             peak_rss_kib = Some(peak_rss_kib.map_or(rss, |prev| prev.max(rss)));
         }
@@ -253,6 +305,18 @@ fn main() -> Result<(), String> {
     if let Some(peak) = peak_rss_kib {
         println!("tour_peak_rss_kib={peak}");
     }
+    let stages: Vec<StageStats> = STAGE_NAMES
+        .iter()
+        .zip(stage_samples.iter_mut())
+        .map(|(name, samples)| stage_stats(name, samples))
+        .collect();
+    for stage in &stages {
+        let name = &stage.stage;
+        println!("tour_stage_{name}_ms_min={:.3}", stage.ms_min);
+        println!("tour_stage_{name}_ms_p50={:.3}", stage.ms_p50);
+        println!("tour_stage_{name}_ms_p95={:.3}", stage.ms_p95);
+        println!("tour_stage_{name}_ms_max={:.3}", stage.ms_max);
+    }
 
     let stats = BenchmarkStats {
         iters,
@@ -264,6 +328,7 @@ fn main() -> Result<(), String> {
         throughput_events_per_sec_p95: throughput_eps(p95_ms, events),
         throughput_events_per_sec_p99: throughput_eps(p99_ms, events),
         peak_rss_kib,
+        stages,
     };
     let command = CommandProvenance {
         argv: std::env::args().collect(),
@@ -315,6 +380,31 @@ mod tests {
         assert_eq!(percentile(&[], 0.95), Duration::ZERO);
     }
 
+    #[test]
+    fn percentile_of_known_vector() {
+        let sorted: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&sorted, 0.0), Duration::from_millis(1));
+        assert_eq!(percentile(&sorted, 0.50), Duration::from_millis(11));
+        assert_eq!(percentile(&sorted, 0.95), Duration::from_millis(19));
+        assert_eq!(percentile(&sorted, 1.0), Duration::from_millis(20));
+    }
+
+    #[test]
+    fn stage_stats_sorts_samples_before_ranking() {
+        let mut samples: Vec<Duration> = [7, 3, 9, 1, 5].map(Duration::from_millis).to_vec();
+        let stats = stage_stats("reducer", &mut samples);
+        assert_eq!(
+            stats,
+            StageStats {
+                stage: "reducer".to_string(),
+                ms_min: 1.0,
+                ms_p50: 5.0,
+                ms_p95: 9.0,
+                ms_max: 9.0,
+            }
+        );
+    }
+
     #[test]
     fn throughput_handles_zero() {
         assert_eq!(throughput_eps(0.0, 1000), 0.0);
@@ -334,6 +424,7 @@ mod tests {
                 throughput_events_per_sec_p95: 10.0,
                 throughput_events_per_sec_p99: 10.0,
                 peak_rss_kib: None,
+                stages: Vec::new(),
             },
             command: CommandProvenance {
                 argv: vec!["bench_tour".to_string()],
@@ -362,6 +453,7 @@ mod tests {
             throughput_events_per_sec_p95: 90.0,
             throughput_events_per_sec_p99: 80.0,
             peak_rss_kib: Some(1234),
+            stages: Vec::new(),
         };
         let record = TrendRecord {
             schema_version: "vifei-perf-trend-v1".to_string(),
@@ -0,0 +1,52 @@
+//! Deterministic synthetic fixture generator driven by a JSON spec.
+//!
+//! Renders a [`FixtureSpec`] as Agent Cassette JSONL. The same spec file
+//! produces byte-identical output on every machine, so capacity runs are
+//! reproducible without committing large fixtures.
+//!
+//! # Usage
+//!
+//! ```sh
+//! cargo run -p vifei-tour --bin gen-fixture -- spec.json out/fixture.jsonl
+//! cargo run -p vifei-tui --bin vifei -- tour out/fixture.jsonl --stress
+//! ```
+//!
+//! See `vifei_import::fixture` for the spec format.
+
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use vifei_import::fixture::{write_fixture, FixtureSpec};
+
+fn main() -> ExitCode {
+    let args: Vec<PathBuf> = std::env::args_os().skip(1).map(PathBuf::from).collect();
+    let [spec_path, output_path] = args.as_slice() else {
+        eprintln!("usage: gen-fixture <spec.json> <output.jsonl>");
+        return ExitCode::from(2);
+    };
+
+    let spec = match FixtureSpec::load(spec_path) {
+        Ok(spec) => spec,
+        Err(e) => {
+            eprintln!("gen-fixture: {e}");
+            return ExitCode::from(2);
+        }
+    };
+    let written =
+        File::create(output_path).and_then(|file| write_fixture(&spec, BufWriter::new(file)));
+    match written {
+        Ok(lines) => {
+            eprintln!("Generated {lines} records to {}", output_path.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!(
+                "gen-fixture: failed to write {}: {e}",
+                output_path.display()
+            );
+            ExitCode::from(4)
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::time::Duration;
+use vifei_core::eventlog::DurabilityPolicy;
 use vifei_tour::{TourConfig, TourStageProfile};
 
 fn fixture_path() -> PathBuf {
@@ -31,6 +32,19 @@ fn stage_pct(stage_ms: f64, total_ms: f64) -> f64 {
     stage_ms / total_ms * 100.0
 }
 
+/// Parse `VIFEI_TOUR_DURABILITY`: `every-append`, `every-<N>`, or
+/// `on-drop` (default).
+fn durability() -> DurabilityPolicy {
+    match std::env::var("VIFEI_TOUR_DURABILITY").ok().as_deref() {
+        Some("every-append") => DurabilityPolicy::EveryAppend,
+        Some(value) => value
+            .strip_prefix("every-")
+            .and_then(|n| n.parse::<u32>().ok())
+            .map_or(DurabilityPolicy::OnDrop, DurabilityPolicy::EveryN),
+        None => DurabilityPolicy::OnDrop,
+    }
+}
+
 fn main() {
     let iters = std::env::var("VIFEI_TOUR_PROFILE_ITERS")
         .ok()
@@ -39,6 +53,8 @@ fn main() {
         .unwrap_or(10);
 
     let fixture = fixture_path();
+    let durability = durability();
+    let mut append_syncs = 0;
     let mut parse_ms = Vec::with_capacity(iters);
     let mut append_ms = Vec::with_capacity(iters);
     let mut reducer_ms = Vec::with_capacity(iters);
@@ -49,7 +65,9 @@ fn main() {
     for _ in 0..iters {
         let tmp = tempfile::tempdir().expect("tempdir");
         let output_dir = tmp.path().join("tour-output");
-        let config = TourConfig::new(fixture.clone()).with_output_dir(output_dir);
+        let config = TourConfig::new(fixture.clone())
+            .with_output_dir(output_dir)
+            .with_durability(durability);
         let (_, profile): (_, TourStageProfile) =
             vifei_tour::run_tour_with_profile(&config).expect("tour run");
 
@@ -59,10 +77,13 @@ fn main() {
         projection_ms.push(dur_ms(profile.projection));
         emit_ms.push(dur_ms(profile.metrics_emit));
         total_ms.push(dur_ms(profile.total));
+        append_syncs = profile.append_syncs;
     }
 
     total_ms.sort_by(f64::total_cmp);
     println!("tour_profile_iters={iters}");
+    println!("tour_profile_durability={durability:?}");
+    println!("tour_profile_append_syncs={append_syncs}");
     println!(
         "tour_profile_total_ms_p50={:.2}",
         percentile(&total_ms, 0.50)
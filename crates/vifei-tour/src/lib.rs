@@ -11,14 +11,42 @@
 //! fixture → import → append → reduce → project → emit artifacts
 //! ```
 //!
+//! Payload text above [`TourConfig::offload_threshold_bytes`] is offloaded
+//! to a blob store beside the temporary EventLog before append.
+//!
+//! # Backpressure
+//!
+//! The parsed fixture is a queue drained by the append writer, one batch of
+//! [`APPEND_BATCH_EVENTS`] at a time. Before each batch a
+//! [`BackpressureController`] observes the queue pressure, the events still
+//! waiting over [`QUEUE_CAPACITY_EVENTS`], and once the queue is empty it
+//! observes the drained queue until it is back at `L0`. Every transition is
+//! appended as a `PolicyDecision` event ahead of the batch it was decided
+//! on, so `metrics.json.degradation_transitions` is read back from the
+//! committed stream. Fixtures shorter than the raise ratio of the capacity
+//! never leave `L0`.
+//!
 //! # Proof artifacts (emitted to tour-output/)
 //!
 //! | Artifact | Format | Purpose |
 //! |---|---|---|
 //! | `metrics.json` | JSON | Timing, throughput, drop counts, queue depths |
 //! | `viewmodel.hash` | Plain text | Determinism proof (BLAKE3 hex, newline-terminated) |
-//! | `ansi.capture` | ANSI text | Visual regression baseline |
+//! | `ansi.capture` | ANSI text | Visual regression baseline at [`TourConfig::ansi_width`] × [`TourConfig::ansi_height`] |
+//! | `hud.txt` | Plain text | `ansi.capture` without escapes (only with [`TourConfig::plain_hud`]) |
 //! | `timetravel.capture` | JSON | Time-travel replay artifact |
+//! | `degradation-timeline.json` | JSON | Ladder transitions by `commit_index`, for postmortem charts |
+//! | `import-report.json` | JSON | Import receipt (informational; not hashed) |
+//!
+//! [`run_tour_with_progress`] reports each stage to a callback as a
+//! [`TourProgress`], for wrappers that show progress without scraping
+//! output; the artifacts are the same with or without it.
+//!
+//! [`summarize_tours`] rolls the `metrics.json` of many runs into one
+//! [`TourSummary`]. [`run_tour_batch`] runs many fixtures concurrently.
+//! [`verify_timetravel`] replays an EventLog to each seek point of a
+//! `timetravel.capture` and checks its hashes; [`compare_timetravel`]
+//! locates the first seek point where two captures diverge.
 //!
 //! # Determinism invariants
 //!
@@ -34,33 +62,98 @@
 //! ```text
 //! VIFEI_TOUR_BENCH_ITERS=10 cargo run -p vifei-tour --bin bench_tour --release
 //! ```
+//!
+//! Besides whole-run percentiles, `bench_tour` prints one
+//! `tour_stage_<stage>_ms_{min,p50,p95,max}=<ms>` line per
+//! [`TourStageProfile`] stage and records the same figures under
+//! `stats.stages` in its JSON artifact.
+//!
+//! `profile_tour` reports per-stage shares; set `VIFEI_TOUR_DURABILITY` to
+//! `every-append`, `every-<N>`, or `on-drop` to compare the append stage
+//! under each [`DurabilityPolicy`].
 
 mod artifacts;
 mod metrics;
+mod timetravel;
 
-use artifacts::emit_artifacts;
-pub use artifacts::{SeekPoint, TimeTravelCapture};
+use artifacts::{emit_artifacts, write_import_report, CaptureOptions};
+pub use artifacts::{
+    DegradationTimeline, SeekPoint, TimeTravelCapture, DEFAULT_ANSI_HEIGHT, DEFAULT_ANSI_WIDTH,
+};
 use metrics::build_metrics;
-pub use metrics::{DegradationTransition, TourMetrics};
+pub use metrics::{
+    read_tour_metrics, summarize_tours, DegradationTransition, TourMetrics, TourRunSummary,
+    TourSummary,
+};
+use std::collections::HashSet;
 use std::fs;
 use std::io::{self, BufReader};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
 use std::time::Duration;
 use std::time::Instant;
-use vifei_core::eventlog::EventLogWriter;
-use vifei_core::projection::{project, viewmodel_hash, ProjectionInvariants};
+pub use timetravel::{
+    compare_timetravel, read_timetravel_capture, verify_timetravel, SeekPointDivergence,
+    SeekPointMismatch, TimeTravelComparison, TimeTravelVerification,
+};
+use vifei_core::backpressure::{queue_pressure, BackpressureController};
+use vifei_core::blob_store::BlobStore;
+use vifei_core::error::VifeiError;
+use vifei_core::eventlog::{
+    validate_eventlog, DurabilityPolicy, EventLogIntegrityReport, EventLogWriter,
+};
+use vifei_core::projection::{project, viewmodel_hash, ProjectionInvariants, ViewModel};
 use vifei_core::reducer::{reduce_in_place, state_hash, State};
-use vifei_import::cassette::parse_cassette;
+use vifei_import::cassette::parse_cassette_with_mapping;
+use vifei_import::mapping::Mapping;
+use vifei_import::offload::{offload_large_payloads, DEFAULT_OFFLOAD_THRESHOLD_BYTES};
+use vifei_import::otel::parse_otel_with_report;
+use vifei_import::report::ImportReport;
+
+/// Events handed to [`EventLogWriter::append_batch`] per call in the
+/// append stage. The backpressure controller observes once per batch.
+pub const APPEND_BATCH_EVENTS: usize = 1024;
+
+/// Queue capacity the append stage's queue pressure is measured against.
+pub const QUEUE_CAPACITY_EVENTS: usize = 8 * APPEND_BATCH_EVENTS;
+
+/// Source format of a Tour fixture.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FixtureFormat {
+    /// Agent Cassette JSONL, read with [`TourConfig::mapping`].
+    #[default]
+    Cassette,
+    /// OpenTelemetry OTLP/JSON spans (see [`vifei_import::otel`]).
+    Otel,
+}
 
 /// Tour configuration.
 #[derive(Debug, Clone)]
 pub struct TourConfig {
-    /// Path to the fixture file (Agent Cassette JSONL).
+    /// Path to the fixture file (see [`TourConfig::format`]).
     pub fixture_path: PathBuf,
     /// Output directory for proof artifacts.
     pub output_dir: PathBuf,
     /// Enable stress mode (required for v0.1).
     pub stress: bool,
+    /// Payload text longer than this many bytes is offloaded to the blob
+    /// store during import.
+    pub offload_threshold_bytes: usize,
+    /// Field mapping applied when parsing a cassette fixture.
+    pub mapping: Mapping,
+    /// Fixture source format.
+    pub format: FixtureFormat,
+    /// Sync policy of the append stage's writer. Affects timing only, never
+    /// artifacts.
+    pub durability: DurabilityPolicy,
+    /// Terminal width `ansi.capture` is laid out at, in columns.
+    pub ansi_width: u16,
+    /// Terminal height `ansi.capture` is clipped to, in rows.
+    pub ansi_height: u16,
+    /// Also write `hud.txt`, the `ansi.capture` text without escapes.
+    pub plain_hud: bool,
 }
 
 impl TourConfig {
@@ -70,6 +163,13 @@ impl TourConfig {
             fixture_path: fixture_path.into(),
             output_dir: PathBuf::from("tour-output"),
             stress: true,
+            offload_threshold_bytes: DEFAULT_OFFLOAD_THRESHOLD_BYTES,
+            mapping: Mapping::default(),
+            format: FixtureFormat::Cassette,
+            durability: DurabilityPolicy::default(),
+            ansi_width: DEFAULT_ANSI_WIDTH,
+            ansi_height: DEFAULT_ANSI_HEIGHT,
+            plain_hud: false,
         }
     }
 
@@ -78,6 +178,43 @@ impl TourConfig {
         self.output_dir = dir.into();
         self
     }
+
+    /// Set the payload offload threshold in bytes.
+    pub fn with_offload_threshold(mut self, bytes: usize) -> Self {
+        self.offload_threshold_bytes = bytes;
+        self
+    }
+
+    /// Parse the fixture with a nonstandard field mapping.
+    pub fn with_mapping(mut self, mapping: Mapping) -> Self {
+        self.mapping = mapping;
+        self
+    }
+
+    /// Set the fixture source format.
+    pub fn with_format(mut self, format: FixtureFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the append stage's durability policy.
+    pub fn with_durability(mut self, durability: DurabilityPolicy) -> Self {
+        self.durability = durability;
+        self
+    }
+
+    /// Set the terminal size `ansi.capture` is rendered at.
+    pub fn with_ansi_size(mut self, width: u16, height: u16) -> Self {
+        self.ansi_width = width;
+        self.ansi_height = height;
+        self
+    }
+
+    /// Also emit the escape-free `hud.txt` capture.
+    pub fn with_plain_hud(mut self) -> Self {
+        self.plain_hud = true;
+        self
+    }
 }
 
 /// Result of a Tour run.
@@ -89,6 +226,12 @@ pub struct TourResult {
     pub metrics: TourMetrics,
     /// The viewmodel hash.
     pub viewmodel_hash: String,
+    /// The projected ViewModel the hash was computed over.
+    pub viewmodel: ViewModel,
+    /// Receipt for the fixture import stage.
+    pub import_report: ImportReport,
+    /// `commit_index` sequence check of the appended EventLog.
+    pub integrity: EventLogIntegrityReport,
 }
 
 /// Stage-level timing profile for a Tour run.
@@ -100,39 +243,120 @@ pub struct TourStageProfile {
     pub projection: Duration,
     pub metrics_emit: Duration,
     pub total: Duration,
+    /// Syncs issued by the append writer under [`TourConfig::durability`].
+    pub append_syncs: u64,
+}
+
+/// Stage transition reported by [`run_tour_with_progress`], in pipeline
+/// order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TourProgress {
+    /// Parsing the fixture is about to start.
+    ParseStarted,
+    /// The fixture was parsed.
+    Parsed {
+        /// Events imported from the fixture.
+        events: usize,
+    },
+    /// One more batch of imported events went through the append writer.
+    AppendProgress {
+        /// Imported events appended so far.
+        appended: usize,
+        /// Imported events to append in all.
+        total: usize,
+    },
+    /// Every committed event was reduced and the seek points captured.
+    Reduced {
+        /// Committed events, including detection events from the writer.
+        committed: usize,
+    },
+    /// The proof artifacts were written to `output_dir`.
+    ArtifactsWritten,
+}
+
+/// Seek point for `state` after the event at `commit_index`, with the
+/// pressure and level of its projected ViewModel.
+fn seek_point(commit_index: u64, state: &State) -> SeekPoint {
+    let vm = project(state, &ProjectionInvariants::new());
+    SeekPoint {
+        commit_index,
+        state_hash: state_hash(state),
+        viewmodel_hash: viewmodel_hash(&vm),
+        queue_pressure_micro: vm.queue_pressure_fixed.max(0) as u64,
+        degradation_level: vm.degradation_level,
+    }
+}
+
+/// `commit_index` sequence check of the EventLog as written to `path`,
+/// rather than of the append results, so a line lost on the way to disk
+/// shows up as a gap.
+fn appended_integrity(path: &Path) -> io::Result<EventLogIntegrityReport> {
+    Ok(validate_eventlog(path)?.integrity)
 }
 
 /// Run the Tour stress harness.
-pub fn run_tour(config: &TourConfig) -> io::Result<TourResult> {
-    let (result, _) = run_tour_with_profile(config)?;
+pub fn run_tour(config: &TourConfig) -> Result<TourResult, VifeiError> {
+    run_tour_with_progress(config, |_| {})
+}
+
+/// [`run_tour`] that reports each stage to `progress` as it happens.
+///
+/// The callback sees [`TourProgress::ParseStarted`] first and one
+/// `AppendProgress` per append batch. It cannot change the outcome: the
+/// artifacts are byte-identical to [`run_tour`]'s.
+pub fn run_tour_with_progress(
+    config: &TourConfig,
+    mut progress: impl FnMut(TourProgress),
+) -> Result<TourResult, VifeiError> {
+    let (result, _) = run_tour_profiled(config, &mut progress)?;
     Ok(result)
 }
 
 /// Run the Tour stress harness and return stage-level timing profile.
-pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, TourStageProfile)> {
+pub fn run_tour_with_profile(
+    config: &TourConfig,
+) -> Result<(TourResult, TourStageProfile), VifeiError> {
+    run_tour_profiled(config, &mut |_| {})
+}
+
+fn run_tour_profiled(
+    config: &TourConfig,
+    progress: &mut dyn FnMut(TourProgress),
+) -> Result<(TourResult, TourStageProfile), VifeiError> {
     // Validate stress mode is enabled
     if !config.stress {
         return Err(io::Error::new(
             io::ErrorKind::InvalidInput,
             "Tour requires --stress flag in v0.1",
-        ));
+        )
+        .into());
     }
     let total_start = Instant::now();
 
     // Stage 1: Parse fixture
     let parse_start = Instant::now();
+    progress(TourProgress::ParseStarted);
     let fixture_file = fs::File::open(&config.fixture_path)?;
     let reader = BufReader::new(fixture_file);
-    let events = parse_cassette(reader);
+    let (mut events, import_report) = match config.format {
+        FixtureFormat::Cassette => {
+            let parsed = parse_cassette_with_mapping(reader, &config.mapping);
+            (parsed.events, parsed.report)
+        }
+        FixtureFormat::Otel => {
+            let parsed = parse_otel_with_report(reader);
+            (parsed.events, parsed.report)
+        }
+    };
     let parse_fixture = parse_start.elapsed();
 
     let imported_event_count = events.len();
     if imported_event_count == 0 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "Fixture contains no events",
-        ));
+        return Err(VifeiError::EmptyFixture);
     }
+    progress(TourProgress::Parsed {
+        events: imported_event_count,
+    });
 
     // Create output directory
     fs::create_dir_all(&config.output_dir)?;
@@ -142,15 +366,54 @@ pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, Tou
     let append_start = Instant::now();
     let temp_dir = tempfile::tempdir()?;
     let eventlog_path = temp_dir.path().join("eventlog.jsonl");
-    let mut writer = EventLogWriter::open(&eventlog_path)?;
+    let blob_store = BlobStore::open(temp_dir.path().join("blobs"))?;
+    offload_large_payloads(&mut events, &blob_store, config.offload_threshold_bytes)?;
+    let mut writer = EventLogWriter::open_with_policy(&eventlog_path, config.durability)?;
     let mut committed_events = Vec::with_capacity(imported_event_count * 2);
 
-    for event in events {
-        let result = writer.append(event)?;
+    // Decisions are stamped with the latest timestamp seen so far, so their
+    // source never moves backward
+    let mut controller = BackpressureController::new();
+    let mut decisions = 0;
+    let mut latest_ns = 0;
+    let mut last_run_id = String::new();
+    let mut events = events.into_iter().peekable();
+    let mut appended = 0;
+    while events.peek().is_some() {
+        let pressure = queue_pressure(imported_event_count - appended, QUEUE_CAPACITY_EVENTS);
+        let chunk: Vec<_> = events.by_ref().take(APPEND_BATCH_EVENTS).collect();
+        let mut batch = Vec::with_capacity(chunk.len() + 1);
+        if let Some(change) = controller.observe(pressure) {
+            latest_ns = latest_ns.max(chunk[0].timestamp_ns);
+            batch.push(change.import_event(&chunk[0].run_id, decisions, latest_ns));
+            decisions += 1;
+        }
+        latest_ns = chunk.iter().fold(latest_ns, |ns, e| ns.max(e.timestamp_ns));
+        last_run_id.clone_from(&chunk[chunk.len() - 1].run_id);
+        appended += chunk.len();
+        batch.extend(chunk);
+        for result in writer.append_batch(batch)? {
+            committed_events.extend(result.detection_events().iter().cloned());
+            committed_events.push(result.committed_event().clone());
+        }
+        progress(TourProgress::AppendProgress {
+            appended,
+            total: imported_event_count,
+        });
+    }
+    let recovery: Vec<_> = controller
+        .drain()
+        .iter()
+        .zip(decisions..)
+        .map(|(change, seq)| change.import_event(&last_run_id, seq, latest_ns))
+        .collect();
+    for result in writer.append_batch(recovery)? {
         committed_events.extend(result.detection_events().iter().cloned());
         committed_events.push(result.committed_event().clone());
     }
+    let append_syncs = writer.sync_count();
     drop(writer);
+    let integrity = appended_integrity(&eventlog_path)?;
     let append_writer = append_start.elapsed();
 
     // Stage 3: Reduce all events with periodic seek point capture
@@ -168,16 +431,13 @@ pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, Tou
         let is_interval = (i + 1) % seek_interval == 0;
         let is_last = i == committed_event_count - 1;
         if is_interval || is_last {
-            let inv = ProjectionInvariants::new();
-            let vm = project(&state, &inv);
-            seek_points.push(SeekPoint {
-                commit_index: event.commit_index,
-                state_hash: state_hash(&state),
-                viewmodel_hash: viewmodel_hash(&vm),
-            });
+            seek_points.push(seek_point(event.commit_index, &state));
         }
     }
     let reducer = reducer_start.elapsed();
+    progress(TourProgress::Reduced {
+        committed: committed_event_count,
+    });
 
     // Stage 4: Project final state
     let projection_start = Instant::now();
@@ -198,7 +458,14 @@ pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, Tou
         &vm_hash,
         committed_event_count,
         seek_points,
+        CaptureOptions {
+            width: config.ansi_width,
+            height: config.ansi_height,
+            plain_hud: config.plain_hud,
+        },
     )?;
+    write_import_report(&config.output_dir, &import_report)?;
+    progress(TourProgress::ArtifactsWritten);
     let metrics_emit = metrics_start.elapsed();
     let total = total_start.elapsed();
 
@@ -206,6 +473,9 @@ pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, Tou
         output_dir: config.output_dir.clone(),
         metrics,
         viewmodel_hash: vm_hash,
+        viewmodel,
+        import_report,
+        integrity,
     };
     let profile = TourStageProfile {
         parse_fixture,
@@ -214,19 +484,80 @@ pub fn run_tour_with_profile(config: &TourConfig) -> io::Result<(TourResult, Tou
         projection,
         metrics_emit,
         total,
+        append_syncs,
     };
 
     Ok((result, profile))
 }
 
+/// Run one Tour per config on a pool of worker threads, one per available
+/// core.
+///
+/// Results are returned in `configs` order. Runs share no state, so each
+/// run's artifacts are identical to a sequential [`run_tour`] of its config,
+/// and a failing run never stops the others. A config whose `output_dir`
+/// repeats an earlier one fails with `InvalidInput` without running.
+pub fn run_tour_batch(configs: &[TourConfig]) -> Vec<Result<TourResult, VifeiError>> {
+    let mut seen = HashSet::new();
+    let runnable: Vec<bool> = configs
+        .iter()
+        .map(|config| seen.insert(config.output_dir.as_path()))
+        .collect();
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(configs.len());
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let (next, runnable) = (&next, &runnable);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(config) = configs.get(i) else {
+                    break;
+                };
+                let result = if runnable[i] {
+                    run_tour(config)
+                } else {
+                    Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!(
+                            "output dir {} is shared with an earlier run in the batch",
+                            config.output_dir.display()
+                        ),
+                    )
+                    .into())
+                };
+                // The receiver outlives the scope, so send cannot fail.
+                let _ = tx.send((i, result));
+            });
+        }
+    });
+    drop(tx);
+
+    let mut results: Vec<Option<Result<TourResult, VifeiError>>> =
+        configs.iter().map(|_| None).collect();
+    for (i, result) in rx {
+        results[i] = Some(result);
+    }
+    results
+        .into_iter()
+        .map(|result| result.expect("every config is run by a worker"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::artifacts::strip_sgr;
     use std::fs;
     use std::io::{BufReader, Cursor};
-    use std::path::Path;
     use tempfile::tempdir;
+    use vifei_core::event::{CommittedEvent, EventPayload, ImportEvent, Tier};
     use vifei_core::eventlog::{read_eventlog, EventLogWriter};
+    use vifei_core::projection::LadderLevel;
+    use vifei_import::cassette::parse_cassette;
 
     fn create_fixture(dir: &Path) -> PathBuf {
         let fixture_path = dir.join("test.jsonl");
@@ -277,6 +608,44 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("--stress"));
     }
 
+    #[test]
+    fn run_tour_with_progress_reports_stages_in_order() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let observed = dir.path().join("observed");
+        let plain = dir.path().join("plain");
+
+        let mut seen = Vec::new();
+        run_tour_with_progress(
+            &TourConfig::new(&fixture_path).with_output_dir(&observed),
+            |event| seen.push(event),
+        )
+        .unwrap();
+        assert_eq!(
+            seen,
+            [
+                TourProgress::ParseStarted,
+                TourProgress::Parsed { events: 4 },
+                TourProgress::AppendProgress {
+                    appended: 4,
+                    total: 4
+                },
+                TourProgress::Reduced { committed: 4 },
+                TourProgress::ArtifactsWritten,
+            ]
+        );
+
+        // Observing does not change the artifacts.
+        run_tour(&TourConfig::new(&fixture_path).with_output_dir(&plain)).unwrap();
+        for artifact in ["viewmodel.hash", "timetravel.capture", "ansi.capture"] {
+            assert_eq!(
+                fs::read(observed.join(artifact)).unwrap(),
+                fs::read(plain.join(artifact)).unwrap(),
+                "{artifact}"
+            );
+        }
+    }
+
     #[test]
     fn run_tour_empty_fixture_fails() {
         let dir = tempdir().unwrap();
@@ -287,7 +656,166 @@ mod tests {
 
         let result = run_tour(&config);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("no events"));
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("no events"));
+        assert!(matches!(err, VifeiError::EmptyFixture));
+    }
+
+    #[test]
+    fn run_tour_missing_fixture_is_io_not_found() {
+        let dir = tempdir().unwrap();
+        let config = TourConfig::new(dir.path().join("missing.jsonl"))
+            .with_output_dir(dir.path().join("output"));
+
+        let err = run_tour(&config).unwrap_err();
+        assert!(matches!(&err, VifeiError::Io(e) if e.kind() == io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    fn run_tour_writes_deterministic_import_report() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let output1 = dir.path().join("output1");
+        let output2 = dir.path().join("output2");
+
+        let result = run_tour(&TourConfig::new(&fixture_path).with_output_dir(&output1)).unwrap();
+        run_tour(&TourConfig::new(&fixture_path).with_output_dir(&output2)).unwrap();
+
+        let report1 = fs::read_to_string(output1.join("import-report.json")).unwrap();
+        let report2 = fs::read_to_string(output2.join("import-report.json")).unwrap();
+        assert_eq!(report1, report2, "import-report.json must be deterministic");
+
+        let report: serde_json::Value = serde_json::from_str(&report1).unwrap();
+        assert_eq!(report["lines_read"], 4);
+        assert_eq!(report["events_emitted"], 4);
+        assert_eq!(report["parsed_by_type"]["tool_use"], 1);
+        assert_eq!(report["run_ids"], serde_json::json!(["test-1"]));
+        assert_eq!(result.import_report.events_emitted, 4);
+    }
+
+    #[test]
+    fn generated_fixture_tours_deterministically() {
+        use vifei_import::fixture::{write_fixture, FixtureSpec};
+
+        let dir = tempdir().unwrap();
+        let fixture_path = dir.path().join("generated.jsonl");
+        let spec = FixtureSpec {
+            seed: 11,
+            sessions: 2,
+            tool_calls: 40,
+            error_rate: 0.1,
+            skew_at: vec![5, 30],
+            ..FixtureSpec::default()
+        };
+        write_fixture(&spec, fs::File::create(&fixture_path).unwrap()).unwrap();
+
+        let run = |name: &str| {
+            run_tour(&TourConfig::new(&fixture_path).with_output_dir(dir.path().join(name)))
+                .unwrap()
+        };
+        let first = run("output1");
+        assert_eq!(first.viewmodel_hash, run("output2").viewmodel_hash);
+        // Two injected skews add two ClockSkewDetected events.
+        assert_eq!(
+            first.metrics.event_count_total as u64,
+            first.import_report.events_emitted + 2
+        );
+    }
+
+    #[test]
+    fn run_tour_reads_otel_fixture() {
+        let dir = tempdir().unwrap();
+        let fixture =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures/otel-spans-small.json");
+        let config = TourConfig::new(&fixture)
+            .with_output_dir(dir.path().join("output"))
+            .with_format(FixtureFormat::Otel);
+        let result = run_tour(&config).unwrap();
+        assert_eq!(result.import_report.source_id, "otel");
+        assert_eq!(result.import_report.events_emitted, 8);
+        assert_eq!(result.metrics.tier_a_drops, 0);
+    }
+
+    #[test]
+    fn run_tour_with_offload_threshold_is_deterministic() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+
+        let run = |name: &str| {
+            let config = TourConfig::new(&fixture_path)
+                .with_output_dir(dir.path().join(name))
+                .with_offload_threshold(1);
+            run_tour(&config).unwrap()
+        };
+        let first = run("output1");
+        let second = run("output2");
+
+        assert_eq!(first.viewmodel_hash, second.viewmodel_hash);
+        assert_eq!(first.metrics.event_count_total, 4);
+        assert_eq!(
+            TourConfig::new(&fixture_path).offload_threshold_bytes,
+            DEFAULT_OFFLOAD_THRESHOLD_BYTES
+        );
+    }
+
+    #[test]
+    fn durability_policy_changes_syncs_not_artifacts() {
+        let dir = tempdir().unwrap();
+        let fixture_path = dir.path().join("tier-b.jsonl");
+        // Unmapped record types import as Generic Tier B events. The
+        // append stage batches, so the middle batch holds Tier B only:
+        // Tier A batches always sync, Tier B-only ones per policy.
+        let mut content = String::from(
+            r#"{"type":"session_start","session_id":"test-1","timestamp":"2026-01-01T00:00:00Z","agent":"test"}"#,
+        );
+        for _ in 0..APPEND_BATCH_EVENTS * 2 {
+            content.push_str(
+                "\n{\"type\":\"thinking\",\"session_id\":\"test-1\",\"timestamp\":\"2026-01-01T00:00:01Z\"}",
+            );
+        }
+        content.push_str(
+            "\n{\"type\":\"session_end\",\"session_id\":\"test-1\",\"timestamp\":\"2026-01-01T00:00:03Z\"}",
+        );
+        fs::write(&fixture_path, content).unwrap();
+
+        let run = |name: &str, durability: DurabilityPolicy| {
+            let config = TourConfig::new(&fixture_path)
+                .with_output_dir(dir.path().join(name))
+                .with_durability(durability);
+            run_tour_with_profile(&config).unwrap()
+        };
+        let (on_drop, on_drop_profile) = run("on-drop", DurabilityPolicy::OnDrop);
+        let (every, every_profile) = run("every", DurabilityPolicy::EveryAppend);
+
+        assert_eq!(on_drop.viewmodel_hash, every.viewmodel_hash);
+        assert_eq!(on_drop_profile.append_syncs, 2);
+        assert_eq!(every_profile.append_syncs, 3);
+        assert_eq!(
+            TourConfig::new(&fixture_path).durability,
+            DurabilityPolicy::OnDrop
+        );
+    }
+
+    #[test]
+    fn run_tour_with_vendor_mapping_matches_standard_fixture() {
+        let dir = tempdir().unwrap();
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../fixtures");
+        let mapping = Mapping::load(&fixtures.join("vendor-session.mapping.json")).unwrap();
+
+        let standard = run_tour(
+            &TourConfig::new(fixtures.join("small-session.jsonl"))
+                .with_output_dir(dir.path().join("standard")),
+        )
+        .unwrap();
+        let vendor = run_tour(
+            &TourConfig::new(fixtures.join("vendor-session.jsonl"))
+                .with_output_dir(dir.path().join("vendor"))
+                .with_mapping(mapping),
+        )
+        .unwrap();
+
+        assert_eq!(vendor.viewmodel_hash, standard.viewmodel_hash);
+        assert_eq!(vendor.import_report.parsed_by_type.get("call"), Some(&4));
     }
 
     #[test]
@@ -310,6 +838,8 @@ mod tests {
         let metrics: TourMetrics = serde_json::from_str(&metrics_content).unwrap();
         assert_eq!(metrics.event_count_total, 4);
         assert_eq!(metrics.tier_a_drops, 0);
+        assert!(result.integrity.is_clean());
+        assert_eq!(result.integrity.total_events, 4);
 
         // Check viewmodel.hash format
         let hash_content = fs::read_to_string(output_dir.join("viewmodel.hash")).unwrap();
@@ -344,6 +874,118 @@ mod tests {
         );
     }
 
+    #[test]
+    fn events_per_tier_sums_to_total_and_is_stable() {
+        let dir = tempdir().unwrap();
+        let fixture_path = dir.path().join("mixed-tier.jsonl");
+        let content = r#"{"type":"session_start","session_id":"test-1","timestamp":"2026-01-01T00:00:00Z","agent":"test"}
+{"type":"heartbeat","session_id":"test-1","timestamp":"2026-01-01T00:00:01Z"}
+{"type":"tool_use","session_id":"test-1","timestamp":"2026-01-01T00:00:03Z","tool":"Read","id":"t1","args":{}}
+{"type":"progress","session_id":"test-1","timestamp":"2026-01-01T00:00:02Z"}
+{"type":"heartbeat","session_id":"test-1","timestamp":"2026-01-01T00:00:04Z"}
+{"type":"session_end","session_id":"test-1","timestamp":"2026-01-01T00:00:05Z"}"#;
+        fs::write(&fixture_path, content).unwrap();
+
+        let run = |name: &str| {
+            run_tour(&TourConfig::new(&fixture_path).with_output_dir(dir.path().join(name)))
+                .unwrap()
+                .metrics
+        };
+        let first = run("first");
+        let second = run("second");
+
+        assert_eq!(first.events_per_tier.get(&Tier::B), Some(&3));
+        assert!(first.events_per_tier.get(&Tier::A).is_some_and(|&n| n >= 3));
+        assert_eq!(
+            first.events_per_tier.values().sum::<u64>(),
+            first.event_count_total as u64
+        );
+        assert_eq!(first.events_per_tier, second.events_per_tier);
+
+        let raw: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(dir.path().join("first/metrics.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(raw["events_per_tier"]["B"], 3);
+    }
+
+    #[test]
+    fn degradation_timeline_follows_policy_decisions() {
+        let dir = tempdir().unwrap();
+        let fixture_path = dir.path().join("backpressure.jsonl");
+        let content = r#"{"type":"session_start","session_id":"bp","timestamp":"2026-01-01T00:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"bp","timestamp":"2026-01-01T00:00:01Z","tool":"Bash","id":"t1"}
+{"type":"policy_decision","session_id":"bp","timestamp":"2026-01-01T00:00:02Z","from_level":"L0","to_level":"L1","trigger":"queue_pressure","queue_pressure":0.62}
+{"type":"tool_result","session_id":"bp","timestamp":"2026-01-01T00:00:03Z","tool":"Bash","tool_use_id":"t1","status":"success"}
+{"type":"policy_decision","session_id":"bp","timestamp":"2026-01-01T00:00:04Z","from_level":"L1","to_level":"L3","trigger":"queue_pressure","queue_pressure":0.91}
+{"type":"policy_decision","session_id":"bp","timestamp":"2026-01-01T00:00:05Z","from_level":"L3","to_level":"L0","trigger":"recovered","queue_pressure":0.1}
+{"type":"session_end","session_id":"bp","timestamp":"2026-01-01T00:00:06Z","exit_code":0}"#;
+        fs::write(&fixture_path, content).unwrap();
+        let output_dir = dir.path().join("out");
+        let result =
+            run_tour(&TourConfig::new(&fixture_path).with_output_dir(&output_dir)).unwrap();
+
+        let events = parse_cassette(Cursor::new(content));
+        let mut state = State::new();
+        for (i, event) in events.into_iter().enumerate() {
+            reduce_in_place(&mut state, &CommittedEvent::commit(event, i as u64));
+        }
+        let transitions = &result.metrics.degradation_transitions;
+        assert_eq!(transitions.len(), state.policy_decisions.len());
+        for (transition, decision) in transitions.iter().zip(&state.policy_decisions) {
+            assert_eq!(transition.commit_index, decision.commit_index);
+            assert_eq!(transition.from_level, decision.from_level);
+            assert_eq!(transition.to_level, decision.to_level);
+            assert_eq!(transition.trigger, decision.trigger);
+        }
+        let indices: Vec<u64> = transitions.iter().map(|t| t.commit_index).collect();
+        assert_eq!(indices, [2, 4, 5]);
+        assert_eq!(result.metrics.max_degradation_level, "L3");
+
+        let timeline: DegradationTimeline = serde_json::from_str(
+            &fs::read_to_string(output_dir.join("degradation-timeline.json")).unwrap(),
+        )
+        .unwrap();
+        let timeline_indices: Vec<u64> = timeline
+            .transitions
+            .iter()
+            .map(|t| t.commit_index)
+            .collect();
+        assert_eq!(timeline_indices, indices);
+        assert_eq!(
+            timeline.projection_invariants_version,
+            result.metrics.projection_invariants_version
+        );
+    }
+
+    #[test]
+    fn metrics_record_reducer_version_for_cross_run_diffs() {
+        let dir = tempdir().unwrap();
+        let run = |name: &str| {
+            let output_dir = dir.path().join(name);
+            run_tour(
+                &TourConfig::new("../../fixtures/small-session.jsonl").with_output_dir(&output_dir),
+            )
+            .unwrap();
+            let raw: serde_json::Value =
+                serde_json::from_str(&fs::read_to_string(output_dir.join("metrics.json")).unwrap())
+                    .unwrap();
+            raw
+        };
+        let first = run("a");
+        let second = run("b");
+
+        // `verify --strict` tours twice; either run's metrics name the
+        // reducer that produced them, so a version change shows as a diff.
+        assert_eq!(first["reducer_version"], "reducer-v0.9");
+        assert_eq!(first["reducer_version"], second["reducer_version"]);
+
+        let mut legacy = first.clone();
+        legacy.as_object_mut().unwrap().remove("reducer_version");
+        let legacy: TourMetrics = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.reducer_version, "");
+    }
+
     #[test]
     fn run_tour_determinism() {
         let dir = tempdir().unwrap();
@@ -373,6 +1015,101 @@ mod tests {
         assert_eq!(ansi1, ansi2);
     }
 
+    #[test]
+    fn ansi_capture_lists_errors_and_top_tools() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let output = dir.path().join("output");
+        run_tour(&TourConfig::new(&fixture_path).with_output_dir(&output)).unwrap();
+
+        let ansi = fs::read_to_string(output.join("ansi.capture")).unwrap();
+        let plain: Vec<String> = ansi.lines().map(strip_sgr).collect();
+        assert!(plain.contains(&"  Errors:   0".to_string()), "{plain:?}");
+        let tools = plain
+            .iter()
+            .position(|line| line == "── Top tools ──")
+            .expect("top tools section");
+        assert_eq!(plain[tools + 1], "  Read  1 calls  0 errors");
+    }
+
+    #[test]
+    fn ansi_capture_follows_configured_size() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let capture = |name: &str, width: u16, height: u16| {
+            let output = dir.path().join(name);
+            let config = TourConfig::new(&fixture_path)
+                .with_output_dir(&output)
+                .with_ansi_size(width, height);
+            run_tour(&config).unwrap();
+            fs::read(output.join("ansi.capture")).unwrap()
+        };
+
+        let wide = capture("wide", DEFAULT_ANSI_WIDTH, DEFAULT_ANSI_HEIGHT);
+        let narrow = capture("narrow", 40, 60);
+        assert_ne!(wide, narrow);
+        assert_eq!(narrow, capture("narrow-again", 40, 60));
+        assert!(String::from_utf8(narrow)
+            .unwrap()
+            .lines()
+            .all(|line| strip_sgr(line).chars().count() <= 40));
+
+        let short = String::from_utf8(capture("short", 40, 5)).unwrap();
+        assert_eq!(short.lines().count(), 5);
+    }
+
+    #[test]
+    fn batch_matches_sequential_runs_and_isolates_failures() {
+        let dir = tempdir().unwrap();
+        let fixtures = [
+            create_fixture(dir.path()),
+            create_clock_skew_fixture(dir.path()),
+            dir.path().join("missing.jsonl"),
+        ];
+        let configs: Vec<TourConfig> = fixtures
+            .iter()
+            .enumerate()
+            .map(|(i, fixture)| {
+                TourConfig::new(fixture).with_output_dir(dir.path().join(format!("batch-{i}")))
+            })
+            .collect();
+
+        let results = run_tour_batch(&configs);
+        assert_eq!(results.len(), 3);
+        assert!(results[2].is_err());
+        for (i, fixture) in fixtures[..2].iter().enumerate() {
+            let batch = results[i].as_ref().unwrap();
+            let sequential_dir = dir.path().join(format!("sequential-{i}"));
+            let sequential =
+                run_tour(&TourConfig::new(fixture).with_output_dir(&sequential_dir)).unwrap();
+            assert_eq!(batch.viewmodel_hash, sequential.viewmodel_hash);
+            for artifact in [
+                "metrics.json",
+                "ansi.capture",
+                "timetravel.capture",
+                "degradation-timeline.json",
+            ] {
+                assert_eq!(
+                    fs::read(batch.output_dir.join(artifact)).unwrap(),
+                    fs::read(sequential_dir.join(artifact)).unwrap(),
+                    "{artifact}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn batch_rejects_shared_output_dir() {
+        let dir = tempdir().unwrap();
+        let fixture = create_fixture(dir.path());
+        let config = TourConfig::new(&fixture).with_output_dir(dir.path().join("out"));
+        let results = run_tour_batch(&[config.clone(), config]);
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(run_tour_batch(&[]).is_empty());
+    }
+
     #[test]
     fn append_result_sequence_matches_eventlog_readback() {
         let dir = tempdir().unwrap();
@@ -398,6 +1135,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn appended_integrity_reads_gaps_from_disk() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_clock_skew_fixture(dir.path());
+        let events = parse_cassette(BufReader::new(fs::File::open(&fixture_path).unwrap()));
+        let eventlog_path = dir.path().join("eventlog.jsonl");
+        let mut writer = EventLogWriter::open(&eventlog_path).unwrap();
+        for event in events {
+            writer.append(event).unwrap();
+        }
+        drop(writer);
+        let clean = appended_integrity(&eventlog_path).unwrap();
+        assert!(clean.is_clean(), "{clean:?}");
+
+        // Drop the second line, as a lost write would.
+        let content = fs::read_to_string(&eventlog_path).unwrap();
+        let mut lines: Vec<&str> = content.lines().collect();
+        lines.remove(1);
+        fs::write(&eventlog_path, lines.join("\n") + "\n").unwrap();
+
+        let report = appended_integrity(&eventlog_path).unwrap();
+        assert_eq!(report.gaps, 1, "{report:?}");
+        assert_eq!(report.total_events, clean.total_events - 1);
+    }
+
     #[test]
     fn stream_fixture_parse_matches_buffered_parse() {
         let dir = tempdir().unwrap();
@@ -428,6 +1190,65 @@ mod tests {
         let point = &capture.seek_points[0];
         assert_eq!(point.state_hash.len(), 64);
         assert_eq!(point.viewmodel_hash.len(), 64);
+
+        let raw: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let keys: Vec<&str> = raw["seek_points"][0]
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        for key in [
+            "commit_index",
+            "state_hash",
+            "viewmodel_hash",
+            "queue_pressure_micro",
+            "degradation_level",
+        ] {
+            assert!(keys.contains(&key), "seek point missing {key}: {keys:?}");
+        }
+    }
+
+    #[test]
+    fn seek_point_records_projected_pressure_and_level() {
+        let mut state = State::new();
+        let event = CommittedEvent::commit(
+            ImportEvent {
+                run_id: "run-1".into(),
+                event_id: "pd-1".into(),
+                source_id: "test".into(),
+                source_seq: Some(0),
+                timestamp_ns: 1_000_000_000,
+                tier: Tier::A,
+                payload: EventPayload::PolicyDecision {
+                    from_level: "L0".into(),
+                    to_level: "L2".into(),
+                    trigger: "queue_pressure".into(),
+                    queue_pressure: 0.85,
+                },
+                payload_ref: None,
+                synthesized: false,
+            },
+            0,
+        );
+        reduce_in_place(&mut state, &event);
+
+        let point = seek_point(0, &state);
+        assert_eq!(point.queue_pressure_micro, 850_000);
+        // The level is the projection's, as in metrics.json, not the
+        // decision's `to_level`.
+        assert_eq!(point.degradation_level, LadderLevel::L0);
+        assert_eq!(seek_point(0, &State::new()).queue_pressure_micro, 0);
+    }
+
+    #[test]
+    fn seek_points_without_pressure_fields_still_parse() {
+        let capture: TimeTravelCapture = serde_json::from_str(
+            r#"{"projection_invariants_version":"v","seek_points":[{"commit_index":0,"state_hash":"a","viewmodel_hash":"b"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(capture.seek_points[0].queue_pressure_micro, 0);
+        assert_eq!(capture.seek_points[0].degradation_level, LadderLevel::L0);
     }
 
     #[test]
@@ -553,6 +1374,41 @@ mod tests {
         assert!(ansi.contains(&result.viewmodel_hash), "Hash value mismatch");
     }
 
+    #[test]
+    fn plain_hud_matches_capture_without_escapes() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let output_dir = dir.path().join("output");
+        let config = TourConfig::new(&fixture_path)
+            .with_output_dir(&output_dir)
+            .with_plain_hud();
+        let result = run_tour(&config).unwrap();
+
+        let hud = fs::read_to_string(output_dir.join("hud.txt")).unwrap();
+        for label in [
+            "Level:",
+            "Agg:",
+            "Pressure:",
+            "Drops:",
+            "Export:",
+            "Version:",
+        ] {
+            assert!(hud.contains(label), "Missing {label}");
+        }
+        assert!(hud.contains(&result.viewmodel_hash));
+        assert!(!hud.contains("\x1b["));
+        let ansi = fs::read_to_string(output_dir.join("ansi.capture")).unwrap();
+        assert_eq!(hud, strip_sgr(&ansi));
+
+        let again = dir.path().join("again");
+        run_tour(&config.clone().with_output_dir(&again)).unwrap();
+        assert_eq!(hud, fs::read_to_string(again.join("hud.txt")).unwrap());
+
+        let default_dir = dir.path().join("default");
+        run_tour(&TourConfig::new(&fixture_path).with_output_dir(&default_dir)).unwrap();
+        assert!(!default_dir.join("hud.txt").exists());
+    }
+
     #[test]
     fn ansi_capture_contains_escape_codes() {
         let dir = tempdir().unwrap();
@@ -587,4 +1443,40 @@ mod tests {
             "ansi.capture still contains placeholder text"
         );
     }
+
+    #[test]
+    fn summarize_tours_flags_run_with_tier_a_drops() {
+        let dir = tempdir().unwrap();
+        let fixture_path = create_fixture(dir.path());
+        let clean_dir = dir.path().join("clean");
+        let dirty_dir = dir.path().join("dirty");
+        let clean = run_tour(&TourConfig::new(&fixture_path).with_output_dir(&clean_dir)).unwrap();
+        run_tour(&TourConfig::new(&fixture_path).with_output_dir(&dirty_dir)).unwrap();
+
+        let mut dirty = read_tour_metrics(&dirty_dir).unwrap();
+        dirty.tier_a_drops = 2;
+        dirty.max_degradation_level = "L3".to_string();
+        dirty.degradation_level_final = "L2".to_string();
+        fs::write(
+            dirty_dir.join("metrics.json"),
+            serde_json::to_string_pretty(&dirty).unwrap(),
+        )
+        .unwrap();
+
+        let runs = [
+            ("clean", read_tour_metrics(&clean_dir).unwrap()),
+            ("dirty", read_tour_metrics(&dirty_dir).unwrap()),
+        ];
+        let summary = summarize_tours(runs.iter().map(|(label, m)| (*label, m)));
+        assert_eq!(summary.run_count, 2);
+        assert_eq!(
+            summary.event_count_total,
+            clean.metrics.event_count_total * 2
+        );
+        assert_eq!(summary.tier_a_drops_total, 2);
+        assert!(summary.any_tier_a_drops);
+        assert_eq!(summary.worst_degradation_level, "L3");
+        assert_eq!(summary.runs_with_tier_a_drops, vec!["dirty"]);
+        assert_eq!(summary.non_l0_final, vec!["dirty"]);
+    }
 }
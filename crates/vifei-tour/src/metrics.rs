@@ -1,14 +1,28 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use vifei_core::event::Tier;
 use vifei_core::projection::ViewModel;
-use vifei_core::reducer::State;
+use vifei_core::reducer::{reducer_version, State};
 
 /// Metrics emitted by Tour.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TourMetrics {
     /// Projection invariants version.
     pub projection_invariants_version: String,
+    /// Reducer version that produced the State behind these metrics.
+    /// Absent in metrics written before it was recorded; read as empty.
+    #[serde(default)]
+    pub reducer_version: String,
     /// Total number of events processed.
     pub event_count_total: usize,
+    /// Events processed per tier (the reducer's `event_counts_by_tier`).
+    /// Sums to `event_count_total`. Absent in metrics written before it was
+    /// recorded; read as empty.
+    #[serde(default)]
+    pub events_per_tier: BTreeMap<Tier, u64>,
     /// Tier A drops (must be 0 for CI pass).
     pub tier_a_drops: u64,
     /// Maximum degradation level reached.
@@ -27,9 +41,14 @@ pub struct TourMetrics {
     pub export_safety_state: String,
 }
 
-/// A degradation level transition.
+/// A degradation level transition, from one `PolicyDecision` in the
+/// reduced State.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DegradationTransition {
+    /// `commit_index` of the `PolicyDecision` event. Absent in metrics
+    /// written before it was recorded; read as 0.
+    #[serde(default)]
+    pub commit_index: u64,
     /// Level before transition.
     pub from_level: String,
     /// Level after transition.
@@ -40,6 +59,97 @@ pub struct DegradationTransition {
     pub queue_pressure: f64,
 }
 
+/// One Tour run in a [`TourSummary`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TourRunSummary {
+    /// Run label (its output directory).
+    pub label: String,
+    /// Events processed by the run.
+    pub event_count_total: usize,
+    /// Tier A drops in the run.
+    pub tier_a_drops: u64,
+    /// Final degradation level of the run.
+    pub degradation_level_final: String,
+}
+
+/// Rollup of many Tour runs' metrics.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TourSummary {
+    /// Runs summarized.
+    pub run_count: usize,
+    /// Events processed across all runs.
+    pub event_count_total: usize,
+    /// Tier A drops across all runs.
+    pub tier_a_drops_total: u64,
+    /// Whether any run dropped a Tier A event (an I1 violation).
+    pub any_tier_a_drops: bool,
+    /// Highest degradation level any run reached, or `L0` with no runs.
+    pub worst_degradation_level: String,
+    /// Labels of runs that dropped Tier A events, in input order.
+    pub runs_with_tier_a_drops: Vec<String>,
+    /// Labels of runs whose final degradation level is not `L0`, in input
+    /// order.
+    pub non_l0_final: Vec<String>,
+    /// Per-run figures, in input order.
+    pub runs: Vec<TourRunSummary>,
+}
+
+/// Read the `metrics.json` in a Tour output directory.
+pub fn read_tour_metrics(output_dir: &Path) -> io::Result<TourMetrics> {
+    let path = output_dir.join("metrics.json");
+    let bytes = fs::read(&path)?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse {}: {e}", path.display()),
+        )
+    })
+}
+
+/// Roll up labelled Tour metrics.
+pub fn summarize_tours<'a>(
+    runs: impl IntoIterator<Item = (&'a str, &'a TourMetrics)>,
+) -> TourSummary {
+    let mut summary = TourSummary {
+        run_count: 0,
+        event_count_total: 0,
+        tier_a_drops_total: 0,
+        any_tier_a_drops: false,
+        worst_degradation_level: "L0".to_string(),
+        runs_with_tier_a_drops: Vec::new(),
+        non_l0_final: Vec::new(),
+        runs: Vec::new(),
+    };
+    for (label, metrics) in runs {
+        summary.run_count += 1;
+        summary.event_count_total += metrics.event_count_total;
+        summary.tier_a_drops_total += metrics.tier_a_drops;
+        if metrics.tier_a_drops > 0 {
+            summary.any_tier_a_drops = true;
+            summary.runs_with_tier_a_drops.push(label.to_string());
+        }
+        if metrics.degradation_level_final != "L0" {
+            summary.non_l0_final.push(label.to_string());
+        }
+        // Level names sort in severity order (L0 < L1 < ... < L5).
+        for level in [
+            &metrics.max_degradation_level,
+            &metrics.degradation_level_final,
+        ] {
+            if *level > summary.worst_degradation_level {
+                summary.worst_degradation_level = level.clone();
+            }
+        }
+        summary.runs.push(TourRunSummary {
+            label: label.to_string(),
+            event_count_total: metrics.event_count_total,
+            tier_a_drops: metrics.tier_a_drops,
+            degradation_level_final: metrics.degradation_level_final.clone(),
+        });
+    }
+    summary
+}
+
 /// Build deterministic Tour metrics from reduced state and projected view model.
 pub(crate) fn build_metrics(
     state: &State,
@@ -51,6 +161,7 @@ pub(crate) fn build_metrics(
         .policy_decisions
         .iter()
         .map(|pd| DegradationTransition {
+            commit_index: pd.commit_index,
             from_level: pd.from_level.clone(),
             to_level: pd.to_level.clone(),
             trigger: pd.trigger.clone(),
@@ -71,7 +182,9 @@ pub(crate) fn build_metrics(
 
     TourMetrics {
         projection_invariants_version: viewmodel.projection_invariants_version.clone(),
+        reducer_version: reducer_version().to_string(),
         event_count_total: committed_event_count,
+        events_per_tier: state.event_counts_by_tier.clone(),
         tier_a_drops: viewmodel.tier_a_drops,
         max_degradation_level,
         degradation_level_final: final_level,
@@ -0,0 +1,429 @@
+//! Independent check of a `timetravel.capture` against its EventLog.
+//!
+//! [`verify_timetravel`] replays the EventLog from `commit_index` 0 and, at
+//! every seek point, recomputes `state_hash` and `viewmodel_hash` from the
+//! replayed state. The capture is honest only if every recomputed hash
+//! equals the captured one. Replay stops at the first mismatch.
+//!
+//! Only the hashes are checked. `queue_pressure_micro` and
+//! `degradation_level` are derived from the same ViewModel the
+//! `viewmodel_hash` covers, and read as defaults in older captures.
+//!
+//! [`compare_timetravel`] checks two captures against each other instead,
+//! with no EventLog: seek points are paired by `commit_index` and the
+//! earliest `state_hash` and `viewmodel_hash` divergences are reported, so
+//! "the final hashes differ" narrows to the stretch of commits between the
+//! last agreeing seek point and the first diverging one. Captures taken at
+//! different seek intervals are compared on the commit indices they share.
+
+use crate::{seek_point, SeekPoint, TimeTravelCapture};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use vifei_core::eventlog::EventLogReader;
+use vifei_core::projection::PROJECTION_INVARIANTS_VERSION;
+use vifei_core::reducer::{reduce_in_place, State};
+
+/// First seek point whose capture disagrees with the replayed EventLog.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeekPointMismatch {
+    /// `commit_index` of the seek point.
+    pub commit_index: u64,
+    /// `state_hash` or `viewmodel_hash`, or `commit_index` when the
+    /// EventLog has no event at that index.
+    pub field: String,
+    /// Value in the capture.
+    pub captured: String,
+    /// Value recomputed from the EventLog.
+    pub recomputed: String,
+}
+
+/// Outcome of [`verify_timetravel`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimeTravelVerification {
+    /// Seek points whose hashes matched.
+    pub seek_points_checked: usize,
+    /// Seek points in the capture.
+    pub seek_points_total: usize,
+    /// Events reduced before replay stopped.
+    pub events_replayed: u64,
+    /// First disagreement, if any.
+    pub first_mismatch: Option<SeekPointMismatch>,
+}
+
+impl TimeTravelVerification {
+    /// True when every seek point matched.
+    pub fn is_verified(&self) -> bool {
+        self.first_mismatch.is_none()
+    }
+}
+
+/// Differing hash values at one shared seek point of two captures.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SeekPointDivergence {
+    /// `commit_index` of the seek point.
+    pub commit_index: u64,
+    /// Value in the left capture.
+    pub left: String,
+    /// Value in the right capture.
+    pub right: String,
+}
+
+/// Outcome of [`compare_timetravel`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimeTravelComparison {
+    /// Projection invariants version of the left capture.
+    pub left_projection_invariants_version: String,
+    /// Projection invariants version of the right capture.
+    pub right_projection_invariants_version: String,
+    /// Seek points in the left capture.
+    pub left_seek_points: usize,
+    /// Seek points in the right capture.
+    pub right_seek_points: usize,
+    /// Seek points whose `commit_index` appears in both captures; only
+    /// these are compared.
+    pub aligned_seek_points: usize,
+    /// True when the captures do not seek to the same commit indices, so
+    /// the comparison covers only their intersection.
+    pub intersection_only: bool,
+    /// Last aligned `commit_index` before the earliest divergence (or the
+    /// last aligned one, when nothing diverges) at which both hashes agree.
+    pub last_agreement: Option<u64>,
+    /// Earliest aligned seek point whose `state_hash` differs.
+    pub first_state_divergence: Option<SeekPointDivergence>,
+    /// Earliest aligned seek point whose `viewmodel_hash` differs.
+    pub first_viewmodel_divergence: Option<SeekPointDivergence>,
+}
+
+impl TimeTravelComparison {
+    /// True when no aligned seek point differs.
+    pub fn is_identical(&self) -> bool {
+        self.first_state_divergence.is_none() && self.first_viewmodel_divergence.is_none()
+    }
+
+    /// True when both captures were written under the same projection
+    /// invariants. When they were not, every `viewmodel_hash` is expected
+    /// to differ.
+    pub fn versions_match(&self) -> bool {
+        self.left_projection_invariants_version == self.right_projection_invariants_version
+    }
+
+    /// `commit_index` of the earliest divergence in either hash.
+    pub fn first_divergence(&self) -> Option<u64> {
+        [
+            &self.first_state_divergence,
+            &self.first_viewmodel_divergence,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|divergence| divergence.commit_index)
+        .min()
+    }
+}
+
+/// Read a `timetravel.capture` file.
+pub fn read_timetravel_capture(path: &Path) -> io::Result<TimeTravelCapture> {
+    let bytes = fs::read(path)?;
+    serde_json::from_slice(&bytes).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("failed to parse {}: {e}", path.display()),
+        )
+    })
+}
+
+/// Replay `eventlog` to each seek point of `capture` and compare hashes.
+///
+/// Fails with `InvalidData` when the capture was written under a different
+/// projection invariants version, since its `viewmodel_hash` values cannot
+/// be reproduced by this build, and with the reader's error when an
+/// EventLog line cannot be read.
+pub fn verify_timetravel(
+    eventlog: &Path,
+    capture: &TimeTravelCapture,
+) -> io::Result<TimeTravelVerification> {
+    if capture.projection_invariants_version != PROJECTION_INVARIANTS_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "capture was written under {}, this build projects {}",
+                capture.projection_invariants_version, PROJECTION_INVARIANTS_VERSION
+            ),
+        ));
+    }
+
+    let mut verification = TimeTravelVerification {
+        seek_points_checked: 0,
+        seek_points_total: capture.seek_points.len(),
+        events_replayed: 0,
+        first_mismatch: None,
+    };
+    let mut points = capture.seek_points.iter().peekable();
+    let mut state = State::new();
+    let missing = |commit_index: u64| SeekPointMismatch {
+        commit_index,
+        field: "commit_index".to_string(),
+        captured: commit_index.to_string(),
+        recomputed: "no event at this commit_index".to_string(),
+    };
+
+    for event in EventLogReader::open(eventlog)? {
+        let Some(next) = points.peek() else {
+            break;
+        };
+        let event = event?;
+        if next.commit_index < event.commit_index {
+            verification.first_mismatch = Some(missing(next.commit_index));
+            return Ok(verification);
+        }
+        reduce_in_place(&mut state, &event);
+        verification.events_replayed += 1;
+
+        while let Some(point) = points.next_if(|p| p.commit_index == event.commit_index) {
+            let replayed = seek_point(event.commit_index, &state);
+            let mismatch = [
+                ("state_hash", &point.state_hash, replayed.state_hash),
+                (
+                    "viewmodel_hash",
+                    &point.viewmodel_hash,
+                    replayed.viewmodel_hash,
+                ),
+            ]
+            .into_iter()
+            .find(|(_, captured, recomputed)| *captured != recomputed);
+            if let Some((field, captured, recomputed)) = mismatch {
+                verification.first_mismatch = Some(SeekPointMismatch {
+                    commit_index: point.commit_index,
+                    field: field.to_string(),
+                    captured: captured.clone(),
+                    recomputed,
+                });
+                return Ok(verification);
+            }
+            verification.seek_points_checked += 1;
+        }
+    }
+
+    if let Some(point) = points.next() {
+        verification.first_mismatch = Some(missing(point.commit_index));
+    }
+    Ok(verification)
+}
+
+/// Compare two captures seek point by seek point, aligned by
+/// `commit_index`.
+///
+/// Seek points whose `commit_index` is in only one capture are skipped and
+/// flagged through [`TimeTravelComparison::intersection_only`]. When a
+/// capture repeats a `commit_index`, its first seek point is used.
+pub fn compare_timetravel(
+    left: &TimeTravelCapture,
+    right: &TimeTravelCapture,
+) -> TimeTravelComparison {
+    let left_points = seek_points_by_index(left);
+    let right_points = seek_points_by_index(right);
+
+    let mut comparison = TimeTravelComparison {
+        left_projection_invariants_version: left.projection_invariants_version.clone(),
+        right_projection_invariants_version: right.projection_invariants_version.clone(),
+        left_seek_points: left.seek_points.len(),
+        right_seek_points: right.seek_points.len(),
+        aligned_seek_points: 0,
+        intersection_only: !left_points.keys().eq(right_points.keys()),
+        last_agreement: None,
+        first_state_divergence: None,
+        first_viewmodel_divergence: None,
+    };
+    let diverge = |commit_index: u64, left: &String, right: &String| {
+        (left != right).then(|| SeekPointDivergence {
+            commit_index,
+            left: left.clone(),
+            right: right.clone(),
+        })
+    };
+
+    for (&commit_index, left_point) in &left_points {
+        let Some(right_point) = right_points.get(&commit_index) else {
+            continue;
+        };
+        comparison.aligned_seek_points += 1;
+        let state = diverge(
+            commit_index,
+            &left_point.state_hash,
+            &right_point.state_hash,
+        );
+        let viewmodel = diverge(
+            commit_index,
+            &left_point.viewmodel_hash,
+            &right_point.viewmodel_hash,
+        );
+        if state.is_none() && viewmodel.is_none() && comparison.is_identical() {
+            comparison.last_agreement = Some(commit_index);
+        }
+        if comparison.first_state_divergence.is_none() {
+            comparison.first_state_divergence = state;
+        }
+        if comparison.first_viewmodel_divergence.is_none() {
+            comparison.first_viewmodel_divergence = viewmodel;
+        }
+    }
+    comparison
+}
+
+fn seek_points_by_index(capture: &TimeTravelCapture) -> BTreeMap<u64, &SeekPoint> {
+    let mut points = BTreeMap::new();
+    for point in &capture.seek_points {
+        points.entry(point.commit_index).or_insert(point);
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{run_tour, TourConfig};
+    use std::io::BufReader;
+    use tempfile::tempdir;
+    use vifei_core::eventlog::EventLogWriter;
+    use vifei_import::cassette::parse_cassette;
+
+    const FIXTURE: &str = "../../fixtures/small-session.jsonl";
+
+    /// Tour the small fixture and write the same import to an EventLog.
+    fn tour_with_eventlog(dir: &Path) -> (std::path::PathBuf, TimeTravelCapture) {
+        let output_dir = dir.join("tour");
+        run_tour(&TourConfig::new(FIXTURE).with_output_dir(&output_dir)).unwrap();
+        let capture = read_timetravel_capture(&output_dir.join("timetravel.capture")).unwrap();
+
+        let eventlog = dir.join("eventlog.jsonl");
+        let events = parse_cassette(BufReader::new(fs::File::open(FIXTURE).unwrap()));
+        let mut writer = EventLogWriter::open(&eventlog).unwrap();
+        writer.append_batch(events).unwrap();
+        drop(writer);
+        (eventlog, capture)
+    }
+
+    #[test]
+    fn genuine_capture_verifies() {
+        let dir = tempdir().unwrap();
+        let (eventlog, capture) = tour_with_eventlog(dir.path());
+
+        let verification = verify_timetravel(&eventlog, &capture).unwrap();
+        assert!(verification.is_verified(), "{verification:?}");
+        assert_eq!(verification.seek_points_checked, capture.seek_points.len());
+        assert_eq!(
+            verification.events_replayed,
+            capture.seek_points.last().unwrap().commit_index + 1
+        );
+    }
+
+    #[test]
+    fn tampered_hash_fails_at_its_commit_index() {
+        let dir = tempdir().unwrap();
+        let (eventlog, mut capture) = tour_with_eventlog(dir.path());
+        let tampered = capture.seek_points.len() / 2;
+        capture.seek_points[tampered].viewmodel_hash = "0".repeat(64);
+
+        let verification = verify_timetravel(&eventlog, &capture).unwrap();
+        let mismatch = verification.first_mismatch.unwrap();
+        assert_eq!(
+            mismatch.commit_index,
+            capture.seek_points[tampered].commit_index
+        );
+        assert_eq!(mismatch.field, "viewmodel_hash");
+        assert_eq!(mismatch.captured, "0".repeat(64));
+        assert_eq!(verification.seek_points_checked, tampered);
+    }
+
+    #[test]
+    fn seek_point_past_end_of_eventlog_fails() {
+        let dir = tempdir().unwrap();
+        let (eventlog, mut capture) = tour_with_eventlog(dir.path());
+        let mut extra = capture.seek_points.last().unwrap().clone();
+        extra.commit_index += 100;
+        capture.seek_points.push(extra);
+
+        let verification = verify_timetravel(&eventlog, &capture).unwrap();
+        let mismatch = verification.first_mismatch.unwrap();
+        assert_eq!(mismatch.field, "commit_index");
+        assert_eq!(
+            mismatch.commit_index,
+            capture.seek_points.last().unwrap().commit_index
+        );
+    }
+
+    fn capture(points: &[(u64, &str, &str)]) -> TimeTravelCapture {
+        TimeTravelCapture {
+            projection_invariants_version: PROJECTION_INVARIANTS_VERSION.to_string(),
+            seek_points: points
+                .iter()
+                .map(|&(commit_index, state_hash, viewmodel_hash)| SeekPoint {
+                    commit_index,
+                    state_hash: state_hash.to_string(),
+                    viewmodel_hash: viewmodel_hash.to_string(),
+                    queue_pressure_micro: 0,
+                    degradation_level: Default::default(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn comparison_reports_earliest_divergence_per_hash() {
+        let left = capture(&[(0, "s0", "v0"), (10, "s1", "v1"), (20, "s2", "v2")]);
+        let same = compare_timetravel(&left, &left);
+        assert!(same.is_identical() && !same.intersection_only);
+        assert_eq!(same.aligned_seek_points, 3);
+        assert_eq!(same.last_agreement, Some(20));
+
+        let right = capture(&[(0, "s0", "v0"), (10, "s1", "x1"), (20, "x2", "x2")]);
+        let diff = compare_timetravel(&left, &right);
+        assert_eq!(diff.first_divergence(), Some(10));
+        assert_eq!(diff.last_agreement, Some(0));
+        assert_eq!(
+            diff.first_viewmodel_divergence,
+            Some(SeekPointDivergence {
+                commit_index: 10,
+                left: "v1".into(),
+                right: "x1".into(),
+            })
+        );
+        assert_eq!(diff.first_state_divergence.unwrap().commit_index, 20);
+    }
+
+    #[test]
+    fn mismatched_intervals_align_on_shared_commit_indices() {
+        let left = capture(&[(0, "s0", "v0"), (5, "s5", "v5"), (10, "s1", "v1")]);
+        let right = capture(&[(0, "s0", "v0"), (10, "x1", "v1"), (15, "s15", "v15")]);
+        let comparison = compare_timetravel(&left, &right);
+        assert!(comparison.intersection_only);
+        assert_eq!(comparison.aligned_seek_points, 2);
+        assert_eq!(comparison.first_divergence(), Some(10));
+        assert!(comparison.first_viewmodel_divergence.is_none());
+    }
+
+    #[test]
+    fn real_captures_of_the_same_fixture_are_identical() {
+        let dir = tempdir().unwrap();
+        let (_, left) = tour_with_eventlog(&dir.path().join("a"));
+        let (_, right) = tour_with_eventlog(&dir.path().join("b"));
+        let comparison = compare_timetravel(&left, &right);
+        assert!(comparison.is_identical() && comparison.versions_match());
+        assert_eq!(comparison.aligned_seek_points, left.seek_points.len());
+    }
+
+    #[test]
+    fn other_invariants_version_is_refused() {
+        let dir = tempdir().unwrap();
+        let eventlog = dir.path().join("eventlog.jsonl");
+        fs::write(&eventlog, "").unwrap();
+        let capture = TimeTravelCapture {
+            projection_invariants_version: "projection-invariants-v0.0".to_string(),
+            seek_points: Vec::new(),
+        };
+        let err = verify_timetravel(&eventlog, &capture).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
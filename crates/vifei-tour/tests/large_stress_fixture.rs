@@ -247,3 +247,50 @@ fn tour_large_fixture_deterministic() {
     let m2 = fs::read_to_string(out2.join("metrics.json")).unwrap();
     assert_eq!(m1, m2);
 }
+
+// --- Parallel replay ---
+
+#[test]
+fn parallel_replay_of_large_fixture_matches_serial() {
+    use std::io::BufReader;
+    use vifei_core::eventlog::{read_eventlog, EventLogWriter};
+    use vifei_core::reducer::{
+        replay, replay_parallel, replay_parallel_sharded, replay_parallel_with, state_hash,
+        ParallelReplayConfig, CHECKPOINT_INTERVAL,
+    };
+    use vifei_import::cassette::parse_cassette;
+
+    let dir = tempfile::tempdir().unwrap();
+    let eventlog = dir.path().join("eventlog.jsonl");
+    let parsed = parse_cassette(BufReader::new(fs::File::open(fixture_path()).unwrap()));
+    let mut writer = EventLogWriter::open(&eventlog).unwrap();
+    writer.append_batch(parsed).unwrap();
+    drop(writer);
+    let events = read_eventlog(&eventlog).unwrap();
+
+    let (serial, _) = replay(&events);
+    let serial_hash = state_hash(&serial);
+    for chunk_size in [CHECKPOINT_INTERVAL as usize, 1_000, 997, 1] {
+        let parallel = replay_parallel(&events, chunk_size).unwrap();
+        assert_eq!(
+            state_hash(&parallel),
+            serial_hash,
+            "chunk_size {chunk_size} diverged from serial replay"
+        );
+    }
+    for shards in [1, 2, 7, 64] {
+        let parallel = replay_parallel_sharded(&events, shards).unwrap();
+        assert_eq!(
+            state_hash(&parallel),
+            serial_hash,
+            "{shards} shards diverged from serial replay"
+        );
+    }
+    // Force the parallel path even if the fixture shrinks below the
+    // default serial fallback.
+    let config = ParallelReplayConfig::new(7)
+        .with_threads(3)
+        .with_min_events(0);
+    let parallel = replay_parallel_with(&events, &config).unwrap();
+    assert_eq!(state_hash(&parallel), serial_hash);
+}
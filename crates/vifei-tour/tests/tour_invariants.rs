@@ -5,10 +5,15 @@ use std::fs;
 use std::io::{BufReader, Cursor};
 use std::path::PathBuf;
 use std::sync::OnceLock;
+use vifei_core::backpressure::{queue_pressure, BackpressureController};
 use vifei_core::eventlog::{read_eventlog, EventLogWriter};
+use vifei_core::projection::LadderLevel;
 use vifei_core::reducer::{reduce, State};
 use vifei_import::cassette::parse_cassette;
-use vifei_tour::{DegradationTransition, TimeTravelCapture, TourConfig};
+use vifei_tour::{
+    DegradationTransition, TimeTravelCapture, TourConfig, APPEND_BATCH_EVENTS,
+    QUEUE_CAPACITY_EVENTS,
+};
 
 type TransitionTuple = (String, String, String, u64);
 
@@ -17,6 +22,8 @@ struct ArtifactMetrics {
     event_count_total: usize,
     queue_pressure: f64,
     projection_invariants_version: String,
+    max_degradation_level: String,
+    degradation_level_final: String,
 }
 
 fn fixture_path() -> PathBuf {
@@ -34,11 +41,31 @@ fn expected_policy_transitions() -> &'static Vec<TransitionTuple> {
         let fixture_content = fs::read_to_string(fixture_path()).expect("fixture");
         let parsed = parse_cassette(BufReader::new(Cursor::new(fixture_content)));
 
+        // Drive the controller with the pressure model Tour documents: one
+        // observation of the remaining queue per append batch, then drain.
         let temp_dir = tempfile::tempdir().expect("tempdir");
         let eventlog_path = temp_dir.path().join("eventlog.jsonl");
         let mut writer = EventLogWriter::open(&eventlog_path).expect("open writer");
-        for event in parsed {
-            writer.append(event).expect("append");
+        let mut controller = BackpressureController::new();
+        let mut decisions = 0;
+        let mut remaining = parsed.len();
+        for chunk in parsed.chunks(APPEND_BATCH_EVENTS) {
+            if let Some(change) =
+                controller.observe(queue_pressure(remaining, QUEUE_CAPACITY_EVENTS))
+            {
+                let event = change.import_event(&chunk[0].run_id, decisions, 0);
+                writer.append(event).expect("append decision");
+                decisions += 1;
+            }
+            for event in chunk {
+                writer.append(event.clone()).expect("append");
+            }
+            remaining -= chunk.len();
+        }
+        for change in controller.drain() {
+            let event = change.import_event("drain", decisions, 0);
+            writer.append(event).expect("append decision");
+            decisions += 1;
         }
         drop(writer);
 
@@ -145,12 +172,14 @@ fn degradation_transitions_respect_ladder_order() {
 fn degradation_transition_checker_is_non_tautological() {
     let ok = vec![
         DegradationTransition {
+            commit_index: 1,
             from_level: "L0".to_string(),
             to_level: "L1".to_string(),
             trigger: "pressure".to_string(),
             queue_pressure: 0.70,
         },
         DegradationTransition {
+            commit_index: 2,
             from_level: "L1".to_string(),
             to_level: "L5".to_string(),
             trigger: "fatal".to_string(),
@@ -160,6 +189,7 @@ fn degradation_transition_checker_is_non_tautological() {
     assert!(transitions_respect_ladder_order(&ok));
 
     let bad = vec![DegradationTransition {
+        commit_index: 1,
         from_level: "L0".to_string(),
         to_level: "L3".to_string(),
         trigger: "skip".to_string(),
@@ -217,6 +247,26 @@ fn metrics_transitions_are_derivable_from_policy_decisions() {
     }
 }
 
+#[test]
+fn queue_pressure_moves_the_ladder_on_large_fixture() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let output_dir = dir.path().join("out");
+    let config = TourConfig::new(fixture_path()).with_output_dir(&output_dir);
+    let metrics = vifei_tour::run_tour(&config).expect("tour run").metrics;
+
+    let transitions = &metrics.degradation_transitions;
+    assert!(!transitions.is_empty(), "stress fixture must escalate");
+    assert_eq!(transitions[0].from_level, "L0");
+    assert!(transitions.iter().all(|t| t.trigger == "queue_pressure"));
+    assert!(transitions
+        .windows(2)
+        .all(|w| w[0].to_level == w[1].from_level && w[0].commit_index < w[1].commit_index));
+    assert_eq!(metrics.max_degradation_level, "L4");
+    // The drained queue brings the controller back to L0 by the end.
+    assert_eq!(transitions.last().expect("last").to_level, "L0");
+    assert_eq!(metrics.degradation_level_final, "L0");
+}
+
 #[test]
 fn tour_artifacts_are_cross_field_consistent_on_large_fixture() {
     let dir = tempfile::tempdir().expect("tempdir");
@@ -268,3 +318,49 @@ fn tour_artifacts_are_cross_field_consistent_on_large_fixture() {
         "queue_pressure must stay in [0.0, 1.0]"
     );
 }
+
+#[test]
+fn seek_points_carry_plausible_pressure_and_level() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let output_dir = dir.path().join("out");
+    let config = TourConfig::new(fixture_path()).with_output_dir(&output_dir);
+    vifei_tour::run_tour(&config).expect("tour run");
+
+    let metrics: ArtifactMetrics = serde_json::from_str(
+        &fs::read_to_string(output_dir.join("metrics.json")).expect("metrics"),
+    )
+    .expect("parse metrics");
+    let timetravel: TimeTravelCapture = serde_json::from_str(
+        &fs::read_to_string(output_dir.join("timetravel.capture")).expect("timetravel"),
+    )
+    .expect("parse timetravel");
+    let parse_level = |name: &str| {
+        serde_json::from_value::<LadderLevel>(serde_json::Value::from(name)).expect("level")
+    };
+    let max_level = parse_level(&metrics.max_degradation_level);
+
+    for point in &timetravel.seek_points {
+        assert!(
+            point.queue_pressure_micro <= 1_000_000,
+            "commit_index {}: queue_pressure_micro {} out of range",
+            point.commit_index,
+            point.queue_pressure_micro
+        );
+        assert!(
+            point.degradation_level <= max_level,
+            "commit_index {}: level {:?} above run maximum {max_level:?}",
+            point.commit_index,
+            point.degradation_level
+        );
+    }
+    let last = timetravel.seek_points.last().expect("last seek point");
+    assert_eq!(
+        last.queue_pressure_micro,
+        (metrics.queue_pressure * 1_000_000.0) as u64,
+        "last seek point must carry the final queue pressure"
+    );
+    assert_eq!(
+        last.degradation_level,
+        parse_level(&metrics.degradation_level_final)
+    );
+}
@@ -0,0 +1,317 @@
+//! Anomaly ranking for the Incident Lens "Action Now" section.
+//!
+//! [`rank_anomalies`] is a pure function over the reducer [`State`]: the same
+//! State always yields the same ranking, independent of rendering.
+//!
+//! # Ordering
+//!
+//! Anomalies sort by [`Severity`] (most severe first), then by score within
+//! a severity (larger first), then by `commit_index` (earliest first).
+//! Aggregate signals without a single event (Tier A drops, tool error rates,
+//! orphaned tool calls) sort before per-event signals of the same severity
+//! and score; tools tie-break by name and orphaned calls by
+//! `invocation_id`.
+
+use std::cmp::Reverse;
+
+use vifei_core::reducer::State;
+
+/// Tool error rate, in millionths, above which a tool is an anomaly.
+pub const TOOL_ERROR_RATE_THRESHOLD_MICRO: u64 = 200_000;
+
+/// Number of anomalies shown in the Incident Lens.
+pub const TOP_ANOMALIES: usize = 5;
+
+/// Triage severity of an anomaly. Declaration order is ascending severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Backpressure policy transitions.
+    Info,
+    /// Backward clock movement.
+    Low,
+    /// Warning-level errors, tools failing above the threshold, and tool
+    /// calls that never returned.
+    Medium,
+    /// Errors.
+    High,
+    /// Tier A drops; should never happen.
+    Critical,
+}
+
+/// What kind of signal an anomaly came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnomalyKind {
+    /// `tier_a_drops` is nonzero.
+    TierADrop { drops: u64 },
+    /// An `Error` event.
+    Error { message: String },
+    /// A tool whose error rate exceeds [`TOOL_ERROR_RATE_THRESHOLD_MICRO`].
+    ToolErrorRate {
+        tool: String,
+        error_count: u64,
+        result_count: u64,
+    },
+    /// A ToolCall still pending at the end of the log.
+    OrphanedToolCall {
+        invocation_id: String,
+        called_at_ns: u64,
+    },
+    /// A `ClockSkewDetected` event.
+    ClockSkew { delta_ns: u64 },
+    /// A `PolicyDecision` transition.
+    Policy {
+        from_level: String,
+        to_level: String,
+        trigger: String,
+    },
+}
+
+/// One ranked anomaly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Anomaly {
+    pub severity: Severity,
+    /// Magnitude within the severity: drop count, error rate in millionths,
+    /// or skew delta in nanoseconds; 0 for errors and policy transitions.
+    pub score: u64,
+    /// Source event, or `None` for aggregate signals.
+    pub commit_index: Option<u64>,
+    pub kind: AnomalyKind,
+}
+
+/// Rank every anomaly in `state`, most urgent first.
+pub fn rank_anomalies(state: &State) -> Vec<Anomaly> {
+    let mut anomalies = Vec::new();
+
+    if state.tier_a_drops > 0 {
+        anomalies.push(Anomaly {
+            severity: Severity::Critical,
+            score: state.tier_a_drops,
+            commit_index: None,
+            kind: AnomalyKind::TierADrop {
+                drops: state.tier_a_drops,
+            },
+        });
+    }
+
+    for err in &state.error_log {
+        let severity = match err.severity.as_deref() {
+            Some("warning" | "warn" | "info") => Severity::Medium,
+            _ => Severity::High,
+        };
+        anomalies.push(Anomaly {
+            severity,
+            score: 0,
+            commit_index: Some(err.commit_index),
+            kind: AnomalyKind::Error {
+                message: err.message.clone(),
+            },
+        });
+    }
+
+    for (tool, summary) in &state.tool_summaries {
+        if summary.result_count == 0 {
+            continue;
+        }
+        let rate_micro = summary.error_count * 1_000_000 / summary.result_count;
+        if rate_micro > TOOL_ERROR_RATE_THRESHOLD_MICRO {
+            anomalies.push(Anomaly {
+                severity: Severity::Medium,
+                score: rate_micro,
+                commit_index: None,
+                kind: AnomalyKind::ToolErrorRate {
+                    tool: tool.clone(),
+                    error_count: summary.error_count,
+                    result_count: summary.result_count,
+                },
+            });
+        }
+    }
+
+    for (invocation_id, called_at_ns) in &state.pending_tool_calls {
+        anomalies.push(Anomaly {
+            severity: Severity::Medium,
+            score: 0,
+            commit_index: None,
+            kind: AnomalyKind::OrphanedToolCall {
+                invocation_id: invocation_id.clone(),
+                called_at_ns: *called_at_ns,
+            },
+        });
+    }
+
+    for skew in &state.clock_skew_events {
+        anomalies.push(Anomaly {
+            severity: Severity::Low,
+            score: skew.delta_ns,
+            commit_index: Some(skew.commit_index),
+            kind: AnomalyKind::ClockSkew {
+                delta_ns: skew.delta_ns,
+            },
+        });
+    }
+
+    for pd in &state.policy_decisions {
+        anomalies.push(Anomaly {
+            severity: Severity::Info,
+            score: 0,
+            commit_index: Some(pd.commit_index),
+            kind: AnomalyKind::Policy {
+                from_level: pd.from_level.clone(),
+                to_level: pd.to_level.clone(),
+                trigger: pd.trigger.clone(),
+            },
+        });
+    }
+
+    // Stable sort: tools and orphaned calls were pushed in key order, so they
+    // tie-break by name and invocation_id.
+    anomalies.sort_by_key(|a| (Reverse(a.severity), Reverse(a.score), a.commit_index));
+    anomalies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vifei_core::reducer::{ClockSkewEntry, ErrorEntry, PolicyTransition, ToolSummary};
+
+    fn error(commit_index: u64, severity: Option<&str>) -> ErrorEntry {
+        ErrorEntry {
+            commit_index,
+            kind: "runtime".to_string(),
+            message: format!("error {commit_index}"),
+            severity: severity.map(str::to_string),
+        }
+    }
+
+    fn skew(commit_index: u64, delta_ns: u64) -> ClockSkewEntry {
+        ClockSkewEntry {
+            commit_index,
+            expected_ns: 10_000_000_000,
+            actual_ns: 10_000_000_000 - delta_ns,
+            delta_ns,
+        }
+    }
+
+    fn tool_summary(result_count: u64, error_count: u64) -> ToolSummary {
+        ToolSummary {
+            call_count: result_count,
+            result_count,
+            success_count: result_count - error_count,
+            error_count,
+            ..ToolSummary::default()
+        }
+    }
+
+    #[test]
+    fn empty_state_has_no_anomalies() {
+        assert!(rank_anomalies(&State::new()).is_empty());
+    }
+
+    #[test]
+    fn error_outranks_single_clock_skew() {
+        let mut state = State::new();
+        state.clock_skew_events.push(skew(3, 5_000_000_000));
+        state.error_log.push(error(9, None));
+
+        let ranked = rank_anomalies(&state);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].commit_index, Some(9));
+        assert_eq!(ranked[0].severity, Severity::High);
+        assert_eq!(ranked[1].severity, Severity::Low);
+    }
+
+    #[test]
+    fn severity_order_and_commit_index_tiebreak() {
+        let mut state = State::new();
+        state.tier_a_drops = 2;
+        state.policy_decisions.push(PolicyTransition {
+            commit_index: 1,
+            from_level: "L0".to_string(),
+            to_level: "L1".to_string(),
+            trigger: "queue_pressure".to_string(),
+            queue_pressure_micro: 900_000,
+        });
+        state.error_log.push(error(20, Some("error")));
+        state.error_log.push(error(4, Some("error")));
+        state.error_log.push(error(2, Some("warning")));
+        state
+            .tool_summaries
+            .insert("Bash".to_string(), tool_summary(4, 2));
+        state.clock_skew_events.push(skew(6, 10_000_000));
+        state.clock_skew_events.push(skew(5, 90_000_000));
+
+        let order: Vec<(Severity, Option<u64>)> = rank_anomalies(&state)
+            .iter()
+            .map(|a| (a.severity, a.commit_index))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (Severity::Critical, None),
+                (Severity::High, Some(4)),
+                (Severity::High, Some(20)),
+                (Severity::Medium, None),
+                (Severity::Medium, Some(2)),
+                (Severity::Low, Some(5)),
+                (Severity::Low, Some(6)),
+                (Severity::Info, Some(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn tool_error_rate_must_exceed_threshold() {
+        let mut state = State::new();
+        state
+            .tool_summaries
+            .insert("Read".to_string(), tool_summary(5, 1));
+        state
+            .tool_summaries
+            .insert("Write".to_string(), tool_summary(3, 1));
+        state
+            .tool_summaries
+            .insert("Idle".to_string(), tool_summary(0, 0));
+
+        let ranked = rank_anomalies(&state);
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(ranked[0].score, 333_333);
+        assert!(matches!(
+            &ranked[0].kind,
+            AnomalyKind::ToolErrorRate { tool, .. } if tool == "Write"
+        ));
+    }
+
+    #[test]
+    fn pending_tool_calls_are_orphan_anomalies() {
+        let mut state = State::new();
+        state.pending_tool_calls.insert("tu_9".to_string(), 7_000);
+        state.pending_tool_calls.insert("tu_2".to_string(), 3_000);
+        state.clock_skew_events.push(skew(4, 1_000_000));
+
+        let ranked = rank_anomalies(&state);
+        assert_eq!(ranked.len(), 3);
+        assert_eq!(ranked[0].severity, Severity::Medium);
+        assert_eq!(
+            ranked[0].kind,
+            AnomalyKind::OrphanedToolCall {
+                invocation_id: "tu_2".to_string(),
+                called_at_ns: 3_000,
+            }
+        );
+        assert!(matches!(
+            &ranked[1].kind,
+            AnomalyKind::OrphanedToolCall { invocation_id, .. } if invocation_id == "tu_9"
+        ));
+        assert_eq!(ranked[2].severity, Severity::Low);
+    }
+
+    #[test]
+    fn ranking_is_deterministic() {
+        let mut state = State::new();
+        for i in 0..10 {
+            state.error_log.push(error(i, Some("error")));
+            state.clock_skew_events.push(skew(i + 10, 20_000_000));
+        }
+        assert_eq!(rank_anomalies(&state), rank_anomalies(&state.clone()));
+    }
+}
@@ -0,0 +1,189 @@
+//! Schema checks behind `vifei validate-artifact`.
+//!
+//! An artifact conforms when it deserializes as its Rust type and its
+//! version strings match the ones this build writes. A failure names one
+//! field as a path such as `seek_points[2].state_hash`; `.` is the
+//! document itself (e.g. invalid JSON).
+
+use serde::de::DeserializeOwned;
+use vifei_core::projection::PROJECTION_INVARIANTS_VERSION;
+use vifei_export::{BundleManifest, RefusalReport, MANIFEST_VERSION, REFUSAL_REPORT_VERSION};
+use vifei_tour::{TimeTravelCapture, TourMetrics};
+
+use crate::cli_contract::ArtifactKind;
+
+/// First problem found in a non-conforming artifact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArtifactIssue {
+    /// Path of the offending field.
+    pub(crate) field: String,
+    /// Human-readable description.
+    pub(crate) message: String,
+}
+
+/// Name of `kind` as given on the command line.
+pub(crate) fn artifact_kind_name(kind: ArtifactKind) -> &'static str {
+    match kind {
+        ArtifactKind::TourMetrics => "tour-metrics",
+        ArtifactKind::RefusalReport => "refusal-report",
+        ArtifactKind::BundleManifest => "bundle-manifest",
+        ArtifactKind::TimeTravelCapture => "time-travel-capture",
+    }
+}
+
+/// Check `bytes` against the schema for `kind`, returning the artifact's
+/// primary version string when it conforms.
+pub(crate) fn validate_artifact(kind: ArtifactKind, bytes: &[u8]) -> Result<String, ArtifactIssue> {
+    match kind {
+        ArtifactKind::TourMetrics => {
+            let metrics: TourMetrics = parse(bytes)?;
+            expect_version(
+                "projection_invariants_version",
+                metrics.projection_invariants_version,
+                PROJECTION_INVARIANTS_VERSION,
+            )
+        }
+        ArtifactKind::RefusalReport => {
+            let report: RefusalReport = parse(bytes)?;
+            expect_version(
+                "report_version",
+                report.report_version,
+                REFUSAL_REPORT_VERSION,
+            )
+        }
+        ArtifactKind::BundleManifest => {
+            let manifest: BundleManifest = parse(bytes)?;
+            expect_version(
+                "projection_invariants_version",
+                manifest.projection_invariants_version,
+                PROJECTION_INVARIANTS_VERSION,
+            )?;
+            expect_version(
+                "manifest_version",
+                manifest.manifest_version,
+                MANIFEST_VERSION,
+            )
+        }
+        ArtifactKind::TimeTravelCapture => {
+            let capture: TimeTravelCapture = parse(bytes)?;
+            expect_version(
+                "projection_invariants_version",
+                capture.projection_invariants_version,
+                PROJECTION_INVARIANTS_VERSION,
+            )
+        }
+    }
+}
+
+fn parse<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ArtifactIssue> {
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|e| {
+        // A trailing `?` is a key that could not be read; report its parent.
+        let path = e.path().to_string();
+        let path = match path.trim_end_matches('?').trim_end_matches('.') {
+            "" => ".".to_string(),
+            parent => parent.to_string(),
+        };
+        let message = e.into_inner().to_string();
+        // Missing fields are reported at their parent; name the field itself.
+        let field = match missing_field_name(&message) {
+            Some(name) if path == "." => name.to_string(),
+            Some(name) => format!("{path}.{name}"),
+            None => path,
+        };
+        ArtifactIssue { field, message }
+    })?;
+    de.end().map_err(|e| ArtifactIssue {
+        field: ".".to_string(),
+        message: e.to_string(),
+    })?;
+    Ok(value)
+}
+
+fn missing_field_name(message: &str) -> Option<&str> {
+    let rest = message.strip_prefix("missing field `")?;
+    rest.split_once('`').map(|(name, _)| name)
+}
+
+fn expect_version(field: &str, found: String, expected: &str) -> Result<String, ArtifactIssue> {
+    if found == expected {
+        Ok(found)
+    } else {
+        Err(ArtifactIssue {
+            field: field.to_string(),
+            message: format!("unsupported version {found:?}, expected {expected:?}"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn metrics() -> serde_json::Value {
+        json!({
+            "projection_invariants_version": PROJECTION_INVARIANTS_VERSION,
+            "event_count_total": 3,
+            "tier_a_drops": 0,
+            "max_degradation_level": "L0",
+            "degradation_level_final": "L0",
+            "degradation_transitions": [],
+            "aggregation_mode": "1:1",
+            "aggregation_bin_size": null,
+            "queue_pressure": 0.0,
+            "export_safety_state": "UNKNOWN",
+        })
+    }
+
+    fn check(kind: ArtifactKind, value: &serde_json::Value) -> Result<String, ArtifactIssue> {
+        validate_artifact(kind, value.to_string().as_bytes())
+    }
+
+    #[test]
+    fn valid_metrics_conform() {
+        assert_eq!(
+            check(ArtifactKind::TourMetrics, &metrics()),
+            Ok(PROJECTION_INVARIANTS_VERSION.to_string())
+        );
+    }
+
+    #[test]
+    fn missing_field_is_named() {
+        let mut value = metrics();
+        value.as_object_mut().unwrap().remove("aggregation_mode");
+        let issue = check(ArtifactKind::TourMetrics, &value).unwrap_err();
+        assert_eq!(issue.field, "aggregation_mode");
+        assert!(issue.message.contains("missing field"), "{issue:?}");
+    }
+
+    #[test]
+    fn nested_type_error_has_a_path() {
+        let value = json!({
+            "projection_invariants_version": PROJECTION_INVARIANTS_VERSION,
+            "seek_points": [
+                {"commit_index": 0, "state_hash": "a", "viewmodel_hash": "b"},
+                {"commit_index": "one", "state_hash": "a", "viewmodel_hash": "b"},
+            ],
+        });
+        let issue = check(ArtifactKind::TimeTravelCapture, &value).unwrap_err();
+        assert_eq!(issue.field, "seek_points[1].commit_index");
+    }
+
+    #[test]
+    fn old_version_string_is_rejected() {
+        let mut value = metrics();
+        value["projection_invariants_version"] = json!("projection-invariants-v0.0");
+        let issue = check(ArtifactKind::TourMetrics, &value).unwrap_err();
+        assert_eq!(issue.field, "projection_invariants_version");
+        assert!(issue.message.contains("projection-invariants-v0.0"));
+    }
+
+    #[test]
+    fn malformed_json_is_reported_at_document_root() {
+        let issue = validate_artifact(ArtifactKind::RefusalReport, b"not json").unwrap_err();
+        assert_eq!(issue.field, ".");
+        let issue = validate_artifact(ArtifactKind::BundleManifest, b"{").unwrap_err();
+        assert_eq!(issue.field, ".");
+    }
+}
@@ -137,6 +137,7 @@ fn sample_events() -> Vec<ImportEvent> {
             payload: EventPayload::ToolCall {
                 tool: "cargo test".into(),
                 args: Some("--workspace".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -152,6 +153,7 @@ fn sample_events() -> Vec<ImportEvent> {
                 tool: "cargo test".into(),
                 result: Some("all tests passed".into()),
                 status: Some("success".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -182,6 +184,7 @@ fn sample_events() -> Vec<ImportEvent> {
             payload: EventPayload::ToolCall {
                 tool: "cargo clippy".into(),
                 args: Some("--all-targets -- -D warnings".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -197,6 +200,7 @@ fn sample_events() -> Vec<ImportEvent> {
                 tool: "cargo clippy".into(),
                 result: Some("no warnings".into()),
                 status: Some("success".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -264,6 +268,7 @@ fn write_sample_export_clean_eventlog(path: &Path) -> io::Result<()> {
                 tool: "verify".into(),
                 result: Some("ok".into()),
                 status: Some("success".into()),
+                invocation_id: None,
             },
             payload_ref: None,
             synthesized: false,
@@ -306,6 +311,7 @@ fn generate_export_refusal(out_dir: &Path) -> io::Result<String> {
         payload: EventPayload::ToolCall {
             tool: "openai".into(),
             args: Some("sk-0123456789abcdef0123456789abcdef0123456789abcdef".into()),
+            invocation_id: None,
         },
         payload_ref: None,
         synthesized: false,
@@ -318,6 +324,7 @@ fn generate_export_refusal(out_dir: &Path) -> io::Result<String> {
 
     match run_export(&config).map_err(io::Error::other)? {
         ExportResult::Success(_) => Ok("Unexpected: export succeeded".to_string()),
+        ExportResult::OversizeBlob(oversize) => Ok(format!("Unexpected: {oversize}")),
         ExportResult::Refused(report) => {
             let mut out = String::new();
             out.push_str(&format!("Export REFUSED: {}\n", report.summary));
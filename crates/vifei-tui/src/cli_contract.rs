@@ -15,20 +15,106 @@ pub(crate) struct Cli {
     #[arg(long, global = true)]
     pub(crate) human: bool,
 
+    /// Suppress non-error human output.
+    #[arg(long, global = true, conflicts_with = "verbose")]
+    pub(crate) quiet: bool,
+
+    /// Print stage-by-stage progress to stderr (a `stages` array in JSON).
+    #[arg(long, global = true)]
+    pub(crate) verbose: bool,
+
     #[command(subcommand)]
     pub(crate) command: Commands,
 }
 
+/// How much a command reports beyond its result.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum Verbosity {
+    /// No human output except errors.
+    Quiet,
+    #[default]
+    Normal,
+    /// Stage notes on stderr, or `stages` in the JSON envelope.
+    Verbose,
+}
+
+impl Verbosity {
+    pub(crate) fn from_flags(quiet: bool, verbose: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, true) => Verbosity::Verbose,
+            (false, false) => Verbosity::Normal,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 pub(crate) enum UiProfileArg {
     Standard,
     Showcase,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum ViewInputFormat {
+    Eventlog,
+    /// Agent cassette; committed in memory, nothing is written.
+    Cassette,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
 pub(crate) enum CompareInputFormat {
     Eventlog,
     Cassette,
+    Otel,
+    /// `.tar.zst` export bundle; its `eventlog.jsonl` is compared.
+    Bundle,
+}
+
+/// How `compare` pairs left events with right events.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum CompareAlignment {
+    /// By `commit_index`; for replays of the same run.
+    Positional,
+    /// By `event_id`, reporting added, removed, moved, and changed events;
+    /// for different runs.
+    ById,
+}
+
+/// How much of each divergence `compare` shows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum CompareDetail {
+    /// One line per divergence: commit, path, class, severity.
+    Summary,
+    /// Also a unified diff of the redacted left and right event JSON.
+    Diff,
+}
+
+/// Artifact types checked by `validate-artifact`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum ArtifactKind {
+    /// Tour `metrics.json`.
+    TourMetrics,
+    /// Export refusal report.
+    RefusalReport,
+    /// Bundle `manifest.json`.
+    BundleManifest,
+    /// Tour `timetravel.capture`.
+    TimeTravelCapture,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum TourFixtureFormat {
+    Cassette,
+    Otel,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub(crate) enum ImportSourceFormat {
+    Cassette,
+    OpenaiResponses,
+    AnthropicMessages,
+    CohereTranslate,
+    Otel,
 }
 
 #[derive(Subcommand)]
@@ -36,12 +122,60 @@ pub(crate) enum Commands {
     /// View an EventLog in the TUI.
     #[command(alias = "viewer")]
     View {
-        /// Path to the EventLog JSONL file.
+        /// Path to the EventLog JSONL file, or a cassette with `--format cassette`.
         eventlog: PathBuf,
 
+        /// Input format of the file.
+        #[arg(long, value_enum, default_value = "eventlog")]
+        format: ViewInputFormat,
+
         /// Presentation profile (style/layout only; does not alter truth semantics).
         #[arg(long, value_enum, default_value = "standard")]
         profile: UiProfileArg,
+
+        /// Load only the first N committed events (display-only truncation).
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Load only events with `commit_index` at or after this value.
+        #[arg(long)]
+        from_commit: Option<u64>,
+
+        /// Load only events with `commit_index` at or before this value.
+        #[arg(long)]
+        to_commit: Option<u64>,
+    },
+
+    /// Import a source recording into a canonical EventLog.
+    Import {
+        /// Path to the source recording (JSONL).
+        cassette: PathBuf,
+
+        /// Output EventLog path (must not already exist).
+        #[arg(short, long)]
+        output: PathBuf,
+
+        /// Source recording format.
+        #[arg(long, value_enum, default_value = "cassette")]
+        format: ImportSourceFormat,
+
+        /// Payload text above this many bytes is moved to the sibling `blobs/` store.
+        #[arg(long, default_value_t = vifei_import::offload::DEFAULT_OFFLOAD_THRESHOLD_BYTES)]
+        offload_threshold: usize,
+
+        /// Keep importing lines appended to the cassette until its sessions
+        /// end or Ctrl-C (cassette format only).
+        #[arg(long)]
+        follow: bool,
+
+        /// JSON field mapping for nonstandard cassette schemas (cassette format only).
+        #[arg(long)]
+        mapping: Option<PathBuf>,
+
+        /// Append a synthesized `RunEnd` (reason "truncated") for every run
+        /// that has none once import stops.
+        #[arg(long)]
+        finalize_runs: bool,
     },
 
     /// Export an EventLog as a share-safe bundle.
@@ -61,13 +195,70 @@ pub(crate) enum Commands {
         /// Path to write refusal report if secrets are detected.
         #[arg(long)]
         refusal_report: Option<PathBuf>,
+
+        /// Include the latest reducer checkpoint as `checkpoint.json`.
+        #[arg(long)]
+        include_checkpoint: bool,
+
+        /// Leave blob files out of the bundle (still scanned for secrets).
+        #[arg(long)]
+        exclude_blobs: bool,
+
+        /// Also refuse on personal data (email addresses), not only secrets.
+        #[arg(long)]
+        scan_pii: bool,
+
+        /// Hex ed25519 seed file; writes a detached `<output>.sig` signature.
+        #[arg(long)]
+        signing_key: Option<PathBuf>,
+
+        /// Export the valid prefix of an EventLog whose final record is truncated.
+        #[arg(long)]
+        allow_truncated: bool,
+
+        /// Rehash every referenced blob first; refuse if any is missing or corrupt.
+        #[arg(long)]
+        verify_blobs: bool,
+
+        /// Refuse if a referenced blob is larger than this many bytes.
+        #[arg(long, value_name = "BYTES")]
+        max_blob_size: Option<u64>,
+
+        /// Leave blobs over --max-blob-size out of the bundle instead of refusing.
+        #[arg(long, requires = "max_blob_size")]
+        exclude_oversize_blobs: bool,
+
+        /// Earlier bundle of this EventLog; export only the events appended since.
+        #[arg(long, value_name = "BUNDLE")]
+        base: Option<PathBuf>,
+    },
+
+    /// Write a copy of an EventLog with secret-scanner matches replaced and a
+    /// RedactionApplied event appended per redaction.
+    Redact {
+        /// EventLog to redact; never modified.
+        input: PathBuf,
+
+        /// Path for the redacted EventLog; must not exist.
+        output: PathBuf,
+
+        /// Scanner pattern to redact (repeatable; default: all patterns).
+        #[arg(long = "pattern", value_name = "NAME")]
+        patterns: Vec<String>,
     },
 
     /// Run the Tour stress harness to generate proof artifacts.
     #[command(alias = "tours")]
     Tour {
-        /// Path to the fixture file (Agent Cassette JSONL).
-        fixture: PathBuf,
+        /// Path to the fixture file (Agent Cassette JSONL or OTLP/JSON).
+        #[arg(required_unless_present = "batch", conflicts_with = "batch")]
+        fixture: Option<PathBuf>,
+
+        /// Tour every fixture in this directory (`*.jsonl`, or `*.json`
+        /// with `--format otel`) in parallel, each into
+        /// `<output-dir>/<fixture stem>`.
+        #[arg(long)]
+        batch: Option<PathBuf>,
 
         /// Enable stress mode (required in v0.1).
         #[arg(long)]
@@ -76,14 +267,29 @@ pub(crate) enum Commands {
         /// Output directory for proof artifacts (default: tour-output).
         #[arg(long, default_value = "tour-output")]
         output_dir: PathBuf,
+
+        /// JSON field mapping for nonstandard cassette schemas (cassette format only).
+        #[arg(long)]
+        mapping: Option<PathBuf>,
+
+        /// Fixture source format.
+        #[arg(long, value_enum, default_value = "cassette")]
+        format: TourFixtureFormat,
+    },
+
+    /// Roll up the `metrics.json` of several Tour output directories.
+    TourSummary {
+        /// Tour output directories.
+        #[arg(required = true)]
+        dirs: Vec<PathBuf>,
     },
 
     /// Deterministically compare two run inputs and report causal divergences.
     Compare {
-        /// Left input path (EventLog JSONL or cassette JSONL).
+        /// Left input path (EventLog JSONL, cassette JSONL, or export bundle).
         left: PathBuf,
 
-        /// Right input path (EventLog JSONL or cassette JSONL).
+        /// Right input path (EventLog JSONL, cassette JSONL, or export bundle).
         right: PathBuf,
 
         /// Input format for the left side.
@@ -93,6 +299,33 @@ pub(crate) enum Commands {
         /// Input format for the right side.
         #[arg(long, value_enum, default_value = "eventlog")]
         right_format: CompareInputFormat,
+
+        /// Also replay both sides and report which State fields differ.
+        #[arg(long)]
+        state_diff: bool,
+
+        /// How events are paired between the two sides.
+        #[arg(long, value_enum, default_value = "positional")]
+        align: CompareAlignment,
+
+        /// Event field to leave out of the comparison, as a dot-path such as
+        /// `timestamp_ns` or `payload.args` (repeatable).
+        #[arg(long = "ignore-path", value_name = "PATH")]
+        ignore_paths: Vec<String>,
+
+        /// Treat both inputs as Tour `timetravel.capture` files and report
+        /// the first seek point where their hashes diverge.
+        #[arg(long, conflicts_with_all = ["state_diff", "ignore_paths", "detail"])]
+        timetravel: bool,
+
+        /// Detail shown per divergence; `diff` also adds the snippets to
+        /// `data.delta.rendered`.
+        #[arg(long, value_enum, default_value = "summary")]
+        detail: CompareDetail,
+
+        /// Most divergences listed (and rendered, with `--detail diff`).
+        #[arg(long, value_name = "N", default_value_t = 10)]
+        max_divergences: usize,
     },
 
     /// Build a local-first deterministic incident evidence pack from two inputs.
@@ -115,6 +348,11 @@ pub(crate) enum Commands {
         /// Output directory for the generated evidence pack.
         #[arg(long, default_value = "incident-pack")]
         output_dir: PathBuf,
+
+        /// Event field to leave out of the comparison, as a dot-path such as
+        /// `timestamp_ns` or `payload.args` (repeatable).
+        #[arg(long = "ignore-path", value_name = "PATH")]
+        ignore_paths: Vec<String>,
     },
 
     /// Run strict trust verification checks and emit an auditable summary.
@@ -135,6 +373,97 @@ pub(crate) enum Commands {
         #[arg(long, default_value = "verify-output")]
         output_dir: PathBuf,
     },
+
+    /// List blobs in the sibling `blobs/` directory that no event references;
+    /// delete them only with `--delete`.
+    Gc {
+        /// Path to the EventLog JSONL file whose blob store is swept.
+        eventlog: PathBuf,
+
+        /// Delete unreferenced blobs. Refused unless the EventLog passes
+        /// `verify-eventlog`'s sequence checks.
+        #[arg(long)]
+        delete: bool,
+    },
+
+    /// List blobs in the sibling `blobs/` directory with their size, marking
+    /// which ones no event references.
+    BlobLs {
+        /// Path to the EventLog JSONL file whose blob store is listed.
+        eventlog: PathBuf,
+    },
+
+    /// Re-hash blobs in the sibling `blobs/` directory and check every ref is present.
+    BlobVerify {
+        /// Path to the EventLog JSONL file whose blob store is verified.
+        eventlog: PathBuf,
+    },
+
+    /// Re-hash every blob in a blob store directory against its content hash.
+    VerifyBlobs {
+        /// Blob store directory (e.g. the `blobs/` beside an EventLog).
+        dir: PathBuf,
+    },
+
+    /// Check a bundle against its manifest and, with a key, its signature.
+    VerifyBundle {
+        /// Path to the `.tar.zst` bundle.
+        bundle: PathBuf,
+
+        /// Hex ed25519 public key file; checks the detached `<bundle>.sig`.
+        #[arg(long)]
+        public_key: Option<PathBuf>,
+    },
+
+    /// Check an EventLog's `commit_index` sequence and hash chain.
+    VerifyEventlog {
+        /// Path to the EventLog JSONL file.
+        eventlog: PathBuf,
+    },
+
+    /// Replay an EventLog and print its counts and hashes without the TUI.
+    Stats {
+        /// Path to the EventLog JSONL file.
+        eventlog: PathBuf,
+    },
+
+    /// Replay an EventLog to each seek point of a `timetravel.capture` and
+    /// check the captured hashes.
+    TimetravelVerify {
+        /// Path to the EventLog JSONL file the capture was made from.
+        eventlog: PathBuf,
+
+        /// Path to the `timetravel.capture` file.
+        capture: PathBuf,
+    },
+
+    /// Check that an artifact file conforms to its schema and version.
+    ValidateArtifact {
+        /// Artifact type.
+        #[arg(value_enum)]
+        kind: ArtifactKind,
+
+        /// Path to the artifact file.
+        path: PathBuf,
+    },
+
+    /// Append a human-written Note event about an existing event.
+    Annotate {
+        /// Path to the EventLog JSONL file.
+        eventlog: PathBuf,
+
+        /// `commit_index` of the event the note is about.
+        #[arg(long)]
+        at: u64,
+
+        /// Note text.
+        #[arg(long)]
+        text: String,
+
+        /// Note author (default: `$USER`, else `unknown`).
+        #[arg(long)]
+        author: Option<String>,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -151,6 +480,8 @@ pub(crate) enum AppExit {
     ExportRefused = 3,
     RuntimeError = 4,
     DiffFound = 5,
+    /// Divergences found, all of them minor (see `DivergenceSeverity`).
+    MinorDiff = 6,
 }
 
 impl AppExit {
@@ -160,25 +491,92 @@ impl AppExit {
 }
 
 pub(crate) const QUICK_HELP: &str = "\
-vifei — deterministic AI run recorder
-Usage: vifei [--json|--human] <command> [args]
+vifei: deterministic AI run recorder
+Usage: vifei [--json|--human] [--quiet|--verbose] <command> [args]
 Commands:
-  view <eventlog.jsonl> [--profile standard|showcase]
+  view <eventlog.jsonl> [--profile standard|showcase] [--limit <N>] [--from-commit <N>] [--to-commit <N>]
+  import <cassette.jsonl> --output <eventlog.jsonl> [--format <source>] [--follow] [--mapping <json>] [--finalize-runs]
   export <eventlog.jsonl> --share-safe --output <bundle.tar.zst> [--refusal-report <path>]
-  tour <fixture.jsonl> --stress [--output-dir <dir>]
-  compare <left.jsonl> <right.jsonl> [--left-format eventlog|cassette] [--right-format eventlog|cassette]
-  incident-pack <left.jsonl> <right.jsonl> [--left-format eventlog|cassette] [--right-format eventlog|cassette] [--output-dir <dir>]
+  tour <fixture.jsonl>|--batch <dir> --stress [--output-dir <dir>] [--mapping <json>] [--format cassette|otel]
+  tour-summary <dir>...
+  verify-blobs <dir>
+  compare <left.jsonl> <right.jsonl> [--left-format|--right-format eventlog|cassette|otel|bundle] [--timetravel]
+  incident-pack <compare args> [--output-dir <dir>]
   verify --strict [--full] [--fixture <fixture.jsonl>] [--output-dir <dir>]
-Tips:
-  vifei --help
-  vifei <command> --help";
+  blob-ls|blob-verify|gc|verify-eventlog|stats <eventlog.jsonl>
+  verify-bundle <bundle.tar.zst> [--public-key <path>]
+  timetravel-verify <eventlog.jsonl> <timetravel.capture>
+  redact <in.jsonl> <out.jsonl> [--pattern <name>]
+  validate-artifact tour-metrics|refusal-report|bundle-manifest|time-travel-capture <path>
+  annotate <eventlog.jsonl> --at <commit_index> --text <text> [--author <name>]
+Tips: vifei [<command>] --help";
 
 pub(crate) const ROBOT_SCHEMA_VERSION: &str = "vifei-cli-robot-v1.1";
 
 #[cfg(test)]
 mod tests {
-    use super::{Cli, Commands, CompareInputFormat, UiProfileArg};
+    use super::{
+        ArtifactKind, Cli, Commands, CompareAlignment, CompareDetail, CompareInputFormat,
+        ImportSourceFormat, TourFixtureFormat, UiProfileArg, Verbosity,
+    };
     use clap::Parser;
+    use std::path::PathBuf;
+
+    #[test]
+    fn quiet_and_verbose_are_global_and_exclusive() {
+        let cli = Cli::try_parse_from(["vifei", "tour", "f.jsonl", "--stress", "--quiet"])
+            .expect("parse");
+        assert_eq!(
+            Verbosity::from_flags(cli.quiet, cli.verbose),
+            Verbosity::Quiet
+        );
+        let cli = Cli::try_parse_from(["vifei", "--verbose", "verify-eventlog", "e.jsonl"])
+            .expect("parse");
+        assert_eq!(
+            Verbosity::from_flags(cli.quiet, cli.verbose),
+            Verbosity::Verbose
+        );
+        assert!(Cli::try_parse_from(["vifei", "--quiet", "--verbose", "gc", "e.jsonl"]).is_err());
+    }
+
+    #[test]
+    fn redact_collects_repeated_patterns() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "redact",
+            "in.jsonl",
+            "out.jsonl",
+            "--pattern",
+            "email",
+            "--pattern",
+            "phone",
+        ])
+        .expect("parse");
+        let Commands::Redact {
+            input,
+            output,
+            patterns,
+        } = cli.command
+        else {
+            panic!("expected redact");
+        };
+        assert_eq!(input, PathBuf::from("in.jsonl"));
+        assert_eq!(output, PathBuf::from("out.jsonl"));
+        assert_eq!(patterns, ["email", "phone"]);
+    }
+
+    #[test]
+    fn gc_defaults_to_dry_run() {
+        let cli = Cli::try_parse_from(["vifei", "gc", "e.jsonl"]).expect("parse");
+        let Commands::Gc { eventlog, delete } = cli.command else {
+            panic!("expected gc");
+        };
+        assert_eq!(eventlog, PathBuf::from("e.jsonl"));
+        assert!(!delete);
+        let cli = Cli::try_parse_from(["vifei", "gc", "e.jsonl", "--delete"]).expect("parse");
+        assert!(matches!(cli.command, Commands::Gc { delete: true, .. }));
+        assert!(Cli::try_parse_from(["vifei", "blob-gc", "e.jsonl"]).is_err());
+    }
 
     #[test]
     fn clap_alias_viewer_maps_to_view() {
@@ -219,6 +617,28 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn view_limit_parses() {
+        let cli = Cli::try_parse_from(["vifei", "view", "e.jsonl", "--limit", "2"]).expect("parse");
+        assert!(matches!(cli.command, Commands::View { limit: Some(2), .. }));
+    }
+
+    #[test]
+    fn view_commit_window_parses() {
+        let cli = Cli::try_parse_from(["vifei", "view", "e.jsonl", "--from-commit", "10"])
+            .expect("parse");
+        let Commands::View {
+            from_commit,
+            to_commit,
+            ..
+        } = cli.command
+        else {
+            panic!("expected view command");
+        };
+        assert_eq!(from_commit, Some(10));
+        assert_eq!(to_commit, None);
+    }
+
     #[test]
     fn compare_formats_parse_from_flags() {
         let cli = Cli::try_parse_from([
@@ -242,6 +662,133 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn compare_state_diff_flag_parses() {
+        let cli = Cli::try_parse_from(["vifei", "compare", "l.jsonl", "r.jsonl", "--state-diff"])
+            .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                state_diff: true,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn compare_align_flag_parses() {
+        let cli = Cli::try_parse_from(["vifei", "compare", "l.jsonl", "r.jsonl"]).expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                align: CompareAlignment::Positional,
+                ..
+            }
+        ));
+        let cli =
+            Cli::try_parse_from(["vifei", "compare", "l.jsonl", "r.jsonl", "--align", "by-id"])
+                .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                align: CompareAlignment::ById,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn ignore_path_flag_repeats() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "compare",
+            "l.jsonl",
+            "r.jsonl",
+            "--ignore-path",
+            "synthesized",
+            "--ignore-path",
+            "payload.args",
+        ])
+        .expect("parse");
+        let Commands::Compare { ignore_paths, .. } = cli.command else {
+            panic!("expected compare");
+        };
+        assert_eq!(ignore_paths, ["synthesized", "payload.args"]);
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "incident-pack",
+            "l.jsonl",
+            "r.jsonl",
+            "--ignore-path",
+            "timestamp_ns",
+        ])
+        .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::IncidentPack { ignore_paths, .. } if ignore_paths == ["timestamp_ns"]
+        ));
+    }
+
+    #[test]
+    fn compare_detail_flags_parse() {
+        let cli = Cli::try_parse_from(["vifei", "compare", "l.jsonl", "r.jsonl"]).expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                detail: CompareDetail::Summary,
+                max_divergences: 10,
+                ..
+            }
+        ));
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "compare",
+            "l.jsonl",
+            "r.jsonl",
+            "--detail",
+            "diff",
+            "--max-divergences",
+            "3",
+        ])
+        .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                detail: CompareDetail::Diff,
+                max_divergences: 3,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn compare_timetravel_flag_parses() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "compare",
+            "a/timetravel.capture",
+            "b/timetravel.capture",
+            "--timetravel",
+        ])
+        .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Compare {
+                timetravel: true,
+                ..
+            }
+        ));
+        assert!(Cli::try_parse_from([
+            "vifei",
+            "compare",
+            "a",
+            "b",
+            "--timetravel",
+            "--state-diff"
+        ])
+        .is_err());
+    }
+
     #[test]
     fn incident_pack_alias_parses() {
         let cli = Cli::try_parse_from([
@@ -276,4 +823,193 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn clap_parses_blob_ls() {
+        let cli = Cli::try_parse_from(["vifei", "blob-ls", "e.jsonl"]).expect("parse");
+        assert!(matches!(cli.command, Commands::BlobLs { .. }));
+    }
+
+    #[test]
+    fn clap_parses_blob_verify() {
+        let cli = Cli::try_parse_from(["vifei", "blob-verify", "e.jsonl"]).expect("parse");
+        assert!(matches!(cli.command, Commands::BlobVerify { .. }));
+    }
+
+    #[test]
+    fn clap_parses_verify_blobs() {
+        let cli = Cli::try_parse_from(["vifei", "verify-blobs", "blobs"]).expect("parse");
+        let Commands::VerifyBlobs { dir } = cli.command else {
+            panic!("expected verify-blobs");
+        };
+        assert_eq!(dir, PathBuf::from("blobs"));
+    }
+
+    #[test]
+    fn clap_parses_import_with_defaults() {
+        let cli = Cli::try_parse_from(["vifei", "import", "c.jsonl", "--output", "run.jsonl"])
+            .expect("parse");
+        let Commands::Import {
+            cassette,
+            output,
+            format,
+            offload_threshold,
+            follow,
+            mapping,
+            finalize_runs,
+        } = cli.command
+        else {
+            panic!("expected import command");
+        };
+        assert_eq!(cassette, std::path::PathBuf::from("c.jsonl"));
+        assert_eq!(output, std::path::PathBuf::from("run.jsonl"));
+        assert_eq!(format, ImportSourceFormat::Cassette);
+        assert_eq!(
+            offload_threshold,
+            vifei_import::offload::DEFAULT_OFFLOAD_THRESHOLD_BYTES
+        );
+        assert!(!follow);
+        assert!(mapping.is_none());
+        assert!(!finalize_runs);
+    }
+
+    #[test]
+    fn clap_parses_import_source_format() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "import",
+            "r.jsonl",
+            "-o",
+            "run.jsonl",
+            "--format",
+            "openai-responses",
+        ])
+        .expect("parse");
+        assert!(matches!(
+            cli.command,
+            Commands::Import {
+                format: ImportSourceFormat::OpenaiResponses,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn clap_parses_tour_otel_format() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "tour",
+            "spans.json",
+            "--stress",
+            "--format",
+            "otel",
+        ])
+        .expect("parse");
+        let Commands::Tour { format, .. } = cli.command else {
+            panic!("expected tour command");
+        };
+        assert_eq!(format, TourFixtureFormat::Otel);
+    }
+
+    #[test]
+    fn tour_batch_replaces_fixture() {
+        let cli = Cli::try_parse_from(["vifei", "tour", "--batch", "fixtures", "--stress"])
+            .expect("parse");
+        let Commands::Tour { fixture, batch, .. } = cli.command else {
+            panic!("expected tour command");
+        };
+        assert_eq!(fixture, None);
+        assert_eq!(batch, Some(PathBuf::from("fixtures")));
+        assert!(Cli::try_parse_from(["vifei", "tour", "f.jsonl", "--batch", "fixtures"]).is_err());
+        assert!(Cli::try_parse_from(["vifei", "tour", "--stress"]).is_err());
+    }
+
+    #[test]
+    fn clap_parses_verify_bundle_with_public_key() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "verify-bundle",
+            "b.tar.zst",
+            "--public-key",
+            "k.pub",
+        ])
+        .expect("parse");
+        let Commands::VerifyBundle { bundle, public_key } = cli.command else {
+            panic!("expected verify-bundle command");
+        };
+        assert_eq!(bundle, std::path::PathBuf::from("b.tar.zst"));
+        assert_eq!(public_key, Some(std::path::PathBuf::from("k.pub")));
+    }
+
+    #[test]
+    fn clap_parses_tour_summary_dirs() {
+        let cli = Cli::try_parse_from(["vifei", "tour-summary", "a", "b"]).expect("parse");
+        let Commands::TourSummary { dirs } = cli.command else {
+            panic!("expected tour-summary command");
+        };
+        assert_eq!(
+            dirs,
+            vec![std::path::PathBuf::from("a"), std::path::PathBuf::from("b")]
+        );
+        assert!(Cli::try_parse_from(["vifei", "tour-summary"]).is_err());
+    }
+
+    #[test]
+    fn clap_parses_validate_artifact() {
+        let cli = Cli::try_parse_from(["vifei", "validate-artifact", "tour-metrics", "m.json"])
+            .expect("parse");
+        let Commands::ValidateArtifact { kind, path } = cli.command else {
+            panic!("expected validate-artifact command");
+        };
+        assert_eq!(kind, ArtifactKind::TourMetrics);
+        assert_eq!(path, std::path::PathBuf::from("m.json"));
+    }
+
+    #[test]
+    fn clap_parses_verify_eventlog() {
+        let cli = Cli::try_parse_from(["vifei", "verify-eventlog", "e.jsonl"]).expect("parse");
+        let Commands::VerifyEventlog { eventlog } = cli.command else {
+            panic!("expected verify-eventlog command");
+        };
+        assert_eq!(eventlog, std::path::PathBuf::from("e.jsonl"));
+    }
+
+    #[test]
+    fn clap_parses_timetravel_verify() {
+        let cli = Cli::try_parse_from(["vifei", "timetravel-verify", "e.jsonl", "t.capture"])
+            .expect("parse");
+        let Commands::TimetravelVerify { eventlog, capture } = cli.command else {
+            panic!("expected timetravel-verify command");
+        };
+        assert_eq!(eventlog, std::path::PathBuf::from("e.jsonl"));
+        assert_eq!(capture, std::path::PathBuf::from("t.capture"));
+        assert!(Cli::try_parse_from(["vifei", "timetravel-verify", "e.jsonl"]).is_err());
+    }
+
+    #[test]
+    fn clap_parses_annotate() {
+        let cli = Cli::try_parse_from([
+            "vifei",
+            "annotate",
+            "e.jsonl",
+            "--at",
+            "3",
+            "--text",
+            "retry storm starts here",
+        ])
+        .expect("parse");
+        let Commands::Annotate {
+            eventlog,
+            at,
+            text,
+            author,
+        } = cli.command
+        else {
+            panic!("expected annotate command");
+        };
+        assert_eq!(eventlog, std::path::PathBuf::from("e.jsonl"));
+        assert_eq!(at, 3);
+        assert_eq!(text, "retry storm starts here");
+        assert_eq!(author, None);
+    }
 }
@@ -1,26 +1,124 @@
+use crate::artifact_check::{artifact_kind_name, validate_artifact};
 use crate::cli_contract::{
-    AppExit, Cli, Commands, CompareInputFormat, OutputMode, UiProfileArg, ROBOT_SCHEMA_VERSION,
+    AppExit, Cli, Commands, CompareAlignment, CompareDetail, CompareInputFormat,
+    ImportSourceFormat, OutputMode, TourFixtureFormat, UiProfileArg, Verbosity, ViewInputFormat,
+    ROBOT_SCHEMA_VERSION,
 };
 use crate::cli_normalize::format_cli_failure;
 use serde_json::{json, Value};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fs::{self, File};
 use std::io::BufReader;
+use std::ops::ControlFlow;
 use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU64, Ordering};
-use vifei_core::delta::diff_runs;
-use vifei_core::event::CommittedEvent;
-use vifei_core::eventlog::{read_eventlog, EventLogWriter};
-use vifei_core::projection::{project, viewmodel_hash, ProjectionInvariants};
-use vifei_core::reducer::{replay, state_hash};
-use vifei_export::{ExportConfig, ExportResult};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use vifei_core::blob_store::{BlobIntegrityError, BlobStore, GcReport};
+use vifei_core::checkpoint_store::{
+    checkpoint_dir, replay_with_checkpoints_progress, CheckpointStore,
+};
+use vifei_core::delta::{
+    diff_runs_with, diff_states, DiffAlignment, DiffOptions, RunDelta, StateDelta,
+};
+use vifei_core::delta_render::render_divergences;
+use vifei_core::error::VifeiError;
+use vifei_core::event::{CommittedEvent, EventPayload, ImportEvent, Tier};
+use vifei_core::eventlog::{
+    read_eventlog, referenced_payload_refs, validate_eventlog, verify_eventlog_chain,
+    DurabilityPolicy, EventLogWriter,
+};
+use vifei_core::projection::{
+    diff_viewmodels, project, viewmodel_hash, ProjectionInvariants, ViewModelDivergence,
+};
+use vifei_core::reducer::{
+    finalize_runs, replay, replay_with_progress, state_hash, ReplayProgress, CHECKPOINT_INTERVAL,
+};
+use vifei_export::redact::{redact_for_display, RedactConfig};
+use vifei_export::verify::{read_bundle_eventlog, verify_bundle};
+use vifei_export::{CorruptBlobs, ExportConfig, ExportResult, TruncatedEventLog};
+use vifei_import::anthropic_messages::parse_anthropic_messages;
 use vifei_import::cassette;
-use vifei_tour::TourConfig;
-use vifei_tui::{run_viewer, UiProfile};
+use vifei_import::cohere_translate::parse_cohere_translate;
+use vifei_import::follow::{CassetteFollower, DEFAULT_POLL_INTERVAL as FOLLOW_POLL_INTERVAL};
+use vifei_import::mapping::Mapping;
+use vifei_import::offload::{needs_offload, offload_event, offload_large_payloads};
+use vifei_import::openai_responses::parse_openai_responses;
+use vifei_import::otel::{parse_otel, parse_otel_with_report};
+use vifei_import::report::ImportReport;
+use vifei_tour::{
+    compare_timetravel, read_timetravel_capture, read_tour_metrics, summarize_tours,
+    verify_timetravel, FixtureFormat, TimeTravelComparison, TourConfig,
+};
+use vifei_tui::{run_viewer, UiProfile, ViewFormat, ViewerOptions};
 
 static CASSETTE_APPEND_TEMP_ID: AtomicU64 = AtomicU64::new(0);
 
-fn emit_json(value: Value) {
+/// Process-wide output mode and [`Verbosity`], set once by [`handle_command`].
+static REPORTING: Mutex<(OutputMode, Verbosity)> =
+    Mutex::new((OutputMode::Human, Verbosity::Normal));
+/// Stage notes recorded under `--verbose`, drained into the JSON envelope.
+static STAGES: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+
+fn reporting() -> (OutputMode, Verbosity) {
+    *REPORTING.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn verbosity() -> Verbosity {
+    reporting().1
+}
+
+/// `println!` for human-mode output; silent under `--quiet`.
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if verbosity() != Verbosity::Quiet {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Record a finished stage under `--verbose`: a `stage:` line on stderr in
+/// human mode, an entry of the envelope's `stages` array in JSON mode.
+fn note_stage(stage: &str, elapsed: Duration) {
+    let (mode, verbosity) = reporting();
+    if verbosity != Verbosity::Verbose {
+        return;
+    }
+    let ms = elapsed.as_secs_f64() * 1000.0;
+    if mode == OutputMode::Json {
+        STAGES
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(json!({"stage": stage, "ms": ms}));
+    } else {
+        eprintln!("stage: {stage} ({ms:.3} ms)");
+    }
+}
+
+/// Events between `replay:` progress lines under `--verbose`.
+const REPLAY_PROGRESS_STRIDE: u64 = 1_000_000;
+
+/// Under `--verbose` in human mode, print a `replay:` line on stderr every
+/// [`REPLAY_PROGRESS_STRIDE`] events so long replays show they are moving.
+fn note_replay_progress(position: ReplayProgress) -> ControlFlow<()> {
+    if position
+        .events_processed
+        .is_multiple_of(REPLAY_PROGRESS_STRIDE)
+        && reporting() == (OutputMode::Human, Verbosity::Verbose)
+    {
+        match position.total {
+            Some(total) => eprintln!("replay: {} / {total} events", position.events_processed),
+            None => eprintln!("replay: {} events", position.events_processed),
+        }
+    }
+    ControlFlow::Continue(())
+}
+
+fn emit_json(mut value: Value) {
+    if verbosity() == Verbosity::Verbose {
+        let stages = std::mem::take(&mut *STAGES.lock().unwrap_or_else(|e| e.into_inner()));
+        value["stages"] = json!(stages);
+    }
     match serde_json::to_string(&value) {
         Ok(line) => println!("{line}"),
         Err(err) => {
@@ -103,39 +201,361 @@ fn load_committed_events(
     match format {
         CompareInputFormat::Eventlog => read_eventlog(path)
             .map_err(|e| format!("failed to read eventlog {}: {e}", path.display())),
-        CompareInputFormat::Cassette => {
-            let file = File::open(path)
-                .map_err(|e| format!("failed to open cassette {}: {e}", path.display()))?;
+        CompareInputFormat::Bundle => read_bundle_eventlog(path)
+            .map_err(|e| format!("failed to read bundle {}: {e}", path.display())),
+        CompareInputFormat::Cassette | CompareInputFormat::Otel => {
+            let file = File::open(path).map_err(|e| {
+                format!(
+                    "failed to open {} {}: {e}",
+                    format_name(format),
+                    path.display()
+                )
+            })?;
             let reader = BufReader::new(file);
-            let imported = cassette::parse_cassette(reader);
+            let imported = if format == CompareInputFormat::Otel {
+                parse_otel(reader)
+            } else {
+                cassette::parse_cassette(reader)
+            };
             let temp_id = CASSETTE_APPEND_TEMP_ID.fetch_add(1, Ordering::Relaxed);
             let eventlog_path = std::env::temp_dir().join(format!(
                 "vifei-cassette-canonical-{}-{temp_id}.jsonl",
                 std::process::id()
             ));
-            let committed = {
-                let mut writer = EventLogWriter::open(&eventlog_path).map_err(|e| {
-                    format!(
-                        "failed to initialize append writer for {}: {e}",
-                        path.display()
-                    )
-                })?;
-                let mut committed = Vec::with_capacity(imported.len() * 2);
-                for import in imported {
-                    let result = writer.append(import).map_err(|e| {
-                        format!(
-                            "failed to append cassette event for {}: {e}",
-                            path.display()
-                        )
-                    })?;
-                    committed.extend(result.detection_events().iter().cloned());
-                    committed.push(result.committed_event().clone());
-                }
-                committed
-            };
+            let committed = append_imported(imported, &eventlog_path, path);
             let _ = fs::remove_file(&eventlog_path);
-            Ok(committed)
+            committed
+        }
+    }
+}
+
+/// Append `imported` through the append writer at `eventlog_path`, returning
+/// the committed sequence including detection events.
+fn append_imported(
+    imported: Vec<ImportEvent>,
+    eventlog_path: &Path,
+    source: &Path,
+) -> Result<Vec<CommittedEvent>, String> {
+    let mut writer = open_import_writer(eventlog_path, source)?;
+    append_with_writer(&mut writer, imported, source)
+}
+
+fn open_import_writer(eventlog_path: &Path, source: &Path) -> Result<EventLogWriter, String> {
+    EventLogWriter::open(eventlog_path).map_err(|e| {
+        format!(
+            "failed to initialize append writer for {}: {e}",
+            source.display()
+        )
+    })
+}
+
+fn append_with_writer(
+    writer: &mut EventLogWriter,
+    imported: Vec<ImportEvent>,
+    source: &Path,
+) -> Result<Vec<CommittedEvent>, String> {
+    let mut committed = Vec::with_capacity(imported.len() * 2);
+    for import in imported {
+        let result = writer
+            .append(import)
+            .map_err(|e| format!("failed to append event for {}: {e}", source.display()))?;
+        committed.extend(result.detection_events().iter().cloned());
+        committed.push(result.committed_event().clone());
+    }
+    Ok(committed)
+}
+
+/// Load a `--mapping` file, or the standard cassette mapping when absent.
+fn load_mapping(path: Option<&Path>) -> Result<Mapping, String> {
+    match path {
+        Some(path) => Mapping::load(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::InvalidData => e.to_string(),
+            _ => format!("failed to read mapping {}: {e}", path.display()),
+        }),
+        None => Ok(Mapping::default()),
+    }
+}
+
+fn mapping_suggestions() -> Vec<String> {
+    vec![
+        "Check that the mapping file exists and is valid JSON with `fields` and `types` keys."
+            .to_string(),
+        "See fixtures/vendor-session.mapping.json for an example.".to_string(),
+    ]
+}
+
+fn import_format_name(format: ImportSourceFormat) -> &'static str {
+    match format {
+        ImportSourceFormat::Cassette => "cassette",
+        ImportSourceFormat::OpenaiResponses => "openai-responses",
+        ImportSourceFormat::AnthropicMessages => "anthropic-messages",
+        ImportSourceFormat::CohereTranslate => "cohere-translate",
+        ImportSourceFormat::Otel => "otel",
+    }
+}
+
+/// Outcome of `vifei import`.
+struct ImportSummary {
+    imported_count: usize,
+    committed: Vec<CommittedEvent>,
+    /// Truncated `RunEnd` events appended by `--finalize-runs`.
+    finalized_runs: usize,
+    offloaded_payloads: usize,
+    report: Option<ImportReport>,
+    follow: Option<FollowOutcome>,
+}
+
+/// How a `vifei import --follow` session ended.
+struct FollowOutcome {
+    stopped_by: &'static str,
+    source_offset: u64,
+    pending_bytes: usize,
+}
+
+/// Parse `source`, offload oversized payloads to the blob store beside
+/// `output`, and append everything to a new EventLog at `output`.
+fn run_import(
+    source: &Path,
+    output: &Path,
+    format: ImportSourceFormat,
+    mapping: &Mapping,
+    offload_threshold: usize,
+) -> Result<ImportSummary, String> {
+    let parse_start = Instant::now();
+    let file = File::open(source)
+        .map_err(|e| format!("failed to open source {}: {e}", source.display()))?;
+    let reader = BufReader::new(file);
+    let (mut imported, report) = match format {
+        ImportSourceFormat::Cassette => {
+            let parsed = cassette::parse_cassette_with_mapping(reader, mapping);
+            (parsed.events, Some(parsed.report))
+        }
+        ImportSourceFormat::OpenaiResponses => (parse_openai_responses(reader), None),
+        ImportSourceFormat::AnthropicMessages => (parse_anthropic_messages(reader), None),
+        ImportSourceFormat::CohereTranslate => (parse_cohere_translate(reader), None),
+        ImportSourceFormat::Otel => {
+            let parsed = parse_otel_with_report(reader);
+            (parsed.events, Some(parsed.report))
+        }
+    };
+    let imported_count = imported.len();
+    note_stage("parse", parse_start.elapsed());
+
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+    }
+    let offload_start = Instant::now();
+    let mut offloaded_payloads = 0;
+    if imported
+        .iter()
+        .any(|event| needs_offload(event, offload_threshold))
+    {
+        let blobs_dir = sibling_blobs_dir(output);
+        offloaded_payloads = BlobStore::open(&blobs_dir)
+            .and_then(|store| offload_large_payloads(&mut imported, &store, offload_threshold))
+            .map_err(|e| format!("failed to offload payloads to {}: {e}", blobs_dir.display()))?;
+    }
+
+    note_stage("offload", offload_start.elapsed());
+
+    // A writer refused by another process's lock leaves that log alone.
+    let append_start = Instant::now();
+    let mut writer = open_import_writer(output, source)?;
+    let committed = append_with_writer(&mut writer, imported, source).inspect_err(|_| {
+        let _ = fs::remove_file(output);
+    })?;
+    note_stage("append", append_start.elapsed());
+    Ok(ImportSummary {
+        imported_count,
+        committed,
+        finalized_runs: 0,
+        offloaded_payloads,
+        report,
+        follow: None,
+    })
+}
+
+/// Tail a cassette that is still being written, appending each completed
+/// line to a new EventLog at `output` until every started session has ended
+/// or Ctrl-C is pressed.
+///
+/// The EventLog is synced after every poll that appended events, so a
+/// concurrent `vifei view` sees fresh data. Ctrl-C is only observed between
+/// polls, so an interrupted follow still leaves whole lines only.
+fn run_import_follow(
+    source: &Path,
+    output: &Path,
+    mapping: &Mapping,
+    offload_threshold: usize,
+) -> Result<ImportSummary, String> {
+    let interrupted = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&interrupted);
+    ctrlc::set_handler(move || flag.store(true, Ordering::SeqCst))
+        .map_err(|e| format!("failed to install Ctrl-C handler: {e}"))?;
+
+    let mut follower = CassetteFollower::open(source)
+        .map_err(|e| format!("failed to open source {}: {e}", source.display()))?
+        .with_mapping(mapping.clone());
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("failed to create {}: {e}", parent.display()))?;
+    }
+    // Every line is synced as it lands so a crash loses at most one event.
+    let mut writer = EventLogWriter::open_with_policy(output, DurabilityPolicy::EveryAppend)
+        .map_err(|e| {
+            format!(
+                "failed to initialize append writer for {}: {e}",
+                source.display()
+            )
+        })?;
+
+    let mut blob_store = None;
+    let mut open_runs = BTreeSet::new();
+    let mut saw_run_end = false;
+    let mut imported_count = 0;
+    let mut offloaded_payloads = 0;
+    let mut committed = Vec::new();
+    let stopped_by = loop {
+        let stop_requested = interrupted.load(Ordering::SeqCst);
+        let mut batch = follower
+            .poll()
+            .map_err(|e| format!("failed to read source {}: {e}", source.display()))?;
+        if stop_requested {
+            batch.extend(follower.drain_unterminated());
+        }
+
+        let appended = !batch.is_empty();
+        for mut event in batch {
+            if needs_offload(&event, offload_threshold) {
+                if blob_store.is_none() {
+                    let blobs_dir = sibling_blobs_dir(output);
+                    blob_store = Some(BlobStore::open(&blobs_dir).map_err(|e| {
+                        format!("failed to open blob store {}: {e}", blobs_dir.display())
+                    })?);
+                }
+                if let Some(store) = &blob_store {
+                    offload_event(&mut event, store, offload_threshold)
+                        .map_err(|e| format!("failed to offload payload: {e}"))?;
+                    offloaded_payloads += 1;
+                }
+            }
+            match &event.payload {
+                EventPayload::RunStart { .. } => {
+                    open_runs.insert(event.run_id.clone());
+                }
+                EventPayload::RunEnd { .. } => {
+                    open_runs.remove(&event.run_id);
+                    saw_run_end = true;
+                }
+                _ => {}
+            }
+            imported_count += 1;
+            let result = writer
+                .append(event)
+                .map_err(|e| format!("failed to append event for {}: {e}", source.display()))?;
+            committed.extend(result.detection_events().iter().cloned());
+            committed.push(result.committed_event().clone());
+        }
+
+        if saw_run_end && open_runs.is_empty() {
+            break "session_end";
+        }
+        if stop_requested {
+            break "interrupted";
+        }
+        if !appended {
+            std::thread::sleep(FOLLOW_POLL_INTERVAL);
+        }
+    };
+
+    Ok(ImportSummary {
+        imported_count,
+        committed,
+        finalized_runs: 0,
+        offloaded_payloads,
+        follow: Some(FollowOutcome {
+            stopped_by,
+            source_offset: follower.offset(),
+            pending_bytes: follower.pending_bytes(),
+        }),
+        report: Some(follower.finish()),
+    })
+}
+
+/// Print the first State divergences of `compare --state-diff`.
+/// Summary counts, the `max` most severe divergences, then any rendered
+/// diff snippets.
+fn say_run_delta(delta: &RunDelta, max: usize) {
+    let by_severity: Vec<String> = delta
+        .summary
+        .by_severity
+        .iter()
+        .rev()
+        .map(|(severity, count)| format!("{severity}={count}"))
+        .collect();
+    let by_class: Vec<String> = delta
+        .summary
+        .by_change_class
+        .iter()
+        .map(|(class, count)| format!("{class}={count}"))
+        .collect();
+    say!("  By severity: {}", by_severity.join(" "));
+    say!("  By class:    {}", by_class.join(" "));
+    say!("Top divergences:");
+    for divergence in delta.most_severe(max) {
+        say!(
+            "  - commit={} path={} class={} severity={}",
+            divergence.commit_index,
+            divergence.path,
+            divergence.change_class,
+            divergence.severity
+        );
+    }
+    if !delta.rendered.is_empty() {
+        say!("Divergence detail:");
+    }
+    for rendered in &delta.rendered {
+        say!(
+            "  commit={} severity={} paths={}",
+            rendered.commit_index,
+            rendered.severity,
+            rendered.paths.join(",")
+        );
+        for line in rendered.diff.lines() {
+            say!("    {line}");
+        }
+    }
+}
+
+/// Mask secrets in the divergence values of `delta` and `state_delta` the
+/// way rendered snippets are masked, so output that shows snippets does
+/// not carry the raw value beside them.
+fn redact_divergence_values(delta: &mut RunDelta, state_delta: Option<&mut StateDelta>) {
+    let redact = |value: &mut Option<String>| {
+        if let Some(text) = value {
+            *text = redact_for_display(text);
         }
+    };
+    for divergence in &mut delta.divergences {
+        redact(&mut divergence.left_value);
+        redact(&mut divergence.right_value);
+    }
+    for divergence in state_delta.into_iter().flat_map(|d| &mut d.divergences) {
+        redact(&mut divergence.left_value);
+        redact(&mut divergence.right_value);
+    }
+}
+
+fn say_state_delta(state_delta: &StateDelta) {
+    say!("  State divergences: {}", state_delta.divergences.len());
+    for divergence in state_delta.divergences.iter().take(10) {
+        say!(
+            "  - {}: {} -> {}",
+            divergence.path,
+            divergence.left_value.as_deref().unwrap_or("(absent)"),
+            divergence.right_value.as_deref().unwrap_or("(absent)")
+        );
     }
 }
 
@@ -151,6 +571,11 @@ fn compare_replay_suggestions(
             "vifei tour {} --stress --output-dir left-tour-output",
             left.display()
         ),
+        CompareInputFormat::Otel => format!(
+            "vifei tour {} --stress --format otel --output-dir left-tour-output",
+            left.display()
+        ),
+        CompareInputFormat::Bundle => format!("vifei verify-bundle {}", left.display()),
     };
     let right_view = match right_format {
         CompareInputFormat::Eventlog => format!("vifei view {}", right.display()),
@@ -158,6 +583,11 @@ fn compare_replay_suggestions(
             "vifei tour {} --stress --output-dir right-tour-output",
             right.display()
         ),
+        CompareInputFormat::Otel => format!(
+            "vifei tour {} --stress --format otel --output-dir right-tour-output",
+            right.display()
+        ),
+        CompareInputFormat::Bundle => format!("vifei verify-bundle {}", right.display()),
     };
     vec![left_view, right_view]
 }
@@ -166,6 +596,8 @@ fn format_name(format: CompareInputFormat) -> &'static str {
     match format {
         CompareInputFormat::Eventlog => "eventlog",
         CompareInputFormat::Cassette => "cassette",
+        CompareInputFormat::Otel => "otel",
+        CompareInputFormat::Bundle => "bundle",
     }
 }
 
@@ -192,6 +624,36 @@ fn write_committed_eventlog(path: &Path, events: &[CommittedEvent]) -> Result<()
     fs::write(path, lines).map_err(|e| format!("failed to write {}: {e}", path.display()))
 }
 
+/// Blob store directory used for an EventLog: the sibling `blobs/` dir,
+/// matching the layout `vifei export` reads from.
+fn sibling_blobs_dir(eventlog: &Path) -> PathBuf {
+    eventlog
+        .parent()
+        .map(|p| p.join("blobs"))
+        .unwrap_or_else(|| PathBuf::from("blobs"))
+}
+
+/// Distinct blob `payload_ref`s referenced by events in an EventLog.
+fn referenced_blob_refs(eventlog: &Path) -> Result<HashSet<String>, String> {
+    referenced_payload_refs(eventlog)
+        .map_err(|e| format!("failed to read eventlog {}: {e}", eventlog.display()))
+}
+
+/// Sweep the blob store beside `eventlog` against the blobs its events
+/// reference. A missing store is an empty sweep.
+fn sweep_blobs(eventlog: &Path, blobs_dir: &Path, dry_run: bool) -> Result<GcReport, String> {
+    let referenced = referenced_blob_refs(eventlog)?;
+    if !blobs_dir.is_dir() {
+        return Ok(GcReport {
+            dry_run,
+            ..GcReport::default()
+        });
+    }
+    BlobStore::open(blobs_dir)
+        .and_then(|store| store.gc(&referenced, dry_run))
+        .map_err(|e| format!("blob gc failed in {}: {e}", blobs_dir.display()))
+}
+
 fn hash_file_blake3(path: &Path) -> Result<String, String> {
     let bytes = fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
     Ok(blake3::hash(&bytes).to_hex().to_string())
@@ -203,8 +665,112 @@ fn write_json_pretty(path: &Path, value: &Value) -> Result<(), String> {
     fs::write(path, bytes).map_err(|e| format!("failed to write {}: {e}", path.display()))
 }
 
-fn replay_summary(events: &[CommittedEvent]) -> Value {
-    let (state, _checkpoints) = replay(events);
+/// Maximum divergence rows rendered into `incident.html`.
+///
+/// The full divergence list always lives in `compare/delta.json`; the HTML
+/// report is a reviewer-facing summary and stays bounded for huge diffs.
+const INCIDENT_HTML_MAX_ROWS: usize = 200;
+
+fn escape_html(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for ch in raw.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Render a self-contained, deterministic HTML report of an incident delta.
+///
+/// Inline CSS only, no external assets, no timestamps: same delta and bundle
+/// hashes always produce identical bytes.
+fn render_incident_html(
+    delta: &RunDelta,
+    left_bundle_hash: &str,
+    right_bundle_hash: &str,
+) -> String {
+    let divergence_count = delta.divergences.len();
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>Vifei incident report</title>\n<style>\n");
+    html.push_str("body{font-family:monospace;margin:2em;color:#222;background:#fafafa}\n");
+    html.push_str("table{border-collapse:collapse;width:100%}\n");
+    html.push_str(
+        "th,td{border:1px solid #ccc;padding:4px 8px;text-align:left;vertical-align:top}\n",
+    );
+    html.push_str("th{background:#eee}\n");
+    html.push_str("td.left{background:#fdecea}\ntd.right{background:#e8f5e9}\n");
+    html.push_str("</style>\n</head>\n<body>\n");
+    html.push_str("<h1>Vifei incident report</h1>\n<table class=\"summary\">\n");
+    let summary_rows = [
+        ("Left run", delta.left_run_id.clone()),
+        ("Right run", delta.right_run_id.clone()),
+        ("Left events", delta.left_event_count.to_string()),
+        ("Right events", delta.right_event_count.to_string()),
+        ("Left bundle hash", left_bundle_hash.to_string()),
+        ("Right bundle hash", right_bundle_hash.to_string()),
+        ("Divergences", divergence_count.to_string()),
+        ("Critical divergences", delta.critical_count().to_string()),
+    ];
+    for (label, value) in summary_rows {
+        html.push_str(&format!(
+            "<tr><th>{}</th><td>{}</td></tr>\n",
+            label,
+            escape_html(&value)
+        ));
+    }
+    html.push_str("</table>\n");
+
+    if divergence_count == 0 {
+        html.push_str("<p>No divergences detected.</p>\n");
+    } else {
+        html.push_str("<h2>Divergences</h2>\n<table class=\"divergences\">\n");
+        html.push_str(
+            "<tr><th>commit_index</th><th>path</th><th>change_class</th><th>severity</th><th>left</th><th>right</th></tr>\n",
+        );
+        for divergence in delta.divergences.iter().take(INCIDENT_HTML_MAX_ROWS) {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td class=\"left\">{}</td><td class=\"right\">{}</td></tr>\n",
+                divergence.commit_index,
+                escape_html(&divergence.path),
+                divergence.change_class,
+                divergence.severity,
+                escape_html(divergence.left_value.as_deref().unwrap_or("")),
+                escape_html(divergence.right_value.as_deref().unwrap_or("")),
+            ));
+        }
+        html.push_str("</table>\n");
+        if divergence_count > INCIDENT_HTML_MAX_ROWS {
+            html.push_str(&format!(
+                "<p>Showing first {INCIDENT_HTML_MAX_ROWS} of {divergence_count} divergences. See compare/delta.json for the full list.</p>\n"
+            ));
+        }
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Replay hashes for `events`, resuming from the checkpoint directory of
+/// `eventlog` when it is an EventLog that has one.
+fn replay_summary(events: &[CommittedEvent], eventlog: Option<&Path>) -> Value {
+    let store = eventlog
+        .map(checkpoint_dir)
+        .filter(|dir| dir.is_dir())
+        .and_then(|dir| CheckpointStore::open(dir).ok());
+    let state = match &store {
+        Some(store) => replay_with_checkpoints_progress(events, store, note_replay_progress).state,
+        None => {
+            replay_with_progress(events, CHECKPOINT_INTERVAL, note_replay_progress)
+                .expect("CHECKPOINT_INTERVAL is nonzero")
+                .state
+        }
+    };
     let state_hash_hex = state_hash(&state);
     let invariants = ProjectionInvariants::default();
     let vm = project(&state, &invariants);
@@ -230,8 +796,11 @@ struct StrictVerifyChecks {
     tier_a_no_drop: bool,
     refusal_semantics: bool,
     explainability_surface: bool,
+    eventlog_integrity: bool,
     hash_a: String,
     hash_b: String,
+    /// ViewModel fields behind a `hash_a`/`hash_b` mismatch.
+    divergences: Vec<ViewModelDivergence>,
     blocked_count: usize,
 }
 
@@ -262,13 +831,22 @@ fn strict_verify_tokens_present(ansi_capture: &str) -> bool {
 }
 
 pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]) -> AppExit {
+    *REPORTING.lock().unwrap_or_else(|e| e.into_inner()) =
+        (mode, Verbosity::from_flags(cli.quiet, cli.verbose));
     let map_profile = |profile: UiProfileArg| match profile {
         UiProfileArg::Standard => UiProfile::Standard,
         UiProfileArg::Showcase => UiProfile::Showcase,
     };
 
     match cli.command {
-        Commands::View { eventlog, profile } => {
+        Commands::View {
+            eventlog,
+            format,
+            profile,
+            limit,
+            from_commit,
+            to_commit,
+        } => {
             if let Err(msg) = ensure_file_exists(&eventlog, "eventlog file") {
                 let suggestions = vec![
                     format!(
@@ -298,7 +876,45 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 }
                 return AppExit::NotFound;
             }
-            if let Err(e) = run_viewer(&eventlog, map_profile(profile)) {
+            let window = (from_commit.is_some() || to_commit.is_some())
+                .then(|| (from_commit.unwrap_or(0), to_commit.unwrap_or(u64::MAX)));
+            if let Some((from, to)) = window.filter(|(from, to)| from > to) {
+                let msg = format!("--from-commit {from} is after --to-commit {to}");
+                let suggestions = vec![format!(
+                    "vifei view {} --from-commit {to} --to-commit {from}",
+                    eventlog.display()
+                )];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "INVALID_ARGS",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::InvalidArgs as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("view failed: {msg}"),
+                            "The commit window is empty by construction.",
+                            &suggestions,
+                            &[eventlog.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::InvalidArgs;
+            }
+            let format = match format {
+                ViewInputFormat::Eventlog => ViewFormat::Eventlog,
+                ViewInputFormat::Cassette => ViewFormat::Cassette,
+            };
+            let options = ViewerOptions::new()
+                .with_format(format)
+                .with_profile(map_profile(profile))
+                .with_window(window)
+                .with_limit(limit);
+            if let Err(e) = run_viewer(&eventlog, &options) {
                 let suggestions = vec![
                     format!("vifei view {}", eventlog.display()),
                     "vifei --help".to_string(),
@@ -323,21 +939,24 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 return AppExit::RuntimeError;
             }
         }
-        Commands::Export {
-            eventlog,
+        Commands::Import {
+            cassette,
             output,
-            share_safe,
-            refusal_report,
+            format,
+            offload_threshold,
+            follow,
+            mapping,
+            finalize_runs: finalize,
         } => {
-            if let Err(msg) = ensure_file_exists(&eventlog, "eventlog file") {
+            if let Err(msg) = ensure_file_exists(&cassette, "source file") {
                 let suggestions = vec![
                     format!(
                         "Check that `{}` exists and is readable.",
-                        eventlog.display()
+                        cassette.display()
                     ),
                     format!(
-                        "vifei export {} --share-safe --output {}",
-                        eventlog.display(),
+                        "vifei import {} --output {}",
+                        cassette.display(),
                         output.display()
                     ),
                 ];
@@ -353,84 +972,474 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     eprintln!(
                         "{}",
                         format_cli_failure(
-                            &format!("export failed: {msg}"),
+                            &format!("import failed: {msg}"),
                             "Input path does not exist.",
                             &suggestions,
-                            &[eventlog.display().to_string()],
+                            &[cassette.display().to_string()],
                         )
                     );
                 }
                 return AppExit::NotFound;
             }
-            if !share_safe {
+            if output.exists() {
+                let msg = format!("output already exists: {}", output.display());
                 let suggestions = vec![
-                    format!(
-                        "vifei export {} --share-safe --output {}",
-                        eventlog.display(),
-                        output.display()
-                    ),
-                    format!(
-                        "vifei export {} --share-safe --output {} --refusal-report out/refusal-report.json",
-                        eventlog.display(),
-                        output.display()
-                    ),
+                    "Choose a new --output path; import never appends to an existing EventLog."
+                        .to_string(),
                 ];
                 if mode == OutputMode::Json {
                     emit_json_error(
                         "INVALID_ARGS",
-                        "--share-safe flag is required in v0.1.",
+                        &msg,
                         &suggestions,
                         repair_notes,
                         AppExit::InvalidArgs as u8,
                     );
                 } else {
-                    let msg = format_cli_failure(
-                        "--share-safe flag is required in v0.1.",
-                        "Export without secret scanning is disabled for share-safe posture.",
-                        &suggestions,
-                        &[eventlog.display().to_string()],
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("import failed: {msg}"),
+                            "Appending would mix runs into one EventLog.",
+                            &suggestions,
+                            &[output.display().to_string()],
+                        )
                     );
-                    eprintln!("{msg}");
                 }
                 return AppExit::InvalidArgs;
             }
 
-            let mut config = ExportConfig::new(&eventlog, &output);
-            config.share_safe = share_safe;
-            if let Some(report_path) = refusal_report {
-                config = config.with_refusal_report(report_path);
+            let cassette_only_flag = if follow {
+                Some("--follow")
+            } else if mapping.is_some() {
+                Some("--mapping")
+            } else {
+                None
+            };
+            if let Some(flag) =
+                cassette_only_flag.filter(|_| format != ImportSourceFormat::Cassette)
+            {
+                let msg = format!(
+                    "{flag} supports cassette sources only, not {}",
+                    import_format_name(format)
+                );
+                let suggestions = vec![format!(
+                    "vifei import {} --output {} --format {}",
+                    cassette.display(),
+                    output.display(),
+                    import_format_name(format)
+                )];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "INVALID_ARGS",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::InvalidArgs as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("import failed: {msg}"),
+                            "Only line-oriented cassette recordings can be tailed or remapped.",
+                            &suggestions,
+                            &[cassette.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::InvalidArgs;
             }
 
-            match vifei_export::run_export(&config) {
-                Ok(ExportResult::Success(success)) => {
+            let field_mapping = match load_mapping(mapping.as_deref()) {
+                Ok(field_mapping) => field_mapping,
+                Err(msg) => {
+                    let suggestions = mapping_suggestions();
                     if mode == OutputMode::Json {
-                        emit_json_success(
-                            "OK",
-                            "Export completed successfully.",
-                            Some("export"),
-                            AppExit::Success as u8,
+                        emit_json_error(
+                            "INVALID_ARGS",
+                            &msg,
+                            &suggestions,
                             repair_notes,
-                            json!({
-                                "bundle_path": success.bundle_path,
-                                "bundle_hash": success.bundle_hash,
-                                "event_count": success.event_count,
-                                "blob_count": success.blob_count,
-                            }),
+                            AppExit::InvalidArgs as u8,
                         );
                     } else {
-                        println!("Export successful!");
-                        println!("  Bundle: {}", success.bundle_path.display());
-                        println!("  Hash:   {}", success.bundle_hash);
-                        println!("  Events: {}", success.event_count);
-                        println!("  Blobs:  {}", success.blob_count);
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("import failed: {msg}"),
+                                "The --mapping file could not be loaded.",
+                                &suggestions,
+                                &[mapping
+                                    .as_deref()
+                                    .map(|p| p.display().to_string())
+                                    .unwrap_or_default()],
+                            )
+                        );
                     }
+                    return AppExit::InvalidArgs;
                 }
-                Ok(ExportResult::Refused(report)) => {
-                    let mut evidence = vec![eventlog.display().to_string()];
-                    if let Some(ref report_path) = config.refusal_report_path {
-                        evidence.push(report_path.display().to_string());
+            };
+
+            let result = if follow {
+                if mode == OutputMode::Human {
+                    eprintln!(
+                        "Following {} -> {} (Ctrl-C to stop)",
+                        cassette.display(),
+                        output.display()
+                    );
+                }
+                run_import_follow(&cassette, &output, &field_mapping, offload_threshold)
+            } else {
+                run_import(
+                    &cassette,
+                    &output,
+                    format,
+                    &field_mapping,
+                    offload_threshold,
+                )
+            };
+            let result = result.and_then(|mut summary| {
+                if finalize {
+                    let run_ends = finalize_runs(&summary.committed);
+                    summary.finalized_runs = run_ends.len();
+                    if !run_ends.is_empty() {
+                        let appended = append_imported(run_ends, &output, &cassette)?;
+                        summary.committed.extend(appended);
                     }
-                    let suggestions = vec![
+                }
+                Ok(summary)
+            });
+            let summary = match result {
+                Ok(summary) => summary,
+                Err(msg) => {
+                    let suggestions = vec![
+                        format!(
+                            "Check that `{}` is a {} recording and `{}` is writable.",
+                            cassette.display(),
+                            import_format_name(format),
+                            output.display()
+                        ),
+                        "vifei import --help".to_string(),
+                    ];
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("import failed: {msg}"),
+                                "Source could not be read or EventLog could not be written.",
+                                &suggestions,
+                                &[cassette.display().to_string(), output.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
+
+            let committed_count = summary.committed.len();
+            let detection_count = committed_count - summary.imported_count - summary.finalized_runs;
+            let final_commit_index = summary.committed.last().map(|e| e.commit_index);
+            let (state, _) = replay(&summary.committed);
+            let replay_hash = state_hash(&state);
+
+            if mode == OutputMode::Json {
+                let mut data = json!({
+                    "eventlog_path": output,
+                    "source_path": cassette,
+                    "format": import_format_name(format),
+                    "imported_count": summary.imported_count,
+                    "committed_count": committed_count,
+                    "detection_count": detection_count,
+                    "finalized_runs": summary.finalized_runs,
+                    "final_commit_index": final_commit_index,
+                    "offloaded_payloads": summary.offloaded_payloads,
+                    "state_hash": replay_hash,
+                });
+                if let Some(report) = &summary.report {
+                    data["import_report"] = json!(report);
+                }
+                if let Some(outcome) = &summary.follow {
+                    data["follow"] = json!({
+                        "stopped_by": outcome.stopped_by,
+                        "source_offset": outcome.source_offset,
+                        "pending_bytes": outcome.pending_bytes,
+                    });
+                }
+                emit_json_success(
+                    "OK",
+                    "Import completed successfully.",
+                    Some("import"),
+                    AppExit::Success as u8,
+                    repair_notes,
+                    data,
+                );
+            } else {
+                say!("Import successful!");
+                say!("  EventLog:   {}", output.display());
+                say!(
+                    "  Events:     {committed_count} committed ({} imported, {detection_count} detection)",
+                    summary.imported_count
+                );
+                match final_commit_index {
+                    Some(index) => say!("  Final commit_index: {index}"),
+                    None => say!("  Final commit_index: (empty EventLog)"),
+                }
+                if summary.finalized_runs > 0 {
+                    say!(
+                        "  Finalized:  {} incomplete run(s) with a synthesized truncated RunEnd",
+                        summary.finalized_runs
+                    );
+                }
+                if summary.offloaded_payloads > 0 {
+                    say!(
+                        "  Offloaded:  {} payload(s) to {}",
+                        summary.offloaded_payloads,
+                        sibling_blobs_dir(&output).display()
+                    );
+                }
+                if let Some(outcome) = &summary.follow {
+                    say!(
+                        "  Follow:     stopped by {} at byte {}",
+                        outcome.stopped_by,
+                        outcome.source_offset
+                    );
+                    if outcome.pending_bytes > 0 {
+                        say!(
+                            "  Pending:    {} byte(s) of an unterminated line were not imported",
+                            outcome.pending_bytes
+                        );
+                    }
+                }
+                say!("  State hash: {replay_hash}");
+                say!("Next: vifei view {}", output.display());
+            }
+        }
+
+        Commands::Export {
+            eventlog,
+            output,
+            share_safe,
+            refusal_report,
+            include_checkpoint,
+            exclude_blobs,
+            scan_pii,
+            signing_key,
+            allow_truncated,
+            verify_blobs,
+            max_blob_size,
+            exclude_oversize_blobs,
+            base,
+        } => {
+            if let Err(msg) = ensure_file_exists(&eventlog, "eventlog file") {
+                let suggestions = vec![
+                    format!(
+                        "Check that `{}` exists and is readable.",
+                        eventlog.display()
+                    ),
+                    format!(
+                        "vifei export {} --share-safe --output {}",
+                        eventlog.display(),
+                        output.display()
+                    ),
+                ];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("export failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[eventlog.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
+            if !share_safe {
+                let suggestions = vec![
+                    format!(
+                        "vifei export {} --share-safe --output {}",
+                        eventlog.display(),
+                        output.display()
+                    ),
+                    format!(
+                        "vifei export {} --share-safe --output {} --refusal-report out/refusal-report.json",
+                        eventlog.display(),
+                        output.display()
+                    ),
+                ];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "INVALID_ARGS",
+                        "--share-safe flag is required in v0.1.",
+                        &suggestions,
+                        repair_notes,
+                        AppExit::InvalidArgs as u8,
+                    );
+                } else {
+                    let msg = format_cli_failure(
+                        "--share-safe flag is required in v0.1.",
+                        "Export without secret scanning is disabled for share-safe posture.",
+                        &suggestions,
+                        &[eventlog.display().to_string()],
+                    );
+                    eprintln!("{msg}");
+                }
+                return AppExit::InvalidArgs;
+            }
+
+            let mut config = ExportConfig::new(&eventlog, &output);
+            config.share_safe = share_safe;
+            if let Some(report_path) = refusal_report {
+                config = config.with_refusal_report(report_path);
+            }
+            if include_checkpoint {
+                config = config.with_checkpoint();
+            }
+            if exclude_blobs {
+                config = config.without_blobs();
+            }
+            if scan_pii {
+                config = config.with_pii_scan();
+            }
+            if let Some(key_path) = signing_key {
+                config = config.with_signing_key(key_path);
+            }
+            if allow_truncated {
+                config = config.with_truncated_allowed();
+            }
+            if verify_blobs {
+                config = config.with_blob_verification();
+            }
+            if let Some(bytes) = max_blob_size {
+                config = config.with_max_blob_size(bytes);
+            }
+            if exclude_oversize_blobs {
+                config = config.with_oversize_blobs_excluded();
+            }
+            if let Some(base_path) = base {
+                match vifei_export::verify::read_bundle_manifest(&base_path) {
+                    Ok(manifest) => config = config.with_base_manifest(manifest),
+                    Err(e) => {
+                        let (code, exit) = if e.kind() == std::io::ErrorKind::NotFound {
+                            ("NOT_FOUND", AppExit::NotFound)
+                        } else {
+                            ("INVALID_ARGS", AppExit::InvalidArgs)
+                        };
+                        let msg = format!("cannot read base bundle manifest: {e}");
+                        let suggestions = vec![
+                            format!("vifei verify-bundle {}", base_path.display()),
+                            format!(
+                                "vifei export {} --share-safe --output {}",
+                                eventlog.display(),
+                                output.display()
+                            ),
+                        ];
+                        if mode == OutputMode::Json {
+                            emit_json_error(code, &msg, &suggestions, repair_notes, exit as u8);
+                        } else {
+                            eprintln!(
+                                "{}",
+                                format_cli_failure(
+                                    &format!("export failed: {msg}"),
+                                    "--base must name a bundle written by `vifei export`.",
+                                    &suggestions,
+                                    &[base_path.display().to_string()],
+                                )
+                            );
+                        }
+                        return exit;
+                    }
+                }
+            }
+
+            let export_start = Instant::now();
+            let exported = vifei_export::run_export(&config);
+            note_stage("discover_scan_bundle", export_start.elapsed());
+            match exported {
+                Ok(ExportResult::Success(success)) => {
+                    if mode == OutputMode::Json {
+                        let notes: Vec<String> = repair_notes
+                            .iter()
+                            .chain(success.notes.iter())
+                            .cloned()
+                            .collect();
+                        let mut data = json!({
+                            "bundle_path": success.bundle_path,
+                            "bundle_hash": success.bundle_hash,
+                            "event_count": success.event_count,
+                            "blob_count": success.blob_count,
+                        });
+                        if let Some(commit_index) = success.checkpoint_commit_index {
+                            data["checkpoint_commit_index"] = json!(commit_index);
+                        }
+                        if let Some(range) = success.base_commit_index_range {
+                            data["base_commit_index_range"] = json!(range);
+                        }
+                        if success.blobs_excluded > 0 {
+                            data["blobs_excluded"] = json!(success.blobs_excluded);
+                        }
+                        if let Some(sig_path) = &success.signature_path {
+                            data["signature_path"] = json!(sig_path);
+                            data["signer_fingerprint"] = json!(success.signer_fingerprint);
+                        }
+                        emit_json_success(
+                            "OK",
+                            "Export completed successfully.",
+                            Some("export"),
+                            AppExit::Success as u8,
+                            &notes,
+                            data,
+                        );
+                    } else {
+                        say!("Export successful!");
+                        say!("  Bundle: {}", success.bundle_path.display());
+                        say!("  Hash:   {}", success.bundle_hash);
+                        say!("  Events: {}", success.event_count);
+                        say!("  Blobs:  {}", success.blob_count);
+                        if success.blobs_excluded > 0 {
+                            say!(
+                                "  Blobs excluded: {} (partial bundle)",
+                                success.blobs_excluded
+                            );
+                        }
+                        if let Some(commit_index) = success.checkpoint_commit_index {
+                            say!("  Checkpoint: commit_index {commit_index}");
+                        }
+                        if let (Some(sig_path), Some(fingerprint)) =
+                            (&success.signature_path, &success.signer_fingerprint)
+                        {
+                            say!("  Signature: {}", sig_path.display());
+                            say!("  Signer:    {fingerprint}");
+                        }
+                        for note in &success.notes {
+                            say!("  Note: {note}");
+                        }
+                    }
+                }
+                Ok(ExportResult::Refused(report)) => {
+                    let mut evidence = vec![eventlog.display().to_string()];
+                    if let Some(ref report_path) = config.refusal_report_path {
+                        evidence.push(report_path.display().to_string());
+                    }
+                    let suggestions = vec![
                         "Inspect refusal-report.json for exact blocked fields.".to_string(),
                         format!(
                             "vifei export {} --share-safe --output {} --refusal-report out/refusal-report.json",
@@ -467,7 +1476,10 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                             let loc = item
                                 .blob_ref
                                 .as_deref()
-                                .map(|b| format!("blob:{}", b))
+                                .map(|b| match item.byte_offset {
+                                    Some(offset) => format!("blob:{b}+{offset}"),
+                                    None => format!("blob:{b}"),
+                                })
                                 .unwrap_or_else(|| format!("event:{}", item.event_id));
                             eprintln!(
                                 "  - {} @ {}: {} ({})",
@@ -477,6 +1489,116 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     }
                     return AppExit::ExportRefused;
                 }
+                Ok(ExportResult::OversizeBlob(oversize)) => {
+                    let suggestions = vec![
+                        format!(
+                            "vifei export {} --share-safe --output {} --max-blob-size {} --exclude-oversize-blobs",
+                            eventlog.display(),
+                            output.display(),
+                            oversize.max_blob_size
+                        ),
+                        format!(
+                            "vifei export {} --share-safe --output {} --exclude-blobs",
+                            eventlog.display(),
+                            output.display()
+                        ),
+                    ];
+                    if mode == OutputMode::Json {
+                        let mut resp = json!({
+                            "schema_version": ROBOT_SCHEMA_VERSION,
+                            "ok": false,
+                            "code": "EXPORT_REFUSED",
+                            "message": format!("export refused: {oversize}"),
+                            "suggestions": suggestions,
+                            "max_blob_size": oversize.max_blob_size,
+                            "oversize_blobs": oversize.blobs,
+                            "exit_code": AppExit::ExportRefused as u8,
+                        });
+                        if !repair_notes.is_empty() {
+                            resp["notes"] = json!(repair_notes);
+                        }
+                        emit_json(resp);
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("export refused: {oversize}"),
+                                "A referenced blob is larger than --max-blob-size allows.",
+                                &suggestions,
+                                &[sibling_blobs_dir(&eventlog).display().to_string()],
+                            )
+                        );
+                        for blob in &oversize.blobs {
+                            eprintln!("  - blob:{} ({} bytes)", blob.payload_ref, blob.size);
+                        }
+                    }
+                    return AppExit::ExportRefused;
+                }
+                Err(VifeiError::Io(e))
+                    if e.get_ref()
+                        .is_some_and(|inner| inner.is::<TruncatedEventLog>()) =>
+                {
+                    let suggestions = vec![
+                        format!("vifei view {}", eventlog.display()),
+                        format!(
+                            "vifei export {} --share-safe --output {} --allow-truncated",
+                            eventlog.display(),
+                            output.display()
+                        ),
+                    ];
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "EXPORT_REFUSED",
+                            &format!("export refused: {e}"),
+                            &suggestions,
+                            repair_notes,
+                            AppExit::ExportRefused as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("export refused: {e}"),
+                                "The last append was cut short, likely by a crash; the partial record is not part of the log.",
+                                &suggestions,
+                                &[eventlog.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::ExportRefused;
+                }
+                Err(VifeiError::Io(e))
+                    if e.get_ref().is_some_and(|inner| inner.is::<CorruptBlobs>()) =>
+                {
+                    let suggestions = vec![
+                        format!("vifei blob-verify {}", eventlog.display()),
+                        format!(
+                            "vifei export {} --share-safe --output {}",
+                            eventlog.display(),
+                            output.display()
+                        ),
+                    ];
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "EXPORT_REFUSED",
+                            &format!("export refused: {e}"),
+                            &suggestions,
+                            repair_notes,
+                            AppExit::ExportRefused as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("export refused: {e}"),
+                                "A referenced blob is missing or its bytes no longer hash to its payload_ref.",
+                                &suggestions,
+                                &[sibling_blobs_dir(&eventlog).display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::ExportRefused;
+                }
                 Err(e) => {
                     let suggestions = vec![
                         format!(
@@ -511,15 +1633,28 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
         }
         Commands::Tour {
             fixture,
+            batch,
             stress,
             output_dir,
+            mapping,
+            format,
         } => {
-            if let Err(msg) = ensure_file_exists(&fixture, "fixture file") {
+            // Exactly one of `fixture` and `batch` is set (enforced by clap).
+            let input = fixture.clone().or(batch.clone()).unwrap_or_default();
+            let target = match &batch {
+                Some(dir) => format!("--batch {}", dir.display()),
+                None => input.display().to_string(),
+            };
+            let exists = match &batch {
+                Some(dir) if dir.is_dir() => Ok(()),
+                Some(dir) => Err(format!("fixture directory not found: {}", dir.display())),
+                None => ensure_file_exists(&input, "fixture file"),
+            };
+            if let Err(msg) = exists {
                 let suggestions = vec![
-                    format!("Check that `{}` exists and is readable.", fixture.display()),
+                    format!("Check that `{}` exists and is readable.", input.display()),
                     format!(
-                        "vifei tour {} --stress --output-dir {}",
-                        fixture.display(),
+                        "vifei tour {target} --stress --output-dir {}",
                         output_dir.display()
                     ),
                 ];
@@ -538,7 +1673,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                             &format!("tour failed: {msg}"),
                             "Fixture path does not exist.",
                             &suggestions,
-                            &[fixture.display().to_string()],
+                            &[input.display().to_string()],
                         )
                     );
                 }
@@ -546,8 +1681,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
             }
             if !stress {
                 let suggestions = vec![format!(
-                    "vifei tour {} --stress --output-dir {}",
-                    fixture.display(),
+                    "vifei tour {target} --stress --output-dir {}",
                     output_dir.display()
                 )];
                 if mode == OutputMode::Json {
@@ -563,17 +1697,100 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                         "--stress flag is required in v0.1.",
                         "Tour is a stress harness and must run with explicit stress intent.",
                         &suggestions,
-                        &[fixture.display().to_string()],
+                        &[input.display().to_string()],
                     );
                     eprintln!("{msg}");
                 }
                 return AppExit::InvalidArgs;
             }
 
-            let config = TourConfig::new(&fixture).with_output_dir(&output_dir);
-
-            match vifei_tour::run_tour(&config) {
-                Ok(result) => {
+            if mapping.is_some() && format != TourFixtureFormat::Cassette {
+                let msg = "--mapping supports cassette fixtures only, not otel";
+                let suggestions = vec![format!(
+                    "vifei tour {target} --stress --format otel --output-dir {}",
+                    output_dir.display()
+                )];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "INVALID_ARGS",
+                        msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::InvalidArgs as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("tour failed: {msg}"),
+                            "OTLP spans have a fixed schema and cannot be remapped.",
+                            &suggestions,
+                            &[input.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::InvalidArgs;
+            }
+
+            let field_mapping = match load_mapping(mapping.as_deref()) {
+                Ok(field_mapping) => field_mapping,
+                Err(msg) => {
+                    let suggestions = mapping_suggestions();
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "INVALID_ARGS",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::InvalidArgs as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("tour failed: {msg}"),
+                                "The --mapping file could not be loaded.",
+                                &suggestions,
+                                &[mapping
+                                    .as_deref()
+                                    .map(|p| p.display().to_string())
+                                    .unwrap_or_default()],
+                            )
+                        );
+                    }
+                    return AppExit::InvalidArgs;
+                }
+            };
+            let fixture_format = match format {
+                TourFixtureFormat::Cassette => FixtureFormat::Cassette,
+                TourFixtureFormat::Otel => FixtureFormat::Otel,
+            };
+            if let Some(batch_dir) = batch {
+                return run_tour_batch_command(
+                    &batch_dir,
+                    &output_dir,
+                    field_mapping,
+                    fixture_format,
+                    mode,
+                    repair_notes,
+                );
+            }
+            let config = TourConfig::new(&input)
+                .with_output_dir(&output_dir)
+                .with_mapping(field_mapping)
+                .with_format(fixture_format);
+
+            match vifei_tour::run_tour_with_profile(&config) {
+                Ok((result, profile)) => {
+                    for (stage, elapsed) in [
+                        ("parse_fixture", profile.parse_fixture),
+                        ("append_writer", profile.append_writer),
+                        ("reducer", profile.reducer),
+                        ("projection", profile.projection),
+                        ("metrics_emit", profile.metrics_emit),
+                    ] {
+                        note_stage(stage, elapsed);
+                    }
                     if mode == OutputMode::Json {
                         emit_json_success(
                             "OK",
@@ -591,29 +1808,30 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                                     "metrics.json",
                                     "viewmodel.hash",
                                     "ansi.capture",
-                                    "timetravel.capture"
+                                    "timetravel.capture",
+                                    "import-report.json"
                                 ],
                             }),
                         );
                     } else {
-                        println!("Tour completed successfully!");
-                        println!("  Output:   {}", result.output_dir.display());
-                        println!("  Events:   {}", result.metrics.event_count_total);
-                        println!("  Drops:    {}", result.metrics.tier_a_drops);
-                        println!("  Level:    {}", result.metrics.degradation_level_final);
-                        println!("  Hash:     {}", result.viewmodel_hash);
-                        println!();
-                        println!("Artifacts:");
-                        println!("  - metrics.json");
-                        println!("  - viewmodel.hash");
-                        println!("  - ansi.capture");
-                        println!("  - timetravel.capture");
+                        say!("Tour completed successfully!");
+                        say!("  Output:   {}", result.output_dir.display());
+                        say!("  Events:   {}", result.metrics.event_count_total);
+                        say!("  Drops:    {}", result.metrics.tier_a_drops);
+                        say!("  Level:    {}", result.metrics.degradation_level_final);
+                        say!("  Hash:     {}", result.viewmodel_hash);
+                        say!();
+                        say!("Artifacts:");
+                        say!("  - metrics.json");
+                        say!("  - viewmodel.hash");
+                        say!("  - ansi.capture");
+                        say!("  - timetravel.capture");
+                        say!("  - import-report.json");
                     }
                 }
                 Err(e) => {
                     let suggestions = vec![format!(
-                        "vifei tour {} --stress --output-dir {}",
-                        fixture.display(),
+                        "vifei tour {target} --stress --output-dir {}",
                         output_dir.display()
                     )];
                     if mode == OutputMode::Json {
@@ -632,7 +1850,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                                 "Fixture path is invalid or tour artifact generation failed.",
                                 &suggestions,
                                 &[
-                                    fixture.display().to_string(),
+                                    input.display().to_string(),
                                     output_dir.display().to_string()
                                 ],
                             )
@@ -647,7 +1865,16 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
             right,
             left_format,
             right_format,
+            state_diff,
+            align,
+            ignore_paths,
+            timetravel,
+            detail,
+            max_divergences,
         } => {
+            if timetravel {
+                return run_timetravel_compare_command(&left, &right, mode, repair_notes);
+            }
             if let Err(msg) = ensure_file_exists(&left, "left input file") {
                 let suggestions =
                     compare_replay_suggestions(&left, &right, left_format, right_format);
@@ -752,39 +1979,106 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 }
             };
 
-            let delta = diff_runs(&left_events, &right_events);
+            let alignment = match align {
+                CompareAlignment::Positional => DiffAlignment::Positional,
+                CompareAlignment::ById => DiffAlignment::ById,
+            };
+            let options = DiffOptions::new()
+                .with_alignment(alignment)
+                .with_ignore_paths(ignore_paths);
+            let mut delta = diff_runs_with(&left_events, &right_events, &options);
+            let mut state_delta =
+                state_diff.then(|| diff_states(&replay(&left_events).0, &replay(&right_events).0));
+            if detail == CompareDetail::Diff {
+                delta.rendered = render_divergences(
+                    &left_events,
+                    &right_events,
+                    &delta,
+                    max_divergences,
+                    redact_for_display,
+                );
+                redact_divergence_values(&mut delta, state_delta.as_mut());
+            }
             let divergence_count = delta.divergences.len();
             let replay = compare_replay_suggestions(&left, &right, left_format, right_format);
             if divergence_count == 0 {
                 if mode == OutputMode::Json {
+                    let mut data = json!({
+                        "status": "NO_DIFF",
+                        "left_path": left,
+                        "right_path": right,
+                        "left_format": format!("{left_format:?}").to_lowercase(),
+                        "right_format": format!("{right_format:?}").to_lowercase(),
+                        "delta": delta,
+                        "replay_commands": replay,
+                    });
+                    if let Some(state_delta) = &state_delta {
+                        data["state_delta"] = json!(state_delta);
+                    }
                     emit_json_success(
                         "OK",
                         "No divergence detected.",
                         Some("compare"),
                         AppExit::Success as u8,
                         repair_notes,
-                        json!({
-                            "status": "NO_DIFF",
-                            "left_path": left,
-                            "right_path": right,
-                            "left_format": format!("{left_format:?}").to_lowercase(),
-                            "right_format": format!("{right_format:?}").to_lowercase(),
-                            "delta": delta,
-                            "replay_commands": replay,
-                        }),
+                        data,
                     );
                 } else {
-                    println!("Compare completed: no divergence.");
-                    println!("  Left:  {}", left.display());
-                    println!("  Right: {}", right.display());
-                    println!("Next command(s):");
+                    say!("Compare completed: no divergence.");
+                    say!("  Left:  {}", left.display());
+                    say!("  Right: {}", right.display());
+                    if let Some(state_delta) = &state_delta {
+                        say_state_delta(state_delta);
+                    }
+                    say!("Next command(s):");
                     for (idx, cmd) in replay.iter().enumerate() {
-                        println!("  {}. {}", idx + 1, cmd);
+                        say!("  {}. {}", idx + 1, cmd);
                     }
                 }
                 return AppExit::Success;
             }
 
+            let critical_count = delta.critical_count();
+            if critical_count == 0 {
+                if mode == OutputMode::Json {
+                    let mut data = json!({
+                        "status": "MINOR_DIFF",
+                        "left_path": left,
+                        "right_path": right,
+                        "left_format": format!("{left_format:?}").to_lowercase(),
+                        "right_format": format!("{right_format:?}").to_lowercase(),
+                        "divergence_count": divergence_count,
+                        "critical_count": critical_count,
+                        "severity": delta.severity(),
+                        "delta": delta,
+                        "replay_commands": replay,
+                    });
+                    if let Some(state_delta) = &state_delta {
+                        data["state_delta"] = json!(state_delta);
+                    }
+                    emit_json_success(
+                        "MINOR_DIFF",
+                        &format!(
+                            "Detected {divergence_count} minor divergence(s); Tier A truth agrees."
+                        ),
+                        Some("compare"),
+                        AppExit::MinorDiff as u8,
+                        repair_notes,
+                        data,
+                    );
+                } else {
+                    say!("Compare completed: minor divergence only.");
+                    say!("  Left:        {}", left.display());
+                    say!("  Right:       {}", right.display());
+                    say!("  Divergences: {} (all minor)", divergence_count);
+                    say_run_delta(&delta, max_divergences);
+                    if let Some(state_delta) = &state_delta {
+                        say_state_delta(state_delta);
+                    }
+                }
+                return AppExit::MinorDiff;
+            }
+
             if mode == OutputMode::Json {
                 let mut response = json!({
                     "schema_version": ROBOT_SCHEMA_VERSION,
@@ -801,28 +2095,34 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                         "left_format": format!("{left_format:?}").to_lowercase(),
                         "right_format": format!("{right_format:?}").to_lowercase(),
                         "divergence_count": divergence_count,
+                        "critical_count": critical_count,
+                        "severity": delta.severity(),
                         "delta": delta,
                     }
                 });
+                if let Some(state_delta) = &state_delta {
+                    response["data"]["state_delta"] = json!(state_delta);
+                }
                 if !repair_notes.is_empty() {
                     response["notes"] = json!(repair_notes);
                 }
                 emit_json(response);
             } else {
-                println!("Compare completed: divergence detected.");
-                println!("  Left:        {}", left.display());
-                println!("  Right:       {}", right.display());
-                println!("  Divergences: {}", divergence_count);
-                println!("Top divergences:");
-                for divergence in delta.divergences.iter().take(10) {
-                    println!(
-                        "  - commit={} path={} class={:?}",
-                        divergence.commit_index, divergence.path, divergence.change_class
-                    );
-                }
-                println!("Next command(s):");
+                say!("Compare completed: divergence detected.");
+                say!("  Left:        {}", left.display());
+                say!("  Right:       {}", right.display());
+                say!(
+                    "  Divergences: {} ({} critical)",
+                    divergence_count,
+                    critical_count
+                );
+                say_run_delta(&delta, max_divergences);
+                if let Some(state_delta) = &state_delta {
+                    say_state_delta(state_delta);
+                }
+                say!("Next command(s):");
                 for (idx, cmd) in replay.iter().enumerate() {
-                    println!("  {}. {}", idx + 1, cmd);
+                    say!("  {}. {}", idx + 1, cmd);
                 }
             }
             return AppExit::DiffFound;
@@ -926,12 +2226,15 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 return AppExit::RuntimeError;
             }
 
-            let run_tour_once = |target_dir: &Path| {
+            let run_tour_once = |target_dir: &Path, stage: &str| {
                 let config = TourConfig::new(&fixture_path).with_output_dir(target_dir);
-                vifei_tour::run_tour(&config)
-                    .map_err(|e| format!("verify tour failed for {}: {e}", target_dir.display()))
+                let start = Instant::now();
+                let result = vifei_tour::run_tour(&config)
+                    .map_err(|e| format!("verify tour failed for {}: {e}", target_dir.display()));
+                note_stage(stage, start.elapsed());
+                result
             };
-            let tour_a = match run_tour_once(&duel_a_dir) {
+            let tour_a = match run_tour_once(&duel_a_dir, "tour_a") {
                 Ok(result) => result,
                 Err(msg) => {
                     let suggestions = vec![format!(
@@ -961,7 +2264,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     return AppExit::RuntimeError;
                 }
             };
-            let tour_b = match run_tour_once(&duel_b_dir) {
+            let tour_b = match run_tour_once(&duel_b_dir, "tour_b") {
                 Ok(result) => result,
                 Err(msg) => {
                     let suggestions = vec![format!(
@@ -992,6 +2295,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 }
             };
 
+            let checks_start = Instant::now();
             let sample_refusal_eventlog = Path::new(env!("CARGO_MANIFEST_DIR"))
                 .parent()
                 .and_then(Path::parent)
@@ -1005,10 +2309,18 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 Ok(ExportResult::Refused(report)) => {
                     (!report.blocked_items.is_empty(), report.blocked_items.len())
                 }
-                Ok(ExportResult::Success(_)) => (false, 0),
+                Ok(ExportResult::Success(_) | ExportResult::OversizeBlob(_)) => (false, 0),
                 Err(_) => (false, 0),
             };
 
+            let eventlog_issues = validate_eventlog(&sample_refusal_eventlog)
+                .map(|validation| validation.issues.len())
+                .ok();
+            // The chain is checked only when the sample log carries one.
+            let eventlog_chain = verify_eventlog_chain(&sample_refusal_eventlog).ok();
+
+            note_stage("refusal_and_eventlog_checks", checks_start.elapsed());
+
             let ansi_capture_path = duel_a_dir.join("ansi.capture");
             let explainability_surface = fs::read_to_string(&ansi_capture_path)
                 .ok()
@@ -1021,16 +2333,37 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     && tour_b.metrics.tier_a_drops == 0,
                 refusal_semantics,
                 explainability_surface,
+                eventlog_integrity: eventlog_issues == Some(0)
+                    && eventlog_chain
+                        .as_ref()
+                        .is_some_and(|chain| chain.is_intact())
+                    && tour_a.integrity.is_clean()
+                    && tour_b.integrity.is_clean(),
                 hash_a: tour_a.viewmodel_hash.clone(),
                 hash_b: tour_b.viewmodel_hash.clone(),
+                divergences: diff_viewmodels(&tour_a.viewmodel, &tour_b.viewmodel),
                 blocked_count,
             };
             let all_pass = checks.determinism_stability
                 && checks.tier_a_no_drop
                 && checks.refusal_semantics
-                && checks.explainability_surface;
+                && checks.explainability_surface
+                && checks.eventlog_integrity;
 
             if mode == OutputMode::Json {
+                let data = json!({
+                    "strict": true,
+                    "mode": if full { "full" } else { "fast" },
+                    "fixture": fixture_path,
+                    "output_dir": verify_dir,
+                    "checks": {
+                        "determinism_stability": {"pass": checks.determinism_stability, "hash_a": checks.hash_a, "hash_b": checks.hash_b, "divergences": checks.divergences},
+                        "tier_a_no_drop": {"pass": checks.tier_a_no_drop},
+                        "refusal_semantics": {"pass": checks.refusal_semantics, "blocked_count": checks.blocked_count},
+                        "explainability_surface": {"pass": checks.explainability_surface},
+                        "eventlog_integrity": {"pass": checks.eventlog_integrity, "issue_count": eventlog_issues, "chained": eventlog_chain.as_ref().map(|chain| chain.chained), "fixture_logs": [tour_a.integrity, tour_b.integrity]}
+                    }
+                });
                 if all_pass {
                     emit_json_success(
                         "OK",
@@ -1038,40 +2371,35 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                         Some("verify"),
                         AppExit::Success as u8,
                         repair_notes,
-                        json!({
-                            "strict": true,
-                            "mode": if full { "full" } else { "fast" },
-                            "fixture": fixture_path,
-                            "output_dir": verify_dir,
-                            "checks": {
-                                "determinism_stability": {"pass": checks.determinism_stability, "hash_a": checks.hash_a, "hash_b": checks.hash_b},
-                                "tier_a_no_drop": {"pass": checks.tier_a_no_drop},
-                                "refusal_semantics": {"pass": checks.refusal_semantics, "blocked_count": checks.blocked_count},
-                                "explainability_surface": {"pass": checks.explainability_surface}
-                            }
-                        }),
+                        data,
                     );
                     return AppExit::Success;
                 }
-                emit_json_error(
-                    "RUNTIME_ERROR",
-                    "Strict verification checks failed.",
-                    &[
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "RUNTIME_ERROR",
+                    "message": "Strict verification checks failed.",
+                    "suggestions": [
                         format!("Inspect verify artifacts at {}", verify_dir.display()),
-                        "Run `vifei verify --strict --full` for stress-grade verification."
-                            .to_string(),
+                        "Run `vifei verify --strict --full` for stress-grade verification.",
                     ],
-                    repair_notes,
-                    AppExit::RuntimeError as u8,
-                );
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "verify",
+                    "data": data,
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
                 return AppExit::RuntimeError;
             }
 
-            println!("Strict verification summary");
-            println!("  mode: {}", if full { "full" } else { "fast" });
-            println!("  fixture: {}", fixture_path.display());
-            println!("  output: {}", verify_dir.display());
-            println!(
+            say!("Strict verification summary");
+            say!("  mode: {}", if full { "full" } else { "fast" });
+            say!("  fixture: {}", fixture_path.display());
+            say!("  output: {}", verify_dir.display());
+            say!(
                 "  determinism: {}",
                 if checks.determinism_stability {
                     "PASS"
@@ -1079,9 +2407,17 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     "FAIL"
                 }
             );
-            println!("    hash_a: {}", checks.hash_a);
-            println!("    hash_b: {}", checks.hash_b);
-            println!(
+            say!("    hash_a: {}", checks.hash_a);
+            say!("    hash_b: {}", checks.hash_b);
+            for divergence in checks.divergences.iter().take(10) {
+                say!(
+                    "    - {}: {} -> {}",
+                    divergence.path,
+                    divergence.left_value.as_deref().unwrap_or("(absent)"),
+                    divergence.right_value.as_deref().unwrap_or("(absent)")
+                );
+            }
+            say!(
                 "  tier_a_no_drop: {}",
                 if checks.tier_a_no_drop {
                     "PASS"
@@ -1089,7 +2425,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     "FAIL"
                 }
             );
-            println!(
+            say!(
                 "  refusal_semantics: {} (blocked_count={})",
                 if checks.refusal_semantics {
                     "PASS"
@@ -1098,7 +2434,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 },
                 checks.blocked_count
             );
-            println!(
+            say!(
                 "  explainability_surface: {}",
                 if checks.explainability_surface {
                     "PASS"
@@ -1106,9 +2442,17 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     "FAIL"
                 }
             );
+            say!(
+                "  eventlog_integrity: {}",
+                if checks.eventlog_integrity {
+                    "PASS"
+                } else {
+                    "FAIL"
+                }
+            );
 
             if all_pass {
-                println!("verify status: PASS");
+                say!("verify status: PASS");
                 return AppExit::Success;
             }
 
@@ -1135,6 +2479,7 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
             left_format,
             right_format,
             output_dir,
+            ignore_paths,
         } => {
             if let Err(msg) = ensure_file_exists(&left, "left input file") {
                 let suggestions =
@@ -1333,7 +2678,11 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 return AppExit::RuntimeError;
             }
 
-            let delta = diff_runs(&left_events, &right_events);
+            // Incident inputs are different runs, not replays.
+            let options = DiffOptions::new()
+                .with_alignment(DiffAlignment::ById)
+                .with_ignore_paths(ignore_paths);
+            let delta = diff_runs_with(&left_events, &right_events, &options);
             let divergence_count = delta.divergences.len();
             let delta_path = compare_dir.join("delta.json");
             if let Err(e) = write_json_pretty(&delta_path, &json!(delta)) {
@@ -1362,11 +2711,45 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 }
                 return AppExit::RuntimeError;
             }
+            let state_delta = diff_states(&replay(&left_events).0, &replay(&right_events).0);
+            let state_delta_path = compare_dir.join("state-delta.json");
+            if let Err(e) = write_json_pretty(&state_delta_path, &json!(state_delta)) {
+                let suggestions = vec![format!(
+                    "Check write permissions for {}",
+                    compare_dir.display()
+                )];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "RUNTIME_ERROR",
+                        &e,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::RuntimeError as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("incident-pack failed: {e}"),
+                            "Unable to persist compare state delta artifact.",
+                            &suggestions,
+                            &[state_delta_path.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::RuntimeError;
+            }
 
             let left_replay_path = replay_dir.join("left.replay.json");
             let right_replay_path = replay_dir.join("right.replay.json");
-            let left_replay = replay_summary(&left_events);
-            let right_replay = replay_summary(&right_events);
+            let left_replay = replay_summary(
+                &left_events,
+                (left_format == CompareInputFormat::Eventlog).then_some(left.as_path()),
+            );
+            let right_replay = replay_summary(
+                &right_events,
+                (right_format == CompareInputFormat::Eventlog).then_some(right.as_path()),
+            );
             if let Err(e) = write_json_pretty(&left_replay_path, &left_replay) {
                 if mode == OutputMode::Json {
                     emit_json_error(
@@ -1471,6 +2854,22 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     }
                     return AppExit::ExportRefused;
                 }
+                // No blob size limit is set above, so this is never reached.
+                (Ok(ExportResult::OversizeBlob(oversize)), _)
+                | (_, Ok(ExportResult::OversizeBlob(oversize))) => {
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "EXPORT_REFUSED",
+                            &oversize.to_string(),
+                            &[],
+                            repair_notes,
+                            AppExit::ExportRefused as u8,
+                        );
+                    } else {
+                        eprintln!("incident-pack export refused: {oversize}");
+                    }
+                    return AppExit::ExportRefused;
+                }
                 (Err(e), _) | (_, Err(e)) => {
                     let suggestions = vec![format!(
                         "vifei export {} --share-safe --output out.tar.zst --refusal-report out/refusal-report.json",
@@ -1499,6 +2898,35 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                 }
             };
 
+            let incident_html_path = output_dir.join("incident.html");
+            let incident_html = render_incident_html(&delta, &left_bundle_hash, &right_bundle_hash);
+            if let Err(e) = fs::write(&incident_html_path, incident_html) {
+                let suggestions = vec![format!(
+                    "Check write permissions for {}",
+                    output_dir.display()
+                )];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "RUNTIME_ERROR",
+                        &format!("failed to write {}: {e}", incident_html_path.display()),
+                        &suggestions,
+                        repair_notes,
+                        AppExit::RuntimeError as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("incident-pack failed: {e}"),
+                            "Unable to persist HTML incident report.",
+                            &suggestions,
+                            &[incident_html_path.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::RuntimeError;
+            }
+
             let mut files = BTreeMap::new();
             let tracked = [
                 (
@@ -1510,10 +2938,12 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     right_eventlog_path.as_path(),
                 ),
                 ("compare/delta.json", delta_path.as_path()),
+                ("compare/state-delta.json", state_delta_path.as_path()),
                 ("replay/left.replay.json", left_replay_path.as_path()),
                 ("replay/right.replay.json", right_replay_path.as_path()),
                 ("export/left.bundle.tar.zst", left_bundle_path.as_path()),
                 ("export/right.bundle.tar.zst", right_bundle_path.as_path()),
+                ("incident.html", incident_html_path.as_path()),
             ];
             for (name, path) in tracked {
                 match hash_file_blake3(path) {
@@ -1593,40 +3023,2103 @@ pub(crate) fn handle_command(cli: Cli, mode: OutputMode, repair_notes: &[String]
                     json!({
                         "output_dir": output_dir,
                         "manifest_path": manifest_path,
+                        "incident_html_path": incident_html_path,
                         "divergence_count": divergence_count,
                         "left_bundle_hash": left_bundle_hash,
                         "right_bundle_hash": right_bundle_hash,
                     }),
                 );
             } else {
-                println!("Incident pack generated.");
-                println!("  Output dir:      {}", output_dir.display());
-                println!("  Manifest:        {}", manifest_path.display());
-                println!("  Divergences:     {}", divergence_count);
-                println!("  Left bundle:     {}", left_bundle_path.display());
-                println!("  Right bundle:    {}", right_bundle_path.display());
+                say!("Incident pack generated.");
+                say!("  Output dir:      {}", output_dir.display());
+                say!("  Manifest:        {}", manifest_path.display());
+                say!("  HTML report:     {}", incident_html_path.display());
+                say!("  Divergences:     {}", divergence_count);
+                say!("  Left bundle:     {}", left_bundle_path.display());
+                say!("  Right bundle:    {}", right_bundle_path.display());
             }
             return AppExit::Success;
         }
-    }
-
-    AppExit::Success
-}
+        Commands::Gc { eventlog, delete } => {
+            return run_gc_command(&eventlog, delete, mode, repair_notes)
+        }
+        Commands::Stats { eventlog } => return run_stats_command(&eventlog, mode, repair_notes),
+        Commands::BlobLs { eventlog } => return run_blob_ls_command(&eventlog, mode, repair_notes),
+        Commands::VerifyBlobs { dir } => return run_verify_blobs_command(&dir, mode, repair_notes),
+        Commands::Redact {
+            input,
+            output,
+            patterns,
+        } => return run_redact_command(&input, &output, patterns, mode, repair_notes),
+        Commands::BlobVerify { eventlog } => {
+            if let Err(msg) = ensure_file_exists(&eventlog, "eventlog file") {
+                let suggestions = vec![
+                    format!(
+                        "Check that `{}` exists and is readable.",
+                        eventlog.display()
+                    ),
+                    format!("vifei blob-verify {}", eventlog.display()),
+                ];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("blob-verify failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[eventlog.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
 
-#[cfg(test)]
-mod tests {
-    use super::share_safe_input_label;
-    use std::path::Path;
+            let blobs_dir = sibling_blobs_dir(&eventlog);
+            let result = referenced_blob_refs(&eventlog).and_then(|referenced| {
+                if blobs_dir.is_dir() {
+                    BlobStore::open(&blobs_dir)
+                        .and_then(|store| store.verify_refs(&referenced))
+                        .map_err(|e| format!("blob verify failed in {}: {e}", blobs_dir.display()))
+                } else {
+                    // No store at all: every referenced blob is missing.
+                    let mut missing: Vec<String> = referenced.into_iter().collect();
+                    missing.sort();
+                    Ok(missing
+                        .into_iter()
+                        .map(|payload_ref| BlobIntegrityError::Missing { payload_ref })
+                        .collect())
+                }
+            });
+            let errors = match result {
+                Ok(errors) => errors,
+                Err(msg) => {
+                    let suggestions = vec![format!(
+                        "Check that `{}` is a valid EventLog and `{}` is readable.",
+                        eventlog.display(),
+                        blobs_dir.display()
+                    )];
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("blob-verify failed: {msg}"),
+                                "EventLog could not be read or blob store scan failed.",
+                                &suggestions,
+                                &[
+                                    eventlog.display().to_string(),
+                                    blobs_dir.display().to_string()
+                                ],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
 
-    #[test]
-    fn share_safe_input_label_uses_file_name_when_present() {
-        let path = Path::new("/tmp/demo/left.jsonl");
-        assert_eq!(share_safe_input_label(path), "left.jsonl");
-    }
+            let failures: Vec<Value> = errors
+                .iter()
+                .map(|error| {
+                    json!({
+                        "kind": error.kind(),
+                        "payload_ref": error.payload_ref(),
+                        "message": error.to_string(),
+                    })
+                })
+                .collect();
+            if errors.is_empty() {
+                if mode == OutputMode::Json {
+                    emit_json_success(
+                        "OK",
+                        "Blob store integrity verified.",
+                        Some("blob-verify"),
+                        AppExit::Success as u8,
+                        repair_notes,
+                        json!({
+                            "eventlog_path": eventlog,
+                            "blobs_dir": blobs_dir,
+                            "failure_count": 0,
+                            "failures": failures,
+                        }),
+                    );
+                } else {
+                    say!("Blob verify: PASS");
+                    say!("  Blobs dir: {}", blobs_dir.display());
+                }
+                return AppExit::Success;
+            }
 
-    #[test]
-    fn share_safe_input_label_falls_back_for_root_like_paths() {
-        let path = Path::new("/");
-        assert_eq!(share_safe_input_label(path), "input");
+            let suggestions = vec![
+                "Restore corrupted or missing blobs from a trusted copy or bundle.".to_string(),
+                format!("vifei blob-verify {}", eventlog.display()),
+            ];
+            if mode == OutputMode::Json {
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "INTEGRITY_FAILED",
+                    "message": format!("Detected {} blob integrity failure(s).", errors.len()),
+                    "suggestions": suggestions,
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "blob-verify",
+                    "data": {
+                        "eventlog_path": eventlog,
+                        "blobs_dir": blobs_dir,
+                        "failure_count": errors.len(),
+                        "failures": failures,
+                    }
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
+            } else {
+                let evidence: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!(
+                            "blob-verify failed: {} integrity failure(s).",
+                            errors.len()
+                        ),
+                        "Blob bytes no longer match their content hash, or referenced blobs are missing.",
+                        &suggestions,
+                        &evidence,
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+
+        Commands::VerifyBundle { bundle, public_key } => {
+            if let Err(msg) = ensure_file_exists(&bundle, "bundle file") {
+                let suggestions = vec![
+                    format!("Check that `{}` exists and is readable.", bundle.display()),
+                    format!("vifei verify-bundle {}", bundle.display()),
+                ];
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("verify-bundle failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[bundle.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
+
+            let verification = match verify_bundle(&bundle, public_key.as_deref()) {
+                Ok(verification) => verification,
+                Err(e) => {
+                    let msg = format!("verify-bundle failed: {e}");
+                    let suggestions = vec![
+                        "Check that the bundle is a `.tar.zst` produced by `vifei export`."
+                            .to_string(),
+                        "Check that the public key file holds 64 hex characters.".to_string(),
+                    ];
+                    let mut evidence = vec![bundle.display().to_string()];
+                    if let Some(key_path) = &public_key {
+                        evidence.push(key_path.display().to_string());
+                    }
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &msg,
+                                "Bundle or key could not be read.",
+                                &suggestions,
+                                &evidence,
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
+
+            let data = json!({
+                "bundle_path": bundle,
+                "bundle_hash": verification.bundle_hash,
+                "files_checked": verification.files_checked,
+                "signature_checked": public_key.is_some(),
+                "signer_fingerprint": verification.signer_fingerprint,
+                "failures": verification.failures,
+            });
+            if verification.is_valid() {
+                if mode == OutputMode::Json {
+                    emit_json_success(
+                        "OK",
+                        "Bundle verified.",
+                        Some("verify-bundle"),
+                        AppExit::Success as u8,
+                        repair_notes,
+                        data,
+                    );
+                } else {
+                    say!("Bundle verify: PASS");
+                    say!("  Hash:  {}", verification.bundle_hash);
+                    say!("  Files: {}", verification.files_checked);
+                    match &verification.signer_fingerprint {
+                        Some(fingerprint) => say!("  Signer: {fingerprint}"),
+                        None => say!("  Signature: not checked (no --public-key)"),
+                    }
+                }
+                return AppExit::Success;
+            }
+
+            let suggestions = vec![
+                "Do not trust this bundle; obtain a fresh copy from its producer.".to_string(),
+                format!("vifei verify-bundle {}", bundle.display()),
+            ];
+            if mode == OutputMode::Json {
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "INTEGRITY_FAILED",
+                    "message": format!(
+                        "Detected {} bundle verification failure(s).",
+                        verification.failures.len()
+                    ),
+                    "suggestions": suggestions,
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "verify-bundle",
+                    "data": data,
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!(
+                            "verify-bundle failed: {} verification failure(s).",
+                            verification.failures.len()
+                        ),
+                        "Bundle contents do not match the manifest, or the signature does not verify.",
+                        &suggestions,
+                        &verification.failures,
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+
+        Commands::VerifyEventlog { eventlog } => {
+            let suggestions = vec![
+                format!(
+                    "Check that `{}` exists and is readable.",
+                    eventlog.display()
+                ),
+                format!("vifei verify-eventlog {}", eventlog.display()),
+            ];
+            if let Err(msg) = ensure_file_exists(&eventlog, "EventLog file") {
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("verify-eventlog failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[eventlog.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
+
+            let checked = validate_eventlog(&eventlog)
+                .and_then(|validation| Ok((validation, verify_eventlog_chain(&eventlog)?)));
+            let (validation, chain) = match checked {
+                Ok(checked) => checked,
+                Err(e) => {
+                    let msg = format!("verify-eventlog failed: {e}");
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &msg,
+                                "EventLog could not be read.",
+                                &suggestions,
+                                &[eventlog.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
+
+            let data = json!({
+                "eventlog_path": eventlog,
+                "events_checked": validation.events_checked,
+                "highest_commit_index": validation.highest_commit_index,
+                "issues": validation.issues,
+                "integrity": validation.integrity,
+                "chain": chain,
+            });
+            let issue_count = validation.issues.len() + usize::from(!chain.is_intact());
+            if issue_count == 0 {
+                if mode == OutputMode::Json {
+                    emit_json_success(
+                        "OK",
+                        "EventLog commit_index sequence verified.",
+                        Some("verify-eventlog"),
+                        AppExit::Success as u8,
+                        repair_notes,
+                        data,
+                    );
+                } else {
+                    say!("EventLog verify: PASS");
+                    say!("  Events: {}", validation.events_checked);
+                    if let Some(highest) = validation.highest_commit_index {
+                        say!("  Highest commit_index: {highest}");
+                    }
+                    if chain.chained {
+                        say!("  Hash chain: intact");
+                    } else {
+                        say!("  Hash chain: absent (written before hash chaining)");
+                    }
+                }
+                return AppExit::Success;
+            }
+
+            let suggestions = vec![
+                "Re-import the source to rebuild a dense EventLog.".to_string(),
+                format!("vifei verify-eventlog {}", eventlog.display()),
+            ];
+            if mode == OutputMode::Json {
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "INTEGRITY_FAILED",
+                    "message": format!("Detected {issue_count} EventLog integrity issue(s)."),
+                    "suggestions": suggestions,
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "verify-eventlog",
+                    "data": data,
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
+            } else {
+                let evidence: Vec<String> = validation
+                    .issues
+                    .iter()
+                    .map(|issue| format!("line {}: {}", issue.line, issue.message))
+                    .chain(chain.first_break.iter().map(|chain_break| {
+                        format!(
+                            "line {}: hash chain broken: {}",
+                            chain_break.line, chain_break.message
+                        )
+                    }))
+                    .collect();
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("verify-eventlog failed: {issue_count} integrity issue(s)."),
+                        "commit_index values are missing, duplicated, or out of order, or a line was edited.",
+                        &suggestions,
+                        &evidence,
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+
+        Commands::TimetravelVerify { eventlog, capture } => {
+            let suggestions = vec![
+                format!(
+                    "Check that `{}` and `{}` exist and are readable.",
+                    eventlog.display(),
+                    capture.display()
+                ),
+                format!(
+                    "vifei timetravel-verify {} {}",
+                    eventlog.display(),
+                    capture.display()
+                ),
+            ];
+            let present = ensure_file_exists(&eventlog, "EventLog file")
+                .and_then(|()| ensure_file_exists(&capture, "time-travel capture"));
+            if let Err(msg) = present {
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("timetravel-verify failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[
+                                eventlog.display().to_string(),
+                                capture.display().to_string()
+                            ],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
+
+            let verified = read_timetravel_capture(&capture)
+                .and_then(|parsed| verify_timetravel(&eventlog, &parsed));
+            let verification = match verified {
+                Ok(verification) => verification,
+                Err(e) => {
+                    let msg = format!("timetravel-verify failed: {e}");
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &msg,
+                                "EventLog or capture could not be read, or the capture was written under other projection invariants.",
+                                &suggestions,
+                                &[eventlog.display().to_string(), capture.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
+
+            let data = json!({
+                "eventlog_path": eventlog,
+                "capture_path": capture,
+                "seek_points_checked": verification.seek_points_checked,
+                "seek_points_total": verification.seek_points_total,
+                "events_replayed": verification.events_replayed,
+                "first_mismatch": verification.first_mismatch,
+            });
+            let Some(mismatch) = &verification.first_mismatch else {
+                if mode == OutputMode::Json {
+                    emit_json_success(
+                        "OK",
+                        "Time-travel capture matches the replayed EventLog.",
+                        Some("timetravel-verify"),
+                        AppExit::Success as u8,
+                        repair_notes,
+                        data,
+                    );
+                } else {
+                    say!("Time-travel verify: PASS");
+                    say!("  Seek points: {}", verification.seek_points_checked);
+                    say!("  Events replayed: {}", verification.events_replayed);
+                }
+                return AppExit::Success;
+            };
+
+            let suggestions = vec![
+                "Do not trust this capture; regenerate it with `vifei tour`.".to_string(),
+                format!(
+                    "vifei timetravel-verify {} {}",
+                    eventlog.display(),
+                    capture.display()
+                ),
+            ];
+            if mode == OutputMode::Json {
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "INTEGRITY_FAILED",
+                    "message": format!(
+                        "Seek point at commit_index {} does not match the replayed EventLog.",
+                        mismatch.commit_index
+                    ),
+                    "suggestions": suggestions,
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "timetravel-verify",
+                    "data": data,
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!(
+                            "timetravel-verify failed at commit_index {}.",
+                            mismatch.commit_index
+                        ),
+                        "The capture's hashes were not produced by replaying this EventLog.",
+                        &suggestions,
+                        &[
+                            format!("{} captured: {}", mismatch.field, mismatch.captured),
+                            format!("{} recomputed: {}", mismatch.field, mismatch.recomputed),
+                        ],
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+
+        Commands::Annotate {
+            eventlog,
+            at,
+            text,
+            author,
+        } => {
+            let suggestions = vec![
+                format!(
+                    "Check that `{}` exists and is readable.",
+                    eventlog.display()
+                ),
+                format!(
+                    "vifei annotate {} --at <commit_index> --text <text>",
+                    eventlog.display()
+                ),
+            ];
+            if let Err(msg) = ensure_file_exists(&eventlog, "EventLog file") {
+                if mode == OutputMode::Json {
+                    emit_json_error(
+                        "NOT_FOUND",
+                        &msg,
+                        &suggestions,
+                        repair_notes,
+                        AppExit::NotFound as u8,
+                    );
+                } else {
+                    eprintln!(
+                        "{}",
+                        format_cli_failure(
+                            &format!("annotate failed: {msg}"),
+                            "Input path does not exist.",
+                            &suggestions,
+                            &[eventlog.display().to_string()],
+                        )
+                    );
+                }
+                return AppExit::NotFound;
+            }
+
+            let author = author
+                .or_else(|| std::env::var("USER").ok())
+                .filter(|a| !a.trim().is_empty())
+                .unwrap_or_else(|| "unknown".to_string());
+            let committed = match annotate_eventlog(&eventlog, at, author, text) {
+                Ok(committed) => committed,
+                Err(AnnotateError::TargetMissing(msg)) => {
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "INVALID_ARGS",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::InvalidArgs as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("annotate failed: {msg}"),
+                                "--at must name a commit_index present in the EventLog.",
+                                &suggestions,
+                                &[eventlog.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::InvalidArgs;
+                }
+                Err(AnnotateError::Io(msg)) => {
+                    let msg = format!("annotate failed: {msg}");
+                    if mode == OutputMode::Json {
+                        emit_json_error(
+                            "RUNTIME_ERROR",
+                            &msg,
+                            &suggestions,
+                            repair_notes,
+                            AppExit::RuntimeError as u8,
+                        );
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &msg,
+                                "EventLog could not be read or appended.",
+                                &suggestions,
+                                &[eventlog.display().to_string()],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            };
+
+            if mode == OutputMode::Json {
+                emit_json_success(
+                    "OK",
+                    "Note appended.",
+                    Some("annotate"),
+                    AppExit::Success as u8,
+                    repair_notes,
+                    json!({
+                        "eventlog_path": eventlog,
+                        "commit_index": committed.commit_index,
+                        "target_commit_index": at,
+                        "event_id": committed.event_id,
+                    }),
+                );
+            } else {
+                say!(
+                    "Note appended at commit_index {} (annotates #{at}).",
+                    committed.commit_index
+                );
+            }
+            return AppExit::Success;
+        }
+
+        Commands::TourSummary { dirs } => {
+            let mut runs = Vec::with_capacity(dirs.len());
+            for dir in &dirs {
+                match read_tour_metrics(dir) {
+                    Ok(metrics) => runs.push((dir.display().to_string(), metrics)),
+                    Err(e) => {
+                        let not_found = e.kind() == std::io::ErrorKind::NotFound;
+                        let (code, exit) = if not_found {
+                            ("NOT_FOUND", AppExit::NotFound)
+                        } else {
+                            ("RUNTIME_ERROR", AppExit::RuntimeError)
+                        };
+                        let msg =
+                            format!("failed to read {}: {e}", dir.join("metrics.json").display());
+                        let suggestions = vec![
+                            format!("Check that `{}` is a Tour output directory.", dir.display()),
+                            format!(
+                                "vifei tour <fixture.jsonl> --stress --output-dir {}",
+                                dir.display()
+                            ),
+                        ];
+                        if mode == OutputMode::Json {
+                            emit_json_error(code, &msg, &suggestions, repair_notes, exit as u8);
+                        } else {
+                            eprintln!(
+                                "{}",
+                                format_cli_failure(
+                                    &format!("tour-summary failed: {msg}"),
+                                    "Every directory must hold a readable Tour metrics.json.",
+                                    &suggestions,
+                                    &[dir.display().to_string()],
+                                )
+                            );
+                        }
+                        return exit;
+                    }
+                }
+            }
+            let summary = summarize_tours(runs.iter().map(|(label, m)| (label.as_str(), m)));
+            let data = serde_json::to_value(&summary).unwrap_or(Value::Null);
+
+            if !summary.any_tier_a_drops {
+                if mode == OutputMode::Json {
+                    emit_json_success(
+                        "OK",
+                        "Tour runs summarized.",
+                        Some("tour-summary"),
+                        AppExit::Success as u8,
+                        repair_notes,
+                        data,
+                    );
+                } else {
+                    say!("Tour summary: PASS");
+                    say!("  Runs:   {}", summary.run_count);
+                    say!("  Events: {}", summary.event_count_total);
+                    say!("  Drops:  0");
+                    say!("  Worst:  {}", summary.worst_degradation_level);
+                    for label in &summary.non_l0_final {
+                        say!("  Non-L0 final: {label}");
+                    }
+                }
+                return AppExit::Success;
+            }
+
+            let suggestions =
+                vec!["Tier A drops violate I1; inspect the listed runs' artifacts.".to_string()];
+            let msg = format!(
+                "{} of {} Tour run(s) dropped Tier A events.",
+                summary.runs_with_tier_a_drops.len(),
+                summary.run_count
+            );
+            if mode == OutputMode::Json {
+                let mut response = json!({
+                    "schema_version": ROBOT_SCHEMA_VERSION,
+                    "ok": false,
+                    "code": "INTEGRITY_FAILED",
+                    "message": msg,
+                    "suggestions": suggestions,
+                    "exit_code": AppExit::RuntimeError as u8,
+                    "command": "tour-summary",
+                    "data": data,
+                });
+                if !repair_notes.is_empty() {
+                    response["notes"] = json!(repair_notes);
+                }
+                emit_json(response);
+            } else {
+                let evidence: Vec<String> = summary
+                    .runs
+                    .iter()
+                    .filter(|run| run.tier_a_drops > 0)
+                    .map(|run| format!("{}: {} Tier A drop(s)", run.label, run.tier_a_drops))
+                    .collect();
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("tour-summary failed: {msg}"),
+                        "Tier A events must never be dropped (I1).",
+                        &suggestions,
+                        &evidence,
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+
+        Commands::ValidateArtifact { kind, path } => {
+            let kind_name = artifact_kind_name(kind);
+            let suggestions = vec![
+                format!("Check that `{}` exists and is readable.", path.display()),
+                format!("vifei validate-artifact {kind_name} {}", path.display()),
+            ];
+            let bytes = match fs::read(&path) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    let not_found = e.kind() == std::io::ErrorKind::NotFound;
+                    let (code, exit) = if not_found {
+                        ("NOT_FOUND", AppExit::NotFound)
+                    } else {
+                        ("RUNTIME_ERROR", AppExit::RuntimeError)
+                    };
+                    let msg = format!("failed to read artifact {}: {e}", path.display());
+                    if mode == OutputMode::Json {
+                        emit_json_error(code, &msg, &suggestions, repair_notes, exit as u8);
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("validate-artifact failed: {msg}"),
+                                "Artifact file could not be read.",
+                                &suggestions,
+                                &[path.display().to_string()],
+                            )
+                        );
+                    }
+                    return exit;
+                }
+            };
+
+            match validate_artifact(kind, &bytes) {
+                Ok(version) => {
+                    if mode == OutputMode::Json {
+                        emit_json_success(
+                            "OK",
+                            "Artifact conforms to its schema.",
+                            Some("validate-artifact"),
+                            AppExit::Success as u8,
+                            repair_notes,
+                            json!({
+                                "kind": kind_name,
+                                "path": path,
+                                "version": version,
+                            }),
+                        );
+                    } else {
+                        say!("Artifact validate: PASS");
+                        say!("  Kind:    {kind_name}");
+                        say!("  Version: {version}");
+                    }
+                    return AppExit::Success;
+                }
+                Err(issue) => {
+                    let msg = format!(
+                        "{} does not conform to {kind_name}: {}: {}",
+                        path.display(),
+                        issue.field,
+                        issue.message
+                    );
+                    if mode == OutputMode::Json {
+                        let mut response = json!({
+                            "schema_version": ROBOT_SCHEMA_VERSION,
+                            "ok": false,
+                            "code": "SCHEMA_INVALID",
+                            "message": msg,
+                            "suggestions": suggestions,
+                            "exit_code": AppExit::RuntimeError as u8,
+                            "command": "validate-artifact",
+                            "data": {
+                                "kind": kind_name,
+                                "path": path,
+                                "field": issue.field,
+                                "detail": issue.message,
+                            }
+                        });
+                        if !repair_notes.is_empty() {
+                            response["notes"] = json!(repair_notes);
+                        }
+                        emit_json(response);
+                    } else {
+                        eprintln!(
+                            "{}",
+                            format_cli_failure(
+                                &format!("validate-artifact failed: {msg}"),
+                                "Artifact is malformed or was written by an incompatible version.",
+                                &suggestions,
+                                &[format!("field: {}", issue.field)],
+                            )
+                        );
+                    }
+                    return AppExit::RuntimeError;
+                }
+            }
+        }
+    }
+
+    AppExit::Success
+}
+
+enum AnnotateError {
+    /// `--at` names no event in the log.
+    TargetMissing(String),
+    Io(String),
+}
+
+/// Fixtures of `format` directly inside `dir`, sorted by path.
+fn discover_tour_fixtures(dir: &Path, format: FixtureFormat) -> std::io::Result<Vec<PathBuf>> {
+    let extension = match format {
+        FixtureFormat::Cassette => "jsonl",
+        FixtureFormat::Otel => "json",
+    };
+    let mut fixtures = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == extension) {
+            fixtures.push(path);
+        }
+    }
+    fixtures.sort();
+    Ok(fixtures)
+}
+
+fn run_redact_command(
+    input: &Path,
+    output: &Path,
+    patterns: Vec<String>,
+    mode: OutputMode,
+    repair_notes: &[String],
+) -> AppExit {
+    let fail = |code: &str, msg: &str, cause: &str, suggestions: &[String], exit: AppExit| {
+        if mode == OutputMode::Json {
+            emit_json_error(code, msg, suggestions, repair_notes, exit as u8);
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("redact failed: {msg}"),
+                    cause,
+                    suggestions,
+                    &[input.display().to_string()],
+                )
+            );
+        }
+        exit
+    };
+
+    if let Err(msg) = ensure_file_exists(input, "eventlog file") {
+        return fail(
+            "NOT_FOUND",
+            &msg,
+            "Input path does not exist.",
+            &[format!(
+                "Check that `{}` exists and is readable.",
+                input.display()
+            )],
+            AppExit::NotFound,
+        );
+    }
+
+    let config = RedactConfig::new(input, output).with_patterns(patterns);
+    let summary = match vifei_export::redact::run_redact(&config) {
+        Ok(summary) => summary,
+        Err(e) if e.kind() == std::io::ErrorKind::InvalidInput => {
+            return fail(
+                "INVALID_ARGS",
+                &e.to_string(),
+                "Redact writes a new EventLog and only knows the secret scanner's patterns.",
+                &[format!(
+                    "vifei redact {} <new-output.jsonl>",
+                    input.display()
+                )],
+                AppExit::InvalidArgs,
+            );
+        }
+        Err(e) => {
+            return fail(
+                "RUNTIME_ERROR",
+                &e.to_string(),
+                "EventLog could not be read, redacted, or written.",
+                &[format!("vifei verify-eventlog {}", input.display())],
+                AppExit::RuntimeError,
+            );
+        }
+    };
+
+    if mode == OutputMode::Json {
+        let redactions: Vec<_> = summary
+            .redactions
+            .iter()
+            .map(|r| {
+                json!({
+                    "target_event_id": r.target_event_id,
+                    "field_path": r.field_path,
+                    "pattern": r.pattern,
+                    "matches": r.matches,
+                })
+            })
+            .collect();
+        emit_json_success(
+            "OK",
+            "Redacted EventLog written.",
+            Some("redact"),
+            AppExit::Success as u8,
+            repair_notes,
+            json!({
+                "input_path": input,
+                "output_path": summary.output_path,
+                "events_copied": summary.events_copied,
+                "blobs_redacted": summary.blobs_redacted,
+                "redaction_count": redactions.len(),
+                "redactions": redactions,
+            }),
+        );
+    } else {
+        say!("Redacted EventLog written: {}", output.display());
+        say!("  Events copied: {}", summary.events_copied);
+        say!(
+            "  Redactions:    {} (one RedactionApplied event each)",
+            summary.redactions.len()
+        );
+        for r in &summary.redactions {
+            say!(
+                "    - {} {}: {} x{}",
+                r.target_event_id,
+                r.field_path,
+                r.pattern,
+                r.matches
+            );
+        }
+        if summary.blobs_redacted > 0 {
+            say!("  Blobs redacted: {}", summary.blobs_redacted);
+        }
+        say!(
+            "Next: vifei export {} --share-safe --output bundle.tar.zst",
+            output.display()
+        );
+    }
+    AppExit::Success
+}
+
+/// `vifei gc`: list the blobs no event of `eventlog` references, deleting
+/// them with `delete` once the EventLog passes [`validate_eventlog`].
+fn run_gc_command(
+    eventlog: &Path,
+    delete: bool,
+    mode: OutputMode,
+    repair_notes: &[String],
+) -> AppExit {
+    if let Err(msg) = ensure_file_exists(eventlog, "eventlog file") {
+        let suggestions = vec![format!(
+            "Check that `{}` exists and is readable.",
+            eventlog.display()
+        )];
+        if mode == OutputMode::Json {
+            emit_json_error(
+                "NOT_FOUND",
+                &msg,
+                &suggestions,
+                repair_notes,
+                AppExit::NotFound as u8,
+            );
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("gc failed: {msg}"),
+                    "Input path does not exist.",
+                    &suggestions,
+                    &[eventlog.display().to_string()],
+                )
+            );
+        }
+        return AppExit::NotFound;
+    }
+
+    let verify_suggestion = format!("vifei verify-eventlog {}", eventlog.display());
+    let issues = match validate_eventlog(eventlog) {
+        Ok(validation) => validation.issues.len(),
+        Err(e) => {
+            let msg = format!("failed to read eventlog {}: {e}", eventlog.display());
+            let suggestions = vec![verify_suggestion];
+            if mode == OutputMode::Json {
+                emit_json_error(
+                    "RUNTIME_ERROR",
+                    &msg,
+                    &suggestions,
+                    repair_notes,
+                    AppExit::RuntimeError as u8,
+                );
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("gc failed: {msg}"),
+                        "EventLog could not be read.",
+                        &suggestions,
+                        &[eventlog.display().to_string()],
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+    };
+    // A reference on an unreadable or missing line would be deleted.
+    if delete && issues > 0 {
+        let msg = format!("refusing to delete blobs: EventLog has {issues} integrity issue(s)");
+        let suggestions = vec![
+            verify_suggestion,
+            format!("vifei gc {}", eventlog.display()),
+        ];
+        if mode == OutputMode::Json {
+            emit_json_error(
+                "INTEGRITY_FAILED",
+                &msg,
+                &suggestions,
+                repair_notes,
+                AppExit::RuntimeError as u8,
+            );
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("gc failed: {msg}"),
+                    "Blobs referenced by unreadable events cannot be told apart from garbage.",
+                    &suggestions,
+                    &[eventlog.display().to_string()],
+                )
+            );
+        }
+        return AppExit::RuntimeError;
+    }
+
+    let blobs_dir = sibling_blobs_dir(eventlog);
+    let report = match sweep_blobs(eventlog, &blobs_dir, !delete) {
+        Ok(report) => report,
+        Err(msg) => {
+            let suggestions = vec![format!(
+                "Check that `{}` is a valid EventLog and `{}` is writable.",
+                eventlog.display(),
+                blobs_dir.display()
+            )];
+            if mode == OutputMode::Json {
+                emit_json_error(
+                    "RUNTIME_ERROR",
+                    &msg,
+                    &suggestions,
+                    repair_notes,
+                    AppExit::RuntimeError as u8,
+                );
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("gc failed: {msg}"),
+                        "EventLog could not be read or blob store sweep failed.",
+                        &suggestions,
+                        &[
+                            eventlog.display().to_string(),
+                            blobs_dir.display().to_string()
+                        ],
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+    };
+
+    if mode == OutputMode::Json {
+        emit_json_success(
+            "OK",
+            if delete {
+                "Unreferenced blobs deleted."
+            } else {
+                "Unreferenced blobs listed (dry run)."
+            },
+            Some("gc"),
+            AppExit::Success as u8,
+            repair_notes,
+            json!({
+                "eventlog_path": eventlog,
+                "blobs_dir": blobs_dir,
+                "dry_run": report.dry_run,
+                "integrity_issues": issues,
+                "scanned": report.scanned,
+                "kept": report.kept,
+                "unreferenced": report.unreferenced,
+                "removed": report.removed,
+                "skipped_in_flight": report.skipped_in_flight,
+                "freed_bytes": report.freed_bytes,
+            }),
+        );
+    } else {
+        if report.dry_run {
+            say!("GC dry run: nothing deleted (pass --delete to remove).");
+        } else {
+            say!("GC completed.");
+        }
+        say!("  Blobs dir:    {}", blobs_dir.display());
+        say!("  Scanned:      {}", report.scanned);
+        say!("  Kept:         {}", report.kept);
+        say!("  Unreferenced: {}", report.removed);
+        say!("  Bytes:        {}", report.freed_bytes);
+        for payload_ref in &report.unreferenced {
+            say!("  - {payload_ref}");
+        }
+    }
+    AppExit::Success
+}
+
+/// `vifei verify-blobs`: re-hash every blob in the store at `dir`,
+/// failing with `INTEGRITY_FAILED` on any whose bytes do not match their
+/// `payload_ref`.
+fn run_verify_blobs_command(dir: &Path, mode: OutputMode, repair_notes: &[String]) -> AppExit {
+    if !dir.is_dir() {
+        let msg = format!("blob store directory not found: {}", dir.display());
+        let suggestions = vec![format!(
+            "Check that `{}` is a blob store directory, e.g. the `blobs/` beside an EventLog.",
+            dir.display()
+        )];
+        if mode == OutputMode::Json {
+            emit_json_error(
+                "NOT_FOUND",
+                &msg,
+                &suggestions,
+                repair_notes,
+                AppExit::NotFound as u8,
+            );
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("verify-blobs failed: {msg}"),
+                    "Input path is not a directory.",
+                    &suggestions,
+                    &[dir.display().to_string()],
+                )
+            );
+        }
+        return AppExit::NotFound;
+    }
+
+    let report = match BlobStore::open(dir).and_then(|store| store.verify_all()) {
+        Ok(report) => report,
+        Err(e) => {
+            let msg = format!("blob verify failed in {}: {e}", dir.display());
+            let suggestions = vec![format!("Check that `{}` is readable.", dir.display())];
+            if mode == OutputMode::Json {
+                emit_json_error(
+                    "RUNTIME_ERROR",
+                    &msg,
+                    &suggestions,
+                    repair_notes,
+                    AppExit::RuntimeError as u8,
+                );
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("verify-blobs failed: {msg}"),
+                        "Blob store scan failed.",
+                        &suggestions,
+                        &[dir.display().to_string()],
+                    )
+                );
+            }
+            return AppExit::RuntimeError;
+        }
+    };
+
+    let failures: Vec<Value> = report
+        .failures
+        .iter()
+        .map(|error| {
+            let actual_ref = match error {
+                BlobIntegrityError::HashMismatch { actual_ref, .. } => Some(actual_ref),
+                _ => None,
+            };
+            json!({
+                "kind": error.kind(),
+                "payload_ref": error.payload_ref(),
+                "actual_ref": actual_ref,
+                "message": error.to_string(),
+            })
+        })
+        .collect();
+    let data = json!({
+        "blobs_dir": dir,
+        "checked": report.checked,
+        "failure_count": report.failures.len(),
+        "failures": failures,
+    });
+    if report.is_clean() {
+        if mode == OutputMode::Json {
+            emit_json_success(
+                "OK",
+                "Blob store integrity verified.",
+                Some("verify-blobs"),
+                AppExit::Success as u8,
+                repair_notes,
+                data,
+            );
+        } else {
+            say!("Blob verify: PASS");
+            say!("  Blobs dir: {}", dir.display());
+            say!("  Checked:   {}", report.checked);
+        }
+        return AppExit::Success;
+    }
+
+    let suggestions = vec![
+        "Restore corrupted blobs from a trusted copy or bundle.".to_string(),
+        format!("vifei verify-blobs {}", dir.display()),
+    ];
+    if mode == OutputMode::Json {
+        let mut response = json!({
+            "schema_version": ROBOT_SCHEMA_VERSION,
+            "ok": false,
+            "code": "INTEGRITY_FAILED",
+            "message": format!(
+                "Detected {} blob integrity failure(s).",
+                report.failures.len()
+            ),
+            "suggestions": suggestions,
+            "exit_code": AppExit::RuntimeError as u8,
+            "command": "verify-blobs",
+            "data": data,
+        });
+        if !repair_notes.is_empty() {
+            response["notes"] = json!(repair_notes);
+        }
+        emit_json(response);
+    } else {
+        let evidence: Vec<String> = report.failures.iter().map(ToString::to_string).collect();
+        eprintln!(
+            "{}",
+            format_cli_failure(
+                &format!(
+                    "verify-blobs failed: {} of {} blob(s) failed integrity checks.",
+                    report.failures.len(),
+                    report.checked
+                ),
+                "Blob bytes no longer match their content hash.",
+                &suggestions,
+                &evidence,
+            )
+        );
+    }
+    AppExit::RuntimeError
+}
+
+/// One stored blob as listed by `vifei blob-ls`.
+struct ListedBlob {
+    payload_ref: String,
+    size_bytes: u64,
+    referenced: bool,
+}
+
+/// Blobs in `blobs_dir`, sorted by ref, marked against the refs `eventlog`
+/// uses. A missing store lists nothing.
+fn list_blobs(eventlog: &Path, blobs_dir: &Path) -> Result<Vec<ListedBlob>, String> {
+    let referenced = referenced_blob_refs(eventlog)?;
+    if !blobs_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let listed = (|| {
+        let store = BlobStore::open(blobs_dir)?;
+        let mut listed = Vec::new();
+        for payload_ref in store.iter_refs()? {
+            // Removed concurrently; not worth failing the listing.
+            let Some(size_bytes) = store.stored_size(&payload_ref)? else {
+                continue;
+            };
+            listed.push(ListedBlob {
+                referenced: referenced.contains(&payload_ref),
+                payload_ref,
+                size_bytes,
+            });
+        }
+        Ok::<_, std::io::Error>(listed)
+    })();
+    listed.map_err(|e| format!("blob listing failed in {}: {e}", blobs_dir.display()))
+}
+
+/// `vifei blob-ls`: list the blob store beside an EventLog, flagging blobs
+/// no event references.
+fn run_blob_ls_command(eventlog: &Path, mode: OutputMode, repair_notes: &[String]) -> AppExit {
+    let blobs_dir = sibling_blobs_dir(eventlog);
+    let fail = |code: &str, msg: &str, cause: &str, suggestions: &[String], exit: AppExit| {
+        if mode == OutputMode::Json {
+            emit_json_error(code, msg, suggestions, repair_notes, exit as u8);
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("blob-ls failed: {msg}"),
+                    cause,
+                    suggestions,
+                    &[
+                        eventlog.display().to_string(),
+                        blobs_dir.display().to_string()
+                    ],
+                )
+            );
+        }
+        exit
+    };
+
+    if let Err(msg) = ensure_file_exists(eventlog, "eventlog file") {
+        return fail(
+            "NOT_FOUND",
+            &msg,
+            "Input path does not exist.",
+            &[format!(
+                "Check that `{}` exists and is readable.",
+                eventlog.display()
+            )],
+            AppExit::NotFound,
+        );
+    }
+
+    let blobs = match list_blobs(eventlog, &blobs_dir) {
+        Ok(blobs) => blobs,
+        Err(msg) => {
+            return fail(
+                "RUNTIME_ERROR",
+                &msg,
+                "EventLog could not be read or blob store scan failed.",
+                &[format!(
+                    "Check that `{}` is a valid EventLog and `{}` is readable.",
+                    eventlog.display(),
+                    blobs_dir.display()
+                )],
+                AppExit::RuntimeError,
+            );
+        }
+    };
+    let orphan_count = blobs.iter().filter(|blob| !blob.referenced).count();
+    let total_bytes: u64 = blobs.iter().map(|blob| blob.size_bytes).sum();
+
+    if mode == OutputMode::Json {
+        let listed: Vec<Value> = blobs
+            .iter()
+            .map(|blob| {
+                json!({
+                    "payload_ref": blob.payload_ref,
+                    "size_bytes": blob.size_bytes,
+                    "referenced": blob.referenced,
+                })
+            })
+            .collect();
+        emit_json_success(
+            "OK",
+            "Blob store listed.",
+            Some("blob-ls"),
+            AppExit::Success as u8,
+            repair_notes,
+            json!({
+                "eventlog_path": eventlog,
+                "blobs_dir": blobs_dir,
+                "blob_count": blobs.len(),
+                "orphan_count": orphan_count,
+                "total_bytes": total_bytes,
+                "blobs": listed,
+            }),
+        );
+    } else {
+        say!("Blobs: {}", blobs_dir.display());
+        for blob in &blobs {
+            say!(
+                "  {} {:>12} {}",
+                blob.payload_ref,
+                blob.size_bytes,
+                if blob.referenced {
+                    "referenced"
+                } else {
+                    "orphan"
+                }
+            );
+        }
+        say!(
+            "  {} blob(s), {} orphaned, {} bytes",
+            blobs.len(),
+            orphan_count,
+            total_bytes
+        );
+        if orphan_count > 0 {
+            say!("Next: vifei gc {}", eventlog.display());
+        }
+    }
+    AppExit::Success
+}
+
+/// `vifei compare --timetravel`: pair the seek points of two Tour
+/// `timetravel.capture` files by `commit_index` and report where their
+/// hashes first diverge.
+fn run_timetravel_compare_command(
+    left: &Path,
+    right: &Path,
+    mode: OutputMode,
+    repair_notes: &[String],
+) -> AppExit {
+    let fail = |code: &str, msg: &str, cause: &str, suggestions: &[String], exit: AppExit| {
+        if mode == OutputMode::Json {
+            emit_json_error(code, msg, suggestions, repair_notes, exit as u8);
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("compare failed: {msg}"),
+                    cause,
+                    suggestions,
+                    &[left.display().to_string(), right.display().to_string()],
+                )
+            );
+        }
+        exit
+    };
+    let suggestions = [format!(
+        "Check that `{}` and `{}` are Tour timetravel.capture files.",
+        left.display(),
+        right.display()
+    )];
+
+    let present = ensure_file_exists(left, "left capture")
+        .and_then(|()| ensure_file_exists(right, "right capture"));
+    if let Err(msg) = present {
+        return fail(
+            "NOT_FOUND",
+            &msg,
+            "Input path does not exist.",
+            &suggestions,
+            AppExit::NotFound,
+        );
+    }
+    let captures =
+        read_timetravel_capture(left).and_then(|l| read_timetravel_capture(right).map(|r| (l, r)));
+    let comparison = match captures {
+        Ok((left_capture, right_capture)) => compare_timetravel(&left_capture, &right_capture),
+        Err(e) => {
+            return fail(
+                "RUNTIME_ERROR",
+                &e.to_string(),
+                "A capture could not be read or parsed.",
+                &suggestions,
+                AppExit::RuntimeError,
+            );
+        }
+    };
+    if comparison.aligned_seek_points == 0 {
+        return fail(
+            "RUNTIME_ERROR",
+            "the captures share no seek point commit_index",
+            "Nothing to compare.",
+            &suggestions,
+            AppExit::RuntimeError,
+        );
+    }
+
+    let mut notes = repair_notes.to_vec();
+    if comparison.intersection_only {
+        notes.push(format!(
+            "Seek intervals differ; compared the {} commit_index value(s) both captures share.",
+            comparison.aligned_seek_points
+        ));
+    }
+    if !comparison.versions_match() {
+        notes.push(format!(
+            "Captures were written under {} and {}; viewmodel_hash values are expected to differ.",
+            comparison.left_projection_invariants_version,
+            comparison.right_projection_invariants_version
+        ));
+    }
+    let data = json!({
+        "left_path": left,
+        "right_path": right,
+        "first_divergence": comparison.first_divergence(),
+        "comparison": comparison,
+    });
+
+    if comparison.is_identical() {
+        if mode == OutputMode::Json {
+            emit_json_success(
+                "OK",
+                "Aligned seek points agree.",
+                Some("compare"),
+                AppExit::Success as u8,
+                &notes,
+                data,
+            );
+        } else {
+            say!("Time-travel compare: no divergence.");
+            say_timetravel_comparison(&comparison, &notes);
+        }
+        return AppExit::Success;
+    }
+
+    let first = comparison.first_divergence().unwrap_or_default();
+    if mode == OutputMode::Json {
+        let mut response = json!({
+            "schema_version": ROBOT_SCHEMA_VERSION,
+            "ok": false,
+            "code": "DIFF_FOUND",
+            "message": format!("Captures first diverge at commit_index {first}."),
+            "suggestions": [format!(
+                "vifei compare <left.eventlog> <right.eventlog> --state-diff to inspect commit_index {first}"
+            )],
+            "exit_code": AppExit::DiffFound as u8,
+            "command": "compare",
+            "data": data,
+        });
+        if !notes.is_empty() {
+            response["notes"] = json!(notes);
+        }
+        emit_json(response);
+    } else {
+        say!("Time-travel compare: divergence at commit_index {first}.");
+        say_timetravel_comparison(&comparison, &notes);
+    }
+    AppExit::DiffFound
+}
+
+fn say_timetravel_comparison(comparison: &TimeTravelComparison, notes: &[String]) {
+    say!(
+        "  Seek points: {} aligned ({} left, {} right)",
+        comparison.aligned_seek_points,
+        comparison.left_seek_points,
+        comparison.right_seek_points
+    );
+    if let Some(agreed) = comparison.last_agreement {
+        say!("  Last agreement: commit_index {agreed}");
+    }
+    for (field, divergence) in [
+        ("state_hash", &comparison.first_state_divergence),
+        ("viewmodel_hash", &comparison.first_viewmodel_divergence),
+    ] {
+        if let Some(divergence) = divergence {
+            say!(
+                "  First {field} divergence: commit_index {} ({} vs {})",
+                divergence.commit_index,
+                divergence.left,
+                divergence.right
+            );
+        }
+    }
+    for note in notes {
+        say!("  Note: {note}");
+    }
+}
+
+/// `vifei stats`: replay an EventLog and report the counts the Incident
+/// Lens summarizes, with the state and viewmodel hashes.
+fn run_stats_command(eventlog: &Path, mode: OutputMode, repair_notes: &[String]) -> AppExit {
+    let fail = |code: &str, msg: &str, cause: &str, suggestions: &[String], exit: AppExit| {
+        if mode == OutputMode::Json {
+            emit_json_error(code, msg, suggestions, repair_notes, exit as u8);
+        } else {
+            eprintln!(
+                "{}",
+                format_cli_failure(
+                    &format!("stats failed: {msg}"),
+                    cause,
+                    suggestions,
+                    &[eventlog.display().to_string()],
+                )
+            );
+        }
+        exit
+    };
+
+    if let Err(msg) = ensure_file_exists(eventlog, "eventlog file") {
+        return fail(
+            "NOT_FOUND",
+            &msg,
+            "Input path does not exist.",
+            &[format!(
+                "Check that `{}` exists and is readable.",
+                eventlog.display()
+            )],
+            AppExit::NotFound,
+        );
+    }
+
+    let events = match read_eventlog(eventlog) {
+        Ok(events) => events,
+        Err(e) => {
+            return fail(
+                "RUNTIME_ERROR",
+                &format!("failed to read eventlog {}: {e}", eventlog.display()),
+                "EventLog could not be read.",
+                &[format!("vifei verify-eventlog {}", eventlog.display())],
+                AppExit::RuntimeError,
+            );
+        }
+    };
+    let (state, _) = replay(&events);
+    let vm = project(&state, &ProjectionInvariants::new());
+    let tool_calls: u64 = state.tool_summaries.values().map(|t| t.call_count).sum();
+    let tool_errors: u64 = state.tool_summaries.values().map(|t| t.error_count).sum();
+    let state_hash_hex = state_hash(&state);
+    let vm_hash_hex = viewmodel_hash(&vm);
+
+    if mode == OutputMode::Json {
+        emit_json_success(
+            "OK",
+            "EventLog replayed.",
+            Some("stats"),
+            AppExit::Success as u8,
+            repair_notes,
+            json!({
+                "eventlog_path": eventlog,
+                "event_count_total": events.len(),
+                "event_counts_by_type": state.event_counts_by_type,
+                "event_counts_by_tier": state.event_counts_by_tier,
+                "run_count": state.run_metadata.len(),
+                "tool_call_total": tool_calls,
+                "tool_error_total": tool_errors,
+                "tier_a_drops": state.tier_a_drops,
+                "clock_skew_count": state.clock_skew_events.len(),
+                "state_hash": state_hash_hex,
+                "viewmodel_hash": vm_hash_hex,
+                "projection_invariants_version": vm.projection_invariants_version,
+            }),
+        );
+    } else {
+        let join = |counts: Vec<String>| {
+            if counts.is_empty() {
+                "-".to_string()
+            } else {
+                counts.join(", ")
+            }
+        };
+        say!("Stats: {}", eventlog.display());
+        say!("  Events:         {}", events.len());
+        say!(
+            "  By type:        {}",
+            join(
+                state
+                    .event_counts_by_type
+                    .iter()
+                    .map(|(kind, n)| format!("{kind}={n}"))
+                    .collect()
+            )
+        );
+        say!(
+            "  By tier:        {}",
+            join(
+                state
+                    .event_counts_by_tier
+                    .iter()
+                    .map(|(tier, n)| format!("{tier}={n}"))
+                    .collect()
+            )
+        );
+        say!("  Runs:           {}", state.run_metadata.len());
+        say!("  Tool calls:     {tool_calls} ({tool_errors} errors)");
+        say!("  Tier A drops:   {}", state.tier_a_drops);
+        say!("  Clock skew:     {}", state.clock_skew_events.len());
+        say!("  state_hash:     {state_hash_hex}");
+        say!("  viewmodel_hash: {vm_hash_hex}");
+    }
+    AppExit::Success
+}
+
+/// `vifei tour --batch`: Tour every fixture in `batch_dir` in parallel,
+/// each into `<output_dir>/<fixture stem>`, and report every run.
+fn run_tour_batch_command(
+    batch_dir: &Path,
+    output_dir: &Path,
+    mapping: Mapping,
+    format: FixtureFormat,
+    mode: OutputMode,
+    repair_notes: &[String],
+) -> AppExit {
+    let fixtures = match discover_tour_fixtures(batch_dir, format) {
+        Ok(fixtures) if !fixtures.is_empty() => fixtures,
+        Ok(_) | Err(_) => {
+            let msg = format!("no tour fixtures found in {}", batch_dir.display());
+            let suggestions = vec![
+                "Cassette batches tour `*.jsonl` files; `--format otel` tours `*.json` files."
+                    .to_string(),
+                format!(
+                    "vifei tour --batch {} --stress --format otel",
+                    batch_dir.display()
+                ),
+            ];
+            if mode == OutputMode::Json {
+                emit_json_error(
+                    "NOT_FOUND",
+                    &msg,
+                    &suggestions,
+                    repair_notes,
+                    AppExit::NotFound as u8,
+                );
+            } else {
+                eprintln!(
+                    "{}",
+                    format_cli_failure(
+                        &format!("tour failed: {msg}"),
+                        "The batch directory holds no fixtures of the selected format.",
+                        &suggestions,
+                        &[batch_dir.display().to_string()],
+                    )
+                );
+            }
+            return AppExit::NotFound;
+        }
+    };
+    let configs: Vec<TourConfig> = fixtures
+        .iter()
+        .map(|fixture| {
+            let stem = fixture.file_stem().unwrap_or_default();
+            TourConfig::new(fixture)
+                .with_output_dir(output_dir.join(stem))
+                .with_mapping(mapping.clone())
+                .with_format(format)
+        })
+        .collect();
+    let results = vifei_tour::run_tour_batch(&configs);
+
+    let runs: Vec<Value> = configs
+        .iter()
+        .zip(&results)
+        .map(|(config, result)| match result {
+            Ok(result) => json!({
+                "fixture": config.fixture_path,
+                "output_dir": result.output_dir,
+                "ok": true,
+                "event_count": result.metrics.event_count_total,
+                "tier_a_drops": result.metrics.tier_a_drops,
+                "degradation_level": result.metrics.degradation_level_final,
+                "viewmodel_hash": result.viewmodel_hash,
+            }),
+            Err(e) => json!({
+                "fixture": config.fixture_path,
+                "output_dir": config.output_dir,
+                "ok": false,
+                "error": e.to_string(),
+            }),
+        })
+        .collect();
+    let failed: Vec<&TourConfig> = configs
+        .iter()
+        .zip(&results)
+        .filter(|(_, result)| result.is_err())
+        .map(|(config, _)| config)
+        .collect();
+    let data = json!({
+        "output_dir": output_dir,
+        "fixture_count": configs.len(),
+        "succeeded": configs.len() - failed.len(),
+        "failed": failed.len(),
+        "runs": runs,
+    });
+
+    if failed.is_empty() {
+        if mode == OutputMode::Json {
+            emit_json_success(
+                "OK",
+                "Tour batch completed successfully.",
+                Some("tour"),
+                AppExit::Success as u8,
+                repair_notes,
+                data,
+            );
+        } else {
+            say!("Tour batch completed successfully!");
+            say!("  Fixtures: {}", configs.len());
+            for (config, result) in configs.iter().zip(&results) {
+                if let Ok(result) = result {
+                    say!(
+                        "  - {} -> {} ({} events, hash {})",
+                        config.fixture_path.display(),
+                        result.output_dir.display(),
+                        result.metrics.event_count_total,
+                        result.viewmodel_hash
+                    );
+                }
+            }
+        }
+        return AppExit::Success;
+    }
+
+    let msg = format!(
+        "{} of {} tour fixture(s) failed.",
+        failed.len(),
+        configs.len()
+    );
+    let suggestions: Vec<String> = failed
+        .iter()
+        .map(|config| {
+            format!(
+                "vifei tour {} --stress --output-dir {}",
+                config.fixture_path.display(),
+                config.output_dir.display()
+            )
+        })
+        .collect();
+    if mode == OutputMode::Json {
+        let mut response = json!({
+            "schema_version": ROBOT_SCHEMA_VERSION,
+            "ok": false,
+            "code": "RUNTIME_ERROR",
+            "message": msg,
+            "suggestions": suggestions,
+            "exit_code": AppExit::RuntimeError as u8,
+            "command": "tour",
+            "data": data,
+        });
+        if !repair_notes.is_empty() {
+            response["notes"] = json!(repair_notes);
+        }
+        emit_json(response);
+    } else {
+        let evidence: Vec<String> = configs
+            .iter()
+            .zip(&results)
+            .filter_map(|(config, result)| {
+                result
+                    .as_ref()
+                    .err()
+                    .map(|e| format!("{}: {e}", config.fixture_path.display()))
+            })
+            .collect();
+        eprintln!(
+            "{}",
+            format_cli_failure(
+                &format!("tour failed: {msg}"),
+                "Each fixture runs independently; the other fixtures' artifacts were written.",
+                &suggestions,
+                &evidence,
+            )
+        );
+    }
+    AppExit::RuntimeError
+}
+
+/// Append a synthesized Tier A `Note` about the event at `target`.
+///
+/// The note takes the target's `run_id` and the log's latest timestamp, so
+/// appending it never trips clock skew detection.
+fn annotate_eventlog(
+    eventlog: &Path,
+    target: u64,
+    author: String,
+    text: String,
+) -> Result<CommittedEvent, AnnotateError> {
+    let events = read_eventlog(eventlog)
+        .map_err(|e| AnnotateError::Io(format!("failed to read {}: {e}", eventlog.display())))?;
+    let run_id = events
+        .iter()
+        .find(|e| e.commit_index == target)
+        .map(|e| e.run_id.clone())
+        .ok_or_else(|| {
+            AnnotateError::TargetMissing(format!(
+                "no event with commit_index {target} in {}",
+                eventlog.display()
+            ))
+        })?;
+    let timestamp_ns = events.iter().map(|e| e.timestamp_ns).max().unwrap_or(0);
+    let prior_notes = events
+        .iter()
+        .filter(|e| {
+            matches!(
+                e.payload,
+                EventPayload::Note { target_commit_index, .. } if target_commit_index == target
+            )
+        })
+        .count();
+
+    let note = ImportEvent {
+        run_id,
+        event_id: format!("note:{target}:{prior_notes}"),
+        source_id: "vifei-annotate".to_string(),
+        source_seq: None,
+        timestamp_ns,
+        tier: Tier::A,
+        payload: EventPayload::Note {
+            target_commit_index: target,
+            author,
+            text,
+        },
+        payload_ref: None,
+        synthesized: true,
+    };
+    let mut writer = EventLogWriter::open(eventlog).map_err(|e| {
+        AnnotateError::Io(format!(
+            "failed to open {} for append: {e}",
+            eventlog.display()
+        ))
+    })?;
+    let result = writer.append(note).map_err(|e| {
+        AnnotateError::Io(format!("failed to append to {}: {e}", eventlog.display()))
+    })?;
+    Ok(result.committed_event().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_incident_html, share_safe_input_label};
+    use std::path::Path;
+    use vifei_core::delta::{
+        ChangeClass, DeltaSummary, DiffAlignment, Divergence, DivergenceSeverity, RunDelta,
+    };
+
+    #[test]
+    fn share_safe_input_label_uses_file_name_when_present() {
+        let path = Path::new("/tmp/demo/left.jsonl");
+        assert_eq!(share_safe_input_label(path), "left.jsonl");
+    }
+
+    #[test]
+    fn share_safe_input_label_falls_back_for_root_like_paths() {
+        let path = Path::new("/");
+        assert_eq!(share_safe_input_label(path), "input");
+    }
+
+    fn sample_delta() -> RunDelta {
+        let divergences = vec![
+            Divergence {
+                commit_index: 3,
+                path: "$.payload.result".to_string(),
+                change_class: ChangeClass::ValueMismatch,
+                severity: DivergenceSeverity::Critical,
+                left_value: Some("\"ok\"".to_string()),
+                right_value: Some("\"<b>different</b>\"".to_string()),
+            },
+            Divergence {
+                commit_index: 8,
+                path: "$event".to_string(),
+                change_class: ChangeClass::EventMissingLeft,
+                severity: DivergenceSeverity::Minor,
+                left_value: None,
+                right_value: Some("present".to_string()),
+            },
+        ];
+        RunDelta {
+            left_run_id: "run-left".to_string(),
+            right_run_id: "run-right".to_string(),
+            left_event_count: 8,
+            right_event_count: 9,
+            alignment: DiffAlignment::Positional,
+            ignore_paths: Vec::new(),
+            summary: DeltaSummary::of(&divergences),
+            divergences,
+            rendered: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn incident_html_contains_count_and_divergence_rows() {
+        let delta = sample_delta();
+        let html = render_incident_html(&delta, "aaaa", "bbbb");
+
+        assert!(html.contains("<th>Divergences</th><td>2</td>"));
+        assert!(html.contains("<th>Critical divergences</th><td>1</td>"));
+        for divergence in &delta.divergences {
+            assert!(
+                html.contains(&format!("<tr><td>{}</td>", divergence.commit_index)),
+                "missing row for commit_index {}",
+                divergence.commit_index
+            );
+        }
+        assert!(html.contains("<td>ValueMismatch</td><td>critical</td>"));
+        assert!(html.contains("<td>EventMissingLeft</td><td>minor</td>"));
+        assert!(html.contains("aaaa"));
+        assert!(html.contains("bbbb"));
+        assert!(html.contains("&lt;b&gt;different&lt;/b&gt;"));
+        assert!(!html.contains("<b>different</b>"));
+        assert!(!html.contains("http://") && !html.contains("https://"));
+    }
+
+    #[test]
+    fn incident_html_is_deterministic() {
+        let delta = sample_delta();
+        assert_eq!(
+            render_incident_html(&delta, "aaaa", "bbbb"),
+            render_incident_html(&delta, "aaaa", "bbbb")
+        );
     }
 }
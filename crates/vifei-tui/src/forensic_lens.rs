@@ -5,7 +5,8 @@
 //! # Layout
 //!
 //! - Left: Timeline scrubber — navigate events by commit_index
-//! - Right: Event inspector — full details for the selected event
+//! - Right: Event inspector — full details for the selected event, including
+//!   any `source_seq` gap the event closes
 //!
 //! # Constitution
 //!
@@ -21,6 +22,8 @@ use ratatui::{
     Frame,
 };
 use vifei_core::event::{CommittedEvent, EventPayload};
+use vifei_core::reducer::{RedactionEntry, State};
+use vifei_core::time::{format_ms_utc, format_ns_utc};
 
 /// Forensic Lens navigation and display state.
 #[derive(Debug, Default)]
@@ -60,15 +63,17 @@ pub fn render_forensic_lens(
     frame: &mut Frame,
     area: Rect,
     events: &[CommittedEvent],
+    state: &State,
     forensic: &ForensicState,
 ) {
-    render_forensic_lens_with_profile(frame, area, events, forensic, UiProfile::Standard);
+    render_forensic_lens_with_profile(frame, area, events, state, forensic, UiProfile::Standard);
 }
 
 pub fn render_forensic_lens_with_profile(
     frame: &mut Frame,
     area: Rect,
     events: &[CommittedEvent],
+    state: &State,
     forensic: &ForensicState,
     profile: UiProfile,
 ) {
@@ -109,8 +114,8 @@ pub fn render_forensic_lens_with_profile(
             .split(inner)
     };
 
-    render_timeline(frame, columns[0], events, forensic, profile);
-    render_inspector(frame, columns[1], events, forensic, profile);
+    render_timeline(frame, columns[0], events, state, forensic, profile);
+    render_inspector(frame, columns[1], events, state, forensic, profile);
 }
 
 /// Render the timeline scrubber (left pane).
@@ -118,6 +123,7 @@ fn render_timeline(
     frame: &mut Frame,
     area: Rect,
     events: &[CommittedEvent],
+    state: &State,
     forensic: &ForensicState,
     profile: UiProfile,
 ) {
@@ -164,6 +170,11 @@ fn render_timeline(
             ),
             Span::styled(synth_marker, visual_tone::accent_for(profile)),
             Span::styled(type_name, Style::default().fg(type_color)),
+            if state.redacted_event_ids.contains_key(&ev.event_id) {
+                Span::styled(" [REDACTED]", visual_tone::warning_for(profile))
+            } else {
+                Span::raw("")
+            },
         ]));
     }
 
@@ -197,6 +208,7 @@ fn render_inspector(
     frame: &mut Frame,
     area: Rect,
     events: &[CommittedEvent],
+    state: &State,
     forensic: &ForensicState,
     profile: UiProfile,
 ) {
@@ -220,6 +232,7 @@ fn render_inspector(
     }
 
     let ev = &events[forensic.cursor];
+    let redactions: Vec<&RedactionEntry> = state.redactions_of(&ev.event_id).collect();
     let mut lines = Vec::new();
 
     // Header: commit_index and event type
@@ -239,6 +252,11 @@ fn render_inspector(
         } else {
             Span::raw("")
         },
+        if redactions.is_empty() {
+            Span::raw("")
+        } else {
+            Span::styled("  [REDACTED]", visual_tone::warning_for(profile))
+        },
     ]));
     lines.push(Line::from(""));
 
@@ -255,6 +273,10 @@ fn render_inspector(
         Span::styled("  tier:     ", visual_tone::muted_for(profile)),
         Span::raw(format!("{}", ev.tier)),
     ]));
+    lines.push(Line::from(vec![
+        Span::styled("  time:     ", visual_tone::muted_for(profile)),
+        Span::raw(timestamp_text(ev.timestamp_ns, forensic.expanded)),
+    ]));
 
     if let Some(ref pr) = ev.payload_ref {
         lines.push(Line::from(vec![
@@ -262,22 +284,58 @@ fn render_inspector(
             Span::styled(pr, Style::default().fg(Color::Blue)),
         ]));
     }
+    // The first event after a skip carries the gap it closes.
+    if let (Some(seq), Some(gaps)) = (ev.source_seq, state.source_gaps.get(&ev.source_id)) {
+        for (expected, actual) in gaps.iter().filter(|(_, actual)| *actual == seq) {
+            lines.push(Line::from(vec![
+                Span::styled("  gap:      ", visual_tone::muted_for(profile)),
+                Span::styled(
+                    format!(
+                        "expected {} seq {expected}, got {actual} ({} missing)",
+                        ev.source_id,
+                        actual - expected
+                    ),
+                    visual_tone::warning_for(profile),
+                ),
+            ]));
+        }
+    }
+    for redaction in &redactions {
+        lines.push(Line::from(vec![
+            Span::styled("  redacted: ", visual_tone::muted_for(profile)),
+            Span::styled(
+                format!("{} by #{}", redaction.field_path, redaction.commit_index),
+                visual_tone::warning_for(profile),
+            ),
+        ]));
+    }
     lines.push(Line::from(""));
 
     // Payload details (always shown when selected; expanded shows more)
-    render_payload_details(&mut lines, &ev.payload, forensic.expanded);
+    render_payload_details(&mut lines, &ev.payload, &redactions, forensic.expanded);
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
     frame.render_widget(paragraph, inner);
 }
 
 /// Render payload-specific details into the lines buffer.
+///
+/// Free-text fields covered by one of `redactions` show `[REDACTED]`
+/// instead of their value.
 fn render_payload_details<'a>(
     lines: &mut Vec<Line<'a>>,
     payload: &'a EventPayload,
+    redactions: &[&RedactionEntry],
     expanded: bool,
 ) {
     let label_style = visual_tone::muted();
+    let shown = |field_path: &str, value: &str| {
+        if redactions.iter().any(|r| r.covers(field_path)) {
+            "[REDACTED]".to_string()
+        } else {
+            truncate_or_full(value, expanded)
+        }
+    };
 
     match payload {
         EventPayload::RunStart { agent, args } => {
@@ -288,7 +346,7 @@ fn render_payload_details<'a>(
             if let Some(a) = args {
                 lines.push(Line::from(vec![
                     Span::styled("  args:  ", label_style),
-                    Span::raw(truncate_or_full(a, expanded)),
+                    Span::raw(shown("payload.args", a)),
                 ]));
             }
         }
@@ -308,20 +366,30 @@ fn render_payload_details<'a>(
             if let Some(r) = reason {
                 lines.push(Line::from(vec![
                     Span::styled("  reason:    ", label_style),
-                    Span::raw(truncate_or_full(r, expanded)),
+                    Span::raw(shown("payload.reason", r)),
                 ]));
             }
         }
 
-        EventPayload::ToolCall { tool, args } => {
+        EventPayload::ToolCall {
+            tool,
+            args,
+            invocation_id,
+        } => {
             lines.push(Line::from(vec![
                 Span::styled("  tool: ", label_style),
                 Span::styled(tool, visual_tone::info()),
             ]));
+            if let Some(id) = invocation_id {
+                lines.push(Line::from(vec![
+                    Span::styled("  id:   ", label_style),
+                    Span::raw(id),
+                ]));
+            }
             if let Some(a) = args {
                 lines.push(Line::from(vec![
                     Span::styled("  args: ", label_style),
-                    Span::raw(truncate_or_full(a, expanded)),
+                    Span::raw(shown("payload.args", a)),
                 ]));
             }
         }
@@ -330,11 +398,18 @@ fn render_payload_details<'a>(
             tool,
             result,
             status,
+            invocation_id,
         } => {
             lines.push(Line::from(vec![
                 Span::styled("  tool:   ", label_style),
                 Span::styled(tool, visual_tone::info()),
             ]));
+            if let Some(id) = invocation_id {
+                lines.push(Line::from(vec![
+                    Span::styled("  id:     ", label_style),
+                    Span::raw(id),
+                ]));
+            }
             if let Some(s) = status {
                 let status_style = if s == "success" || s == "ok" {
                     visual_tone::success()
@@ -349,7 +424,7 @@ fn render_payload_details<'a>(
             if let Some(r) = result {
                 lines.push(Line::from(vec![
                     Span::styled("  result: ", label_style),
-                    Span::raw(truncate_or_full(r, expanded)),
+                    Span::raw(shown("payload.result", r)),
                 ]));
             }
         }
@@ -406,7 +481,7 @@ fn render_payload_details<'a>(
             ]));
             lines.push(Line::from(vec![
                 Span::styled("  message:  ", label_style),
-                Span::styled(truncate_or_full(message, expanded), visual_tone::error()),
+                Span::styled(shown("payload.message", message), visual_tone::error()),
             ]));
             if let Some(s) = severity {
                 lines.push(Line::from(vec![
@@ -423,11 +498,11 @@ fn render_payload_details<'a>(
         } => {
             lines.push(Line::from(vec![
                 Span::styled("  expected: ", label_style),
-                Span::raw(format!("{}ns", expected_ns)),
+                Span::raw(timestamp_text(*expected_ns, expanded)),
             ]));
             lines.push(Line::from(vec![
                 Span::styled("  actual:   ", label_style),
-                Span::raw(format!("{}ns", actual_ns)),
+                Span::raw(timestamp_text(*actual_ns, expanded)),
             ]));
             lines.push(Line::from(vec![
                 Span::styled("  delta:    ", label_style),
@@ -438,6 +513,59 @@ fn render_payload_details<'a>(
             ]));
         }
 
+        EventPayload::Note {
+            target_commit_index,
+            author,
+            text,
+        } => {
+            lines.push(Line::from(vec![
+                Span::styled("  target: ", label_style),
+                Span::raw(format!("#{}", target_commit_index)),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  author: ", label_style),
+                Span::styled(author, visual_tone::info()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  text:   ", label_style),
+                Span::raw(shown("payload.text", text)),
+            ]));
+        }
+
+        EventPayload::TokenUsage {
+            model,
+            input_tokens,
+            output_tokens,
+        } => {
+            lines.push(Line::from(vec![
+                Span::styled("  model:  ", label_style),
+                Span::styled(model, visual_tone::info()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  tokens: ", label_style),
+                Span::raw(format!("{input_tokens} in / {output_tokens} out")),
+            ]));
+        }
+
+        EventPayload::SubagentSpawned {
+            parent_run_id,
+            child_run_id,
+            agent,
+        } => {
+            lines.push(Line::from(vec![
+                Span::styled("  agent:  ", label_style),
+                Span::styled(agent, visual_tone::info()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  parent: ", label_style),
+                Span::raw(parent_run_id.as_str()),
+            ]));
+            lines.push(Line::from(vec![
+                Span::styled("  child:  ", label_style),
+                Span::raw(child_run_id.as_str()),
+            ]));
+        }
+
         EventPayload::Generic { event_type, data } => {
             lines.push(Line::from(vec![
                 Span::styled("  type: ", label_style),
@@ -447,7 +575,7 @@ fn render_payload_details<'a>(
                 for (k, v) in data {
                     lines.push(Line::from(vec![
                         Span::styled(format!("  {}: ", k), label_style),
-                        Span::raw(v.as_str()),
+                        Span::raw(shown(&format!("payload.data.{k}"), v)),
                     ]));
                 }
             } else if !data.is_empty() {
@@ -481,6 +609,16 @@ fn event_type_color(type_name: &str) -> Color {
     }
 }
 
+/// UTC rendering of `timestamp_ns`: to the millisecond, or to the
+/// nanosecond followed by the raw integer when expanded.
+fn timestamp_text(timestamp_ns: u64, expanded: bool) -> String {
+    if expanded {
+        format!("{} ({timestamp_ns}ns)", format_ns_utc(timestamp_ns))
+    } else {
+        format_ms_utc(timestamp_ns)
+    }
+}
+
 /// Truncate text unless expanded. Uses char boundaries to avoid UTF-8 panics.
 fn truncate_or_full(s: &str, expanded: bool) -> String {
     if expanded || s.len() <= 60 {
@@ -553,6 +691,8 @@ mod tests {
             payload,
             payload_ref: None,
             synthesized,
+            prev_hash: None,
+            schema_version: 1,
         }
     }
 
@@ -571,6 +711,7 @@ mod tests {
                 EventPayload::ToolCall {
                     tool: "read_file".into(),
                     args: Some("/etc/config".into()),
+                    invocation_id: None,
                 },
                 false,
             ),
@@ -580,6 +721,7 @@ mod tests {
                     tool: "read_file".into(),
                     result: Some("contents here".into()),
                     status: Some("success".into()),
+                    invocation_id: None,
                 },
                 false,
             ),
@@ -613,7 +755,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -625,6 +767,42 @@ mod tests {
         assert!(text.contains("Next:"), "Missing next-action hint");
     }
 
+    #[test]
+    fn inspector_renders_timestamp_as_utc_with_raw_ns_on_expand() {
+        let mut event = test_event(
+            0,
+            EventPayload::RunStart {
+                agent: "test".into(),
+                args: None,
+            },
+            false,
+        );
+        event.timestamp_ns = 1_709_210_096_789_012_345;
+        let events = vec![event];
+        let render = |forensic: &ForensicState| {
+            let mut terminal = Terminal::new(TestBackend::new(140, 20)).unwrap();
+            terminal
+                .draw(|frame| {
+                    let area = Rect::new(0, 0, 140, 20);
+                    render_forensic_lens(frame, area, &events, &State::new(), forensic);
+                })
+                .unwrap();
+            buffer_text(&terminal, Rect::new(0, 0, 140, 20))
+        };
+
+        let collapsed = render(&ForensicState::new());
+        assert!(collapsed.contains("time:     2024-02-29T12:34:56.789Z"));
+        assert!(!collapsed.contains("1709210096789012345"));
+
+        let expanded = render(&ForensicState {
+            cursor: 0,
+            expanded: true,
+        });
+        assert!(
+            expanded.contains("time:     2024-02-29T12:34:56.789012345Z (1709210096789012345ns)")
+        );
+    }
+
     #[test]
     fn forensic_lens_hint_changes_with_expand_state() {
         let backend = TestBackend::new(120, 30);
@@ -635,7 +813,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &collapsed);
+                render_forensic_lens(frame, area, &events, &State::new(), &collapsed);
             })
             .unwrap();
         let collapsed_text = buffer_text(&terminal, Rect::new(0, 0, 120, 30));
@@ -651,7 +829,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &expanded);
+                render_forensic_lens(frame, area, &events, &State::new(), &expanded);
             })
             .unwrap();
         let expanded_text = buffer_text(&terminal, Rect::new(0, 0, 120, 30));
@@ -671,7 +849,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -695,7 +873,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -757,7 +935,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -774,6 +952,7 @@ mod tests {
             EventPayload::ToolCall {
                 tool: "write_file".into(),
                 args: None,
+                invocation_id: None,
             },
             false,
         );
@@ -784,7 +963,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -812,7 +991,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -841,7 +1020,7 @@ mod tests {
         terminal
             .draw(|frame| {
                 let area = Rect::new(0, 0, 120, 30);
-                render_forensic_lens(frame, area, &events, &state);
+                render_forensic_lens(frame, area, &events, &State::new(), &state);
             })
             .unwrap();
 
@@ -849,6 +1028,75 @@ mod tests {
         assert!(text.contains("500ms"), "Missing delta display");
     }
 
+    #[test]
+    fn forensic_lens_shows_gap_on_first_event_after_it() {
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut events = sample_events();
+        for (ev, seq) in events.iter_mut().zip([0, 1, 5, 6, 7]) {
+            ev.source_seq = Some(seq);
+        }
+        let (reduced, _) = vifei_core::reducer::replay(&events);
+        let render = |terminal: &mut Terminal<TestBackend>, cursor: usize| {
+            let forensic = ForensicState {
+                cursor,
+                expanded: false,
+            };
+            terminal
+                .draw(|frame| {
+                    let area = Rect::new(0, 0, 120, 30);
+                    render_forensic_lens(frame, area, &events, &reduced, &forensic);
+                })
+                .unwrap();
+            buffer_text(terminal, Rect::new(0, 0, 120, 30))
+        };
+
+        let text = render(&mut terminal, 2);
+        assert!(
+            text.contains("expected test seq 2, got 5 (3 missing)"),
+            "Missing gap detail"
+        );
+        let text = render(&mut terminal, 3);
+        assert!(!text.contains("gap:"), "Only the first event after a gap");
+    }
+
+    #[test]
+    fn forensic_lens_badges_redacted_event_and_hides_field() {
+        let backend = TestBackend::new(120, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut events = sample_events();
+        events.push(test_event(
+            5,
+            EventPayload::RedactionApplied {
+                target_event_id: "e-1".into(),
+                field_path: "payload.args".into(),
+                reason: "secret".into(),
+            },
+            false,
+        ));
+        let (reduced, _) = vifei_core::reducer::replay(&events);
+        let forensic = ForensicState {
+            cursor: 1,
+            expanded: false,
+        };
+
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 120, 30);
+                render_forensic_lens(frame, area, &events, &reduced, &forensic);
+            })
+            .unwrap();
+
+        let text = buffer_text(&terminal, Rect::new(0, 0, 120, 30));
+        assert_eq!(text.matches("[REDACTED]").count(), 3, "{text}");
+        assert!(text.contains("redacted: payload.args by #5"));
+        assert!(
+            !text.contains("/etc/config"),
+            "Redacted args must not render"
+        );
+        assert!(text.contains("read_file"), "Unredacted fields still render");
+    }
+
     #[test]
     fn visible_window_basic() {
         assert_eq!(visible_window(0, 10, 7), (0, 5));
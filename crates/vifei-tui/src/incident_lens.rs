@@ -4,14 +4,18 @@
 //!
 //! # Layout
 //!
-//! - Top: Action Now (anomalies needing triage first)
+//! - Top: Action Now (top anomalies, most severe first; see
+//!   [`crate::anomaly_rank`])
+//! - Clock Integrity (only when clock skew was detected)
 //! - Middle: Run context (which runs, status, event count)
+//! - Tool latency (only when tool calls were paired with their results)
 //! - Bottom: Event breakdown (counts by type)
 //!
 //! # Constitution
 //!
 //! See `PLANS.md` § D5: "Correctness target: Deep investigation. Entry behavior: Incident triage."
 
+use crate::anomaly_rank::{rank_anomalies, Anomaly, AnomalyKind, TOP_ANOMALIES};
 use crate::{visual_tone, UiProfile};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -20,7 +24,23 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
     Frame,
 };
-use vifei_core::reducer::State;
+use vifei_core::eventlog::EventLogIntegrityReport;
+use vifei_core::reducer::{RunInfo, State, ToolSummary, TRUNCATED_RUN_REASON};
+
+/// Number of events loaded into the viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EventCount {
+    /// Events loaded and reduced.
+    pub total: usize,
+    /// True when `--limit` stopped loading before the end of the EventLog.
+    pub truncated: bool,
+    /// Inclusive `commit_index` bounds when only a window was loaded.
+    pub window: Option<(u64, u64)>,
+    /// Length of a truncated final record dropped from the EventLog.
+    pub truncated_tail_bytes: Option<u64>,
+    /// `commit_index` problems in the loaded events, if any.
+    pub integrity_issues: Option<EventLogIntegrityReport>,
+}
 
 /// Render the Incident Lens into the given area.
 ///
@@ -40,7 +60,13 @@ pub fn render_incident_lens(
         area,
         state,
         eventlog_path,
-        total_events,
+        EventCount {
+            total: total_events,
+            truncated: false,
+            window: None,
+            truncated_tail_bytes: None,
+            integrity_issues: None,
+        },
         show_onboarding,
         UiProfile::Standard,
     );
@@ -51,7 +77,7 @@ pub fn render_incident_lens_with_profile(
     area: Rect,
     state: &State,
     eventlog_path: &str,
-    total_events: usize,
+    events: EventCount,
     show_onboarding: bool,
     profile: UiProfile,
 ) {
@@ -76,43 +102,39 @@ pub fn render_incident_lens_with_profile(
             .constraints([
                 Constraint::Length(3),
                 Constraint::Length(anomalies_height(state, inner.width)),
+                Constraint::Length(clock_integrity_height(state)),
                 Constraint::Length(run_summary_height(state)),
+                Constraint::Length(tool_latency_height(state)),
                 Constraint::Length(event_breakdown_height(state)),
             ])
             .split(inner);
 
         render_onboarding_strip(frame, sections[0], profile);
         render_anomalies(frame, sections[1], state, profile);
-        render_run_summary(
-            frame,
-            sections[2],
-            state,
-            eventlog_path,
-            total_events,
-            profile,
-        );
-        render_event_breakdown(frame, sections[3], state, profile);
+        render_clock_integrity(frame, sections[2], state, profile);
+        render_run_summary(frame, sections[3], state, eventlog_path, events, profile);
+        render_tool_latency(frame, sections[4], state, profile);
+        render_event_breakdown(frame, sections[5], state, profile);
     } else {
-        // Split inner area into three sections: anomalies, run summary, event breakdown
+        // Split inner area into sections: anomalies, clock integrity (empty
+        // for clean logs), run summary, tool latency (empty without paired
+        // calls), event breakdown
         let sections = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Length(anomalies_height(state, inner.width)),
+                Constraint::Length(clock_integrity_height(state)),
                 Constraint::Length(run_summary_height(state)),
+                Constraint::Length(tool_latency_height(state)),
                 Constraint::Length(event_breakdown_height(state)),
             ])
             .split(inner);
 
         render_anomalies(frame, sections[0], state, profile);
-        render_run_summary(
-            frame,
-            sections[1],
-            state,
-            eventlog_path,
-            total_events,
-            profile,
-        );
-        render_event_breakdown(frame, sections[2], state, profile);
+        render_clock_integrity(frame, sections[1], state, profile);
+        render_run_summary(frame, sections[2], state, eventlog_path, events, profile);
+        render_tool_latency(frame, sections[3], state, profile);
+        render_event_breakdown(frame, sections[4], state, profile);
     }
 }
 
@@ -134,9 +156,28 @@ fn render_onboarding_strip(frame: &mut Frame, area: Rect, profile: UiProfile) {
 
 /// Height needed for the run summary section.
 fn run_summary_height(state: &State) -> u16 {
-    // Header + one line per run + 1 blank line, minimum 3
+    // Header + one line per run + one per token model + 1 blank line, minimum 3
     let runs = state.run_metadata.len() as u16;
-    (2 + runs).max(3)
+    let models = state.token_usage.len() as u16;
+    (2 + runs + models).max(3)
+}
+
+/// Tools with paired latencies or unmatched results, in name order.
+fn latency_tools(state: &State) -> impl Iterator<Item = (&String, &ToolSummary)> {
+    state
+        .tool_summaries
+        .iter()
+        .filter(|(_, summary)| !summary.latency.is_empty() || summary.unmatched_results > 0)
+}
+
+/// Height needed for the tool latency section (zero when no tool has
+/// paired calls).
+fn tool_latency_height(state: &State) -> u16 {
+    match latency_tools(state).count() as u16 {
+        0 => 0,
+        // Header + one line per tool + blank
+        tools => 2 + tools,
+    }
 }
 
 /// Height needed for the event breakdown section.
@@ -148,15 +189,26 @@ fn event_breakdown_height(state: &State) -> u16 {
 
 /// Height needed for anomalies section.
 fn anomalies_height(state: &State, width: u16) -> u16 {
-    let count =
-        state.error_log.len() + state.clock_skew_events.len() + state.policy_decisions.len();
-    let anomaly_lines = (count as u16).max(1);
+    let count = rank_anomalies(state).len();
+    // Top K, plus one "+N more" line when truncated.
+    let shown = count.min(TOP_ANOMALIES) + usize::from(count > TOP_ANOMALIES);
+    let anomaly_lines = (shown as u16).max(1);
     let hint = next_action_line(count > 0, width);
     let hint_lines = wrapped_line_count(&hint, width);
     // Header + priority + anomalies + blank + next-action hint (possibly wrapped)
     (3 + anomaly_lines + 1 + hint_lines).max(6)
 }
 
+/// Height needed for the clock integrity panel (zero when no skew).
+fn clock_integrity_height(state: &State) -> u16 {
+    if state.clock_skew_events.is_empty() {
+        0
+    } else {
+        // Header + summary + blank
+        3
+    }
+}
+
 fn wrapped_line_count(text: &str, width: u16) -> u16 {
     let safe_width = width.max(1) as usize;
     let chars = text.chars().count();
@@ -180,23 +232,101 @@ fn next_action_line(has_anomalies: bool, width: u16) -> String {
     }
 }
 
+/// Render the clock integrity panel: skew count and largest backward delta.
+fn render_clock_integrity(frame: &mut Frame, area: Rect, state: &State, profile: UiProfile) {
+    let Some(worst) = state
+        .clock_skew_events
+        .iter()
+        .max_by_key(|skew| skew.delta_ns)
+    else {
+        return;
+    };
+    let lines = vec![
+        Line::from(Span::styled("Clock Integrity", visual_tone::header())),
+        Line::from(vec![
+            Span::raw("  "),
+            Span::styled(
+                format!(
+                    "WARN {} backward timestamp(s); largest {}ms",
+                    state.clock_skew_events.len(),
+                    worst.delta_ns / 1_000_000
+                ),
+                visual_tone::warning_for(profile),
+            ),
+            Span::styled(
+                format!(" (delta_ns={} @{})", worst.delta_ns, worst.commit_index),
+                visual_tone::muted_for(profile),
+            ),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
 /// Render the run summary section.
 fn render_run_summary(
     frame: &mut Frame,
     area: Rect,
     state: &State,
     eventlog_path: &str,
-    total_events: usize,
+    events: EventCount,
     profile: UiProfile,
 ) {
-    let mut lines = vec![Line::from(vec![
+    let mut header = vec![
         Span::styled("Run Context", visual_tone::header()),
         Span::raw("  "),
         Span::styled(
-            format!("{} ({} events)", eventlog_path, total_events),
+            format!("{} ({} events)", eventlog_path, events.total),
             visual_tone::muted_for(profile),
         ),
-    ])];
+    ];
+    if events.truncated {
+        header.push(Span::raw("  "));
+        header.push(Span::styled(
+            format!("TRUNCATED: first {} shown (--limit)", events.total),
+            visual_tone::warning_for(profile),
+        ));
+    }
+    if let Some((from, to)) = events.window {
+        let bounds = if to == u64::MAX {
+            format!("{from}..")
+        } else {
+            format!("{from}..={to}")
+        };
+        header.push(Span::raw("  "));
+        header.push(Span::styled(
+            format!("WINDOW: commits {bounds}"),
+            visual_tone::warning_for(profile),
+        ));
+    }
+    let mut lines = vec![Line::from(header)];
+    if let Some(bytes) = events.truncated_tail_bytes {
+        lines.push(Line::from(Span::styled(
+            format!("  log ends with a truncated record ({bytes} bytes not shown)"),
+            visual_tone::error(),
+        )));
+    }
+    if let Some(report) = events.integrity_issues {
+        if !report.is_clean() {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  commit_index integrity: {} gaps, {} duplicates, {} out of order (run `vifei verify-eventlog`)",
+                    report.gaps, report.duplicates, report.non_monotonic
+                ),
+                visual_tone::warning_for(profile),
+            )));
+        }
+        if report.unknown_payloads > 0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  {} events of unknown type (newer schema) shown as Generic",
+                    report.unknown_payloads
+                ),
+                visual_tone::warning_for(profile),
+            )));
+        }
+    }
 
     if state.run_metadata.is_empty() {
         lines.push(Line::from(Span::styled(
@@ -209,10 +339,13 @@ fn render_run_summary(
                 match info.exit_code {
                     Some(0) => Span::styled("OK", visual_tone::success()),
                     Some(code) => Span::styled(format!("exit {}", code), visual_tone::error()),
+                    None if info.reason.as_deref() == Some(TRUNCATED_RUN_REASON) => {
+                        Span::styled("truncated", visual_tone::error())
+                    }
                     None => Span::styled("ended", visual_tone::warning()),
                 }
             } else {
-                Span::styled("running", visual_tone::warning())
+                Span::styled("incomplete: no RunEnd", visual_tone::error())
             };
 
             lines.push(Line::from(vec![
@@ -222,14 +355,93 @@ fn render_run_summary(
                 Span::raw(" ["),
                 status_span,
                 Span::raw(format!("] {} events", info.event_count)),
+                Span::styled(run_duration(info), visual_tone::muted_for(profile)),
             ]));
         }
     }
+    for (model, totals) in &state.token_usage {
+        lines.push(Line::from(vec![
+            Span::raw("  tokens "),
+            Span::styled(model, visual_tone::info_for(profile)),
+            Span::styled(
+                format!(
+                    ": {} in / {} out ({} total)",
+                    totals.input_tokens,
+                    totals.output_tokens,
+                    totals.total()
+                ),
+                visual_tone::muted_for(profile),
+            ),
+        ]));
+    }
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
     frame.render_widget(paragraph, area);
 }
 
+/// Render call-to-result latency per tool, from calls paired with their
+/// results by `invocation_id`.
+fn render_tool_latency(frame: &mut Frame, area: Rect, state: &State, profile: UiProfile) {
+    if area.height == 0 {
+        return;
+    }
+    let mut lines = vec![Line::from(Span::styled(
+        "Tool Latency (paired calls)",
+        visual_tone::header(),
+    ))];
+    for (tool, summary) in latency_tools(state) {
+        let latency = &summary.latency;
+        let mut spans = vec![
+            Span::raw("  "),
+            Span::styled(format!("{tool:<16}"), visual_tone::info_for(profile)),
+            Span::raw(format!("{:>6} paired", latency.count())),
+        ];
+        if let Some(typical) = latency.percentile_ns(50) {
+            spans.push(Span::styled(
+                format!(
+                    "  typical <={}  max {}",
+                    format_latency(typical),
+                    format_latency(latency.max_ns)
+                ),
+                visual_tone::muted_for(profile),
+            ));
+        }
+        if summary.unmatched_results > 0 {
+            spans.push(Span::styled(
+                format!("  {} unmatched results", summary.unmatched_results),
+                visual_tone::warning_for(profile),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+/// `, <duration>` from a run's earliest to latest event, or nothing when
+/// the run has no timestamps.
+fn run_duration(info: &RunInfo) -> String {
+    match (info.first_timestamp_ns, info.last_timestamp_ns) {
+        (Some(first), Some(last)) => format!(", {}", format_latency(last.saturating_sub(first))),
+        _ => String::new(),
+    }
+}
+
+/// Nanoseconds in the largest whole unit up to seconds (`850ms`, `1.2s`).
+fn format_latency(ns: u64) -> String {
+    match ns {
+        0..=999 => format!("{ns}ns"),
+        1_000..=999_999 => format!("{}us", ns / 1_000),
+        1_000_000..=999_999_999 => format!("{}ms", ns / 1_000_000),
+        _ => format!(
+            "{}.{}s",
+            ns / 1_000_000_000,
+            ns % 1_000_000_000 / 100_000_000
+        ),
+    }
+}
+
 /// Render the event type breakdown section.
 fn render_event_breakdown(frame: &mut Frame, area: Rect, state: &State, profile: UiProfile) {
     let mut lines = vec![Line::from(Span::styled(
@@ -263,16 +475,15 @@ fn render_event_breakdown(frame: &mut Frame, area: Rect, state: &State, profile:
     frame.render_widget(paragraph, area);
 }
 
-/// Render the anomalies section (errors, clock skew, policy decisions).
+/// Render the anomalies section: the top ranked anomalies, most severe first.
 fn render_anomalies(frame: &mut Frame, area: Rect, state: &State, profile: UiProfile) {
     let mut lines = vec![Line::from(Span::styled(
         "Action Now (Anomalies)",
         visual_tone::header(),
     ))];
 
-    let has_anomalies = !state.error_log.is_empty()
-        || !state.clock_skew_events.is_empty()
-        || !state.policy_decisions.is_empty();
+    let ranked = rank_anomalies(state);
+    let has_anomalies = !ranked.is_empty();
 
     lines.push(Line::from(vec![
         Span::styled("Priority:", Style::default().add_modifier(Modifier::BOLD)),
@@ -290,36 +501,14 @@ fn render_anomalies(frame: &mut Frame, area: Rect, state: &State, profile: UiPro
             visual_tone::success(),
         )));
     } else {
-        // Errors
-        for err in &state.error_log {
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled("ERR ", visual_tone::error()),
-                Span::raw(format!("@{}: ", err.commit_index)),
-                Span::styled(&err.message, visual_tone::error()),
-            ]));
+        for anomaly in ranked.iter().take(TOP_ANOMALIES) {
+            lines.push(anomaly_line(anomaly, profile));
         }
-
-        // Clock skew
-        for skew in &state.clock_skew_events {
-            let delta_ms = skew.delta_ns / 1_000_000;
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled("SKEW", visual_tone::warning()),
-                Span::raw(format!(" @{}: {}ms backward", skew.commit_index, delta_ms)),
-            ]));
-        }
-
-        // Policy decisions
-        for pd in &state.policy_decisions {
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled("POLICY", visual_tone::accent_for(profile)),
-                Span::raw(format!(
-                    " @{}: {} → {} ({})",
-                    pd.commit_index, pd.from_level, pd.to_level, pd.trigger
-                )),
-            ]));
+        if ranked.len() > TOP_ANOMALIES {
+            lines.push(Line::from(Span::styled(
+                format!("  +{} more (Tab to Forensic)", ranked.len() - TOP_ANOMALIES),
+                visual_tone::muted_for(profile),
+            )));
         }
     }
 
@@ -335,11 +524,66 @@ fn render_anomalies(frame: &mut Frame, area: Rect, state: &State, profile: UiPro
     frame.render_widget(paragraph, area);
 }
 
+/// Render one ranked anomaly as an indented line.
+fn anomaly_line(anomaly: &Anomaly, profile: UiProfile) -> Line<'_> {
+    let at = anomaly
+        .commit_index
+        .map(|i| format!("@{i}"))
+        .unwrap_or_default();
+    match &anomaly.kind {
+        AnomalyKind::TierADrop { drops } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("DROP", visual_tone::error()),
+            Span::styled(
+                format!(" {drops} Tier A event(s) dropped"),
+                visual_tone::error(),
+            ),
+        ]),
+        AnomalyKind::Error { message } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("ERR ", visual_tone::error()),
+            Span::raw(format!("{at}: ")),
+            Span::styled(message.as_str(), visual_tone::error()),
+        ]),
+        AnomalyKind::ToolErrorRate {
+            tool,
+            error_count,
+            result_count,
+        } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("TOOL", visual_tone::warning()),
+            Span::raw(format!(
+                " {tool}: {error_count}/{result_count} results failed ({}%)",
+                anomaly.score / 10_000
+            )),
+        ]),
+        AnomalyKind::OrphanedToolCall { invocation_id, .. } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("TOOL", visual_tone::warning()),
+            Span::raw(format!(" call {invocation_id} never returned")),
+        ]),
+        AnomalyKind::ClockSkew { delta_ns } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("SKEW", visual_tone::warning()),
+            Span::raw(format!(" {at}: {}ms backward", delta_ns / 1_000_000)),
+        ]),
+        AnomalyKind::Policy {
+            from_level,
+            to_level,
+            trigger,
+        } => Line::from(vec![
+            Span::raw("  "),
+            Span::styled("POLICY", visual_tone::accent_for(profile)),
+            Span::raw(format!(" {at}: {from_level} → {to_level} ({trigger})")),
+        ]),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ratatui::{backend::TestBackend, Terminal};
-    use vifei_core::reducer::{ClockSkewEntry, ErrorEntry, PolicyTransition, RunInfo, State};
+    use vifei_core::reducer::{ClockSkewEntry, ErrorEntry, PolicyTransition};
 
     /// Extract the text content from a rendered buffer area.
     fn buffer_text(terminal: &Terminal<TestBackend>, area: Rect) -> String {
@@ -368,6 +612,8 @@ mod tests {
                 exit_code: Some(0),
                 reason: Some("completed".to_string()),
                 event_count: 10,
+                first_timestamp_ns: Some(1_000_000_000),
+                last_timestamp_ns: Some(3_500_000_000),
             },
         );
         state.event_counts_by_type.insert("RunStart".to_string(), 1);
@@ -401,7 +647,51 @@ mod tests {
         );
         assert!(text.contains("run-001"), "Missing run ID");
         assert!(text.contains("OK"), "Missing status for successful run");
-        assert!(text.contains("10 events"), "Missing event count per run");
+        assert!(
+            text.contains("10 events, 2.5s"),
+            "Missing run duration: {text}"
+        );
+    }
+
+    #[test]
+    fn incident_lens_shows_token_totals_per_model() {
+        let state = replay_cassette(
+            r#"{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:00Z","agent":"test-agent"}
+{"type":"token_usage","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","model":"model-large","input_tokens":1200,"output_tokens":345}
+{"type":"token_usage","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","model":"model-large","input_tokens":800,"output_tokens":55}
+{"type":"session_end","session_id":"s1","timestamp":"2026-02-16T10:00:03Z","exit_code":0}"#,
+        );
+        let text = render_to_text(&state);
+        assert!(
+            text.contains("tokens model-large: 2000 in / 400 out (2400 total)"),
+            "Missing token totals in run summary:\n{text}"
+        );
+    }
+
+    #[test]
+    fn incident_lens_shows_tool_latency_and_orphaned_calls() {
+        let state = replay_cassette(
+            r#"{"type":"session_start","session_id":"s1","timestamp":"2026-02-16T10:00:00Z","agent":"test-agent"}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:01Z","tool":"Read","id":"tu_1"}
+{"type":"tool_result","session_id":"s1","timestamp":"2026-02-16T10:00:01.700Z","tool":"Read","tool_use_id":"tu_1","status":"success"}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:02Z","tool":"Read","id":"tu_2"}
+{"type":"tool_result","session_id":"s1","timestamp":"2026-02-16T10:00:04.500Z","tool":"Read","tool_use_id":"tu_2","status":"success"}
+{"type":"tool_use","session_id":"s1","timestamp":"2026-02-16T10:00:05Z","tool":"Bash","id":"tu_3"}
+{"type":"session_end","session_id":"s1","timestamp":"2026-02-16T10:00:06Z","exit_code":0}"#,
+        );
+        let text = render_to_text(&state);
+        assert!(
+            text.contains("Tool Latency (paired calls)"),
+            "Missing tool latency section:\n{text}"
+        );
+        assert!(
+            text.contains("2 paired  typical <=1.0s  max 2.5s"),
+            "Missing Read latency line:\n{text}"
+        );
+        assert!(
+            text.contains("TOOL call tu_3 never returned"),
+            "Missing orphaned call anomaly:\n{text}"
+        );
     }
 
     #[test]
@@ -500,6 +790,59 @@ mod tests {
         assert!(text.contains("500ms"), "Missing delta for clock skew");
     }
 
+    /// Replay a cassette through the append writer into reducer State.
+    fn replay_cassette(cassette: &str) -> State {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("eventlog.jsonl");
+        let mut writer = vifei_core::eventlog::EventLogWriter::open(&path).unwrap();
+        for event in vifei_import::cassette::parse_cassette(cassette.as_bytes()) {
+            writer.append(event).unwrap();
+        }
+        drop(writer);
+        let events = vifei_core::eventlog::read_eventlog(&path).unwrap();
+        vifei_core::reducer::replay(&events).0
+    }
+
+    fn render_to_text(state: &State) -> String {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 30);
+                render_incident_lens(frame, area, state, "test.jsonl", 4, false);
+            })
+            .unwrap();
+        buffer_text(&terminal, Rect::new(0, 0, 100, 30))
+    }
+
+    #[test]
+    fn incident_lens_clock_integrity_panel_shows_largest_delta() {
+        let state = replay_cassette(
+            r#"{"type":"session_start","session_id":"run-1","timestamp":"2026-01-01T00:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"run-1","timestamp":"2026-01-01T00:00:03Z","tool":"Read","id":"t1","args":{}}
+{"type":"tool_result","session_id":"run-1","timestamp":"2026-01-01T00:00:01.750Z","tool":"Read","id":"t1","result":"ok"}
+{"type":"tool_use","session_id":"run-1","timestamp":"2026-01-01T00:00:04Z","tool":"Bash","id":"t2","args":{}}
+{"type":"tool_result","session_id":"run-1","timestamp":"2026-01-01T00:00:03.500Z","tool":"Bash","id":"t2","result":"ok"}
+{"type":"session_end","session_id":"run-1","timestamp":"2026-01-01T00:00:05Z"}"#,
+        );
+        assert_eq!(state.clock_skew_events.len(), 2);
+
+        let text = render_to_text(&state);
+        assert!(text.contains("Clock Integrity"), "Missing clock panel");
+        assert!(
+            text.contains("WARN 2 backward timestamp(s); largest 1250ms"),
+            "Missing skew count or largest delta"
+        );
+        assert!(text.contains("delta_ns=1250000000"), "Missing exact delta");
+    }
+
+    #[test]
+    fn incident_lens_clock_integrity_panel_absent_for_clean_log() {
+        let state = replay_cassette(include_str!("../../../fixtures/small-session.jsonl"));
+        assert!(state.clock_skew_events.is_empty());
+        assert!(!render_to_text(&state).contains("Clock Integrity"));
+    }
+
     #[test]
     fn incident_lens_renders_policy_decisions() {
         let backend = TestBackend::new(100, 30);
@@ -540,6 +883,8 @@ mod tests {
                 exit_code: Some(1),
                 reason: Some("error".to_string()),
                 event_count: 5,
+                first_timestamp_ns: None,
+                last_timestamp_ns: None,
             },
         );
 
@@ -555,7 +900,7 @@ mod tests {
     }
 
     #[test]
-    fn incident_lens_shows_running_run() {
+    fn incident_lens_flags_run_without_run_end_as_incomplete() {
         let backend = TestBackend::new(100, 30);
         let mut terminal = Terminal::new(backend).unwrap();
         let mut state = empty_state();
@@ -568,6 +913,8 @@ mod tests {
                 exit_code: None,
                 reason: None,
                 event_count: 3,
+                first_timestamp_ns: None,
+                last_timestamp_ns: None,
             },
         );
 
@@ -579,7 +926,40 @@ mod tests {
             .unwrap();
 
         let text = buffer_text(&terminal, Rect::new(0, 0, 100, 30));
-        assert!(text.contains("running"), "Missing 'running' status");
+        assert!(
+            text.contains("incomplete: no RunEnd"),
+            "Missing incomplete status"
+        );
+    }
+
+    #[test]
+    fn incident_lens_shows_truncated_run() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = empty_state();
+        state.run_metadata.insert(
+            "run-crashed".to_string(),
+            RunInfo {
+                agent: "crashed-agent".to_string(),
+                args: None,
+                ended: true,
+                exit_code: None,
+                reason: Some(TRUNCATED_RUN_REASON.to_string()),
+                event_count: 4,
+                first_timestamp_ns: None,
+                last_timestamp_ns: None,
+            },
+        );
+
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 30);
+                render_incident_lens(frame, area, &state, "test.jsonl", 4, false);
+            })
+            .unwrap();
+
+        let text = buffer_text(&terminal, Rect::new(0, 0, 100, 30));
+        assert!(text.contains("[truncated]"), "Missing truncated status");
     }
 
     #[test]
@@ -665,6 +1045,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn incident_lens_lists_top_anomaly_first() {
+        let backend = TestBackend::new(100, 30);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = empty_state();
+        for i in 0..6 {
+            state.clock_skew_events.push(ClockSkewEntry {
+                commit_index: i,
+                expected_ns: 2_000_000_000,
+                actual_ns: 1_900_000_000,
+                delta_ns: 100_000_000,
+            });
+        }
+        state.error_log.push(ErrorEntry {
+            commit_index: 30,
+            kind: "runtime".into(),
+            message: "disk full".into(),
+            severity: None,
+        });
+
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 30);
+                render_incident_lens(frame, area, &state, "test.jsonl", 40, false);
+            })
+            .unwrap();
+
+        let text = buffer_text(&terminal, Rect::new(0, 0, 100, 30));
+        let err_idx = text.find("ERR @30: disk full").expect("error listed");
+        let skew_idx = text.find("SKEW @0:").expect("first skew listed");
+        assert!(err_idx < skew_idx, "Error must rank above clock skew");
+        assert!(
+            !text.contains("SKEW @4:"),
+            "Only the top anomalies are listed"
+        );
+        assert!(text.contains("+2 more"), "Missing truncation line");
+    }
+
     #[test]
     fn incident_lens_orders_triage_before_context() {
         let backend = TestBackend::new(100, 30);
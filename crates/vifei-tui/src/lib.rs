@@ -28,6 +28,7 @@
 //! - **I2 (Deterministic projection):** ViewModel is deterministic.
 //! - Truth HUD is always visible and confesses system state.
 
+mod anomaly_rank;
 mod forensic_lens;
 mod incident_lens;
 mod truth_hud;
@@ -41,18 +42,29 @@ use crossterm::{
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout},
+    widgets::{Block, Borders, Paragraph},
     Frame, Terminal,
 };
-use std::io::{self, stdout};
+use std::fs::File;
+use std::io::{self, stdout, BufReader};
+use std::ops::ControlFlow;
 use std::path::Path;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use vifei_core::{
+    checkpoint_store::{checkpoint_dir, replay_with_checkpoints_progress, CheckpointStore},
     event::CommittedEvent,
-    eventlog::read_eventlog,
-    projection::{project, LadderLevel, ProjectionInvariants, ViewModel},
-    reducer::{reduce, State},
+    eventlog::{
+        commit_in_memory, read_eventlog_head, read_eventlog_range, read_eventlog_recovering,
+        EventLogIntegrityReport,
+    },
+    export_safety::load_export_safety,
+    projection::{project, ExportSafetyState, LadderLevel, ProjectionInvariants, ViewModel},
+    reducer::{replay_with_progress, ReplayProgress, State, CHECKPOINT_INTERVAL},
 };
 
+/// Minimum time between redraws of the loading screen while replaying.
+const LOADING_REDRAW: Duration = Duration::from_millis(100);
+
 /// Presentation profile for UI rendering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum UiProfile {
@@ -61,6 +73,62 @@ pub enum UiProfile {
     Showcase,
 }
 
+/// Input format of the file given to the viewer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewFormat {
+    /// Committed EventLog JSONL.
+    #[default]
+    Eventlog,
+    /// Agent cassette JSONL, committed in memory before viewing.
+    Cassette,
+}
+
+/// How [`run_viewer`] loads and presents its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ViewerOptions {
+    /// Input format of the file.
+    pub format: ViewFormat,
+    /// Presentation profile.
+    pub profile: UiProfile,
+    /// Inclusive `commit_index` bounds; only events inside are read and
+    /// reduced.
+    pub window: Option<(u64, u64)>,
+    /// Load at most this many events; the Incident Lens marks the view as
+    /// truncated.
+    pub limit: Option<usize>,
+}
+
+impl ViewerOptions {
+    /// Whole EventLog, standard profile.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the input format.
+    pub fn with_format(mut self, format: ViewFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Set the presentation profile.
+    pub fn with_profile(mut self, profile: UiProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Load only events with `commit_index` in `from..=to`.
+    pub fn with_window(mut self, window: Option<(u64, u64)>) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Load at most `limit` events.
+    pub fn with_limit(mut self, limit: Option<usize>) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
 /// Which lens is currently active.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 enum ActiveLens {
@@ -103,8 +171,16 @@ struct App {
     should_quit: bool,
     /// Path to the EventLog file.
     eventlog_path: String,
-    /// Total events in the EventLog.
+    /// Events loaded for display (all of them unless `--limit` truncated).
     total_events: usize,
+    /// Whether `--limit` stopped loading before the end of the EventLog.
+    truncated: bool,
+    /// Inclusive `commit_index` bounds loaded, if only a window was.
+    window: Option<(u64, u64)>,
+    /// Length of a truncated final record dropped while loading, if any.
+    truncated_tail_bytes: Option<u64>,
+    /// `commit_index` problems found by a full load, if any.
+    integrity_issues: Option<EventLogIntegrityReport>,
     /// Committed events for the Forensic Lens.
     events: Vec<CommittedEvent>,
     /// Forensic Lens navigation state.
@@ -118,17 +194,107 @@ struct App {
 impl App {
     /// Create a new App by loading an EventLog and reducing it.
     fn new(eventlog_path: &Path) -> io::Result<Self> {
-        let events = read_eventlog(eventlog_path)?;
-        let total_events = events.len();
+        Self::with_limit(eventlog_path, None)
+    }
+
+    /// Create an App from at most `limit` events of the EventLog.
+    ///
+    /// Display-only: the EventLog itself is never modified.
+    fn with_limit(eventlog_path: &Path, limit: Option<usize>) -> io::Result<Self> {
+        Self::with_window(eventlog_path, None, limit)
+    }
+
+    /// Create an App from the events with `commit_index` inside the
+    /// inclusive `window`, then at most `limit` of them.
+    ///
+    /// Display-only: the reducer sees only the loaded events. A full load
+    /// tolerates a truncated final record and marks the view instead, and
+    /// flags `commit_index` gaps, duplicates, and out-of-order indices.
+    fn with_window(
+        eventlog_path: &Path,
+        window: Option<(u64, u64)>,
+        limit: Option<usize>,
+    ) -> io::Result<Self> {
+        let options = ViewerOptions::new().with_window(window).with_limit(limit);
+        Self::with_options_progress(eventlog_path, &options, |_| ControlFlow::Continue(()))
+    }
 
-        // Reduce all events to state
-        let mut state = State::new();
-        for event in &events {
-            state = reduce(&state, event);
+    /// [`App::with_window`] for a file in `options.format` that reports
+    /// replay progress to `progress`.
+    ///
+    /// A cassette is committed in memory through the same clock-skew
+    /// detection as the append writer, so the Forensic Lens shows its
+    /// synthesized events; nothing is written next to it.
+    ///
+    /// When `progress` returns `ControlFlow::Break`, the App holds the
+    /// events reduced so far and is marked truncated, as with `limit`.
+    fn with_options_progress(
+        eventlog_path: &Path,
+        options: &ViewerOptions,
+        progress: impl FnMut(ReplayProgress) -> ControlFlow<()>,
+    ) -> io::Result<Self> {
+        let ViewerOptions {
+            format,
+            profile,
+            window,
+            limit,
+        } = *options;
+        let mut truncated_tail_bytes = None;
+        let mut integrity_issues = None;
+        let mut checkpoints = None;
+        let (mut events, mut truncated) = match (format, window, limit) {
+            (ViewFormat::Cassette, window, limit) => {
+                let imported = vifei_import::cassette::parse_cassette(BufReader::new(File::open(
+                    eventlog_path,
+                )?));
+                let mut events = commit_in_memory(imported)?;
+                if let Some((from, to)) = window {
+                    events.retain(|event| (from..=to).contains(&event.commit_index));
+                }
+                let truncated = limit.is_some_and(|limit| events.len() > limit);
+                events.truncate(limit.unwrap_or(usize::MAX));
+                (events, truncated)
+            }
+            (ViewFormat::Eventlog, Some((from, to)), limit) => {
+                let mut events = read_eventlog_range(eventlog_path, from, to)?;
+                let truncated = limit.is_some_and(|limit| events.len() > limit);
+                events.truncate(limit.unwrap_or(usize::MAX));
+                (events, truncated)
+            }
+            (ViewFormat::Eventlog, None, Some(limit)) => read_eventlog_head(eventlog_path, limit)?,
+            (ViewFormat::Eventlog, None, None) => {
+                let outcome = read_eventlog_recovering(eventlog_path)?;
+                truncated_tail_bytes = outcome.truncated_tail_bytes;
+                integrity_issues = Some(outcome.integrity)
+                    .filter(|report| !report.is_clean() || report.unknown_payloads > 0);
+                let dir = checkpoint_dir(eventlog_path);
+                if dir.is_dir() {
+                    checkpoints = CheckpointStore::open(dir).ok();
+                }
+                (outcome.events, false)
+            }
+        };
+
+        // Reduce all events to state, resuming from a checkpoint when the
+        // EventLog has a checkpoint directory
+        let outcome = match &checkpoints {
+            Some(store) => replay_with_checkpoints_progress(&events, store, progress),
+            None => replay_with_progress(&events, CHECKPOINT_INTERVAL, progress)?,
+        };
+        if outcome.is_cancelled() {
+            events.truncate(outcome.events_processed as usize);
+            truncated = true;
         }
+        let state = outcome.state;
+        let total_events = events.len();
 
-        // Project to ViewModel
-        let invariants = ProjectionInvariants::new();
+        // Project to ViewModel, with the export verdict if it is still
+        // current for this EventLog; a cassette has never been exported
+        let export_safety = match format {
+            ViewFormat::Eventlog => load_export_safety(eventlog_path),
+            ViewFormat::Cassette => ExportSafetyState::Unknown,
+        };
+        let invariants = ProjectionInvariants::new().with_export_safety(export_safety);
         let viewmodel = project(&state, &invariants);
 
         Ok(App {
@@ -139,10 +305,14 @@ impl App {
             should_quit: false,
             eventlog_path: eventlog_display_label(eventlog_path),
             total_events,
+            truncated,
+            window,
+            truncated_tail_bytes,
+            integrity_issues,
             events,
             forensic_state: forensic_lens::ForensicState::new(),
             show_onboarding: true,
-            ui_profile: UiProfile::Standard,
+            ui_profile: profile,
         })
     }
 
@@ -313,8 +483,9 @@ fn render_multiline(app: &App, width: u16, height: u16, profile: UiProfile) -> i
     Ok(text)
 }
 
-/// Run the TUI viewer for an EventLog.
-pub fn run_viewer(eventlog_path: &Path, profile: UiProfile) -> io::Result<()> {
+/// Run the TUI viewer for an EventLog, or for another input as `options`
+/// says; see [`ViewerOptions`].
+pub fn run_viewer(eventlog_path: &Path, options: &ViewerOptions) -> io::Result<()> {
     // Set up panic hook to restore terminal
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -329,9 +500,22 @@ pub fn run_viewer(eventlog_path: &Path, profile: UiProfile) -> io::Result<()> {
     let backend = CrosstermBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
 
-    // Create app state
-    let mut app = App::new(eventlog_path)?;
-    app.ui_profile = profile;
+    // Create app state, showing replay progress on long loads; q, Esc, or
+    // Ctrl-C stops the replay and shows what was reduced so far
+    let label = eventlog_display_label(eventlog_path);
+    let mut last_draw = Instant::now();
+    let mut app = App::with_options_progress(eventlog_path, options, |position| {
+        if last_draw.elapsed() < LOADING_REDRAW {
+            return ControlFlow::Continue(());
+        }
+        last_draw = Instant::now();
+        let _ = terminal.draw(|frame| render_loading(frame, &label, position));
+        if stop_requested() {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })?;
 
     // Main event loop
     loop {
@@ -360,6 +544,34 @@ pub fn run_viewer(eventlog_path: &Path, profile: UiProfile) -> io::Result<()> {
     Ok(())
 }
 
+/// Drain pending key events; true if any asks to stop loading.
+fn stop_requested() -> bool {
+    while let Ok(true) = event::poll(Duration::ZERO) {
+        if let Ok(Event::Key(key)) = event::read() {
+            let stop = matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                || (key.code == KeyCode::Char('c')
+                    && key.modifiers.contains(KeyModifiers::CONTROL));
+            if key.kind == KeyEventKind::Press && stop {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Render the loading screen shown while a long EventLog replays.
+fn render_loading(frame: &mut Frame, label: &str, position: ReplayProgress) {
+    let count = match position.total {
+        Some(total) => format!("{} / {total}", position.events_processed),
+        None => position.events_processed.to_string(),
+    };
+    let text = format!(
+        "Replaying {label}: {count} events\n\nq / Esc: stop and show the events replayed so far"
+    );
+    let block = Block::default().borders(Borders::ALL).title("Loading");
+    frame.render_widget(Paragraph::new(text).block(block), frame.area());
+}
+
 fn infallible_to_io(err: std::convert::Infallible) -> io::Error {
     match err {}
 }
@@ -384,7 +596,13 @@ fn render(frame: &mut Frame, app: &App, profile: UiProfile) {
             main_area,
             &app.state,
             &app.eventlog_path,
-            app.total_events,
+            incident_lens::EventCount {
+                total: app.total_events,
+                truncated: app.truncated,
+                window: app.window,
+                truncated_tail_bytes: app.truncated_tail_bytes,
+                integrity_issues: app.integrity_issues,
+            },
             app.show_onboarding,
             profile,
         ),
@@ -392,6 +610,7 @@ fn render(frame: &mut Frame, app: &App, profile: UiProfile) {
             frame,
             main_area,
             &app.events,
+            &app.state,
             &app.forensic_state,
             profile,
         ),
@@ -408,7 +627,7 @@ mod tests {
     use ratatui::layout::Rect;
     use std::path::Path;
     use vifei_core::event::{EventPayload, ImportEvent, Tier};
-    use vifei_core::eventlog::EventLogWriter;
+    use vifei_core::eventlog::{DurabilityPolicy, EventLogWriter};
 
     fn make_test_event(id: &str, ts: u64) -> ImportEvent {
         ImportEvent {
@@ -490,6 +709,275 @@ mod tests {
         assert!(!label.trim().is_empty());
     }
 
+    #[test]
+    fn limit_truncates_loaded_events_and_marks_view() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("five.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let app = App::with_limit(&path, Some(2)).unwrap();
+        assert_eq!(app.total_events, 2);
+        assert_eq!(app.events.len(), 2);
+        assert!(app.truncated);
+        let text = render_multiline(&app, 120, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("TRUNCATED: first 2 shown"), "{text}");
+
+        let full = App::with_limit(&path, None).unwrap();
+        assert_eq!(full.total_events, 5);
+        assert!(!full.truncated);
+        let text = render_multiline(&full, 120, 40, UiProfile::Standard).unwrap();
+        assert!(!text.contains("TRUNCATED"));
+    }
+
+    #[test]
+    fn stopped_replay_shows_the_replayed_prefix_as_truncated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("five.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let app = App::with_options_progress(&path, &ViewerOptions::new(), |p| {
+            assert_eq!(p.total, Some(5));
+            if p.events_processed == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .unwrap();
+        assert_eq!(app.total_events, 3);
+        assert_eq!(app.events.len(), 3);
+        assert_eq!(app.state.last_commit_index, 2);
+        assert!(app.truncated);
+        let text = render_multiline(&app, 120, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("TRUNCATED: first 3 shown"), "{text}");
+    }
+
+    #[test]
+    fn viewer_options_carry_window_limit_and_profile() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("five.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let options = ViewerOptions::new()
+            .with_profile(UiProfile::Showcase)
+            .with_window(Some((1, 4)))
+            .with_limit(Some(2));
+        let app =
+            App::with_options_progress(&path, &options, |_| ControlFlow::Continue(())).unwrap();
+        assert_eq!(app.ui_profile, UiProfile::Showcase);
+        assert_eq!(app.window, Some((1, 4)));
+        assert_eq!(
+            app.events
+                .iter()
+                .map(|e| e.commit_index)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert!(app.truncated);
+    }
+
+    #[test]
+    fn loads_segmented_eventlog_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("segments");
+        let mut writer =
+            EventLogWriter::open_segmented(&path, 1, DurabilityPolicy::OnDrop).unwrap();
+        for i in 0..3u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let app = App::new(&path).unwrap();
+        assert_eq!(app.total_events, 3);
+        let head = App::with_limit(&path, Some(2)).unwrap();
+        assert_eq!(head.events.len(), 2);
+        assert!(head.truncated);
+    }
+
+    #[test]
+    fn full_load_resumes_from_checkpoint_directory_when_present() {
+        use vifei_core::checkpoint_store::{checkpoint_dir, CheckpointStore};
+        use vifei_core::reducer::{create_checkpoint, replay, state_hash};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("five.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let plain = App::new(&path).unwrap();
+        assert!(!checkpoint_dir(&path).exists(), "checkpoints are opt-in");
+
+        let store = CheckpointStore::open(checkpoint_dir(&path)).unwrap();
+        let (prefix, _) = replay(&plain.events[..3]);
+        store.save(&create_checkpoint(&prefix)).unwrap();
+        let resumed = App::new(&path).unwrap();
+        assert_eq!(resumed.events.len(), 5);
+        assert_eq!(state_hash(&resumed.state), state_hash(&plain.state));
+    }
+
+    #[test]
+    fn window_loads_only_bounded_commits_and_marks_view() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("five.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..5u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+
+        let app = App::with_window(&path, Some((1, 3)), None).unwrap();
+        let indices: Vec<u64> = app.events.iter().map(|e| e.commit_index).collect();
+        assert_eq!(indices, vec![1, 2, 3]);
+        assert!(!app.truncated);
+        let text = render_multiline(&app, 120, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("WINDOW: commits 1..=3"), "{text}");
+
+        let limited = App::with_window(&path, Some((1, u64::MAX)), Some(2)).unwrap();
+        assert_eq!(limited.total_events, 2);
+        assert!(limited.truncated);
+        let text = render_multiline(&limited, 120, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("WINDOW: commits 1.."), "{text}");
+    }
+
+    #[test]
+    fn torn_final_record_loads_prefix_and_shows_banner() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("torn.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        for i in 0..3u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap();
+        std::io::Write::write_all(&mut file, br#"{"run_id":"run-1","event_id":"e3","sou"#).unwrap();
+
+        let app = App::new(&path).unwrap();
+        assert_eq!(app.total_events, 3);
+        assert_eq!(app.truncated_tail_bytes, Some(38));
+        let text = render_multiline(&app, 160, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("log ends with a truncated record"), "{text}");
+    }
+
+    #[test]
+    fn commit_index_regression_shows_integrity_banner() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.jsonl");
+        let mut writer = EventLogWriter::open(&source).unwrap();
+        for i in 0..4u64 {
+            writer
+                .append(make_test_event(&format!("e{i}"), (i + 1) * 1_000_000_000))
+                .unwrap();
+        }
+        drop(writer);
+        let lines: Vec<String> = std::fs::read_to_string(&source)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let path = dir.path().join("swapped.jsonl");
+        let body = [&lines[0], &lines[1], &lines[3], &lines[2]].map(|l| l.to_string());
+        std::fs::write(&path, body.join("\n") + "\n").unwrap();
+
+        let app = App::new(&path).unwrap();
+        let report = app.integrity_issues.expect("integrity issues");
+        assert_eq!((report.gaps, report.non_monotonic), (1, 1));
+        let text = render_multiline(&app, 160, 40, UiProfile::Standard).unwrap();
+        assert!(
+            text.contains("commit_index integrity: 1 gaps, 0 duplicates, 1 out of order"),
+            "{text}"
+        );
+
+        assert!(App::new(&source).unwrap().integrity_issues.is_none());
+    }
+
+    #[test]
+    fn cassette_view_surfaces_clock_skew_in_event_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("clock-skew-cassette.jsonl");
+        let body = r#"{"type":"session_start","session_id":"run-1","timestamp":"2026-01-01T00:00:00Z","agent":"test"}
+{"type":"tool_use","session_id":"run-1","timestamp":"2026-01-01T00:00:02Z","tool":"Read","id":"t1","args":{}}
+{"type":"tool_result","session_id":"run-1","timestamp":"2026-01-01T00:00:01Z","tool":"Read","id":"t1","result":"ok"}
+{"type":"session_end","session_id":"run-1","timestamp":"2026-01-01T00:00:03Z"}"#;
+        std::fs::write(&path, body).unwrap();
+
+        let mut app = App::with_options_progress(
+            &path,
+            &ViewerOptions::new().with_format(ViewFormat::Cassette),
+            |_| ControlFlow::Continue(()),
+        )
+        .unwrap();
+        assert_eq!(app.total_events, 5);
+        assert!(app
+            .events
+            .iter()
+            .any(|e| matches!(e.payload, EventPayload::ClockSkewDetected { .. })));
+        app.active_lens = ActiveLens::Forensic;
+        let text = render_multiline(&app, 160, 40, UiProfile::Standard).unwrap();
+        assert!(text.contains("ClockSkewDetected"), "{text}");
+        assert_eq!(
+            std::fs::read_dir(dir.path()).unwrap().count(),
+            1,
+            "viewing a cassette writes nothing"
+        );
+    }
+
+    #[test]
+    fn unknown_payload_type_shows_schema_banner() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("future.jsonl");
+        let mut writer = EventLogWriter::open(&path).unwrap();
+        writer.append(make_test_event("e0", 1_000_000_000)).unwrap();
+        drop(writer);
+        let future = r#"{"commit_index":1,"run_id":"run-1","event_id":"e1","source_id":"test","timestamp_ns":2000000000,"tier":"B","payload":{"type":"Thought","text":"hmm"},"schema_version":2}"#;
+        let mut text = std::fs::read_to_string(&path).unwrap();
+        text.push_str(future);
+        text.push('\n');
+        std::fs::write(&path, text).unwrap();
+
+        let app = App::new(&path).unwrap();
+        let report = app.integrity_issues.expect("integrity warning");
+        assert!(report.is_clean());
+        assert_eq!(report.unknown_payloads, 1);
+        let text = render_multiline(&app, 160, 40, UiProfile::Standard).unwrap();
+        assert!(
+            text.contains("1 events of unknown type (newer schema) shown as Generic"),
+            "{text}"
+        );
+        assert!(!text.contains("commit_index integrity"), "{text}");
+    }
+
     // --- Key handling tests ---
 
     #[test]
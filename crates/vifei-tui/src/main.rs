@@ -3,6 +3,7 @@
 //! Provides the `vifei` binary with subcommands for viewing, exporting,
 //! stress-testing EventLogs, and deterministic run comparison.
 
+mod artifact_check;
 mod cli_contract;
 mod cli_handlers;
 mod cli_normalize;
@@ -108,7 +109,8 @@ fn parse_error_guidance(kind: ErrorKind) -> (&'static str, Vec<String>) {
         ErrorKind::ArgumentConflict => (
             "Conflicting flags or arguments.",
             vec![
-                "Use either `--json` or `--human`, but not both.".to_string(),
+                "Use either `--json` or `--human`, and `--quiet` or `--verbose`, but not both."
+                    .to_string(),
                 "Run `vifei --help` to review valid flag combinations.".to_string(),
             ],
         ),
@@ -8,11 +8,15 @@
 //! # Required fields (from BACKPRESSURE_POLICY projection invariants)
 //!
 //! 1. Current degradation ladder level (L0..L5)
-//! 2. Aggregation mode + bin size (e.g., "1:1", "10:1", "collapsed")
+//! 2. Aggregation mode + bin size, with the real bin count at L1 and the
+//!    collapsed event total at L2+ (e.g., "1:1", "10:1 (bin=10, 3 bins)")
 //! 3. Backlog / queue pressure indicator
 //! 4. Tier A drops counter (must be 0)
 //! 5. Export safety state: UNKNOWN, CLEAN, DIRTY, REFUSED
-//! 6. Projection invariants version
+//! 6. Projection invariants version, next to the reducer version
+//!
+//! The version line also confesses how many `source_seq` gaps the EventLog
+//! has, and the worst tool error rate when any tool has failed.
 //!
 //! # Visibility rules
 //!
@@ -28,6 +32,7 @@ use ratatui::{
     Frame,
 };
 use vifei_core::projection::{ExportSafetyState, LadderLevel, ViewModel};
+use vifei_core::reducer::reducer_version;
 
 /// Color for the degradation ladder level indicator.
 fn level_style(level: LadderLevel) -> Style {
@@ -48,6 +53,15 @@ fn drops_style(drops: u64) -> Style {
     }
 }
 
+/// Color for the source sequence gap count.
+fn gaps_style(gaps: u64) -> Style {
+    if gaps > 0 {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
 /// Color for the export safety state.
 fn export_style(state: ExportSafetyState) -> Style {
     match state {
@@ -69,6 +83,15 @@ fn pressure_style(pressure_pct: u32) -> Style {
     }
 }
 
+/// Color for the worst tool error rate (millionths).
+fn tool_error_style(rate_micro: u64) -> Style {
+    if rate_micro >= 500_000 {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default().fg(Color::Yellow)
+    }
+}
+
 /// Render the Truth HUD strip into the given area.
 ///
 /// The Truth HUD confesses at minimum (per BACKPRESSURE_POLICY):
@@ -77,7 +100,8 @@ fn pressure_style(pressure_pct: u32) -> Style {
 /// - Queue pressure indicator
 /// - Tier A drops counter
 /// - Export safety state
-/// - projection_invariants_version
+/// - projection_invariants_version (and the reducer version)
+/// - Source sequence gaps
 #[allow(dead_code)] // Compatibility wrapper; default profile path for direct tests.
 pub fn render_truth_hud(frame: &mut Frame, area: Rect, vm: &ViewModel) {
     render_truth_hud_with_profile(frame, area, vm, UiProfile::Standard);
@@ -89,9 +113,7 @@ pub fn render_truth_hud_with_profile(
     vm: &ViewModel,
     profile: UiProfile,
 ) {
-    let aggregation = vm
-        .aggregation_bin_size
-        .map(|bin| format!("{} (bin={bin})", vm.aggregation_mode));
+    let aggregation = vm.aggregation_summary();
 
     let queue_pressure_pct = (vm.queue_pressure() * 100.0) as u32;
 
@@ -103,10 +125,7 @@ pub fn render_truth_hud_with_profile(
         ),
         Span::raw(" | "),
         Span::styled("Agg: ", Style::default().fg(Color::White)),
-        match &aggregation {
-            Some(text) => Span::raw(text.as_str()),
-            None => Span::raw(vm.aggregation_mode.as_str()),
-        },
+        Span::raw(aggregation),
         Span::raw(" | "),
         Span::styled("Pressure: ", Style::default().fg(Color::White)),
         Span::styled(
@@ -124,13 +143,35 @@ pub fn render_truth_hud_with_profile(
         ),
     ]);
 
-    let version_line = Line::from(vec![
+    let mut version_spans = vec![
         Span::styled(" Version: ", Style::default().fg(Color::DarkGray)),
         Span::styled(
-            &vm.projection_invariants_version,
+            format!(
+                "{} / {}",
+                vm.projection_invariants_version,
+                reducer_version()
+            ),
             Style::default().fg(Color::DarkGray),
         ),
-    ]);
+        Span::raw(" | "),
+        Span::styled("Gaps: ", Style::default().fg(Color::White)),
+        Span::styled(
+            format!("{}", vm.source_gap_count),
+            gaps_style(vm.source_gap_count),
+        ),
+    ];
+    if let Some((tool, rate_micro)) = vm.worst_tool_error_rate() {
+        version_spans.push(Span::raw(" | "));
+        version_spans.push(Span::styled(
+            "Worst tool: ",
+            Style::default().fg(Color::White),
+        ));
+        version_spans.push(Span::styled(
+            format!("{tool} {}% err", rate_micro / 10_000),
+            tool_error_style(rate_micro),
+        ));
+    }
+    let version_line = Line::from(version_spans);
 
     let block = Block::default()
         .title(match profile {
@@ -156,7 +197,7 @@ mod tests {
     use super::*;
     use ratatui::{backend::TestBackend, Terminal};
     use std::collections::BTreeMap;
-    use vifei_core::projection::ViewModel;
+    use vifei_core::projection::{BinSummary, ViewModel};
 
     /// Create a default ViewModel for testing.
     fn test_viewmodel() -> ViewModel {
@@ -164,11 +205,18 @@ mod tests {
             tier_a_summaries: BTreeMap::new(),
             aggregation_mode: "1:1".to_string(),
             aggregation_bin_size: None,
+            tier_bc_bins: Vec::new(),
+            tier_bc_collapsed: BTreeMap::new(),
             degradation_level: LadderLevel::L0,
             queue_pressure_fixed: 0,
             tier_a_drops: 0,
+            source_gap_count: 0,
+            tool_error_rate_micro: BTreeMap::new(),
+            top_tools: Vec::new(),
+            error_count: 0,
+            last_error_kind: None,
             export_safety_state: ExportSafetyState::Unknown,
-            projection_invariants_version: "projection-invariants-v0.1".to_string(),
+            projection_invariants_version: "projection-invariants-v0.7".to_string(),
         }
     }
 
@@ -207,15 +255,48 @@ mod tests {
         assert!(text.contains("Pressure:"), "Missing pressure indicator");
         assert!(text.contains("0%"), "Missing pressure value");
         assert!(text.contains("Drops:"), "Missing drops counter");
+        assert!(text.contains("Gaps: 0"), "Missing source gap count");
         assert!(text.contains("Export:"), "Missing export safety state");
         assert!(text.contains("UNKNOWN"), "Missing export value");
         assert!(text.contains("Version:"), "Missing version label");
         assert!(
-            text.contains("projection-invariants-v0.1"),
+            text.contains("projection-invariants-v0.7 / reducer-v0.9"),
             "Missing version value"
         );
     }
 
+    #[test]
+    fn truth_hud_confesses_worst_tool_error_rate() {
+        let backend = TestBackend::new(100, 5);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut vm = test_viewmodel();
+        vm.tool_error_rate_micro.insert("Read".to_string(), 0);
+        vm.tool_error_rate_micro.insert("Bash".to_string(), 400_000);
+
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 5);
+                render_truth_hud(frame, area, &vm);
+            })
+            .unwrap();
+
+        let text = buffer_text(&terminal, Rect::new(0, 0, 100, 5));
+        assert!(
+            text.contains("Worst tool: Bash 40% err"),
+            "Missing worst tool error rate"
+        );
+
+        vm.tool_error_rate_micro.insert("Bash".to_string(), 0);
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 5);
+                render_truth_hud(frame, area, &vm);
+            })
+            .unwrap();
+        let text = buffer_text(&terminal, Rect::new(0, 0, 100, 5));
+        assert!(!text.contains("Worst tool"), "No failing tool to confess");
+    }
+
     #[test]
     fn truth_hud_shows_degraded_level() {
         let backend = TestBackend::new(100, 5);
@@ -241,6 +322,15 @@ mod tests {
         let mut vm = test_viewmodel();
         vm.aggregation_mode = "10:1".to_string();
         vm.aggregation_bin_size = Some(10);
+        vm.degradation_level = LadderLevel::L1;
+        vm.tier_bc_bins = vec![
+            BinSummary {
+                first_commit_index: 0,
+                last_commit_index: 9,
+                count: 10,
+            };
+            3
+        ];
 
         terminal
             .draw(|frame| {
@@ -252,6 +342,7 @@ mod tests {
         let text = buffer_text(&terminal, Rect::new(0, 0, 100, 5));
         assert!(text.contains("10:1"), "Should display aggregation mode");
         assert!(text.contains("bin=10"), "Should display bin size");
+        assert!(text.contains("3 bins"), "Should display the real bin count");
     }
 
     #[test]
@@ -275,6 +366,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn truth_hud_confesses_source_gaps() {
+        let backend = TestBackend::new(100, 5);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut vm = test_viewmodel();
+        vm.source_gap_count = 3;
+
+        terminal
+            .draw(|frame| {
+                let area = Rect::new(0, 0, 100, 5);
+                render_truth_hud(frame, area, &vm);
+            })
+            .unwrap();
+
+        let text = buffer_text(&terminal, Rect::new(0, 0, 100, 5));
+        assert!(text.contains("Gaps: 3"), "Should confess source gaps");
+    }
+
     #[test]
     fn truth_hud_shows_export_clean() {
         let backend = TestBackend::new(100, 5);
@@ -16,11 +16,13 @@ fn make_fixture(path: &Path) {
         EventPayload::ToolCall {
             tool: "cargo test".into(),
             args: Some("--workspace".into()),
+            invocation_id: None,
         },
         EventPayload::ToolResult {
             tool: "cargo test".into(),
             result: Some("ok".into()),
             status: Some("success".into()),
+            invocation_id: None,
         },
         EventPayload::PolicyDecision {
             from_level: "L0".into(),
@@ -8,6 +8,8 @@
 
 use vifei_core::event::{EventPayload, ImportEvent, Tier};
 use vifei_core::eventlog::EventLogWriter;
+use vifei_core::export_safety::write_export_safety;
+use vifei_core::projection::ExportSafetyState;
 use vifei_tui::render_to_buffer;
 
 /// Create a minimal fixture event for the EventLog.
@@ -80,6 +82,10 @@ fn truth_hud_all_fields_present() {
         text.contains("Drops:"),
         "Missing Tier A drops label in Truth HUD"
     );
+    assert!(
+        text.contains("Gaps: 0"),
+        "Repeated source_seq must not count as a gap"
+    );
     assert!(
         text.contains("Export:"),
         "Missing export safety state label in Truth HUD"
@@ -93,7 +99,7 @@ fn truth_hud_all_fields_present() {
         "Missing projection invariants version label in Truth HUD"
     );
     assert!(
-        text.contains("projection-invariants-v0.1"),
+        text.contains("projection-invariants-v0.7"),
         "Missing projection invariants version value"
     );
 }
@@ -144,7 +150,7 @@ fn truth_hud_renders_with_empty_eventlog() {
         "HUD version must render even with empty EventLog"
     );
     assert!(
-        text.contains("projection-invariants-v0.1"),
+        text.contains("projection-invariants-v0.7"),
         "HUD version value must be present with empty EventLog"
     );
 }
@@ -162,7 +168,92 @@ fn truth_hud_version_exact() {
     let text = render_to_buffer(&path, 120, 24).unwrap();
 
     assert!(
-        text.contains("projection-invariants-v0.1"),
-        "Exact version string 'projection-invariants-v0.1' must appear in HUD"
+        text.contains("projection-invariants-v0.7"),
+        "Exact version string 'projection-invariants-v0.7' must appear in HUD"
+    );
+}
+
+/// Skipped `source_seq` values reach the HUD as a gap count.
+#[test]
+fn truth_hud_confesses_source_gaps() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fixture.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    for (id, seq) in [("e1", 0), ("e2", 1), ("e3", 4), ("e4", 5), ("e5", 9)] {
+        let mut event = fixture_event(id, 1_000_000_000);
+        event.source_seq = Some(seq);
+        writer.append(event).unwrap();
+    }
+    drop(writer);
+
+    let text = render_to_buffer(&path, 120, 24).unwrap();
+    assert!(text.contains("Gaps: 2"), "Two skips must be confessed");
+}
+
+/// A current export safety sidecar sets the HUD's export state.
+#[test]
+fn truth_hud_shows_persisted_export_safety() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fixture.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer.append(fixture_event("e1", 1_000_000_000)).unwrap();
+    drop(writer);
+
+    let text = render_to_buffer(&path, 120, 24).unwrap();
+    assert!(text.contains("Export: UNKNOWN"), "No sidecar means UNKNOWN");
+
+    for (state, shown) in [
+        (ExportSafetyState::Clean, "Export: CLEAN"),
+        (ExportSafetyState::Dirty, "Export: DIRTY"),
+        (ExportSafetyState::Refused, "Export: REFUSED"),
+    ] {
+        write_export_safety(&path, state, "secret-scanner-v0.1").unwrap();
+        let text = render_to_buffer(&path, 120, 24).unwrap();
+        assert!(text.contains(shown), "Missing {shown:?} in HUD");
+    }
+}
+
+/// A sidecar written before the EventLog changed is ignored.
+#[test]
+fn truth_hud_ignores_stale_export_safety() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fixture.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer.append(fixture_event("e1", 1_000_000_000)).unwrap();
+    drop(writer);
+    write_export_safety(&path, ExportSafetyState::Clean, "secret-scanner-v0.1").unwrap();
+
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer.append(fixture_event("e2", 2_000_000_000)).unwrap();
+    drop(writer);
+
+    let text = render_to_buffer(&path, 120, 24).unwrap();
+    assert!(
+        text.contains("Export: UNKNOWN"),
+        "Stale sidecar must not be trusted"
+    );
+}
+
+/// Viewing an EventLog after a clean share-safe export shows the scan's
+/// verdict, end to end through the real scanner.
+#[test]
+fn truth_hud_shows_clean_after_real_export() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fixture.jsonl");
+    let mut writer = EventLogWriter::open(&path).unwrap();
+    writer.append(fixture_event("e1", 1_000_000_000)).unwrap();
+    drop(writer);
+
+    let config = vifei_export::ExportConfig::new(&path, dir.path().join("bundle.tar.zst"));
+    let result = vifei_export::run_export(&config).unwrap();
+    assert!(
+        result.into_success().is_ok(),
+        "Fixture export must be clean"
+    );
+
+    let text = render_to_buffer(&path, 120, 24).unwrap();
+    assert!(
+        text.contains("Export: CLEAN"),
+        "HUD must show the clean scan"
     );
 }